@@ -3,8 +3,10 @@
 
 use godot::prelude::*;
 use sim_runtime::{parse_command_line, CommandEnvelope};
-use std::sync::mpsc::Sender;
-use std::sync::{mpsc, OnceLock};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::mpsc::{Receiver, Sender};
+use std::sync::{mpsc, Mutex, OnceLock};
 use std::thread;
 
 use crate::runtime::transmit_proto_command;
@@ -14,19 +16,57 @@ use crate::runtime::transmit_proto_command;
 pub struct CommandBridge;
 
 static COMMAND_BRIDGE_SENDER: OnceLock<Sender<CommandRequest>> = OnceLock::new();
+static PENDING_COMMANDS: OnceLock<PendingCommands> = OnceLock::new();
+static NEXT_CORRELATION_ID: AtomicU64 = AtomicU64::new(1);
 
 struct CommandRequest {
     host: String,
     port: u16,
     envelope: CommandEnvelope,
-    callback: Sender<CommandResult>,
 }
 
+#[derive(Debug)]
 struct CommandResult {
+    correlation_id: u64,
     ok: bool,
     error: Option<String>,
 }
 
+/// Callers waiting on a command, keyed by the correlation id stamped on its envelope. The worker
+/// hands each result to the caller whose id it carries, so a result can never be delivered to a
+/// different request than the one that produced it.
+#[derive(Default)]
+struct PendingCommands {
+    callbacks: Mutex<HashMap<u64, Sender<CommandResult>>>,
+}
+
+impl PendingCommands {
+    fn register(&self, correlation_id: u64) -> Receiver<CommandResult> {
+        let (tx, rx) = mpsc::channel();
+        self.callbacks.lock().unwrap().insert(correlation_id, tx);
+        rx
+    }
+
+    /// Drops the callback for a caller that stopped waiting (e.g. timed out).
+    fn cancel(&self, correlation_id: u64) {
+        self.callbacks.lock().unwrap().remove(&correlation_id);
+    }
+
+    /// Delivers `result` to its caller; `false` when nobody is waiting on that id.
+    fn resolve(&self, result: CommandResult) -> bool {
+        let callback = self
+            .callbacks
+            .lock()
+            .unwrap()
+            .remove(&result.correlation_id);
+        callback.is_some_and(|callback| callback.send(result).is_ok())
+    }
+}
+
+fn pending_commands() -> &'static PendingCommands {
+    PENDING_COMMANDS.get_or_init(PendingCommands::default)
+}
+
 #[godot_api]
 impl CommandBridge {
     #[allow(dead_code)]
@@ -47,10 +87,11 @@ impl CommandBridge {
         let host_str = host.to_string();
         let line_str = line.to_string();
 
+        let correlation_id = NEXT_CORRELATION_ID.fetch_add(1, Ordering::Relaxed);
         let envelope = match parse_command_line(&line_str) {
             Ok(payload) => CommandEnvelope {
                 payload,
-                correlation_id: None,
+                correlation_id: Some(correlation_id),
             },
             Err(err) => {
                 let _ = dict.insert("ok", false);
@@ -61,13 +102,14 @@ impl CommandBridge {
 
         let sender = command_sender();
 
-        let (tx, rx) = mpsc::channel();
+        let pending = pending_commands();
+        let rx = pending.register(correlation_id);
         if let Err(err) = sender.send(CommandRequest {
             host: host_str,
             port: proto_port as u16,
             envelope,
-            callback: tx,
         }) {
+            pending.cancel(correlation_id);
             let _ = dict.insert("ok", false);
             let _ = dict.insert("error", format!("dispatch error: {err}"));
             return dict;
@@ -81,10 +123,12 @@ impl CommandBridge {
                 }
             }
             Err(_) => {
+                pending.cancel(correlation_id);
                 let _ = dict.insert("ok", false);
                 let _ = dict.insert("error", "command timed out");
             }
         }
+        let _ = dict.insert("correlation_id", correlation_id as i64);
 
         dict
     }
@@ -92,18 +136,21 @@ impl CommandBridge {
 
 fn prototype_command_worker(receiver: mpsc::Receiver<CommandRequest>) {
     for request in receiver {
+        let correlation_id = request.envelope.correlation_id.unwrap_or_default();
         let result = match transmit_proto_command(&request.host, request.port, &request.envelope) {
-            Ok(_) => CommandResult {
+            Ok(response) => CommandResult {
+                correlation_id: response.correlation_id.unwrap_or(correlation_id),
                 ok: true,
                 error: None,
             },
             Err(err) => CommandResult {
+                correlation_id,
                 ok: false,
                 error: Some(err),
             },
         };
 
-        let _ = request.callback.send(result);
+        pending_commands().resolve(result);
     }
 }
 
@@ -135,3 +182,168 @@ pub(crate) fn resolve_entry_path(manifest_path: &str, entry: &str) -> String {
         format!("{base}{trimmed}")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sim_runtime::{CommandPayload, CommandResponse, COMMAND_PROTOCOL_VERSION};
+    use std::io::{Read, Write};
+    use std::net::{TcpListener, TcpStream};
+
+    fn result(correlation_id: u64, ok: bool) -> CommandResult {
+        CommandResult {
+            correlation_id,
+            ok,
+            error: (!ok).then(|| format!("failed {correlation_id}")),
+        }
+    }
+
+    #[test]
+    fn pending_commands_route_results_by_correlation_id() {
+        let pending = PendingCommands::default();
+        let first = pending.register(1);
+        let second = pending.register(2);
+
+        assert!(pending.resolve(result(2, false)));
+        assert!(pending.resolve(result(1, true)));
+        assert!(!pending.resolve(result(3, true)), "nobody waits on id 3");
+
+        let first = first.try_recv().expect("first result");
+        assert_eq!(first.correlation_id, 1);
+        assert!(first.ok);
+        let second = second.try_recv().expect("second result");
+        assert_eq!(second.correlation_id, 2);
+        assert_eq!(second.error.as_deref(), Some("failed 2"));
+
+        let cancelled = pending.register(4);
+        pending.cancel(4);
+        assert!(!pending.resolve(result(4, true)));
+        assert!(cancelled.try_recv().is_err());
+    }
+
+    fn read_envelope(stream: &mut TcpStream) -> CommandEnvelope {
+        let mut len_buf = [0u8; 4];
+        stream.read_exact(&mut len_buf).expect("frame length");
+        let mut frame = vec![0u8; u32::from_le_bytes(len_buf) as usize];
+        stream.read_exact(&mut frame).expect("frame");
+        CommandEnvelope::decode(&frame).expect("envelope decodes")
+    }
+
+    fn reply(stream: &mut TcpStream, correlation_id: Option<u64>) {
+        respond(stream, &CommandResponse::accepted(correlation_id));
+    }
+
+    fn respond(stream: &mut TcpStream, response: &CommandResponse) {
+        let bytes = response.encode_to_vec();
+        stream
+            .write_all(&(bytes.len() as u32).to_le_bytes())
+            .expect("write length");
+        stream.write_all(&bytes).expect("write response");
+    }
+
+    /// Every connection opens with a handshake at the client's protocol version; ack it.
+    fn accept_handshake(stream: &mut TcpStream) {
+        let envelope = read_envelope(stream);
+        assert!(
+            matches!(
+                envelope.payload,
+                CommandPayload::Handshake {
+                    protocol_version: COMMAND_PROTOCOL_VERSION,
+                    ..
+                }
+            ),
+            "{:?}",
+            envelope.payload
+        );
+        reply(stream, envelope.correlation_id);
+    }
+
+    fn send(port: u16, correlation_id: u64) -> thread::JoinHandle<Result<CommandResponse, String>> {
+        thread::spawn(move || {
+            let envelope = CommandEnvelope {
+                payload: CommandPayload::Turn { steps: 1 },
+                correlation_id: Some(correlation_id),
+            };
+            transmit_proto_command("127.0.0.1", port, &envelope)
+        })
+    }
+
+    #[test]
+    fn out_of_order_responses_match_their_requests() {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("bind");
+        let port = listener.local_addr().expect("addr").port();
+        let server = thread::spawn(move || {
+            let mut inbound: Vec<(TcpStream, Option<u64>)> = (0..2)
+                .map(|_| {
+                    let (mut stream, _) = listener.accept().expect("accept");
+                    accept_handshake(&mut stream);
+                    let envelope = read_envelope(&mut stream);
+                    (stream, envelope.correlation_id)
+                })
+                .collect();
+            // Answer the later request first.
+            for (stream, correlation_id) in inbound.iter_mut().rev() {
+                reply(stream, *correlation_id);
+            }
+        });
+
+        let first = send(port, 7);
+        let second = send(port, 8);
+        let first = first.join().unwrap().expect("first command acknowledged");
+        let second = second.join().unwrap().expect("second command acknowledged");
+        server.join().unwrap();
+
+        assert_eq!(first.correlation_id, Some(7));
+        assert_eq!(second.correlation_id, Some(8));
+    }
+
+    #[test]
+    fn mismatched_correlation_id_is_an_error() {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("bind");
+        let port = listener.local_addr().expect("addr").port();
+        let server = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().expect("accept");
+            accept_handshake(&mut stream);
+            let _ = read_envelope(&mut stream);
+            reply(&mut stream, Some(99));
+        });
+
+        let err = send(port, 5)
+            .join()
+            .unwrap()
+            .expect_err("wrong id rejected");
+        server.join().unwrap();
+        assert!(err.contains("does not match"), "{err}");
+    }
+
+    #[test]
+    fn rejected_handshake_stops_the_command() {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("bind");
+        let port = listener.local_addr().expect("addr").port();
+        let server = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().expect("accept");
+            let handshake = read_envelope(&mut stream);
+            respond(
+                &mut stream,
+                &CommandResponse::rejected(
+                    handshake.correlation_id,
+                    "protocol version mismatch: client speaks 1, server speaks 2",
+                ),
+            );
+            // The command frame never follows a refused handshake.
+            let mut rest = Vec::new();
+            stream.read_to_end(&mut rest).expect("client hangs up");
+            assert!(rest.is_empty());
+        });
+
+        let err = send(port, 6)
+            .join()
+            .unwrap()
+            .expect_err("handshake refused");
+        server.join().unwrap();
+        assert!(
+            err.starts_with("handshake failed: protocol version mismatch"),
+            "{err}"
+        );
+    }
+}
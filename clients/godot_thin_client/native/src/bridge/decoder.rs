@@ -5,8 +5,8 @@ use godot::prelude::*;
 use shadow_scale_flatbuffers::shadow_scale::sim as fb;
 
 use crate::dict::campaign::{
-    command_events_to_array, pending_forks_to_array, stance_axes_to_array, victory_state_to_dict,
-    voice_medium_to_array,
+    calendar_to_dict, command_events_to_array, pending_forks_to_array, stance_axes_to_array,
+    victory_state_to_dict, voice_medium_to_array,
 };
 use crate::dict::culture::{
     axis_bias_to_dict, culture_layers_to_array, culture_tensions_to_array, influencers_to_array,
@@ -14,16 +14,19 @@ use crate::dict::culture::{
 };
 use crate::dict::economy::trade_links_to_array;
 use crate::dict::governance::{
-    corruption_to_dict, crisis_overlay_to_dict, crisis_telemetry_to_dict, power_metrics_to_dict,
-    power_nodes_to_array,
+    corruption_to_dict, crisis_overlay_to_dict, crisis_telemetry_to_dict,
+    diplomacy_leverage_to_array, power_metrics_to_dict, power_nodes_to_array,
 };
 use crate::dict::knowledge::{
-    discovered_sites_to_array, discovery_progress_to_array, great_discovery_definitions_to_array,
-    great_discovery_progress_states_to_array, great_discovery_states_to_array,
-    great_discovery_telemetry_to_dict,
+    discovered_sites_to_array, discovery_progress_to_array, espionage_agents_to_array,
+    great_discovery_definitions_to_array, great_discovery_progress_states_to_array,
+    great_discovery_states_to_array, great_discovery_telemetry_to_dict,
+    knowledge_early_warnings_to_array,
 };
 use crate::dict::map::tiles_to_array;
-use crate::dict::population::{demographics_to_array, generations_to_array, populations_to_array};
+use crate::dict::population::{
+    demographics_to_array, generations_to_array, morale_summary_to_array, populations_to_array,
+};
 use crate::dict::subsistence::{
     forage_patches_to_array, herds_to_array, intensification_knowledge_to_array,
     sedentarization_to_array,
@@ -36,44 +39,191 @@ use crate::snapshot::snapshot_to_dict;
 
 #[derive(Default, GodotClass)]
 #[class(init, base=RefCounted)]
-pub struct SnapshotDecoder;
+pub struct SnapshotDecoder {
+    sequence: SequenceTracker,
+}
 
 #[godot_api]
 impl SnapshotDecoder {
+    /// Decode a snapshot (or delta) envelope. On failure returns `{ ok: false, error, kind }`
+    /// instead of an empty dictionary, so a truncated frame surfaces a diagnostic rather than a
+    /// blank world. Every decoded frame carries `needs_resync`: true once a delta arrives out of
+    /// sequence, until the next full snapshot.
     #[func]
-    pub fn decode_snapshot(&self, data: PackedByteArray) -> VarDictionary {
-        decode_snapshot(&data).unwrap_or_default()
+    pub fn decode_snapshot(&mut self, data: PackedByteArray) -> VarDictionary {
+        decode_snapshot(&data, &mut self.sequence).unwrap_or_else(|err| error_to_dict(&err))
     }
 
     #[func]
-    pub fn decode_delta(&self, data: PackedByteArray) -> VarDictionary {
-        decode_delta(&data).unwrap_or_default()
+    pub fn decode_delta(&mut self, data: PackedByteArray) -> VarDictionary {
+        decode_delta(&data, &mut self.sequence).unwrap_or_else(|err| error_to_dict(&err))
     }
 }
 
-fn decode_snapshot(data: &PackedByteArray) -> Option<VarDictionary> {
-    if data.is_empty() {
-        return None;
+/// Header `sequence` of the frames applied so far. Deltas are only meaningful applied in order on
+/// top of the last full snapshot, so a delta that is not `last + sequenceSpan` (one for a single
+/// delta, N for a backlog of N coalesced by the server) means one was dropped and the reconstructed
+/// world is stale until a full snapshot replaces it. The later parts of a split delta
+/// (`deltaPart > 0`) repeat their first part's sequence. Sequence 0 is an unsequenced producer and
+/// never flags a gap.
+#[derive(Debug, Default)]
+pub(crate) struct SequenceTracker {
+    last_applied: Option<u64>,
+    resync_pending: bool,
+}
+
+impl SequenceTracker {
+    /// A full snapshot is a fresh baseline: it clears any pending resync.
+    pub(crate) fn observe_snapshot(&mut self, sequence: u64) {
+        self.last_applied = (sequence != 0).then_some(sequence);
+        self.resync_pending = false;
+    }
+
+    /// Records a delta and returns whether the client must resync. Stays `true` after a gap until
+    /// the next [`Self::observe_snapshot`], since every later delta builds on the corrupted state.
+    pub(crate) fn observe_delta(&mut self, sequence: u64, part: u32, span: u32) -> bool {
+        if sequence != 0 {
+            let advance = u64::from(span.max(1));
+            let expected = |last: u64| {
+                if part > 0 {
+                    last
+                } else {
+                    last.wrapping_add(advance)
+                }
+            };
+            if self
+                .last_applied
+                .is_some_and(|last| sequence != expected(last))
+            {
+                self.resync_pending = true;
+            }
+            self.last_applied = Some(sequence);
+        }
+        self.resync_pending
     }
-    let bytes = data.as_slice();
-    let envelope = fb::root_as_envelope(bytes).ok()?;
-    match envelope.payload_type() {
-        fb::SnapshotPayload::snapshot => envelope.payload_as_snapshot().map(snapshot_to_dict),
-        fb::SnapshotPayload::delta => decode_delta(data),
-        _ => None,
+}
+
+fn header_sequence(header: Option<fb::SnapshotHeader<'_>>) -> u64 {
+    header.map_or(0, |header| header.sequence())
+}
+
+fn header_delta_part(header: Option<fb::SnapshotHeader<'_>>) -> u32 {
+    header.map_or(0, |header| header.deltaPart())
+}
+
+fn header_sequence_span(header: Option<fb::SnapshotHeader<'_>>) -> u32 {
+    header.map_or(0, |header| header.sequenceSpan())
+}
+
+/// Why a frame could not be decoded. Kept free of Godot types so the classification is testable
+/// without a live engine.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub(crate) enum FrameDecodeError {
+    #[error("empty frame")]
+    Empty,
+    /// The verifier tried to read past the end of the buffer: the frame was cut short in transit.
+    #[error("truncated frame ({len} bytes): {detail}")]
+    Truncated { len: usize, detail: String },
+    /// The buffer is long enough but its contents do not verify as an `Envelope`.
+    #[error("corrupt frame ({len} bytes): {detail}")]
+    Corrupt { len: usize, detail: String },
+    #[error("unexpected payload type `{found}` (expected {expected})")]
+    UnexpectedPayload {
+        expected: &'static str,
+        found: String,
+    },
+}
+
+impl FrameDecodeError {
+    /// Stable machine-readable tag for the `kind` field of the diagnostic dictionary.
+    pub(crate) fn kind(&self) -> &'static str {
+        match self {
+            FrameDecodeError::Empty => "empty",
+            FrameDecodeError::Truncated { .. } => "truncated",
+            FrameDecodeError::Corrupt { .. } => "corrupt",
+            FrameDecodeError::UnexpectedPayload { .. } => "unexpected_payload",
+        }
     }
 }
 
-fn decode_delta(data: &PackedByteArray) -> Option<VarDictionary> {
-    if data.is_empty() {
-        return None;
+fn error_to_dict(err: &FrameDecodeError) -> VarDictionary {
+    let mut dict = VarDictionary::new();
+    let _ = dict.insert("ok", false);
+    let _ = dict.insert("kind", err.kind());
+    let _ = dict.insert("error", err.to_string().as_str());
+    dict
+}
+
+/// Verify `bytes` as an `Envelope`, classifying a failure as truncation (a read past the end of the
+/// buffer) or corruption (anything else the verifier rejects).
+pub(crate) fn parse_envelope(bytes: &[u8]) -> Result<fb::Envelope<'_>, FrameDecodeError> {
+    if bytes.is_empty() {
+        return Err(FrameDecodeError::Empty);
+    }
+    fb::root_as_envelope(bytes).map_err(|err| {
+        let len = bytes.len();
+        let detail = err.to_string().trim_end().to_string();
+        match err {
+            flatbuffers::InvalidFlatbuffer::RangeOutOfBounds { .. } => {
+                FrameDecodeError::Truncated { len, detail }
+            }
+            _ => FrameDecodeError::Corrupt { len, detail },
+        }
+    })
+}
+
+fn payload_name(payload: fb::SnapshotPayload) -> String {
+    payload
+        .variant_name()
+        .map(str::to_string)
+        .unwrap_or_else(|| format!("#{}", payload.0))
+}
+
+fn decode_snapshot(
+    data: &PackedByteArray,
+    sequence: &mut SequenceTracker,
+) -> Result<VarDictionary, FrameDecodeError> {
+    let envelope = parse_envelope(data.as_slice())?;
+    match envelope.payload_type() {
+        fb::SnapshotPayload::snapshot => {
+            let snapshot = envelope.payload_as_snapshot().ok_or_else(|| {
+                FrameDecodeError::UnexpectedPayload {
+                    expected: "snapshot",
+                    found: payload_name(envelope.payload_type()),
+                }
+            })?;
+            sequence.observe_snapshot(header_sequence(snapshot.header()));
+            let mut dict = snapshot_to_dict(snapshot);
+            let _ = dict.insert("needs_resync", false);
+            let _ = dict.insert("ok", true);
+            Ok(dict)
+        }
+        fb::SnapshotPayload::delta => decode_delta(data, sequence),
+        other => Err(FrameDecodeError::UnexpectedPayload {
+            expected: "snapshot or delta",
+            found: payload_name(other),
+        }),
     }
-    let bytes = data.as_slice();
-    let envelope = fb::root_as_envelope(bytes).ok()?;
+}
+
+fn decode_delta(
+    data: &PackedByteArray,
+    sequence: &mut SequenceTracker,
+) -> Result<VarDictionary, FrameDecodeError> {
+    let envelope = parse_envelope(data.as_slice())?;
+    let unexpected = || FrameDecodeError::UnexpectedPayload {
+        expected: "delta",
+        found: payload_name(envelope.payload_type()),
+    };
     if envelope.payload_type() != fb::SnapshotPayload::delta {
-        return None;
-    }
-    let delta = envelope.payload_as_delta()?;
+        return Err(unexpected());
+    }
+    let delta = envelope.payload_as_delta().ok_or_else(unexpected)?;
+    let needs_resync = sequence.observe_delta(
+        header_sequence(delta.header()),
+        header_delta_part(delta.header()),
+        header_sequence_span(delta.header()),
+    );
     // For now, render deltas by synthesizing a snapshot-sized dictionary where only
     // updated tiles affect the overlays. This keeps the UI responsive while we pump
     // full snapshots on the same stream.
@@ -82,6 +232,11 @@ fn decode_delta(data: &PackedByteArray) -> Option<VarDictionary> {
         agg.tick = header.tick();
         agg.wrap_horizontal = header.wrapHorizontal();
         agg.world_epoch = header.worldEpoch();
+        agg.config_hash = header.configHash();
+        agg.sequence = header.sequence();
+        agg.delta_part = header.deltaPart();
+        agg.delta_part_count = header.deltaPartCount();
+        agg.computed_channels = header.computedChannels();
         if let Some(build) = header.serverBuild() {
             agg.server_build = build.to_string();
         }
@@ -115,6 +270,9 @@ fn decode_delta(data: &PackedByteArray) -> Option<VarDictionary> {
     if let Some(raster) = delta.vision().and_then(|s| s.militaryRaster()) {
         agg.apply_military_raster(raster);
     }
+    if let Some(raster) = delta.population().and_then(|s| s.populationDensityRaster()) {
+        agg.apply_population_density_raster(raster);
+    }
     if let Some(overlay) = delta.governance().and_then(|s| s.crisisOverlay()) {
         agg.apply_crisis_overlay(overlay);
     }
@@ -149,6 +307,10 @@ fn decode_delta(data: &PackedByteArray) -> Option<VarDictionary> {
         let _ = dict.insert("voice_medium", &voice_medium_to_array(voice_medium));
     }
 
+    if let Some(calendar) = delta.campaign().and_then(|s| s.calendar()) {
+        let _ = dict.insert("calendar", &calendar_to_dict(calendar));
+    }
+
     if let Some(herds) = delta.subsistence().and_then(|s| s.herds()) {
         let _ = dict.insert("herds", &herds_to_array(herds));
     }
@@ -177,6 +339,9 @@ fn decode_delta(data: &PackedByteArray) -> Option<VarDictionary> {
     if let Some(demographics) = delta.population().and_then(|s| s.demographics()) {
         let _ = dict.insert("demographics", &demographics_to_array(demographics));
     }
+    if let Some(summary) = delta.population().and_then(|s| s.moraleSummary()) {
+        let _ = dict.insert("morale_summary", &morale_summary_to_array(summary));
+    }
 
     if let Some(discovered_sites) = delta.knowledge().and_then(|s| s.discoveredSites()) {
         let _ = dict.insert(
@@ -184,6 +349,9 @@ fn decode_delta(data: &PackedByteArray) -> Option<VarDictionary> {
             &discovered_sites_to_array(discovered_sites),
         );
     }
+    if let Some(agents) = delta.knowledge().and_then(|s| s.espionageAgents()) {
+        let _ = dict.insert("espionage_agents", &espionage_agents_to_array(agents));
+    }
 
     if let Some(definitions) = delta
         .knowledge()
@@ -246,6 +414,11 @@ fn decode_delta(data: &PackedByteArray) -> Option<VarDictionary> {
         let _ = dict.insert("corruption", &corruption_to_dict(ledger));
     }
 
+    // Present only when some pair moved, and then it is the whole matrix (replace, not merge).
+    if let Some(pairs) = delta.governance().and_then(|s| s.diplomacyLeverage()) {
+        let _ = dict.insert("diplomacy_leverage", &diplomacy_leverage_to_array(pairs));
+    }
+
     if let Some(populations) = delta.population().and_then(|s| s.populations()) {
         let _ = dict.insert("population_updates", &populations_to_array(populations));
     }
@@ -319,6 +492,210 @@ fn decode_delta(data: &PackedByteArray) -> Option<VarDictionary> {
             &discovery_progress_to_array(progress),
         );
     }
+    if let Some(warnings) = delta.knowledge().and_then(|s| s.knowledgeEarlyWarnings()) {
+        let _ = dict.insert(
+            "knowledge_early_warnings",
+            &knowledge_early_warnings_to_array(warnings),
+        );
+    }
+
+    let _ = dict.insert("needs_resync", needs_resync);
+    let _ = dict.insert("ok", true);
+    Ok(dict)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn encoded_delta_frame() -> Vec<u8> {
+        let mut builder = flatbuffers::FlatBufferBuilder::new();
+        let delta = fb::WorldDelta::create(&mut builder, &fb::WorldDeltaArgs::default());
+        let envelope = fb::Envelope::create(
+            &mut builder,
+            &fb::EnvelopeArgs {
+                payload_type: fb::SnapshotPayload::delta,
+                payload: Some(delta.as_union_value()),
+            },
+        );
+        builder.finish(envelope, None);
+        builder.finished_data().to_vec()
+    }
+
+    #[test]
+    fn intact_frame_parses() {
+        let bytes = encoded_delta_frame();
+        let envelope = parse_envelope(&bytes).expect("intact frame verifies");
+        assert_eq!(envelope.payload_type(), fb::SnapshotPayload::delta);
+    }
+
+    #[test]
+    fn truncated_frame_reports_truncation() {
+        let bytes = encoded_delta_frame();
+        for cut in [1, 3, bytes.len() / 2, bytes.len() - 1] {
+            let err = parse_envelope(&bytes[..cut]).expect_err("truncated frame must not decode");
+            assert_eq!(err.kind(), "truncated", "cut at {cut}: {err}");
+            assert!(err.to_string().contains(&format!("{cut} bytes")));
+        }
+    }
+
+    fn sequenced_frame(payload_type: fb::SnapshotPayload, sequence: u64) -> Vec<u8> {
+        split_frame(payload_type, sequence, 0)
+    }
+
+    fn split_frame(payload_type: fb::SnapshotPayload, sequence: u64, delta_part: u32) -> Vec<u8> {
+        header_frame(
+            payload_type,
+            fb::SnapshotHeaderArgs {
+                sequence,
+                deltaPart: delta_part,
+                ..Default::default()
+            },
+        )
+    }
+
+    fn coalesced_frame(sequence: u64, span: u32) -> Vec<u8> {
+        header_frame(
+            fb::SnapshotPayload::delta,
+            fb::SnapshotHeaderArgs {
+                sequence,
+                sequenceSpan: span,
+                ..Default::default()
+            },
+        )
+    }
+
+    fn header_frame(
+        payload_type: fb::SnapshotPayload,
+        header_args: fb::SnapshotHeaderArgs<'_>,
+    ) -> Vec<u8> {
+        let mut builder = flatbuffers::FlatBufferBuilder::new();
+        let header = fb::SnapshotHeader::create(&mut builder, &header_args);
+        let payload = if payload_type == fb::SnapshotPayload::snapshot {
+            fb::WorldSnapshot::create(
+                &mut builder,
+                &fb::WorldSnapshotArgs {
+                    header: Some(header),
+                    ..Default::default()
+                },
+            )
+            .as_union_value()
+        } else {
+            fb::WorldDelta::create(
+                &mut builder,
+                &fb::WorldDeltaArgs {
+                    header: Some(header),
+                    ..Default::default()
+                },
+            )
+            .as_union_value()
+        };
+        let envelope = fb::Envelope::create(
+            &mut builder,
+            &fb::EnvelopeArgs {
+                payload_type,
+                payload: Some(payload),
+            },
+        );
+        builder.finish(envelope, None);
+        builder.finished_data().to_vec()
+    }
+
+    /// Feeds one encoded frame through the tracker the way `decode_snapshot` does, returning the
+    /// `needs_resync` value the decoded dictionary would carry.
+    fn observe(tracker: &mut SequenceTracker, bytes: &[u8]) -> bool {
+        let envelope = parse_envelope(bytes).expect("frame verifies");
+        match envelope.payload_type() {
+            fb::SnapshotPayload::snapshot => {
+                let snapshot = envelope.payload_as_snapshot().expect("snapshot payload");
+                tracker.observe_snapshot(header_sequence(snapshot.header()));
+                false
+            }
+            _ => {
+                let delta = envelope.payload_as_delta().expect("delta payload");
+                tracker.observe_delta(
+                    header_sequence(delta.header()),
+                    header_delta_part(delta.header()),
+                    header_sequence_span(delta.header()),
+                )
+            }
+        }
+    }
+
+    #[test]
+    fn skipped_delta_sequence_sets_needs_resync_until_a_full_snapshot() {
+        let mut tracker = SequenceTracker::default();
+        let snapshot = fb::SnapshotPayload::snapshot;
+        let delta = fb::SnapshotPayload::delta;
+
+        assert!(!observe(&mut tracker, &sequenced_frame(snapshot, 4)));
+        assert!(!observe(&mut tracker, &sequenced_frame(delta, 5)));
+        assert!(!observe(&mut tracker, &sequenced_frame(delta, 6)));
+        // Delta 7 was dropped in transit.
+        assert!(observe(&mut tracker, &sequenced_frame(delta, 8)));
+        assert!(
+            observe(&mut tracker, &sequenced_frame(delta, 9)),
+            "later in-order deltas still build on the stale world"
+        );
+
+        assert!(!observe(&mut tracker, &sequenced_frame(snapshot, 9)));
+        assert!(!observe(&mut tracker, &sequenced_frame(delta, 10)));
+    }
+
+    #[test]
+    fn later_parts_of_a_split_delta_share_its_sequence() {
+        let mut tracker = SequenceTracker::default();
+        let delta = fb::SnapshotPayload::delta;
+
+        assert!(!observe(
+            &mut tracker,
+            &sequenced_frame(fb::SnapshotPayload::snapshot, 4)
+        ));
+        assert!(!observe(&mut tracker, &split_frame(delta, 5, 0)));
+        assert!(!observe(&mut tracker, &split_frame(delta, 5, 1)));
+        assert!(!observe(&mut tracker, &split_frame(delta, 5, 2)));
+        assert!(!observe(&mut tracker, &sequenced_frame(delta, 6)));
+        assert!(
+            observe(&mut tracker, &split_frame(delta, 8, 1)),
+            "a continuation of a delta never seen is a gap"
+        );
+    }
+
+    #[test]
+    fn a_coalesced_delta_advances_the_sequence_by_its_span() {
+        let mut tracker = SequenceTracker::default();
+        let delta = fb::SnapshotPayload::delta;
+
+        assert!(!observe(
+            &mut tracker,
+            &sequenced_frame(fb::SnapshotPayload::snapshot, 4)
+        ));
+        assert!(!observe(&mut tracker, &sequenced_frame(delta, 5)));
+        // Deltas 6..=8 were folded into one frame for a lagging subscriber.
+        assert!(!observe(&mut tracker, &coalesced_frame(8, 3)));
+        assert!(!observe(&mut tracker, &sequenced_frame(delta, 9)));
+        assert!(
+            observe(&mut tracker, &coalesced_frame(12, 2)),
+            "a span that does not reach back to the last delta is a gap"
+        );
+    }
+
+    #[test]
+    fn unsequenced_frames_never_request_a_resync() {
+        let mut tracker = SequenceTracker::default();
+        assert!(!observe(
+            &mut tracker,
+            &sequenced_frame(fb::SnapshotPayload::snapshot, 0)
+        ));
+        assert!(!observe(&mut tracker, &encoded_delta_frame()));
+        assert!(!observe(
+            &mut tracker,
+            &sequenced_frame(fb::SnapshotPayload::delta, 3)
+        ));
+    }
 
-    Some(dict)
+    #[test]
+    fn empty_frame_is_distinguished() {
+        assert_eq!(parse_envelope(&[]).unwrap_err(), FrameDecodeError::Empty);
+    }
 }
@@ -141,6 +141,18 @@ pub(crate) fn voice_medium_to_array(
     array
 }
 
+/// The in-world calendar: `season` is 0..4 from the year's start, `day_in_year` the turn within
+/// the year, and `phase` the fraction of the year elapsed (what the seasonal food weights run on).
+pub(crate) fn calendar_to_dict(calendar: fb::CalendarState<'_>) -> VarDictionary {
+    let mut dict = VarDictionary::new();
+    let _ = dict.insert("year", calendar.year() as i64);
+    let _ = dict.insert("season", calendar.season() as i64);
+    let _ = dict.insert("day_in_year", calendar.dayInYear() as i64);
+    let _ = dict.insert("ticks_per_year", calendar.ticksPerYear() as i64);
+    let _ = dict.insert("phase", calendar.phase() as f64);
+    dict
+}
+
 /// The Telling (docs/plan_the_telling.md): one register's rendering of a player-visible line.
 /// `register` is a FREE-FORM string by design — a new voice register needs no schema change — so
 /// the decoder never enumerates them and the client builds its toggle from what is present.
@@ -264,11 +276,21 @@ pub(crate) fn command_events_to_array(
         if let Some(detail) = event.detail() {
             let _ = dict.insert("detail", detail);
         }
+        let _ = dict.insert("severity", event_severity_to_str(event.severity()));
         array.push(&dict.to_variant());
     }
     array
 }
 
+fn event_severity_to_str(severity: fb::EventSeverity) -> &'static str {
+    match severity {
+        fb::EventSeverity::Notable => "notable",
+        fb::EventSeverity::Alert => "alert",
+        fb::EventSeverity::Major => "major",
+        _ => "routine",
+    }
+}
+
 pub(crate) fn victory_state_to_dict(state: fb::VictoryState<'_>) -> VarDictionary {
     let mut dict = VarDictionary::new();
     let mut modes_array = VarArray::new();
@@ -301,6 +323,16 @@ pub(crate) fn victory_state_to_dict(state: fb::VictoryState<'_>) -> VarDictionar
             let _ = entry.insert("threshold", f64::from(threshold));
             let _ = entry.insert("achieved", mode.achieved());
             let _ = entry.insert("progress_pct", f64::from(progress_pct));
+            let mut leaderboard = VarArray::new();
+            if let Some(slices) = mode.perFactionProgress() {
+                for slice in slices {
+                    let mut row = VarDictionary::new();
+                    let _ = row.insert("faction", i64::from(slice.faction()));
+                    let _ = row.insert("progress", f64::from(slice.progress()));
+                    leaderboard.push(&row.to_variant());
+                }
+            }
+            let _ = entry.insert("per_faction_progress", leaderboard);
             modes_array.push(&entry.to_variant());
 
             if let Some(target) = winner_mode_id.as_ref() {
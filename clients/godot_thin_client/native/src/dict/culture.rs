@@ -3,6 +3,7 @@
 
 use godot::prelude::*;
 use shadow_scale_flatbuffers::shadow_scale::sim as fb;
+use sim_runtime::CultureOwnerRef;
 
 use crate::dict::fixed64_to_f64;
 use crate::dict::population::audience_generations_to_array;
@@ -60,6 +61,7 @@ fn sentiment_driver_category_label(category: fb::SentimentDriverCategory) -> &'s
         fb::SentimentDriverCategory::Policy => "Policy",
         fb::SentimentDriverCategory::Incident => "Incident",
         fb::SentimentDriverCategory::Influencer => "Influencer",
+        fb::SentimentDriverCategory::Generation => "Generation",
         _ => "Unknown",
     }
 }
@@ -69,10 +71,25 @@ fn sentiment_axis_to_dict(axis: fb::SentimentAxisTelemetry<'_>) -> VarDictionary
     let _ = dict.insert("policy", fixed64_to_f64(axis.policy()));
     let _ = dict.insert("incidents", fixed64_to_f64(axis.incidents()));
     let _ = dict.insert("influencers", fixed64_to_f64(axis.influencers()));
+    let _ = dict.insert("generations", fixed64_to_f64(axis.generations()));
     let _ = dict.insert("total", fixed64_to_f64(axis.total()));
+    // `drivers` account for `total`; the generation lean is reported beside it, broken down in
+    // `generation_drivers`.
+    let _ = dict.insert("drivers", &sentiment_drivers_to_array(axis.drivers()));
+    let _ = dict.insert(
+        "generation_drivers",
+        &sentiment_drivers_to_array(axis.generationDrivers()),
+    );
+    dict
+}
 
+fn sentiment_drivers_to_array(
+    list: Option<
+        flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<fb::SentimentDriverState<'_>>>,
+    >,
+) -> VarArray {
     let mut drivers = VarArray::new();
-    if let Some(list) = axis.drivers() {
+    if let Some(list) = list {
         for driver in list {
             let mut driver_dict = VarDictionary::new();
             let _ = driver_dict.insert(
@@ -86,8 +103,7 @@ fn sentiment_axis_to_dict(axis: fb::SentimentAxisTelemetry<'_>) -> VarDictionary
             drivers.push(&variant);
         }
     }
-    let _ = dict.insert("drivers", &drivers);
-    dict
+    drivers
 }
 
 pub(crate) fn sentiment_to_dict(sentiment: fb::SentimentTelemetryState<'_>) -> VarDictionary {
@@ -202,6 +218,7 @@ fn influencer_to_dict(state: fb::InfluentialIndividualState<'_>) -> VarDictionar
     let _ = dict.insert("lifecycle", influence_lifecycle_label(state.lifecycle()));
     let _ = dict.insert("coherence", fixed64_to_f64(state.coherence()));
     let _ = dict.insert("ticks_in_status", state.ticksInStatus() as i64);
+    let _ = dict.insert("ticks_below_dormancy", state.ticksBelowDormancy() as i64);
     let audience = audience_generations_to_array(state.audienceGenerations());
     let _ = dict.insert("audience_generations", &audience);
     let _ = dict.insert("support_popular", fixed64_to_f64(state.supportPopular()));
@@ -282,10 +299,7 @@ fn culture_layer_to_dict(layer: fb::CultureLayerState<'_>) -> VarDictionary {
     let _ = dict.insert("id", id as i64);
     let _ = dict.insert("scope", culture_scope_to_key(scope));
     let _ = dict.insert("scope_label", scope_label);
-    let _ = dict.insert("owner", format!("{owner:016X}"));
-    if owner <= i64::MAX as u64 {
-        let _ = dict.insert("owner_value", owner as i64);
-    }
+    insert_culture_owner(&mut dict, owner);
     let _ = dict.insert("parent", parent as i64);
     let _ = dict.insert("divergence", fixed64_to_f64(baseline));
     let _ = dict.insert("soft_threshold", fixed64_to_f64(soft));
@@ -328,9 +342,19 @@ fn culture_tension_to_dict(state: fb::CultureTensionState<'_>) -> VarDictionary
     let _ = dict.insert("kind_label", culture_tension_to_label(kind));
     let _ = dict.insert("severity", fixed64_to_f64(state.severity()));
     let _ = dict.insert("timer", state.timer() as i64);
+    insert_culture_owner(&mut dict, state.owner());
     dict
 }
 
+/// Decoded `owner_kind` / `owner_id` for panels, plus the packed `owner` hex for debugging.
+/// Tile ids are entity bits; they stay below `i64::MAX` because the top bit is the province tag.
+fn insert_culture_owner(dict: &mut VarDictionary, owner: u64) {
+    let decoded = CultureOwnerRef::decode(owner);
+    let _ = dict.insert("owner", format!("{owner:016X}"));
+    let _ = dict.insert("owner_kind", decoded.kind_key());
+    let _ = dict.insert("owner_id", decoded.id() as i64);
+}
+
 pub(crate) fn culture_layers_to_array(
     list: flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<fb::CultureLayerState<'_>>>,
 ) -> VarArray {
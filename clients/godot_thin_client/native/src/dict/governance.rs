@@ -49,10 +49,36 @@ pub(crate) fn corruption_to_dict(ledger: fb::CorruptionLedger<'_>) -> VarDiction
     dict
 }
 
+/// The pairwise diplomacy leverage matrix: one `{faction_a, faction_b, leverage}` per
+/// non-neutral pair, `leverage` being what `faction_a` holds over `faction_b`.
+pub(crate) fn diplomacy_leverage_to_array(
+    list: flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<fb::DiplomacyLeverageState<'_>>>,
+) -> VarArray {
+    let mut array = VarArray::new();
+    for pair in list {
+        let mut dict = VarDictionary::new();
+        let _ = dict.insert("faction_a", pair.factionA() as i64);
+        let _ = dict.insert("faction_b", pair.factionB() as i64);
+        let _ = dict.insert("leverage", pair.leverage());
+        array.push(&dict.to_variant());
+    }
+    array
+}
+
+fn power_node_kind_to_str(kind: fb::PowerNodeKind) -> &'static str {
+    match kind {
+        fb::PowerNodeKind::Generator => "generator",
+        fb::PowerNodeKind::Storage => "storage",
+        fb::PowerNodeKind::Consumer => "consumer",
+        _ => "hybrid",
+    }
+}
+
 fn power_node_to_dict(node: fb::PowerNodeState<'_>) -> VarDictionary {
     let mut dict = VarDictionary::new();
     let _ = dict.insert("entity", node.entity() as i64);
     let _ = dict.insert("node_id", node.nodeId() as i64);
+    let _ = dict.insert("kind", power_node_kind_to_str(node.kind()));
 
     let generation_raw = node.generation();
     let demand_raw = node.demand();
@@ -80,6 +106,9 @@ fn power_node_to_dict(node: fb::PowerNodeState<'_>) -> VarDictionary {
     let _ = dict.insert("deficit", fixed64_to_f64(deficit_raw));
     let _ = dict.insert("deficit_raw", deficit_raw);
     let _ = dict.insert("incident_count", node.incidentCount() as i64);
+    let _ = dict.insert("priority", node.priority() as i64);
+    let _ = dict.insert("shed", fixed64_to_f64(node.shed()));
+    let _ = dict.insert("shed_raw", node.shed());
 
     dict
 }
@@ -261,6 +290,7 @@ pub(crate) fn crisis_overlay_to_dict(overlay: fb::CrisisOverlayState<'_>) -> Var
                 let _ = annotation.insert("label", label);
             }
             let _ = annotation.insert("severity", crisis_severity_band_to_str(entry.severity()));
+            let _ = annotation.insert("crisis_id", entry.crisisId() as i64);
             if let Some(path) = entry.path() {
                 let mut packed = PackedInt32Array::new();
                 packed.resize(path.len());
@@ -276,6 +306,20 @@ pub(crate) fn crisis_overlay_to_dict(overlay: fb::CrisisOverlayState<'_>) -> Var
         }
     }
     let _ = dict.insert("annotations", &annotations);
+
+    let mut crises = VarArray::new();
+    if let Some(entries) = overlay.crises() {
+        for entry in entries {
+            let mut crisis = VarDictionary::new();
+            let _ = crisis.insert("crisis_id", entry.crisisId() as i64);
+            let _ = crisis.insert("archetype", entry.archetype().unwrap_or_default());
+            let _ = crisis.insert("faction", entry.faction() as i64);
+            let _ = crisis.insert("chain_depth", entry.chainDepth() as i64);
+            let _ = crisis.insert("escalated", entry.escalated());
+            crises.push(&crisis.to_variant());
+        }
+    }
+    let _ = dict.insert("crises", &crises);
     dict
 }
 
@@ -285,6 +329,7 @@ pub(crate) fn crisis_annotation_to_dict(record: &CrisisAnnotationRecord) -> VarD
         let _ = dict.insert("label", label.clone());
     }
     let _ = dict.insert("severity", crisis_severity_band_to_str(record.severity));
+    let _ = dict.insert("crisis_id", record.crisis_id as i64);
     if record.path.is_empty() {
         let _ = dict.insert("path", &PackedInt32Array::new());
     } else {
@@ -1,4 +1,7 @@
-//! `knowledge` section -- discovery progress, great discoveries, and discovered sites.
+//! `knowledge` section -- discovery progress, great discoveries, discovered sites, and espionage
+//! agents.
+
+use std::collections::BTreeMap;
 
 use flatbuffers::{ForwardsUOffset, Vector};
 use godot::prelude::*;
@@ -40,6 +43,26 @@ pub(crate) fn discovered_sites_to_array(
     array
 }
 
+pub(crate) fn espionage_agents_to_array(
+    agents: Vector<'_, ForwardsUOffset<fb::EspionageAgentState<'_>>>,
+) -> VarArray {
+    let mut array = VarArray::new();
+    for agent in agents {
+        let mut dict = VarDictionary::new();
+        let _ = dict.insert("faction", agent.faction() as i64);
+        let _ = dict.insert("handle", agent.handle() as i64);
+        if let Some(template_id) = agent.templateId() {
+            let _ = dict.insert("template_id", template_id);
+        }
+        if let Some(name) = agent.name() {
+            let _ = dict.insert("name", name);
+        }
+        let _ = dict.insert("experience", agent.experience() as i64);
+        array.push(&dict.to_variant());
+    }
+    array
+}
+
 fn knowledge_field_label(field: fb::KnowledgeField) -> &'static str {
     match field {
         fb::KnowledgeField::Physics => "Physics",
@@ -78,6 +101,29 @@ fn discovery_progress_entry_to_dict(entry: fb::DiscoveryProgressEntry<'_>) -> Va
     dict
 }
 
+pub(crate) fn knowledge_early_warnings_to_array(
+    states: Vector<'_, ForwardsUOffset<fb::KnowledgeEarlyWarningState<'_>>>,
+) -> VarArray {
+    let mut array = VarArray::new();
+    for state in states {
+        let mut dict = VarDictionary::new();
+        let _ = dict.insert("faction", state.faction() as i64);
+        let mut entries = VarArray::new();
+        if let Some(list) = state.entries() {
+            for entry in list {
+                let mut entry_dict = VarDictionary::new();
+                let _ = entry_dict.insert("discovery_id", entry.discoveryId() as i64);
+                let _ = entry_dict.insert("time_to_cascade", entry.timeToCascade() as i64);
+                let _ = entry_dict.insert("progress_percent", entry.progressPercent() as i64);
+                entries.push(&entry_dict.to_variant());
+            }
+        }
+        let _ = dict.insert("entries", &entries);
+        array.push(&dict.to_variant());
+    }
+    array
+}
+
 pub(crate) fn discovery_progress_to_array(
     list: flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<fb::DiscoveryProgressEntry<'_>>>,
 ) -> VarArray {
@@ -113,11 +159,58 @@ pub(crate) fn great_discovery_states_to_array(
     array
 }
 
+/// One constellation edge off the wire: requirement `from` is a prerequisite of `to`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct RequirementEdge {
+    pub from: u32,
+    pub to: u32,
+    pub weight: f32,
+}
+
+/// A definition's constellation edges. Plain Rust over the FlatBuffer (no Godot types) so the graph
+/// decode is testable outside a live engine.
+pub(crate) fn requirement_edges(
+    definition: fb::GreatDiscoveryDefinition<'_>,
+) -> Vec<RequirementEdge> {
+    definition
+        .edges()
+        .map(|edges| {
+            edges
+                .iter()
+                .map(|edge| RequirementEdge {
+                    from: edge.from(),
+                    to: edge.to(),
+                    weight: edge.weight(),
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Requirement id → the requirement ids it builds on, the per-node view of `edges`.
+pub(crate) fn requirement_builds_on(edges: &[RequirementEdge]) -> BTreeMap<u32, Vec<u32>> {
+    let mut builds_on: BTreeMap<u32, Vec<u32>> = BTreeMap::new();
+    for edge in edges {
+        builds_on.entry(edge.to).or_default().push(edge.from);
+    }
+    builds_on
+}
+
 fn great_discovery_requirement_definition_to_dict(
     req: fb::GreatDiscoveryRequirementDefinition<'_>,
+    builds_on: &BTreeMap<u32, Vec<u32>>,
 ) -> VarDictionary {
     let mut dict = VarDictionary::new();
     let _ = dict.insert("discovery_id", req.discoveryId() as i64);
+    let mut parents = PackedInt64Array::new();
+    if let Some(ids) = builds_on.get(&req.discoveryId()) {
+        parents.resize(ids.len());
+        let slice = parents.as_mut_slice();
+        for (index, &id) in ids.iter().enumerate() {
+            slice[index] = id as i64;
+        }
+    }
+    let _ = dict.insert("builds_on", &parents);
     let _ = dict.insert("weight", f64::from(req.weight()));
     let _ = dict.insert("minimum_progress", f64::from(req.minimumProgress()));
     if let Some(name) = req.name() {
@@ -134,10 +227,11 @@ fn great_discovery_requirements_to_array(
         '_,
         flatbuffers::ForwardsUOffset<fb::GreatDiscoveryRequirementDefinition<'_>>,
     >,
+    builds_on: &BTreeMap<u32, Vec<u32>>,
 ) -> VarArray {
     let mut array = VarArray::new();
     for req in list {
-        let dict = great_discovery_requirement_definition_to_dict(req);
+        let dict = great_discovery_requirement_definition_to_dict(req, builds_on);
         array.push(&dict.to_variant());
     }
     array
@@ -187,12 +281,23 @@ fn great_discovery_definition_to_dict(
     if let Some(profile) = definition.leakProfile() {
         let _ = dict.insert("leak_profile", &GString::from(profile));
     }
+    let edges = requirement_edges(definition);
+    let builds_on = requirement_builds_on(&edges);
     if let Some(requirements) = definition.requirements() {
-        let array = great_discovery_requirements_to_array(requirements);
+        let array = great_discovery_requirements_to_array(requirements, &builds_on);
         let _ = dict.insert("requirements", &array);
     } else {
         let _ = dict.insert("requirements", &VarArray::new());
     }
+    let mut edge_array = VarArray::new();
+    for edge in &edges {
+        let mut edge_dict = VarDictionary::new();
+        let _ = edge_dict.insert("from", edge.from as i64);
+        let _ = edge_dict.insert("to", edge.to as i64);
+        let _ = edge_dict.insert("weight", f64::from(edge.weight));
+        edge_array.push(&edge_dict.to_variant());
+    }
+    let _ = dict.insert("edges", &edge_array);
     dict
 }
 
@@ -219,6 +324,13 @@ fn great_discovery_progress_state_to_dict(
     let _ = dict.insert("observation_deficit", state.observationDeficit() as i64);
     let _ = dict.insert("eta_ticks", state.etaTicks() as i64);
     let _ = dict.insert("covert", state.covert());
+    if let Some(delta) = state.progressDelta() {
+        let mut delta_dict = VarDictionary::new();
+        let _ = delta_dict.insert("progress_raw", delta.progress());
+        let _ = delta_dict.insert("progress", fixed64_to_f64(delta.progress()));
+        let _ = delta_dict.insert("observation_deficit", delta.observationDeficit() as i64);
+        let _ = dict.insert("progress_delta", &delta_dict);
+    }
     dict
 }
 
@@ -243,5 +355,96 @@ pub(crate) fn great_discovery_telemetry_to_dict(
     let _ = dict.insert("total_resolved", state.totalResolved() as i64);
     let _ = dict.insert("pending_candidates", state.pendingCandidates() as i64);
     let _ = dict.insert("active_constellations", state.activeConstellations() as i64);
+    let mut audit = VarArray::new();
+    if let Some(entries) = state.effectAudit() {
+        for entry in entries {
+            let mut entry_dict = VarDictionary::new();
+            let _ = entry_dict.insert("tick", entry.tick() as i64);
+            let _ = entry_dict.insert("discovery", entry.discovery() as i64);
+            let _ = entry_dict.insert("faction", entry.faction() as i64);
+            let _ = entry_dict.insert("effect", entry.effect() as i64);
+            if let Some(target) = entry.target() {
+                let _ = entry_dict.insert("target", target);
+            }
+            let _ = entry_dict.insert("target_id", entry.targetId() as i64);
+            let _ = entry_dict.insert("magnitude", fixed64_to_f64(entry.magnitude()));
+            let _ = entry_dict.insert("magnitude_raw", entry.magnitude());
+            audit.push(&entry_dict.to_variant());
+        }
+    }
+    let _ = dict.insert("effect_audit", &audit);
     dict
 }
+
+#[cfg(test)]
+mod constellation_graph_tests {
+    use super::*;
+    use sim_runtime::{
+        encode_snapshot_flatbuffer, GreatDiscoveryDefinitionState,
+        GreatDiscoveryRequirementEdgeState, GreatDiscoveryRequirementState, WorldSnapshot,
+    };
+
+    fn requirement(discovery: u32, weight: f32) -> GreatDiscoveryRequirementState {
+        GreatDiscoveryRequirementState {
+            discovery,
+            weight,
+            ..Default::default()
+        }
+    }
+
+    /// The definition dict is built from `requirement_edges` / `requirement_builds_on`; the Godot
+    /// `Dictionary` itself cannot be constructed outside a live engine. Encode a diamond through the
+    /// real snapshot encoder and rebuild the graph from the wire.
+    #[test]
+    fn constellation_graph_rebuilds_from_the_wire() {
+        let edge = |from, to, weight| GreatDiscoveryRequirementEdgeState { from, to, weight };
+        let snapshot = WorldSnapshot {
+            great_discovery_definitions: vec![GreatDiscoveryDefinitionState {
+                id: 7,
+                name: "Diamond".to_string(),
+                requirements: vec![
+                    requirement(1, 2.0),
+                    requirement(2, 1.0),
+                    requirement(3, 1.0),
+                    requirement(4, 1.0),
+                ],
+                edges: vec![
+                    edge(1, 2, 0.4),
+                    edge(1, 3, 0.4),
+                    edge(2, 4, 0.2),
+                    edge(3, 4, 0.2),
+                ],
+                ..Default::default()
+            }],
+            ..WorldSnapshot::default()
+        };
+
+        let bytes = encode_snapshot_flatbuffer(&snapshot);
+        let definition = fb::root_as_envelope(&bytes)
+            .expect("snapshot decodes")
+            .payload_as_snapshot()
+            .expect("snapshot payload")
+            .knowledge()
+            .expect("knowledge section present")
+            .greatDiscoveryDefinitions()
+            .expect("definitions present")
+            .get(0);
+
+        let edges = requirement_edges(definition);
+        assert_eq!(edges.len(), 4);
+        assert_eq!(
+            edges[0],
+            RequirementEdge {
+                from: 1,
+                to: 2,
+                weight: 0.4
+            }
+        );
+
+        let builds_on = requirement_builds_on(&edges);
+        assert_eq!(builds_on.get(&1), None, "the root builds on nothing");
+        assert_eq!(builds_on[&2], vec![1]);
+        assert_eq!(builds_on[&3], vec![1]);
+        assert_eq!(builds_on[&4], vec![2, 3]);
+    }
+}
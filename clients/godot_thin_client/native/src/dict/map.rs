@@ -127,6 +127,12 @@ fn tile_to_dict(tile: fb::TileState<'_>) -> VarDictionary {
     // hex also exits into the sea / delta it drains into (open water carries no channel, so that bit is
     // not mirrored back). MapView packs it into its own R8 river-channel splatmap.
     let _ = dict.insert("river_channel", tile.riverChannel() as i64);
+    // This turn's river flow (largest flow volume of any river on the hex; 0 off-river). Swings with
+    // basin moisture and season, so a floodplain overlay can pulse with it.
+    let _ = dict.insert("river_flow", tile.riverFlow() as f64);
+    // This turn's lake depth (water over the hex at its lake's current level; 0 off-lake or dried
+    // out). Lakes fill closed basins up to their spill point and recede in dry turns.
+    let _ = dict.insert("lake_depth", tile.lakeDepth() as f64);
     let _ = dict.insert("culture_layer", tile.cultureLayer() as i64);
     let _ = dict.insert("mountain_kind", i64::from(tile.mountainKind().0));
     let _ = dict.insert("mountain_relief", tile.mountainRelief());
@@ -5,7 +5,7 @@ use godot::prelude::*;
 use shadow_scale_flatbuffers::shadow_scale::sim as fb;
 
 use crate::dict::economy::fragment_to_dict;
-use crate::dict::fixed64_to_f64;
+use crate::dict::{fixed64_to_f64, u32_vector_to_packed_int32};
 
 pub(crate) fn demographics_to_array(
     states: Vector<'_, ForwardsUOffset<fb::PopulationDemographicsState<'_>>>,
@@ -22,6 +22,24 @@ pub(crate) fn demographics_to_array(
     array
 }
 
+pub(crate) fn morale_summary_to_array(
+    states: Vector<'_, ForwardsUOffset<fb::MoraleSummaryState<'_>>>,
+) -> VarArray {
+    let mut array = VarArray::new();
+    for state in states {
+        let mut dict = VarDictionary::new();
+        let _ = dict.insert("faction", state.faction() as i64);
+        let _ = dict.insert("cohorts", state.cohorts() as i64);
+        let _ = dict.insert("min", fixed64_to_f64(state.min()));
+        let _ = dict.insert("mean", fixed64_to_f64(state.mean()));
+        let _ = dict.insert("max", fixed64_to_f64(state.max()));
+        let _ = dict.insert("threshold", fixed64_to_f64(state.threshold()));
+        let _ = dict.insert("below_threshold", state.belowThreshold() as f64);
+        array.push(&dict.to_variant());
+    }
+    array
+}
+
 pub(crate) fn audience_generations_to_array(
     generations: Option<flatbuffers::Vector<'_, u16>>,
 ) -> PackedInt32Array {
@@ -56,6 +74,7 @@ struct CohortScalars {
     morale_terrain: f64,
     morale_climate: f64,
     morale_unrest: f64,
+    morale_diet: f64,
     age_children: f64,
     age_working: f64,
     age_elders: f64,
@@ -72,6 +91,7 @@ fn cohort_scalars(cohort: fb::PopulationCohortState<'_>) -> CohortScalars {
         morale_terrain: fixed64_to_f64(cohort.moraleTerrain()),
         morale_climate: fixed64_to_f64(cohort.moraleClimate()),
         morale_unrest: fixed64_to_f64(cohort.moraleUnrest()),
+        morale_diet: fixed64_to_f64(cohort.moraleDiet()),
         age_children: fixed64_to_f64(cohort.children()),
         age_working: fixed64_to_f64(cohort.working()),
         age_elders: fixed64_to_f64(cohort.elders()),
@@ -94,12 +114,12 @@ fn population_to_dict(cohort: fb::PopulationCohortState<'_>) -> VarDictionary {
     let scalars = cohort_scalars(cohort);
     let _ = dict.insert("morale", scalars.morale);
     // Signed per-turn morale trend + the dominant negative driver when falling
-    // (0=None, 1=Terrain, 2=Cold, 3=Unrest). A rehydrated save reports 0/None for
+    // (0=None, 1=Terrain, 2=Cold, 3=Unrest, 4=Diet). A rehydrated save reports 0/None for
     // one turn (the sim doesn't persist them) — the HUD handles that gracefully.
     let _ = dict.insert("morale_delta", scalars.morale_delta);
     let _ = dict.insert("morale_cause", i64::from(cohort.moraleCause()));
     // Civilization Wellbeing (docs/plan_civ_wellbeing.md). Productivity + discontent +
-    // migration counters + the four signed Layer-1 morale contributions (their sum IS
+    // migration counters + the five signed Layer-1 morale contributions (their sum IS
     // morale_delta) that drive the itemized morale breakdown in the band drawer.
     let _ = dict.insert("output_multiplier", scalars.output_multiplier);
     let _ = dict.insert("discontent_fraction", scalars.discontent_fraction);
@@ -111,6 +131,7 @@ fn population_to_dict(cohort: fb::PopulationCohortState<'_>) -> VarDictionary {
     let _ = dict.insert("morale_terrain", scalars.morale_terrain);
     let _ = dict.insert("morale_climate", scalars.morale_climate);
     let _ = dict.insert("morale_unrest", scalars.morale_unrest);
+    let _ = dict.insert("morale_diet", scalars.morale_diet);
     let _ = dict.insert("generation", cohort.generation() as i64);
     let _ = dict.insert("faction", cohort.faction() as i64);
     let _ = dict.insert("turns_of_food", cohort.turnsOfFood() as f64);
@@ -334,6 +355,22 @@ fn population_to_dict(cohort: fb::PopulationCohortState<'_>) -> VarDictionary {
         f64::from(cohort.expeditionProjectedDelivery()),
     );
     let _ = dict.insert("expedition_recurring", cohort.expeditionRecurring());
+    // Scout-party reveal preview: the sight radius and the tiles it will observe on reaching its
+    // travel target (zipped x/y, LOS applied server-side) — the same tiles it reports home on
+    // return, so the map can outline them before the party arrives. 0 / empty for hunt parties
+    // and normal bands.
+    let _ = dict.insert(
+        "expedition_reveal_radius",
+        i64::from(cohort.expeditionRevealRadius()),
+    );
+    let _ = dict.insert(
+        "expedition_reveal_footprint_x",
+        &u32_vector_to_packed_int32(cohort.expeditionRevealFootprintX()),
+    );
+    let _ = dict.insert(
+        "expedition_reveal_footprint_y",
+        &u32_vector_to_packed_int32(cohort.expeditionRevealFootprintY()),
+    );
     // Hard cap on party size the server enforces (from the expedition config, default 8). The
     // outfit stepper clamps its max to min(idle_workers, this) so the player can't dial an
     // over-cap party.
@@ -462,8 +499,8 @@ mod cohort_decode_tests {
                 working: 16_537_500,
                 elders: 4_642_500,
                 morale: 820_000,
-                // == the four Layer-1 contributions below, which the test asserts.
-                moraleDelta: -11_000,
+                // == the five Layer-1 contributions below, which the test asserts.
+                moraleDelta: -15_000,
                 outputMultiplier: 1_000_000,
                 discontentFraction: 250_000,
                 grievance: 40_000,
@@ -471,6 +508,7 @@ mod cohort_decode_tests {
                 moraleTerrain: -26_000,
                 moraleClimate: -6_000,
                 moraleUnrest: 11_000,
+                moraleDiet: -4_000,
                 ..Default::default()
             },
         );
@@ -498,15 +536,16 @@ mod cohort_decode_tests {
         );
 
         assert!((scalars.morale - 0.82).abs() < 1e-9);
-        assert!((scalars.morale_delta - -0.011).abs() < 1e-9);
+        assert!((scalars.morale_delta - -0.015).abs() < 1e-9);
         assert!((scalars.output_multiplier - 1.0).abs() < 1e-9);
         assert!((scalars.discontent_fraction - 0.25).abs() < 1e-9);
         assert!((scalars.grievance - 0.04).abs() < 1e-9);
-        // The four signed Layer-1 contributions must sum to the reported morale trend.
+        // The five signed Layer-1 contributions must sum to the reported morale trend.
         let contributions = scalars.morale_settling
             + scalars.morale_terrain
             + scalars.morale_climate
-            + scalars.morale_unrest;
+            + scalars.morale_unrest
+            + scalars.morale_diet;
         assert!(
             (contributions - scalars.morale_delta).abs() < 1e-9,
             "contributions {contributions} != morale_delta {}",
@@ -4,17 +4,22 @@ use rquickjs::{Context, Ctx, Exception, Function, Runtime, Value};
 use serde::{Deserialize, Serialize};
 use serde_json::{json, Map as JsonMap, Value as JsonValue};
 use std::collections::{HashMap, HashSet};
-use std::io::Write;
+use std::io::{Read, Write};
 use std::net::TcpStream;
 use std::sync::atomic::{AtomicBool, AtomicI64, AtomicU64, Ordering};
 use std::sync::mpsc::{self, Receiver, Sender, TryRecvError};
-use std::sync::{Arc, Condvar, Mutex};
+use std::sync::{Arc, Condvar, Mutex, OnceLock};
 use std::thread;
 use std::time::Duration;
 use thiserror::Error;
 
-use sim_runtime::scripting::{ScriptManifest, ScriptManifestRef, SimScriptState};
-use sim_runtime::{parse_command_line, CommandEncodeError, CommandEnvelope, CommandPayload};
+use sim_runtime::scripting::{
+    capability_registry, topic_matches, ScriptManifest, ScriptManifestRef, SimScriptState,
+};
+use sim_runtime::{
+    parse_command_line, CommandEncodeError, CommandEnvelope, CommandPayload, CommandResponse,
+    COMMAND_PROTOCOL_VERSION,
+};
 
 #[derive(Debug, Error)]
 pub enum ScriptError {
@@ -30,6 +35,17 @@ struct CommandEndpoint {
     port: u16,
 }
 
+/// A script command waiting for the command worker, which reports its outcome back to the
+/// issuing script as a `commands.issue.result` event.
+struct ScriptCommandRequest {
+    shared: Arc<ScriptSharedState>,
+    endpoint: CommandEndpoint,
+    envelope: CommandEnvelope,
+    line: Option<String>,
+}
+
+static SCRIPT_COMMAND_SENDER: OnceLock<Sender<ScriptCommandRequest>> = OnceLock::new();
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -102,6 +118,101 @@ mod tests {
 
         manager.shutdown(script_id);
     }
+
+    #[test]
+    fn wildcard_subscription_receives_only_matching_broadcasts() {
+        let manager = ScriptManager::new();
+        let manifest = ScriptManifest {
+            id: "demo.crisis_watch".to_string(),
+            version: "0.1.0".to_string(),
+            entry: "./script.js".to_string(),
+            capabilities: vec![
+                "telemetry.subscribe".to_string(),
+                "storage.session".to_string(),
+            ],
+            subscriptions: vec!["crisis.*".to_string()],
+            description: None,
+            author: None,
+            config: None,
+            manifest_path: None,
+        };
+
+        let source = r#"
+            host.register({ onEvent: "onEvent" });
+
+            function onEvent(topic, payload) {
+              const seen = host.sessionGet("topics") ?? [];
+              seen.push(topic);
+              host.sessionSet("topics", seen);
+            }
+        "#
+        .to_string();
+
+        let script_id = manager
+            .spawn_script(manifest, source)
+            .expect("script should spawn");
+        std::thread::sleep(Duration::from_millis(20));
+
+        manager.broadcast_event("crisis.spawned", json!({}));
+        manager.broadcast_event("world.snapshot", json!({}));
+        manager.broadcast_event("crisis.resolved", json!({}));
+        manager.broadcast_event("crisisx.spawned", json!({}));
+        std::thread::sleep(Duration::from_millis(20));
+
+        let session = manager
+            .snapshot_session(script_id)
+            .expect("session snapshot");
+        assert_eq!(
+            session.get("topics"),
+            Some(&json!(["crisis.spawned", "crisis.resolved"]))
+        );
+
+        manager.shutdown(script_id);
+    }
+
+    #[test]
+    fn script_commands_do_not_wait_for_the_server() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").expect("bind");
+        let port = listener.local_addr().expect("addr").port();
+        let (release_tx, release_rx) = mpsc::channel::<()>();
+        // Accepts the command and holds the connection open without answering until released.
+        let server = thread::spawn(move || {
+            let (_stream, _) = listener.accept().expect("accept");
+            let _ = release_rx.recv();
+        });
+
+        let (responses_tx, responses_rx) = mpsc::channel();
+        let shared = ScriptSharedState::new(
+            1,
+            HashSet::new(),
+            responses_tx,
+            Arc::new(Mutex::new(Some(CommandEndpoint {
+                host: "127.0.0.1".to_string(),
+                port,
+            }))),
+        );
+        dispatch_proto_command(
+            &shared,
+            CommandPayload::Turn { steps: 1 },
+            Some(7),
+            Some("turn 1".to_string()),
+        );
+        assert!(matches!(responses_rx.try_recv(), Err(TryRecvError::Empty)));
+
+        release_tx.send(()).expect("release");
+        server.join().expect("server");
+        match responses_rx
+            .recv_timeout(Duration::from_secs(5))
+            .expect("result event")
+        {
+            ScriptResponse::Event { event, payload } => {
+                assert_eq!(event, "commands.issue.result");
+                assert_eq!(payload["ok"], json!(false));
+                assert_eq!(payload["correlation_id"], json!(7));
+            }
+            other => panic!("unexpected response {other:?}"),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -207,6 +318,29 @@ impl ScriptSharedState {
         })
     }
 
+    fn is_subscribed(&self, topic: &str) -> bool {
+        let subs = self.subscriptions.lock().unwrap();
+        subs.iter().any(|pattern| topic_matches(topic, pattern))
+    }
+
+    /// Rejects subscription patterns no granted capability covers, mirroring manifest validation.
+    fn ensure_subscription_allowed(&self, pattern: &str) -> Result<(), ScriptError> {
+        let registry = capability_registry();
+        let covered = self
+            .capabilities
+            .iter()
+            .filter_map(|id| registry.get(id))
+            .any(|spec| spec.allows_subscription(pattern));
+        if covered {
+            Ok(())
+        } else {
+            Err(ScriptError::Runtime(format!(
+                "script {} subscription '{pattern}' not covered by declared capabilities",
+                self.id
+            )))
+        }
+    }
+
     fn ensure_capability(&self, required: &str) -> Result<(), ScriptError> {
         if self
             .capabilities
@@ -318,9 +452,14 @@ impl ScriptManager {
         Ok(id)
     }
 
+    /// Delivers `event` to every script with a matching subscription (exact topic or glob such
+    /// as `crisis.*`).
     pub fn broadcast_event(&self, event: &str, payload: JsonValue) {
         let guard = self.inner.scripts.lock().unwrap();
         for script in guard.values() {
+            if !script.shared.is_subscribed(event) {
+                continue;
+            }
             let _ = script.command_tx.send(ScriptCommand::Dispatch {
                 event: event.to_string(),
                 payload: payload.clone(),
@@ -913,6 +1052,7 @@ fn handle_host_request(
         "telemetry.subscribe" => {
             shared.ensure_capability("telemetry.subscribe")?;
             if let Some(topic) = payload.get("topic").and_then(|v| v.as_str()) {
+                shared.ensure_subscription_allowed(topic)?;
                 let mut subs = shared.subscriptions.lock().unwrap();
                 subs.insert(topic.to_string());
                 let _ = shared.responses_tx.send(ScriptResponse::Subscriptions {
@@ -1035,13 +1175,21 @@ fn dispatch_proto_command(
             payload: command_payload,
             correlation_id,
         };
-        match transmit_proto_command(&endpoint.host, endpoint.port, &envelope) {
-            Ok(_) => {
-                send_command_event(shared, true, line.clone(), correlation_id, None);
-            }
-            Err(err) => {
-                send_command_event(shared, false, line, correlation_id, Some(err));
-            }
+        // The round trip can wait out the response timeout, so it runs on the command worker
+        // rather than stalling this script's tick.
+        if let Err(mpsc::SendError(request)) = script_command_sender().send(ScriptCommandRequest {
+            shared: Arc::clone(shared),
+            endpoint,
+            envelope,
+            line,
+        }) {
+            send_command_event(
+                shared,
+                false,
+                request.line,
+                correlation_id,
+                Some("command worker unavailable".to_string()),
+            );
         }
     } else {
         send_command_event(
@@ -1054,6 +1202,36 @@ fn dispatch_proto_command(
     }
 }
 
+/// Carries script commands to the server one at a time, in the order they were issued.
+fn script_command_worker(receiver: Receiver<ScriptCommandRequest>) {
+    for request in receiver {
+        let ScriptCommandRequest {
+            shared,
+            endpoint,
+            envelope,
+            line,
+        } = request;
+        let correlation_id = envelope.correlation_id;
+        match transmit_proto_command(&endpoint.host, endpoint.port, &envelope) {
+            Ok(_) => send_command_event(&shared, true, line, correlation_id, None),
+            Err(err) => send_command_event(&shared, false, line, correlation_id, Some(err)),
+        }
+    }
+}
+
+fn script_command_sender() -> Sender<ScriptCommandRequest> {
+    SCRIPT_COMMAND_SENDER
+        .get_or_init(|| {
+            let (sender, receiver) = mpsc::channel::<ScriptCommandRequest>();
+            thread::Builder::new()
+                .name("script-command-worker".into())
+                .spawn(move || script_command_worker(receiver))
+                .expect("failed to spawn script command worker thread");
+            sender
+        })
+        .clone()
+}
+
 fn send_command_event(
     shared: &Arc<ScriptSharedState>,
     ok: bool,
@@ -1073,17 +1251,52 @@ fn send_command_event(
     });
 }
 
+/// How long `transmit_proto_command` waits for the server's acknowledgement frame.
+const COMMAND_RESPONSE_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Shared command token the client presents in its handshake, for servers configured with
+/// `command_token`.
+const COMMAND_TOKEN_ENV: &str = "SIM_COMMAND_TOKEN";
+
+/// Sends `envelope` and waits for the server's [`CommandResponse`]. This blocks for up to the
+/// connect time plus [`COMMAND_RESPONSE_TIMEOUT`] per frame, so callers run it on a worker
+/// thread, never the frame thread. Each connection opens with a
+/// handshake frame (protocol version plus the optional `SIM_COMMAND_TOKEN`), so a server that
+/// requires one admits the command and a version mismatch surfaces as the server's error. The
+/// response must echo the envelope's `correlation_id`; a rejected command or a mismatched id is an
+/// error.
 pub(crate) fn transmit_proto_command(
     host: &str,
     port: u16,
     envelope: &CommandEnvelope,
-) -> Result<(), String> {
-    let bytes = envelope
-        .encode_to_vec()
-        .map_err(|CommandEncodeError::Encode(err)| format!("encode error: {err}"))?;
+) -> Result<CommandResponse, String> {
     let addr = format!("{}:{}", host, port);
     let mut stream = TcpStream::connect(&addr).map_err(|err| format!("connect error: {err}"))?;
     let _ = stream.set_nodelay(true);
+    let _ = stream.set_read_timeout(Some(COMMAND_RESPONSE_TIMEOUT));
+
+    let handshake = CommandEnvelope {
+        payload: CommandPayload::Handshake {
+            protocol_version: COMMAND_PROTOCOL_VERSION,
+            token: std::env::var(COMMAND_TOKEN_ENV)
+                .ok()
+                .filter(|token| !token.is_empty()),
+        },
+        correlation_id: None,
+    };
+    exchange_command_frame(&mut stream, &handshake)
+        .map_err(|err| format!("handshake failed: {err}"))?;
+    exchange_command_frame(&mut stream, envelope)
+}
+
+/// Writes one length-prefixed envelope and reads the matching [`CommandResponse`].
+fn exchange_command_frame(
+    stream: &mut TcpStream,
+    envelope: &CommandEnvelope,
+) -> Result<CommandResponse, String> {
+    let bytes = envelope
+        .encode_to_vec()
+        .map_err(|CommandEncodeError::Encode(err)| format!("encode error: {err}"))?;
     stream
         .write_all(&(bytes.len() as u32).to_le_bytes())
         .map_err(|err| format!("length write error: {err}"))?;
@@ -1093,7 +1306,29 @@ pub(crate) fn transmit_proto_command(
     stream
         .flush()
         .map_err(|err| format!("flush error: {err}"))?;
-    Ok(())
+
+    let mut len_buf = [0u8; 4];
+    stream
+        .read_exact(&mut len_buf)
+        .map_err(|err| format!("response length read error: {err}"))?;
+    let mut frame = vec![0u8; u32::from_le_bytes(len_buf) as usize];
+    stream
+        .read_exact(&mut frame)
+        .map_err(|err| format!("response read error: {err}"))?;
+    let response =
+        CommandResponse::decode(&frame).map_err(|err| format!("response decode error: {err}"))?;
+    if response.correlation_id != envelope.correlation_id {
+        return Err(format!(
+            "response correlation id {:?} does not match request {:?}",
+            response.correlation_id, envelope.correlation_id
+        ));
+    }
+    if !response.accepted {
+        return Err(response
+            .error
+            .unwrap_or_else(|| "command rejected".to_string()));
+    }
+    Ok(response)
 }
 
 fn register_descriptor(shared: &ScriptSharedState, descriptor: &JsonValue) -> Result<(), String> {
@@ -1113,13 +1348,14 @@ fn register_descriptor(shared: &ScriptSharedState, descriptor: &JsonValue) -> Re
         shared
             .ensure_capability("telemetry.subscribe")
             .map_err(|err| err.to_string())?;
+        let topics: Vec<&str> = items.iter().filter_map(|v| v.as_str()).collect();
+        for topic in &topics {
+            shared
+                .ensure_subscription_allowed(topic)
+                .map_err(|err| err.to_string())?;
+        }
         let mut subs = shared.subscriptions.lock().unwrap();
-        subs.extend(
-            items
-                .iter()
-                .filter_map(|v| v.as_str())
-                .map(|s| s.to_string()),
-        );
+        subs.extend(topics.into_iter().map(|s| s.to_string()));
     }
     Ok(())
 }
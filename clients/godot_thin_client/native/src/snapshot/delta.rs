@@ -4,6 +4,7 @@
 
 use godot::prelude::*;
 use shadow_scale_flatbuffers::shadow_scale::sim as fb;
+use sim_runtime::decode_float_raster_samples;
 use std::collections::HashMap;
 
 use crate::dict::fixed64_to_f32;
@@ -15,6 +16,7 @@ pub(crate) struct CrisisAnnotationRecord {
     pub(crate) label: Option<String>,
     pub(crate) severity: fb::CrisisSeverityBand,
     pub(crate) path: Vec<i32>,
+    pub(crate) crisis_id: u64,
 }
 
 #[derive(Default)]
@@ -25,11 +27,17 @@ pub(crate) struct DeltaAggregator {
     pub(crate) wrap_horizontal: bool,
     pub(crate) server_build: String,
     pub(crate) world_epoch: u32,
+    pub(crate) config_hash: u64,
+    pub(crate) sequence: u64,
+    pub(crate) delta_part: u32,
+    pub(crate) delta_part_count: u32,
+    pub(crate) computed_channels: u32,
     tile_updates: HashMap<(u32, u32), f32>,
     terrain_width: u32,
     terrain_height: u32,
     terrain_types: Vec<u16>,
     terrain_tags: Vec<u16>,
+    terrain_palette_hash: Option<u64>,
     logistics_width: u32,
     logistics_height: u32,
     logistics_samples: Vec<f32>,
@@ -51,6 +59,9 @@ pub(crate) struct DeltaAggregator {
     military_width: u32,
     military_height: u32,
     military_samples: Vec<f32>,
+    population_density_width: u32,
+    population_density_height: u32,
+    population_density_samples: Vec<f32>,
     crisis_width: u32,
     crisis_height: u32,
     crisis_samples: Vec<f32>,
@@ -82,6 +93,7 @@ impl DeltaAggregator {
     pub(crate) fn apply_terrain_overlay(&mut self, overlay: fb::TerrainOverlay<'_>) {
         self.terrain_width = overlay.width();
         self.terrain_height = overlay.height();
+        self.terrain_palette_hash = Some(overlay.paletteHash());
         let count = (self.terrain_width as usize)
             .saturating_mul(self.terrain_height as usize)
             .max(1);
@@ -217,6 +229,23 @@ impl DeltaAggregator {
         }
     }
 
+    pub(crate) fn apply_population_density_raster(&mut self, raster: fb::ScalarRaster<'_>) {
+        self.population_density_width = raster.width();
+        self.population_density_height = raster.height();
+        let count = (self.population_density_width as usize)
+            .saturating_mul(self.population_density_height as usize)
+            .max(1);
+        self.population_density_samples.resize(count, 0.0);
+        if let Some(samples) = raster.samples() {
+            for (idx, value) in samples.iter().enumerate() {
+                if idx >= count {
+                    break;
+                }
+                self.population_density_samples[idx] = fixed64_to_f32(value);
+            }
+        }
+    }
+
     pub(crate) fn apply_crisis_overlay(&mut self, overlay: fb::CrisisOverlayState<'_>) {
         if let Some(raster) = overlay.heatmap() {
             self.crisis_width = raster.width();
@@ -249,6 +278,7 @@ impl DeltaAggregator {
                     label: entry.label().map(|value| value.to_string()),
                     severity: entry.severity(),
                     path,
+                    crisis_id: entry.crisisId(),
                 });
             }
         }
@@ -288,15 +318,8 @@ impl DeltaAggregator {
         let count = (self.moisture_width as usize)
             .saturating_mul(self.moisture_height as usize)
             .max(1);
+        self.moisture_samples = decode_float_raster_samples(raster);
         self.moisture_samples.resize(count, 0.0);
-        if let Some(samples) = raster.samples() {
-            for (idx, value) in samples.iter().enumerate() {
-                if idx >= count {
-                    break;
-                }
-                self.moisture_samples[idx] = value;
-            }
-        }
     }
 
     pub(crate) fn into_dictionary(self) -> VarDictionary {
@@ -307,11 +330,17 @@ impl DeltaAggregator {
             wrap_horizontal,
             server_build,
             world_epoch,
+            config_hash,
+            sequence,
+            delta_part,
+            delta_part_count,
+            computed_channels,
             tile_updates,
             terrain_width,
             terrain_height,
             terrain_types,
             terrain_tags,
+            terrain_palette_hash,
             logistics_width,
             logistics_height,
             logistics_samples,
@@ -333,6 +362,9 @@ impl DeltaAggregator {
             military_width,
             military_height,
             military_samples,
+            population_density_width,
+            population_density_height,
+            population_density_samples,
             crisis_width,
             crisis_height,
             crisis_samples,
@@ -356,6 +388,7 @@ impl DeltaAggregator {
             .max(visibility_width)
             .max(culture_width)
             .max(military_width)
+            .max(population_density_width)
             .max(crisis_width)
             .max(elevation_width)
             .max(moisture_width);
@@ -368,6 +401,7 @@ impl DeltaAggregator {
             .max(visibility_height)
             .max(culture_height)
             .max(military_height)
+            .max(population_density_height)
             .max(crisis_height)
             .max(elevation_height)
             .max(moisture_height);
@@ -506,6 +540,28 @@ impl DeltaAggregator {
             }
         }
 
+        // Only a frame that has carried a density raster publishes the channel.
+        let mut population_density = Vec::new();
+        if population_density_width > 0
+            && population_density_height > 0
+            && !population_density_samples.is_empty()
+        {
+            population_density = vec![0.0f32; total];
+            for y in 0..population_density_height {
+                for x in 0..population_density_width {
+                    let src_idx = (y as usize) * (population_density_width as usize) + x as usize;
+                    if src_idx >= population_density_samples.len() {
+                        break;
+                    }
+                    if x >= final_width || y >= final_height {
+                        continue;
+                    }
+                    let dst_idx = (y as usize) * (final_width as usize) + x as usize;
+                    population_density[dst_idx] = population_density_samples[src_idx];
+                }
+            }
+        }
+
         let mut crisis = vec![0.0f32; total];
         if crisis_width > 0 && crisis_height > 0 && !crisis_samples.is_empty() {
             for y in 0..crisis_height {
@@ -596,6 +652,7 @@ impl DeltaAggregator {
                 // Same reasoning: a delta carries no forage-patch list, so it publishes NO forage
                 // channel rather than a field of zeros that would claim there are no gathering sites.
                 forage_capacity: &[],
+                population_density: &population_density,
             },
             TerrainSlices {
                 terrain: terrain_ref.as_deref(),
@@ -617,6 +674,21 @@ impl DeltaAggregator {
         // `worldEpoch`), so a delta arriving before the first full frame can be recognised as
         // pre-/post-rebuild by the loading gate. Default 0 (idle boot app / absent header).
         let _ = dict.insert("world_epoch", world_epoch as i64);
+        let _ = dict.insert("config_hash", format!("{config_hash:016x}").as_str());
+        let _ = dict.insert("sequence", sequence as i64);
+        // Split-delta position (see snapshot.fbs `deltaPart`): parts apply cumulatively, and the
+        // world is whole again once `delta_part + 1 == delta_part_count`.
+        let _ = dict.insert("delta_part", delta_part as i64);
+        let _ = dict.insert("delta_part_count", delta_part_count as i64);
+        let _ = dict.insert("computed_channels", computed_channels as i64);
+        // Only a delta that carried a terrain overlay speaks for the legend, so the key is absent
+        // otherwise. Nothing on the script side reads it yet.
+        if let Some(palette_hash) = terrain_palette_hash {
+            let _ = dict.insert(
+                "terrain_palette_hash",
+                format!("{palette_hash:016x}").as_str(),
+            );
+        }
         dict
     }
 }
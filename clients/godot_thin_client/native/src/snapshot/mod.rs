@@ -7,10 +7,11 @@ pub(crate) mod raster;
 
 use godot::prelude::*;
 use shadow_scale_flatbuffers::shadow_scale::sim as fb;
+use sim_runtime::decode_float_raster_samples;
 use std::collections::{BTreeSet, HashMap};
 
 use crate::dict::campaign::{
-    campaign_label_to_dict, campaign_profile_to_dict, command_events_to_array,
+    calendar_to_dict, campaign_label_to_dict, campaign_profile_to_dict, command_events_to_array,
     pending_forks_to_array, stance_axes_to_array, victory_state_to_dict, voice_medium_to_array,
 };
 use crate::dict::culture::{
@@ -21,15 +22,19 @@ use crate::dict::economy::{faction_inventory_to_array, trade_links_to_array};
 use crate::dict::fixed64_to_f32;
 use crate::dict::governance::{
     corruption_to_dict, crisis_annotation_to_dict, crisis_overlay_to_dict,
-    crisis_telemetry_to_dict, power_metrics_to_dict, power_nodes_to_array,
+    crisis_telemetry_to_dict, diplomacy_leverage_to_array, power_metrics_to_dict,
+    power_nodes_to_array,
 };
 use crate::dict::knowledge::{
-    discovered_sites_to_array, discovery_progress_to_array, great_discovery_definitions_to_array,
-    great_discovery_progress_states_to_array, great_discovery_states_to_array,
-    great_discovery_telemetry_to_dict,
+    discovered_sites_to_array, discovery_progress_to_array, espionage_agents_to_array,
+    great_discovery_definitions_to_array, great_discovery_progress_states_to_array,
+    great_discovery_states_to_array, great_discovery_telemetry_to_dict,
+    knowledge_early_warnings_to_array,
 };
 use crate::dict::map::{terrain_label_from_id, tiles_to_array, TERRAIN_TAG_LABELS};
-use crate::dict::population::{demographics_to_array, generations_to_array, populations_to_array};
+use crate::dict::population::{
+    demographics_to_array, generations_to_array, morale_summary_to_array, populations_to_array,
+};
 use crate::dict::subsistence::{
     food_modules_to_array, forage_patches_to_array, herds_to_array,
     intensification_knowledge_to_array, sedentarization_to_array,
@@ -91,6 +96,11 @@ fn snapshot_dict(
     let moisture_base = copy_into(overlays.moisture);
     let pasture_base = copy_into(overlays.pasture_capacity);
     let forage_base = copy_into(overlays.forage_capacity);
+    let density_base = if overlays.population_density.is_empty() {
+        Vec::new()
+    } else {
+        copy_into(overlays.population_density)
+    };
 
     let mut logistics_normalized = logistics_base.clone();
     normalize_overlay(&mut logistics_normalized);
@@ -210,6 +220,8 @@ fn snapshot_dict(
             vec![0.0f32; base.len()]
         }
     };
+    let density_max = channel_max(&density_base);
+    let density_normalized = normalize_channel(&density_base, density_max);
     let hunt_danger_max = channel_max(&hunt_danger_base);
     let threat_max = channel_max(&threat_base);
     let hunt_danger_normalized = normalize_channel(&hunt_danger_base, hunt_danger_max);
@@ -287,6 +299,8 @@ fn snapshot_dict(
     let hunt_danger_raw_array = packed_from_slice(&hunt_danger_base);
     let threat_array = packed_from_slice(&threat_normalized);
     let threat_raw_array = packed_from_slice(&threat_base);
+    let density_array = packed_from_slice(&density_normalized);
+    let density_raw_array = packed_from_slice(&density_base);
 
     let elevation_placeholder = elevation_array.is_empty();
     let moisture_placeholder = overlays.moisture.is_empty();
@@ -340,6 +354,25 @@ fn snapshot_dict(
             placeholder: false,
         },
     );
+    // Density normalizes against the map's most crowded tile (like pasture, not min-max): an empty
+    // tile is a real "nobody lives here" reading, not the low end of a stretched range.
+    if density_max > 0.0 {
+        insert_overlay_channel(
+            &mut channels,
+            &mut channel_order,
+            OverlayChannelParams {
+                key: "population_density",
+                label: "Population Density",
+                description: Some(
+                    "Head-count of the population cohorts homed on the tile (1.0 = the most crowded tile on the map).",
+                ),
+                normalized: &density_array,
+                raw: &density_raw_array,
+                contrast: &density_array,
+                placeholder: false,
+            },
+        );
+    }
     insert_overlay_channel(
         &mut channels,
         &mut channel_order,
@@ -653,6 +686,10 @@ fn snapshot_dict(
 
     let _ = dict.insert("overlays", &overlays);
 
+    // Shape-only placeholders. Unit markers are built client-side from `populations` (each cohort
+    // carries its current/travel tiles and `labor_assignments`; expeditions ride the same array),
+    // so there is no separate wire `units` section to decode. `orders` stays empty because the
+    // turn queue only ever holds `Order::EndTurn`, which has nothing to draw.
     let _ = dict.insert("units", &VarArray::new());
     let _ = dict.insert("orders", &VarArray::new());
 
@@ -966,6 +1003,30 @@ pub(crate) fn snapshot_to_dict(snapshot: fb::WorldSnapshot<'_>) -> VarDictionary
         }
     }
 
+    // No fallback fill: an absent density raster stays empty so the channel is omitted.
+    let mut density_grid: Vec<f32> = Vec::new();
+    let mut density_dims = (0u32, 0u32);
+    if let Some(raster) = snapshot
+        .population()
+        .and_then(|s| s.populationDensityRaster())
+    {
+        let width = raster.width();
+        let height = raster.height();
+        if width > 0 && height > 0 {
+            let total = (width as usize).saturating_mul(height as usize);
+            density_grid = vec![0.0f32; total];
+            if let Some(samples) = raster.samples() {
+                for (idx, value) in samples.iter().enumerate() {
+                    if idx >= total {
+                        break;
+                    }
+                    density_grid[idx] = fixed64_to_f32(value);
+                }
+            }
+            density_dims = (width, height);
+        }
+    }
+
     if let Some(overlay) = snapshot.governance().and_then(|s| s.crisisOverlay()) {
         if let Some(raster) = overlay.heatmap() {
             let width = raster.width();
@@ -990,6 +1051,7 @@ pub(crate) fn snapshot_to_dict(snapshot: fb::WorldSnapshot<'_>) -> VarDictionary
                     label: annotation.label().map(|value| value.to_string()),
                     severity: annotation.severity(),
                     path: Vec::new(),
+                    crisis_id: annotation.crisisId(),
                 };
                 if let Some(path) = annotation.path() {
                     record.path.reserve(path.len());
@@ -1007,15 +1069,9 @@ pub(crate) fn snapshot_to_dict(snapshot: fb::WorldSnapshot<'_>) -> VarDictionary
         let height = raster.height();
         if width > 0 && height > 0 {
             let total = (width as usize).saturating_mul(height as usize);
-            moisture_grid = vec![0.0f32; total];
-            if let Some(samples) = raster.samples() {
-                for (idx, value) in samples.iter().enumerate() {
-                    if idx >= total {
-                        break;
-                    }
-                    moisture_grid[idx] = value;
-                }
-            }
+            // The sim may quantize this raster (`RasterPrecision`); decode whichever vector it sent.
+            moisture_grid = decode_float_raster_samples(raster);
+            moisture_grid.resize(total, 0.0);
             moisture_dims = (width, height);
         }
     }
@@ -1113,6 +1169,7 @@ pub(crate) fn snapshot_to_dict(snapshot: fb::WorldSnapshot<'_>) -> VarDictionary
         .max(fog_dims.0)
         .max(culture_dims.0)
         .max(military_dims.0)
+        .max(density_dims.0)
         .max(crisis_dims.0)
         .max(elevation_dims.0)
         .max(moisture_dims.0)
@@ -1125,6 +1182,7 @@ pub(crate) fn snapshot_to_dict(snapshot: fb::WorldSnapshot<'_>) -> VarDictionary
         .max(fog_dims.1)
         .max(culture_dims.1)
         .max(military_dims.1)
+        .max(density_dims.1)
         .max(crisis_dims.1)
         .max(elevation_dims.1)
         .max(moisture_dims.1)
@@ -1252,6 +1310,24 @@ pub(crate) fn snapshot_to_dict(snapshot: fb::WorldSnapshot<'_>) -> VarDictionary
         }
     }
 
+    let mut density_resized = Vec::new();
+    if !density_grid.is_empty() {
+        density_resized = vec![0.0f32; total];
+        for y in 0..density_dims.1 {
+            for x in 0..density_dims.0 {
+                let src_idx = (y as usize) * (density_dims.0 as usize) + x as usize;
+                if src_idx >= density_grid.len() {
+                    break;
+                }
+                if x >= final_width || y >= final_height {
+                    continue;
+                }
+                let dst_idx = (y as usize) * (final_width as usize) + x as usize;
+                density_resized[dst_idx] = density_grid[src_idx];
+            }
+        }
+    }
+
     let mut crisis_resized = vec![0.0f32; total];
     if crisis_dims.0 > 0 && crisis_dims.1 > 0 {
         for y in 0..crisis_dims.1 {
@@ -1420,6 +1496,7 @@ pub(crate) fn snapshot_to_dict(snapshot: fb::WorldSnapshot<'_>) -> VarDictionary
             visibility: &visibility_resized,
             pasture_capacity: &pasture_capacity_vec,
             forage_capacity: &forage_capacity_vec,
+            population_density: &density_resized,
         },
         TerrainSlices {
             terrain: terrain_slice,
@@ -1453,6 +1530,10 @@ pub(crate) fn snapshot_to_dict(snapshot: fb::WorldSnapshot<'_>) -> VarDictionary
         let _ = dict.insert("voice_medium", &voice_medium_to_array(voice_medium));
     }
 
+    if let Some(calendar) = snapshot.campaign().and_then(|s| s.calendar()) {
+        let _ = dict.insert("calendar", &calendar_to_dict(calendar));
+    }
+
     if let Some(server_build) = header.serverBuild() {
         let _ = dict.insert("server_build", server_build);
     }
@@ -1463,6 +1544,35 @@ pub(crate) fn snapshot_to_dict(snapshot: fb::WorldSnapshot<'_>) -> VarDictionary
     // last-revealed baseline, so a reconnecting client ignores the replayed pre-rebuild frame.
     let _ = dict.insert("world_epoch", header.worldEpoch() as i64);
 
+    // Config lineage fingerprint (see snapshot.fbs `configHash`), as 16 hex digits: Godot ints are
+    // signed, and the value is only ever compared or shown, never used arithmetically.
+    let _ = dict.insert(
+        "config_hash",
+        format!("{:016x}", header.configHash()).as_str(),
+    );
+
+    // Stream position (see snapshot.fbs `sequence`): the decoder resumes delta gap detection from
+    // the sequence of the latest full snapshot.
+    let _ = dict.insert("sequence", header.sequence() as i64);
+
+    // Influencers the server left off the roster list as below its snapshot minimum (see
+    // snapshot.fbs `influencersOmitted`); 0 means the roster list is complete.
+    let _ = dict.insert("influencers_omitted", header.influencersOmitted() as i64);
+
+    // Overlay rasters the server actually computed (see snapshot.fbs `computedChannels`): an
+    // empty raster with its bit clear is a placeholder, one with its bit set is a real zero.
+    let _ = dict.insert("computed_channels", header.computedChannels() as i64);
+
+    // Terrain legend fingerprint (see snapshot.fbs `TerrainOverlay.paletteHash`), hex like
+    // `config_hash`. Exposed for a palette cache; no script reads it yet, so the map view still
+    // rebuilds its terrain palette from `terrain_palette` on every overlay.
+    if let Some(layer) = snapshot.map().and_then(|s| s.terrainOverlay()) {
+        let _ = dict.insert(
+            "terrain_palette_hash",
+            format!("{:016x}", layer.paletteHash()).as_str(),
+        );
+    }
+
     if let Some(sedentarization) = snapshot.subsistence().and_then(|s| s.sedentarization()) {
         let _ = dict.insert(
             "sedentarization",
@@ -1487,6 +1597,9 @@ pub(crate) fn snapshot_to_dict(snapshot: fb::WorldSnapshot<'_>) -> VarDictionary
     if let Some(demographics) = snapshot.population().and_then(|s| s.demographics()) {
         let _ = dict.insert("demographics", &demographics_to_array(demographics));
     }
+    if let Some(summary) = snapshot.population().and_then(|s| s.moraleSummary()) {
+        let _ = dict.insert("morale_summary", &morale_summary_to_array(summary));
+    }
 
     if let Some(discovered_sites) = snapshot.knowledge().and_then(|s| s.discoveredSites()) {
         let _ = dict.insert(
@@ -1494,6 +1607,9 @@ pub(crate) fn snapshot_to_dict(snapshot: fb::WorldSnapshot<'_>) -> VarDictionary
             &discovered_sites_to_array(discovered_sites),
         );
     }
+    if let Some(agents) = snapshot.knowledge().and_then(|s| s.espionageAgents()) {
+        let _ = dict.insert("espionage_agents", &espionage_agents_to_array(agents));
+    }
 
     if let Some(axis_bias) = snapshot.culture().and_then(|s| s.axisBias()) {
         let _ = dict.insert("axis_bias", &axis_bias_to_dict(axis_bias));
@@ -1511,6 +1627,10 @@ pub(crate) fn snapshot_to_dict(snapshot: fb::WorldSnapshot<'_>) -> VarDictionary
         let _ = dict.insert("corruption", &corruption_to_dict(ledger));
     }
 
+    if let Some(pairs) = snapshot.governance().and_then(|s| s.diplomacyLeverage()) {
+        let _ = dict.insert("diplomacy_leverage", &diplomacy_leverage_to_array(pairs));
+    }
+
     if let Some(populations) = snapshot.population().and_then(|s| s.populations()) {
         let _ = dict.insert("populations", &populations_to_array(populations));
     }
@@ -1591,6 +1711,15 @@ pub(crate) fn snapshot_to_dict(snapshot: fb::WorldSnapshot<'_>) -> VarDictionary
     if let Some(progress) = snapshot.knowledge().and_then(|s| s.discoveryProgress()) {
         let _ = dict.insert("discovery_progress", &discovery_progress_to_array(progress));
     }
+    if let Some(warnings) = snapshot
+        .knowledge()
+        .and_then(|s| s.knowledgeEarlyWarnings())
+    {
+        let _ = dict.insert(
+            "knowledge_early_warnings",
+            &knowledge_early_warnings_to_array(warnings),
+        );
+    }
 
     dict
 }
@@ -3,6 +3,9 @@
 
 use godot::prelude::*;
 
+/// Min-max overlay stretch, shared with the sim via `sim_schema::raster`.
+pub(crate) use sim_runtime::normalize_overlay;
+
 pub(crate) fn packed_from_slice(values: &[f32]) -> PackedFloat32Array {
     if values.is_empty() {
         return PackedFloat32Array::new();
@@ -85,40 +88,13 @@ pub(crate) struct OverlaySlices<'a> {
     /// shelves carry real fishing potential and sit ON the capacity ramp; only genuinely-zero tiles
     /// are the off-ramp barren fill (see MapView `_forage_color`).
     pub(crate) forage_capacity: &'a [f32],
+    /// Head-count per tile (`PopulationSection.populationDensityRaster`), summed over the cohorts
+    /// homed there. Empty when the frame carried no density raster, in which case the channel is
+    /// omitted rather than published as an empty map.
+    pub(crate) population_density: &'a [f32],
 }
 
 pub(crate) struct TerrainSlices<'a> {
     pub(crate) terrain: Option<&'a [u16]>,
     pub(crate) tags: Option<&'a [u16]>,
 }
-
-pub(crate) fn normalize_overlay(values: &mut [f32]) {
-    if values.is_empty() {
-        return;
-    }
-    let mut min = f32::INFINITY;
-    let mut max = f32::NEG_INFINITY;
-    for &v in values.iter() {
-        if !v.is_finite() {
-            continue;
-        }
-        if v < min {
-            min = v;
-        }
-        if v > max {
-            max = v;
-        }
-    }
-    if !min.is_finite() || !max.is_finite() || (max - min).abs() < f32::EPSILON {
-        values.fill(0.0);
-        return;
-    }
-    let range = max - min;
-    for v in values.iter_mut() {
-        if v.is_finite() {
-            *v = ((*v - min) / range).clamp(0.0, 1.0);
-        } else {
-            *v = 0.0;
-        }
-    }
-}
@@ -325,6 +325,7 @@ const OVERLAY_COLORS := {
 	"fog": FOG_COLOR,
 	"culture": CULTURE_COLOR,
 	"military": MILITARY_COLOR,
+	"population_density": Color(0.95, 0.72, 0.30, 1.0),
 	"crisis": CRISIS_COLOR,
 	"elevation": ELEVATION_HIGH_COLOR,
 	"moisture": Color(0.2, 0.65, 0.95, 1.0),
@@ -1776,7 +1777,7 @@ func _rebuild_unit_markers(snapshot: Dictionary) -> void:
 			"morale_delta": float(entry.get("morale_delta", 0.0)),
 			"morale_cause": int(entry.get("morale_cause", 0)),
 			# Civilization Wellbeing (docs/plan_civ_wellbeing.md): productivity, discontent,
-			# migration counters, and the four signed Layer-1 morale contributions that feed
+			# migration counters, and the five signed Layer-1 morale contributions that feed
 			# the band drawer's itemized breakdown + "people leaving" alert reason.
 			"output_multiplier": float(entry.get("output_multiplier", 1.0)),
 			"discontent_fraction": float(entry.get("discontent_fraction", 0.0)),
@@ -1786,6 +1787,7 @@ func _rebuild_unit_markers(snapshot: Dictionary) -> void:
 			"morale_terrain": float(entry.get("morale_terrain", 0.0)),
 			"morale_climate": float(entry.get("morale_climate", 0.0)),
 			"morale_unrest": float(entry.get("morale_unrest", 0.0)),
+			"morale_diet": float(entry.get("morale_diet", 0.0)),
 			# Data-driven settlement stage (icon glyph + label). The icon becomes the band's
 			# map token; empty icon → neutral non-circular fallback marker (square; ownership is
 			# on the banner, no disc). Label surfaces in tooltip/roster.
@@ -3133,8 +3135,10 @@ func _install_province_overlay() -> void:
 		var scope := String(layer_dict.get("scope", ""))
 		if scope == "Regional":
 			var id: int = int(layer_dict.get("id", -1))
-			var owner: int = int(layer_dict.get("owner", -1))
-			if id >= 0:
+			if String(layer_dict.get("owner_kind", "")) != "province":
+				continue
+			var owner: int = int(layer_dict.get("owner_id", -1))
+			if id >= 0 and owner >= 0:
 				regional_owner[id] = owner
 	if regional_owner.is_empty():
 		return
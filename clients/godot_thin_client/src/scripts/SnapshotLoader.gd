@@ -84,6 +84,12 @@ func poll_stream(delta: float) -> Dictionary:
                     _warned_decoder_missing = true
                 return {}
         var snapshot_dict: Dictionary = decoder.decode_snapshot(payload)
+        if not bool(snapshot_dict.get("ok", true)):
+            push_warning("Snapshot frame dropped (%s): %s" % [
+                snapshot_dict.get("kind", "unknown"),
+                snapshot_dict.get("error", ""),
+            ])
+            continue
         if snapshot_dict.is_empty():
             continue
         last_stream_snapshot = snapshot_dict
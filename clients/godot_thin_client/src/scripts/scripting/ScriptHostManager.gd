@@ -114,15 +114,8 @@ func reload_package(key: String) -> Dictionary:
 func broadcast_topic(topic: String, payload: Variant) -> void:
     if _host == null:
         return
-    for key in _packages.keys():
-        var pkg: Dictionary = _packages[key]
-        if not pkg.get(PackageFields.ENABLED, false):
-            continue
-        var subs: Array = pkg.get(PackageFields.SUBSCRIPTIONS, [])
-        if subs.has(topic):
-            var script_id: int = pkg.get(PackageFields.SCRIPT_ID, -1)
-            if script_id >= 0:
-                _host.dispatch_event(script_id, topic, payload)
+    # The native host matches exact and glob (`crisis.*`) subscriptions per live script.
+    _host.broadcast_event(topic, payload)
 
 func handle_snapshot(snapshot: Dictionary) -> void:
     broadcast_topic("world.snapshot", snapshot)
@@ -53,6 +53,7 @@ const MORALE_TREND_RISING_GLYPH := "▲"
 # contribution reads as "culture" (cohesion), negative as "unrest".
 const MORALE_CONTRIB_LABEL_SETTLING := "settling"
 const MORALE_CONTRIB_LABEL_CULTURE := "culture"
+const MORALE_CONTRIB_LABEL_DIET := "familiar food"
 
 # ---- Accessible-stockpile rows (the band's reachable stores, from `accessible_stockpile`).
 const STOCKPILE_RADIUS_FORMAT := "Stockpile: radius %d"
@@ -324,7 +325,7 @@ func _band_output_line(unit_data: Dictionary, ctx: DetailFormat.Context) -> Stri
     ctx.output = output
     return "Output: %d%%" % int(round(output * 100.0))
 
-## Itemized morale breakdown: the four signed Layer-1 contributions (their sum IS morale_delta) as
+## Itemized morale breakdown: the five signed Layer-1 contributions (their sum IS morale_delta) as
 ## indented sub-lines, each above the breakdown epsilon rendered as `    ▲ +1.0%  settling`
 ## (`DetailFormat.detail_bbcode` tints by sign glyph). Now a click-to-expand disclosure (like Food): the
 ## contributions always compute so the row can be manually opened in the good state; the
@@ -336,12 +337,14 @@ func _morale_breakdown_lines(unit_data: Dictionary, terrain_label: String) -> Ar
     if terrain_label != "":
         terrain_row_label = "%s (%s)" % [DetailFormat.MORALE_CAUSE_LABEL_TERRAIN, terrain_label]
     var unrest_value := float(unit_data.get("morale_unrest", 0.0))
-    # (value, label) in the display order of the spec: settling, terrain, climate, unrest.
+    var diet_value := float(unit_data.get("morale_diet", 0.0))
+    # (value, label) in the display order of the spec: settling, terrain, climate, unrest, diet.
     var contributions := [
         [float(unit_data.get("morale_settling", 0.0)), MORALE_CONTRIB_LABEL_SETTLING],
         [float(unit_data.get("morale_terrain", 0.0)), terrain_row_label],
         [float(unit_data.get("morale_climate", 0.0)), DetailFormat.MORALE_CAUSE_LABEL_COLD],
         [unrest_value, MORALE_CONTRIB_LABEL_CULTURE if unrest_value > 0.0 else DetailFormat.MORALE_CAUSE_LABEL_UNREST],
+        [diet_value, MORALE_CONTRIB_LABEL_DIET if diet_value > 0.0 else DetailFormat.MORALE_CAUSE_LABEL_DIET],
     ]
     var epsilon := BandFoodStatus.morale_breakdown_epsilon()
     for entry in contributions:
@@ -57,6 +57,8 @@ const MORALE_CAUSE_COLD := 2
 
 const MORALE_CAUSE_UNREST := 3
 
+const MORALE_CAUSE_DIET := 4
+
 # Plain-language cause labels, shared by the drawer morale line and the alert reason.
 # Cold reads "harsh climate" because the server penalty fires on hot OR cold deviation.
 const MORALE_CAUSE_LABEL_TERRAIN := "harsh terrain"
@@ -65,6 +67,8 @@ const MORALE_CAUSE_LABEL_COLD := "harsh climate"
 
 const MORALE_CAUSE_LABEL_UNREST := "unrest"
 
+const MORALE_CAUSE_LABEL_DIET := "unfamiliar food"
+
 # |morale_delta| below this (0.5%/turn) reads as flat (no arrow), so trivial drift — nearly every tile
 # bleeds a hair today — isn't shown as a decline. (The ▲/▼ ARROWS are `BandDetailLines`', the only
 # thing that draws them.)
@@ -639,7 +643,7 @@ static func expedition_mission_label(mission: String) -> String:
         return HudExpeditionVocab.EXPEDITION_MISSION_LABELS[key]
     return key.capitalize() if key != "" else "Expedition"
 
-## Plain-language label for a morale cause (0=None,1=Terrain,2=Cold,3=Unrest); "" for None or
+## Plain-language label for a morale cause (0=None,1=Terrain,2=Cold,3=Unrest,4=Diet); "" for None or
 ## unknown. Shared by the drawer morale line and the losing-population alert reason.
 static func morale_cause_label(cause: int) -> String:
     match cause:
@@ -649,6 +653,8 @@ static func morale_cause_label(cause: int) -> String:
             return DetailFormat.MORALE_CAUSE_LABEL_COLD
         DetailFormat.MORALE_CAUSE_UNREST:
             return DetailFormat.MORALE_CAUSE_LABEL_UNREST
+        DetailFormat.MORALE_CAUSE_DIET:
+            return DetailFormat.MORALE_CAUSE_LABEL_DIET
         _:
             return ""
 
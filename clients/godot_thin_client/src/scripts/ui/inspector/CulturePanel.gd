@@ -269,10 +269,7 @@ func _update_culture_divergence_detail() -> void:
 	_publish_culture_layer_highlight_from_layer(layer)
 	var lines: Array[String] = []
 	var scope_label: String = str(layer.get("scope_label", layer.get("scope", "")))
-	var owner_variant: Variant = layer.get("owner")
-	if owner_variant == null:
-		owner_variant = layer.get("owner_value", 0)
-	var owner_display: String = _format_owner_display(owner_variant)
+	var owner_display: String = _format_owner_display(layer)
 	var parent_id: int = int(layer.get("parent", 0))
 	var divergence_val: float = float(layer.get("divergence", 0.0))
 	var soft_threshold: float = float(layer.get("soft_threshold", 0.0))
@@ -382,17 +379,17 @@ func _compare_trait_strength(a: Dictionary, b: Dictionary) -> bool:
 		return a_val > b_val
 	return absf(float(a.get("modifier", 0.0))) > absf(float(b.get("modifier", 0.0)))
 
-func _format_owner_display(owner_variant: Variant) -> String:
-	match typeof(owner_variant):
-		TYPE_INT, TYPE_FLOAT:
-			var numeric: int = int(owner_variant)
-			return "0x%016x" % numeric
-		TYPE_STRING:
-			return String(owner_variant)
-		TYPE_NIL:
-			return "n/a"
+func _format_owner_display(layer: Dictionary) -> String:
+	var owner_id: int = int(layer.get("owner_id", 0))
+	match str(layer.get("owner_kind", "")):
+		"global":
+			return "Global"
+		"province":
+			return "Province %d" % owner_id
+		"tile":
+			return "Tile 0x%x" % owner_id
 		_:
-			return str(owner_variant)
+			return str(layer.get("owner", "n/a"))
 
 func _on_culture_divergence_selected(index: int) -> void:
 	if culture_divergence_list == null:
@@ -193,7 +193,7 @@ func _update_node_detail() -> void:
 		return
 	var info: Dictionary = _nodes[_selected_node_id]
 	var lines: Array[String] = []
-	lines.append("[b]Node #%03d[/b]" % _selected_node_id)
+	lines.append("[b]Node #%03d[/b] (%s)" % [_selected_node_id, String(info.get("kind", "hybrid"))])
 	var entity_id: int = int(info.get("entity", 0))
 	lines.append("Entity %016X" % entity_id)
 	var generation: float = float(info.get("generation", info.get("generation_raw", 0.0)))
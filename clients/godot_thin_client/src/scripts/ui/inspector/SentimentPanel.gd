@@ -76,8 +76,10 @@ func _render() -> void:
 			var policy = float(axis.get("policy", 0.0))
 			var incidents = float(axis.get("incidents", 0.0))
 			var influencer_val = float(axis.get("influencers", 0.0))
-			lines.append(" • %s: %.3f (policy %.3f | incidents %.3f | influencers %.3f)"
-				% [key.capitalize(), total, policy, incidents, influencer_val])
+			var generation_val = float(axis.get("generations", 0.0))
+			# The generation lean is not part of `total`; it is listed beside it.
+			lines.append(" • %s: %.3f (policy %.3f | incidents %.3f | influencers %.3f) · generation lean %.3f"
+				% [key.capitalize(), total, policy, incidents, influencer_val, generation_val])
 
 			var drivers = axis.get("drivers", [])
 			var count = 0
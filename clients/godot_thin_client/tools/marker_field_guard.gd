@@ -48,6 +48,7 @@ const PANEL_CONSUMED_KEYS := [
 	"morale_terrain",      # BandDetailLines morale breakdown
 	"morale_climate",      # BandDetailLines morale breakdown
 	"morale_unrest",       # BandDetailLines morale breakdown
+	"morale_diet",         # BandDetailLines morale breakdown
 	"working_age",         # allocation header Workers / _effective_idle
 	"idle_workers",        # allocation header Idle / quick_assign_hunters
 	"age_children",        # band panel age-structure row (children / working / elders)
@@ -103,13 +104,14 @@ const FRACTIONAL_ROUND_TRIP_KEYS := {
 	"age_children": 9.2925,
 	"age_working": 16.5375,
 	"age_elders": 4.6425,
-	# Morale + its four signed Layer-1 contributions — all fixed-point Scalars.
+	# Morale + its five signed Layer-1 contributions — all fixed-point Scalars.
 	"morale": 0.4137,
 	"morale_delta": -0.0325,
 	"morale_settling": 0.0113,
 	"morale_terrain": -0.0217,
 	"morale_climate": -0.0154,
 	"morale_unrest": -0.0061,
+	"morale_diet": -0.0006,
 	# Wellbeing scalars — fixed-point.
 	"output_multiplier": 0.7225,
 	"discontent_fraction": 0.1837,
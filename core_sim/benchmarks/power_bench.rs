@@ -4,7 +4,7 @@ use bevy::{ecs::system::SystemState, prelude::*};
 use core_sim::{
     scalar_from_f32, scalar_zero, simulate_power, CorruptionLedgers, CultureCorruptionConfig,
     CultureCorruptionConfigHandle, CultureEffectsCache, ElementKind, InfluencerImpacts,
-    PowerGridState, PowerNode, PowerNodeId, PowerSimParams, PowerTopology, Scalar,
+    PowerGridState, PowerNode, PowerNodeId, PowerNodeKind, PowerSimParams, PowerTopology, Scalar,
     SimulationConfig, Tile, TurnPipelineConfig, TurnPipelineConfigHandle,
 };
 use criterion::{criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion};
@@ -84,6 +84,7 @@ fn spawn_power_nodes(
                     },
                     PowerNode {
                         id: PowerNodeId(idx as u32),
+                        kind: PowerNodeKind::Hybrid,
                         base_generation: Scalar::from_f32(spec.base_generation),
                         base_demand: Scalar::from_f32(spec.base_demand),
                         generation: Scalar::from_f32(spec.base_generation),
@@ -95,6 +96,8 @@ fn spawn_power_nodes(
                         surplus: scalar_zero(),
                         deficit: scalar_zero(),
                         incident_count: 0,
+                        priority: 0,
+                        shed: scalar_zero(),
                     },
                 ))
                 .id();
@@ -0,0 +1,66 @@
+use core_sim::{bulk_clamp, bulk_normalize, bulk_scale, Scalar};
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
+
+const WIDTH: usize = 512;
+const HEIGHT: usize = 256;
+
+fn large_raster() -> Vec<Scalar> {
+    (0..(WIDTH * HEIGHT) as i64)
+        .map(|i| Scalar::from_raw((i * 7_919 % 100_003 - 50_000) * 31))
+        .collect()
+}
+
+fn bench_bulk_ops(c: &mut Criterion) {
+    let raster = large_raster();
+    let factor = Scalar::from_f32(0.37);
+    let (lo, hi) = (Scalar::from_f32(-0.5), Scalar::from_f32(0.75));
+
+    let mut group = c.benchmark_group("scalar_bulk");
+    group.bench_function("normalize/bulk", |b| {
+        b.iter_batched_ref(
+            || raster.clone(),
+            |values| bulk_normalize(black_box(values)),
+            BatchSize::LargeInput,
+        )
+    });
+    group.bench_function("normalize/naive", |b| {
+        b.iter_batched_ref(
+            || raster.clone(),
+            |values| {
+                let min = *values.iter().min().unwrap();
+                let max = *values.iter().max().unwrap();
+                for value in values.iter_mut() {
+                    *value = (*value - min) / (max - min);
+                }
+                black_box(values);
+            },
+            BatchSize::LargeInput,
+        )
+    });
+    group.bench_function("scale/bulk", |b| {
+        b.iter_batched_ref(
+            || raster.clone(),
+            |values| bulk_scale(black_box(values), factor),
+            BatchSize::LargeInput,
+        )
+    });
+    group.bench_function("clamp/bulk", |b| {
+        b.iter_batched_ref(
+            || raster.clone(),
+            |values| bulk_clamp(black_box(values), lo, hi),
+            BatchSize::LargeInput,
+        )
+    });
+    group.bench_function("overlay_f32/normalize", |b| {
+        let samples: Vec<f32> = raster.iter().map(|value| value.to_f32()).collect();
+        b.iter_batched_ref(
+            || samples.clone(),
+            |values| sim_runtime::normalize_overlay(black_box(values)),
+            BatchSize::LargeInput,
+        )
+    });
+    group.finish();
+}
+
+criterion_group!(scalar_bulk_benches, bench_bulk_ops);
+criterion_main!(scalar_bulk_benches);
@@ -0,0 +1,54 @@
+use bevy::prelude::{Entity, UVec2};
+use core_sim::{grid_utils::hex_distance_wrapped, TileRegistry, TileSpatialIndex};
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+
+const WIDTH: u32 = 512;
+const HEIGHT: u32 = 256;
+
+fn large_registry() -> TileRegistry {
+    TileRegistry {
+        tiles: (0..WIDTH * HEIGHT).map(Entity::from_raw).collect(),
+        width: WIDTH,
+        height: HEIGHT,
+    }
+}
+
+fn bench_radius_query(c: &mut Criterion) {
+    let registry = large_registry();
+    let index = TileSpatialIndex::build(&registry, true);
+    let center = UVec2::new(WIDTH / 2, HEIGHT / 2);
+
+    let mut group = c.benchmark_group("radius_query");
+    for radius in [2u32, 6, 16] {
+        group.bench_with_input(BenchmarkId::new("index", radius), &radius, |b, &radius| {
+            b.iter(|| black_box(index.query_radius(center, radius).count()))
+        });
+        group.bench_with_input(
+            BenchmarkId::new("brute_force", radius),
+            &radius,
+            |b, &radius| {
+                b.iter(|| {
+                    black_box(
+                        registry
+                            .tiles
+                            .iter()
+                            .enumerate()
+                            .filter(|(idx, _)| {
+                                let pos = UVec2::new(*idx as u32 % WIDTH, *idx as u32 / WIDTH);
+                                hex_distance_wrapped(center, pos, WIDTH, true) <= radius
+                            })
+                            .count(),
+                    )
+                })
+            },
+        );
+    }
+    group.finish();
+
+    c.bench_function("radius_query/build", |b| {
+        b.iter(|| black_box(TileSpatialIndex::build(&registry, true)))
+    });
+}
+
+criterion_group!(spatial_index_benches, bench_radius_query);
+criterion_main!(spatial_index_benches);
@@ -1,4 +1,4 @@
-use std::io::{self, BufReader, Read};
+use std::io::{self, BufReader, Read, Write};
 use std::net::{TcpListener, TcpStream};
 use std::path::PathBuf;
 use std::sync::{mpsc, Arc};
@@ -33,31 +33,37 @@ use core_sim::{
     WellbeingConfigHandle, NO_FORAGE_SEASON,
 };
 use core_sim::{
-    build_headless_app, hunt_trip_forecast, recapture_snapshot_in_place,
-    restore_world_from_snapshot, run_turn, scalar_from_f32, AgentAssignment, CommandEventEntry,
-    CommandEventKind, CommandEventLog, CorruptionLedgers, CounterIntelBudgets,
-    CrisisArchetypeCatalog, CrisisArchetypeCatalogHandle, CrisisArchetypeCatalogMetadata,
+    build_headless_app, difficulty_preset_override, hunt_trip_forecast, inspect_region,
+    load_great_discovery_catalog, recapture_snapshot_in_place, restore_world_from_snapshot,
+    run_turn, scalar_from_f32, AgentAssignment, CommandEventEntry, CommandEventKind,
+    CommandEventLog, CorruptionLedgers, CounterIntelBudgets, CrisisArchetypeCatalog,
+    CrisisArchetypeCatalogHandle, CrisisArchetypeCatalogMetadata, CrisisContainmentOrder,
     CrisisModifierCatalog, CrisisModifierCatalogHandle, CrisisModifierCatalogMetadata,
     CrisisTelemetry, CrisisTelemetryConfig, CrisisTelemetryConfigHandle,
     CrisisTelemetryConfigMetadata, DiscoveryProgressLedger, EcologyPhase, EspionageAgentHandle,
     EspionageCatalog, EspionageMissionId, EspionageMissionKind, EspionageMissionState,
-    EspionageMissionTemplate, EspionageRoster, FactionId, FactionOrders, FactionRegistry,
-    FactionSecurityPolicies, FaunaConfigHandle, FogRevealLedger, FollowPolicy, ForageRegistry,
-    GenerationId, GenerationRegistry, HerdRegistry, InfluencerImpacts, InfluentialRoster,
-    LaborConfigHandle, MapPresetsHandle, PendingCrisisSpawns, PopulationCohort, QueueMissionError,
-    QueueMissionParams, Scalar, SecurityPolicy, SentimentAxisBias, Settlement, SimulationConfig,
-    SimulationConfigMetadata, SimulationTick, SnapshotHistory, SnapshotOverlaysConfig,
-    SnapshotOverlaysConfigHandle, SnapshotOverlaysConfigMetadata, StartLocation,
-    StartProfileLookup, StartProfilesHandle, StartingUnit, StoredSnapshot, SubmitError,
-    SubmitOutcome, SupportChannel, Tile, TileRegistry, TownCenter, TurnPipelineConfig,
-    TurnPipelineConfigHandle, TurnPipelineConfigMetadata, TurnQueue, WorldEpoch, FOOD,
+    EspionageMissionTemplate, EspionageRoster, FactionId, FactionInventory, FactionOrders,
+    FactionRegistry, FactionSecurityPolicies, FaunaConfigHandle, FogRevealLedger, FollowPolicy,
+    ForageRegistry, GenerationId, GenerationRegistry, HerdRegistry, HerdTelemetry,
+    InfluencerImpacts, InfluentialRoster, KnowledgeLedger, LaborConfigHandle, MapPresetsHandle,
+    Order, PendingCrisisContainment, PendingCrisisSpawns, PopulationCohort, PostureChangeError,
+    QueueMissionError, QueueMissionParams, Scalar, SecurityPolicy, SentimentAxisBias, Settlement,
+    SimulationConfig, SimulationConfigMetadata, SimulationTick, SnapshotHistory,
+    SnapshotOverlaysConfig, SnapshotOverlaysConfigHandle, SnapshotOverlaysConfigMetadata,
+    StartLocation, StartProfileLookup, StartProfilesHandle, StartingUnit, StoredSnapshot,
+    SubmitError, SubmitOutcome, SupportChannel, Tile, TileChangedEvent, TileField, TileRegistry,
+    TownCenter, TurnPipelineConfig, TurnPipelineConfigHandle, TurnPipelineConfigMetadata,
+    TurnQueue, TurnReport, VictoryResult, WorldEpoch, BUILTIN_SIMULATION_CONFIG, FOOD,
 };
+use core_sim::{ActiveCrisisLedger, RegionInspection, ViewerFaction};
 use sim_runtime::{
     commands::{EspionageGeneratorUpdate as CommandGeneratorUpdate, ReloadConfigKind},
     AxisBiasState, CancelScope, CommandEnvelope as ProtoCommandEnvelope,
-    CommandPayload as ProtoCommandPayload, CorruptionEntry, CorruptionSubsystem,
-    InfluenceScopeKind, OrdersDirective as ProtoOrdersDirective, SecurityPolicyKind,
-    SupportChannel as ProtoSupportChannel, TerrainTags,
+    CommandPayload as ProtoCommandPayload, CommandResponse, CommandSession, CorruptionEntry,
+    CorruptionSubsystem, EventSeverity, HandshakeError, HandshakePolicy, InfluenceScopeKind,
+    KnowledgeSecurityPosture, OrdersDirective as ProtoOrdersDirective, SecurityPolicyKind,
+    SupportAction, SupportChannel as ProtoSupportChannel, TerrainTags, CONTAIN_CRISIS_EFFORT_MAX,
+    INFLUENCER_ACTION_MAGNITUDE_MAX, INFLUENCER_ACTION_MAGNITUDE_SCALE,
 };
 use sim_schema::{encode_map_export_json, MapExport};
 
@@ -146,8 +152,13 @@ fn main() {
         warn!(target: "shadow_scale::server", "log_stream.start_failed");
     }
 
-    let snapshot_server = start_snapshot_server(bound_ports.snapshot);
-    let snapshot_flat_server = start_snapshot_server(bound_ports.snapshot_flat);
+    // The frame cap is fixed at startup, like the bind addresses.
+    let max_delta_bytes = app
+        .world
+        .resource::<SimulationConfig>()
+        .snapshot_delta_max_bytes;
+    let snapshot_server = start_snapshot_server(bound_ports.snapshot, max_delta_bytes);
+    let snapshot_flat_server = start_snapshot_server(bound_ports.snapshot_flat, max_delta_bytes);
 
     let config_watch_path = app
         .world
@@ -180,7 +191,11 @@ fn main() {
         .path()
         .cloned();
 
-    let (command_rx, command_tx) = spawn_command_listener(bound_ports.command);
+    let handshake_policy = HandshakePolicy {
+        required: config.command_handshake_required,
+        token: config.command_token.clone(),
+    };
+    let (command_rx, command_tx) = spawn_command_listener(bound_ports.command, handshake_policy);
     app.world
         .insert_resource(CommandSenderResource(command_tx.clone()));
     app.world.insert_resource(ConfigWatcherRegistry::default());
@@ -264,21 +279,20 @@ fn main() {
                     continue;
                 }
                 for _ in 0..turns {
-                    {
-                        let mut queue = app.world.resource_mut::<TurnQueue>();
-                        let awaiting = queue.awaiting();
-                        for faction in &awaiting {
-                            info!(
-                                target: "shadow_scale::server",
-                                %faction,
-                                "orders.auto_generated=end_turn"
-                            );
-                        }
-                        queue.force_submit_all(|_| FactionOrders::end_turn());
-                    }
+                    auto_ready_awaiting_factions(&mut app);
                     resolve_ready_turn(&mut app, bin_server, flat_server);
                 }
             }
+            Command::AdvanceTurns { count } => {
+                if !world_active {
+                    warn!(
+                        target: "shadow_scale::server",
+                        "advance_turns.rejected=no active game — send new_game first"
+                    );
+                    continue;
+                }
+                handle_advance_turns(&mut app, count, bin_server, flat_server);
+            }
             Command::ResetMap { width, height } => {
                 if width == 0 || height == 0 {
                     warn!(
@@ -364,6 +378,9 @@ fn main() {
             Command::ExportMap { path } => {
                 write_map_export(&app, path);
             }
+            Command::InspectRegion { x0, y0, x1, y1 } => {
+                handle_inspect_region(&mut app, (x0, y0, x1, y1));
+            }
             Command::Heat { entity, delta } => {
                 apply_heat(&mut app, entity, delta);
                 info!(
@@ -376,6 +393,9 @@ fn main() {
             Command::Orders { faction, orders } => {
                 handle_order_submission(&mut app, faction, orders, bin_server, flat_server);
             }
+            Command::UndoOrder { faction } => {
+                handle_undo_order(&mut app, faction);
+            }
             Command::Rollback { tick } => {
                 handle_rollback(&mut app, tick, bin_server, flat_server);
             }
@@ -387,7 +407,7 @@ fn main() {
                     &mut app,
                     id,
                     magnitude,
-                    InfluencerAction::Support,
+                    SupportAction::Support,
                     bin_server,
                     flat_server,
                 );
@@ -397,7 +417,7 @@ fn main() {
                     &mut app,
                     id,
                     magnitude,
-                    InfluencerAction::Suppress,
+                    SupportAction::Suppress,
                     bin_server,
                     flat_server,
                 );
@@ -416,6 +436,22 @@ fn main() {
                     flat_server,
                 );
             }
+            Command::InfluencerAction {
+                id,
+                action,
+                channel,
+                magnitude,
+            } => {
+                handle_influencer_action(
+                    &mut app,
+                    id,
+                    action,
+                    channel,
+                    magnitude,
+                    bin_server,
+                    flat_server,
+                );
+            }
             Command::SpawnInfluencer { scope, generation } => {
                 handle_influencer_spawn(&mut app, scope, generation, bin_server, flat_server);
             }
@@ -455,6 +491,14 @@ fn main() {
             } => {
                 handle_adjust_counter_intel_budget(&mut app, faction, reserve, delta);
             }
+            Command::SetKnowledgePosture {
+                faction,
+                discovery,
+                posture,
+            } => {
+                // A refusal has already been reported to the faction's feed.
+                let _ = handle_set_knowledge_posture(&mut app, faction, discovery, posture);
+            }
             Command::ReloadConfig { kind, path } => {
                 handle_reload_config(&mut app, kind, path);
             }
@@ -485,6 +529,24 @@ fn main() {
                     "crisis.spawn.enqueued"
                 );
             }
+            Command::ContainCrisis {
+                faction,
+                crisis_id,
+                effort,
+            } => {
+                handle_contain_crisis(&mut app, faction, crisis_id, effort);
+            }
+            Command::SpawnHerd {
+                species,
+                x,
+                y,
+                biomass,
+            } => {
+                handle_spawn_herd(&mut app, species, UVec2::new(x, y), biomass);
+            }
+            Command::CullHerd { id } => {
+                handle_cull_herd(&mut app, id);
+            }
             Command::SetStartProfile { profile_id } => {
                 handle_set_start_profile(&mut app, profile_id);
             }
@@ -626,6 +688,9 @@ fn main() {
 #[derive(Debug)]
 enum Command {
     Turn(u32),
+    AdvanceTurns {
+        count: u32,
+    },
     ResetMap {
         width: u32,
         height: u32,
@@ -638,6 +703,9 @@ enum Command {
         faction: FactionId,
         orders: FactionOrders,
     },
+    UndoOrder {
+        faction: FactionId,
+    },
     Rollback {
         tick: u64,
     },
@@ -658,6 +726,12 @@ enum Command {
         channel: SupportChannel,
         magnitude: f32,
     },
+    InfluencerAction {
+        id: u32,
+        action: SupportAction,
+        channel: SupportChannel,
+        magnitude: i64,
+    },
     SpawnInfluencer {
         scope: Option<InfluenceScopeKind>,
         generation: Option<GenerationId>,
@@ -686,6 +760,11 @@ enum Command {
         reserve: Option<Scalar>,
         delta: Option<Scalar>,
     },
+    SetKnowledgePosture {
+        faction: FactionId,
+        discovery: u32,
+        posture: KnowledgeSecurityPosture,
+    },
     ReloadConfig {
         kind: ReloadConfigKind,
         path: Option<String>,
@@ -697,6 +776,20 @@ enum Command {
         faction: FactionId,
         archetype_id: String,
     },
+    ContainCrisis {
+        faction: FactionId,
+        crisis_id: u64,
+        effort: i64,
+    },
+    SpawnHerd {
+        species: String,
+        x: u32,
+        y: u32,
+        biomass: f32,
+    },
+    CullHerd {
+        id: String,
+    },
     SetStartProfile {
         profile_id: String,
     },
@@ -781,6 +874,12 @@ enum Command {
     ExportMap {
         path: Option<String>,
     },
+    InspectRegion {
+        x0: u32,
+        y0: u32,
+        x1: u32,
+        y1: u32,
+    },
     /// Boot-idle new game: generate a world on demand (the server boots with none). `seed == 0`
     /// randomizes the map seed (mirrors `ResetMap`); an unknown `profile_id` is rejected. Field 43.
     NewGame {
@@ -792,11 +891,6 @@ enum Command {
     },
 }
 
-enum InfluencerAction {
-    Support,
-    Suppress,
-}
-
 #[derive(Resource, Clone)]
 struct CommandSenderResource(Sender<Command>);
 
@@ -1031,8 +1125,11 @@ const MAX_PROTO_FRAME: usize = 64 * 1024;
 
 /// Starts the command listener on an already-bound listener. Binding happens
 /// up front in `port_alloc::allocate`, so this can no longer panic on a port
-/// conflict.
-fn spawn_command_listener(listener: TcpListener) -> (Receiver<Command>, Sender<Command>) {
+/// conflict. Every connection is gated by its own [`CommandSession`] under `policy`.
+fn spawn_command_listener(
+    listener: TcpListener,
+    policy: HandshakePolicy,
+) -> (Receiver<Command>, Sender<Command>) {
     if let Err(err) = listener.set_nonblocking(true) {
         warn!("Failed to set nonblocking on command listener: {}", err);
     }
@@ -1044,7 +1141,8 @@ fn spawn_command_listener(listener: TcpListener) -> (Receiver<Command>, Sender<C
             Ok((stream, addr)) => {
                 info!("Command client connected: {}", addr);
                 let sender = sender_for_thread.clone();
-                thread::spawn(move || handle_proto_client(stream, sender));
+                let session = CommandSession::new(policy.clone());
+                thread::spawn(move || handle_proto_client(stream, sender, session));
             }
             Err(ref err) if err.kind() == io::ErrorKind::WouldBlock => {
                 thread::sleep(std::time::Duration::from_millis(50));
@@ -1059,7 +1157,10 @@ fn spawn_command_listener(listener: TcpListener) -> (Receiver<Command>, Sender<C
     (receiver, sender)
 }
 
-fn handle_proto_client(stream: TcpStream, sender: Sender<Command>) {
+/// Reads length-prefixed command frames until the client hangs up. Every frame gets a response;
+/// a failed handshake is answered and then drops the connection, while commands sent before a
+/// required handshake are refused one by one.
+fn handle_proto_client(stream: TcpStream, sender: Sender<Command>, mut session: CommandSession) {
     let mut reader = BufReader::new(stream);
     loop {
         let mut len_buf = [0u8; 4];
@@ -1091,21 +1192,47 @@ fn handle_proto_client(stream: TcpStream, sender: Sender<Command>) {
             }
             break;
         }
-        match ProtoCommandEnvelope::decode(&payload) {
-            Ok(envelope) => {
-                if let Some(cmd) = command_from_payload(envelope.payload) {
-                    if sender.send(cmd).is_err() {
-                        break;
+        let mut hang_up = false;
+        let response = match ProtoCommandEnvelope::decode(&payload) {
+            Ok(envelope) => match session.admit(envelope.payload) {
+                Ok(admitted) => {
+                    if let Some(cmd) = admitted.and_then(command_from_payload) {
+                        if sender.send(cmd).is_err() {
+                            break;
+                        }
                     }
+                    CommandResponse::accepted(envelope.correlation_id)
                 }
-            }
+                Err(err) => {
+                    warn!("Proto command refused: {}", err);
+                    hang_up = !matches!(err, HandshakeError::Required { .. });
+                    CommandResponse::rejected(envelope.correlation_id, err.to_string())
+                }
+            },
             Err(err) => {
                 warn!("Proto command decode error: {}", err);
+                CommandResponse::rejected(
+                    ProtoCommandEnvelope::peek_correlation_id(&payload),
+                    err.to_string(),
+                )
             }
+        };
+        // Fire-and-forget senders may already have hung up; the ack is best-effort.
+        let _ = write_command_response(reader.get_mut(), &response);
+        if hang_up {
+            break;
         }
     }
 }
 
+/// Writes `response` as a length-prefixed frame, mirroring the request framing.
+fn write_command_response(stream: &mut TcpStream, response: &CommandResponse) -> io::Result<()> {
+    let bytes = response.encode_to_vec();
+    stream.write_all(&(bytes.len() as u32).to_le_bytes())?;
+    stream.write_all(&bytes)?;
+    stream.flush()
+}
+
 fn start_file_watcher(
     path: PathBuf,
     sender: Sender<Command>,
@@ -1216,6 +1343,93 @@ fn handle_reload_config(
     }
 }
 
+/// **Inspect a map region** (`inspect_region <x0> <y0> <x1> <y1>`): aggregate the latest snapshot
+/// over the rectangle (corners ordered and clamped to the map) and report it to the viewing faction
+/// as a `RegionInspected` feed entry. Touches nothing but the feed; a missing snapshot or an empty
+/// map is refused there instead.
+fn handle_inspect_region(
+    app: &mut bevy::prelude::App,
+    corners: (u32, u32, u32, u32),
+) -> Option<RegionInspection> {
+    let viewer = app
+        .world
+        .get_resource::<ViewerFaction>()
+        .map_or(FactionId(0), |viewer| viewer.0);
+    let grid_size = app.world.resource::<SimulationConfig>().grid_size;
+    let inspection = match app
+        .world
+        .resource::<SnapshotHistory>()
+        .last_snapshot
+        .as_ref()
+    {
+        None => Err(("no_snapshot", "No snapshot has been captured yet.")),
+        Some(snapshot) => inspect_region(snapshot, corners, grid_size)
+            .ok_or(("empty_map", "The map has no tiles.")),
+    };
+    let inspection = match inspection {
+        Ok(inspection) => inspection,
+        Err((reason, detail)) => {
+            warn!(
+                target: "shadow_scale::server",
+                reason,
+                "inspect_region.rejected"
+            );
+            emit_command_failure(app, CommandEventKind::RegionInspected, viewer, detail);
+            return None;
+        }
+    };
+    info!(
+        target: "shadow_scale::server",
+        command = "inspect_region",
+        tick = inspection.tick,
+        min_x = inspection.rect.min.x,
+        min_y = inspection.rect.min.y,
+        max_x = inspection.rect.max.x,
+        max_y = inspection.rect.max.y,
+        tiles = inspection.rect.tile_count(),
+        population = inspection.population,
+        dominant_terrain = ?inspection.dominant_terrain,
+        overlays = ?inspection.overlays,
+        crises = ?inspection.crises,
+        "command.inspect_region.completed"
+    );
+    let overlays = inspection
+        .overlays
+        .iter()
+        .map(|(name, stats)| {
+            format!(
+                "{}={:.3}[{:.3},{:.3}]",
+                name, stats.mean, stats.min, stats.max
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(" ");
+    let tick = app.world.resource::<SimulationTick>().0;
+    push_command_event(
+        app,
+        tick,
+        CommandEventKind::RegionInspected,
+        viewer,
+        format!(
+            "Region ({}, {})-({}, {})",
+            inspection.rect.min.x,
+            inspection.rect.min.y,
+            inspection.rect.max.x,
+            inspection.rect.max.y
+        ),
+        Some(format!(
+            "snapshot_tick={} tiles={} population={} terrain={:?} crises=[{}] {}",
+            inspection.tick,
+            inspection.rect.tile_count(),
+            inspection.population,
+            inspection.dominant_terrain,
+            inspection.crises.join(","),
+            overlays
+        )),
+    );
+    Some(inspection)
+}
+
 /// Write the current world map (terrain snapshot + resolved seed/preset) to disk
 /// as JSON for offline inspection and as a test fixture. Never panics: on any
 /// failure it logs a warning and returns, leaving the simulation untouched.
@@ -1362,6 +1576,8 @@ fn rebuild_world_from_config(
         let mut config_res = new_app.world.resource_mut::<SimulationConfig>();
         *config_res = config;
     }
+    // The catalog loaded at build time was scaled for the env config's map; rescale for this one.
+    load_great_discovery_catalog(&mut new_app.world);
     new_app.insert_resource(SimulationMetrics::default());
     new_app.insert_resource(CommandSenderResource(command_sender.clone()));
     new_app.insert_resource(ConfigWatcherRegistry::default());
@@ -3168,6 +3384,127 @@ fn handle_tame(app: &mut bevy::prelude::App, faction: FactionId, herd_id: String
     );
 }
 
+/// **Debug: seat a herd** (`spawn_herd`). Designer tooling, not a player verb — it writes
+/// `HerdRegistry` directly and refreshes `HerdTelemetry`, so the herd shows in the next snapshot.
+///
+/// Rejects an unknown species (resolved by config key or display name), a tile outside the map, or
+/// a non-positive / non-finite biomass. Debug verbs carry no faction; the feed files them under
+/// faction 0.
+fn handle_spawn_herd(app: &mut bevy::prelude::App, species: String, pos: UVec2, biomass: f32) {
+    let faction = FactionId(0);
+    let fauna = app.world.resource::<FaunaConfigHandle>().get();
+    let Some((key, def)) = fauna.resolve_species(&species) else {
+        warn!(
+            target: "shadow_scale::command",
+            command = "spawn_herd",
+            species = %species,
+            "command.spawn_herd.unknown_species"
+        );
+        emit_command_failure(
+            app,
+            CommandEventKind::HerdSpawned,
+            faction,
+            format!("Unknown fauna species '{}'.", species),
+        );
+        return;
+    };
+    let grid_size = app.world.resource::<SimulationConfig>().grid_size;
+    if pos.x >= grid_size.x || pos.y >= grid_size.y {
+        emit_command_failure(
+            app,
+            CommandEventKind::HerdSpawned,
+            faction,
+            format!(
+                "Tile ({}, {}) is outside the {}x{} map.",
+                pos.x, pos.y, grid_size.x, grid_size.y
+            ),
+        );
+        return;
+    }
+    if !biomass.is_finite() || biomass <= 0.0 {
+        emit_command_failure(
+            app,
+            CommandEventKind::HerdSpawned,
+            faction,
+            format!("Herd biomass must be positive (got {}).", biomass),
+        );
+        return;
+    }
+
+    let (herd_id, label, entries) = {
+        let mut registry = app.world.resource_mut::<HerdRegistry>();
+        let herd_id = registry.spawn_debug(key, def, &fauna, pos, biomass);
+        let label = registry
+            .find(&herd_id)
+            .map(|herd| herd.label.clone())
+            .unwrap_or_else(|| herd_id.clone());
+        (herd_id, label, registry.snapshot_entries())
+    };
+    app.world.resource_mut::<HerdTelemetry>().entries = entries;
+
+    let tick = app.world.resource::<SimulationTick>().0;
+    info!(
+        target: "shadow_scale::command",
+        command = "spawn_herd",
+        herd = %herd_id,
+        species = %key,
+        x = pos.x,
+        y = pos.y,
+        biomass,
+        "command.spawn_herd.spawned"
+    );
+    push_command_event(
+        app,
+        tick,
+        CommandEventKind::HerdSpawned,
+        faction,
+        format!("Spawned {}", label),
+        Some(format!(
+            "herd={} species={} x={} y={} biomass={:.1}",
+            herd_id, key, pos.x, pos.y, biomass
+        )),
+    );
+}
+
+/// **Debug: remove a herd** (`cull_herd`) from `HerdRegistry` by id, refreshing `HerdTelemetry`.
+/// An unknown id is rejected into the feed rather than silently ignored.
+fn handle_cull_herd(app: &mut bevy::prelude::App, herd_id: String) {
+    let faction = FactionId(0);
+    let removed = {
+        let mut registry = app.world.resource_mut::<HerdRegistry>();
+        registry
+            .remove(&herd_id)
+            .map(|herd| (herd, registry.snapshot_entries()))
+    };
+    let Some((herd, entries)) = removed else {
+        emit_command_failure(
+            app,
+            CommandEventKind::HerdCulled,
+            faction,
+            format!("No herd with id '{}'.", herd_id),
+        );
+        return;
+    };
+    app.world.resource_mut::<HerdTelemetry>().entries = entries;
+
+    let tick = app.world.resource::<SimulationTick>().0;
+    info!(
+        target: "shadow_scale::command",
+        command = "cull_herd",
+        herd = %herd_id,
+        biomass = herd.biomass,
+        "command.cull_herd.culled"
+    );
+    push_command_event(
+        app,
+        tick,
+        CommandEventKind::HerdCulled,
+        faction,
+        format!("Culled {}", herd.label),
+        Some(format!("herd={} biomass={:.1}", herd_id, herd.biomass)),
+    );
+}
+
 /// **Answer a pending narrative fork** (The Telling's fork tier).
 ///
 /// The choice's writes land in the `BeatLedger` — declared stance offsets and consequence flags —
@@ -3761,8 +4098,11 @@ fn handle_reload_simulation_config(app: &mut bevy::prelude::App, path: Option<St
                 .cloned()
         });
 
+    // Re-resolve the SIM_DIFFICULTY_PRESET override too, so a reload keeps the preset the server
+    // booted with instead of silently reverting to the file's own.
+    let preset = difficulty_preset_override();
     let (mut new_config, applied_path) = match requested_path {
-        Some(path) => match SimulationConfig::from_file(&path) {
+        Some(path) => match SimulationConfig::from_file_with_preset(&path, preset) {
             Ok(cfg) => (cfg, Some(path)),
             Err(err) => {
                 warn!(
@@ -3773,7 +4113,11 @@ fn handle_reload_simulation_config(app: &mut bevy::prelude::App, path: Option<St
                 return;
             }
         },
-        None => (SimulationConfig::builtin(), None),
+        None => (
+            SimulationConfig::from_json_str_with_preset(BUILTIN_SIMULATION_CONFIG, preset)
+                .expect("builtin simulation config should parse"),
+            None,
+        ),
     };
 
     // Reapply the port base the process ACTUALLY bound (post auto-bump), so a
@@ -3801,6 +4145,12 @@ fn handle_reload_simulation_config(app: &mut bevy::prelude::App, path: Option<St
         history.set_capacity(new_config.snapshot_history_limit.max(1));
     }
 
+    {
+        let mut queue = app.world.resource_mut::<TurnQueue>();
+        queue.set_schedule_horizon(new_config.order_schedule_horizon);
+        queue.set_order_rate_limit(new_config.max_orders_per_turn);
+    }
+
     let watch_path = app
         .world
         .resource::<SimulationConfigMetadata>()
@@ -3836,6 +4186,8 @@ fn handle_reload_simulation_config(app: &mut bevy::prelude::App, path: Option<St
         || new_config.snapshot_bind != current_config.snapshot_bind
         || new_config.snapshot_flat_bind != current_config.snapshot_flat_bind
         || new_config.log_bind != current_config.log_bind
+        || new_config.command_handshake_required != current_config.command_handshake_required
+        || new_config.command_token != current_config.command_token
     {
         warn!(
             target: "shadow_scale::config",
@@ -4208,6 +4560,7 @@ fn handle_reload_crisis_telemetry_config(app: &mut bevy::prelude::App, path: Opt
 fn command_from_payload(payload: ProtoCommandPayload) -> Option<Command> {
     match payload {
         ProtoCommandPayload::Turn { steps } => Some(Command::Turn(steps)),
+        ProtoCommandPayload::AdvanceTurns { count } => Some(Command::AdvanceTurns { count }),
         ProtoCommandPayload::ResetMap { width, height } => {
             Some(Command::ResetMap { width, height })
         }
@@ -4224,6 +4577,9 @@ fn command_from_payload(payload: ProtoCommandPayload) -> Option<Command> {
                 orders: FactionOrders::end_turn(),
             }),
         },
+        ProtoCommandPayload::UndoOrder { faction_id } => Some(Command::UndoOrder {
+            faction: FactionId(faction_id),
+        }),
         ProtoCommandPayload::Rollback { tick } => Some(Command::Rollback { tick }),
         ProtoCommandPayload::AxisBias { axis, value } => Some(Command::AxisBias {
             axis: axis as usize,
@@ -4247,6 +4603,17 @@ fn command_from_payload(payload: ProtoCommandPayload) -> Option<Command> {
                 magnitude,
             })
         }
+        ProtoCommandPayload::InfluencerAction {
+            id,
+            action,
+            channel,
+            magnitude,
+        } => Some(Command::InfluencerAction {
+            id,
+            action,
+            channel: map_support_channel(channel)?,
+            magnitude,
+        }),
         ProtoCommandPayload::SpawnInfluencer { scope, generation } => {
             let generation = generation.map(|value| value as GenerationId);
             Some(Command::SpawnInfluencer { scope, generation })
@@ -4351,6 +4718,27 @@ fn command_from_payload(payload: ProtoCommandPayload) -> Option<Command> {
             faction: FactionId(faction_id),
             archetype_id,
         }),
+        ProtoCommandPayload::ContainCrisis {
+            faction_id,
+            crisis_id,
+            effort,
+        } => Some(Command::ContainCrisis {
+            faction: FactionId(faction_id),
+            crisis_id,
+            effort,
+        }),
+        ProtoCommandPayload::SpawnHerd {
+            species,
+            x,
+            y,
+            biomass,
+        } => Some(Command::SpawnHerd {
+            species,
+            x,
+            y,
+            biomass,
+        }),
+        ProtoCommandPayload::CullHerd { id } => Some(Command::CullHerd { id }),
         ProtoCommandPayload::SetStartProfile { profile_id } => {
             Some(Command::SetStartProfile { profile_id })
         }
@@ -4502,7 +4890,19 @@ fn command_from_payload(payload: ProtoCommandPayload) -> Option<Command> {
             band_entity_bits,
             scope,
         }),
+        ProtoCommandPayload::SetKnowledgePosture {
+            faction,
+            discovery,
+            posture,
+        } => Some(Command::SetKnowledgePosture {
+            faction: FactionId(faction),
+            discovery,
+            posture,
+        }),
         ProtoCommandPayload::ExportMap { path } => Some(Command::ExportMap { path }),
+        ProtoCommandPayload::InspectRegion { x0, y0, x1, y1 } => {
+            Some(Command::InspectRegion { x0, y0, x1, y1 })
+        }
         ProtoCommandPayload::NewGame {
             preset_id,
             width,
@@ -4516,6 +4916,8 @@ fn command_from_payload(payload: ProtoCommandPayload) -> Option<Command> {
             seed,
             profile_id,
         }),
+        // Consumed by the connection's `CommandSession`; never reaches the queue.
+        ProtoCommandPayload::Handshake { .. } => None,
     }
 }
 
@@ -4546,10 +4948,21 @@ fn apply_heat(app: &mut bevy::prelude::App, entity_bits: u64, delta_raw: i64) {
             return;
         }
     };
-    if let Some(mut tile) = app.world.get_mut::<Tile>(entity) {
-        tile.temperature += Scalar::from_raw(delta_raw);
-    } else {
+    let min_delta = app
+        .world
+        .resource::<SimulationConfig>()
+        .tile_change_min_delta;
+    let Some(mut tile) = app.world.get_mut::<Tile>(entity) else {
         warn!("Entity {} not found for heat command", entity_bits);
+        return;
+    };
+    let old = tile.temperature;
+    tile.temperature += Scalar::from_raw(delta_raw);
+    let new = tile.temperature;
+    if let Some(event) =
+        TileChangedEvent::between(entity, TileField::Temperature, old, new, min_delta)
+    {
+        app.world.send_event(event);
     }
 }
 
@@ -4823,7 +5236,13 @@ fn emit_command_failure(
 ) {
     let tick = app.world.resource::<SimulationTick>().0;
     let summary = format!("{} failed", command_kind_display(kind));
-    push_command_event(app, tick, kind, faction, summary, Some(detail.into()));
+    // A rejected order is something the player has to act on, whatever the command's own kind.
+    if let Some(mut log) = app.world.get_resource_mut::<CommandEventLog>() {
+        log.push(
+            CommandEventEntry::new(tick, kind, faction, summary, Some(detail.into()))
+                .with_severity(EventSeverity::Alert),
+        );
+    }
 }
 
 fn command_kind_display(kind: CommandEventKind) -> &'static str {
@@ -4850,6 +5269,14 @@ fn command_kind_display(kind: CommandEventKind) -> &'static str {
         CommandEventKind::ExpeditionArrived => "Expedition arrived",
         CommandEventKind::ExpeditionRecalled => "Expedition recalled",
         CommandEventKind::ExpeditionReturned => "Expedition returned",
+        CommandEventKind::HerdSpawned => "Spawn herd",
+        CommandEventKind::HerdCulled => "Cull herd",
+        CommandEventKind::InfluencerAction => "Influence",
+        CommandEventKind::ContainCrisis => "Contain crisis",
+        CommandEventKind::RegionInspected => "Inspect region",
+        CommandEventKind::TurnsAdvanced => "Advance turns",
+        CommandEventKind::OrderUndone => "Undo order",
+        CommandEventKind::KnowledgePosture => "Set knowledge posture",
     }
 }
 
@@ -4966,7 +5393,67 @@ fn handle_order_submission(
             %f,
             "orders.rejected=duplicate_submission"
         ),
+        Err(SubmitError::RateLimited {
+            faction: f,
+            attempted,
+            used,
+            limit,
+        }) => warn!(
+            target: "shadow_scale::server",
+            %f,
+            attempted,
+            used,
+            limit,
+            "orders.rejected=rate_limited"
+        ),
+    }
+}
+
+/// **Withdraw the latest pending order** (`undo_order <faction>`). Only orders the queue still
+/// holds can be withdrawn; a withdrawn scheduled order drops out of the faction's reserved cost,
+/// and a withdrawn end-turn puts the faction back on the awaiting list. The withdrawn order and
+/// the faction's remaining reserved cost go to the feed as an `OrderUndone` entry; with nothing
+/// pending the faction gets an `OrderUndone` failure instead.
+fn handle_undo_order(app: &mut bevy::prelude::App, faction: FactionId) -> Option<Order> {
+    let (undone, reserved) = {
+        let mut queue = app.world.resource_mut::<TurnQueue>();
+        let undone = queue.undo_last(faction);
+        (undone, queue.reserved_cost(faction))
+    };
+    match &undone {
+        Some(order) => {
+            info!(
+                target: "shadow_scale::server",
+                %faction,
+                ?order,
+                reserved = reserved.to_f32(),
+                "orders.undone"
+            );
+            let tick = app.world.resource::<SimulationTick>().0;
+            push_command_event(
+                app,
+                tick,
+                CommandEventKind::OrderUndone,
+                faction,
+                format!("Withdrew {order:?}"),
+                Some(format!("order={order:?} reserved={:.2}", reserved.to_f32())),
+            );
+        }
+        None => {
+            warn!(
+                target: "shadow_scale::server",
+                %faction,
+                "orders.undo_rejected=nothing_pending"
+            );
+            emit_command_failure(
+                app,
+                CommandEventKind::OrderUndone,
+                faction,
+                "no pending order to withdraw",
+            );
+        }
     }
+    undone
 }
 
 fn handle_axis_bias(
@@ -5071,35 +5558,236 @@ fn handle_influencer_channel_support(
     );
 }
 
-fn handle_influencer_spawn(
+/// The `influence` verb: back or suppress influencer `id` through `channel`. `magnitude` is in
+/// hundredths; anything outside `1..=INFLUENCER_ACTION_MAGNITUDE_MAX` or an unknown id is refused
+/// into the command feed.
+fn handle_influencer_action(
     app: &mut bevy::prelude::App,
-    scope: Option<InfluenceScopeKind>,
-    generation: Option<GenerationId>,
+    id: u32,
+    action: SupportAction,
+    channel: SupportChannel,
+    magnitude: i64,
     snapshot_server_bin: &SnapshotServer,
     snapshot_server_flat: &SnapshotServer,
 ) {
-    let registry_snapshot = app.world.resource::<GenerationRegistry>().clone();
-    let spawned = {
-        let mut roster = app.world.resource_mut::<InfluentialRoster>();
-        roster.force_spawn(scope, generation, &registry_snapshot)
+    let faction = FactionId(0);
+    let verb = match action {
+        SupportAction::Support => "support",
+        SupportAction::Suppress => "suppress",
     };
-
-    let Some(new_id) = spawned else {
+    if !(1..=INFLUENCER_ACTION_MAGNITUDE_MAX).contains(&magnitude) {
         warn!(
             target: "shadow_scale::server",
-            scope = ?scope,
-            generation = ?generation,
-            "influencer.spawn.rejected"
+            id,
+            magnitude,
+            "influencer.action.rejected=magnitude_out_of_range"
+        );
+        emit_command_failure(
+            app,
+            CommandEventKind::InfluencerAction,
+            faction,
+            format!(
+                "Magnitude {} is outside 1..={}.",
+                magnitude, INFLUENCER_ACTION_MAGNITUDE_MAX
+            ),
         );
         return;
+    }
+    let amount = magnitude as f32 / INFLUENCER_ACTION_MAGNITUDE_SCALE as f32;
+    let applied = {
+        let mut roster = app.world.resource_mut::<InfluentialRoster>();
+        roster.apply_channel_action(id, action, channel, Scalar::from_f32(amount))
     };
+    if !applied {
+        warn!(
+            target: "shadow_scale::server",
+            id,
+            channel = channel.as_str(),
+            "influencer.action.rejected=unknown_id"
+        );
+        emit_command_failure(
+            app,
+            CommandEventKind::InfluencerAction,
+            faction,
+            format!("No influencer with id {}.", id),
+        );
+        return;
+    }
 
     broadcast_influencer_update(app, snapshot_server_bin, snapshot_server_flat);
 
-    let label = {
-        let roster = app.world.resource::<InfluentialRoster>();
-        roster
-            .states()
+    let tick = app.world.resource::<SimulationTick>().0;
+    info!(
+        target: "shadow_scale::server",
+        id,
+        action = verb,
+        channel = channel.as_str(),
+        magnitude = amount,
+        "influencer.action.applied"
+    );
+    push_command_event(
+        app,
+        tick,
+        CommandEventKind::InfluencerAction,
+        faction,
+        format!("Influencer {} ({} via {})", id, verb, channel.as_str()),
+        Some(format!(
+            "id={} action={} channel={} magnitude={:.2}",
+            id,
+            verb,
+            channel.as_str(),
+            amount
+        )),
+    );
+}
+
+/// The `contain_crisis` verb: charge the issuing faction `effort × cost_per_effort` of the
+/// configured stockpile item and queue the containment for the next turn. An unknown crisis, an
+/// effort outside `1..=CONTAIN_CRISIS_EFFORT_MAX`, or a stockpile that cannot cover the cost is
+/// refused into the command feed against the issuer and charges nothing.
+fn handle_contain_crisis(
+    app: &mut bevy::prelude::App,
+    faction: FactionId,
+    crisis_id: u64,
+    effort: i64,
+) {
+    if app
+        .world
+        .resource::<ActiveCrisisLedger>()
+        .faction_of(crisis_id)
+        .is_none()
+    {
+        warn!(
+            target: "shadow_scale::server",
+            crisis_id,
+            faction = %faction.0,
+            "crisis.containment.rejected=unknown_crisis"
+        );
+        emit_command_failure(
+            app,
+            CommandEventKind::ContainCrisis,
+            faction,
+            format!("No active crisis with id {}.", crisis_id),
+        );
+        return;
+    };
+    if !(1..=CONTAIN_CRISIS_EFFORT_MAX).contains(&effort) {
+        warn!(
+            target: "shadow_scale::server",
+            crisis_id,
+            effort,
+            "crisis.containment.rejected=effort_out_of_range"
+        );
+        emit_command_failure(
+            app,
+            CommandEventKind::ContainCrisis,
+            faction,
+            format!(
+                "Effort {} is outside 1..={}.",
+                effort, CONTAIN_CRISIS_EFFORT_MAX
+            ),
+        );
+        return;
+    }
+    let config = app
+        .world
+        .resource::<SimulationConfig>()
+        .crisis_containment
+        .clone();
+    let cost = config.cost(effort);
+    let available = app
+        .world
+        .resource::<FactionInventory>()
+        .stockpile(faction)
+        .and_then(|stockpile| stockpile.get(&config.cost_item))
+        .copied()
+        .unwrap_or(0);
+    if available < cost {
+        warn!(
+            target: "shadow_scale::server",
+            crisis_id,
+            effort,
+            cost,
+            available,
+            "crisis.containment.rejected=insufficient_stockpile"
+        );
+        emit_command_failure(
+            app,
+            CommandEventKind::ContainCrisis,
+            faction,
+            format!(
+                "Containment needs {} {} ({} in stock).",
+                cost, config.cost_item, available
+            ),
+        );
+        return;
+    }
+    let taken = app.world.resource_mut::<FactionInventory>().take_stockpile(
+        faction,
+        &config.cost_item,
+        cost,
+    );
+    app.world
+        .resource_mut::<PendingCrisisContainment>()
+        .push(CrisisContainmentOrder {
+            faction,
+            crisis_id,
+            effort,
+            cost_item: config.cost_item.clone(),
+            cost: taken,
+        });
+
+    let tick = app.world.resource::<SimulationTick>().0;
+    info!(
+        target: "shadow_scale::server",
+        crisis_id,
+        faction = %faction.0,
+        effort,
+        cost,
+        "crisis.containment.enqueued"
+    );
+    push_command_event(
+        app,
+        tick,
+        CommandEventKind::ContainCrisis,
+        faction,
+        format!("Contain crisis {} (effort {})", crisis_id, effort),
+        Some(format!(
+            "crisis_id={} effort={} cost={} {}",
+            crisis_id, effort, cost, config.cost_item
+        )),
+    );
+}
+
+fn handle_influencer_spawn(
+    app: &mut bevy::prelude::App,
+    scope: Option<InfluenceScopeKind>,
+    generation: Option<GenerationId>,
+    snapshot_server_bin: &SnapshotServer,
+    snapshot_server_flat: &SnapshotServer,
+) {
+    let registry_snapshot = app.world.resource::<GenerationRegistry>().clone();
+    let spawned = {
+        let mut roster = app.world.resource_mut::<InfluentialRoster>();
+        roster.force_spawn(scope, generation, &registry_snapshot)
+    };
+
+    let Some(new_id) = spawned else {
+        warn!(
+            target: "shadow_scale::server",
+            scope = ?scope,
+            generation = ?generation,
+            "influencer.spawn.rejected"
+        );
+        return;
+    };
+
+    broadcast_influencer_update(app, snapshot_server_bin, snapshot_server_flat);
+
+    let label = {
+        let roster = app.world.resource::<InfluentialRoster>();
+        roster
+            .states()
             .into_iter()
             .find(|state| state.id == new_id)
             .map(|state| state.name)
@@ -5121,10 +5809,10 @@ fn broadcast_influencer_update(
     snapshot_server_bin: &SnapshotServer,
     snapshot_server_flat: &SnapshotServer,
 ) {
-    let (states, sentiment_totals, logistics_total, morale_total, power_total) = {
+    let ((states, latent), sentiment_totals, logistics_total, morale_total, power_total) = {
         let roster = app.world.resource::<InfluentialRoster>();
         (
-            roster.states(),
+            roster.snapshot_states(),
             roster.sentiment_totals(),
             roster.logistics_total(),
             roster.morale_total(),
@@ -5155,7 +5843,7 @@ fn broadcast_influencer_update(
 
     let (influencer_delta, bias_delta) = {
         let mut history = app.world.resource_mut::<SnapshotHistory>();
-        let influencer_delta = history.update_influencers(states);
+        let influencer_delta = history.update_influencers(states, latent);
         let bias_delta = history.update_axis_bias(bias_state);
         (influencer_delta, bias_delta)
     };
@@ -5194,7 +5882,7 @@ fn handle_influencer_command(
     app: &mut bevy::prelude::App,
     id: u32,
     magnitude: f32,
-    action: InfluencerAction,
+    action: SupportAction,
     snapshot_server_bin: &SnapshotServer,
     snapshot_server_flat: &SnapshotServer,
 ) {
@@ -5204,8 +5892,8 @@ fn handle_influencer_command(
     let applied = {
         let mut roster = app.world.resource_mut::<InfluentialRoster>();
         match action {
-            InfluencerAction::Support => roster.apply_support(id, scalar_amount),
-            InfluencerAction::Suppress => roster.apply_suppress(id, scalar_amount),
+            SupportAction::Support => roster.apply_support(id, scalar_amount),
+            SupportAction::Suppress => roster.apply_suppress(id, scalar_amount),
         }
     };
 
@@ -5222,13 +5910,13 @@ fn handle_influencer_command(
     broadcast_influencer_update(app, snapshot_server_bin, snapshot_server_flat);
 
     match action {
-        InfluencerAction::Support => info!(
+        SupportAction::Support => info!(
             target: "shadow_scale::server",
             id,
             magnitude = clamped,
             "influencer.support.applied"
         ),
-        InfluencerAction::Suppress => info!(
+        SupportAction::Suppress => info!(
             target: "shadow_scale::server",
             id,
             magnitude = clamped,
@@ -5408,6 +6096,57 @@ fn handle_update_counter_intel_policy(
     );
 }
 
+/// **Set a discovery's security posture** (`knowledge_posture <faction> <discovery> <posture>`).
+/// The faction's counter-intel reserve must cover one turn of the new posture's upkeep; the
+/// change takes effect from the next leak tick. A refusal reaches the faction's feed as a
+/// `KnowledgePosture` failure.
+fn handle_set_knowledge_posture(
+    app: &mut bevy::prelude::App,
+    faction: FactionId,
+    discovery: u32,
+    posture: KnowledgeSecurityPosture,
+) -> Result<KnowledgeSecurityPosture, PostureChangeError> {
+    let tick = app.world.resource::<SimulationTick>().0;
+    let reserve = app
+        .world
+        .resource::<CounterIntelBudgets>()
+        .available(faction);
+    let (upkeep, result) = {
+        let mut ledger = app.world.resource_mut::<KnowledgeLedger>();
+        let upkeep = ledger.config().posture_upkeep(posture);
+        let result = ledger.set_security_posture(faction, discovery, posture, reserve, tick);
+        (upkeep, result)
+    };
+    match &result {
+        Ok(previous) => info!(
+            target: "shadow_scale::espionage",
+            %faction,
+            discovery,
+            ?previous,
+            ?posture,
+            upkeep = upkeep.to_f32(),
+            "knowledge.posture.updated"
+        ),
+        Err(err) => {
+            warn!(
+                target: "shadow_scale::espionage",
+                %faction,
+                discovery,
+                ?posture,
+                error = %err,
+                "knowledge.posture.rejected"
+            );
+            emit_command_failure(
+                app,
+                CommandEventKind::KnowledgePosture,
+                faction,
+                err.to_string(),
+            );
+        }
+    }
+    result
+}
+
 fn handle_adjust_counter_intel_budget(
     app: &mut bevy::prelude::App,
     faction: FactionId,
@@ -5487,9 +6226,12 @@ fn handle_queue_espionage_mission(app: &mut bevy::prelude::App, mut params: Queu
                         }
                     };
 
-                    let Some(handle) =
-                        pick_best_agent_for_mission(&roster, queued_params.owner, template)
-                    else {
+                    let Some(handle) = pick_best_agent_for_mission(
+                        &roster,
+                        catalog,
+                        queued_params.owner,
+                        template,
+                    ) else {
                         return Err(QueueMissionError::NoAgentAvailable {
                             faction: queued_params.owner,
                         });
@@ -5539,6 +6281,7 @@ fn handle_queue_espionage_mission(app: &mut bevy::prelude::App, mut params: Queu
 
 fn pick_best_agent_for_mission(
     roster: &EspionageRoster,
+    catalog: &EspionageCatalog,
     faction: FactionId,
     template: &EspionageMissionTemplate,
 ) -> Option<EspionageAgentHandle> {
@@ -5549,7 +6292,7 @@ fn pick_best_agent_for_mission(
             continue;
         }
 
-        let score = match template.kind {
+        let stat_score = match template.kind {
             EspionageMissionKind::Probe => {
                 agent.stealth.to_f32() * template.stealth_weight.to_f32()
                     + agent.recon.to_f32() * template.recon_weight.to_f32()
@@ -5558,6 +6301,10 @@ fn pick_best_agent_for_mission(
                 agent.counter_intel.to_f32() * template.counter_intel_weight.to_f32()
             }
         };
+        let score = stat_score
+            + agent
+                .experience_bonus(catalog.config().agent_experience())
+                .to_f32();
 
         let is_better = match &best {
             Some((_, best_score)) => score > *best_score,
@@ -5572,12 +6319,123 @@ fn pick_best_agent_for_mission(
     best.map(|(handle, _)| handle)
 }
 
+/// Submits end-turn orders for every faction the queue is still waiting on, so the next
+/// `resolve_ready_turn` never stalls (the `turn` / `advance` commands drive all factions).
+fn auto_ready_awaiting_factions(app: &mut bevy::prelude::App) {
+    let mut queue = app.world.resource_mut::<TurnQueue>();
+    let awaiting = queue.awaiting();
+    for faction in &awaiting {
+        info!(
+            target: "shadow_scale::server",
+            %faction,
+            "orders.auto_generated=end_turn"
+        );
+    }
+    queue.force_submit_all(|_| FactionOrders::end_turn());
+}
+
+/// What one `advance` command did: how many of the requested turns actually resolved, and why it
+/// stopped short if it did.
+#[derive(Debug, Clone)]
+struct AdvanceTurnsSummary {
+    requested: u32,
+    resolved: u32,
+    /// `requested` exceeded `SimulationConfig::max_advance_turns` and was clamped to it.
+    capped: bool,
+    /// Live tick after the last resolved turn.
+    final_tick: u64,
+    /// Set when a victory was declared; the advance stops on that turn.
+    winner: Option<VictoryResult>,
+}
+
+/// **Advance several turns atomically** (`advance <count>`): each turn auto-readies every faction
+/// and resolves exactly as `turn` does, but the count is clamped to
+/// `SimulationConfig::max_advance_turns` and the run stops on the turn a winner is declared. The
+/// summary is reported to the viewing faction as a `TurnsAdvanced` feed entry.
+fn handle_advance_turns(
+    app: &mut bevy::prelude::App,
+    requested: u32,
+    snapshot_server_bin: &SnapshotServer,
+    snapshot_server_flat: &SnapshotServer,
+) -> AdvanceTurnsSummary {
+    let cap = app
+        .world
+        .resource::<SimulationConfig>()
+        .max_advance_turns
+        .max(1);
+    let target = requested.min(cap);
+    let mut resolved = 0u32;
+    let mut winner = None;
+    while resolved < target {
+        auto_ready_awaiting_factions(app);
+        let Some(report) = resolve_ready_turn(app, snapshot_server_bin, snapshot_server_flat)
+        else {
+            break;
+        };
+        resolved += 1;
+        if report.winner.is_some() {
+            winner = report.winner;
+            break;
+        }
+    }
+
+    let summary = AdvanceTurnsSummary {
+        requested,
+        resolved,
+        capped: requested > cap,
+        final_tick: app.world.resource::<SimulationTick>().0,
+        winner,
+    };
+    info!(
+        target: "shadow_scale::server",
+        command = "advance",
+        requested = summary.requested,
+        resolved = summary.resolved,
+        capped = summary.capped,
+        final_tick = summary.final_tick,
+        winner = ?summary.winner,
+        "command.advance_turns.completed"
+    );
+    let viewer = app
+        .world
+        .get_resource::<ViewerFaction>()
+        .map_or(FactionId(0), |viewer| viewer.0);
+    let mut detail = format!(
+        "requested={} resolved={} final_tick={}",
+        summary.requested, summary.resolved, summary.final_tick
+    );
+    if summary.capped {
+        detail.push_str(&format!(" capped_at={}", cap));
+    }
+    if let Some(winner) = &summary.winner {
+        detail.push_str(&format!(
+            " winner={} faction={} tick={}",
+            winner.mode.0, winner.faction.0, winner.tick
+        ));
+    }
+    push_command_event(
+        app,
+        summary.final_tick,
+        CommandEventKind::TurnsAdvanced,
+        viewer,
+        format!(
+            "Advanced {} of {} turns",
+            summary.resolved, summary.requested
+        ),
+        Some(detail),
+    );
+    summary
+}
+
+/// Resolves one turn if every faction is ready, returning its report; `None` when the queue is
+/// still awaiting orders and nothing ran.
 fn resolve_ready_turn(
     app: &mut bevy::prelude::App,
     snapshot_server_bin: &SnapshotServer,
     snapshot_server_flat: &SnapshotServer,
-) {
+) -> Option<TurnReport> {
     let turn_start = std::time::Instant::now();
+    let world_seed = app.world.resource::<SimulationConfig>().map_seed;
     let ready_orders = {
         let mut queue = app.world.resource_mut::<TurnQueue>();
         if !queue.is_ready() {
@@ -5586,21 +6444,27 @@ fn resolve_ready_turn(
                 awaiting = ?queue.awaiting(),
                 "turn.resolve_skipped=awaiting_orders"
             );
-            return;
+            return None;
         }
-        queue.drain_ready_orders()
+        queue.drain_ready_orders(world_seed)
     };
 
     apply_orders(&ready_orders);
-    run_turn(app);
+    let report = run_turn(app);
 
     {
         let mut queue = app.world.resource_mut::<TurnQueue>();
         queue.advance_turn();
     }
 
+    // Under a coarser `snapshot_every_n_ticks` cadence the turn may not have captured; only
+    // broadcast when the latest ring entry is this turn's, so subscribers never see a stale delta
+    // replayed.
+    let current_tick = app.world.resource::<SimulationTick>().0;
     let history = app.world.resource::<SnapshotHistory>();
-    broadcast_latest(snapshot_server_bin, snapshot_server_flat, history);
+    if history.latest_tick() == Some(current_tick) {
+        broadcast_latest(snapshot_server_bin, snapshot_server_flat, history);
+    }
 
     let metrics = app.world.resource::<SimulationMetrics>();
     let duration_ms = turn_start.elapsed().as_secs_f64() * 1000.0;
@@ -5614,6 +6478,7 @@ fn resolve_ready_turn(
         duration_ms,
         "turn.completed"
     );
+    Some(report)
 }
 
 fn apply_orders(submissions: &[(FactionId, FactionOrders)]) {
@@ -5677,11 +6542,13 @@ fn handle_rollback(
 mod tests {
     use super::*;
     use bevy::math::UVec2;
+    use sim_runtime::COMMAND_PROTOCOL_VERSION;
     // The ladder's knowledge ids are named only by the tests now: the handlers resolve their gate
     // off the rung record (`unlock_discovery_id`), never a hard-coded id.
     use core_sim::{
-        build_headless_app, ForagePatch, CULTIVATION_DISCOVERY_ID, HERDING_DISCOVERY_ID,
-        PENNING_DISCOVERY_ID, RUNG_COMPLETE, SEED_SELECTION_DISCOVERY_ID, SITE_ACCEPTED,
+        build_headless_app, ForagePatch, CULTIVATION_DISCOVERY_ID, DEBUG_HERD_ID_PREFIX,
+        HERDING_DISCOVERY_ID, PENNING_DISCOVERY_ID, RUNG_COMPLETE, SEED_SELECTION_DISCOVERY_ID,
+        SITE_ACCEPTED,
     };
 
     /// Insert a **Thriving, wild** patch — a valid Cultivate target (there is no early claim any
@@ -5744,7 +6611,78 @@ mod tests {
     /// world-build path's broadcast without a real client.
     fn loopback_snapshot_server() -> SnapshotServer {
         let listener = std::net::TcpListener::bind("127.0.0.1:0").expect("bind loopback");
-        start_snapshot_server(listener)
+        start_snapshot_server(listener, 0)
+    }
+
+    /// Writes one command frame and reads the listener's response to it.
+    fn exchange(
+        stream: &mut TcpStream,
+        payload: ProtoCommandPayload,
+        correlation_id: u64,
+    ) -> CommandResponse {
+        let bytes = ProtoCommandEnvelope {
+            payload,
+            correlation_id: Some(correlation_id),
+        }
+        .encode_to_vec()
+        .expect("envelope encodes");
+        stream
+            .write_all(&(bytes.len() as u32).to_le_bytes())
+            .expect("write length");
+        stream.write_all(&bytes).expect("write frame");
+        let mut len_buf = [0u8; 4];
+        stream.read_exact(&mut len_buf).expect("response length");
+        let mut frame = vec![0u8; u32::from_le_bytes(len_buf) as usize];
+        stream.read_exact(&mut frame).expect("response frame");
+        CommandResponse::decode(&frame).expect("response decodes")
+    }
+
+    fn handshake(protocol_version: u32) -> ProtoCommandPayload {
+        ProtoCommandPayload::Handshake {
+            protocol_version,
+            token: None,
+        }
+    }
+
+    /// With the handshake required, a mismatched version is refused and the connection dropped,
+    /// commands before the handshake are refused, and a correct handshake lets commands through.
+    #[test]
+    fn command_listener_gates_commands_on_the_handshake() {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("bind loopback");
+        let addr = listener.local_addr().expect("listener addr");
+        let (commands, _sender) = spawn_command_listener(
+            listener,
+            HandshakePolicy {
+                required: true,
+                token: None,
+            },
+        );
+
+        let mut stream = TcpStream::connect(addr).expect("connect");
+        let response = exchange(&mut stream, handshake(COMMAND_PROTOCOL_VERSION + 1), 1);
+        assert!(!response.accepted);
+        assert_eq!(response.correlation_id, Some(1));
+        let error = response.error.unwrap_or_default();
+        assert!(error.contains("protocol version mismatch"), "{error}");
+        let mut rest = Vec::new();
+        stream.read_to_end(&mut rest).expect("server hangs up");
+        assert!(rest.is_empty());
+
+        let mut stream = TcpStream::connect(addr).expect("connect");
+        let response = exchange(&mut stream, ProtoCommandPayload::Turn { steps: 2 }, 2);
+        assert!(!response.accepted, "commands wait for the handshake");
+        assert_eq!(response.correlation_id, Some(2));
+        assert!(exchange(&mut stream, handshake(COMMAND_PROTOCOL_VERSION), 3).accepted);
+        assert!(exchange(&mut stream, ProtoCommandPayload::Turn { steps: 2 }, 4).accepted);
+
+        let command = commands
+            .recv_timeout(Duration::from_secs(2))
+            .expect("admitted command queued");
+        assert!(matches!(command, Command::Turn(2)), "{command:?}");
+        assert!(
+            commands.try_recv().is_err(),
+            "neither the handshake nor the refused command is queued"
+        );
     }
 
     /// Boot-idle + `new_game`: the server boots with no world (Startup never ran), `new_game` builds
@@ -5874,6 +6812,370 @@ mod tests {
         );
     }
 
+    /// `inspect_region` decodes off the wire, clamps an inverted, oversized rectangle to the map and
+    /// reports the whole world's head-count into the feed when it covers every tile; with no
+    /// snapshot yet it is refused there instead.
+    #[test]
+    fn inspect_region_clamps_to_the_map_and_aggregates_the_snapshot() {
+        let mut app = build_headless_app();
+        app.world
+            .insert_resource(CommandSenderResource(unbounded::<Command>().0));
+        let bin = loopback_snapshot_server();
+        let flat = loopback_snapshot_server();
+        let mut world_active = false;
+        let mut world_epoch: u32 = 0;
+        let inspected = |app: &bevy::prelude::App, severity: EventSeverity| {
+            app.world
+                .resource::<CommandEventLog>()
+                .iter()
+                .filter(|entry| {
+                    matches!(entry.kind, CommandEventKind::RegionInspected)
+                        && entry.severity == severity
+                })
+                .map(|entry| entry.detail.clone().unwrap_or_default())
+                .last()
+        };
+        assert!(
+            handle_inspect_region(&mut app, (0, 0, 1, 1)).is_none(),
+            "no snapshot before a world exists"
+        );
+        assert!(inspected(&app, EventSeverity::Alert).is_some());
+        handle_new_game(
+            &mut app,
+            &mut world_active,
+            &mut world_epoch,
+            "earthlike".to_string(),
+            48,
+            32,
+            7,
+            "late_forager_tribe".to_string(),
+            &bin,
+            &flat,
+        );
+        assert!(world_active);
+
+        let payload = sim_runtime::parse_command_line("inspect_region 9999 9999 0 0")
+            .expect("inspect_region parses");
+        let Some(Command::InspectRegion { x0, y0, x1, y1 }) = command_from_payload(payload) else {
+            panic!("inspect_region maps to its server command");
+        };
+        let inspection =
+            handle_inspect_region(&mut app, (x0, y0, x1, y1)).expect("region inspects");
+        assert_eq!(inspection.rect.min, bevy::math::UVec2::ZERO);
+        assert_eq!(inspection.rect.max, bevy::math::UVec2::new(47, 31));
+
+        let history = app.world.resource::<SnapshotHistory>();
+        let snapshot = history.last_snapshot.as_ref().expect("snapshot captured");
+        let everyone: u64 = snapshot
+            .populations
+            .iter()
+            .map(|cohort| u64::from(cohort.size))
+            .sum();
+        assert_eq!(inspection.population, everyone);
+        assert_eq!(inspection.tick, snapshot.header.tick);
+        assert!(inspection.dominant_terrain.is_some());
+        let detail = inspected(&app, EventSeverity::Routine).expect("inspection reaches the feed");
+        assert!(
+            detail.contains(&format!("population={everyone}")),
+            "{detail}"
+        );
+    }
+
+    /// `undo_order` decodes off the wire and withdraws the faction's end-turn, so the queue waits on
+    /// it again; the feed reports the withdrawal, and a second undo with nothing pending fails.
+    #[test]
+    fn undo_order_command_withdraws_the_pending_submission() {
+        let mut app = build_headless_app();
+        let faction = FactionId(0);
+        app.world
+            .resource_mut::<TurnQueue>()
+            .submit_orders(faction, FactionOrders::end_turn())
+            .expect("end turn accepted");
+
+        let payload = sim_runtime::parse_command_line("undo 0").expect("undo parses");
+        let Some(Command::UndoOrder { faction: parsed }) = command_from_payload(payload) else {
+            panic!("undo_order maps to its server command");
+        };
+        assert!(matches!(
+            handle_undo_order(&mut app, parsed),
+            Some(Order::EndTurn)
+        ));
+        assert!(app
+            .world
+            .resource::<TurnQueue>()
+            .awaiting()
+            .contains(&faction));
+        assert!(handle_undo_order(&mut app, faction).is_none());
+
+        let log = app.world.resource::<CommandEventLog>();
+        let entries: Vec<_> = log
+            .iter()
+            .filter(|entry| matches!(entry.kind, CommandEventKind::OrderUndone))
+            .collect();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].faction, faction);
+        assert_eq!(
+            entries[0].detail.as_deref(),
+            Some("order=EndTurn reserved=0.00")
+        );
+        assert_eq!(entries[1].severity, EventSeverity::Alert);
+        assert_eq!(
+            entries[1].detail.as_deref(),
+            Some("no pending order to withdraw")
+        );
+    }
+
+    /// `knowledge_posture` decodes off the wire, swaps the entry's posture when the reserve covers
+    /// the upkeep, and rejects unknown entries and unaffordable postures.
+    #[test]
+    fn knowledge_posture_command_updates_the_ledger_entry() {
+        let mut app = build_headless_app();
+        let faction = FactionId(0);
+        {
+            let mut ledger = app.world.resource_mut::<KnowledgeLedger>();
+            let entry = core_sim::KnowledgeLedgerEntry::new(faction, 11, ledger.config().as_ref());
+            ledger.upsert_entry(entry);
+        }
+
+        let payload = sim_runtime::parse_command_line("knowledge_posture 0 11 black_vault")
+            .expect("knowledge_posture parses");
+        let Some(Command::SetKnowledgePosture {
+            faction: parsed_faction,
+            discovery,
+            posture,
+        }) = command_from_payload(payload)
+        else {
+            panic!("knowledge_posture maps to its server command");
+        };
+        assert_eq!(
+            handle_set_knowledge_posture(&mut app, parsed_faction, discovery, posture),
+            Ok(KnowledgeSecurityPosture::Standard)
+        );
+        let ledger = app.world.resource::<KnowledgeLedger>();
+        assert_eq!(
+            ledger.entry(faction, 11).unwrap().security_posture,
+            KnowledgeSecurityPosture::BlackVault
+        );
+
+        assert!(matches!(
+            handle_set_knowledge_posture(&mut app, faction, 12, posture),
+            Err(PostureChangeError::UnknownEntry { .. })
+        ));
+        let budget_config = app
+            .world
+            .resource::<EspionageCatalog>()
+            .config()
+            .counter_intel_budget()
+            .clone();
+        app.world.resource_mut::<CounterIntelBudgets>().set_reserve(
+            faction,
+            Scalar::zero(),
+            &budget_config,
+        );
+        assert!(matches!(
+            handle_set_knowledge_posture(&mut app, faction, 11, KnowledgeSecurityPosture::Hardened),
+            Err(PostureChangeError::Unaffordable { .. })
+        ));
+
+        let refusals: Vec<_> = app
+            .world
+            .resource::<CommandEventLog>()
+            .iter()
+            .filter(|entry| matches!(entry.kind, CommandEventKind::KnowledgePosture))
+            .map(|entry| (entry.faction, entry.severity))
+            .collect();
+        assert_eq!(
+            refusals,
+            vec![(faction, EventSeverity::Alert); 2],
+            "both refusals reach the faction's feed"
+        );
+    }
+
+    /// `advance <count>` resolves exactly `count` turns, and a request past `max_advance_turns` is
+    /// clamped to it and flagged as capped; each summary reaches the feed. Victory is pushed out of
+    /// reach so no run stops early.
+    #[test]
+    fn advance_turns_resolves_count_and_clamps_to_the_cap() {
+        let mut app = build_headless_app();
+        app.world
+            .insert_resource(CommandSenderResource(unbounded::<Command>().0));
+        let bin = loopback_snapshot_server();
+        let flat = loopback_snapshot_server();
+        let mut world_active = false;
+        let mut world_epoch: u32 = 0;
+        handle_new_game(
+            &mut app,
+            &mut world_active,
+            &mut world_epoch,
+            "earthlike".to_string(),
+            48,
+            32,
+            7,
+            "late_forager_tribe".to_string(),
+            &bin,
+            &flat,
+        );
+        assert!(world_active);
+        {
+            let mut config = app.world.resource_mut::<SimulationConfig>();
+            config.max_advance_turns = 4;
+            config.victory_threshold_scale = 1.0e6;
+        }
+        let advanced = |app: &bevy::prelude::App| {
+            app.world
+                .resource::<CommandEventLog>()
+                .iter()
+                .filter(|entry| matches!(entry.kind, CommandEventKind::TurnsAdvanced))
+                .map(|entry| entry.detail.clone().unwrap_or_default())
+                .last()
+        };
+
+        let tick_before = app.world.resource::<SimulationTick>().0;
+        let summary = handle_advance_turns(&mut app, 3, &bin, &flat);
+        assert!(!summary.capped);
+        assert!(summary.winner.is_none());
+        assert_eq!(summary.resolved, 3, "no victory, so every turn resolves");
+        assert_eq!(summary.final_tick, app.world.resource::<SimulationTick>().0);
+        assert_eq!(
+            summary.final_tick - tick_before,
+            3,
+            "the tick advances once per resolved turn"
+        );
+        assert_eq!(
+            advanced(&app).as_deref(),
+            Some(format!("requested=3 resolved=3 final_tick={}", summary.final_tick).as_str())
+        );
+
+        let tick_before = summary.final_tick;
+        let summary = handle_advance_turns(&mut app, 50, &bin, &flat);
+        assert_eq!(summary.requested, 50);
+        assert!(summary.capped, "50 turns exceeds the cap of 4");
+        assert!(summary.winner.is_none());
+        assert_eq!(
+            summary.resolved, 4,
+            "a capped advance resolves exactly the cap"
+        );
+        assert_eq!(summary.final_tick - tick_before, 4);
+        assert_eq!(
+            advanced(&app).as_deref(),
+            Some(
+                format!(
+                    "requested=50 resolved=4 final_tick={} capped_at=4",
+                    summary.final_tick
+                )
+                .as_str()
+            )
+        );
+    }
+
+    /// `spawn_herd` seats a herd the next snapshot reports; unknown species, off-map tiles and
+    /// non-positive biomass are refused into the feed; `cull_herd` removes it again.
+    #[test]
+    fn spawn_herd_appears_in_next_snapshot_and_cull_removes_it() {
+        let mut app = build_headless_app();
+        app.world
+            .insert_resource(CommandSenderResource(unbounded::<Command>().0));
+        let bin = loopback_snapshot_server();
+        let flat = loopback_snapshot_server();
+        let mut world_active = false;
+        let mut world_epoch: u32 = 0;
+        handle_new_game(
+            &mut app,
+            &mut world_active,
+            &mut world_epoch,
+            "earthlike".to_string(),
+            48,
+            32,
+            7,
+            "late_forager_tribe".to_string(),
+            &bin,
+            &flat,
+        );
+        assert!(world_active);
+
+        let fauna = app.world.resource::<FaunaConfigHandle>().get();
+        let key = fauna
+            .species
+            .iter()
+            .filter(|(_, def)| def.size_class != core_sim::SizeClass::Migratory)
+            .map(|(key, _)| key.clone())
+            .min()
+            .expect("fauna config carries a game species");
+        let herds_before = app.world.resource::<HerdRegistry>().herds.len();
+
+        let failures = |app: &bevy::prelude::App| {
+            app.world
+                .resource::<CommandEventLog>()
+                .iter()
+                .filter(|entry| entry.kind == CommandEventKind::HerdSpawned)
+                .count()
+        };
+        handle_spawn_herd(
+            &mut app,
+            "no_such_beast".to_string(),
+            UVec2::new(5, 5),
+            50.0,
+        );
+        handle_spawn_herd(&mut app, key.clone(), UVec2::new(48, 5), 50.0);
+        handle_spawn_herd(&mut app, key.clone(), UVec2::new(5, 5), 0.0);
+        assert_eq!(failures(&app), 3, "each rejection is filed in the feed");
+        assert_eq!(
+            app.world.resource::<HerdRegistry>().herds.len(),
+            herds_before,
+            "a rejected spawn leaves the registry untouched"
+        );
+
+        handle_spawn_herd(&mut app, key.clone(), UVec2::new(5, 5), 50.0);
+        let herd_id = format!("{DEBUG_HERD_ID_PREFIX}{key}_00");
+        assert!(app
+            .world
+            .resource::<HerdRegistry>()
+            .find(&herd_id)
+            .is_some());
+
+        run_turn(&mut app);
+        let snapshot = app
+            .world
+            .resource::<SnapshotHistory>()
+            .last_snapshot
+            .clone()
+            .expect("the turn captured a snapshot");
+        assert!(
+            snapshot.herds.iter().any(|herd| herd.id == herd_id),
+            "the spawned herd is in the next snapshot's herds array"
+        );
+
+        handle_cull_herd(&mut app, herd_id.clone());
+        assert!(app
+            .world
+            .resource::<HerdRegistry>()
+            .find(&herd_id)
+            .is_none());
+        assert!(!app
+            .world
+            .resource::<HerdTelemetry>()
+            .entries
+            .iter()
+            .any(|entry| entry.id == herd_id));
+        let culled = app
+            .world
+            .resource::<CommandEventLog>()
+            .iter()
+            .filter(|entry| entry.kind == CommandEventKind::HerdCulled)
+            .count();
+        assert_eq!(culled, 1);
+        handle_cull_herd(&mut app, herd_id);
+        assert_eq!(
+            app.world
+                .resource::<CommandEventLog>()
+                .iter()
+                .filter(|entry| entry.kind == CommandEventKind::HerdCulled)
+                .count(),
+            2,
+            "culling an unknown id is refused into the feed"
+        );
+    }
+
     /// The policy the band's single assignment currently carries.
     fn band_policy(app: &bevy::prelude::App, band: Entity) -> FollowPolicy {
         match &app
@@ -7262,11 +8564,11 @@ mod tests {
             })
             .collect();
         let source_tile = tiles[(source.y * GRID + source.x) as usize];
-        app.world.entity_mut(source_tile).insert(FoodModuleTag {
-            module: FoodModule::SavannaGrassland,
-            seasonal_weight: 1.0,
-            kind: FoodSiteKind::SavannaTrack,
-        });
+        app.world.entity_mut(source_tile).insert(FoodModuleTag::new(
+            FoodModule::SavannaGrassland,
+            1.0,
+            FoodSiteKind::SavannaTrack,
+        ));
         app.world.insert_resource(TileRegistry {
             tiles,
             width: GRID,
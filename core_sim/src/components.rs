@@ -13,7 +13,7 @@ use crate::{
     grid_utils::{HEX_CORNER_COUNT, HEX_DIRECTION_COUNT},
     mapgen::MountainType,
     orders::FactionId,
-    power::PowerNodeId,
+    power::{PowerNodeId, PowerNodeKind},
     scalar::{scalar_from_f32, scalar_one, scalar_zero, Scalar},
 };
 
@@ -165,7 +165,7 @@ pub struct MountainMetadata {
 
 /// Procedural element categories used to vary material behavior.
 #[repr(u8)]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
 pub enum ElementKind {
     Ferrite,
     Arborite,
@@ -395,7 +395,7 @@ pub struct PopulationCohort {
 /// Starvation is deliberately excluded — it is surfaced through the days-of-food path, not morale.
 ///
 /// Snapshot wire encoding (see [`MoraleCause::as_u8`]): `0 = None, 1 = Terrain, 2 = Cold,
-/// 3 = Unrest`.
+/// 3 = Unrest, 4 = Diet`.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum MoraleCause {
     /// Morale rose or held this turn — no dominant negative driver.
@@ -407,16 +407,20 @@ pub enum MoraleCause {
     Cold,
     /// Crisis impacts + cultural sentiment (unrest) dominated.
     Unrest,
+    /// The band's culture is at odds with the food its home tile yields.
+    Diet,
 }
 
 impl MoraleCause {
-    /// Encode for the snapshot's `moraleCause:ubyte` field: `0=None, 1=Terrain, 2=Cold, 3=Unrest`.
+    /// Encode for the snapshot's `moraleCause:ubyte` field: `0=None, 1=Terrain, 2=Cold, 3=Unrest,
+    /// 4=Diet`.
     pub fn as_u8(self) -> u8 {
         match self {
             MoraleCause::None => 0,
             MoraleCause::Terrain => 1,
             MoraleCause::Cold => 2,
             MoraleCause::Unrest => 3,
+            MoraleCause::Diet => 4,
         }
     }
 }
@@ -436,6 +440,8 @@ pub enum MoraleFactor {
     Climate,
     /// Crisis impacts + cultural sentiment (signed).
     Unrest,
+    /// Culture × food-module compatibility of the home tile's diet (signed).
+    Diet,
 }
 
 /// The Phase-1 named morale contributions for a cohort this turn (each signed; their sum IS
@@ -452,18 +458,21 @@ pub struct MoraleContributions {
     pub climate: Scalar,
     /// crisis impacts + cultural sentiment bias (signed).
     pub unrest: Scalar,
+    /// `diet.morale_weight × (preference − 1)` for the home tile's food module (signed).
+    pub diet: Scalar,
 }
 
 impl MoraleContributions {
     /// The active contributions as `(factor, signed value)` pairs — the itemized breakdown the
     /// client can render and the single source both `total` and cause attribution iterate. Ordered
-    /// by the historical tie-break priority (Terrain ≥ Climate ≥ Unrest) so the dominant-cause scan
-    /// is a stable first-max.
-    pub fn contributions(&self) -> [(MoraleFactor, Scalar); 4] {
+    /// by the historical tie-break priority (Terrain ≥ Climate ≥ Unrest ≥ Diet) so the
+    /// dominant-cause scan is a stable first-max.
+    pub fn contributions(&self) -> [(MoraleFactor, Scalar); 5] {
         [
             (MoraleFactor::Terrain, self.terrain),
             (MoraleFactor::Climate, self.climate),
             (MoraleFactor::Unrest, self.unrest),
+            (MoraleFactor::Diet, self.diet),
             (MoraleFactor::Settling, self.settling),
         ]
     }
@@ -477,7 +486,7 @@ impl MoraleContributions {
 
     /// The dominant *negative* contributor as a [`MoraleCause`] (the "why morale fell" label). The
     /// most-negative labeled contribution wins; `Settling` is base growth (never a negative cause),
-    /// and ties resolve by `contributions()` order (Terrain ≥ Climate ≥ Unrest).
+    /// and ties resolve by `contributions()` order (Terrain ≥ Climate ≥ Unrest ≥ Diet).
     pub fn dominant_negative_cause(&self) -> MoraleCause {
         let mut best: Option<(MoraleFactor, Scalar)> = None;
         for (factor, value) in self.contributions() {
@@ -492,6 +501,7 @@ impl MoraleContributions {
             Some((MoraleFactor::Terrain, _)) => MoraleCause::Terrain,
             Some((MoraleFactor::Climate, _)) => MoraleCause::Cold,
             Some((MoraleFactor::Unrest, _)) => MoraleCause::Unrest,
+            Some((MoraleFactor::Diet, _)) => MoraleCause::Diet,
             _ => MoraleCause::None,
         }
     }
@@ -537,6 +547,7 @@ impl PopulationCohort {
 #[derive(Component, Debug, Clone)]
 pub struct PowerNode {
     pub id: PowerNodeId,
+    pub kind: PowerNodeKind,
     pub base_generation: Scalar,
     pub base_demand: Scalar,
     pub generation: Scalar,
@@ -548,6 +559,10 @@ pub struct PowerNode {
     pub surplus: Scalar,
     pub deficit: Scalar,
     pub incident_count: u32,
+    /// Shedding priority: higher keeps power longer under `LoadSheddingPolicy::Priority`.
+    pub priority: u8,
+    /// Load cut from this node in the last power phase.
+    pub shed: Scalar,
 }
 
 /// Marks a starting population cohort spawned from a scenario profile.
@@ -1348,6 +1363,7 @@ impl Default for PowerNode {
     fn default() -> Self {
         Self {
             id: PowerNodeId(0),
+            kind: PowerNodeKind::Hybrid,
             base_generation: scalar_zero(),
             base_demand: scalar_zero(),
             generation: scalar_zero(),
@@ -1359,6 +1375,8 @@ impl Default for PowerNode {
             surplus: scalar_zero(),
             deficit: scalar_zero(),
             incident_count: 0,
+            priority: 0,
+            shed: scalar_zero(),
         }
     }
 }
@@ -1,41 +1,62 @@
 use std::{
-    collections::{HashMap, VecDeque},
+    collections::{BTreeMap, BTreeSet, HashMap, VecDeque},
     f32::consts::PI,
     hash::{Hash, Hasher},
 };
 
-use bevy::prelude::*;
+use bevy::{ecs::system::SystemParam, prelude::*};
 use rand::{rngs::SmallRng, seq::SliceRandom, Rng, SeedableRng};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use tracing::{info, warn};
 
 use crate::{
+    components::{PopulationCohort, Tile},
     crisis_config::{
         CrisisArchetype, CrisisArchetypeCatalog, CrisisArchetypeCatalogHandle, CrisisModifier,
-        CrisisModifierCatalog, CrisisModifierCatalogHandle, CrisisTelemetryConfig,
+        CrisisModifierCatalog, CrisisModifierCatalogHandle, CrisisModifierStacking,
+        CrisisModifierStackingConfig, CrisisModifierStackingRule, CrisisTelemetryConfig,
         CrisisTelemetryConfigHandle, CrisisTelemetryThreshold,
     },
     fauna::HerdDensityMap,
+    great_discovery::{
+        GreatDiscoveryEffectAudit, GreatDiscoveryEffectKind, GreatDiscoveryId,
+        GreatDiscoveryTelemetry,
+    },
     hashing::FnvHasher,
     orders::FactionId,
-    resources::{PendingCrisisSeeds, PendingCrisisSpawns, SimulationConfig, SimulationTick},
+    resources::{
+        CrisisContainmentOrder, CrisisFollowOn, FactionInventory, PendingCrisisContainment,
+        PendingCrisisSeeds, PendingCrisisSpawns, SimulationConfig, SimulationTick, TurnWarnings,
+    },
+    rng_stream::{RngStream, RngStreamState},
     scalar::Scalar,
 };
 use sim_runtime::{
-    CrisisOverlayAnnotationState, CrisisSeverityBand as SchemaCrisisSeverityBand, ScalarRasterState,
+    CrisisLedgerEntryState, CrisisOverlayAnnotationState,
+    CrisisSeverityBand as SchemaCrisisSeverityBand, ScalarRasterState,
 };
 
 const MIN_GRID_DIMENSION: u32 = 1;
 const HERD_DENSITY_CRISIS_WEIGHT: f32 = 0.35;
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Overlay signal a contagion footprint tile adds before the intensity scale (about half a
+/// hotspot's peak), so the spread reads on the crisis raster.
+const CONTAGION_FOOTPRINT_SIGNAL: f32 = 0.05;
+/// Decorrelates the spread stream from the spawn stream that placed the hotspots.
+const CONTAGION_SEED_SALT: u64 = 0x5EED_C0A7_A610_0001;
+/// Decorrelates the escalation rolls from the spawn and spread streams.
+const ESCALATION_SEED_SALT: u64 = 0x5EED_E5CA_1A7E_0002;
+/// Keeps a follow-on's spawn stream apart from its parent's and from a manual spawn of the same
+/// archetype on the same tick.
+const FOLLOW_ON_SEED_SALT: u64 = 0x5EED_F011_0A0E_0003;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum CrisisSeverityBand {
     Safe,
     Warn,
     Critical,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum CrisisMetricKind {
     R0,
     GridStressPct,
@@ -44,6 +65,18 @@ pub enum CrisisMetricKind {
     PhageDensity,
 }
 
+/// A gauge's EMA crossed into a different severity band. Emitted by `advance_crisis_system` so
+/// clients and downstream systems react to escalations instead of polling the gauge bands.
+/// Classified on the EMA, not the raw sample, so one noisy tick across a threshold does not
+/// flicker a warn/critical pair.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CrisisThresholdEvent {
+    pub kind: CrisisMetricKind,
+    pub from: CrisisSeverityBand,
+    pub to: CrisisSeverityBand,
+    pub tick: u64,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct CrisisTrendSample {
     pub tick: u64,
@@ -59,17 +92,22 @@ impl Default for CrisisTrendSample {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct CrisisGaugeSnapshot {
     pub kind: CrisisMetricKind,
     pub raw: f32,
     pub ema: f32,
     pub trend_5t: f32,
     pub band: CrisisSeverityBand,
+    /// The raw value's band before the staleness override `band` applies.
+    pub raw_band: CrisisSeverityBand,
+    /// The band the EMA was last classified into; threshold events are raised on its changes.
+    pub ema_band: CrisisSeverityBand,
     pub last_updated_tick: u64,
     pub stale_ticks: u64,
     pub warn_threshold: f32,
     pub critical_threshold: f32,
+    /// Newest first.
     pub history: Vec<CrisisTrendSample>,
 }
 
@@ -81,6 +119,8 @@ impl Default for CrisisGaugeSnapshot {
             ema: 0.0,
             trend_5t: 0.0,
             band: CrisisSeverityBand::Safe,
+            raw_band: CrisisSeverityBand::Safe,
+            ema_band: CrisisSeverityBand::Safe,
             last_updated_tick: 0,
             stale_ticks: 0,
             warn_threshold: 0.0,
@@ -90,7 +130,7 @@ impl Default for CrisisGaugeSnapshot {
     }
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, PartialEq)]
 pub struct CrisisMetricsSnapshot {
     pub gauges: Vec<CrisisGaugeSnapshot>,
     pub modifiers_active: u32,
@@ -127,6 +167,7 @@ struct GaugeParameters {
     alpha: f32,
     history_depth: usize,
     trend_window: usize,
+    exit_margin: f32,
 }
 
 #[derive(Resource, Debug, Clone)]
@@ -143,6 +184,8 @@ pub struct CrisisTelemetry {
     stale_critical: u64,
     alert_cooldown_ticks: u64,
     config_hash: u64,
+    /// EMA band crossings recorded since the last drain, in the order they happened.
+    threshold_crossings: Vec<CrisisThresholdEvent>,
 }
 
 impl CrisisTelemetry {
@@ -151,6 +194,7 @@ impl CrisisTelemetry {
             alpha: config.ema_alpha,
             history_depth: config.history_depth.max(1),
             trend_window: config.trend_window.max(1),
+            exit_margin: config.band_exit_margin.clamp(0.0, 1.0),
         };
         let thresholds = |key: &str, warn: f32, critical: f32| {
             config
@@ -193,6 +237,7 @@ impl CrisisTelemetry {
             stale_critical: config.stale_tick_critical,
             alert_cooldown_ticks: config.alert_cooldown_ticks,
             config_hash: hash_telemetry_config(config),
+            threshold_crossings: Vec::new(),
         }
     }
 
@@ -209,24 +254,19 @@ impl CrisisTelemetry {
 
     pub fn record_sample(&mut self, tick: u64, sample: CrisisTelemetrySample) {
         if let Some(value) = sample.r0 {
-            let transition = self.r0.update(tick, value);
-            self.log_transition(transition, tick);
+            self.record_metric(tick, CrisisMetricKind::R0, value);
         }
         if let Some(value) = sample.grid_stress_pct {
-            let transition = self.grid_stress_pct.update(tick, value);
-            self.log_transition(transition, tick);
+            self.record_metric(tick, CrisisMetricKind::GridStressPct, value);
         }
         if let Some(value) = sample.unauthorized_queue_pct {
-            let transition = self.unauthorized_queue_pct.update(tick, value);
-            self.log_transition(transition, tick);
+            self.record_metric(tick, CrisisMetricKind::UnauthorizedQueuePct, value);
         }
         if let Some(value) = sample.swarms_active {
-            let transition = self.swarms_active.update(tick, value);
-            self.log_transition(transition, tick);
+            self.record_metric(tick, CrisisMetricKind::SwarmsActive, value);
         }
         if let Some(value) = sample.phage_density {
-            let transition = self.phage_density.update(tick, value);
-            self.log_transition(transition, tick);
+            self.record_metric(tick, CrisisMetricKind::PhageDensity, value);
         }
         if let Some(value) = sample.modifiers_active {
             self.modifiers_active = value;
@@ -240,26 +280,70 @@ impl CrisisTelemetry {
     }
 
     pub fn record_metric(&mut self, tick: u64, kind: CrisisMetricKind, value: f32) {
-        let transition = match kind {
-            CrisisMetricKind::R0 => self.r0.update(tick, value),
-            CrisisMetricKind::GridStressPct => self.grid_stress_pct.update(tick, value),
-            CrisisMetricKind::UnauthorizedQueuePct => {
-                self.unauthorized_queue_pct.update(tick, value)
-            }
-            CrisisMetricKind::SwarmsActive => self.swarms_active.update(tick, value),
-            CrisisMetricKind::PhageDensity => self.phage_density.update(tick, value),
-        };
+        let gauge = self.gauge_mut(kind);
+        let transition = gauge.update(tick, value);
+        let crossing = gauge.ema_crossing(tick);
         self.log_transition(transition, tick);
+        self.threshold_crossings.extend(crossing);
+    }
+
+    /// Takes the EMA band crossings recorded since the last call, oldest first.
+    pub fn drain_threshold_crossings(&mut self) -> Vec<CrisisThresholdEvent> {
+        std::mem::take(&mut self.threshold_crossings)
+    }
+
+    /// The gauges' turn-to-turn state, for `crate::world_save`.
+    pub(crate) fn save_state(&self) -> CrisisTelemetrySave {
+        CrisisTelemetrySave {
+            gauges: self
+                .gauges()
+                .iter()
+                .map(|gauge| gauge.save_state())
+                .collect(),
+            modifiers_active: self.modifiers_active,
+            foreshock_incidents: self.foreshock_incidents,
+            containment_incidents: self.containment_incidents,
+        }
+    }
+
+    /// Put back the state [`Self::save_state`] took. Thresholds and smoothing stay as the
+    /// current config built them; crossings not yet drained are dropped.
+    pub(crate) fn restore_save_state(&mut self, state: &CrisisTelemetrySave) {
+        for saved in &state.gauges {
+            self.gauge_mut(saved.kind).restore_save_state(saved);
+        }
+        self.modifiers_active = state.modifiers_active;
+        self.foreshock_incidents = state.foreshock_incidents;
+        self.containment_incidents = state.containment_incidents;
+        self.threshold_crossings.clear();
+    }
+
+    fn gauges(&self) -> [&CrisisGauge; 5] {
+        [
+            &self.r0,
+            &self.grid_stress_pct,
+            &self.unauthorized_queue_pct,
+            &self.swarms_active,
+            &self.phage_density,
+        ]
+    }
+
+    fn gauge_mut(&mut self, kind: CrisisMetricKind) -> &mut CrisisGauge {
+        match kind {
+            CrisisMetricKind::R0 => &mut self.r0,
+            CrisisMetricKind::GridStressPct => &mut self.grid_stress_pct,
+            CrisisMetricKind::UnauthorizedQueuePct => &mut self.unauthorized_queue_pct,
+            CrisisMetricKind::SwarmsActive => &mut self.swarms_active,
+            CrisisMetricKind::PhageDensity => &mut self.phage_density,
+        }
     }
 
     pub fn snapshot(&self, current_tick: u64) -> CrisisMetricsSnapshot {
-        let mut gauges = vec![
-            self.r0.snapshot(current_tick),
-            self.grid_stress_pct.snapshot(current_tick),
-            self.unauthorized_queue_pct.snapshot(current_tick),
-            self.swarms_active.snapshot(current_tick),
-            self.phage_density.snapshot(current_tick),
-        ];
+        let mut gauges: Vec<_> = self
+            .gauges()
+            .iter()
+            .map(|gauge| gauge.snapshot(current_tick))
+            .collect();
 
         for gauge in &mut gauges {
             if gauge.stale_ticks >= self.stale_critical {
@@ -366,11 +450,13 @@ struct CrisisGauge {
     alpha: f32,
     history_depth: usize,
     trend_window: usize,
+    exit_margin: f32,
     raw: f32,
     ema: Option<f32>,
     history: VecDeque<(u64, f32)>,
     last_updated_tick: u64,
     last_band: CrisisSeverityBand,
+    ema_band: CrisisSeverityBand,
 }
 
 impl CrisisGauge {
@@ -386,11 +472,13 @@ impl CrisisGauge {
             alpha: params.alpha,
             history_depth: params.history_depth,
             trend_window: params.trend_window,
+            exit_margin: params.exit_margin,
             raw: 0.0,
             ema: None,
             history: VecDeque::with_capacity(params.history_depth),
             last_updated_tick: 0,
             last_band: CrisisSeverityBand::Safe,
+            ema_band: CrisisSeverityBand::Safe,
         }
     }
 
@@ -410,7 +498,7 @@ impl CrisisGauge {
             self.history.pop_front();
         }
         self.last_updated_tick = tick;
-        let current_band = self.classify(self.raw);
+        let current_band = self.classify_from(previous_band, self.raw);
         self.last_band = current_band;
         if current_band != previous_band {
             Some((self.kind, previous_band, current_band))
@@ -419,6 +507,19 @@ impl CrisisGauge {
         }
     }
 
+    /// Re-classifies the smoothed value and reports a band change since the last call.
+    fn ema_crossing(&mut self, tick: u64) -> Option<CrisisThresholdEvent> {
+        let previous = self.ema_band;
+        let current = self.classify_from(previous, self.ema.unwrap_or(self.raw));
+        self.ema_band = current;
+        (current != previous).then_some(CrisisThresholdEvent {
+            kind: self.kind,
+            from: previous,
+            to: current,
+            tick,
+        })
+    }
+
     fn snapshot(&self, current_tick: u64) -> CrisisGaugeSnapshot {
         let ema = self.ema.unwrap_or(self.raw);
         let baseline_tick = current_tick.saturating_sub(self.trend_window as u64);
@@ -430,7 +531,7 @@ impl CrisisGauge {
             .or_else(|| self.history.front().map(|(_, value)| *value))
             .unwrap_or(self.raw);
         let trend = self.raw - baseline;
-        let band = self.classify(self.raw);
+        let band = self.last_band;
         let stale_ticks = current_tick.saturating_sub(self.last_updated_tick);
         let history = self
             .history
@@ -449,6 +550,8 @@ impl CrisisGauge {
             ema,
             trend_5t: trend,
             band,
+            raw_band: self.last_band,
+            ema_band: self.ema_band,
             last_updated_tick: self.last_updated_tick,
             stale_ticks,
             warn_threshold: self.warn_threshold,
@@ -457,6 +560,34 @@ impl CrisisGauge {
         }
     }
 
+    fn save_state(&self) -> CrisisGaugeSave {
+        CrisisGaugeSave {
+            kind: self.kind,
+            raw: self.raw,
+            ema: self.ema,
+            history: self.history.iter().copied().collect(),
+            last_updated_tick: self.last_updated_tick,
+            last_band: self.last_band,
+            ema_band: self.ema_band,
+        }
+    }
+
+    fn restore_save_state(&mut self, saved: &CrisisGaugeSave) {
+        self.raw = saved.raw;
+        self.ema = saved.ema;
+        self.history = saved
+            .history
+            .iter()
+            .rev()
+            .take(self.history_depth)
+            .rev()
+            .copied()
+            .collect();
+        self.last_updated_tick = saved.last_updated_tick;
+        self.last_band = saved.last_band;
+        self.ema_band = saved.ema_band;
+    }
+
     fn classify(&self, value: f32) -> CrisisSeverityBand {
         if value >= self.critical_threshold {
             CrisisSeverityBand::Critical
@@ -466,6 +597,23 @@ impl CrisisGauge {
             CrisisSeverityBand::Safe
         }
     }
+
+    /// [`Self::classify`] with hysteresis against the band `previous`: rising happens at the
+    /// threshold, falling only once the value is `exit_margin` of the threshold below it.
+    fn classify_from(&self, previous: CrisisSeverityBand, value: f32) -> CrisisSeverityBand {
+        let entered = self.classify(value);
+        if entered >= previous {
+            return entered;
+        }
+        let holds = |threshold: f32| value >= threshold - threshold.abs() * self.exit_margin;
+        if previous == CrisisSeverityBand::Critical && holds(self.critical_threshold) {
+            CrisisSeverityBand::Critical
+        } else if holds(self.warn_threshold) {
+            CrisisSeverityBand::Warn
+        } else {
+            CrisisSeverityBand::Safe
+        }
+    }
 }
 
 #[derive(Debug, Clone, Default)]
@@ -523,10 +671,37 @@ struct CrisisArchetypeRuntime {
     incident_acceleration: f32,
     telemetry: CrisisTelemetryWeights,
     incidents: Vec<CrisisIncidentTemplate>,
+    /// Intensity at which the crisis counts as critical: the lowest trigger among the
+    /// archetype's critical incidents, or the default critical trigger when it lists none.
+    critical_intensity: f32,
+    /// Set for R0-governed archetypes (`propagation.contagion`); `None` keeps the footprint at
+    /// the seeded hotspots.
+    contagion: Option<CrisisContagionRuntime>,
+    /// `CrisisArchetype::escalates_to`, rolled once when the crisis first turns critical.
+    escalates_to: Vec<(String, f32)>,
     _overlay_palette: Option<String>,
     _annotation_glyph: Option<String>,
 }
 
+/// Spatial spread for an R0-governed archetype. Each turn every footprint tile gets one draw
+/// against each orthogonal neighbour not yet in the footprint.
+#[derive(Debug, Clone, Copy)]
+struct CrisisContagionRuntime {
+    spread_rate: f32,
+    density_weight: f32,
+}
+
+impl CrisisContagionRuntime {
+    /// Chance one footprint tile infects one neighbour this turn: `spread_rate × R0`, weighted
+    /// toward the neighbour's population density and cut by containment (`0..=1`).
+    fn spread_chance(&self, r0: f32, density: f32, containment: f32) -> f32 {
+        let density_factor =
+            1.0 - self.density_weight + self.density_weight * density.clamp(0.0, 1.0);
+        let contained = 1.0 - containment.clamp(0.0, 1.0);
+        (self.spread_rate * r0.max(0.0) * density_factor * contained).clamp(0.0, 1.0)
+    }
+}
+
 #[derive(Debug, Clone)]
 struct CrisisHotspot {
     position: UVec2,
@@ -541,6 +716,7 @@ struct ModifierEffects {
     swarms_active_bonus: f32,
     phage_density_bonus: f32,
     overlay_multiplier: f32,
+    containment: f32,
 }
 
 impl ModifierEffects {
@@ -551,15 +727,95 @@ impl ModifierEffects {
         self.swarms_active_bonus += other.swarms_active_bonus;
         self.phage_density_bonus += other.phage_density_bonus;
         self.overlay_multiplier += other.overlay_multiplier;
+        self.containment += other.containment;
+    }
+
+    fn fields(&self) -> [f32; 7] {
+        [
+            self.r0_delta,
+            self.grid_stress_pct,
+            self.queue_pressure_pct,
+            self.swarms_active_bonus,
+            self.phage_density_bonus,
+            self.overlay_multiplier,
+            self.containment,
+        ]
+    }
+
+    fn from_fields(fields: [f32; 7]) -> Self {
+        let [r0_delta, grid_stress_pct, queue_pressure_pct, swarms_active_bonus, phage_density_bonus, overlay_multiplier, containment] =
+            fields;
+        Self {
+            r0_delta,
+            grid_stress_pct,
+            queue_pressure_pct,
+            swarms_active_bonus,
+            phage_density_bonus,
+            overlay_multiplier,
+            containment,
+        }
     }
 }
 
 #[derive(Debug, Clone)]
 struct ActiveModifier {
     _id: String,
+    category: Option<String>,
     effects: ModifierEffects,
 }
 
+/// Combine a crisis's modifiers: each kind (category) stacks under its configured rule, and the
+/// per-kind results sum.
+fn stack_modifier_effects(
+    modifiers: &[ActiveModifier],
+    stacking: &CrisisModifierStackingConfig,
+) -> ModifierEffects {
+    let mut by_kind: BTreeMap<Option<&str>, Vec<[f32; 7]>> = BTreeMap::new();
+    for modifier in modifiers {
+        by_kind
+            .entry(modifier.category.as_deref())
+            .or_default()
+            .push(modifier.effects.fields());
+    }
+
+    let mut combined = ModifierEffects::default();
+    for (kind, effects) in by_kind {
+        let rules = stacking.for_category(kind);
+        let mut fields = [0.0f32; 7];
+        for (field, slot) in fields.iter_mut().enumerate() {
+            let values: Vec<f32> = effects.iter().map(|entry| entry[field]).collect();
+            *slot = stack_field(&values, rules);
+        }
+        combined.accumulate(&ModifierEffects::from_fields(fields));
+    }
+    combined
+}
+
+/// One effect field across same-kind modifiers. Aggravating and mitigating values stack
+/// separately, strongest first, so the falloff curve and `MaxOnly` never trade one for the other.
+fn stack_field(values: &[f32], stacking: CrisisModifierStacking) -> f32 {
+    let side = |positive: bool| {
+        let mut side: Vec<f32> = values
+            .iter()
+            .copied()
+            .filter(|value| if positive { *value > 0.0 } else { *value < 0.0 })
+            .collect();
+        side.sort_by(|a, b| b.abs().total_cmp(&a.abs()));
+        let weighted = side
+            .iter()
+            .enumerate()
+            .map(|(rank, value)| value * stacking.falloff.powi(rank as i32));
+        match stacking.rule {
+            CrisisModifierStackingRule::Additive => weighted.sum::<f32>(),
+            CrisisModifierStackingRule::Multiplicative => {
+                weighted.fold(1.0, |acc, value| acc * (1.0 + value)) - 1.0
+            }
+            CrisisModifierStackingRule::MaxOnly => side.first().copied().unwrap_or(0.0),
+        }
+    };
+    side(true) + side(false)
+}
+
 #[derive(Debug, Clone)]
 struct CrisisAnnotationMarker {
     label: String,
@@ -569,8 +825,9 @@ struct CrisisAnnotationMarker {
 }
 
 impl CrisisAnnotationMarker {
-    fn to_state(&self) -> CrisisOverlayAnnotationState {
+    fn to_state(&self, crisis_id: u64) -> CrisisOverlayAnnotationState {
         CrisisOverlayAnnotationState {
+            crisis_id,
             label: self.label.clone(),
             severity: severity_to_schema(self.severity),
             path: self
@@ -584,9 +841,11 @@ impl CrisisAnnotationMarker {
 
 #[derive(Debug, Clone)]
 struct ActiveCrisis {
-    _id: String,
+    /// Ledger-assigned handle (`contain_crisis` targets it); `0` until pushed onto the ledger.
+    crisis_id: u64,
+    archetype_id: String,
     name: String,
-    _faction: FactionId,
+    faction: FactionId,
     _seed_tick: u64,
     runtime: CrisisArchetypeRuntime,
     centers: Vec<CrisisHotspot>,
@@ -599,6 +858,23 @@ struct ActiveCrisis {
     incident_timers: HashMap<String, u32>,
     annotations: Vec<CrisisAnnotationMarker>,
     modifiers: Vec<ActiveModifier>,
+    /// `modifiers` combined under the catalog's stacking rules, fixed at spawn.
+    modifier_effects: ModifierEffects,
+    /// Tiles the contagion has reached, seeded from the hotspots. Empty unless the archetype is
+    /// R0-governed. Ordered so the spread pass draws in a fixed order.
+    footprint: BTreeSet<(u32, u32)>,
+    /// Dedicated spread stream, seeded from the spawn seed, so the footprint replays exactly.
+    spread_rng: RngStream,
+    /// Follow-ons between this crisis and the root of its escalation chain.
+    chain_depth: u8,
+    /// Seed for the one-shot escalation roll, derived from the spawn seed.
+    escalation_seed: u64,
+    /// Set once the crisis has reached critical and rolled its follow-ons.
+    escalated: bool,
+    /// Fraction (`0..=1`) cut from growth and R0 by `contain_crisis` orders.
+    containment: f32,
+    /// Turns left before `containment` lapses.
+    containment_turns: u32,
 }
 
 impl ActiveCrisis {
@@ -608,11 +884,23 @@ impl ActiveCrisis {
         runtime: CrisisArchetypeRuntime,
         centers: Vec<CrisisHotspot>,
         modifiers: Vec<ActiveModifier>,
+        stacking: &CrisisModifierStackingConfig,
+        spread_seed: u64,
     ) -> Self {
+        let modifier_effects = stack_modifier_effects(&modifiers, stacking);
+        let footprint = if runtime.contagion.is_some() {
+            centers
+                .iter()
+                .map(|hotspot| (hotspot.position.x, hotspot.position.y))
+                .collect()
+        } else {
+            BTreeSet::new()
+        };
         Self {
-            _id: runtime.id.clone(),
+            crisis_id: 0,
+            archetype_id: runtime.id.clone(),
             name: runtime.name.clone(),
-            _faction: faction,
+            faction,
             _seed_tick: seed_tick,
             runtime,
             centers,
@@ -625,22 +913,70 @@ impl ActiveCrisis {
             incident_timers: HashMap::new(),
             annotations: Vec::new(),
             modifiers,
+            modifier_effects,
+            footprint,
+            spread_rng: RngStream::new(spread_seed ^ CONTAGION_SEED_SALT),
+            chain_depth: 0,
+            escalation_seed: spread_seed ^ ESCALATION_SEED_SALT,
+            escalated: false,
+            containment: 0.0,
+            containment_turns: 0,
+        }
+    }
+
+    fn with_chain_depth(mut self, chain_depth: u8) -> Self {
+        self.chain_depth = chain_depth;
+        self
+    }
+
+    /// Add `strength` containment (capped at `max`) and restart the countdown at `turns`.
+    fn contain(&mut self, strength: f32, turns: u32, max: f32) {
+        self.containment = (self.containment + strength).clamp(0.0, max.clamp(0.0, 1.0));
+        self.containment_turns = turns;
+    }
+
+    /// Whether the crisis has reached its archetype's critical intensity.
+    fn is_critical(&self) -> bool {
+        self.intensity >= self.runtime.critical_intensity
+    }
+
+    /// The follow-ons this crisis queues, rolled the first turn it is critical and never again.
+    /// Nothing escalates at or past the depth cap.
+    fn escalate(&mut self, depth_cap: u8) -> Vec<CrisisFollowOn> {
+        if self.escalated || !self.is_critical() {
+            return Vec::new();
         }
+        self.escalated = true;
+        if self.chain_depth >= depth_cap {
+            return Vec::new();
+        }
+        roll_follow_ons(&self.runtime.escalates_to, self.escalation_seed)
+            .into_iter()
+            .map(|archetype| CrisisFollowOn {
+                faction: self.faction,
+                archetype: archetype.to_string(),
+                chain_depth: self.chain_depth + 1,
+            })
+            .collect()
     }
 
     fn advance(&mut self) -> CrisisAdvanceOutput {
         let mut output = CrisisAdvanceOutput::default();
+        let damping = 1.0 - self.containment;
         let growth = self.runtime.base_growth + self.runtime.incident_acceleration * self.intensity;
-        self.intensity = (self.intensity + growth).clamp(0.0, 1.0);
-
-        let mut modifier_effects = ModifierEffects::default();
-        for modifier in &self.modifiers {
-            modifier_effects.accumulate(&modifier.effects);
-        }
+        self.intensity = (self.intensity + growth * damping).clamp(0.0, 1.0);
 
-        self.r0 = (self.runtime.base_r0
+        let modifier_effects = &self.modifier_effects;
+        self.r0 = ((self.runtime.base_r0
             + (self.runtime.max_r0 - self.runtime.base_r0) * self.intensity)
-            + modifier_effects.r0_delta;
+            + modifier_effects.r0_delta)
+            * damping;
+        if self.containment_turns > 0 {
+            self.containment_turns -= 1;
+            if self.containment_turns == 0 {
+                self.containment = 0.0;
+            }
+        }
 
         let telemetry = self.runtime.telemetry.clone().apply_defaults();
         self.grid_stress_pct = (self.intensity * 100.0 * telemetry.grid_stress_weight
@@ -702,13 +1038,44 @@ impl ActiveCrisis {
         output
     }
 
+    /// Grow the footprint one turn under the current R0. Candidates are drawn against the
+    /// footprint as it stood at the start of the turn, so a tile infected this turn spreads next
+    /// turn at the earliest.
+    fn spread(&mut self, grid_size: UVec2, density: &[f32]) {
+        let Some(contagion) = self.runtime.contagion else {
+            return;
+        };
+        let width = grid_size.x.max(MIN_GRID_DIMENSION);
+        let height = grid_size.y.max(MIN_GRID_DIMENSION);
+        let containment = self.modifier_effects.containment;
+        let mut infected = BTreeSet::new();
+        for &(x, y) in &self.footprint {
+            let neighbours = [
+                (x.checked_sub(1), Some(y)),
+                (x.checked_add(1).filter(|nx| *nx < width), Some(y)),
+                (Some(x), y.checked_sub(1)),
+                (Some(x), y.checked_add(1).filter(|ny| *ny < height)),
+            ];
+            for (nx, ny) in neighbours {
+                let (Some(nx), Some(ny)) = (nx, ny) else {
+                    continue;
+                };
+                if self.footprint.contains(&(nx, ny)) || infected.contains(&(nx, ny)) {
+                    continue;
+                }
+                let idx = (ny as usize) * width as usize + nx as usize;
+                let local_density = density.get(idx).copied().unwrap_or(0.0);
+                let chance = contagion.spread_chance(self.r0, local_density, containment);
+                if self.spread_rng.gen::<f32>() < chance {
+                    infected.insert((nx, ny));
+                }
+            }
+        }
+        self.footprint.extend(infected);
+    }
+
     fn overlay_multiplier(&self) -> f32 {
-        1.0 + self.modifiers.len() as f32 * 0.05
-            + self
-                .modifiers
-                .iter()
-                .map(|modifier| modifier.effects.overlay_multiplier)
-                .sum::<f32>()
+        1.0 + self.modifiers.len() as f32 * 0.05 + self.modifier_effects.overlay_multiplier
     }
 
     fn overlay_value_at(&self, position: UVec2) -> f32 {
@@ -722,6 +1089,9 @@ impl ActiveCrisis {
                 (-distance_sq / (2.0 * sigma * sigma)).exp() / (2.0 * PI * sigma * sigma);
             value += gaussian;
         }
+        if self.footprint.contains(&(position.x, position.y)) {
+            value += CONTAGION_FOOTPRINT_SIGNAL;
+        }
         (value * self.intensity * self.runtime.telemetry.r0_weight).min(1.0)
     }
 
@@ -742,6 +1112,8 @@ struct CrisisAdvanceOutput {
 #[derive(Resource, Debug, Default, Clone)]
 pub struct ActiveCrisisLedger {
     entries: Vec<ActiveCrisis>,
+    /// Last id handed out; ids start at 1 and are never reused.
+    last_id: u64,
 }
 
 impl ActiveCrisisLedger {
@@ -753,13 +1125,388 @@ impl ActiveCrisisLedger {
         &mut self.entries
     }
 
-    fn push(&mut self, crisis: ActiveCrisis) {
+    fn push(&mut self, mut crisis: ActiveCrisis) -> u64 {
+        self.last_id += 1;
+        crisis.crisis_id = self.last_id;
         self.entries.push(crisis);
+        self.last_id
     }
 
+    fn get_mut(&mut self, crisis_id: u64) -> Option<&mut ActiveCrisis> {
+        self.entries
+            .iter_mut()
+            .find(|crisis| crisis.crisis_id == crisis_id)
+    }
+
+    /// Faction that owns the crisis `crisis_id`, or `None` if no such crisis is active.
+    pub fn faction_of(&self, crisis_id: u64) -> Option<FactionId> {
+        self.entries
+            .iter()
+            .find(|crisis| crisis.crisis_id == crisis_id)
+            .map(|crisis| crisis.faction)
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Names of crises with a hotspot or contagion tile inside the inclusive `min..=max` box.
     fn total_modifiers(&self) -> usize {
         self.entries.iter().map(|entry| entry.modifiers.len()).sum()
     }
+
+    /// Each active crisis's id, archetype and escalation progress, in ledger order.
+    pub fn entry_states(&self) -> Vec<CrisisLedgerEntryState> {
+        self.entries
+            .iter()
+            .map(|crisis| CrisisLedgerEntryState {
+                crisis_id: crisis.crisis_id,
+                archetype: crisis.archetype_id.clone(),
+                faction: crisis.faction.0,
+                chain_depth: crisis.chain_depth,
+                escalated: crisis.escalated,
+                tiles: crisis
+                    .centers
+                    .iter()
+                    .map(|hotspot| (hotspot.position.x, hotspot.position.y))
+                    .chain(crisis.footprint.iter().copied())
+                    .collect::<BTreeSet<_>>()
+                    .into_iter()
+                    .flat_map(|(x, y)| [x, y])
+                    .collect(),
+            })
+            .collect()
+    }
+
+    /// Rewind to a snapshot's ledger: crises it does not list spawned after it and are dropped,
+    /// and the rest take back their chain depth and escalation flag, so a replayed turn rolls no
+    /// follow-on twice. Crises are never retired, so the snapshot's highest id was the last one
+    /// handed out.
+    pub fn restore_entry_states(&mut self, states: &[CrisisLedgerEntryState]) {
+        self.entries.retain_mut(|crisis| {
+            let Some(state) = states
+                .iter()
+                .find(|state| state.crisis_id == crisis.crisis_id)
+            else {
+                return false;
+            };
+            crisis.chain_depth = state.chain_depth;
+            crisis.escalated = state.escalated;
+            true
+        });
+        self.last_id = states
+            .iter()
+            .map(|state| state.crisis_id)
+            .max()
+            .unwrap_or(0);
+    }
+}
+
+/// The crisis state a snapshot leaves out: every active crisis in full, the next id, and the
+/// queued seeds, spawns, follow-ons and containment orders. Carried by `crate::world_save`.
+/// Archetype tuning is not saved; each crisis rebuilds it from the catalog on restore.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct CrisisSave {
+    crises: Vec<ActiveCrisisSave>,
+    last_id: u64,
+    seeds: Vec<(u32, u16)>,
+    follow_ons: Vec<(u32, String, u8)>,
+    spawns: Vec<(u32, String)>,
+    containment: Vec<(u32, u64, i64, String, i64)>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct ActiveCrisisSave {
+    crisis_id: u64,
+    archetype_id: String,
+    faction: u32,
+    seed_tick: u64,
+    /// Hotspots as `(x, y, radius)`.
+    centers: Vec<(u32, u32, f32)>,
+    intensity: f32,
+    r0: f32,
+    grid_stress_pct: f32,
+    queue_pressure_pct: f32,
+    swarms_active: f32,
+    phage_density: f32,
+    incident_timers: Vec<(String, u32)>,
+    annotations: Vec<CrisisAnnotationSave>,
+    /// `(id, category, effect fields)` per modifier.
+    modifiers: Vec<(String, Option<String>, [f32; 7])>,
+    modifier_effects: [f32; 7],
+    footprint: Vec<(u32, u32)>,
+    spread_rng: RngStreamState,
+    chain_depth: u8,
+    escalation_seed: u64,
+    escalated: bool,
+    containment: f32,
+    containment_turns: u32,
+}
+
+/// [`CrisisTelemetry`]'s turn-to-turn state: per gauge the raw value, EMA, history and both
+/// hysteresis bands, plus the incident counters. Carried by `crate::world_save` and rebuilt from
+/// the snapshot's gauges on a rollback; thresholds and smoothing come from the config.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct CrisisTelemetrySave {
+    pub(crate) gauges: Vec<CrisisGaugeSave>,
+    pub(crate) modifiers_active: u32,
+    pub(crate) foreshock_incidents: u32,
+    pub(crate) containment_incidents: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct CrisisGaugeSave {
+    pub(crate) kind: CrisisMetricKind,
+    pub(crate) raw: f32,
+    /// `None` until the gauge's first sample.
+    pub(crate) ema: Option<f32>,
+    /// `(tick, value)`, oldest first.
+    pub(crate) history: Vec<(u64, f32)>,
+    pub(crate) last_updated_tick: u64,
+    pub(crate) last_band: CrisisSeverityBand,
+    pub(crate) ema_band: CrisisSeverityBand,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CrisisAnnotationSave {
+    label: String,
+    severity: CrisisSeverityBand,
+    coords: Vec<(u32, u32)>,
+    ttl: u8,
+}
+
+impl CrisisSave {
+    /// `None` unless the world holds the ledger and all three queues.
+    pub(crate) fn capture(world: &World) -> Option<Self> {
+        let ledger = world.get_resource::<ActiveCrisisLedger>()?;
+        let seeds = world.get_resource::<PendingCrisisSeeds>()?;
+        let spawns = world.get_resource::<PendingCrisisSpawns>()?;
+        let containment = world.get_resource::<PendingCrisisContainment>()?;
+
+        let crises = ledger
+            .entries
+            .iter()
+            .map(|crisis| {
+                let mut incident_timers: Vec<_> = crisis
+                    .incident_timers
+                    .iter()
+                    .map(|(id, timer)| (id.clone(), *timer))
+                    .collect();
+                incident_timers.sort();
+                ActiveCrisisSave {
+                    crisis_id: crisis.crisis_id,
+                    archetype_id: crisis.archetype_id.clone(),
+                    faction: crisis.faction.0,
+                    seed_tick: crisis._seed_tick,
+                    centers: crisis
+                        .centers
+                        .iter()
+                        .map(|hotspot| (hotspot.position.x, hotspot.position.y, hotspot.radius))
+                        .collect(),
+                    intensity: crisis.intensity,
+                    r0: crisis.r0,
+                    grid_stress_pct: crisis.grid_stress_pct,
+                    queue_pressure_pct: crisis.queue_pressure_pct,
+                    swarms_active: crisis.swarms_active,
+                    phage_density: crisis.phage_density,
+                    incident_timers,
+                    annotations: crisis
+                        .annotations
+                        .iter()
+                        .map(|marker| CrisisAnnotationSave {
+                            label: marker.label.clone(),
+                            severity: marker.severity,
+                            coords: marker.coords.iter().map(|c| (c.x, c.y)).collect(),
+                            ttl: marker.ttl,
+                        })
+                        .collect(),
+                    modifiers: crisis
+                        .modifiers
+                        .iter()
+                        .map(|modifier| {
+                            (
+                                modifier._id.clone(),
+                                modifier.category.clone(),
+                                modifier.effects.fields(),
+                            )
+                        })
+                        .collect(),
+                    modifier_effects: crisis.modifier_effects.fields(),
+                    footprint: crisis.footprint.iter().copied().collect(),
+                    spread_rng: crisis.spread_rng.state(),
+                    chain_depth: crisis.chain_depth,
+                    escalation_seed: crisis.escalation_seed,
+                    escalated: crisis.escalated,
+                    containment: crisis.containment,
+                    containment_turns: crisis.containment_turns,
+                }
+            })
+            .collect();
+
+        Some(Self {
+            crises,
+            last_id: ledger.last_id,
+            seeds: seeds
+                .seeds
+                .iter()
+                .map(|(faction, discovery)| (faction.0, *discovery))
+                .collect(),
+            follow_ons: seeds
+                .follow_ons
+                .iter()
+                .map(|follow_on| {
+                    (
+                        follow_on.faction.0,
+                        follow_on.archetype.clone(),
+                        follow_on.chain_depth,
+                    )
+                })
+                .collect(),
+            spawns: spawns
+                .spawns
+                .iter()
+                .map(|(faction, archetype)| (faction.0, archetype.clone()))
+                .collect(),
+            containment: containment
+                .orders
+                .iter()
+                .map(|order| {
+                    (
+                        order.faction.0,
+                        order.crisis_id,
+                        order.effort,
+                        order.cost_item.clone(),
+                        order.cost,
+                    )
+                })
+                .collect(),
+        })
+    }
+
+    /// Replace the world's crisis ledger and queues with the saved state. A crisis whose
+    /// archetype the world's catalog no longer has is dropped with a warning; its id stays spent.
+    pub(crate) fn restore(&self, world: &mut World) {
+        let catalog = world
+            .get_resource::<CrisisArchetypeCatalogHandle>()
+            .map(CrisisArchetypeCatalogHandle::get);
+        let mut entries = Vec::with_capacity(self.crises.len());
+        for saved in &self.crises {
+            let Some(runtime) = catalog
+                .as_ref()
+                .and_then(|catalog| catalog.archetype(&saved.archetype_id))
+                .and_then(archetype_runtime)
+            else {
+                warn!(
+                    target: "shadow_scale::crisis",
+                    crisis_id = saved.crisis_id,
+                    archetype = %saved.archetype_id,
+                    "crisis.save.unknown_archetype"
+                );
+                continue;
+            };
+            entries.push(ActiveCrisis {
+                crisis_id: saved.crisis_id,
+                archetype_id: saved.archetype_id.clone(),
+                name: runtime.name.clone(),
+                faction: FactionId(saved.faction),
+                _seed_tick: saved.seed_tick,
+                runtime,
+                centers: saved
+                    .centers
+                    .iter()
+                    .map(|&(x, y, radius)| CrisisHotspot {
+                        position: UVec2::new(x, y),
+                        radius,
+                    })
+                    .collect(),
+                intensity: saved.intensity,
+                r0: saved.r0,
+                grid_stress_pct: saved.grid_stress_pct,
+                queue_pressure_pct: saved.queue_pressure_pct,
+                swarms_active: saved.swarms_active,
+                phage_density: saved.phage_density,
+                incident_timers: saved.incident_timers.iter().cloned().collect(),
+                annotations: saved
+                    .annotations
+                    .iter()
+                    .map(|marker| CrisisAnnotationMarker {
+                        label: marker.label.clone(),
+                        severity: marker.severity,
+                        coords: marker
+                            .coords
+                            .iter()
+                            .map(|&(x, y)| UVec2::new(x, y))
+                            .collect(),
+                        ttl: marker.ttl,
+                    })
+                    .collect(),
+                modifiers: saved
+                    .modifiers
+                    .iter()
+                    .map(|(id, category, effects)| ActiveModifier {
+                        _id: id.clone(),
+                        category: category.clone(),
+                        effects: ModifierEffects::from_fields(*effects),
+                    })
+                    .collect(),
+                modifier_effects: ModifierEffects::from_fields(saved.modifier_effects),
+                footprint: saved.footprint.iter().copied().collect(),
+                spread_rng: RngStream::resume(saved.spread_rng),
+                chain_depth: saved.chain_depth,
+                escalation_seed: saved.escalation_seed,
+                escalated: saved.escalated,
+                containment: saved.containment,
+                containment_turns: saved.containment_turns,
+            });
+        }
+        world.insert_resource(ActiveCrisisLedger {
+            entries,
+            last_id: self.last_id,
+        });
+
+        world.insert_resource(PendingCrisisSeeds {
+            seeds: self
+                .seeds
+                .iter()
+                .map(|&(faction, discovery)| (FactionId(faction), discovery))
+                .collect(),
+            follow_ons: self
+                .follow_ons
+                .iter()
+                .map(|(faction, archetype, chain_depth)| CrisisFollowOn {
+                    faction: FactionId(*faction),
+                    archetype: archetype.clone(),
+                    chain_depth: *chain_depth,
+                })
+                .collect(),
+        });
+        world.insert_resource(PendingCrisisSpawns {
+            spawns: self
+                .spawns
+                .iter()
+                .map(|(faction, archetype)| (FactionId(*faction), archetype.clone()))
+                .collect(),
+        });
+        world.insert_resource(PendingCrisisContainment {
+            orders: self
+                .containment
+                .iter()
+                .map(
+                    |(faction, crisis_id, effort, cost_item, cost)| CrisisContainmentOrder {
+                        faction: FactionId(*faction),
+                        crisis_id: *crisis_id,
+                        effort: *effort,
+                        cost_item: cost_item.clone(),
+                        cost: *cost,
+                    },
+                )
+                .collect(),
+        });
+    }
 }
 
 #[derive(Resource, Debug, Clone, Default)]
@@ -805,6 +1552,25 @@ struct ArchetypePropagationConfig {
     base_growth: Option<f32>,
     #[serde(default)]
     incident_acceleration: Option<f32>,
+    #[serde(default)]
+    contagion: Option<ArchetypeContagionConfig>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+struct ArchetypeContagionConfig {
+    #[serde(default)]
+    spread_rate: Option<f32>,
+    #[serde(default)]
+    density_weight: Option<f32>,
+}
+
+impl ArchetypeContagionConfig {
+    fn into_runtime(self) -> CrisisContagionRuntime {
+        CrisisContagionRuntime {
+            spread_rate: self.spread_rate.unwrap_or(0.08).max(0.0),
+            density_weight: self.density_weight.unwrap_or(0.5).clamp(0.0, 1.0),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Default, Deserialize)]
@@ -876,6 +1642,12 @@ fn archetype_runtime(archetype: &CrisisArchetype) -> Option<CrisisArchetypeRunti
         .into_iter()
         .map(build_incident_template)
         .collect::<Vec<_>>();
+    let critical_intensity = incidents
+        .iter()
+        .filter(|incident| incident.severity == CrisisSeverityBand::Critical)
+        .map(|incident| incident.trigger_intensity)
+        .reduce(f32::min)
+        .unwrap_or_else(|| default_trigger_for(CrisisSeverityBand::Critical));
 
     Some(CrisisArchetypeRuntime {
         id: archetype.id.clone(),
@@ -886,6 +1658,11 @@ fn archetype_runtime(archetype: &CrisisArchetype) -> Option<CrisisArchetypeRunti
         incident_acceleration: propagation.incident_acceleration.unwrap_or(0.02),
         telemetry: telemetry_cfg.into_weights(),
         incidents,
+        critical_intensity,
+        contagion: propagation
+            .contagion
+            .map(ArchetypeContagionConfig::into_runtime),
+        escalates_to: archetype.escalates_to.clone(),
         _overlay_palette: overlay_cfg.palette,
         _annotation_glyph: overlay_cfg.annotation_glyph,
     })
@@ -912,6 +1689,16 @@ fn build_incident_template(config: ArchetypeIncidentConfig) -> CrisisIncidentTem
     }
 }
 
+/// One draw per `(archetype, chance)` pair, in listed order, from a stream seeded by `seed`.
+fn roll_follow_ons(escalates_to: &[(String, f32)], seed: u64) -> Vec<&str> {
+    let mut rng = SmallRng::seed_from_u64(seed);
+    escalates_to
+        .iter()
+        .filter(|(_, chance)| rng.gen::<f32>() < *chance)
+        .map(|(archetype, _)| archetype.as_str())
+        .collect()
+}
+
 fn select_archetype(
     catalog: &CrisisArchetypeCatalog,
     discovery_id: u16,
@@ -957,6 +1744,7 @@ fn choose_modifiers(rng: &mut SmallRng, catalog: &CrisisModifierCatalog) -> Vec<
         .filter_map(|idx| catalog.modifiers.get(idx))
         .map(|modifier| ActiveModifier {
             _id: modifier.id.clone(),
+            category: modifier.category.clone(),
             effects: parse_modifier_effects(modifier),
         })
         .collect()
@@ -987,6 +1775,9 @@ fn parse_modifier_effects(modifier: &CrisisModifier) -> ModifierEffects {
         if let Some(value) = map.get("overlay_multiplier").and_then(|v| v.as_f64()) {
             effects.overlay_multiplier += value as f32;
         }
+        if let Some(value) = map.get("containment").and_then(|v| v.as_f64()) {
+            effects.containment += value as f32;
+        }
     }
     effects
 }
@@ -1014,9 +1805,39 @@ fn generate_hotspots(rng: &mut SmallRng, grid_size: UVec2) -> Vec<CrisisHotspot>
     hotspots
 }
 
+/// Head-count per tile on its cohorts' home tiles, normalized against the densest tile, for
+/// contagion spread. Row-major over the crisis grid.
+fn population_density_grid(
+    grid_size: UVec2,
+    tiles: &Query<&Tile>,
+    cohorts: &Query<&PopulationCohort>,
+) -> Vec<f32> {
+    let width = grid_size.x.max(MIN_GRID_DIMENSION);
+    let height = grid_size.y.max(MIN_GRID_DIMENSION);
+    let mut density = vec![0.0f32; (width as usize).saturating_mul(height as usize).max(1)];
+    for cohort in cohorts.iter() {
+        let Ok(tile) = tiles.get(cohort.home) else {
+            continue;
+        };
+        if tile.position.x >= width || tile.position.y >= height {
+            continue;
+        }
+        let idx = (tile.position.y as usize) * width as usize + tile.position.x as usize;
+        density[idx] += cohort.size as f32;
+    }
+    let max = density.iter().copied().fold(0.0f32, f32::max);
+    if max > 0.0 {
+        for value in &mut density {
+            *value /= max;
+        }
+    }
+    density
+}
+
 fn rebuild_overlay(
     ledger: &mut ActiveCrisisLedger,
     grid_size: UVec2,
+    density: &[f32],
 ) -> (Vec<f32>, Vec<CrisisOverlayAnnotationState>, u32, u32) {
     let width = grid_size.x.max(MIN_GRID_DIMENSION);
     let height = grid_size.y.max(MIN_GRID_DIMENSION);
@@ -1028,6 +1849,7 @@ fn rebuild_overlay(
 
     for crisis in ledger.entries_mut() {
         let result = crisis.advance();
+        crisis.spread(grid_size, density);
         warn_events_total += result.warn_events;
         critical_events_total += result.critical_events;
         let multiplier = crisis.overlay_multiplier();
@@ -1041,7 +1863,7 @@ fn rebuild_overlay(
         }
 
         for marker in &crisis.annotations {
-            annotations.push(marker.to_state());
+            annotations.push(marker.to_state(crisis.crisis_id));
         }
     }
 
@@ -1068,20 +1890,40 @@ fn rebuild_overlay(
     )
 }
 
+/// The queues `advance_crisis_system` drains each turn, plus the stockpiles a containment order
+/// naming a vanished crisis is refunded to. Bundled to keep the system within Bevy's 16-arg limit.
+#[derive(SystemParam)]
+pub struct CrisisQueues<'w> {
+    pub seeds: ResMut<'w, PendingCrisisSeeds>,
+    pub spawns: ResMut<'w, PendingCrisisSpawns>,
+    pub containment: ResMut<'w, PendingCrisisContainment>,
+    pub inventory: ResMut<'w, FactionInventory>,
+}
+
 #[allow(clippy::too_many_arguments)]
 pub fn advance_crisis_system(
     config: Res<SimulationConfig>,
     tick: Res<SimulationTick>,
     herd_density: Res<HerdDensityMap>,
-    mut pending_seeds: ResMut<PendingCrisisSeeds>,
-    mut pending_spawns: ResMut<PendingCrisisSpawns>,
+    tiles: Query<&Tile>,
+    cohorts: Query<&PopulationCohort>,
+    queues: CrisisQueues,
     archetypes: Res<CrisisArchetypeCatalogHandle>,
     modifiers: Res<CrisisModifierCatalogHandle>,
     telemetry_config: Res<CrisisTelemetryConfigHandle>,
     mut ledger: ResMut<ActiveCrisisLedger>,
     mut overlay: ResMut<CrisisOverlayCache>,
     mut telemetry: ResMut<CrisisTelemetry>,
+    mut turn_warnings: Option<ResMut<TurnWarnings>>,
+    mut threshold_events: EventWriter<CrisisThresholdEvent>,
+    mut discovery_audit: Option<ResMut<GreatDiscoveryTelemetry>>,
 ) {
+    let CrisisQueues {
+        seeds: mut pending_seeds,
+        spawns: mut pending_spawns,
+        containment: mut pending_containment,
+        mut inventory,
+    } = queues;
     let grid_size = config.grid_size;
     let telemetry_cfg = telemetry_config.get();
     telemetry.ensure_config(telemetry_cfg.as_ref());
@@ -1106,23 +1948,43 @@ pub fn advance_crisis_system(
         }
     }
 
+    // One crisis per faction and archetype per turn, however many seeds and spawns ask for it:
+    // seeds resolve to their archetype first, then both queues are checked against this set.
+    let mut spawned_this_turn: BTreeSet<(FactionId, String)> = BTreeSet::new();
     let seeds = pending_seeds.drain();
     for (faction, discovery_id) in seeds {
         if let Some(archetype) = select_archetype(&catalog, discovery_id) {
+            if !spawned_this_turn.insert((faction, archetype.id.clone())) {
+                continue;
+            }
             if let Some(runtime) = archetype_runtime(archetype) {
                 let seed = compose_seed(faction, discovery_id, tick.0);
                 let mut rng = SmallRng::seed_from_u64(seed);
                 let hotspots = generate_hotspots(&mut rng, grid_size);
+                let hotspot_count = hotspots.len() as u32;
                 let assigned_modifiers = choose_modifiers(&mut rng, &modifier_catalog);
-                ledger.push(ActiveCrisis::new(
+                let crisis_id = ledger.push(ActiveCrisis::new(
                     faction,
                     tick.0,
                     runtime,
                     hotspots,
                     assigned_modifiers,
+                    &modifier_catalog.stacking,
+                    seed,
                 ));
+                if let Some(audit) = discovery_audit.as_mut() {
+                    audit.record_effect(GreatDiscoveryEffectAudit {
+                        tick: tick.0,
+                        discovery: GreatDiscoveryId(discovery_id),
+                        faction,
+                        kind: GreatDiscoveryEffectKind::Crisis,
+                        target_id: crisis_id,
+                        magnitude: Scalar::from_u32(hotspot_count),
+                    });
+                }
                 info!(
                     target: "shadow_scale::crisis",
+                    crisis_id,
                     %discovery_id,
                     faction = %faction.0,
                     archetype = %archetype.id,
@@ -1135,27 +1997,38 @@ pub fn advance_crisis_system(
                 %discovery_id,
                 "crisis.spawn.discovery_unknown"
             );
+            if let Some(warnings) = turn_warnings.as_mut() {
+                warnings.push(format!(
+                    "crisis seed for discovery {discovery_id} matched no archetype"
+                ));
+            }
         }
     }
 
+    // Already lowercased by the drain.
     let manual_spawns = pending_spawns.drain();
-    for (faction, archetype_id) in manual_spawns {
-        let normalized = archetype_id.to_ascii_lowercase();
+    for (faction, normalized) in manual_spawns {
         if let Some(archetype) = catalog.archetype(&normalized) {
+            if !spawned_this_turn.insert((faction, archetype.id.clone())) {
+                continue;
+            }
             if let Some(runtime) = archetype_runtime(archetype) {
                 let seed = compose_seed(faction, 0, tick.0 ^ hash_identifier(&normalized));
                 let mut rng = SmallRng::seed_from_u64(seed);
                 let hotspots = generate_hotspots(&mut rng, grid_size);
                 let assigned_modifiers = choose_modifiers(&mut rng, &modifier_catalog);
-                ledger.push(ActiveCrisis::new(
+                let crisis_id = ledger.push(ActiveCrisis::new(
                     faction,
                     tick.0,
                     runtime,
                     hotspots,
                     assigned_modifiers,
+                    &modifier_catalog.stacking,
+                    seed,
                 ));
                 info!(
                     target: "shadow_scale::crisis",
+                    crisis_id,
                     faction = %faction.0,
                     archetype = %normalized,
                     "crisis.spawn.manual"
@@ -1168,9 +2041,105 @@ pub fn advance_crisis_system(
                 archetype = %normalized,
                 "crisis.spawn.manual.unknown_archetype"
             );
+            if let Some(warnings) = turn_warnings.as_mut() {
+                warnings.push(format!(
+                    "crisis spawn for faction {} names unknown archetype '{normalized}'",
+                    faction.0
+                ));
+            }
         }
     }
 
+    for follow_on in pending_seeds.drain_follow_ons() {
+        let CrisisFollowOn {
+            faction,
+            archetype: archetype_id,
+            chain_depth,
+        } = follow_on;
+        let Some(runtime) = catalog.archetype(&archetype_id).and_then(archetype_runtime) else {
+            warn!(
+                target: "shadow_scale::crisis",
+                faction = %faction.0,
+                archetype = %archetype_id,
+                "crisis.spawn.follow_on.unknown_archetype"
+            );
+            continue;
+        };
+        let seed = compose_seed(
+            faction,
+            u16::from(chain_depth),
+            tick.0 ^ hash_identifier(&archetype_id),
+        ) ^ FOLLOW_ON_SEED_SALT;
+        let mut rng = SmallRng::seed_from_u64(seed);
+        let hotspots = generate_hotspots(&mut rng, grid_size);
+        let assigned_modifiers = choose_modifiers(&mut rng, &modifier_catalog);
+        let crisis_id = ledger.push(
+            ActiveCrisis::new(
+                faction,
+                tick.0,
+                runtime,
+                hotspots,
+                assigned_modifiers,
+                &modifier_catalog.stacking,
+                seed,
+            )
+            .with_chain_depth(chain_depth),
+        );
+        info!(
+            target: "shadow_scale::crisis",
+            crisis_id,
+            faction = %faction.0,
+            archetype = %archetype_id,
+            chain_depth,
+            "crisis.spawn.follow_on"
+        );
+    }
+
+    let containment_cfg = &config.crisis_containment;
+    for order in pending_containment.drain() {
+        let Some(crisis) = ledger.get_mut(order.crisis_id) else {
+            warn!(
+                target: "shadow_scale::crisis",
+                crisis_id = order.crisis_id,
+                faction = %order.faction.0,
+                refunded = order.cost,
+                "crisis.containment.unknown_crisis"
+            );
+            if let Some(warnings) = turn_warnings.as_mut() {
+                warnings.push(format!(
+                    "containment order names unknown crisis {}",
+                    order.crisis_id
+                ));
+            }
+            inventory.add_stockpile(order.faction, order.cost_item, order.cost);
+            continue;
+        };
+        crisis.contain(
+            order.effort as f32 * containment_cfg.containment_per_effort,
+            containment_cfg.duration_turns,
+            containment_cfg.max_containment,
+        );
+        info!(
+            target: "shadow_scale::crisis",
+            crisis_id = order.crisis_id,
+            faction = %order.faction.0,
+            effort = order.effort,
+            containment = crisis.containment,
+            turns = crisis.containment_turns,
+            "crisis.containment.applied"
+        );
+        info!(
+            target: "shadow_scale::analytics",
+            event = "crisis_containment",
+            crisis_id = order.crisis_id,
+            archetype = %crisis.archetype_id,
+            faction = order.faction.0,
+            effort = order.effort,
+            containment = crisis.containment,
+            turns = crisis.containment_turns,
+        );
+    }
+
     let herd_density_signal = herd_density.normalized_average();
 
     if ledger.entries().is_empty() {
@@ -1191,11 +2160,36 @@ pub fn advance_crisis_system(
                 containment_incidents: Some(0),
             },
         );
+        threshold_events.send_batch(telemetry.drain_threshold_crossings());
         return;
     }
 
+    let density = if ledger
+        .entries()
+        .iter()
+        .any(|crisis| crisis.runtime.contagion.is_some())
+    {
+        population_density_grid(grid_size, &tiles, &cohorts)
+    } else {
+        Vec::new()
+    };
     let (samples, annotations, warn_events, critical_events) =
-        rebuild_overlay(&mut ledger, grid_size);
+        rebuild_overlay(&mut ledger, grid_size, &density);
+
+    // Follow-ons spawn next turn, so a chain advances at most one generation per turn.
+    let depth_cap = catalog.escalation_depth_cap();
+    for crisis in ledger.entries_mut() {
+        for follow_on in crisis.escalate(depth_cap) {
+            info!(
+                target: "shadow_scale::crisis",
+                parent = %crisis.name,
+                archetype = %follow_on.archetype,
+                chain_depth = follow_on.chain_depth,
+                "crisis.escalation.queued"
+            );
+            pending_seeds.push_follow_on(follow_on);
+        }
+    }
 
     let width = grid_size.x.max(MIN_GRID_DIMENSION);
     let height = grid_size.y.max(MIN_GRID_DIMENSION);
@@ -1232,6 +2226,7 @@ pub fn advance_crisis_system(
         containment_incidents: Some(critical_events),
     };
     telemetry.record_sample(tick.0, sample);
+    threshold_events.send_batch(telemetry.drain_threshold_crossings());
 }
 
 fn severity_from_str(input: Option<&str>) -> CrisisSeverityBand {
@@ -1279,20 +2274,26 @@ fn severity_to_schema(band: CrisisSeverityBand) -> SchemaCrisisSeverityBand {
 
 fn hash_telemetry_config(config: &CrisisTelemetryConfig) -> u64 {
     let mut hasher = FnvHasher::new();
-    // We only hash the gauges as they drive the telemetry structure
+    // Hash what the gauges are built from: the thresholds and the band hysteresis.
     for (key, threshold) in &config.gauges {
         key.hash(&mut hasher);
         threshold.warn.to_bits().hash(&mut hasher);
         threshold.critical.to_bits().hash(&mut hasher);
     }
+    config.band_exit_margin.to_bits().hash(&mut hasher);
     hasher.finish()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::crisis_config::{CrisisArchetypeCatalog, CrisisModifierCatalog};
+    use crate::crisis_config::{
+        CrisisArchetypeCatalog, CrisisArchetypeCatalogError, CrisisModifierCatalog,
+        DEFAULT_CRISIS_ESCALATION_DEPTH,
+    };
+    use crate::resources::{CrisisContainmentConfig, CrisisContainmentOrder};
     use bevy_ecs::system::RunSystemOnce;
+    use std::sync::Arc;
 
     #[test]
     fn ema_progression_matches_alpha() {
@@ -1305,6 +2306,7 @@ mod tests {
             alpha: 0.35,
             history_depth: 6,
             trend_window: 5,
+            exit_margin: 0.0,
         };
         let mut gauge = CrisisGauge::new(CrisisMetricKind::R0, threshold, params);
         gauge.update(1, 1.0);
@@ -1326,6 +2328,7 @@ mod tests {
             alpha: 0.35,
             history_depth: 6,
             trend_window: 5,
+            exit_margin: 0.0,
         };
         let mut gauge = CrisisGauge::new(CrisisMetricKind::GridStressPct, threshold, params);
         gauge.update(1, 10.0);
@@ -1367,6 +2370,150 @@ mod tests {
             .unwrap_or(false));
     }
 
+    #[test]
+    fn threshold_events_follow_the_ema_through_warn_then_critical() {
+        let mut telemetry = CrisisTelemetry::default();
+        // r0: warn 0.9, critical 1.2, alpha 0.35. The first sample seeds the EMA at 0.5; the raw
+        // band would flip warn -> critical -> warn -> critical across t4..t6.
+        let raw = [0.5, 1.1, 1.1, 1.1, 1.3, 1.1, 1.6, 1.6];
+        for (tick, value) in raw.into_iter().enumerate() {
+            telemetry.record_metric(tick as u64, CrisisMetricKind::R0, value);
+        }
+
+        let events = telemetry.drain_threshold_crossings();
+        let bands: Vec<_> = events.iter().map(|e| (e.from, e.to)).collect();
+        assert_eq!(
+            bands,
+            vec![
+                (CrisisSeverityBand::Safe, CrisisSeverityBand::Warn),
+                (CrisisSeverityBand::Warn, CrisisSeverityBand::Critical),
+            ],
+            "the one-tick raw spike at t4 stays below critical on the EMA: {events:?}"
+        );
+        assert!(events.iter().all(|e| e.kind == CrisisMetricKind::R0));
+        assert!(events[0].tick < events[1].tick);
+        assert_eq!((events[0].tick, events[1].tick), (3, 6));
+        assert!(telemetry.drain_threshold_crossings().is_empty());
+    }
+
+    /// Band changes of an r0 gauge (warn 0.9) fed a value wobbling ±0.01 around its threshold.
+    fn band_changes_hovering_on_warn(exit_margin: f32) -> usize {
+        let threshold = CrisisTelemetryThreshold {
+            warn: 0.9,
+            critical: 1.2,
+            ..Default::default()
+        };
+        let params = GaugeParameters {
+            alpha: 0.35,
+            history_depth: 6,
+            trend_window: 5,
+            exit_margin,
+        };
+        let mut gauge = CrisisGauge::new(CrisisMetricKind::R0, threshold, params);
+        (0..20)
+            .filter_map(|tick| gauge.update(tick, if tick % 2 == 0 { 0.91 } else { 0.89 }))
+            .count()
+    }
+
+    #[test]
+    fn band_hysteresis_holds_a_gauge_hovering_on_a_threshold() {
+        assert_eq!(
+            band_changes_hovering_on_warn(0.0),
+            20,
+            "flickers every tick"
+        );
+        assert_eq!(
+            band_changes_hovering_on_warn(0.05),
+            1,
+            "enters warn once and stays"
+        );
+    }
+
+    #[test]
+    fn band_hysteresis_releases_once_the_value_clears_the_margin() {
+        let mut telemetry = CrisisTelemetry::default();
+        // r0 critical 1.2, default margin 0.05: critical holds down to 1.14, warn down to 0.855.
+        for (tick, value) in [1.25, 1.15, 1.13, 0.86, 0.85].into_iter().enumerate() {
+            telemetry.record_metric(tick as u64, CrisisMetricKind::R0, value);
+            let band = telemetry
+                .snapshot(tick as u64)
+                .gauge(CrisisMetricKind::R0)
+                .unwrap()
+                .band;
+            let expected = [
+                CrisisSeverityBand::Critical,
+                CrisisSeverityBand::Critical,
+                CrisisSeverityBand::Warn,
+                CrisisSeverityBand::Warn,
+                CrisisSeverityBand::Safe,
+            ][tick];
+            assert_eq!(band, expected, "tick {tick} at {value}");
+        }
+    }
+
+    #[test]
+    fn ema_crossings_do_not_flicker_under_hysteresis() {
+        let crossings = |band_exit_margin: f32| {
+            let mut telemetry = CrisisTelemetry::from_config(&CrisisTelemetryConfig {
+                band_exit_margin,
+                ..CrisisTelemetryConfig::default()
+            });
+            // Seeded on the threshold, so the EMA wobbles narrowly around 70.
+            for tick in 0..20u64 {
+                let value = if tick % 2 == 0 { 70.5 } else { 69.0 };
+                telemetry.record_metric(tick, CrisisMetricKind::GridStressPct, value);
+            }
+            telemetry.drain_threshold_crossings().len()
+        };
+        assert!(crossings(0.0) > 4, "EMA flickers without hysteresis");
+        assert_eq!(crossings(0.05), 1);
+    }
+
+    #[test]
+    fn advance_crisis_system_emits_pending_threshold_events() {
+        let mut app = App::new();
+        app.insert_resource(SimulationConfig::default());
+        app.insert_resource(SimulationTick(3));
+        app.insert_resource(PendingCrisisSeeds::default());
+        app.insert_resource(PendingCrisisSpawns::default());
+        app.insert_resource(PendingCrisisContainment::default());
+        app.insert_resource(FactionInventory::default());
+        app.insert_resource(ActiveCrisisLedger::default());
+        app.insert_resource(CrisisOverlayCache::default());
+        app.insert_resource(HerdDensityMap::default());
+        app.add_event::<CrisisThresholdEvent>();
+        let telemetry_cfg = CrisisTelemetryConfig::builtin();
+        app.insert_resource(CrisisArchetypeCatalogHandle::new(
+            CrisisArchetypeCatalog::builtin(),
+        ));
+        app.insert_resource(CrisisModifierCatalogHandle::new(
+            CrisisModifierCatalog::builtin(),
+        ));
+        app.insert_resource(CrisisTelemetryConfigHandle::new(telemetry_cfg.clone()));
+        let mut telemetry = CrisisTelemetry::from_config(telemetry_cfg.as_ref());
+        telemetry.record_metric(2, CrisisMetricKind::GridStressPct, 90.0);
+        app.insert_resource(telemetry);
+
+        app.world.run_system_once(advance_crisis_system);
+
+        let events = app.world.resource::<Events<CrisisThresholdEvent>>();
+        let sent: Vec<_> = events.get_reader().read(events).copied().collect();
+        assert_eq!(
+            sent.first(),
+            Some(&CrisisThresholdEvent {
+                kind: CrisisMetricKind::GridStressPct,
+                from: CrisisSeverityBand::Safe,
+                to: CrisisSeverityBand::Critical,
+                tick: 2,
+            })
+        );
+        assert!(app
+            .world
+            .resource_mut::<CrisisTelemetry>()
+            .drain_threshold_crossings()
+            .is_empty());
+    }
+
     #[test]
     fn crisis_overlay_generation() {
         let mut app = App::new();
@@ -1378,9 +2525,12 @@ mod tests {
         app.insert_resource(SimulationTick(0));
         app.insert_resource(PendingCrisisSeeds::default());
         app.insert_resource(PendingCrisisSpawns::default());
+        app.insert_resource(PendingCrisisContainment::default());
+        app.insert_resource(FactionInventory::default());
         app.insert_resource(ActiveCrisisLedger::default());
         app.insert_resource(CrisisOverlayCache::default());
         app.insert_resource(HerdDensityMap::default());
+        app.add_event::<CrisisThresholdEvent>();
 
         let archetypes = CrisisArchetypeCatalog::builtin();
         let modifiers = CrisisModifierCatalog::builtin();
@@ -1423,9 +2573,12 @@ mod tests {
         app.insert_resource(SimulationTick(0));
         app.insert_resource(PendingCrisisSeeds::default());
         app.insert_resource(PendingCrisisSpawns::default());
+        app.insert_resource(PendingCrisisContainment::default());
+        app.insert_resource(FactionInventory::default());
         app.insert_resource(ActiveCrisisLedger::default());
         app.insert_resource(CrisisOverlayCache::default());
         app.insert_resource(HerdDensityMap::default());
+        app.add_event::<CrisisThresholdEvent>();
 
         let archetypes = CrisisArchetypeCatalog::builtin();
         let modifiers = CrisisModifierCatalog::builtin();
@@ -1445,4 +2598,667 @@ mod tests {
             "crisis overlay auto-seeding should produce non-zero samples"
         );
     }
+
+    fn modifier(category: &str, r0_delta: f32) -> ActiveModifier {
+        ActiveModifier {
+            _id: format!("{category}_{r0_delta}"),
+            category: Some(category.to_string()),
+            effects: ModifierEffects {
+                r0_delta,
+                ..Default::default()
+            },
+        }
+    }
+
+    fn stacked_r0(
+        modifiers: &[ActiveModifier],
+        rule: CrisisModifierStackingRule,
+        falloff: f32,
+    ) -> f32 {
+        let stacking = CrisisModifierStackingConfig {
+            default: CrisisModifierStacking::default(),
+            categories: [(
+                "biological".to_string(),
+                CrisisModifierStacking { rule, falloff },
+            )]
+            .into_iter()
+            .collect(),
+        };
+        stack_modifier_effects(modifiers, &stacking).r0_delta
+    }
+
+    #[test]
+    fn same_kind_modifiers_stack_under_the_configured_rule() {
+        let three = [
+            modifier("biological", 0.1),
+            modifier("biological", 0.3),
+            modifier("biological", 0.2),
+        ];
+        let cases = [
+            (CrisisModifierStackingRule::Additive, 1.0, 0.6),
+            // Strongest first: 0.3 + 0.2 * 0.5 + 0.1 * 0.25.
+            (CrisisModifierStackingRule::Additive, 0.5, 0.425),
+            (
+                CrisisModifierStackingRule::Multiplicative,
+                1.0,
+                1.3 * 1.2 * 1.1 - 1.0,
+            ),
+            (
+                CrisisModifierStackingRule::Multiplicative,
+                0.5,
+                1.3 * 1.1 * 1.025 - 1.0,
+            ),
+            (CrisisModifierStackingRule::MaxOnly, 1.0, 0.3),
+            (CrisisModifierStackingRule::MaxOnly, 0.5, 0.3),
+        ];
+        for (rule, falloff, expected) in cases {
+            let combined = stacked_r0(&three, rule, falloff);
+            assert!(
+                (combined - expected).abs() < 1e-5,
+                "{rule:?} falloff {falloff}: expected {expected}, got {combined}"
+            );
+        }
+    }
+
+    #[test]
+    fn stacking_nets_mitigation_and_sums_across_kinds() {
+        let mixed = [
+            modifier("biological", 0.3),
+            modifier("biological", 0.2),
+            modifier("biological", -0.1),
+            // A different kind uses the default (additive) rule and is added on top.
+            modifier("infrastructure", 0.05),
+        ];
+        let combined = stacked_r0(&mixed, CrisisModifierStackingRule::MaxOnly, 1.0);
+        assert!((combined - 0.25).abs() < 1e-6, "got {combined}");
+    }
+
+    fn contagion_crisis(r0: f32, containment: f32) -> ActiveCrisis {
+        let runtime = CrisisArchetypeRuntime {
+            id: "test_contagion".to_string(),
+            name: "Test Contagion".to_string(),
+            base_r0: r0,
+            max_r0: r0,
+            base_growth: 0.0,
+            incident_acceleration: 0.0,
+            telemetry: CrisisTelemetryWeights::default(),
+            incidents: Vec::new(),
+            critical_intensity: default_trigger_for(CrisisSeverityBand::Critical),
+            contagion: Some(CrisisContagionRuntime {
+                spread_rate: 0.1,
+                density_weight: 0.5,
+            }),
+            escalates_to: Vec::new(),
+            _overlay_palette: None,
+            _annotation_glyph: None,
+        };
+        let hotspots = vec![CrisisHotspot {
+            position: UVec2::new(16, 16),
+            radius: 2.0,
+        }];
+        let modifiers = vec![ActiveModifier {
+            _id: "quarantine".to_string(),
+            category: Some("biological".to_string()),
+            effects: ModifierEffects {
+                containment,
+                ..Default::default()
+            },
+        }];
+        ActiveCrisis::new(
+            FactionId(0),
+            0,
+            runtime,
+            hotspots,
+            modifiers,
+            &CrisisModifierStackingConfig::default(),
+            42,
+        )
+    }
+
+    fn footprint_after(r0: f32, containment: f32, turns: usize) -> BTreeSet<(u32, u32)> {
+        let grid_size = UVec2::new(32, 32);
+        let density = vec![0.5f32; 32 * 32];
+        let mut crisis = contagion_crisis(r0, containment);
+        for _ in 0..turns {
+            crisis.advance();
+            crisis.spread(grid_size, &density);
+        }
+        crisis.footprint
+    }
+
+    #[test]
+    fn higher_r0_grows_the_contagion_footprint_faster() {
+        let low = footprint_after(0.8, 0.0, 10);
+        let high = footprint_after(2.4, 0.0, 10);
+        assert!(
+            high.len() > low.len() * 2,
+            "R0 2.4 reached {} tiles, R0 0.8 reached {}",
+            high.len(),
+            low.len()
+        );
+        assert_eq!(
+            high,
+            footprint_after(2.4, 0.0, 10),
+            "the same spawn seed replays the same footprint"
+        );
+    }
+
+    #[test]
+    fn containment_slows_contagion_spread() {
+        let open = footprint_after(2.4, 0.0, 10);
+        let contained = footprint_after(2.4, 0.7, 10);
+        assert!(
+            contained.len() < open.len(),
+            "containment reached {} tiles, uncontained {}",
+            contained.len(),
+            open.len()
+        );
+        assert_eq!(
+            footprint_after(2.4, 1.0, 10).len(),
+            1,
+            "full containment holds the footprint at its seed"
+        );
+    }
+
+    #[test]
+    fn ledger_ids_start_at_one_and_resolve_the_owning_faction() {
+        let mut ledger = ActiveCrisisLedger::default();
+        assert_eq!(ledger.push(contagion_crisis(2.4, 0.0)), 1);
+        assert_eq!(ledger.push(contagion_crisis(2.4, 0.0)), 2);
+        assert_eq!(ledger.faction_of(2), Some(FactionId(0)));
+        assert_eq!(ledger.faction_of(0), None);
+        assert_eq!(ledger.faction_of(3), None);
+    }
+
+    #[test]
+    fn contained_crisis_spreads_slower_until_the_order_lapses() {
+        let mut app = App::new();
+        app.insert_resource(SimulationConfig {
+            grid_size: UVec2::new(32, 32),
+            ..SimulationConfig::default()
+        });
+        app.insert_resource(SimulationTick(0));
+        app.insert_resource(PendingCrisisSeeds::default());
+        app.insert_resource(PendingCrisisSpawns::default());
+        app.insert_resource(PendingCrisisContainment::default());
+        app.insert_resource(FactionInventory::default());
+        app.insert_resource(TurnWarnings::default());
+        app.insert_resource(CrisisOverlayCache::default());
+        app.insert_resource(HerdDensityMap::default());
+        app.add_event::<CrisisThresholdEvent>();
+        let telemetry_cfg = CrisisTelemetryConfig::builtin();
+        app.insert_resource(CrisisArchetypeCatalogHandle::new(Arc::new(
+            CrisisArchetypeCatalog::default(),
+        )));
+        app.insert_resource(CrisisModifierCatalogHandle::new(Arc::new(
+            CrisisModifierCatalog::default(),
+        )));
+        app.insert_resource(CrisisTelemetryConfigHandle::new(telemetry_cfg.clone()));
+        app.insert_resource(CrisisTelemetry::from_config(telemetry_cfg.as_ref()));
+
+        // Two crises from the same spawn seed; only the first is contained. At R0 20 the open one
+        // takes every neighbour each turn.
+        let mut ledger = ActiveCrisisLedger::default();
+        let contained_id = ledger.push(contagion_crisis(20.0, 0.0));
+        let open_id = ledger.push(contagion_crisis(20.0, 0.0));
+        app.insert_resource(ledger);
+        {
+            let mut pending = app.world.resource_mut::<PendingCrisisContainment>();
+            pending.push(CrisisContainmentOrder {
+                faction: FactionId(0),
+                crisis_id: contained_id,
+                effort: 10,
+                cost_item: "trade_goods".to_string(),
+                cost: 20,
+            });
+            pending.push(CrisisContainmentOrder {
+                faction: FactionId(0),
+                crisis_id: 99,
+                effort: 1,
+                cost_item: "trade_goods".to_string(),
+                cost: 2,
+            });
+        }
+
+        let footprint = |app: &App, id: u64| {
+            let ledger = app.world.resource::<ActiveCrisisLedger>();
+            let crisis = ledger
+                .entries()
+                .iter()
+                .find(|crisis| crisis.crisis_id == id)
+                .expect("crisis stays active");
+            (crisis.footprint.len(), crisis.containment, crisis.r0)
+        };
+
+        step(&mut app);
+        let (contained, containment, contained_r0) = footprint(&app, contained_id);
+        let (open, _, open_r0) = footprint(&app, open_id);
+        let cfg = CrisisContainmentConfig::default();
+        assert!(
+            (containment - cfg.max_containment).abs() < 1e-6,
+            "got {containment}"
+        );
+        assert!((contained_r0 - open_r0 * (1.0 - cfg.max_containment)).abs() < 1e-4);
+        assert!(
+            contained < open,
+            "contained crisis reached {contained} tiles, uncontained {open}"
+        );
+        assert_eq!(
+            app.world.resource::<TurnWarnings>().iter().count(),
+            1,
+            "the order naming an unknown crisis is reported"
+        );
+        let refunded = app
+            .world
+            .resource::<FactionInventory>()
+            .stockpile(FactionId(0))
+            .and_then(|stockpile| stockpile.get("trade_goods"))
+            .copied();
+        assert_eq!(
+            refunded,
+            Some(2),
+            "only the unknown crisis' order is refunded"
+        );
+
+        for _ in 1..cfg.duration_turns {
+            step(&mut app);
+        }
+        let (_, containment, contained_r0) = footprint(&app, contained_id);
+        assert_eq!(containment, 0.0, "containment lapses after its duration");
+        step(&mut app);
+        let (_, _, after_r0) = footprint(&app, contained_id);
+        assert!(contained_r0 < after_r0, "R0 recovers once the order lapses");
+    }
+
+    #[test]
+    fn entry_states_carry_each_crisis_tiles() {
+        let mut ledger = ActiveCrisisLedger::default();
+        let mut crisis = contagion_crisis(2.4, 0.0);
+        crisis.footprint.clear();
+        crisis.footprint.extend([(20, 16), (16, 16)]);
+        ledger.push(crisis);
+
+        // The hotspot centre and the footprint, once each, ascending by `(x, y)`.
+        assert_eq!(ledger.entry_states()[0].tiles, vec![16, 16, 20, 16]);
+    }
+
+    #[test]
+    fn denser_neighbours_catch_the_contagion_sooner() {
+        let contagion = CrisisContagionRuntime {
+            spread_rate: 0.1,
+            density_weight: 0.5,
+        };
+        let sparse = contagion.spread_chance(2.0, 0.0, 0.0);
+        let dense = contagion.spread_chance(2.0, 1.0, 0.0);
+        assert!((sparse - 0.1).abs() < 1e-6, "got {sparse}");
+        assert!((dense - 0.2).abs() < 1e-6, "got {dense}");
+        assert_eq!(contagion.spread_chance(2.0, 1.0, 1.0), 0.0);
+    }
+
+    #[test]
+    fn modifier_catalog_parses_and_validates_stacking() {
+        let json = r#"{
+            "stacking": {
+                "default": {"rule": "multiplicative", "falloff": 0.75},
+                "categories": {"AI": {"rule": "max_only"}}
+            },
+            "modifiers": []
+        }"#;
+        let catalog = CrisisModifierCatalog::from_json_str(json).expect("valid stacking");
+        assert_eq!(
+            catalog.stacking.for_category(Some("ai")).rule,
+            CrisisModifierStackingRule::MaxOnly
+        );
+        assert_eq!(catalog.stacking.for_category(Some("ai")).falloff, 1.0);
+        assert_eq!(catalog.stacking.for_category(None).falloff, 0.75);
+
+        let zero = r#"{"stacking": {"default": {"falloff": 0.0}}, "modifiers": []}"#;
+        assert!(CrisisModifierCatalog::from_json_str(zero).is_err());
+    }
+
+    fn escalation_app(archetypes_json: &str) -> App {
+        let mut app = App::new();
+        app.insert_resource(SimulationConfig {
+            grid_size: UVec2::new(8, 6),
+            ..SimulationConfig::default()
+        });
+        app.insert_resource(SimulationTick(0));
+        app.insert_resource(PendingCrisisSeeds::default());
+        app.insert_resource(PendingCrisisSpawns::default());
+        app.insert_resource(PendingCrisisContainment::default());
+        app.insert_resource(FactionInventory::default());
+        app.insert_resource(ActiveCrisisLedger::default());
+        app.insert_resource(CrisisOverlayCache::default());
+        app.insert_resource(HerdDensityMap::default());
+        app.add_event::<CrisisThresholdEvent>();
+        let archetypes =
+            CrisisArchetypeCatalog::from_json_str(archetypes_json).expect("catalog validates");
+        let telemetry_cfg = CrisisTelemetryConfig::builtin();
+        app.insert_resource(CrisisArchetypeCatalogHandle::new(Arc::new(archetypes)));
+        app.insert_resource(CrisisModifierCatalogHandle::new(Arc::new(
+            CrisisModifierCatalog::default(),
+        )));
+        app.insert_resource(CrisisTelemetryConfigHandle::new(telemetry_cfg.clone()));
+        app.insert_resource(CrisisTelemetry::from_config(telemetry_cfg.as_ref()));
+        app.world
+            .resource_mut::<PendingCrisisSpawns>()
+            .push(FactionId(0), "grid_failure");
+        app
+    }
+
+    /// Run the crisis system for one turn and advance the tick.
+    fn step(app: &mut App) {
+        app.world.run_system_once(advance_crisis_system);
+        app.world.resource_mut::<SimulationTick>().0 += 1;
+    }
+
+    #[test]
+    fn follow_ons_roll_at_their_configured_chance() {
+        let table = vec![
+            ("civil_unrest".to_string(), 0.3),
+            ("famine".to_string(), 1.0),
+            ("never".to_string(), 0.0),
+        ];
+        let trials = 4000u64;
+        let mut unrest = 0u64;
+        for seed in 0..trials {
+            let rolled = roll_follow_ons(&table, seed);
+            assert!(rolled.contains(&"famine"));
+            assert!(!rolled.contains(&"never"));
+            unrest += u64::from(rolled.contains(&"civil_unrest"));
+        }
+        let rate = unrest as f32 / trials as f32;
+        assert!((rate - 0.3).abs() < 0.03, "civil_unrest rolled {rate}");
+        assert_eq!(roll_follow_ons(&table, 17), roll_follow_ons(&table, 17));
+    }
+
+    #[test]
+    fn a_crisis_reaching_critical_queues_its_follow_on_once() {
+        let mut app = escalation_app(
+            r#"{"archetypes": [
+                {"id": "grid_failure", "name": "Grid Failure",
+                 "propagation": {"base_growth": 0.2},
+                 "escalates_to": [["Civil_Unrest", 1.0]]},
+                {"id": "civil_unrest", "name": "Civil Unrest", "propagation": {}}
+            ]}"#,
+        );
+
+        let mut queued_at = None;
+        for turn in 0..10 {
+            step(&mut app);
+            let follow_ons = app
+                .world
+                .resource::<PendingCrisisSeeds>()
+                .follow_ons
+                .clone();
+            if !follow_ons.is_empty() {
+                assert_eq!(
+                    follow_ons,
+                    vec![CrisisFollowOn {
+                        faction: FactionId(0),
+                        archetype: "civil_unrest".to_string(),
+                        chain_depth: 1,
+                    }]
+                );
+                assert!(app.world.resource::<ActiveCrisisLedger>().entries()[0].is_critical());
+                queued_at = Some(turn);
+                break;
+            }
+            assert!(!app.world.resource::<ActiveCrisisLedger>().entries()[0].is_critical());
+        }
+        assert!(queued_at.is_some(), "grid failure never turned critical");
+
+        for _ in 0..10 {
+            step(&mut app);
+        }
+        let ledger = app.world.resource::<ActiveCrisisLedger>();
+        let names: Vec<_> = ledger.entries().iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, vec!["Grid Failure", "Civil Unrest"]);
+        assert_eq!(ledger.entries()[1].chain_depth, 1);
+    }
+
+    #[test]
+    fn a_configured_critical_trigger_sets_when_a_crisis_escalates() {
+        let mut app = escalation_app(
+            r#"{"archetypes": [
+                {"id": "grid_failure", "name": "Grid Failure",
+                 "propagation": {"base_growth": 0.2},
+                 "incident_table": [
+                     {"id": "blackout", "severity": "critical", "trigger_intensity": 0.3}
+                 ],
+                 "escalates_to": [["grid_failure", 1.0]]}
+            ]}"#,
+        );
+
+        // Intensity passes 0.3 on the first turn, well short of the default 0.68.
+        step(&mut app);
+        let crisis = &app.world.resource::<ActiveCrisisLedger>().entries()[0];
+        assert!(crisis.intensity < default_trigger_for(CrisisSeverityBand::Critical));
+        assert!(crisis.is_critical());
+        assert_eq!(
+            app.world.resource::<PendingCrisisSeeds>().follow_ons.len(),
+            1
+        );
+
+        step(&mut app);
+        let ledger = app.world.resource::<ActiveCrisisLedger>();
+        assert_eq!(ledger.entries().len(), 2);
+        assert_eq!(ledger.entries()[1].chain_depth, 1);
+        assert_ne!(
+            ledger.entries()[1].escalation_seed,
+            ledger.entries()[0].escalation_seed
+        );
+    }
+
+    #[test]
+    fn restoring_escalation_state_does_not_roll_follow_ons_again() {
+        let mut app = escalation_app(
+            r#"{"archetypes": [
+                {"id": "grid_failure", "name": "Grid Failure",
+                 "propagation": {"base_growth": 0.2},
+                 "escalates_to": [["civil_unrest", 1.0]]},
+                {"id": "civil_unrest", "name": "Civil Unrest", "propagation": {}}
+            ]}"#,
+        );
+        for _ in 0..10 {
+            step(&mut app);
+            if !app
+                .world
+                .resource::<PendingCrisisSeeds>()
+                .follow_ons
+                .is_empty()
+            {
+                break;
+            }
+        }
+        let crises = app.world.resource::<ActiveCrisisLedger>().entry_states();
+        let follow_ons = app
+            .world
+            .resource::<PendingCrisisSeeds>()
+            .follow_on_states();
+        assert!(crises[0].escalated);
+        assert_eq!(follow_ons.len(), 1);
+
+        step(&mut app);
+        step(&mut app);
+        assert_eq!(app.world.resource::<ActiveCrisisLedger>().len(), 2);
+
+        app.world
+            .resource_mut::<ActiveCrisisLedger>()
+            .restore_entry_states(&crises);
+        app.world
+            .resource_mut::<PendingCrisisSeeds>()
+            .restore_follow_ons(&follow_ons);
+        assert_eq!(
+            app.world.resource::<ActiveCrisisLedger>().entry_states(),
+            crises
+        );
+
+        step(&mut app);
+        let ledger = app.world.resource::<ActiveCrisisLedger>();
+        let ids: Vec<_> = ledger.entries().iter().map(|c| c.crisis_id).collect();
+        assert_eq!(ids, vec![1, 2]);
+        assert!(app
+            .world
+            .resource::<PendingCrisisSeeds>()
+            .follow_ons
+            .is_empty());
+    }
+
+    const QUEUE_CATALOG: &str = r#"{"archetypes": [
+        {"id": "grid_failure", "name": "Grid Failure", "propagation": {}},
+        {"id": "civil_unrest", "name": "Civil Unrest", "propagation": {}}
+    ]}"#;
+
+    /// Crisis id, faction, archetype and hotspot positions of every ledger entry.
+    fn ledger_signature(app: &App) -> Vec<(u64, u32, String, Vec<UVec2>)> {
+        app.world
+            .resource::<ActiveCrisisLedger>()
+            .entries()
+            .iter()
+            .map(|crisis| {
+                (
+                    crisis.crisis_id,
+                    crisis.faction.0,
+                    crisis.archetype_id.clone(),
+                    crisis
+                        .centers
+                        .iter()
+                        .map(|hotspot| hotspot.position)
+                        .collect(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn a_crisis_queued_twice_spawns_once() {
+        let mut app = escalation_app(QUEUE_CATALOG);
+        {
+            let mut spawns = app.world.resource_mut::<PendingCrisisSpawns>();
+            spawns.push(FactionId(0), "grid_failure");
+            spawns.push(FactionId(0), "Grid_Failure");
+            // Discovery 1 seeds `civil_unrest` for faction 1 below: a spawn of the same archetype
+            // for the same faction is the same request.
+            spawns.push(FactionId(1), "Civil_Unrest");
+        }
+        {
+            let mut seeds = app.world.resource_mut::<PendingCrisisSeeds>();
+            seeds.push(FactionId(1), 1);
+            seeds.push(FactionId(1), 1);
+        }
+        step(&mut app);
+
+        let signature = ledger_signature(&app);
+        let spawned: Vec<_> = signature
+            .iter()
+            .map(|(_, faction, archetype, _)| (*faction, archetype.as_str()))
+            .collect();
+        assert_eq!(spawned, vec![(1, "civil_unrest"), (0, "grid_failure")]);
+    }
+
+    #[test]
+    fn a_discovery_seed_audits_the_crisis_it_spawned() {
+        let mut app = escalation_app(QUEUE_CATALOG);
+        app.insert_resource(GreatDiscoveryTelemetry::default());
+        app.world
+            .resource_mut::<PendingCrisisSeeds>()
+            .push(FactionId(1), 1);
+        step(&mut app);
+
+        let (crisis_id, _, _, centers) = ledger_signature(&app)
+            .into_iter()
+            .find(|(_, faction, _, _)| *faction == 1)
+            .expect("the seed spawned a crisis");
+        let telemetry = app.world.resource::<GreatDiscoveryTelemetry>();
+        let entry = telemetry
+            .effect_audit
+            .back()
+            .expect("the spawn was audited");
+        assert_eq!(entry.kind, GreatDiscoveryEffectKind::Crisis);
+        assert_eq!(entry.discovery, GreatDiscoveryId(1));
+        assert_eq!(entry.target_id, crisis_id);
+        assert_eq!(entry.magnitude, Scalar::from_u32(centers.len() as u32));
+    }
+
+    #[test]
+    fn queue_order_does_not_change_the_spawned_crises() {
+        let run = |reverse: bool| {
+            let mut app = escalation_app(QUEUE_CATALOG);
+            let mut spawns = vec![
+                (FactionId(2), "civil_unrest"),
+                (FactionId(1), "grid_failure"),
+            ];
+            let mut seeds = vec![(FactionId(3), 0), (FactionId(1), 1), (FactionId(2), 0)];
+            if reverse {
+                spawns.reverse();
+                seeds.reverse();
+            }
+            for (faction, archetype) in spawns {
+                app.world
+                    .resource_mut::<PendingCrisisSpawns>()
+                    .push(faction, archetype);
+            }
+            for (faction, discovery_id) in seeds {
+                app.world
+                    .resource_mut::<PendingCrisisSeeds>()
+                    .push(faction, discovery_id);
+            }
+            step(&mut app);
+            ledger_signature(&app)
+        };
+
+        let forward = run(false);
+        assert_eq!(forward.len(), 6);
+        assert_eq!(forward, run(true));
+    }
+
+    #[test]
+    fn escalation_chains_stop_at_the_depth_cap() {
+        let mut app = escalation_app(
+            r#"{"max_escalation_depth": 2, "archetypes": [
+                {"id": "grid_failure", "name": "Grid Failure",
+                 "propagation": {"base_growth": 0.2},
+                 "escalates_to": [["grid_failure", 1.0]]}
+            ]}"#,
+        );
+        for _ in 0..40 {
+            step(&mut app);
+        }
+        let depths: Vec<_> = app
+            .world
+            .resource::<ActiveCrisisLedger>()
+            .entries()
+            .iter()
+            .map(|crisis| crisis.chain_depth)
+            .collect();
+        assert_eq!(depths, vec![0, 1, 2]);
+        assert!(app
+            .world
+            .resource::<PendingCrisisSeeds>()
+            .follow_ons
+            .is_empty());
+    }
+
+    #[test]
+    fn escalation_targets_must_exist_and_chances_be_probabilities() {
+        let unknown = r#"{"archetypes": [
+            {"id": "grid_failure", "name": "Grid Failure", "escalates_to": [["famine", 0.5]]}
+        ]}"#;
+        assert!(matches!(
+            CrisisArchetypeCatalog::from_json_str(unknown),
+            Err(CrisisArchetypeCatalogError::UnknownEscalation { to, .. }) if to == "famine"
+        ));
+        let overdrawn = r#"{"archetypes": [
+            {"id": "grid_failure", "name": "Grid Failure", "escalates_to": [["grid_failure", 1.5]]}
+        ]}"#;
+        assert!(matches!(
+            CrisisArchetypeCatalog::from_json_str(overdrawn),
+            Err(CrisisArchetypeCatalogError::InvalidEscalationChance { .. })
+        ));
+        assert_eq!(
+            CrisisArchetypeCatalog::builtin().escalation_depth_cap(),
+            DEFAULT_CRISIS_ESCALATION_DEPTH
+        );
+    }
 }
@@ -21,11 +21,18 @@ pub const BUILTIN_CRISIS_ARCHETYPES: &str = include_str!("data/crisis_archetypes
 pub const BUILTIN_CRISIS_MODIFIERS: &str = include_str!("data/crisis_modifiers.json");
 pub const BUILTIN_CRISIS_TELEMETRY_CONFIG: &str = include_str!("data/crisis_telemetry_config.json");
 
+/// Follow-on generations allowed below a root crisis when the catalog sets no
+/// `max_escalation_depth`.
+pub const DEFAULT_CRISIS_ESCALATION_DEPTH: u8 = 3;
+
 #[derive(Debug, Clone, Default, Deserialize)]
 #[serde(default)]
 pub struct CrisisArchetypeCatalog {
     pub version: u32,
     pub archetypes: Vec<CrisisArchetype>,
+    /// Deepest escalation chain: a crisis `max_escalation_depth` follow-ons below its root no
+    /// longer escalates. Caps self- and mutually-escalating archetypes.
+    pub max_escalation_depth: Option<u8>,
     #[serde(flatten)]
     pub extra: BTreeMap<String, Value>,
 }
@@ -57,6 +64,11 @@ impl CrisisArchetypeCatalog {
         self.archetypes.iter().find(|entry| entry.id == id)
     }
 
+    pub fn escalation_depth_cap(&self) -> u8 {
+        self.max_escalation_depth
+            .unwrap_or(DEFAULT_CRISIS_ESCALATION_DEPTH)
+    }
+
     fn validate(&mut self) -> Result<(), CrisisArchetypeCatalogError> {
         let mut seen = HashSet::new();
         let mut expanded = Vec::new();
@@ -82,6 +94,23 @@ impl CrisisArchetypeCatalog {
             }
             expanded.push(archetype);
         }
+        for archetype in &expanded {
+            for (target, chance) in &archetype.escalates_to {
+                if !seen.contains(target) {
+                    return Err(CrisisArchetypeCatalogError::UnknownEscalation {
+                        from: archetype.id.clone(),
+                        to: target.clone(),
+                    });
+                }
+                if !(0.0..=1.0).contains(chance) {
+                    return Err(CrisisArchetypeCatalogError::InvalidEscalationChance {
+                        from: archetype.id.clone(),
+                        to: target.clone(),
+                        chance: *chance,
+                    });
+                }
+            }
+        }
         self.archetypes = expanded;
         Ok(())
     }
@@ -96,6 +125,9 @@ pub struct CrisisArchetype {
     pub tags: Vec<String>,
     pub synopsis: Option<String>,
     pub generator: Option<CrisisArchetypeGeneratorEntry>,
+    /// Follow-on crises as `[archetype_id, chance]` pairs. The first turn this crisis reaches
+    /// critical intensity, each pair is rolled once and a hit queues the follow-on.
+    pub escalates_to: Vec<(String, f32)>,
     #[serde(flatten)]
     pub extra: BTreeMap<String, Value>,
 }
@@ -106,6 +138,9 @@ impl CrisisArchetype {
         self.tags
             .iter_mut()
             .for_each(|tag| tag.make_ascii_lowercase());
+        self.escalates_to
+            .iter_mut()
+            .for_each(|(target, _)| target.make_ascii_lowercase());
     }
 }
 
@@ -121,6 +156,14 @@ pub enum CrisisArchetypeCatalogError {
     },
     #[error("duplicate crisis archetype id {id}")]
     Duplicate { id: String },
+    #[error("crisis archetype {from} escalates to unknown archetype {to}")]
+    UnknownEscalation { from: String, to: String },
+    #[error("crisis archetype {from} escalates to {to} with chance {chance} outside 0..=1")]
+    InvalidEscalationChance {
+        from: String,
+        to: String,
+        chance: f32,
+    },
 }
 
 #[derive(Debug, Clone, Default, Deserialize)]
@@ -128,6 +171,7 @@ pub enum CrisisArchetypeCatalogError {
 pub struct CrisisModifierCatalog {
     pub version: u32,
     pub modifiers: Vec<CrisisModifier>,
+    pub stacking: CrisisModifierStackingConfig,
     #[serde(flatten)]
     pub extra: BTreeMap<String, Value>,
 }
@@ -160,6 +204,7 @@ impl CrisisModifierCatalog {
     }
 
     fn validate(&mut self) -> Result<(), CrisisModifierCatalogError> {
+        self.stacking.validate()?;
         let mut seen = HashSet::new();
         let mut expanded = Vec::new();
         for mut modifier in self.modifiers.drain(..) {
@@ -226,6 +271,80 @@ pub enum CrisisModifierCatalogError {
     },
     #[error("duplicate crisis modifier id {id}")]
     Duplicate { id: String },
+    #[error("crisis modifier stacking for {category} has falloff {falloff}; expected (0, 1]")]
+    InvalidStacking { category: String, falloff: f32 },
+}
+
+/// How several active modifiers of one kind (their `category`) combine into a single effect.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CrisisModifierStackingRule {
+    /// Effects sum.
+    #[default]
+    Additive,
+    /// Effects compound, `Π(1 + effect) − 1`. Reads best on fractional effects (`r0_delta`,
+    /// `overlay_multiplier`); on percentage-point effects it escalates quickly.
+    Multiplicative,
+    /// Only the strongest effect applies.
+    MaxOnly,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct CrisisModifierStacking {
+    pub rule: CrisisModifierStackingRule,
+    /// Diminishing returns: the n-th strongest same-kind effect (0-based) counts at `falloff^n` of
+    /// its face value before the rule combines them. `1.0` disables the curve.
+    pub falloff: f32,
+}
+
+impl Default for CrisisModifierStacking {
+    fn default() -> Self {
+        Self {
+            rule: CrisisModifierStackingRule::Additive,
+            falloff: 1.0,
+        }
+    }
+}
+
+/// Per-kind stacking rules. Aggravating and mitigating effects stack separately under the rule and
+/// then net out, so a strong aggravator never swallows a countermeasure under `max_only`.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct CrisisModifierStackingConfig {
+    /// Applies to categories without their own entry, and to uncategorized modifiers.
+    pub default: CrisisModifierStacking,
+    pub categories: BTreeMap<String, CrisisModifierStacking>,
+}
+
+impl CrisisModifierStackingConfig {
+    pub fn for_category(&self, category: Option<&str>) -> CrisisModifierStacking {
+        category
+            .and_then(|category| self.categories.get(category))
+            .copied()
+            .unwrap_or(self.default)
+    }
+
+    fn validate(&mut self) -> Result<(), CrisisModifierCatalogError> {
+        self.categories = std::mem::take(&mut self.categories)
+            .into_iter()
+            .map(|(category, stacking)| (category.to_ascii_lowercase(), stacking))
+            .collect();
+        let entries = std::iter::once(("default", &self.default)).chain(
+            self.categories
+                .iter()
+                .map(|(category, stacking)| (category.as_str(), stacking)),
+        );
+        for (category, stacking) in entries {
+            if !(stacking.falloff > 0.0 && stacking.falloff <= 1.0) {
+                return Err(CrisisModifierCatalogError::InvalidStacking {
+                    category: category.to_string(),
+                    falloff: stacking.falloff,
+                });
+            }
+        }
+        Ok(())
+    }
 }
 
 #[derive(Debug, Clone, Default, Deserialize)]
@@ -709,6 +828,10 @@ pub struct CrisisTelemetryConfig {
     pub stale_tick_warning: u64,
     pub stale_tick_critical: u64,
     pub alert_cooldown_ticks: u64,
+    /// Hysteresis on band downgrades, as a fraction of the threshold being left: a gauge enters a
+    /// band at its threshold but only drops out once below `threshold × (1 − margin)`, so a value
+    /// hovering on a threshold holds its band instead of flickering. `0` disables it.
+    pub band_exit_margin: f32,
     pub gauges: BTreeMap<String, CrisisTelemetryThreshold>,
     #[serde(flatten)]
     pub extra: BTreeMap<String, Value>,
@@ -746,6 +869,7 @@ impl Default for CrisisTelemetryConfig {
             stale_tick_warning: 6,
             stale_tick_critical: 12,
             alert_cooldown_ticks: 5,
+            band_exit_margin: 0.05,
             gauges: {
                 let mut map = BTreeMap::new();
                 map.insert(
@@ -1,15 +1,18 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use bevy::prelude::*;
+use serde::Deserialize;
 use sim_runtime::{
     CultureLayerScope as SchemaLayerScope, CultureLayerState as SchemaCultureLayerState,
-    CultureTensionState as SchemaCultureTensionState, CultureTraitAxis as SchemaCultureTraitAxis,
+    CultureOwnerRef, CultureTensionState as SchemaCultureTensionState,
+    CultureTraitAxis as SchemaCultureTraitAxis,
 };
+use thiserror::Error;
 
 use crate::{
     culture_corruption_config::CulturePropagationSettings,
     influencers::{InfluencerCultureResonance, InfluencerImpacts},
-    resources::SimulationTick,
+    resources::{SimulationTick, TileRegistry},
     scalar::{scalar_from_f32, Scalar},
 };
 
@@ -19,10 +22,11 @@ pub const CULTURE_TRAIT_AXES: usize = 15;
 /// Unique identifier for a culture layer instance.
 pub type CultureLayerId = u32;
 
-/// Opaque owner identifier encoded into snapshots.
+/// Packed owner identifier encoded into snapshots.
 ///
-/// Global layers use `0`, regional layers should encode their region id, and
-/// local layers should encode the entity bits of the owning settlement/cohort.
+/// Global layers use `0`, regional layers their province id tagged with
+/// `CULTURE_OWNER_PROVINCE_TAG`, and local layers the entity bits of the owning tile. Use
+/// [`CultureOwner::decode`] rather than reading the bits directly.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
 pub struct CultureOwner(pub u64);
 
@@ -30,11 +34,24 @@ impl CultureOwner {
     pub const GLOBAL: CultureOwner = CultureOwner(0);
 
     pub fn from_region(region_id: u32) -> Self {
-        CultureOwner(region_id as u64)
+        Self::from_ref(CultureOwnerRef::Province(region_id))
     }
 
     pub fn from_entity(entity: Entity) -> Self {
-        CultureOwner(entity.to_bits())
+        Self::from_ref(CultureOwnerRef::Tile(entity.to_bits()))
+    }
+
+    pub fn from_ref(owner: CultureOwnerRef) -> Self {
+        CultureOwner(owner.encode())
+    }
+
+    /// Split a packed snapshot `owner` into its kind and id.
+    pub fn decode(owner: u64) -> CultureOwnerRef {
+        CultureOwnerRef::decode(owner)
+    }
+
+    pub fn resolve(self) -> CultureOwnerRef {
+        Self::decode(self.0)
     }
 }
 
@@ -47,7 +64,8 @@ pub enum CultureLayerScope {
 }
 
 /// Named axes as described in the game manual.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum CultureTraitAxis {
     PassiveAggressive,
     OpenClosed,
@@ -332,6 +350,9 @@ pub(crate) struct CultureManagerSettings {
     global: ScopeSettings,
     regional: ScopeSettings,
     local: ScopeSettings,
+    merge_divergence_cap: Scalar,
+    /// Per-axis multiplier on trait gaps in `CultureLayer::evaluate_divergence`.
+    divergence_axis_weights: [Scalar; CULTURE_TRAIT_AXES],
 }
 
 impl Default for CultureManagerSettings {
@@ -340,6 +361,8 @@ impl Default for CultureManagerSettings {
             global: ScopeSettings::default_for(CultureLayerScope::Global),
             regional: ScopeSettings::default_for(CultureLayerScope::Regional),
             local: ScopeSettings::default_for(CultureLayerScope::Local),
+            merge_divergence_cap: scalar_from_f32(0.3),
+            divergence_axis_weights: [Scalar::one(); CULTURE_TRAIT_AXES],
         }
     }
 }
@@ -368,6 +391,9 @@ impl CultureManagerSettings {
                 config.local().soft_trigger_ticks(),
                 config.local().hard_trigger_ticks(),
             ),
+            merge_divergence_cap: scalar_from_f32(config.merge_divergence_cap()),
+            divergence_axis_weights: CultureTraitAxis::ALL
+                .map(|axis| scalar_from_f32(config.divergence_axis_weight(axis))),
         }
     }
 
@@ -428,10 +454,15 @@ impl CultureLayer {
         }
     }
 
-    fn evaluate_divergence(&mut self, parent_values: &[Scalar; CULTURE_TRAIT_AXES]) {
+    /// Divergence is the largest weighted per-axis gap from the parent.
+    fn evaluate_divergence(
+        &mut self,
+        parent_values: &[Scalar; CULTURE_TRAIT_AXES],
+        axis_weights: &[Scalar; CULTURE_TRAIT_AXES],
+    ) {
         let mut max_delta = Scalar::zero();
         for (idx, parent_value) in parent_values.iter().enumerate() {
-            let diff = (self.traits.value[idx] - *parent_value).abs();
+            let diff = (self.traits.value[idx] - *parent_value).abs() * axis_weights[idx];
             if diff > max_delta {
                 max_delta = diff;
             }
@@ -497,9 +528,30 @@ pub struct CultureManager {
     regional: HashMap<u32, CultureLayer>,
     locals: HashMap<u64, CultureLayer>,
     tension_events: Vec<CultureTensionRecord>,
+    /// Layers absorbed by [`CultureManager::merge_layers`] since the last drain.
+    retired_layers: Vec<CultureLayerId>,
+    /// Tile entity bits under each layer: every tile with a local layer sits under that layer,
+    /// its regional parent and the global layer. Rebuilt by reconcile, merge and restore.
+    tile_members: HashMap<CultureLayerId, HashSet<u64>>,
     settings: CultureManagerSettings,
 }
 
+/// Why [`CultureManager::merge_layers`] refused a reunification.
+#[derive(Debug, Error, PartialEq)]
+pub enum CultureMergeError {
+    #[error("culture layer {0} does not exist")]
+    UnknownLayer(CultureLayerId),
+    #[error("culture layer {0} cannot merge with itself")]
+    SameLayer(CultureLayerId),
+    #[error("culture layer {layer} is {scope:?}; only regional layers reunify")]
+    UnsupportedScope {
+        layer: CultureLayerId,
+        scope: CultureLayerScope,
+    },
+    #[error("culture layers diverge by {divergence:.3}, above the merge cap {cap:.3}")]
+    DivergenceTooHigh { divergence: f32, cap: f32 },
+}
+
 impl CultureManager {
     pub fn new() -> Self {
         Self::with_settings(CultureManagerSettings::default())
@@ -512,6 +564,8 @@ impl CultureManager {
             regional: HashMap::new(),
             locals: HashMap::new(),
             tension_events: Vec::new(),
+            retired_layers: Vec::new(),
+            tile_members: HashMap::new(),
             settings,
         }
     }
@@ -567,6 +621,124 @@ impl CultureManager {
         id
     }
 
+    /// **Cultural reunification** — the inverse of a schism: folds regional layer `absorbed` into
+    /// `survivor` and returns `survivor`.
+    ///
+    /// Baseline, modifier and resolved traits become the average of the two, weighted by the
+    /// people living under each (`population` lists residents per local owner, as
+    /// [`Self::faction_trait_average`] takes them; equal weights when neither side has any). Every
+    /// local layer parented on `absorbed` is re-pointed at `survivor`, so its tiles now reconcile
+    /// against the merged culture, and `absorbed` is dropped — its id is queued for
+    /// [`Self::take_retired_layers`] and disappears from the next snapshot delta. The survivor's
+    /// threshold timers restart, since the tension they measured is gone.
+    ///
+    /// Refused when the layers differ on any axis by more than the configured
+    /// `merge_divergence_cap`: cultures that far apart do not simply fuse.
+    pub fn merge_layers(
+        &mut self,
+        survivor: CultureLayerId,
+        absorbed: CultureLayerId,
+        population: &[(CultureOwner, u32)],
+    ) -> Result<CultureLayerId, CultureMergeError> {
+        if survivor == absorbed {
+            return Err(CultureMergeError::SameLayer(survivor));
+        }
+        let survivor_region = self.regional_key(survivor)?;
+        let absorbed_region = self.regional_key(absorbed)?;
+
+        let survivor_values = *self.regional[&survivor_region].traits.values();
+        let absorbed_layer = &self.regional[&absorbed_region];
+        let divergence = survivor_values
+            .iter()
+            .zip(absorbed_layer.traits.values())
+            .map(|(a, b)| (*a - *b).abs())
+            .fold(Scalar::zero(), Scalar::max);
+        if divergence > self.settings.merge_divergence_cap {
+            return Err(CultureMergeError::DivergenceTooHigh {
+                divergence: divergence.to_f32(),
+                cap: self.settings.merge_divergence_cap.to_f32(),
+            });
+        }
+
+        let residents: HashMap<u64, u32> = population
+            .iter()
+            .map(|(owner, people)| (owner.0, *people))
+            .collect();
+        let weight_of = |region: CultureLayerId| -> i128 {
+            self.locals
+                .values()
+                .filter(|layer| layer.parent == Some(region))
+                .map(|layer| i128::from(residents.get(&layer.owner.0).copied().unwrap_or(0)))
+                .sum()
+        };
+        let (mut survivor_weight, mut absorbed_weight) = (weight_of(survivor), weight_of(absorbed));
+        if survivor_weight + absorbed_weight == 0 {
+            (survivor_weight, absorbed_weight) = (1, 1);
+        }
+
+        let absorbed_layer = self
+            .regional
+            .remove(&absorbed_region)
+            .expect("absorbed region resolved above");
+        let blend = |a: Scalar, b: Scalar| {
+            Scalar::from_raw(
+                ((i128::from(a.raw()) * survivor_weight + i128::from(b.raw()) * absorbed_weight)
+                    / (survivor_weight + absorbed_weight)) as i64,
+            )
+        };
+        let merged = self
+            .regional
+            .get_mut(&survivor_region)
+            .expect("survivor region resolved above");
+        for idx in 0..CULTURE_TRAIT_AXES {
+            let traits = &mut merged.traits;
+            traits.baseline[idx] = blend(traits.baseline[idx], absorbed_layer.traits.baseline[idx]);
+            traits.modifier[idx] = blend(traits.modifier[idx], absorbed_layer.traits.modifier[idx]);
+            traits.value[idx] = blend(traits.value[idx], absorbed_layer.traits.value[idx]);
+        }
+        merged.divergence.ticks_above_soft = 0;
+        merged.divergence.ticks_above_hard = 0;
+
+        for layer in self.locals.values_mut() {
+            if layer.parent == Some(absorbed) {
+                layer.parent = Some(survivor);
+            }
+        }
+        self.retired_layers.push(absorbed);
+        self.rebuild_tile_members();
+
+        tracing::info!(
+            target: "culture.merge",
+            survivor,
+            absorbed,
+            divergence = divergence.to_f32(),
+            survivor_weight = survivor_weight as u64,
+            absorbed_weight = absorbed_weight as u64,
+            "culture layers reunified"
+        );
+        Ok(survivor)
+    }
+
+    /// Layer ids absorbed by [`Self::merge_layers`] since the last call.
+    pub fn take_retired_layers(&mut self) -> Vec<CultureLayerId> {
+        std::mem::take(&mut self.retired_layers)
+    }
+
+    /// The `regional` map key holding layer `id`, or why `id` cannot take part in a merge.
+    fn regional_key(&self, id: CultureLayerId) -> Result<u32, CultureMergeError> {
+        if let Some((region, _)) = self.regional.iter().find(|(_, layer)| layer.id == id) {
+            return Ok(*region);
+        }
+        let scope = self
+            .global
+            .iter()
+            .chain(self.locals.values())
+            .find(|layer| layer.id == id)
+            .map(|layer| layer.scope)
+            .ok_or(CultureMergeError::UnknownLayer(id))?;
+        Err(CultureMergeError::UnsupportedScope { layer: id, scope })
+    }
+
     fn allocate_id(&mut self) -> CultureLayerId {
         let id = self.next_id;
         self.next_id = self.next_id.wrapping_add(1).max(1);
@@ -589,6 +761,7 @@ impl CultureManager {
     }
 
     pub fn reconcile(&mut self, tick: &SimulationTick, resonance: &InfluencerCultureResonance) {
+        self.rebuild_tile_members();
         if self.global.is_none() && self.regional.is_empty() && self.locals.is_empty() {
             return;
         }
@@ -603,6 +776,7 @@ impl CultureManager {
 
         self.tension_events.clear();
         let mut pending_events = Vec::new();
+        let axis_weights = self.settings.divergence_axis_weights;
 
         let mut global_values = [Scalar::zero(); CULTURE_TRAIT_AXES];
         if let Some(global) = &mut self.global {
@@ -630,7 +804,7 @@ impl CultureManager {
         for layer in self.regional.values_mut() {
             *layer.traits.baseline_mut() = global_values;
             layer.resolve_against(&global_values, regional_resonance.as_ref());
-            layer.evaluate_divergence(&global_values);
+            layer.evaluate_divergence(&global_values, &axis_weights);
             let alert = layer.tick_thresholds();
             layer.last_updated_tick = tick.0;
             if let Some(kind) = alert {
@@ -639,7 +813,7 @@ impl CultureManager {
                     target: "culture.tension",
                     kind = ?record.kind,
                     scope = ?record.scope,
-                    owner = record.owner.0,
+                    owner = ?record.owner.resolve(),
                     layer_id = record.layer_id,
                     magnitude = record.magnitude.to_f32(),
                     timer = record.timer,
@@ -670,7 +844,7 @@ impl CultureManager {
                 continue;
             };
             layer.resolve_against(parent_values, local_resonance.as_ref());
-            layer.evaluate_divergence(parent_values);
+            layer.evaluate_divergence(parent_values, &axis_weights);
             let alert = layer.tick_thresholds();
             layer.last_updated_tick = tick.0;
             if let Some(kind) = alert {
@@ -679,7 +853,7 @@ impl CultureManager {
                     target: "culture.tension",
                     kind = ?record.kind,
                     scope = ?record.scope,
-                    owner = record.owner.0,
+                    owner = ?record.owner.resolve(),
                     layer_id = record.layer_id,
                     magnitude = record.magnitude.to_f32(),
                     timer = record.timer,
@@ -802,6 +976,7 @@ impl CultureManager {
         self.regional.clear();
         self.locals.clear();
         self.tension_events.clear();
+        self.retired_layers.clear();
 
         let next_id = layers.iter().map(|layer| layer.id).max().unwrap_or(0);
         self.next_id = next_id.wrapping_add(1).max(1);
@@ -845,7 +1020,12 @@ impl CultureManager {
                     self.global = Some(layer);
                 }
                 CultureLayerScope::Regional => {
-                    let region_id = state.owner as u32;
+                    // Snapshots from before the province tag carry the bare region id.
+                    let region_id = match CultureOwner::decode(state.owner) {
+                        CultureOwnerRef::Province(region_id) => region_id,
+                        _ => state.owner as u32,
+                    };
+                    layer.owner = CultureOwner::from_region(region_id);
                     self.regional.insert(region_id, layer);
                 }
                 CultureLayerScope::Local => {
@@ -853,6 +1033,55 @@ impl CultureManager {
                 }
             }
         }
+        self.rebuild_tile_members();
+    }
+
+    /// Tiles (entity bits) under `layer` as of the last reconcile, in `tiles` order. A tile
+    /// belongs to its local layer, that layer's regional parent and the global layer; tiles with
+    /// no local layer belong to none, and tiles `tiles` no longer holds are skipped.
+    pub fn tiles_of_layer(&self, layer: CultureLayerId, tiles: &TileRegistry) -> Vec<u64> {
+        let Some(members) = self.tile_members.get(&layer) else {
+            return Vec::new();
+        };
+        tiles
+            .tiles
+            .iter()
+            .map(|entity| entity.to_bits())
+            .filter(|bits| members.contains(bits))
+            .collect()
+    }
+
+    /// Whether tile `tile` (entity bits) sits under `layer` as of the last reconcile.
+    pub fn tile_in_layer(&self, layer: CultureLayerId, tile: u64) -> bool {
+        self.tile_members
+            .get(&layer)
+            .is_some_and(|members| members.contains(&tile))
+    }
+
+    /// The local layer tile `tile` (entity bits) is assigned, or `None` for an unassigned tile.
+    pub fn tile_layer(&self, tile: u64) -> Option<CultureLayerId> {
+        self.locals
+            .get(&CultureOwnerRef::Tile(tile).encode())
+            .map(|layer| layer.id)
+    }
+
+    fn rebuild_tile_members(&mut self) {
+        self.tile_members.clear();
+        let regional_parents: HashMap<CultureLayerId, Option<CultureLayerId>> = self
+            .regional
+            .values()
+            .map(|layer| (layer.id, layer.parent))
+            .collect();
+        for layer in self.locals.values() {
+            let CultureOwnerRef::Tile(tile) = layer.owner.resolve() else {
+                continue;
+            };
+            let region = layer.parent;
+            let global = region.and_then(|id| regional_parents.get(&id).copied().flatten());
+            for id in [Some(layer.id), region, global].into_iter().flatten() {
+                self.tile_members.entry(id).or_default().insert(tile);
+            }
+        }
     }
 
     pub fn regional_layers(&self) -> impl Iterator<Item = &CultureLayer> {
@@ -955,7 +1184,7 @@ pub fn reconcile_culture_layers(
             target: "culture.tension",
             kind = ?record.kind,
             scope = ?record.scope,
-            owner = record.owner.0,
+            owner = ?record.owner.resolve(),
             layer_id = record.layer_id,
             magnitude = record.magnitude.to_f32(),
             timer = record.timer,
@@ -1010,6 +1239,7 @@ fn from_schema_axis(axis: SchemaCultureTraitAxis) -> CultureTraitAxis {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::culture_corruption_config::CultureCorruptionConfig;
     use crate::influencers::InfluencerCultureResonance;
 
     fn default_resonance() -> InfluencerCultureResonance {
@@ -1141,4 +1371,302 @@ mod tests {
             "assimilation push should emit when divergence resolves"
         );
     }
+
+    #[test]
+    fn divergence_weights_the_same_trait_gap_by_axis() {
+        let json = r#"{
+            "culture": {
+                "propagation": {
+                    "regional": {"elasticity": 1.0, "soft_threshold": 0.6, "hard_threshold": 1.2},
+                    "divergence_axis_weights": {"secular_devout": 2.0, "open_closed": 0.5}
+                }
+            }
+        }"#;
+        let config = CultureCorruptionConfig::from_json_str(json).expect("weights parse");
+        let mut manager = CultureManager::from_config(config.culture().propagation());
+        manager.ensure_global();
+        for (region, axis) in [
+            (1, CultureTraitAxis::SecularDevout),
+            (2, CultureTraitAxis::OpenClosed),
+            (3, CultureTraitAxis::AdaptiveStubborn),
+        ] {
+            manager.upsert_regional(region);
+            manager
+                .regional_layer_mut_by_region(region)
+                .expect("regional layer should exist")
+                .traits
+                .set_modifier(axis, scalar_from_f32(0.4));
+        }
+
+        manager.reconcile(&SimulationTick(1), &default_resonance());
+
+        let magnitude = |region: u32| {
+            manager
+                .regional_layers()
+                .find(|layer| layer.owner == CultureOwner::from_region(region))
+                .expect("regional layer should exist")
+                .divergence
+                .magnitude
+                .to_f32()
+        };
+        assert!((magnitude(1) - 0.8).abs() < 1e-4, "devout {}", magnitude(1));
+        assert!((magnitude(2) - 0.2).abs() < 1e-4, "open {}", magnitude(2));
+        assert!(
+            (magnitude(3) - 0.4).abs() < 1e-4,
+            "an unlisted axis keeps weight 1.0, got {}",
+            magnitude(3)
+        );
+    }
+
+    #[test]
+    fn negative_divergence_weight_is_rejected() {
+        let json = r#"{
+            "culture": {"propagation": {"divergence_axis_weights": {"secular_devout": -0.5}}}
+        }"#;
+        assert!(CultureCorruptionConfig::from_json_str(json).is_err());
+
+        let unknown = r#"{
+            "culture": {"propagation": {"divergence_axis_weights": {"not_an_axis": 1.0}}}
+        }"#;
+        assert!(CultureCorruptionConfig::from_json_str(unknown).is_err());
+    }
+
+    /// Two regions whose `OpenClosed` resolved value is `open_a` / `open_b`, with three tile-owned
+    /// local layers: tiles 10 and 11 under the first region, tile 12 under the second.
+    fn two_regions(open_a: f32, open_b: f32) -> (CultureManager, CultureLayerId, CultureLayerId) {
+        let mut manager = CultureManager::new();
+        let axis = CultureTraitAxis::OpenClosed.index();
+        let a = manager.upsert_regional(1);
+        let b = manager.upsert_regional(2);
+        for (region, open) in [(1, open_a), (2, open_b)] {
+            let layer = manager
+                .regional_layer_mut_by_region(region)
+                .expect("regional layer should exist");
+            layer.traits.update_value(axis, scalar_from_f32(open));
+        }
+        manager.attach_local(Entity::from_raw(10), a);
+        manager.attach_local(Entity::from_raw(11), a);
+        manager.attach_local(Entity::from_raw(12), b);
+        (manager, a, b)
+    }
+
+    #[test]
+    fn merge_layers_blends_traits_by_population_and_reassigns_tiles() {
+        let (mut manager, a, b) = two_regions(0.2, 0.4);
+        let population = [
+            (CultureOwner::from_entity(Entity::from_raw(10)), 30),
+            (CultureOwner::from_entity(Entity::from_raw(11)), 30),
+            (CultureOwner::from_entity(Entity::from_raw(12)), 20),
+        ];
+
+        assert_eq!(manager.merge_layers(a, b, &population), Ok(a));
+
+        // 60 people at 0.2 and 20 at 0.4 settle at 0.25.
+        let merged = manager
+            .regional_layers()
+            .find(|layer| layer.id == a)
+            .expect("survivor remains");
+        let open = merged.traits.values()[CultureTraitAxis::OpenClosed.index()];
+        assert!((open.to_f32() - 0.25).abs() < 1e-4, "merged open {open:?}");
+        assert_eq!(manager.regional_layers().count(), 1);
+
+        let moved = manager
+            .local_layer_by_owner(CultureOwner::from_entity(Entity::from_raw(12)))
+            .expect("tile 12 keeps its local layer");
+        assert_eq!(
+            moved.parent,
+            Some(a),
+            "tile 12 now reconciles against the survivor"
+        );
+        assert_eq!(manager.take_retired_layers(), vec![b]);
+        assert!(manager.take_retired_layers().is_empty());
+    }
+
+    /// Every layer id, and each tile's membership recomputed from the layer parents.
+    fn brute_force_members(
+        manager: &CultureManager,
+        tiles: &TileRegistry,
+    ) -> Vec<(CultureLayerId, Vec<u64>)> {
+        let layers: Vec<&CultureLayer> = manager
+            .global_layer()
+            .into_iter()
+            .chain(manager.regional_layers())
+            .chain(manager.local_layers())
+            .collect();
+        let parent_of = |id: CultureLayerId| {
+            layers
+                .iter()
+                .find(|layer| layer.id == id)
+                .and_then(|layer| layer.parent)
+        };
+        let mut ids: Vec<_> = layers.iter().map(|layer| layer.id).collect();
+        ids.sort_unstable();
+        ids.into_iter()
+            .map(|id| {
+                let members = tiles
+                    .tiles
+                    .iter()
+                    .filter(|entity| {
+                        let mut cursor = manager
+                            .local_layer_by_owner(CultureOwner::from_entity(**entity))
+                            .map(|layer| layer.id);
+                        while let Some(layer) = cursor {
+                            if layer == id {
+                                return true;
+                            }
+                            cursor = parent_of(layer);
+                        }
+                        false
+                    })
+                    .map(|entity| entity.to_bits())
+                    .collect();
+                (id, members)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn tile_membership_index_matches_a_brute_force_scan() {
+        let (mut manager, a, b) = two_regions(0.2, 0.3);
+        // Tile 13 has no local layer.
+        let tiles = TileRegistry {
+            tiles: (10..14).map(Entity::from_raw).collect(),
+            width: 4,
+            height: 1,
+        };
+        let indexed = |manager: &CultureManager| {
+            brute_force_members(manager, &tiles)
+                .into_iter()
+                .map(|(id, _)| (id, manager.tiles_of_layer(id, &tiles)))
+                .collect::<Vec<_>>()
+        };
+
+        manager.reconcile(&SimulationTick(1), &default_resonance());
+        assert_eq!(indexed(&manager), brute_force_members(&manager, &tiles));
+        assert_eq!(
+            manager.tiles_of_layer(a, &tiles),
+            vec![
+                Entity::from_raw(10).to_bits(),
+                Entity::from_raw(11).to_bits()
+            ]
+        );
+
+        // Tile 11 moves to the second region.
+        manager
+            .local_layer_mut_by_owner(CultureOwner::from_entity(Entity::from_raw(11)))
+            .expect("tile 11 has a local layer")
+            .parent = Some(b);
+        manager.reconcile(&SimulationTick(2), &default_resonance());
+        manager.reconcile(&SimulationTick(3), &default_resonance());
+        assert_eq!(indexed(&manager), brute_force_members(&manager, &tiles));
+        assert!(manager.tile_in_layer(b, Entity::from_raw(11).to_bits()));
+        assert!(!manager.tile_in_layer(a, Entity::from_raw(11).to_bits()));
+
+        assert_eq!(manager.merge_layers(a, b, &[]), Ok(a));
+        manager.reconcile(&SimulationTick(4), &default_resonance());
+        assert_eq!(indexed(&manager), brute_force_members(&manager, &tiles));
+        assert_eq!(manager.tiles_of_layer(a, &tiles).len(), 3);
+        assert!(manager.tiles_of_layer(b, &tiles).is_empty());
+
+        let unassigned = Entity::from_raw(13).to_bits();
+        assert_eq!(manager.tile_layer(unassigned), None);
+        let global = manager.global_layer().expect("global layer").id;
+        assert!(!manager.tile_in_layer(global, unassigned));
+    }
+
+    #[test]
+    fn merge_layers_rejects_divergent_or_non_regional_layers() {
+        let (mut manager, a, b) = two_regions(0.0, 0.9);
+        assert!(matches!(
+            manager.merge_layers(a, b, &[]),
+            Err(CultureMergeError::DivergenceTooHigh { .. })
+        ));
+        assert_eq!(manager.regional_layers().count(), 2, "nothing was absorbed");
+        assert!(manager.take_retired_layers().is_empty());
+
+        let local = manager
+            .local_layer_by_owner(CultureOwner::from_entity(Entity::from_raw(10)))
+            .expect("local layer")
+            .id;
+        assert_eq!(
+            manager.merge_layers(a, local, &[]),
+            Err(CultureMergeError::UnsupportedScope {
+                layer: local,
+                scope: CultureLayerScope::Local,
+            })
+        );
+        assert_eq!(
+            manager.merge_layers(a, 999, &[]),
+            Err(CultureMergeError::UnknownLayer(999))
+        );
+        assert_eq!(
+            manager.merge_layers(a, a, &[]),
+            Err(CultureMergeError::SameLayer(a))
+        );
+    }
+
+    #[test]
+    fn owner_kinds_round_trip_through_decode() {
+        let tile = Entity::from_raw(12);
+        let cases = [
+            (CultureOwner::GLOBAL, CultureOwnerRef::Global),
+            (CultureOwner::from_region(0), CultureOwnerRef::Province(0)),
+            (
+                CultureOwner::from_region(u32::MAX),
+                CultureOwnerRef::Province(u32::MAX),
+            ),
+            (
+                CultureOwner::from_entity(tile),
+                CultureOwnerRef::Tile(tile.to_bits()),
+            ),
+        ];
+        for (owner, expected) in cases {
+            assert_eq!(CultureOwner::decode(owner.0), expected, "{owner:?}");
+            assert_eq!(CultureOwner::from_ref(expected), owner);
+        }
+        assert_ne!(
+            CultureOwner::from_region(0),
+            CultureOwner::GLOBAL,
+            "province 0 no longer aliases the global layer"
+        );
+        assert_eq!(CultureOwnerRef::Province(7).kind_key(), "province");
+        assert_eq!(CultureOwnerRef::Province(7).id(), 7);
+        assert_eq!(CultureOwnerRef::Tile(tile.to_bits()).id(), tile.to_bits());
+    }
+
+    #[test]
+    fn restore_retags_untagged_regional_owners() {
+        let layer = |id: u32, owner: u64, scope: SchemaLayerScope| SchemaCultureLayerState {
+            id,
+            owner,
+            parent: 0,
+            scope,
+            traits: Vec::new(),
+            divergence: 0,
+            soft_threshold: 0,
+            hard_threshold: 0,
+            ticks_above_soft: 0,
+            ticks_above_hard: 0,
+            last_updated_tick: 0,
+        };
+        let mut manager = CultureManager::new();
+        manager.restore_from_snapshot(
+            &[
+                layer(1, 0, SchemaLayerScope::Global),
+                layer(2, 4, SchemaLayerScope::Regional),
+                layer(
+                    3,
+                    CultureOwner::from_region(5).0,
+                    SchemaLayerScope::Regional,
+                ),
+            ],
+            &[],
+        );
+        for region in [4, 5] {
+            let restored = manager
+                .regional_layer_mut_by_region(region)
+                .expect("regional layer keyed by province");
+            assert_eq!(restored.owner.resolve(), CultureOwnerRef::Province(region));
+        }
+    }
 }
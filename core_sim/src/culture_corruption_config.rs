@@ -1,9 +1,13 @@
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use bevy::prelude::Resource;
-use serde::Deserialize;
+use serde::{de::Error as _, Deserialize};
 
-use crate::scalar::{scalar_from_f32, Scalar};
+use crate::{
+    culture::CultureTraitAxis,
+    scalar::{scalar_from_f32, Scalar},
+};
 
 pub const BUILTIN_CULTURE_CORRUPTION_CONFIG: &str =
     include_str!("data/culture_corruption_config.json");
@@ -17,7 +21,18 @@ pub struct CultureCorruptionConfig {
 
 impl CultureCorruptionConfig {
     pub fn from_json_str(json: &str) -> Result<Self, serde_json::Error> {
-        serde_json::from_str(json)
+        let parsed: Self = serde_json::from_str(json)?;
+        parsed
+            .culture
+            .propagation
+            .validate()
+            .map_err(serde_json::Error::custom)?;
+        parsed
+            .corruption
+            .audit
+            .validate()
+            .map_err(serde_json::Error::custom)?;
+        Ok(parsed)
     }
 
     pub fn culture(&self) -> &CultureSeverityConfig {
@@ -101,6 +116,12 @@ pub struct CulturePropagationSettings {
     global: CultureScopePropagation,
     regional: CultureScopePropagation,
     local: CultureScopePropagation,
+    /// Largest per-axis trait gap at which two regional layers may still reunify
+    /// (`CultureManager::merge_layers`).
+    merge_divergence_cap: f32,
+    /// Multiplier on each axis's trait gap when a layer's divergence from its parent is measured,
+    /// keyed by snake_case axis name. Unlisted axes weigh 1.0; 0 ignores the axis entirely.
+    divergence_axis_weights: HashMap<CultureTraitAxis, f32>,
 }
 
 impl CulturePropagationSettings {
@@ -115,6 +136,29 @@ impl CulturePropagationSettings {
     pub fn local(&self) -> &CultureScopePropagation {
         &self.local
     }
+
+    pub fn merge_divergence_cap(&self) -> f32 {
+        self.merge_divergence_cap
+    }
+
+    pub fn divergence_axis_weight(&self, axis: CultureTraitAxis) -> f32 {
+        self.divergence_axis_weights
+            .get(&axis)
+            .copied()
+            .unwrap_or(1.0)
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        for (axis, weight) in &self.divergence_axis_weights {
+            if !weight.is_finite() || *weight < 0.0 {
+                return Err(format!(
+                    "culture.propagation.divergence_axis_weights.{axis:?} must be a finite \
+                     non-negative number (got {weight})"
+                ));
+            }
+        }
+        Ok(())
+    }
 }
 
 impl Default for CulturePropagationSettings {
@@ -141,6 +185,8 @@ impl Default for CulturePropagationSettings {
                 soft_trigger_ticks: 1,
                 hard_trigger_ticks: 1,
             },
+            merge_divergence_cap: 0.3,
+            divergence_axis_weights: HashMap::new(),
         }
     }
 }
@@ -243,6 +289,7 @@ pub struct CorruptionSeverityConfig {
     sentiment_delta_max: f32,
     max_penalty_ratio: f32,
     min_output_multiplier: f32,
+    audit: CorruptionAuditConfig,
 }
 
 impl CorruptionSeverityConfig {
@@ -264,6 +311,10 @@ impl CorruptionSeverityConfig {
     pub fn min_output_multiplier(&self) -> Scalar {
         scalar_from_f32(self.min_output_multiplier)
     }
+
+    pub fn audit(&self) -> &CorruptionAuditConfig {
+        &self.audit
+    }
 }
 
 impl Default for CorruptionSeverityConfig {
@@ -274,6 +325,56 @@ impl Default for CorruptionSeverityConfig {
             sentiment_delta_max: 0.5,
             max_penalty_ratio: 0.9,
             min_output_multiplier: 0.1,
+            audit: CorruptionAuditConfig::default(),
+        }
+    }
+}
+
+/// How `CorruptionLedger.audit_capacity` speeds up incident exposure.
+///
+/// Each tick an incident's `exposure_timer` counts down by
+/// `min(base_exposure_step + audit_capacity / capacity_per_extra_step, max_exposure_step)`
+/// (integer division), so every `capacity_per_extra_step` points of audit capacity shave one
+/// more tick off the timer.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct CorruptionAuditConfig {
+    base_exposure_step: u16,
+    capacity_per_extra_step: u16,
+    max_exposure_step: u16,
+}
+
+impl CorruptionAuditConfig {
+    pub fn exposure_step(&self, audit_capacity: u16) -> u16 {
+        let bonus = audit_capacity / self.capacity_per_extra_step.max(1);
+        self.base_exposure_step
+            .saturating_add(bonus)
+            .min(self.max_exposure_step)
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if self.base_exposure_step == 0 {
+            return Err("corruption.audit.base_exposure_step must be at least 1".to_string());
+        }
+        if self.capacity_per_extra_step == 0 {
+            return Err("corruption.audit.capacity_per_extra_step must be at least 1".to_string());
+        }
+        if self.max_exposure_step < self.base_exposure_step {
+            return Err(format!(
+                "corruption.audit.max_exposure_step ({}) must be >= base_exposure_step ({})",
+                self.max_exposure_step, self.base_exposure_step
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl Default for CorruptionAuditConfig {
+    fn default() -> Self {
+        Self {
+            base_exposure_step: 1,
+            capacity_per_extra_step: 10,
+            max_exposure_step: 4,
         }
     }
 }
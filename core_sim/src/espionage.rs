@@ -3,7 +3,7 @@ use std::hash::{Hash, Hasher};
 use std::sync::Arc;
 
 use bevy::{ecs::system::SystemParam, prelude::*};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 use crate::hashing::FnvHasher;
@@ -97,6 +97,10 @@ pub struct EspionageMissionTemplate {
     pub note: Option<String>,
     pub target_tier_min: Option<u8>,
     pub target_tier_max: Option<u8>,
+    /// Ticks a faction must wait after queuing this mission before queuing it again; 0 disables.
+    pub cooldown_ticks: u16,
+    /// Per-faction cap on in-flight instances of this mission; `None` is unlimited.
+    pub max_concurrent: Option<u16>,
     pub generated: bool,
 }
 
@@ -132,6 +136,8 @@ pub enum EspionageCatalogError {
     UnknownCountermeasureKind { mission: String, kind: String },
     #[error("failed to parse espionage balance config: {0}")]
     ParseConfig(serde_json::Error),
+    #[error("mission '{0}' sets max_concurrent to 0 and could never be queued")]
+    ZeroConcurrencyLimit(String),
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -150,6 +156,8 @@ pub struct EspionageBalanceConfig {
     mission_generator_defaults: MissionGeneratorDefaults,
     #[serde(default)]
     queue_defaults: EspionageQueueDefaults,
+    #[serde(default)]
+    agent_experience: AgentExperienceTuning,
 }
 
 impl EspionageBalanceConfig {
@@ -180,6 +188,57 @@ impl EspionageBalanceConfig {
     pub fn queue_defaults(&self) -> &EspionageQueueDefaults {
         &self.queue_defaults
     }
+
+    pub fn agent_experience(&self) -> &AgentExperienceTuning {
+        &self.agent_experience
+    }
+}
+
+/// Experience curve for agents. Each full success adds `experience_per_success`, capped at
+/// `max_experience`; the success-score bonus is
+/// `max_success_bonus * xp / (xp + half_bonus_experience)`, so it halves at the midpoint and
+/// flattens out toward the cap.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct AgentExperienceTuning {
+    experience_per_success: u32,
+    max_experience: u32,
+    max_success_bonus: f32,
+    half_bonus_experience: u32,
+}
+
+impl Default for AgentExperienceTuning {
+    fn default() -> Self {
+        Self {
+            experience_per_success: 1,
+            max_experience: 10,
+            max_success_bonus: 0.1,
+            half_bonus_experience: 3,
+        }
+    }
+}
+
+impl AgentExperienceTuning {
+    pub fn max_experience(&self) -> u32 {
+        self.max_experience
+    }
+
+    /// Success-score bonus granted at `experience` (clamped to the cap).
+    pub fn bonus(&self, experience: u32) -> Scalar {
+        let experience = experience.min(self.max_experience);
+        if experience == 0 {
+            return scalar_zero();
+        }
+        let experience = Scalar::from_u32(experience);
+        let half = Scalar::from_u32(self.half_bonus_experience);
+        scalar_from_f32(self.max_success_bonus.max(0.0)) * experience / (experience + half)
+    }
+
+    fn award(&self, experience: u32) -> u32 {
+        experience
+            .saturating_add(self.experience_per_success)
+            .min(self.max_experience)
+    }
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -193,6 +252,10 @@ pub struct CounterIntelBudgetConfig {
     lenient_suspicion_threshold: f32,
     lenient_progress_threshold: u16,
     hardened_progress_threshold: u16,
+    /// Counter-intel success bonus a faction gains against a source it caught probing it.
+    known_aggressor_bonus: f32,
+    /// How much of that bonus fades each tick.
+    known_aggressor_decay_per_tick: f32,
 }
 
 impl Default for CounterIntelBudgetConfig {
@@ -206,6 +269,8 @@ impl Default for CounterIntelBudgetConfig {
             lenient_suspicion_threshold: 0.6,
             lenient_progress_threshold: 95,
             hardened_progress_threshold: 60,
+            known_aggressor_bonus: 0.15,
+            known_aggressor_decay_per_tick: 0.03,
         }
     }
 }
@@ -242,6 +307,14 @@ impl CounterIntelBudgetConfig {
     pub fn hardened_progress_threshold(&self) -> u16 {
         self.hardened_progress_threshold
     }
+
+    pub fn known_aggressor_bonus(&self) -> Scalar {
+        scalar_from_f32(self.known_aggressor_bonus.max(0.0))
+    }
+
+    pub fn known_aggressor_decay_per_tick(&self) -> Scalar {
+        scalar_from_f32(self.known_aggressor_decay_per_tick.max(0.0))
+    }
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -605,6 +678,10 @@ impl EspionageCatalog {
                 None
             };
 
+            if entry.max_concurrent == Some(0) {
+                return Err(EspionageCatalogError::ZeroConcurrencyLimit(entry.id));
+            }
+
             let base_template = EspionageMissionTemplate {
                 id: id.clone(),
                 name: entry.name.clone(),
@@ -625,6 +702,8 @@ impl EspionageCatalog {
                 note: entry.note.clone(),
                 target_tier_min: entry.target_tier_min,
                 target_tier_max: entry.target_tier_max,
+                cooldown_ticks: entry.cooldown_ticks.unwrap_or(0),
+                max_concurrent: entry.max_concurrent,
                 generated: false,
             };
 
@@ -723,6 +802,14 @@ pub struct EspionageAgent {
     pub note: Option<String>,
     pub assignment: AgentAssignment,
     pub generated: bool,
+    /// Earned on fully successful missions; see `AgentExperienceTuning`.
+    pub experience: u32,
+}
+
+impl EspionageAgent {
+    pub fn experience_bonus(&self, tuning: &AgentExperienceTuning) -> Scalar {
+        tuning.bonus(self.experience)
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -763,6 +850,7 @@ impl EspionageRoster {
                     note: template.note.clone(),
                     assignment: AgentAssignment::Available,
                     generated: false,
+                    experience: 0,
                 });
             }
 
@@ -812,6 +900,17 @@ impl EspionageRoster {
             .and_then(|agents| agents.iter().find(|agent| agent.handle == handle))
     }
 
+    /// Every faction's agents, factions in id order.
+    pub fn iter_sorted(&self) -> Vec<(FactionId, &[EspionageAgent])> {
+        let mut out: Vec<_> = self
+            .agents
+            .iter()
+            .map(|(faction, agents)| (*faction, agents.as_slice()))
+            .collect();
+        out.sort_by_key(|(faction, _)| faction.0);
+        out
+    }
+
     pub fn refresh_generated_agents(&mut self, catalog: &EspionageCatalog, factions: &[FactionId]) {
         for faction in factions {
             let mut additions: Vec<EspionageAgent> = Vec::new();
@@ -850,6 +949,8 @@ impl EspionageRoster {
 pub struct EspionageMissionState {
     active: Vec<ScheduledEspionageMission>,
     next_instance: u64,
+    /// Scheduled tick of the most recent queue per (faction, mission), for cooldown checks.
+    last_queued: HashMap<(FactionId, EspionageMissionId), u64>,
 }
 
 #[derive(Debug, Clone)]
@@ -891,6 +992,34 @@ impl EspionageMissionState {
             });
         }
 
+        if mission.cooldown_ticks > 0 {
+            if let Some(&last) = self.last_queued.get(&(owner, mission_id.clone())) {
+                let ready_at = last.saturating_add(mission.cooldown_ticks as u64);
+                if scheduled_tick < ready_at {
+                    return Err(QueueMissionError::OnCooldown {
+                        mission: mission_id.0.clone(),
+                        faction: owner,
+                        ready_at,
+                    });
+                }
+            }
+        }
+
+        if let Some(limit) = mission.max_concurrent {
+            let in_flight = self
+                .active
+                .iter()
+                .filter(|active| active.owner == owner && active.mission_id == mission_id)
+                .count();
+            if in_flight >= limit as usize {
+                return Err(QueueMissionError::TooManyConcurrent {
+                    mission: mission_id.0.clone(),
+                    faction: owner,
+                    limit,
+                });
+            }
+        }
+
         let agent =
             roster
                 .agent_mut(owner, agent_handle)
@@ -914,6 +1043,8 @@ impl EspionageMissionState {
                     scheduled_tick,
                     note: mission.note.clone(),
                 });
+                self.last_queued
+                    .insert((owner, mission_id.clone()), scheduled_tick);
                 Ok(instance_id)
             }
             AgentAssignment::Assigned(existing) => Err(QueueMissionError::AgentUnavailable {
@@ -974,12 +1105,30 @@ pub enum QueueMissionError {
         mission: String,
         target_tier: Option<u8>,
     },
+    #[error("mission '{mission}' is on cooldown for faction {faction:?} until tick {ready_at}")]
+    OnCooldown {
+        mission: String,
+        faction: FactionId,
+        ready_at: u64,
+    },
+    #[error(
+        "faction {faction:?} already has {limit} instance(s) of mission '{mission}' in flight"
+    )]
+    TooManyConcurrent {
+        mission: String,
+        faction: FactionId,
+        limit: u16,
+    },
 }
 
 #[derive(Debug, Default)]
 struct MissionOutcome {
     probe_event: Option<EspionageProbeEvent>,
     sweep_event: Option<CounterIntelSweepEvent>,
+    /// Full success (not a partial probe); earns the agent experience.
+    succeeded: bool,
+    /// A probe that failed outright and was caught by its target.
+    detected: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -1123,6 +1272,7 @@ impl EspionageAgentGenerator {
                 .or_else(|| Some(format!("Generated from {}", self.id.0))),
             assignment: AgentAssignment::Available,
             generated: true,
+            experience: 0,
         }
     }
 }
@@ -1280,6 +1430,8 @@ impl EspionageMissionGenerator {
             note,
             target_tier_min: base.target_tier_min,
             target_tier_max: base.target_tier_max,
+            cooldown_ticks: base.cooldown_ticks,
+            max_concurrent: base.max_concurrent,
             generated: true,
         }
     }
@@ -1431,6 +1583,9 @@ pub fn initialise_espionage_roster(
 #[derive(Resource, Debug)]
 pub struct CounterIntelBudgets {
     reserves: HashMap<FactionId, Scalar>,
+    /// Counter-intel bonus keyed `(detector, aggressor)`: set when the detector catches one of the
+    /// aggressor's probes, fading by `known_aggressor_decay_per_tick` until it is dropped.
+    known_aggressors: HashMap<(FactionId, FactionId), Scalar>,
 }
 
 impl CounterIntelBudgets {
@@ -1440,10 +1595,48 @@ impl CounterIntelBudgets {
         for faction in factions {
             reserves.insert(*faction, initial);
         }
-        Self { reserves }
+        Self {
+            reserves,
+            known_aggressors: HashMap::new(),
+        }
+    }
+
+    /// Records that `detector` caught a mission run by `aggressor`. A repeat detection refreshes
+    /// the bonus to `known_aggressor_bonus` rather than stacking it.
+    pub fn mark_aggressor(
+        &mut self,
+        detector: FactionId,
+        aggressor: FactionId,
+        config: &CounterIntelBudgetConfig,
+    ) {
+        let bonus = config.known_aggressor_bonus();
+        if bonus <= Scalar::zero() {
+            return;
+        }
+        let entry = self
+            .known_aggressors
+            .entry((detector, aggressor))
+            .or_insert_with(scalar_zero);
+        if *entry < bonus {
+            *entry = bonus;
+        }
+    }
+
+    /// Counter-intel success bonus `detector` currently holds against `aggressor`.
+    pub fn aggressor_bonus(&self, detector: FactionId, aggressor: FactionId) -> Scalar {
+        self.known_aggressors
+            .get(&(detector, aggressor))
+            .copied()
+            .unwrap_or_else(scalar_zero)
     }
 
     pub fn regenerate(&mut self, config: &CounterIntelBudgetConfig) {
+        let decay = config.known_aggressor_decay_per_tick();
+        self.known_aggressors.retain(|_, bonus| {
+            *bonus -= decay;
+            *bonus > Scalar::zero()
+        });
+
         let regen = config.regen_per_tick();
         let max_reserve = config.max_reserve();
         if regen <= Scalar::zero() {
@@ -1537,7 +1730,7 @@ impl CounterIntelBudgets {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum SecurityPolicy {
     Lenient,
     Standard,
@@ -1587,6 +1780,238 @@ impl FactionSecurityPolicies {
     }
 }
 
+/// Espionage state a snapshot leaves out: agent rosters, the mission queue and cooldowns,
+/// counter-intel reserves and known aggressors, and per-faction security policies. Carried by
+/// `crate::world_save`. Maps are written sorted, so the save bytes do not depend on hash order.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct EspionageSave {
+    agents: Vec<(u32, Vec<EspionageAgentSave>)>,
+    next_agent_handle: u32,
+    missions: Vec<ScheduledMissionSave>,
+    next_instance: u64,
+    last_queued: Vec<(u32, String, u64)>,
+    reserves: Vec<(u32, i64)>,
+    known_aggressors: Vec<(u32, u32, i64)>,
+    policies: Vec<(u32, SecurityPolicy)>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct EspionageAgentSave {
+    handle: u32,
+    template_id: String,
+    name: String,
+    stealth: i64,
+    recon: i64,
+    counter_intel: i64,
+    tags: Vec<String>,
+    note: Option<String>,
+    /// Mission instance the agent is assigned to; `None` while available.
+    assignment: Option<u64>,
+    generated: bool,
+    experience: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct ScheduledMissionSave {
+    instance_id: u64,
+    mission_id: String,
+    owner: u32,
+    target_owner: u32,
+    discovery_id: u32,
+    agent: u32,
+    ticks_remaining: u16,
+    scheduled_tick: u64,
+    note: Option<String>,
+}
+
+impl EspionageSave {
+    /// `None` unless the world holds all four espionage resources.
+    pub(crate) fn capture(world: &World) -> Option<Self> {
+        let roster = world.get_resource::<EspionageRoster>()?;
+        let missions = world.get_resource::<EspionageMissionState>()?;
+        let budgets = world.get_resource::<CounterIntelBudgets>()?;
+        let policies = world.get_resource::<FactionSecurityPolicies>()?;
+
+        let mut agents: Vec<_> = roster
+            .agents
+            .iter()
+            .map(|(faction, agents)| {
+                let saved = agents
+                    .iter()
+                    .map(|agent| EspionageAgentSave {
+                        handle: agent.handle.0,
+                        template_id: agent.template_id.0.clone(),
+                        name: agent.name.clone(),
+                        stealth: agent.stealth.raw(),
+                        recon: agent.recon.raw(),
+                        counter_intel: agent.counter_intel.raw(),
+                        tags: agent.tags.clone(),
+                        note: agent.note.clone(),
+                        assignment: match agent.assignment {
+                            AgentAssignment::Available => None,
+                            AgentAssignment::Assigned(instance) => Some(instance.0),
+                        },
+                        generated: agent.generated,
+                        experience: agent.experience,
+                    })
+                    .collect();
+                (faction.0, saved)
+            })
+            .collect();
+        agents.sort_by_key(|(faction, _)| *faction);
+
+        let mut last_queued: Vec<_> = missions
+            .last_queued
+            .iter()
+            .map(|((faction, mission), tick)| (faction.0, mission.0.clone(), *tick))
+            .collect();
+        last_queued.sort();
+        let mut reserves: Vec<_> = budgets
+            .reserves
+            .iter()
+            .map(|(faction, reserve)| (faction.0, reserve.raw()))
+            .collect();
+        reserves.sort();
+        let mut known_aggressors: Vec<_> = budgets
+            .known_aggressors
+            .iter()
+            .map(|((detector, aggressor), bonus)| (detector.0, aggressor.0, bonus.raw()))
+            .collect();
+        known_aggressors.sort();
+        let mut saved_policies: Vec<_> = policies
+            .policies
+            .iter()
+            .map(|(faction, policy)| (faction.0, *policy))
+            .collect();
+        saved_policies.sort_by_key(|(faction, _)| *faction);
+
+        Some(Self {
+            agents,
+            next_agent_handle: roster.next_agent_handle,
+            missions: missions
+                .active
+                .iter()
+                .map(|mission| ScheduledMissionSave {
+                    instance_id: mission.instance_id.0,
+                    mission_id: mission.mission_id.0.clone(),
+                    owner: mission.owner.0,
+                    target_owner: mission.target_owner.0,
+                    discovery_id: mission.discovery_id,
+                    agent: mission.agent.0,
+                    ticks_remaining: mission.ticks_remaining,
+                    scheduled_tick: mission.scheduled_tick,
+                    note: mission.note.clone(),
+                })
+                .collect(),
+            next_instance: missions.next_instance,
+            last_queued,
+            reserves,
+            known_aggressors,
+            policies: saved_policies,
+        })
+    }
+
+    /// Replace the world's espionage resources with the saved state. Resources the world lacks
+    /// are inserted; the default security policy stays the world's own.
+    pub(crate) fn restore(&self, world: &mut World) {
+        let roster = EspionageRoster {
+            agents: self
+                .agents
+                .iter()
+                .map(|(faction, agents)| {
+                    let restored = agents
+                        .iter()
+                        .map(|agent| EspionageAgent {
+                            handle: EspionageAgentHandle(agent.handle),
+                            template_id: EspionageAgentId::new(agent.template_id.clone()),
+                            name: agent.name.clone(),
+                            stealth: Scalar::from_raw(agent.stealth),
+                            recon: Scalar::from_raw(agent.recon),
+                            counter_intel: Scalar::from_raw(agent.counter_intel),
+                            tags: agent.tags.clone(),
+                            note: agent.note.clone(),
+                            assignment: agent.assignment.map_or(
+                                AgentAssignment::Available,
+                                |instance| {
+                                    AgentAssignment::Assigned(EspionageMissionInstanceId(instance))
+                                },
+                            ),
+                            generated: agent.generated,
+                            experience: agent.experience,
+                        })
+                        .collect();
+                    (FactionId(*faction), restored)
+                })
+                .collect(),
+            next_agent_handle: self.next_agent_handle,
+        };
+        world.insert_resource(roster);
+
+        world.insert_resource(EspionageMissionState {
+            active: self
+                .missions
+                .iter()
+                .map(|mission| ScheduledEspionageMission {
+                    instance_id: EspionageMissionInstanceId(mission.instance_id),
+                    mission_id: EspionageMissionId::new(mission.mission_id.clone()),
+                    owner: FactionId(mission.owner),
+                    target_owner: FactionId(mission.target_owner),
+                    discovery_id: mission.discovery_id,
+                    agent: EspionageAgentHandle(mission.agent),
+                    ticks_remaining: mission.ticks_remaining,
+                    scheduled_tick: mission.scheduled_tick,
+                    note: mission.note.clone(),
+                })
+                .collect(),
+            next_instance: self.next_instance,
+            last_queued: self
+                .last_queued
+                .iter()
+                .map(|(faction, mission, tick)| {
+                    (
+                        (
+                            FactionId(*faction),
+                            EspionageMissionId::new(mission.clone()),
+                        ),
+                        *tick,
+                    )
+                })
+                .collect(),
+        });
+
+        world.insert_resource(CounterIntelBudgets {
+            reserves: self
+                .reserves
+                .iter()
+                .map(|(faction, reserve)| (FactionId(*faction), Scalar::from_raw(*reserve)))
+                .collect(),
+            known_aggressors: self
+                .known_aggressors
+                .iter()
+                .map(|(detector, aggressor, bonus)| {
+                    (
+                        (FactionId(*detector), FactionId(*aggressor)),
+                        Scalar::from_raw(*bonus),
+                    )
+                })
+                .collect(),
+        });
+
+        let default_policy = world
+            .get_resource::<FactionSecurityPolicies>()
+            .map(|policies| policies.default_policy)
+            .unwrap_or(SecurityPolicy::Standard);
+        world.insert_resource(FactionSecurityPolicies {
+            policies: self
+                .policies
+                .iter()
+                .map(|(faction, policy)| (FactionId(*faction), *policy))
+                .collect(),
+            default_policy,
+        });
+    }
+}
+
 struct CounterIntelCandidate {
     owner: FactionId,
     discovery_id: u32,
@@ -1856,6 +2281,7 @@ pub fn resolve_espionage_missions(
     mut probe_writer: EventWriter<EspionageProbeEvent>,
     mut sweep_writer: EventWriter<CounterIntelSweepEvent>,
     ledger: Res<KnowledgeLedger>,
+    mut budgets: ResMut<CounterIntelBudgets>,
 ) {
     let mut resolved_instances: Vec<EspionageMissionInstanceId> = Vec::new();
 
@@ -1871,12 +2297,21 @@ pub fn resolve_espionage_missions(
                 tick.0,
                 &catalog,
                 &ledger,
+                &budgets,
                 mission,
                 roster
                     .agent(mission.owner, mission.agent)
                     .expect("assigned agent should exist"),
             );
 
+            if outcome.detected {
+                budgets.mark_aggressor(
+                    mission.target_owner,
+                    mission.owner,
+                    catalog.config().counter_intel_budget(),
+                );
+            }
+
             if let Some(probe) = outcome.probe_event {
                 probe_writer.send(probe);
             }
@@ -1886,6 +2321,9 @@ pub fn resolve_espionage_missions(
 
             if let Some(agent) = roster.agent_mut(mission.owner, mission.agent) {
                 agent.assignment = AgentAssignment::Available;
+                if outcome.succeeded {
+                    agent.experience = catalog.config().agent_experience().award(agent.experience);
+                }
             }
 
             resolved_instances.push(mission.instance_id);
@@ -1901,6 +2339,7 @@ fn determine_mission_outcome(
     tick: u64,
     catalog: &EspionageCatalog,
     ledger: &KnowledgeLedger,
+    budgets: &CounterIntelBudgets,
     mission: &ScheduledEspionageMission,
     agent: &EspionageAgent,
 ) -> MissionOutcome {
@@ -1932,11 +2371,8 @@ fn determine_mission_outcome(
                 .map(|record| record.suspicion)
                 .unwrap_or_else(scalar_zero);
 
-            let success_score = template.base_success
-                + agent.stealth * template.stealth_weight
-                + agent.recon * template.recon_weight
-                - security_penalty
-                - suspicion_penalty;
+            let success_score =
+                estimate_success(config, template, agent, security_posture, suspicion_penalty);
 
             let success_threshold = template.success_threshold;
             let partial_threshold = if probe_tuning.partial_margin() > 0.0 {
@@ -1956,6 +2392,7 @@ fn determine_mission_outcome(
             }
 
             if success_score >= success_threshold {
+                outcome.succeeded = true;
                 outcome.probe_event = Some(EspionageProbeEvent {
                     owner: mission.target_owner,
                     discovery_id: mission.discovery_id,
@@ -1996,6 +2433,7 @@ fn determine_mission_outcome(
                         .or_else(|| Some(format!("{} achieved partial success", template.name))),
                 });
             } else {
+                outcome.detected = true;
                 outcome.probe_event = Some(EspionageProbeEvent {
                     owner: mission.target_owner,
                     discovery_id: mission.discovery_id,
@@ -2014,27 +2452,31 @@ fn determine_mission_outcome(
             }
         }
         EspionageMissionKind::CounterIntel => {
-            let success_score = template.base_success
-                + agent.counter_intel * template.counter_intel_weight
-                - security_penalty * counter_tuning.security_penalty_factor();
+            let cleared_faction =
+                ledger
+                    .entry(mission.owner, mission.discovery_id)
+                    .and_then(|entry| {
+                        entry
+                            .infiltrations
+                            .iter()
+                            .max_by_key(|inf| inf.suspicion)
+                            .map(|inf| inf.faction)
+                    });
+            // A sweep against a source the owner recently caught probing it lands more easily.
+            let aggressor_bonus = cleared_faction
+                .map(|aggressor| budgets.aggressor_bonus(mission.owner, aggressor))
+                .unwrap_or_else(scalar_zero);
+            let success_score =
+                estimate_success(config, template, agent, security_posture, scalar_zero())
+                    + aggressor_bonus;
 
             if success_score >= template.success_threshold {
+                outcome.succeeded = true;
                 let countermeasure = template
                     .countermeasure
                     .clone()
                     .unwrap_or_else(|| counter_tuning.default_countermeasure());
 
-                let cleared_faction =
-                    ledger
-                        .entry(mission.owner, mission.discovery_id)
-                        .and_then(|entry| {
-                            entry
-                                .infiltrations
-                                .iter()
-                                .max_by_key(|inf| inf.suspicion)
-                                .map(|inf| inf.faction)
-                        });
-
                 outcome.sweep_event = Some(CounterIntelSweepEvent {
                     owner: mission.owner,
                     discovery_id: mission.discovery_id,
@@ -2061,6 +2503,38 @@ fn determine_mission_outcome(
     outcome
 }
 
+/// Success score `agent` would roll on `template` against a target at `security_posture`,
+/// compared with `template.success_threshold` at resolution. Includes the agent's experience
+/// bonus; `suspicion` (the infiltrator's current suspicion) only weighs on probes. A counter-intel
+/// sweep additionally rolls its owner's [`CounterIntelBudgets::aggressor_bonus`] against the
+/// infiltrator it targets, which this estimate leaves out.
+pub fn estimate_success(
+    config: &EspionageBalanceConfig,
+    template: &EspionageMissionTemplate,
+    agent: &EspionageAgent,
+    security_posture: sim_runtime::KnowledgeSecurityPosture,
+    suspicion: Scalar,
+) -> Scalar {
+    let security_penalty = config.security_penalty(security_posture);
+    let experience_bonus = agent.experience_bonus(config.agent_experience());
+    match template.kind {
+        EspionageMissionKind::Probe => {
+            template.base_success
+                + agent.stealth * template.stealth_weight
+                + agent.recon * template.recon_weight
+                + experience_bonus
+                - security_penalty
+                - suspicion
+        }
+        EspionageMissionKind::CounterIntel => {
+            template.base_success
+                + agent.counter_intel * template.counter_intel_weight
+                + experience_bonus
+                - security_penalty * config.counter_intel_resolution().security_penalty_factor()
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -2707,6 +3181,378 @@ mod tests {
             );
         }
     }
+
+    const LIMITED_MISSION_CATALOG: &str = r#"{
+        "missions": [
+            { "id": "slow_probe", "name": "Slow Probe", "resolution_ticks": 4, "cooldown_ticks": 5 },
+            { "id": "capped_probe", "name": "Capped Probe", "resolution_ticks": 4, "max_concurrent": 1 }
+        ]
+    }"#;
+
+    fn limited_catalog() -> EspionageCatalog {
+        EspionageCatalog::load_from_str(
+            BUILTIN_ESPIONAGE_AGENT_CATALOG,
+            LIMITED_MISSION_CATALOG,
+            BUILTIN_ESPIONAGE_CONFIG,
+        )
+        .expect("limited catalog parses")
+    }
+
+    fn handcrafted_handles(
+        roster: &EspionageRoster,
+        faction: FactionId,
+    ) -> Vec<EspionageAgentHandle> {
+        roster
+            .agents_for(faction)
+            .iter()
+            .filter(|agent| !agent.generated)
+            .map(|agent| agent.handle)
+            .collect()
+    }
+
+    fn limited_params(
+        mission: &str,
+        agent: EspionageAgentHandle,
+        scheduled_tick: u64,
+    ) -> QueueMissionParams {
+        QueueMissionParams {
+            mission_id: EspionageMissionId::new(mission),
+            owner: FactionId(1),
+            target_owner: FactionId(0),
+            discovery_id: 101,
+            agent,
+            target_tier: None,
+            scheduled_tick,
+        }
+    }
+
+    #[test]
+    fn queue_mission_rejects_requeue_within_cooldown() {
+        let catalog = limited_catalog();
+        let mut roster = EspionageRoster::default();
+        roster.seed_from_catalog(&[FactionId(0), FactionId(1)], &catalog);
+        let handles = handcrafted_handles(&roster, FactionId(1));
+        let mut state = EspionageMissionState::default();
+
+        state
+            .queue_mission(
+                &catalog,
+                &mut roster,
+                limited_params("slow_probe", handles[0], 10),
+            )
+            .expect("first queue succeeds");
+        let err = state
+            .queue_mission(
+                &catalog,
+                &mut roster,
+                limited_params("slow_probe", handles[1], 12),
+            )
+            .expect_err("second queue inside the cooldown is rejected");
+        assert!(
+            matches!(err, QueueMissionError::OnCooldown { ready_at: 15, .. }),
+            "unexpected error {err:?}"
+        );
+        assert_eq!(state.missions().len(), 1);
+
+        state
+            .queue_mission(
+                &catalog,
+                &mut roster,
+                limited_params("slow_probe", handles[1], 15),
+            )
+            .expect("queue succeeds once the cooldown has elapsed");
+    }
+
+    #[test]
+    fn queue_mission_rejects_past_concurrency_cap() {
+        let catalog = limited_catalog();
+        let mut roster = EspionageRoster::default();
+        roster.seed_from_catalog(&[FactionId(0), FactionId(1)], &catalog);
+        let handles = handcrafted_handles(&roster, FactionId(1));
+        let mut state = EspionageMissionState::default();
+
+        state
+            .queue_mission(
+                &catalog,
+                &mut roster,
+                limited_params("capped_probe", handles[0], 0),
+            )
+            .expect("first instance fits under the cap");
+        let err = state
+            .queue_mission(
+                &catalog,
+                &mut roster,
+                limited_params("capped_probe", handles[1], 0),
+            )
+            .expect_err("second instance exceeds the cap");
+        assert!(
+            matches!(err, QueueMissionError::TooManyConcurrent { limit: 1, .. }),
+            "unexpected error {err:?}"
+        );
+
+        let other_faction = handcrafted_handles(&roster, FactionId(0));
+        let mut params = limited_params("capped_probe", other_faction[0], 0);
+        params.owner = FactionId(0);
+        params.target_owner = FactionId(1);
+        state
+            .queue_mission(&catalog, &mut roster, params)
+            .expect("the cap is per faction");
+    }
+
+    #[test]
+    fn catalog_rejects_zero_concurrency_limit() {
+        let missions =
+            r#"{ "missions": [ { "id": "never", "name": "Never", "max_concurrent": 0 } ] }"#;
+        let err = EspionageCatalog::load_from_str(
+            BUILTIN_ESPIONAGE_AGENT_CATALOG,
+            missions,
+            BUILTIN_ESPIONAGE_CONFIG,
+        )
+        .expect_err("zero cap is invalid");
+        assert!(matches!(err, EspionageCatalogError::ZeroConcurrencyLimit(id) if id == "never"));
+    }
+
+    const EXPERIENCE_MISSION_CATALOG: &str = r#"{
+        "missions": [
+            { "id": "sure_probe", "name": "Sure Probe", "base_success": 0.9, "success_threshold": 0.5 },
+            {
+                "id": "doomed_probe", "name": "Doomed Probe", "base_success": 0.0,
+                "success_threshold": 0.95, "stealth_weight": 0.0, "recon_weight": 0.0
+            }
+        ]
+    }"#;
+
+    fn run_mission(app: &mut App, mission: &str, agent: EspionageAgentHandle) {
+        app.world
+            .resource_scope(|world, mut mission_state: Mut<EspionageMissionState>| {
+                world.resource_scope(|world, mut roster: Mut<EspionageRoster>| {
+                    mission_state
+                        .queue_mission(
+                            world.resource::<EspionageCatalog>(),
+                            &mut roster,
+                            limited_params(mission, agent, 0),
+                        )
+                        .expect("mission queued");
+                });
+            });
+        app.world.run_system_once(resolve_espionage_missions);
+        assert!(app
+            .world
+            .resource::<EspionageMissionState>()
+            .missions()
+            .is_empty());
+    }
+
+    #[test]
+    fn successful_missions_build_experience_along_the_curve() {
+        let infiltrator = FactionId(1);
+        let mut app = setup_app_with_catalog(&[FactionId(0), infiltrator]);
+        app.insert_resource(
+            EspionageCatalog::load_from_str(
+                BUILTIN_ESPIONAGE_AGENT_CATALOG,
+                EXPERIENCE_MISSION_CATALOG,
+                BUILTIN_ESPIONAGE_CONFIG,
+            )
+            .expect("experience catalog parses"),
+        );
+        let handle = handcrafted_handles(app.world.resource::<EspionageRoster>(), infiltrator)[0];
+
+        let estimate = |app: &App| {
+            let catalog = app.world.resource::<EspionageCatalog>();
+            let agent = app
+                .world
+                .resource::<EspionageRoster>()
+                .agent(infiltrator, handle)
+                .expect("agent exists")
+                .clone();
+            let template = catalog
+                .mission(&EspionageMissionId::new("sure_probe"))
+                .expect("mission exists");
+            (
+                agent.experience,
+                estimate_success(
+                    catalog.config(),
+                    template,
+                    &agent,
+                    sim_runtime::KnowledgeSecurityPosture::Standard,
+                    scalar_zero(),
+                ),
+            )
+        };
+
+        let (experience, base_estimate) = estimate(&app);
+        assert_eq!(experience, 0);
+        let tuning = AgentExperienceTuning::default();
+        let mut previous = base_estimate;
+        for expected in 1..=3 {
+            run_mission(&mut app, "sure_probe", handle);
+            let (experience, current) = estimate(&app);
+            assert_eq!(experience, expected);
+            assert!(current > previous, "estimate should rise with experience");
+            assert_eq!(current - base_estimate, tuning.bonus(expected));
+            previous = current;
+        }
+        // Default curve: 0.1 * 3 / (3 + 3).
+        assert_eq!(tuning.bonus(3), Scalar::from_f32(0.05));
+
+        run_mission(&mut app, "doomed_probe", handle);
+        let (experience, current) = estimate(&app);
+        assert_eq!(experience, 3, "failed missions earn no experience");
+        assert_eq!(current, previous);
+    }
+
+    #[test]
+    fn experience_is_capped() {
+        let tuning = AgentExperienceTuning::default();
+        let mut experience = 0;
+        for _ in 0..50 {
+            experience = tuning.award(experience);
+        }
+        assert_eq!(experience, tuning.max_experience());
+        assert_eq!(
+            tuning.bonus(u32::MAX),
+            tuning.bonus(tuning.max_experience())
+        );
+        assert!(tuning.bonus(tuning.max_experience()) < Scalar::from_f32(0.1));
+    }
+
+    /// A probe that always fails (and is detected) plus a sweep that lands only with the
+    /// known-aggressor bonus: 0.45 base against a 0.5 threshold on a `Minimal` posture.
+    const AGGRESSOR_MISSION_CATALOG: &str = r#"{
+        "missions": [
+            {
+                "id": "doomed_probe", "name": "Doomed Probe", "base_success": 0.0,
+                "success_threshold": 0.95, "stealth_weight": 0.0, "recon_weight": 0.0
+            },
+            {
+                "id": "marginal_sweep", "name": "Marginal Sweep", "kind": "counter_intel",
+                "base_success": 0.45, "success_threshold": 0.5, "counter_intel_weight": 0.0
+            }
+        ]
+    }"#;
+
+    fn run_marginal_sweep(app: &mut App, owner: FactionId) -> Option<CounterIntelSweepEvent> {
+        let agent = app
+            .world
+            .resource::<EspionageRoster>()
+            .agents_for(owner)
+            .iter()
+            .find(|agent| agent.tags.contains(&"counter_intel".to_string()))
+            .expect("expected a counter-intel capable agent")
+            .handle;
+        let mut params = limited_params("marginal_sweep", agent, 0);
+        params.owner = owner;
+        params.target_owner = owner;
+        app.world
+            .resource_scope(|world, mut mission_state: Mut<EspionageMissionState>| {
+                world.resource_scope(|world, mut roster: Mut<EspionageRoster>| {
+                    mission_state
+                        .queue_mission(world.resource::<EspionageCatalog>(), &mut roster, params)
+                        .expect("sweep queued");
+                });
+            });
+        app.world.run_system_once(resolve_espionage_missions);
+        let mut sweeps = app.world.resource_mut::<Events<CounterIntelSweepEvent>>();
+        let drained: Vec<_> = sweeps.drain().collect();
+        assert!(drained.len() <= 1);
+        drained.into_iter().next()
+    }
+
+    #[test]
+    fn detected_probe_raises_counter_intel_against_its_source() {
+        let defender = FactionId(0);
+        let aggressor = FactionId(1);
+        let mut app = setup_app_with_catalog(&[defender, aggressor]);
+        app.insert_resource(
+            EspionageCatalog::load_from_str(
+                BUILTIN_ESPIONAGE_AGENT_CATALOG,
+                AGGRESSOR_MISSION_CATALOG,
+                BUILTIN_ESPIONAGE_CONFIG,
+            )
+            .expect("aggressor catalog parses"),
+        );
+        {
+            let config = app.world.resource::<KnowledgeLedgerConfigHandle>().get();
+            let mut entry = KnowledgeLedgerEntry::new(defender, 101, config.as_ref());
+            entry.security_posture = sim_runtime::KnowledgeSecurityPosture::Minimal;
+            let mut ledger = app.world.resource_mut::<KnowledgeLedger>();
+            ledger.upsert_entry(entry);
+            ledger.record_espionage_probe(EspionageProbeEvent {
+                owner: defender,
+                discovery_id: 101,
+                infiltrator: aggressor,
+                fidelity_gain: Scalar::from_f32(0.2),
+                suspicion_gain: Scalar::from_f32(0.3),
+                cells: 1,
+                tick: 0,
+                note: None,
+            });
+        }
+
+        assert!(
+            run_marginal_sweep(&mut app, defender).is_none(),
+            "without a detection the sweep falls short"
+        );
+
+        let prober = handcrafted_handles(app.world.resource::<EspionageRoster>(), aggressor)[0];
+        run_mission(&mut app, "doomed_probe", prober);
+        let bonus = CounterIntelBudgetConfig::default().known_aggressor_bonus();
+        let budgets = app.world.resource::<CounterIntelBudgets>();
+        assert_eq!(budgets.aggressor_bonus(defender, aggressor), bonus);
+        assert_eq!(
+            budgets.aggressor_bonus(aggressor, defender),
+            scalar_zero(),
+            "only the detecting side gains the bonus"
+        );
+
+        let sweep = run_marginal_sweep(&mut app, defender)
+            .expect("the known-aggressor bonus carries the sweep over its threshold");
+        assert_eq!(sweep.cleared_faction, Some(aggressor));
+    }
+
+    #[test]
+    fn known_aggressor_bonus_decays_each_tick() {
+        let detector = FactionId(0);
+        let aggressor = FactionId(1);
+        let config = CounterIntelBudgetConfig {
+            known_aggressor_bonus: 0.2,
+            known_aggressor_decay_per_tick: 0.05,
+            ..CounterIntelBudgetConfig::default()
+        };
+        let mut budgets = CounterIntelBudgets::new(&[detector, aggressor], &config);
+        budgets.mark_aggressor(detector, aggressor, &config);
+        assert_eq!(
+            budgets.aggressor_bonus(detector, aggressor),
+            Scalar::from_f32(0.2)
+        );
+
+        budgets.regenerate(&config);
+        assert_eq!(
+            budgets.aggressor_bonus(detector, aggressor),
+            Scalar::from_f32(0.15)
+        );
+
+        // A repeat detection refreshes the bonus instead of stacking it.
+        budgets.mark_aggressor(detector, aggressor, &config);
+        assert_eq!(
+            budgets.aggressor_bonus(detector, aggressor),
+            Scalar::from_f32(0.2)
+        );
+
+        for _ in 0..3 {
+            budgets.regenerate(&config);
+        }
+        assert_eq!(
+            budgets.aggressor_bonus(detector, aggressor),
+            Scalar::from_f32(0.05)
+        );
+        budgets.regenerate(&config);
+        assert_eq!(budgets.aggressor_bonus(detector, aggressor), scalar_zero());
+        assert!(
+            budgets.known_aggressors.is_empty(),
+            "a spent bonus is dropped"
+        );
+    }
 }
 
 #[derive(Deserialize)]
@@ -2776,6 +3622,10 @@ struct EspionageMissionCatalogEntry {
     #[serde(default)]
     target_tier_max: Option<u8>,
     #[serde(default)]
+    cooldown_ticks: Option<u16>,
+    #[serde(default)]
+    max_concurrent: Option<u16>,
+    #[serde(default)]
     generator: Option<EspionageMissionGeneratorEntry>,
 }
 
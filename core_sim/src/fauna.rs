@@ -4,16 +4,17 @@ use std::f32::consts::TAU;
 
 use bevy::prelude::*;
 use rand::{rngs::SmallRng, seq::SliceRandom, Rng, SeedableRng};
-use sim_runtime::TerrainTags;
+use sim_runtime::{EventSeverity, TerrainTags};
 use sim_schema::HerdState;
 use tracing::info;
 
 use std::hash::{Hash, Hasher};
 
 use crate::{
+    combat::CombatStats,
     components::{FollowPolicy, PopulationCohort, ResidentBand, SourceYield, Tile},
     fauna_config::{
-        default_loiter_radius, EcologyConfig, FaunaConfig, FaunaConfigHandle, GrazeConfig,
+        default_loiter_radius, Diet, EcologyConfig, FaunaConfig, FaunaConfigHandle, GrazeConfig,
         HusbandryCeiling, SizeClass, SpeciesDef, DEFAULT_HUSBANDRY_DENSITY, NO_GRAZE_CAPACITY,
     },
     food::{classify_food_module, FoodModule},
@@ -27,6 +28,7 @@ use crate::{
         CommandEventEntry, CommandEventKind, CommandEventLog, SimulationConfig, SimulationTick,
         StartLocation, TileRegistry,
     },
+    spawn_rules_config::{SpawnRulesConfig, SpawnRulesConfigHandle},
     systems::workers_needed_for_take,
 };
 
@@ -44,6 +46,10 @@ const HERD_MOVEMENT_SEED_SALT: u64 = 0x4D0E_9A17_C0FF_EE21;
 /// (`placed.len()`) and per-turn immigration.
 const GAME_ID_PREFIX: &str = "game_";
 
+/// Id prefix for herds seated by the `spawn_herd` debug command — distinct from `game_` so a
+/// designer's herd never counts against (or is trimmed by) the wild-game cap.
+pub const DEBUG_HERD_ID_PREFIX: &str = "debug_";
+
 pub const HERD_DENSITY_REFERENCE_BIOMASS: f32 = 8_000.0;
 
 /// Discovery id for the faction-level **Herding** knowledge — the animal ladder's **rung-2** gate
@@ -1170,6 +1176,47 @@ impl HerdRegistry {
         self.herds.iter().map(to_entry).collect()
     }
 
+    /// Seat a designer-spawned herd of species `key` at `pos` (the `spawn_herd` debug command).
+    /// The herd is stationary-routed on its tile and built exactly as a wild-game spawn would be,
+    /// with `biomass` clamped into `(0, carrying_capacity]`. Returns the new herd's id
+    /// (`debug_<key>_NN`, the first free index). Callers validate the species and tile.
+    pub fn spawn_debug(
+        &mut self,
+        key: &str,
+        def: &SpeciesDef,
+        fauna: &FaunaConfig,
+        pos: UVec2,
+        biomass: f32,
+    ) -> String {
+        let id = (0u32..)
+            .map(|idx| format!("{DEBUG_HERD_ID_PREFIX}{key}_{idx:02}"))
+            .find(|id| self.find(id).is_none())
+            .expect("debug herd index space exhausted");
+        let carrying_capacity = def.carrying_capacity();
+        let mut herd = Herd::new(
+            id.clone(),
+            def.display_name.clone(),
+            def.size_class,
+            vec![pos],
+            biomass.min(carrying_capacity),
+            carrying_capacity,
+            def.fodder_per_biomass,
+            def.regrowth_rate_or(fauna.ecology.regrowth_rate),
+            def.body_mass,
+        );
+        herd.husbandry_ceiling = def.husbandry_ceiling;
+        herd.refresh_ecology_phase(fauna);
+        log_herd_spawn(&herd);
+        self.herds.push(herd);
+        id
+    }
+
+    /// Remove a herd by id (the `cull_herd` debug command), returning it if it existed.
+    pub fn remove(&mut self, id: &str) -> Option<Herd> {
+        let index = self.herds.iter().position(|herd| herd.id == id)?;
+        Some(self.herds.remove(index))
+    }
+
     /// Number of domesticated groups owned by `faction`. The seam the future
     /// `SedentarizationScore` reads for its "domestication progress" input (`TASKS.md`).
     pub fn domesticated_count(&self, faction: FactionId) -> usize {
@@ -1355,6 +1402,9 @@ pub fn spawn_initial_herds(
     tiles: Query<&Tile>,
     world_seed: Option<Res<WorldGenSeed>>,
     fauna_config: Res<FaunaConfigHandle>,
+    // Optional so hand-built test harnesses need no rules table; absent reads as "no rules", and
+    // every species spawns where it always has.
+    spawn_rules: Option<Res<SpawnRulesConfigHandle>>,
 ) {
     if !registry.herds.is_empty() {
         telemetry.entries = registry.herds.iter().map(to_entry).collect();
@@ -1363,6 +1413,7 @@ pub fn spawn_initial_herds(
     }
 
     let fauna = fauna_config.get();
+    let spawn_rules = spawn_rules.map(|handle| handle.get()).unwrap_or_default();
     let seed = world_seed
         .map(|seed| seed.0)
         .unwrap_or_else(|| config.map_seed);
@@ -1384,6 +1435,7 @@ pub fn spawn_initial_herds(
     //    each species drawn from the config's migratory rows.
     spawn_migratory_herds(
         &fauna,
+        &spawn_rules,
         base,
         width,
         height,
@@ -1396,6 +1448,7 @@ pub fn spawn_initial_herds(
     // 2. Short-range wild game — biome-density placement across the whole map.
     spawn_short_range_game(
         &fauna,
+        &spawn_rules,
         width,
         height,
         wrap,
@@ -1434,9 +1487,13 @@ fn log_herd_spawn(herd: &Herd) {
 /// lives in its biome range across the map rather than clustered at the player start. A species whose
 /// host biomes the map lacks falls back to the start-anchored spiral (`build_migratory_route`), so it
 /// still spawns somewhere.
+///
+/// A species with a spawn rule keeps only the route anchors whose ground the rule allows (the
+/// legs between them still cross anything), and skips its slot when none remain.
 #[allow(clippy::too_many_arguments)]
 fn spawn_migratory_herds(
     fauna: &FaunaConfig,
+    spawn_rules: &SpawnRulesConfig,
     base: UVec2,
     width: u32,
     height: u32,
@@ -1446,7 +1503,11 @@ fn spawn_migratory_herds(
     herds: &mut Vec<Herd>,
     wrap: bool,
 ) {
-    let migratory = fauna.migratory_species();
+    let migratory: Vec<_> = fauna
+        .migratory_species()
+        .into_iter()
+        .filter(|(key, _)| spawn_rules.species_weight(key) > 0.0)
+        .collect();
     if migratory.is_empty() {
         return;
     }
@@ -1464,10 +1525,18 @@ fn spawn_migratory_herds(
     }
     let herd_target = determine_herd_count(width, height);
     for idx in 0..herd_target {
-        let (key, def) = migratory[rng.gen_range(0..migratory.len())];
+        let (key, def) = pick_by_spawn_weight(&migratory, spawn_rules, rng);
         let steps = def.sample_route_len(rng);
-        let suitable = suitable_tiles_for(def, &suitable_by_module);
-        let Some(route) = build_migratory_route(
+        let ruled = spawn_rules.has_species_rule(key);
+        let allowed = |pos: UVec2| {
+            tags_at(pos, tile_registry, tiles)
+                .is_some_and(|tags| spawn_rules.allows_species(key, tags))
+        };
+        let mut suitable = suitable_tiles_for(def, &suitable_by_module);
+        if ruled {
+            suitable.retain(|&pos| allowed(pos));
+        }
+        let Some(mut route) = build_migratory_route(
             base,
             width,
             height,
@@ -1481,6 +1550,12 @@ fn spawn_migratory_herds(
         ) else {
             continue;
         };
+        if ruled {
+            route.retain(|&pos| allowed(pos));
+            if route.is_empty() {
+                continue;
+            }
+        }
         let biomass = def.sample_biomass(rng);
         let carrying_capacity = def.carrying_capacity();
         let id = format!("herd_{key}_{idx:02}");
@@ -1514,6 +1589,7 @@ fn spawn_migratory_herds(
 #[allow(clippy::too_many_arguments)] // Bevy resources + grid bounds + topology; a struct would only move the noise
 fn spawn_short_range_game(
     fauna: &FaunaConfig,
+    spawn_rules: &SpawnRulesConfig,
     width: u32,
     height: u32,
     wrap: bool,
@@ -1562,6 +1638,7 @@ fn spawn_short_range_game(
             module_key,
             game_idx,
             fauna,
+            spawn_rules,
             width,
             height,
             wrap,
@@ -1580,8 +1657,8 @@ fn spawn_short_range_game(
 
 /// Build a single short-range game group at `pos`: pick a species hosting `module_key`,
 /// roll its route/biomass, and stamp its initial `ecology_phase`. Returns `None` if no
-/// species hosts the biome or the origin is not land. Shared by initial spawn and
-/// per-turn immigration.
+/// species hosts the biome (or the spawn rules allow none on this tile) or the origin is not
+/// land. Shared by initial spawn and per-turn immigration.
 // Placement needs the config, grid bounds, both tile resources, and the RNG; grouping
 // them into a struct would just move the noise without improving clarity.
 #[allow(clippy::too_many_arguments)]
@@ -1590,6 +1667,7 @@ fn spawn_game_group_at(
     module_key: &str,
     game_idx: u32,
     fauna: &FaunaConfig,
+    spawn_rules: &SpawnRulesConfig,
     width: u32,
     height: u32,
     wrap: bool,
@@ -1612,10 +1690,13 @@ fn spawn_game_group_at(
             adjacent_water_kinds(pos, width, height, wrap, tile_registry, tiles);
         candidates.retain(|(_, def)| def.adjacent_water.satisfied_by(has_salt, has_fresh));
     }
+    if let Some(tags) = tags_at(pos, tile_registry, tiles) {
+        candidates.retain(|(key, _)| spawn_rules.allows_species(key, tags));
+    }
     if candidates.is_empty() {
         return None;
     }
-    let (key, def) = candidates[rng.gen_range(0..candidates.len())];
+    let (key, def) = pick_by_spawn_weight(&candidates, spawn_rules, rng);
     let steps = def.sample_route_len(rng);
     let route = build_short_route(pos, steps, width, height, tile_registry, tiles, rng)?;
     let biomass = def.sample_biomass(rng);
@@ -1745,7 +1826,11 @@ pub fn advance_herds(
                 herd.carrying_capacity = k;
             }
         }
-        regrow_biomass(herd, &fauna);
+        // A carnivore's growth is the prey it eats, not its range: `advance_predation` steps it once
+        // every herd has moved.
+        if !def.is_some_and(|def| def.diet == Diet::Carnivore) {
+            regrow_biomass(herd, &fauna);
+        }
         let position = herd.position();
         info!(
             target: "shadow_scale::analytics",
@@ -1760,6 +1845,7 @@ pub fn advance_herds(
             ecology_phase = herd.ecology_phase.as_str(),
         );
     }
+    advance_predation(&mut registry.herds, &fauna, width, wrap);
     // Local extinction: a group hunted to zero, or a collapsing remnant that has fallen below the
     // viability floor, **disperses** and despawns — measured against the ecology/capacity the herd
     // actually lives under (`herd_ecology`/`herd_capacity`), never the raw wild pair.
@@ -1778,6 +1864,65 @@ pub fn advance_herds(
     density.rebuild(config.grid_size, &registry);
 }
 
+/// **One turn of predator–prey coupling** ([`crate::fauna_config::PredationConfig`], the discrete
+/// Lotka–Volterra step): every carnivore herd, in registry order, draws its take from the wild
+/// herbivore herds in range whose defense its attack clears, then grows by what it converted and
+/// shrinks by its mortality, clamped to `[0, K]`. Packs draw in turn from the prey biomass the
+/// previous pack left, so two packs sharing a herd can never take more than stands — the order is
+/// the registry's, which is stable under rollback.
+///
+/// Runs after regrowth, so the take is measured against this turn's grown prey; a pack's
+/// `biomass_before_regrowth` is its pre-step biomass, the slot a grazer's `regrow_biomass` fills.
+pub(crate) fn advance_predation(herds: &mut [Herd], fauna: &FaunaConfig, width: u32, wrap: bool) {
+    let predation = &fauna.predation;
+    for predator in 0..herds.len() {
+        let Some(attack) = fauna
+            .species_by_display(&herds[predator].species)
+            .filter(|def| def.diet == Diet::Carnivore)
+            .map(|def| def.combat.attack)
+        else {
+            continue;
+        };
+        let den = herds[predator].current_pos;
+        let prey: Vec<usize> = (0..herds.len())
+            .filter(|&candidate| {
+                let herd = &herds[candidate];
+                // An unresolvable species reads as a default herbivore (harmless, defense 1).
+                let (diet, defense) = fauna
+                    .species_by_display(&herd.species)
+                    .map(|def| (def.diet, def.combat.defense))
+                    .unwrap_or((Diet::Herbivore, CombatStats::default().defense));
+                candidate != predator
+                    && diet == Diet::Herbivore
+                    && !herd.is_corralled()
+                    && attack >= defense
+                    && hex_distance_wrapped(den, herd.current_pos, width, wrap)
+                        <= predation.range_tiles
+            })
+            .collect();
+        let prey_biomass: f32 = prey.iter().map(|&idx| herds[idx].biomass).sum();
+        let pack = herds[predator].biomass;
+        let take = (predation.attack_rate * pack * prey_biomass)
+            .min(predation.max_take_fraction * prey_biomass);
+        if take > 0.0 {
+            for &idx in &prey {
+                let herd = &mut herds[idx];
+                herd.biomass = (herd.biomass - take * herd.biomass / prey_biomass).max(0.0);
+                // The kill-credit bank never holds more than the herd that is left (slice 8b).
+                herd.hunt_credit = herd.hunt_credit.min(herd.biomass);
+                herd.refresh_ecology_phase(fauna);
+            }
+        }
+        let herd = &mut herds[predator];
+        let cap = herd_capacity(herd, fauna);
+        herd.biomass_before_regrowth = pack;
+        herd.biomass = (pack + predation.conversion_efficiency * take
+            - predation.mortality_rate * pack)
+            .clamp(0.0, cap);
+        herd.refresh_ecology_phase(fauna);
+    }
+}
+
 /// The **graze's sustainable flow** at biomass `G` (Grazing Phase 2b-ii) — one turn's regrowth at the
 /// MSY-clamped biomass (`min(G, cap/2)`), **pure logistic, without the Allee cutoff**. This is the
 /// graze counterpart of [`sustainable_yield`], but deliberately *not* that helper: `sustainable_yield`
@@ -2439,8 +2584,10 @@ pub fn repopulate_fauna(
     world_seed: Option<Res<WorldGenSeed>>,
     tile_registry: Res<TileRegistry>,
     tiles: Query<&Tile>,
+    spawn_rules: Option<Res<SpawnRulesConfigHandle>>,
 ) {
     let fauna = fauna_config.get();
+    let spawn_rules = spawn_rules.map(|handle| handle.get()).unwrap_or_default();
     let imm = &fauna.immigration;
     // `max_total_game` caps short-range game groups only (matching spawn's `placed`
     // counter); migratory `herd_*` are spawned separately and don't count against it.
@@ -2489,6 +2636,7 @@ pub fn repopulate_fauna(
             module_key,
             idx,
             &fauna,
+            &spawn_rules,
             width,
             height,
             config.map_topology.wrap_horizontal,
@@ -2676,19 +2824,22 @@ pub fn advance_husbandry(
                     // machine-readable `status=… reason=… herd=…` fields.
                     if let Some(owner) = herd.owner {
                         let (pen_x, pen_y) = pen.map(|t| (t.x, t.y)).unwrap_or_default();
-                        event_log.push(CommandEventEntry::new(
-                            tick.0,
-                            CommandEventKind::Corral,
-                            owner,
-                            format!(
-                                "The {} herd broke out — untended, the pen is lost",
-                                herd.species
-                            ),
-                            Some(format!(
+                        event_log.push(
+                            CommandEventEntry::new(
+                                tick.0,
+                                CommandEventKind::Corral,
+                                owner,
+                                format!(
+                                    "The {} herd broke out — untended, the pen is lost",
+                                    herd.species
+                                ),
+                                Some(format!(
                                 "status=escaped reason=untended action=corral herd={} x={} y={}",
                                 herd.id, pen_x, pen_y
                             )),
-                        ));
+                            )
+                            .with_severity(EventSeverity::Alert),
+                        );
                     }
                 }
             } else {
@@ -2805,19 +2956,22 @@ fn starve_underfed_pen(
     }
     herd.pen_starving = true;
     if let Some(owner) = herd.owner {
-        event_log.push(CommandEventEntry::new(
-            tick,
-            CommandEventKind::Corral,
-            owner,
-            format!(
-                "The {} herd is starving — the pen has no feed",
-                herd.species
-            ),
-            Some(format!(
-                "status=starving fed={fed:.2} action=corral herd={}",
-                herd.id
-            )),
-        ));
+        event_log.push(
+            CommandEventEntry::new(
+                tick,
+                CommandEventKind::Corral,
+                owner,
+                format!(
+                    "The {} herd is starving — the pen has no feed",
+                    herd.species
+                ),
+                Some(format!(
+                    "status=starving fed={fed:.2} action=corral herd={}",
+                    herd.id
+                )),
+            )
+            .with_severity(EventSeverity::Alert),
+        );
     }
 }
 
@@ -4203,6 +4357,37 @@ fn build_short_route(
     Some(points)
 }
 
+/// Terrain tags of the tile at `position`, or `None` off the map.
+fn tags_at(position: UVec2, registry: &TileRegistry, tiles: &Query<&Tile>) -> Option<TerrainTags> {
+    let entity = registry.index(position.x, position.y)?;
+    tiles.get(entity).ok().map(|tile| tile.terrain_tags)
+}
+
+/// Draw one species, weighted by its spawn rule. When every candidate shares a weight this is
+/// the single uniform `gen_range` the spawner has always made, so a map without weighted rules
+/// draws exactly the herds it did before.
+fn pick_by_spawn_weight<'a>(
+    candidates: &[(&'a String, &'a SpeciesDef)],
+    spawn_rules: &SpawnRulesConfig,
+    rng: &mut SmallRng,
+) -> (&'a String, &'a SpeciesDef) {
+    let weights: Vec<f32> = candidates
+        .iter()
+        .map(|(key, _)| spawn_rules.species_weight(key))
+        .collect();
+    if weights.iter().all(|weight| *weight == weights[0]) {
+        return candidates[rng.gen_range(0..candidates.len())];
+    }
+    let mut roll = rng.gen::<f32>() * weights.iter().sum::<f32>();
+    for (candidate, weight) in candidates.iter().zip(&weights) {
+        if roll < *weight {
+            return *candidate;
+        }
+        roll -= weight;
+    }
+    candidates[candidates.len() - 1]
+}
+
 /// Food module for a tile position, or `None` for water / unclassified tiles.
 fn module_at(position: UVec2, registry: &TileRegistry, tiles: &Query<&Tile>) -> Option<FoodModule> {
     let entity = registry.index(position.x, position.y)?;
@@ -4408,6 +4593,7 @@ mod tests {
                     FoodModule::BorealArctic.as_str(),
                     0,
                     &fauna,
+                    &SpawnRulesConfig::default(),
                     SIZE,
                     SIZE,
                     false,
@@ -4458,6 +4644,98 @@ mod tests {
         assert!(ShoreRequirement::None.satisfied_by(salt, fresh));
     }
 
+    /// Spawn rules gate the real short-range spawn path on the origin tile's tags: on a woodland
+    /// whose alternate columns are `FERTILE`, deer (required `FERTILE`) stand only on those columns
+    /// and boar (forbidden `FERTILE`) only on the others.
+    #[test]
+    fn game_spawns_respect_terrain_tag_rules() {
+        use bevy::ecs::system::SystemState;
+
+        const SIZE: u32 = 6;
+        const DRAWS: u64 = 40;
+        const DEER: &str = "Red Deer";
+        const BOAR: &str = "Wild Boar";
+
+        let mut world = World::new();
+        let mut tiles = Vec::new();
+        for y in 0..SIZE {
+            for x in 0..SIZE {
+                let terrain_tags = if x % 2 == 0 {
+                    TerrainTags::FERTILE
+                } else {
+                    TerrainTags::empty()
+                };
+                // MixedWoodland classifies as `temperate_forest` by terrain alone, whatever its tags.
+                let entity = world
+                    .spawn(Tile {
+                        position: UVec2::new(x, y),
+                        terrain: TerrainType::MixedWoodland,
+                        terrain_tags,
+                        ..Default::default()
+                    })
+                    .id();
+                tiles.push(entity);
+            }
+        }
+        let registry = TileRegistry {
+            tiles,
+            width: SIZE,
+            height: SIZE,
+        };
+        let fauna = FaunaConfig::builtin();
+        let rules = SpawnRulesConfig::from_json_str(
+            r#"{ "species": {
+                "deer": { "required": ["FERTILE"] },
+                "boar": { "forbidden": ["FERTILE"] }
+            } }"#,
+        )
+        .expect("rules parse");
+
+        let mut state: SystemState<Query<&Tile>> = SystemState::new(&mut world);
+        let query = state.get(&world);
+        let mut seen = (0, 0);
+        for draw in 0..DRAWS {
+            let mut rng = SmallRng::seed_from_u64(draw);
+            for y in 0..SIZE {
+                for x in 0..SIZE {
+                    let pos = UVec2::new(x, y);
+                    let Some(herd) = spawn_game_group_at(
+                        pos,
+                        FoodModule::TemperateForest.as_str(),
+                        0,
+                        &fauna,
+                        &rules,
+                        SIZE,
+                        SIZE,
+                        false,
+                        &registry,
+                        &query,
+                        &mut rng,
+                    ) else {
+                        continue;
+                    };
+                    let fertile = tags_at(herd.position(), &registry, &query)
+                        .expect("herd stands on the map")
+                        .contains(TerrainTags::FERTILE);
+                    if herd.species == DEER {
+                        assert!(fertile, "deer spawned off FERTILE ground at {pos:?}");
+                        seen.0 += 1;
+                    } else if herd.species == BOAR {
+                        assert!(
+                            !fertile,
+                            "boar spawned on forbidden FERTILE ground at {pos:?}"
+                        );
+                        seen.1 += 1;
+                    }
+                }
+            }
+        }
+        assert!(
+            seen.0 > 0 && seen.1 > 0,
+            "both ruled species must spawn somewhere, got {seen:?}"
+        );
+    }
+
     /// A tile with no water at all satisfies nothing but `None` — including `Any`, which is the
     /// state the pre-split `requires_adjacent_water: true` expressed.
     #[test]
@@ -5162,4 +5440,133 @@ mod tests {
             .biomass;
         assert!(grazed < 240.0, "the herd's tile was grazed: {grazed}");
     }
+
+    // ---- Predator–prey coupling ------------------------------------------------------------------
+
+    /// The builtin table plus a carnivore pack and a herbivore it can take (attack 2 clears the
+    /// deer's defense 1), cloned from the deer row so only the trophic fields differ.
+    fn predation_fauna() -> FaunaConfig {
+        let mut fauna = (*FaunaConfig::builtin()).clone();
+        let template = fauna.species["deer"].clone();
+        let mut wolf = template.clone();
+        wolf.display_name = "Test Wolf".to_string();
+        wolf.diet = Diet::Carnivore;
+        wolf.combat.attack = 2.0;
+        let mut deer = template;
+        deer.display_name = "Test Deer".to_string();
+        fauna.species.insert("test_wolf".to_string(), wolf);
+        fauna.species.insert("test_deer".to_string(), deer);
+        fauna
+    }
+
+    /// `[deer, wolves]` on neighbouring tiles of the strip: a fast-breeding herd near its `K` and a
+    /// small pack. Neither grazes, so both `K`s stay the constants set here.
+    fn predator_and_prey() -> Vec<Herd> {
+        let mut deer = herd_of_size(SizeClass::Small, 800.0, 1000.0, 0.0);
+        deer.id = "game_deer".to_string();
+        deer.species = "Test Deer".to_string();
+        deer.regrowth_rate = 0.35;
+        deer.route = vec![UVec2::new(1, 0)];
+        deer.current_pos = UVec2::new(1, 0);
+        let mut wolves = herd_of_size(SizeClass::Small, 20.0, 500.0, 0.0);
+        wolves.id = "game_wolves".to_string();
+        wolves.species = "Test Wolf".to_string();
+        wolves.route = vec![UVec2::new(2, 0)];
+        wolves.current_pos = UVec2::new(2, 0);
+        vec![deer, wolves]
+    }
+
+    /// Direction changes in a series — the peaks and troughs of a cycle.
+    fn turning_points(series: &[f32]) -> usize {
+        series
+            .windows(3)
+            .filter(|w| (w[1] - w[0]) * (w[2] - w[1]) < 0.0)
+            .count()
+    }
+
+    /// **The Lotka–Volterra cycle, per the configured coefficients.** The pack grows while it eats
+    /// the herd down, overshoots, and starves back as the deer thin; the two series keep trading
+    /// peaks and troughs, and the prey the pack meets settles on `m / (a · b)` — the level at which
+    /// conversion exactly repays mortality.
+    #[test]
+    fn predator_and_prey_oscillate_around_the_configured_equilibrium() {
+        let fauna = predation_fauna();
+        let mut herds = predator_and_prey();
+        let (mut prey, mut pack) = (Vec::new(), Vec::new());
+        for _ in 0..200 {
+            regrow_biomass(&mut herds[0], &fauna);
+            prey.push(herds[0].biomass);
+            advance_predation(&mut herds, &fauna, 5, false);
+            pack.push(herds[1].biomass);
+        }
+
+        // The pack rises as the deer are consumed: up every turn of the first 40, while the deer
+        // fall from their early peak.
+        assert!(pack[..40].windows(2).all(|w| w[1] > w[0]), "{pack:?}");
+        assert!(
+            pack[39] > 10.0 * 20.0,
+            "the pack grew on the herd: {}",
+            pack[39]
+        );
+        assert!(prey[10..40].windows(2).all(|w| w[1] < w[0]), "{prey:?}");
+
+        // Both cycle rather than converging monotonically …
+        assert!(turning_points(&prey) >= 4, "{prey:?}");
+        assert!(turning_points(&pack) >= 4, "{pack:?}");
+        // … around the coefficients' equilibrium, without the herd ever crashing past its Allee point.
+        let predation = &fauna.predation;
+        let equilibrium =
+            predation.mortality_rate / (predation.attack_rate * predation.conversion_efficiency);
+        let settled = prey[prey.len() - 1];
+        assert!(
+            (settled - equilibrium).abs() < 0.01 * equilibrium,
+            "prey met by the pack settles on m/(a·b) = {equilibrium}: {settled}"
+        );
+        let allee = fauna.ecology.collapse_fraction * 1000.0;
+        assert!(prey.iter().all(|&b| b > allee), "{prey:?}");
+    }
+
+    /// The step is wired into `advance_herds`: on the strip the pack feeds on the co-located deer
+    /// and grows instead of regrowing logistically, and a deer herd out of range or out of reach
+    /// (defense above the pack's attack) is left alone.
+    #[test]
+    fn advance_herds_feeds_a_pack_on_the_prey_in_range() {
+        use bevy::ecs::system::RunSystemOnce;
+        let mut fauna = predation_fauna();
+        fauna.predation.range_tiles = 5; // the whole strip, wherever the herds roam
+        let mut armoured = fauna.species["test_deer"].clone();
+        armoured.display_name = "Test Armoured Deer".to_string();
+        armoured.combat.defense = 3.0;
+        fauna
+            .species
+            .insert("test_armoured_deer".to_string(), armoured);
+
+        let mut world = roam_world(false);
+        world.insert_resource(FaunaConfigHandle::new(std::sync::Arc::new(fauna)));
+        let mut herds = predator_and_prey();
+        let mut armoured_herd = herds[0].clone();
+        armoured_herd.id = "game_armoured".to_string();
+        armoured_herd.species = "Test Armoured Deer".to_string();
+        herds.push(armoured_herd);
+        world.resource_mut::<HerdRegistry>().herds = herds;
+
+        let biomass = |world: &bevy::prelude::World, id: &str| {
+            world
+                .resource::<HerdRegistry>()
+                .find(id)
+                .expect("herd still on the map")
+                .biomass
+        };
+        let mut last_pack = biomass(&world, "game_wolves");
+        for turn in 0..20 {
+            world.run_system_once(advance_herds);
+            let pack = biomass(&world, "game_wolves");
+            assert!(pack > last_pack, "turn {turn}: the pack grows on the herd");
+            last_pack = pack;
+        }
+        assert!(
+            biomass(&world, "game_deer") < biomass(&world, "game_armoured"),
+            "only the deer the pack can take were eaten"
+        );
+    }
 }
@@ -29,7 +29,8 @@ pub const BUILTIN_FAUNA_CONFIG: &str = include_str!("data/fauna_config.json");
 /// land (the graze layer); a `Carnivore` eats prey biomass. The **only** knob that changes the
 /// food/carrying-capacity layer — and it does so in **Phase 1**, not here. `#[serde(default)]` =
 /// `Herbivore`, persisted-enum convention (`as_str` / `from_key`), so every existing species is
-/// byte-identical and this field is **inert this phase**.
+/// byte-identical. A `Carnivore` herd's biomass is stepped by the predator–prey model
+/// ([`PredationConfig`]) instead of logistic regrowth.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Default)]
 #[serde(rename_all = "snake_case")]
 pub enum Diet {
@@ -373,8 +374,9 @@ pub struct SpeciesDef {
     #[serde(default)]
     pub combat: CombatStats,
     /// **What this species eats** — herbivore (grazes) vs carnivore (eats prey). `#[serde(default)]` =
-    /// `Herbivore`. **Inert this phase** — Phase 1 makes `ecological_carrying_capacity` sum prey flow
-    /// for carnivores.
+    /// `Herbivore`. A carnivore herd grows by eating the herbivore herds around it
+    /// ([`PredationConfig`]); its `K` still comes from `ecological_carrying_capacity`, so a predator
+    /// row should omit `fodder_per_biomass` until Phase 1 sums prey flow there.
     #[serde(default)]
     pub diet: Diet,
     /// **Does it initiate?** `0..1` — the probability it raids unguarded foragers *unprovoked* (`> 0`),
@@ -894,6 +896,52 @@ impl Default for MarketConfig {
     }
 }
 
+/// **Predator–prey dynamics between herds** (`docs/plan_predators.md` §Ecology) — a discrete
+/// Lotka–Volterra step `fauna::advance_herds` runs once every herd has moved and regrown. A
+/// [`Diet::Carnivore`] herd does not regrow logistically; its biomass instead follows the prey it
+/// meets:
+///
+/// ```text
+/// take = min(attack_rate · P · N, max_take_fraction · N)
+/// P'   = P + conversion_efficiency · take − mortality_rate · P        (clamped to [0, K])
+/// ```
+///
+/// `N` is the summed biomass of the wild herbivore herds within `range_tiles` whose
+/// `combat.defense` the predator's `combat.attack` clears (penned herds are fenced off), and the take
+/// is drawn from them in proportion to their biomass. A pack holds steady when the prey it meets is
+/// `mortality_rate / (attack_rate · conversion_efficiency)`; around that point the two populations
+/// cycle, and the prey's own logistic ceiling damps the cycle toward it. `max_take_fraction` is the
+/// bound that keeps one turn from stripping a herd however large the pack grows. With no carnivore
+/// in the species table the whole block is inert.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct PredationConfig {
+    /// The capture coefficient `a`: the fraction of the prey in range that one unit of predator
+    /// biomass kills per turn.
+    pub attack_rate: f32,
+    /// The conversion coefficient `b`: predator biomass gained per unit of prey biomass eaten.
+    pub conversion_efficiency: f32,
+    /// The starvation coefficient `m`: the fraction of its biomass a pack loses each turn before
+    /// eating.
+    pub mortality_rate: f32,
+    /// Ceiling on the fraction of the prey in range one pack may take in a turn.
+    pub max_take_fraction: f32,
+    /// How close (hexes, wrap-aware) a prey herd must stand to a pack to count as co-located.
+    pub range_tiles: u32,
+}
+
+impl Default for PredationConfig {
+    fn default() -> Self {
+        Self {
+            attack_rate: 0.0008,
+            conversion_efficiency: 0.25,
+            mortality_rate: 0.10,
+            max_take_fraction: 0.5,
+            range_tiles: 3,
+        }
+    }
+}
+
 /// **The graze (pasture) layer** — the land's *animal-edible* vegetal stock (grass, browse, forbs),
 /// distinct from the human-edible `ForagePatch.biomass` (seeds/nuts/tubers) on food-module tiles.
 /// Authoritative design: `docs/plan_grazing_foundation.md`. It lives on **any vegetated land tile**,
@@ -998,6 +1046,8 @@ pub struct FaunaConfig {
     pub market: MarketConfig,
     /// The per-biome graze (pasture) layer — see [`GrazeConfig`].
     pub graze: GrazeConfig,
+    /// Predator–prey coupling between carnivore and herbivore herds — see [`PredationConfig`].
+    pub predation: PredationConfig,
 }
 
 impl GrazeConfig {
@@ -1309,6 +1359,20 @@ impl FaunaConfig {
         validate_ecology("graze.ecology", &self.graze.ecology)?;
         validate_graze(&self.graze)?;
 
+        // --- Predation. A negative coefficient would run the Lotka–Volterra step backwards (prey
+        // regrown by being eaten, packs fed by starving), and a fraction above 1 would let a pack
+        // take more prey than stands in range or lose more biomass than it has.
+        require_non_negative_finite("predation.attack_rate", self.predation.attack_rate)?;
+        require_in_unit_range(
+            "predation.conversion_efficiency",
+            self.predation.conversion_efficiency,
+        )?;
+        require_in_unit_range("predation.mortality_rate", self.predation.mortality_rate)?;
+        require_in_unit_range(
+            "predation.max_take_fraction",
+            self.predation.max_take_fraction,
+        )?;
+
         Ok(())
     }
 
@@ -1342,6 +1406,20 @@ impl FaunaConfig {
             .find(|def| def.display_name == display)
     }
 
+    /// Resolve a designer-typed species name — the config key first, then the display name, both
+    /// case-insensitive — to its `(key, row)`. The lookup the `spawn_herd` debug command uses.
+    pub fn resolve_species(&self, name: &str) -> Option<(&str, &SpeciesDef)> {
+        self.species
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .or_else(|| {
+                self.species
+                    .iter()
+                    .find(|(_, def)| def.display_name.eq_ignore_ascii_case(name))
+            })
+            .map(|(key, def)| (key.as_str(), def))
+    }
+
     /// **The species' taming timescale** ([`SpeciesDef::taming_rate`]), resolved by the display name a
     /// `Herd` carries — the same live-resolution path the movement cadence levers take
     /// (`fauna::advance_herds` → [`FaunaConfig::species_by_display`]), so retuning the dial takes
@@ -2175,6 +2253,15 @@ mod tests {
         assert_rejects_field(err, "graze.capacity_by_biome");
     }
 
+    /// A pack cannot take more than the prey standing in range, nor lose more than it has.
+    #[test]
+    fn validate_rejects_predation_fractions_above_one() {
+        let err = reject(|json| json["predation"]["max_take_fraction"] = (1.5).into());
+        assert_rejects_field(err, "predation.max_take_fraction");
+        let err = reject(|json| json["predation"]["mortality_rate"] = (1.2).into());
+        assert_rejects_field(err, "predation.mortality_rate");
+    }
+
     /// A dead graze ecology (`r = 0`) means grass never regrows — every pasture is a one-shot stock
     /// and, from Phase 2b, every herd starves.
     #[test]
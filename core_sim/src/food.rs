@@ -1,10 +1,13 @@
 use std::str::FromStr;
 
-use bevy::prelude::Component;
+use bevy::prelude::{Component, Query, Res};
 use serde::{Deserialize, Serialize};
 use sim_runtime::{TerrainTags, TerrainType};
 
-use crate::components::Tile;
+use crate::{
+    components::Tile,
+    resources::{SimulationConfig, SimulationTick},
+};
 
 pub const DEFAULT_HARVEST_TRAVEL_TILES_PER_TURN: f32 = 3.0;
 pub const DEFAULT_HARVEST_WORK_TURNS: u32 = 2;
@@ -143,7 +146,10 @@ impl FoodSiteKind {
 #[derive(Component, Debug, Clone)]
 pub struct FoodModuleTag {
     pub module: FoodModule,
+    /// This turn's gather weight: `base_weight` scaled by the food season (`advance_food_seasons`).
     pub seasonal_weight: f32,
+    /// The weight worldgen assigned, before any seasonal swing.
+    pub base_weight: f32,
     pub kind: FoodSiteKind,
 }
 
@@ -152,11 +158,29 @@ impl FoodModuleTag {
         Self {
             module,
             seasonal_weight,
+            base_weight: seasonal_weight,
             kind,
         }
     }
 }
 
+/// Rescales every food site's `seasonal_weight` from its `base_weight` by
+/// `SimulationConfig::food_season` at this turn's phase of the year (`ticks_per_year`). With the
+/// shipped zero amplitude the weights stay at their worldgen values.
+pub fn advance_food_seasons(
+    config: Res<SimulationConfig>,
+    tick: Res<SimulationTick>,
+    mut modules: Query<&mut FoodModuleTag>,
+) {
+    let factor = config.food_season.factor(config.season_phase(tick.0));
+    for mut module in &mut modules {
+        let weight = module.base_weight * factor;
+        if module.seasonal_weight != weight {
+            module.seasonal_weight = weight;
+        }
+    }
+}
+
 pub fn classify_food_module(tile: &Tile) -> Option<FoodModule> {
     classify_food_module_from_traits(tile.terrain, tile.terrain_tags)
 }
@@ -205,3 +229,143 @@ pub fn classify_food_module_from_traits(
 
     Some(module)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::resources::FoodSeasonConfig;
+    use bevy::prelude::World;
+    use bevy_ecs::system::RunSystemOnce;
+    use sim_runtime::CalendarState;
+
+    fn seasonal_config(ticks_per_year: u32) -> SimulationConfig {
+        SimulationConfig {
+            ticks_per_year,
+            food_season: FoodSeasonConfig {
+                amplitude: 0.5,
+                peak_phase: 0.25,
+            },
+            ..SimulationConfig::default()
+        }
+    }
+
+    /// The site's weight on each tick of one year.
+    fn weights_over_a_year(ticks_per_year: u32) -> Vec<f32> {
+        let mut world = World::new();
+        world.insert_resource(seasonal_config(ticks_per_year));
+        let site = world
+            .spawn(FoodModuleTag::new(
+                FoodModule::SavannaGrassland,
+                2.0,
+                FoodSiteKind::SavannaTrack,
+            ))
+            .id();
+        (0..u64::from(ticks_per_year))
+            .map(|tick| {
+                world.insert_resource(SimulationTick(tick));
+                world.run_system_once(advance_food_seasons);
+                world.get::<FoodModuleTag>(site).unwrap().seasonal_weight
+            })
+            .collect()
+    }
+
+    #[test]
+    fn season_phase_completes_one_cycle_per_configured_year() {
+        for ticks_per_year in [8, 20] {
+            let config = seasonal_config(ticks_per_year);
+            let year = u64::from(ticks_per_year);
+            assert_eq!(config.season_phase(0), 0.0);
+            assert_eq!(config.season_phase(year), 0.0, "wraps after one year");
+            assert_eq!(config.season_phase(year / 2), 0.5);
+            let phases: Vec<f32> = (0..year).map(|tick| config.season_phase(tick)).collect();
+            assert!(phases.windows(2).all(|pair| pair[0] < pair[1]));
+            assert_eq!(config.season_phase(year + 3), config.season_phase(3));
+        }
+        assert_eq!(seasonal_config(0).season_phase(17), 0.0, "no calendar");
+    }
+
+    #[test]
+    fn food_weights_peak_at_the_same_phase_fraction_for_any_year_length() {
+        for ticks_per_year in [8, 20] {
+            let weights = weights_over_a_year(ticks_per_year);
+            let (peak_tick, peak) = weights
+                .iter()
+                .enumerate()
+                .max_by(|a, b| a.1.total_cmp(b.1))
+                .unwrap();
+            assert_eq!(
+                peak_tick as f32 / ticks_per_year as f32,
+                0.25,
+                "year of {ticks_per_year}"
+            );
+            assert!((peak - 3.0).abs() < 1e-5, "base 2.0 × (1 + 0.5)");
+            let trough = weights.iter().copied().fold(f32::INFINITY, f32::min);
+            assert!((trough - 1.0).abs() < 1e-5, "base 2.0 × (1 − 0.5)");
+        }
+    }
+
+    #[test]
+    fn calendar_rolls_over_at_the_year_boundary() {
+        let config = seasonal_config(8);
+        let last_day = config.calendar(15);
+        assert_eq!(
+            (last_day.year, last_day.season, last_day.day_in_year),
+            (1, 3, 7)
+        );
+        assert_eq!(last_day.phase, 0.875);
+
+        let new_year = config.calendar(16);
+        assert_eq!(
+            (new_year.year, new_year.season, new_year.day_in_year),
+            (2, 0, 0)
+        );
+        assert_eq!(new_year.phase, 0.0);
+        assert_eq!(new_year.ticks_per_year, 8);
+
+        let seasons: Vec<u8> = (16..24).map(|tick| config.calendar(tick).season).collect();
+        assert_eq!(seasons, [0, 0, 1, 1, 2, 2, 3, 3]);
+        assert_eq!(
+            seasonal_config(0).calendar(17),
+            CalendarState::default(),
+            "no calendar"
+        );
+    }
+
+    #[test]
+    fn calendar_phase_is_the_food_weight_phase() {
+        for ticks_per_year in [8, 20] {
+            let config = seasonal_config(ticks_per_year);
+            for tick in 0..u64::from(ticks_per_year) * 2 {
+                assert_eq!(config.calendar(tick).phase, config.season_phase(tick));
+            }
+            let weights = weights_over_a_year(ticks_per_year);
+            let peak_tick = (0..weights.len())
+                .max_by(|&a, &b| weights[a].total_cmp(&weights[b]))
+                .unwrap();
+            assert_eq!(
+                config.calendar(peak_tick as u64).phase,
+                config.food_season.peak_phase,
+                "year of {ticks_per_year}"
+            );
+        }
+    }
+
+    #[test]
+    fn flat_food_season_keeps_worldgen_weights() {
+        let mut world = World::new();
+        world.insert_resource(SimulationConfig::default());
+        world.insert_resource(SimulationTick(5));
+        let site = world
+            .spawn(FoodModuleTag::new(
+                FoodModule::RiverineDelta,
+                1.3,
+                FoodSiteKind::RiverGarden,
+            ))
+            .id();
+        world.run_system_once(advance_food_seasons);
+        assert_eq!(
+            world.get::<FoodModuleTag>(site).unwrap().seasonal_weight,
+            1.3
+        );
+    }
+}
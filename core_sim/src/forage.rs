@@ -1048,16 +1048,35 @@ pub fn spawn_initial_forage(
 
 /// Per-turn forage regrowth (`TurnStage::Logistics`, alongside `advance_herds`): regrow every patch
 /// toward its carrying capacity and refresh its ecology phase. Patches never despawn.
+///
+/// A patch on a river hex regrows at a rate scaled by that river's flow this turn
+/// (`HydrologyState::regrowth_factor_by_tile`, weighted by
+/// `RiverFlowConfig::forage_regrowth_weight`): a flush season speeds it, a drought slows it.
+/// `update_river_flow` runs first in the same stage.
 pub fn advance_forage_regrowth(
     mut registry: ResMut<ForageRegistry>,
     labor_config: Res<LaborConfigHandle>,
     flora_config: Res<crate::flora_config::FloraConfigHandle>,
     tile_registry: Res<crate::resources::TileRegistry>,
     tiles: Query<&Tile>,
+    config: Option<Res<crate::resources::SimulationConfig>>,
+    hydrology: Option<Res<crate::hydrology::HydrologyState>>,
 ) {
     let labor = labor_config.get();
     let forage = &labor.forage;
     let flora = flora_config.get();
+    let flow = config
+        .as_deref()
+        .zip(hydrology.as_deref())
+        .map(|(config, hydrology)| {
+            let factors = hydrology.regrowth_factor_by_tile(
+                config.grid_size.x,
+                config.grid_size.y,
+                config.map_topology.wrap_horizontal,
+                config.river_flow.forage_regrowth_weight,
+            );
+            (config.grid_size.x, factors)
+        });
     for patch in registry.patches.values_mut() {
         // **Concentration, recomputed fresh from the tile every turn** — the plant twin of
         // `fauna::ecological_carrying_capacity`'s one write. Idempotent (`tile_K × concentration`
@@ -1080,7 +1099,16 @@ pub fn advance_forage_regrowth(
                 );
             }
         }
-        regrow_patch(patch, forage);
+        let flow_factor = flow
+            .as_ref()
+            .filter(|(width, _)| patch.tile.x < *width)
+            .and_then(|(width, factors)| {
+                factors
+                    .get((patch.tile.y * width + patch.tile.x) as usize)
+                    .copied()
+            })
+            .unwrap_or(1.0);
+        regrow_patch_at(patch, forage, flow_factor);
     }
 }
 
@@ -1162,13 +1190,18 @@ pub fn advance_cultivation(
 /// harvest the land can actually sustain rather than a promise the stock cannot keep. The animal
 /// mirror is `fauna::regrow_biomass`, which resolves `herd_ecology` for exactly this reason.
 fn regrow_patch(patch: &mut ForagePatch, forage: &ForageLaborConfig) {
+    regrow_patch_at(patch, forage, 1.0);
+}
+
+/// [`regrow_patch`] with the patch's regrowth rate scaled by `rate_factor` (river flow).
+fn regrow_patch_at(patch: &mut ForagePatch, forage: &ForageLaborConfig, rate_factor: f32) {
     let ecology = patch_ecology(patch, forage);
     // The reseed lift + logistic step is the shared plant curve (`fauna::reseeding_logistic_regrowth`),
     // so the human-edible forage stock and the animal-edible graze stock can never drift apart.
     patch.biomass = reseeding_logistic_regrowth(
         patch.biomass,
         patch.carrying_capacity,
-        ecology.regrowth_rate,
+        ecology.regrowth_rate * rate_factor,
         forage.reseed_floor_fraction,
     );
     patch.refresh_ecology_phase(&ecology);
@@ -2443,4 +2476,65 @@ mod tests {
         assert_eq!(registry.cultivated_count(FactionId(1)), 1);
         assert_eq!(registry.cultivated_count(FactionId(2)), 0);
     }
+
+    #[test]
+    fn river_flow_scales_regrowth_on_the_hexes_it_touches() {
+        use crate::hydrology::{HydrologyState, RiverSegment};
+        use crate::resources::{SimulationConfig, TileRegistry};
+        use bevy_ecs::system::RunSystemOnce;
+
+        let (river_hex, dry_hex) = (UVec2::new(0, 0), UVec2::new(3, 3));
+        let regrown = |flow_volume: f32| {
+            let mut world = World::new();
+            world.insert_resource(LaborConfigHandle::default());
+            world.insert_resource(crate::flora_config::FloraConfigHandle::default());
+            world.insert_resource(TileRegistry::default());
+            let mut config = SimulationConfig {
+                grid_size: UVec2::new(4, 4),
+                ..SimulationConfig::default()
+            };
+            config.river_flow.forage_regrowth_weight = 0.5;
+            world.insert_resource(config);
+            world.insert_resource(HydrologyState {
+                rivers: vec![RiverSegment {
+                    id: 0,
+                    order: 1,
+                    edges: Vec::new(),
+                    navigable_hexes: vec![river_hex],
+                    navigable_inflow: None,
+                    base_discharge: 10.0,
+                    flow_volume,
+                }],
+                ..Default::default()
+            });
+            let mut registry = ForageRegistry::default();
+            for tile in [river_hex, dry_hex] {
+                let mut patch = ForagePatch::new(tile, 100.0);
+                patch.biomass = 40.0;
+                registry.patches.insert(tile, patch);
+            }
+            world.insert_resource(registry);
+            world.run_system_once(advance_forage_regrowth);
+            let registry = world.resource::<ForageRegistry>();
+            let biomass = |tile| registry.patch(tile).expect("patch").biomass;
+            (biomass(river_hex), biomass(dry_hex))
+        };
+
+        let (steady_river, steady_dry) = regrown(10.0);
+        let (flush_river, flush_dry) = regrown(20.0);
+        let (drought_river, drought_dry) = regrown(2.0);
+        // At its generated discharge the river hex regrows exactly like dry ground.
+        assert!((steady_river - steady_dry).abs() < 1e-4);
+        assert!(
+            flush_river > steady_river,
+            "{flush_river} vs {steady_river}"
+        );
+        assert!(
+            drought_river < steady_river,
+            "{drought_river} vs {steady_river}"
+        );
+        // Off-river patches never feel the river.
+        assert_eq!(flush_dry, steady_dry);
+        assert_eq!(drought_dry, steady_dry);
+    }
 }
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use bevy::prelude::Resource;
 use rand::{rngs::SmallRng, Rng, SeedableRng};
 
@@ -24,6 +26,11 @@ impl GenerationBias {
         }
     }
 
+    /// The bias in knowledge/trust/equity/agency order.
+    pub fn axes(self) -> [Scalar; 4] {
+        [self.knowledge, self.trust, self.equity, self.agency]
+    }
+
     pub fn to_scaled(self) -> [i64; 4] {
         [
             self.knowledge.raw(),
@@ -41,6 +48,17 @@ pub struct GenerationProfile {
     pub bias: GenerationBias,
 }
 
+/// One generation's pull on the sentiment axes: its bias weighted by its share of the population
+/// (see [`GenerationRegistry::sentiment_contributions`]).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GenerationSentimentContribution {
+    pub generation: GenerationId,
+    /// Fraction of the population belonging to this generation, `[0, 1]`.
+    pub share: Scalar,
+    /// `share × bias` per axis, in knowledge/trust/equity/agency order.
+    pub axes: [Scalar; 4],
+}
+
 #[derive(Resource, Clone)]
 pub struct GenerationRegistry {
     profiles: Vec<GenerationProfile>,
@@ -113,6 +131,29 @@ impl GenerationRegistry {
         }
     }
 
+    /// Each populated generation's bias weighted by its population share, in registry order.
+    /// Generations with no share are skipped; the contributions sum to the population's lean.
+    pub fn sentiment_contributions(
+        &self,
+        shares: &HashMap<GenerationId, f32>,
+    ) -> Vec<GenerationSentimentContribution> {
+        self.profiles
+            .iter()
+            .filter_map(|profile| {
+                let share = shares
+                    .get(&profile.id)
+                    .copied()
+                    .filter(|share| *share > 0.0)?;
+                let share = scalar_from_f32(share);
+                Some(GenerationSentimentContribution {
+                    generation: profile.id,
+                    share,
+                    axes: profile.bias.axes().map(|bias| bias * share),
+                })
+            })
+            .collect()
+    }
+
     pub fn update_from_states(&mut self, states: &[GenerationState]) {
         if states.is_empty() {
             return;
@@ -1,4 +1,4 @@
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
 use std::hash::{Hash, Hasher};
 
 use bevy::prelude::*;
@@ -7,8 +7,11 @@ use crate::{
     hashing::FnvHasher,
     metrics::SimulationMetrics,
     orders::FactionId,
-    power::PowerDiscoveryEffects,
-    resources::{DiplomacyLeverage, DiscoveryProgressLedger, PendingCrisisSeeds, SimulationTick},
+    power::{PowerDiscoveryEffects, PowerTopology},
+    resources::{
+        DiplomacyLeverage, DiscoveryProgressLedger, DiscoveryThresholdScaling, PendingCrisisSeeds,
+        SimulationTick,
+    },
     scalar::{scalar_one, scalar_zero, Scalar},
     CapabilityFlags,
 };
@@ -17,8 +20,10 @@ use rand::{distributions::uniform::SampleUniform, rngs::SmallRng, Rng, SeedableR
 
 use serde::Deserialize;
 use sim_runtime::{
-    GreatDiscoveryDefinitionState, GreatDiscoveryProgressState, GreatDiscoveryRequirementState,
-    GreatDiscoveryState, GreatDiscoveryTelemetryState, KnowledgeField,
+    GreatDiscoveryDefinitionState, GreatDiscoveryEffectAuditState,
+    GreatDiscoveryProgressDeltaState, GreatDiscoveryProgressState,
+    GreatDiscoveryRequirementEdgeState, GreatDiscoveryRequirementState, GreatDiscoveryState,
+    GreatDiscoveryTelemetryState, KnowledgeField,
 };
 use thiserror::Error;
 
@@ -29,6 +34,80 @@ pub mod effect_flags {
     pub const FORCED_PUBLICATION: u32 = 1 << 3;
 }
 
+/// How a resolved Great Discovery stops being a secret. Parsed from the catalog's
+/// `leak_profile` keyword; the label ([`LeakProfile::label`]) is what ships to clients.
+///
+/// - `Stable`: each turn after resolution there is a small chance the discovery is observed
+///   and goes public.
+/// - `Volatile`: as `Stable`, with a much higher per-turn chance.
+/// - `ForcedPublication`: public the turn it resolves (same as the `forced_publication`
+///   effect flag).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum LeakProfile {
+    #[default]
+    Stable,
+    Volatile,
+    ForcedPublication,
+}
+
+impl LeakProfile {
+    pub const STABLE_PUBLICATION_CHANCE: f32 = 0.05;
+    pub const VOLATILE_PUBLICATION_CHANCE: f32 = 0.35;
+
+    /// Parses a catalog keyword (case-insensitive; `-`/space accepted for `_`).
+    pub fn parse(value: &str) -> Option<Self> {
+        let normalized = value.trim().to_ascii_lowercase().replace(['-', ' '], "_");
+        match normalized.as_str() {
+            "stable" => Some(Self::Stable),
+            "volatile" => Some(Self::Volatile),
+            "forced_publication" | "forcedpublication" => Some(Self::ForcedPublication),
+            _ => None,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Stable => "Stable",
+            Self::Volatile => "Volatile",
+            Self::ForcedPublication => "Forced Publication",
+        }
+    }
+
+    /// Probability that a still-secret discovery goes public on any one turn after it resolved.
+    pub fn publication_chance(self) -> f32 {
+        match self {
+            Self::Stable => Self::STABLE_PUBLICATION_CHANCE,
+            Self::Volatile => Self::VOLATILE_PUBLICATION_CHANCE,
+            Self::ForcedPublication => 1.0,
+        }
+    }
+
+    /// One turn's publication roll.
+    pub fn rolls_public(self, rng: &mut impl Rng) -> bool {
+        match self {
+            Self::ForcedPublication => true,
+            other => rng.gen::<f32>() < other.publication_chance(),
+        }
+    }
+}
+
+/// Resolves an optional catalog/snapshot `leak_profile` string, warning and falling back to
+/// [`LeakProfile::Stable`] when it is not a known keyword.
+fn resolve_leak_profile(id: u16, value: Option<&str>) -> LeakProfile {
+    let Some(value) = value else {
+        return LeakProfile::default();
+    };
+    LeakProfile::parse(value).unwrap_or_else(|| {
+        warn!(
+            target: "shadow_scale::great_discovery",
+            id,
+            leak_profile = value,
+            "great_discovery.leak_profile.unknown; treating as Stable"
+        );
+        LeakProfile::default()
+    })
+}
+
 pub const BUILTIN_GREAT_DISCOVERY_CATALOG: &str =
     include_str!("data/great_discovery_definitions.json");
 
@@ -87,6 +166,20 @@ pub enum GreatDiscoveryCatalogError {
     DuplicateDefinition { id: u16 },
     #[error("unknown Great Discovery effect flag '{flag}' in definition {id}")]
     UnknownEffectFlag { id: u16, flag: String },
+    #[error("Great Discovery definition {id} lists unknown prerequisite {prerequisite}")]
+    UnknownPrerequisite { id: u16, prerequisite: u16 },
+    #[error("Great Discovery prerequisites form a cycle through definition {id}")]
+    PrerequisiteCycle { id: u16 },
+    #[error(
+        "Great Discovery definition {id}: requirement {requirement} builds on {builds_on}, which is not one of its requirements"
+    )]
+    UnknownRequirementDependency {
+        id: u16,
+        requirement: u32,
+        builds_on: u32,
+    },
+    #[error("Great Discovery definition {id}: requirements form a cycle through {requirement}")]
+    RequirementCycle { id: u16, requirement: u32 },
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -97,6 +190,8 @@ struct GreatDiscoveryCatalogEntry {
     #[serde(default)]
     requirements: Vec<GreatDiscoveryCatalogRequirement>,
     #[serde(default)]
+    prerequisite_discoveries: Vec<u16>,
+    #[serde(default)]
     observation_threshold: NumericBand<u32>,
     #[serde(default)]
     cooldown_ticks: NumericBand<u16>,
@@ -135,6 +230,10 @@ struct GreatDiscoveryCatalogRequirement {
     name: Option<String>,
     #[serde(default)]
     summary: Option<String>,
+    /// Sibling requirements (by `discovery_id`) this one builds on; the constellation graph's
+    /// edges. Display-only — progress still counts each requirement independently.
+    #[serde(default)]
+    builds_on: Vec<u32>,
 }
 
 #[inline]
@@ -187,6 +286,10 @@ pub struct GreatDiscoveryDefinition {
     pub freshness_window: Option<u16>,
     pub effect_flags: u32,
     pub covert_until_public: bool,
+    /// Great discoveries the faction must already have resolved before this one
+    /// can surface as a candidate.
+    pub prerequisite_discoveries: Vec<GreatDiscoveryId>,
+    pub leak_profile: LeakProfile,
     weight_total: Scalar,
 }
 
@@ -220,13 +323,37 @@ impl GreatDiscoveryDefinition {
             freshness_window,
             effect_flags,
             covert_until_public,
+            prerequisite_discoveries: Vec::new(),
+            leak_profile: LeakProfile::default(),
             weight_total,
         }
     }
 
+    pub fn with_prerequisites(mut self, prerequisites: Vec<GreatDiscoveryId>) -> Self {
+        self.prerequisite_discoveries = prerequisites;
+        self
+    }
+
+    pub fn with_leak_profile(mut self, leak_profile: LeakProfile) -> Self {
+        self.leak_profile = leak_profile;
+        self
+    }
+
+    /// Whether the discovery goes public the turn it resolves.
+    pub fn publishes_on_resolution(&self) -> bool {
+        self.effect_flags & effect_flags::FORCED_PUBLICATION != 0
+            || self.leak_profile == LeakProfile::ForcedPublication
+    }
+
     pub fn weight_total(&self) -> Scalar {
         self.weight_total
     }
+
+    pub fn prerequisites_met(&self, faction: FactionId, ledger: &GreatDiscoveryLedger) -> bool {
+        self.prerequisite_discoveries
+            .iter()
+            .all(|prerequisite| ledger.contains(faction, *prerequisite))
+    }
 }
 
 fn collect_effect_flags(
@@ -259,6 +386,7 @@ fn resolve_catalog_entry(
     entry: &GreatDiscoveryCatalogEntry,
 ) -> Result<(GreatDiscoveryDefinition, GreatDiscoveryDefinitionMetadata), GreatDiscoveryCatalogError>
 {
+    validate_requirement_graph(entry.id, &entry.requirements)?;
     let effect_flags = collect_effect_flags(entry.id, &entry.effect_flags, entry.effect_flag_bits)?;
     let id = GreatDiscoveryId(entry.id);
     let seed = hash_identifier(&entry.id) ^ entry.seed_offset.unwrap_or(0);
@@ -295,9 +423,19 @@ fn resolve_catalog_entry(
             summary: requirement.summary.clone(),
             weight,
             minimum_progress: minimum,
+            builds_on: requirement.builds_on.clone(),
         });
     }
 
+    let prerequisites: Vec<GreatDiscoveryId> = entry
+        .prerequisite_discoveries
+        .iter()
+        .copied()
+        .map(GreatDiscoveryId)
+        .collect();
+
+    let leak_profile = resolve_leak_profile(entry.id, entry.leak_profile.as_deref());
+
     let definition = GreatDiscoveryDefinition::new(
         id,
         entry.name.clone(),
@@ -308,7 +446,9 @@ fn resolve_catalog_entry(
         freshness_window,
         effect_flags,
         entry.covert_until_public,
-    );
+    )
+    .with_prerequisites(prerequisites.clone())
+    .with_leak_profile(leak_profile);
 
     let metadata = GreatDiscoveryDefinitionMetadata {
         id,
@@ -324,13 +464,150 @@ fn resolve_catalog_entry(
         covert_until_public: entry.covert_until_public,
         effects_summary: entry.effects_summary.clone(),
         observation_notes: entry.observation_notes.clone(),
-        leak_profile: entry.leak_profile.clone(),
+        leak_profile: entry
+            .leak_profile
+            .as_ref()
+            .map(|_| leak_profile.label().to_string()),
         requirements: requirement_meta,
+        prerequisites,
     };
 
     Ok((definition, metadata))
 }
 
+fn validate_prerequisite_graph(
+    graph: &HashMap<GreatDiscoveryId, &[GreatDiscoveryId]>,
+) -> Result<(), GreatDiscoveryCatalogError> {
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    enum Visit {
+        InProgress,
+        Done,
+    }
+
+    let mut ids: Vec<GreatDiscoveryId> = graph.keys().copied().collect();
+    ids.sort();
+
+    for id in &ids {
+        for prerequisite in graph[id].iter() {
+            if !graph.contains_key(prerequisite) {
+                return Err(GreatDiscoveryCatalogError::UnknownPrerequisite {
+                    id: id.0,
+                    prerequisite: prerequisite.0,
+                });
+            }
+        }
+    }
+
+    let mut visits: HashMap<GreatDiscoveryId, Visit> = HashMap::new();
+    for root in ids {
+        if visits.contains_key(&root) {
+            continue;
+        }
+        // Iterative DFS; each stack frame tracks the next prerequisite index to explore.
+        let mut stack: Vec<(GreatDiscoveryId, usize)> = vec![(root, 0)];
+        visits.insert(root, Visit::InProgress);
+        while let Some(frame) = stack.last_mut() {
+            let id = frame.0;
+            if let Some(prerequisite) = graph[&id].get(frame.1).copied() {
+                frame.1 += 1;
+                match visits.get(&prerequisite) {
+                    Some(Visit::InProgress) => {
+                        return Err(GreatDiscoveryCatalogError::PrerequisiteCycle {
+                            id: prerequisite.0,
+                        });
+                    }
+                    Some(Visit::Done) => {}
+                    None => {
+                        visits.insert(prerequisite, Visit::InProgress);
+                        stack.push((prerequisite, 0));
+                    }
+                }
+            } else {
+                visits.insert(id, Visit::Done);
+                stack.pop();
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Checks a definition's `builds_on` edges: each must name a sibling requirement, and the
+/// requirements must form a DAG (peeled off in dependency order; anything left is a cycle).
+fn validate_requirement_graph(
+    id: u16,
+    requirements: &[GreatDiscoveryCatalogRequirement],
+) -> Result<(), GreatDiscoveryCatalogError> {
+    let ids: HashSet<u32> = requirements.iter().map(|req| req.discovery_id).collect();
+    for requirement in requirements {
+        if let Some(&missing) = requirement
+            .builds_on
+            .iter()
+            .find(|dependency| !ids.contains(dependency))
+        {
+            return Err(GreatDiscoveryCatalogError::UnknownRequirementDependency {
+                id,
+                requirement: requirement.discovery_id,
+                builds_on: missing,
+            });
+        }
+    }
+
+    let mut placed: HashSet<u32> = HashSet::with_capacity(ids.len());
+    let mut remaining: Vec<&GreatDiscoveryCatalogRequirement> = requirements.iter().collect();
+    while !remaining.is_empty() {
+        let before = remaining.len();
+        remaining.retain(|req| {
+            if req.builds_on.iter().all(|dep| placed.contains(dep)) {
+                placed.insert(req.discovery_id);
+                false
+            } else {
+                true
+            }
+        });
+        if remaining.len() == before {
+            let requirement = remaining
+                .iter()
+                .map(|req| req.discovery_id)
+                .min()
+                .unwrap_or_default();
+            return Err(GreatDiscoveryCatalogError::RequirementCycle { id, requirement });
+        }
+    }
+    Ok(())
+}
+
+/// Constellation graph edges for a definition, derived from its requirements' `builds_on`.
+/// Each edge carries the prerequisite's share of the total requirement weight.
+fn requirement_edges(
+    requirements: &[GreatDiscoveryRequirementMetadata],
+) -> Vec<GreatDiscoveryRequirementEdgeState> {
+    let total: f32 = requirements.iter().map(|req| req.weight.max(0.0)).sum();
+    let weights: HashMap<u32, f32> = requirements
+        .iter()
+        .map(|req| (req.discovery_id, req.weight.max(0.0)))
+        .collect();
+    let mut edges: Vec<GreatDiscoveryRequirementEdgeState> = requirements
+        .iter()
+        .flat_map(|req| {
+            req.builds_on
+                .iter()
+                .map(move |&from| (from, req.discovery_id))
+        })
+        .map(|(from, to)| GreatDiscoveryRequirementEdgeState {
+            from,
+            to,
+            weight: if total > 0.0 {
+                weights.get(&from).copied().unwrap_or(0.0) / total
+            } else {
+                0.0
+            },
+        })
+        .collect();
+    edges.sort_unstable_by_key(|edge| (edge.to, edge.from));
+    edges.dedup_by_key(|edge| (edge.to, edge.from));
+    edges
+}
+
 fn hash_identifier<T: Hash>(identifier: &T) -> u64 {
     let mut hasher = FnvHasher::new();
     identifier.hash(&mut hasher);
@@ -344,6 +621,8 @@ pub struct GreatDiscoveryRequirementMetadata {
     pub summary: Option<String>,
     pub weight: f32,
     pub minimum_progress: f32,
+    /// Sibling requirement ids this one builds on (constellation graph edges).
+    pub builds_on: Vec<u32>,
 }
 
 #[derive(Debug, Clone)]
@@ -363,6 +642,7 @@ pub struct GreatDiscoveryDefinitionMetadata {
     pub observation_notes: Option<String>,
     pub leak_profile: Option<String>,
     pub requirements: Vec<GreatDiscoveryRequirementMetadata>,
+    pub prerequisites: Vec<GreatDiscoveryId>,
 }
 
 #[derive(Resource, Debug, Clone, Default)]
@@ -375,18 +655,59 @@ impl GreatDiscoveryRegistry {
     pub fn load_catalog_from_str(
         &mut self,
         catalog: &str,
+    ) -> Result<usize, GreatDiscoveryCatalogError> {
+        self.load_catalog_for_map(catalog, &DiscoveryThresholdScaling::default(), 0)
+    }
+
+    /// [`Self::load_catalog_from_str`] with each sampled observation threshold passed through
+    /// `scaling` for a map of `tiles` tiles (see [`DiscoveryThresholdScaling::scale`]). The scaled
+    /// value is what both the definition and its metadata carry.
+    pub fn load_catalog_for_map(
+        &mut self,
+        catalog: &str,
+        scaling: &DiscoveryThresholdScaling,
+        tiles: u32,
     ) -> Result<usize, GreatDiscoveryCatalogError> {
         let entries: Vec<GreatDiscoveryCatalogEntry> = serde_json::from_str(catalog)?;
-        let mut added = 0;
-        for entry in entries {
+        let mut resolved: Vec<(GreatDiscoveryDefinition, GreatDiscoveryDefinitionMetadata)> =
+            Vec::with_capacity(entries.len());
+        let mut seen: HashSet<GreatDiscoveryId> = HashSet::new();
+        for entry in &entries {
             let id = GreatDiscoveryId(entry.id);
-            if self.definitions.contains_key(&id) {
+            if self.definitions.contains_key(&id) || !seen.insert(id) {
                 return Err(GreatDiscoveryCatalogError::DuplicateDefinition { id: entry.id });
             }
-            let (definition, metadata) = resolve_catalog_entry(&entry)?;
+            let (mut definition, mut metadata) = resolve_catalog_entry(entry)?;
+            let threshold = scaling.scale(definition.observation_threshold, tiles);
+            definition.observation_threshold = threshold;
+            metadata.observation_threshold = threshold;
+            resolved.push((definition, metadata));
+        }
+
+        // Prerequisites may point at definitions from this catalog or ones registered earlier;
+        // validate against the combined set before mutating the registry.
+        let mut graph: HashMap<GreatDiscoveryId, &[GreatDiscoveryId]> = self
+            .definitions
+            .values()
+            .map(|definition| {
+                (
+                    definition.id,
+                    definition.prerequisite_discoveries.as_slice(),
+                )
+            })
+            .collect();
+        for (definition, _) in &resolved {
+            graph.insert(
+                definition.id,
+                definition.prerequisite_discoveries.as_slice(),
+            );
+        }
+        validate_prerequisite_graph(&graph)?;
+
+        let added = resolved.len();
+        for (definition, metadata) in resolved {
+            self.metadata.insert(definition.id, metadata);
             self.register(definition);
-            self.metadata.insert(id, metadata);
-            added += 1;
         }
         Ok(added)
     }
@@ -418,6 +739,10 @@ impl GreatDiscoveryRegistry {
         for state in states {
             let mut requirement_defs = Vec::with_capacity(state.requirements.len());
             let mut requirement_meta = Vec::with_capacity(state.requirements.len());
+            let mut builds_on: BTreeMap<u32, Vec<u32>> = BTreeMap::new();
+            for edge in &state.edges {
+                builds_on.entry(edge.to).or_default().push(edge.from);
+            }
 
             for req in &state.requirements {
                 let weight = if req.weight <= 0.0 { 1.0 } else { req.weight };
@@ -433,6 +758,7 @@ impl GreatDiscoveryRegistry {
                     summary: req.summary.clone(),
                     weight,
                     minimum_progress: minimum,
+                    builds_on: builds_on.remove(&req.discovery).unwrap_or_default(),
                 });
             }
 
@@ -447,7 +773,19 @@ impl GreatDiscoveryRegistry {
                 state.freshness_window,
                 state.effect_flags,
                 state.covert_until_public,
-            );
+            )
+            .with_prerequisites(
+                state
+                    .prerequisites
+                    .iter()
+                    .copied()
+                    .map(GreatDiscoveryId)
+                    .collect(),
+            )
+            .with_leak_profile(resolve_leak_profile(
+                state.id,
+                state.leak_profile.as_deref(),
+            ));
 
             let metadata = GreatDiscoveryDefinitionMetadata {
                 id,
@@ -465,6 +803,7 @@ impl GreatDiscoveryRegistry {
                 observation_notes: state.observation_notes.clone(),
                 leak_profile: state.leak_profile.clone(),
                 requirements: requirement_meta,
+                prerequisites: definition.prerequisite_discoveries.clone(),
             };
 
             self.definitions.insert(id, definition);
@@ -481,6 +820,11 @@ pub(crate) struct ConstellationProgress {
     pub cooldown_remaining: u16,
     pub resolved: bool,
     pub covert: bool,
+    /// Progress and observation deficit at the last metrics export; the baseline for the next
+    /// [`ConstellationProgress::progress_delta`].
+    exported: Option<(Scalar, u32)>,
+    /// Change between the last two exports; `None` until the entry has been exported twice.
+    progress_delta: Option<(Scalar, i32)>,
 }
 
 impl ConstellationProgress {
@@ -492,9 +836,21 @@ impl ConstellationProgress {
             cooldown_remaining: 0,
             resolved: false,
             covert,
+            exported: None,
+            progress_delta: None,
         }
     }
 
+    /// Diff against the previous export and make the current values the next baseline.
+    fn record_export(&mut self) {
+        let current = (self.progress, self.observation_deficit);
+        // The wrapping difference reinterpreted as `i32` is the signed change.
+        self.progress_delta = self.exported.map(|(progress, deficit)| {
+            (current.0 - progress, current.1.wrapping_sub(deficit) as i32)
+        });
+        self.exported = Some(current);
+    }
+
     fn eta_ticks(&self) -> u32 {
         if self.resolved || self.progress >= scalar_one() {
             return 0;
@@ -560,6 +916,10 @@ impl GreatDiscoveryReadiness {
             progress.progress = Scalar::from_raw(state.progress);
             progress.observation_deficit = state.observation_deficit;
             progress.covert = state.covert;
+            progress.exported = Some((progress.progress, progress.observation_deficit));
+            progress.progress_delta = state
+                .progress_delta
+                .map(|delta| (Scalar::from_raw(delta.progress), delta.observation_deficit));
             entry.insert(id, progress);
         }
 
@@ -702,16 +1062,73 @@ pub struct GreatDiscoveryFlag {
     pub faction: FactionId,
 }
 
+/// Applied effects kept in [`GreatDiscoveryTelemetry::effect_audit`]; older entries roll off.
+pub const GREAT_DISCOVERY_EFFECT_AUDIT_LIMIT: usize = 32;
+
 #[derive(Resource, Debug, Clone, Default)]
 pub struct GreatDiscoveryTelemetry {
     pub pending_candidates: u32,
     pub active_constellations: u32,
+    pub effect_audit: VecDeque<GreatDiscoveryEffectAudit>,
 }
 
 impl GreatDiscoveryTelemetry {
     pub fn set_from_state(&mut self, state: &GreatDiscoveryTelemetryState) {
         self.pending_candidates = state.pending_candidates;
         self.active_constellations = state.active_constellations;
+        self.effect_audit = state
+            .effect_audit
+            .iter()
+            .filter_map(GreatDiscoveryEffectAudit::from_state)
+            .collect();
+    }
+
+    pub fn record_effect(&mut self, audit: GreatDiscoveryEffectAudit) {
+        self.effect_audit.push_back(audit);
+        while self.effect_audit.len() > GREAT_DISCOVERY_EFFECT_AUDIT_LIMIT {
+            self.effect_audit.pop_front();
+        }
+    }
+}
+
+/// What one resolved discovery's effect concretely changed, for balance review.
+///
+/// `target_id` is the entry the effect wrote within [`GreatDiscoveryEffectKind::target`]: the
+/// discovery id keyed into the power unlock set, the id of the crisis the seed spawned, or the
+/// diplomacy roster index. `magnitude` is the size of that change: power nodes the grid-wide
+/// unlock reaches, hotspots the spawned crisis seeded, or roster entries the faction now holds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GreatDiscoveryEffectAudit {
+    pub tick: u64,
+    pub discovery: GreatDiscoveryId,
+    pub faction: FactionId,
+    pub kind: GreatDiscoveryEffectKind,
+    pub target_id: u64,
+    pub magnitude: Scalar,
+}
+
+impl GreatDiscoveryEffectAudit {
+    pub fn to_state(&self) -> GreatDiscoveryEffectAuditState {
+        GreatDiscoveryEffectAuditState {
+            tick: self.tick,
+            discovery: self.discovery.0,
+            faction: self.faction.0,
+            effect: self.kind.flag(),
+            target: self.kind.target().to_string(),
+            magnitude: self.magnitude.raw(),
+            target_id: self.target_id,
+        }
+    }
+
+    fn from_state(state: &GreatDiscoveryEffectAuditState) -> Option<Self> {
+        Some(Self {
+            tick: state.tick,
+            discovery: GreatDiscoveryId(state.discovery),
+            faction: FactionId(state.faction),
+            kind: GreatDiscoveryEffectKind::from_flag(state.effect)?,
+            target_id: state.target_id,
+            magnitude: Scalar::from_raw(state.magnitude),
+        })
     }
 }
 
@@ -733,6 +1150,36 @@ pub enum GreatDiscoveryEffectKind {
     Diplomacy,
 }
 
+impl GreatDiscoveryEffectKind {
+    /// The `effect_flags` bit that enables this effect.
+    pub fn flag(self) -> u32 {
+        match self {
+            GreatDiscoveryEffectKind::Power => effect_flags::POWER,
+            GreatDiscoveryEffectKind::Crisis => effect_flags::CRISIS,
+            GreatDiscoveryEffectKind::Diplomacy => effect_flags::DIPLOMACY,
+        }
+    }
+
+    pub fn from_flag(flag: u32) -> Option<Self> {
+        match flag {
+            effect_flags::POWER => Some(GreatDiscoveryEffectKind::Power),
+            effect_flags::CRISIS => Some(GreatDiscoveryEffectKind::Crisis),
+            effect_flags::DIPLOMACY => Some(GreatDiscoveryEffectKind::Diplomacy),
+            _ => None,
+        }
+    }
+
+    /// What the effect writes to when applied. Power is a faction-wide grid unlock, so the
+    /// target is the unlock set rather than an individual node.
+    pub fn target(self) -> &'static str {
+        match self {
+            GreatDiscoveryEffectKind::Power => "power.discovery_unlocks",
+            GreatDiscoveryEffectKind::Crisis => "crisis.pending_seeds",
+            GreatDiscoveryEffectKind::Diplomacy => "diplomacy.great_discoveries",
+        }
+    }
+}
+
 #[derive(Event, Debug, Clone)]
 pub struct GreatDiscoveryEffectEvent {
     pub kind: GreatDiscoveryEffectKind,
@@ -838,7 +1285,7 @@ pub fn screen_great_discovery_candidates(
 
         for id in discoveries {
             let progress = &entries[&id];
-            let Some(_definition) = registry.definition(&id) else {
+            let Some(definition) = registry.definition(&id) else {
                 continue;
             };
             if progress.resolved
@@ -846,6 +1293,7 @@ pub fn screen_great_discovery_candidates(
                 || progress.observation_deficit > 0
                 || progress.progress < scalar_one()
                 || ledger.contains(faction, id)
+                || !definition.prerequisites_met(faction, &ledger)
             {
                 continue;
             }
@@ -871,6 +1319,8 @@ pub fn resolve_great_discovery(
     mut power_effects: ResMut<PowerDiscoveryEffects>,
     mut crisis_seeds: ResMut<PendingCrisisSeeds>,
     mut diplomacy: ResMut<DiplomacyLeverage>,
+    mut telemetry: ResMut<GreatDiscoveryTelemetry>,
+    topology: Option<Res<PowerTopology>>,
     tick: Res<SimulationTick>,
 ) {
     for event in events.read() {
@@ -899,25 +1349,50 @@ pub fn resolve_great_discovery(
             }
         }
 
+        let mut applied = Vec::new();
+        let audit = |kind, target_id, magnitude| GreatDiscoveryEffectAudit {
+            tick: tick.0,
+            discovery: record.id,
+            faction: record.faction,
+            kind,
+            target_id,
+            magnitude,
+        };
         if definition.effect_flags & effect_flags::POWER != 0 && power_effects.register(record.id) {
-            effect_writer.send(GreatDiscoveryEffectEvent {
-                kind: GreatDiscoveryEffectKind::Power,
-                record: record.clone(),
-            });
+            // The unlock is grid-wide, so it reaches every node in the topology.
+            let nodes = topology.as_deref().map_or(0, PowerTopology::node_count);
+            telemetry.record_effect(audit(
+                GreatDiscoveryEffectKind::Power,
+                u64::from(record.id.0),
+                Scalar::from_u32(nodes as u32),
+            ));
+            applied.push(GreatDiscoveryEffectKind::Power);
         }
 
+        // Audited by `advance_crisis_system` once the seed spawns, when the crisis id is known.
         if definition.effect_flags & effect_flags::CRISIS != 0 {
             crisis_seeds.push(record.faction, record.id.0);
-            effect_writer.send(GreatDiscoveryEffectEvent {
-                kind: GreatDiscoveryEffectKind::Crisis,
-                record: record.clone(),
-            });
+            applied.push(GreatDiscoveryEffectKind::Crisis);
         }
 
         if definition.effect_flags & effect_flags::DIPLOMACY != 0 {
             diplomacy.push_great_discovery(record.faction, record.id.0);
+            let held = diplomacy
+                .great_discoveries
+                .iter()
+                .filter(|(faction, _)| *faction == record.faction)
+                .count();
+            telemetry.record_effect(audit(
+                GreatDiscoveryEffectKind::Diplomacy,
+                diplomacy.great_discoveries.len().saturating_sub(1) as u64,
+                Scalar::from_u32(held as u32),
+            ));
+            applied.push(GreatDiscoveryEffectKind::Diplomacy);
+        }
+
+        for kind in applied {
             effect_writer.send(GreatDiscoveryEffectEvent {
-                kind: GreatDiscoveryEffectKind::Diplomacy,
+                kind,
                 record: record.clone(),
             });
         }
@@ -931,10 +1406,27 @@ pub fn propagate_diffusion_impacts(
     mut resolved_events: EventReader<GreatDiscoveryResolvedEvent>,
     mut discovery_progress: ResMut<DiscoveryProgressLedger>,
     mut ledger: ResMut<GreatDiscoveryLedger>,
+    tick: Res<SimulationTick>,
 ) {
+    // Discoveries resolved on earlier turns that are still secret roll their leak profile once
+    // per turn; this turn's resolutions are handled below and start rolling next turn.
+    for record in ledger.records.iter_mut() {
+        if record.publicly_deployed || record.tick >= tick.0 {
+            continue;
+        }
+        let Some(definition) = registry.definition(&record.id) else {
+            continue;
+        };
+        let mut rng =
+            SmallRng::seed_from_u64(hash_identifier(&(tick.0, record.faction.0, record.id.0)));
+        if definition.leak_profile.rolls_public(&mut rng) {
+            record.publicly_deployed = true;
+        }
+    }
+
     for event in resolved_events.read() {
         if let Some(definition) = registry.definition(&event.record.id) {
-            if definition.effect_flags & effect_flags::FORCED_PUBLICATION != 0 {
+            if definition.publishes_on_resolution() {
                 ledger.mark_public(event.record.faction, event.record.id);
             }
 
@@ -949,11 +1441,19 @@ pub fn propagate_diffusion_impacts(
     }
 }
 
+/// Publishes the counters to [`SimulationMetrics`] and records each constellation's progress
+/// delta since the previous turn, which the snapshot ships alongside its progress state.
 pub fn export_great_discovery_metrics(
     ledger: Res<GreatDiscoveryLedger>,
     telemetry: Res<GreatDiscoveryTelemetry>,
+    mut readiness: ResMut<GreatDiscoveryReadiness>,
     metrics: Option<ResMut<SimulationMetrics>>,
 ) {
+    for (_, entries) in readiness.iter_mut() {
+        entries
+            .values_mut()
+            .for_each(ConstellationProgress::record_export);
+    }
     if let Some(mut metrics) = metrics {
         metrics.great_discoveries_total = ledger.records.len() as u32;
         metrics.great_discovery_candidates = telemetry.pending_candidates;
@@ -992,6 +1492,14 @@ pub fn snapshot_progress(readiness: &GreatDiscoveryReadiness) -> Vec<GreatDiscov
                 observation_deficit: progress.observation_deficit,
                 eta_ticks: progress.eta_ticks(),
                 covert: progress.covert,
+                progress_delta: progress
+                    .progress_delta
+                    .map(
+                        |(progress, observation_deficit)| GreatDiscoveryProgressDeltaState {
+                            progress: progress.raw(),
+                            observation_deficit,
+                        },
+                    ),
             });
         }
     }
@@ -1032,6 +1540,8 @@ pub fn snapshot_definitions(
                 observation_notes: meta.observation_notes.clone(),
                 leak_profile: meta.leak_profile.clone(),
                 requirements,
+                prerequisites: meta.prerequisites.iter().map(|id| id.0).collect(),
+                edges: requirement_edges(&meta.requirements),
             }
         })
         .collect();
@@ -1047,6 +1557,11 @@ pub fn snapshot_telemetry(
         total_resolved: ledger.records.len() as u32,
         pending_candidates: telemetry.pending_candidates,
         active_constellations: telemetry.active_constellations,
+        effect_audit: telemetry
+            .effect_audit
+            .iter()
+            .map(GreatDiscoveryEffectAudit::to_state)
+            .collect(),
     }
 }
 
@@ -1178,6 +1693,53 @@ mod tests {
         assert_eq!(restored_metadata.requirements[0].discovery_id, 101);
     }
 
+    #[test]
+    fn observation_thresholds_scale_with_map_size() {
+        let json = r#"[
+            {"id": 4200, "name": "Scaled", "field": "Physics", "observation_threshold": 8},
+            {"id": 4201, "name": "Unobserved", "field": "Physics", "observation_threshold": 0}
+        ]"#;
+        let thresholds = |scaling: DiscoveryThresholdScaling, tiles: u32| {
+            let mut registry = GreatDiscoveryRegistry::default();
+            registry
+                .load_catalog_for_map(json, &scaling, tiles)
+                .expect("catalog should parse");
+            [4200, 4201].map(|id| {
+                let id = GreatDiscoveryId(id);
+                let threshold = registry.definition(&id).unwrap().observation_threshold;
+                assert_eq!(
+                    registry.metadata(&id).unwrap().observation_threshold,
+                    threshold
+                );
+                threshold
+            })
+        };
+        let scaling = DiscoveryThresholdScaling {
+            enabled: true,
+            reference_tiles: 4_000,
+            exponent: 0.5,
+        };
+
+        // 8 · (tiles / 4000)^0.5: a quarter of the area halves it, four times doubles it.
+        assert_eq!(thresholds(scaling, 1_000), [4, 0]);
+        assert_eq!(thresholds(scaling, 4_000), [8, 0]);
+        assert_eq!(thresholds(scaling, 16_000), [16, 0]);
+        // A tiny map never rounds a real threshold down to "free".
+        assert_eq!(thresholds(scaling, 1), [1, 0]);
+
+        let linear = DiscoveryThresholdScaling {
+            exponent: 1.0,
+            ..scaling
+        };
+        assert_eq!(thresholds(linear, 2_000), [4, 0]);
+
+        let disabled = DiscoveryThresholdScaling {
+            enabled: false,
+            ..scaling
+        };
+        assert_eq!(thresholds(disabled, 16_000), [8, 0]);
+    }
+
     #[test]
     fn range_bands_sample_within_bounds() {
         let entry: GreatDiscoveryCatalogEntry = from_str(
@@ -1405,6 +1967,76 @@ mod tests {
         }
     }
 
+    #[test]
+    fn exported_progress_carries_the_delta_since_last_turn() {
+        let mut app = App::new();
+        app.insert_resource(GreatDiscoveryRegistry::default());
+        app.insert_resource(DiscoveryProgressLedger::default());
+        app.insert_resource(ObservationLedger::default());
+        app.insert_resource(GreatDiscoveryReadiness::default());
+        app.insert_resource(GreatDiscoveryTelemetry::default());
+        app.insert_resource(GreatDiscoveryLedger::default());
+        app.insert_resource(SimulationTick(0));
+        app.world
+            .resource_mut::<GreatDiscoveryRegistry>()
+            .register(GreatDiscoveryDefinition::new(
+                GreatDiscoveryId(1),
+                "Progress Bar",
+                KnowledgeField::Physics,
+                vec![ConstellationRequirement::new(
+                    42,
+                    scalar(1.0),
+                    scalar_zero(),
+                )],
+                3,
+                0,
+                None,
+                0,
+                false,
+            ));
+
+        let turn = |app: &mut App, progress: f32, observations: u32| {
+            app.world
+                .resource_mut::<DiscoveryProgressLedger>()
+                .add_progress(FactionId(0), 42, scalar(progress));
+            app.world
+                .resource_mut::<ObservationLedger>()
+                .set_observations(FactionId(0), KnowledgeField::Physics, observations);
+            app.world.run_system_once(collect_observation_signals);
+            app.world.run_system_once(update_constellation_progress);
+            app.world.run_system_once(export_great_discovery_metrics);
+            app.world.resource_mut::<SimulationTick>().0 += 1;
+            let states = snapshot_progress(app.world.resource::<GreatDiscoveryReadiness>());
+            assert_eq!(states.len(), 1);
+            states[0].clone()
+        };
+
+        let first = turn(&mut app, 0.25, 1);
+        assert_eq!(first.observation_deficit, 2);
+        assert_eq!(
+            first.progress_delta, None,
+            "no baseline on the first export"
+        );
+
+        let second = turn(&mut app, 0.25, 2);
+        assert!(second.progress > first.progress);
+        assert_eq!(
+            second.progress_delta,
+            Some(GreatDiscoveryProgressDeltaState {
+                progress: second.progress - first.progress,
+                observation_deficit: -1,
+            })
+        );
+
+        let idle = turn(&mut app, 0.0, 2);
+        assert_eq!(idle.progress, second.progress);
+        assert_eq!(
+            idle.progress_delta,
+            Some(GreatDiscoveryProgressDeltaState::default()),
+            "an idle turn reports no change"
+        );
+    }
+
     #[test]
     fn freshness_window_resets_progress_without_new_discovery() {
         let mut app = App::new();
@@ -1573,6 +2205,74 @@ mod tests {
         assert_eq!(effect_count, 3);
     }
 
+    #[test]
+    fn resolving_a_power_discovery_records_an_effect_audit() {
+        let mut app = App::new();
+        app.add_event::<GreatDiscoveryCandidateEvent>();
+        app.add_event::<GreatDiscoveryResolvedEvent>();
+        app.add_event::<GreatDiscoveryEffectEvent>();
+        app.insert_resource(GreatDiscoveryRegistry::default());
+        app.insert_resource(GreatDiscoveryReadiness::default());
+        app.insert_resource(GreatDiscoveryTelemetry::default());
+        app.insert_resource(GreatDiscoveryLedger::default());
+        app.insert_resource(PowerDiscoveryEffects::default());
+        app.insert_resource(PendingCrisisSeeds::default());
+        app.insert_resource(DiplomacyLeverage::default());
+        let nodes: Vec<Entity> = (0..6).map(|_| app.world.spawn_empty().id()).collect();
+        app.insert_resource(PowerTopology::from_grid(&nodes, 3, 2, scalar_one()));
+        app.insert_resource(SimulationTick(9));
+
+        app.world
+            .resource_mut::<GreatDiscoveryRegistry>()
+            .register(GreatDiscoveryDefinition::new(
+                GreatDiscoveryId(12),
+                "Grid Harmonics",
+                KnowledgeField::Physics,
+                vec![ConstellationRequirement::new(
+                    7,
+                    scalar_one(),
+                    scalar_zero(),
+                )],
+                0,
+                0,
+                None,
+                effect_flags::POWER,
+                false,
+            ));
+        app.world
+            .resource_mut::<Events<GreatDiscoveryCandidateEvent>>()
+            .send(GreatDiscoveryCandidateEvent {
+                faction: FactionId(2),
+                discovery: GreatDiscoveryId(12),
+            });
+
+        app.world.run_system_once(resolve_great_discovery);
+
+        let telemetry = app.world.resource::<GreatDiscoveryTelemetry>();
+        assert_eq!(
+            telemetry.effect_audit.iter().copied().collect::<Vec<_>>(),
+            vec![GreatDiscoveryEffectAudit {
+                tick: 9,
+                discovery: GreatDiscoveryId(12),
+                faction: FactionId(2),
+                kind: GreatDiscoveryEffectKind::Power,
+                target_id: 12,
+                magnitude: Scalar::from_u32(6),
+            }]
+        );
+
+        let state = snapshot_telemetry(app.world.resource::<GreatDiscoveryLedger>(), telemetry);
+        let entry = &state.effect_audit[0];
+        assert_eq!(entry.effect, effect_flags::POWER);
+        assert_eq!(entry.target, "power.discovery_unlocks");
+        assert_eq!(entry.target_id, 12);
+        assert_eq!(entry.magnitude, Scalar::from_u32(6).raw());
+
+        let mut restored = GreatDiscoveryTelemetry::default();
+        restored.set_from_state(&state);
+        assert_eq!(restored.effect_audit, telemetry.effect_audit);
+    }
+
     #[test]
     fn propagate_marks_publication_and_reinforces_requirements() {
         let mut app = App::new();
@@ -1581,6 +2281,7 @@ mod tests {
         app.insert_resource(GreatDiscoveryRegistry::default());
         app.insert_resource(DiscoveryProgressLedger::default());
         app.insert_resource(GreatDiscoveryLedger::default());
+        app.insert_resource(SimulationTick(10));
 
         let definition = GreatDiscoveryDefinition::new(
             GreatDiscoveryId(21),
@@ -1644,4 +2345,333 @@ mod tests {
             assert_eq!(amount, scalar_one());
         }
     }
+
+    #[test]
+    fn prerequisite_chain_gates_dependent_discovery() {
+        let json = r#"[
+            {
+                "id": 1,
+                "name": "Foundation",
+                "field": "Physics",
+                "requirements": [{"discovery_id": 42, "weight": 1.0, "minimum_progress": 0.0}]
+            },
+            {
+                "id": 2,
+                "name": "Dependent",
+                "field": "Physics",
+                "prerequisite_discoveries": [1]
+            }
+        ]"#;
+
+        let mut app = App::new();
+        app.add_event::<GreatDiscoveryCandidateEvent>();
+        app.add_event::<GreatDiscoveryResolvedEvent>();
+        app.add_event::<GreatDiscoveryEffectEvent>();
+
+        let mut registry = GreatDiscoveryRegistry::default();
+        registry
+            .load_catalog_from_str(json)
+            .expect("chained catalog should load");
+        assert_eq!(
+            registry
+                .definition(&GreatDiscoveryId(2))
+                .expect("dependent defined")
+                .prerequisite_discoveries,
+            vec![GreatDiscoveryId(1)]
+        );
+
+        app.insert_resource(registry);
+        app.insert_resource(DiscoveryProgressLedger::default());
+        app.insert_resource(ObservationLedger::default());
+        app.insert_resource(GreatDiscoveryReadiness::default());
+        app.insert_resource(GreatDiscoveryTelemetry::default());
+        app.insert_resource(GreatDiscoveryLedger::default());
+        app.insert_resource(PowerDiscoveryEffects::default());
+        app.insert_resource(PendingCrisisSeeds::default());
+        app.insert_resource(DiplomacyLeverage::default());
+        app.insert_resource(SimulationTick(0));
+
+        {
+            let mut observation = app.world.resource_mut::<ObservationLedger>();
+            observation.set_observations(FactionId(0), KnowledgeField::Physics, 0);
+        }
+
+        let run_pass = |app: &mut App| {
+            app.world.run_system_once(collect_observation_signals);
+            app.world.run_system_once(update_constellation_progress);
+            app.world.run_system_once(screen_great_discovery_candidates);
+            app.world.run_system_once(resolve_great_discovery);
+        };
+
+        // The dependent has no constellation requirements, so only the prerequisite holds it back.
+        run_pass(&mut app);
+        {
+            let ledger = app.world.resource::<GreatDiscoveryLedger>();
+            assert!(!ledger.contains(FactionId(0), GreatDiscoveryId(1)));
+            assert!(
+                !ledger.contains(FactionId(0), GreatDiscoveryId(2)),
+                "dependent must wait for its prerequisite"
+            );
+        }
+
+        {
+            let mut progress = app.world.resource_mut::<DiscoveryProgressLedger>();
+            progress.add_progress(FactionId(0), 42, scalar_one());
+        }
+
+        run_pass(&mut app);
+        {
+            let ledger = app.world.resource::<GreatDiscoveryLedger>();
+            assert!(ledger.contains(FactionId(0), GreatDiscoveryId(1)));
+            assert!(
+                !ledger.contains(FactionId(0), GreatDiscoveryId(2)),
+                "dependent is screened before the prerequisite resolves this pass"
+            );
+        }
+
+        run_pass(&mut app);
+        {
+            let ledger = app.world.resource::<GreatDiscoveryLedger>();
+            assert!(ledger.contains(FactionId(0), GreatDiscoveryId(2)));
+        }
+    }
+
+    #[test]
+    fn catalog_rejects_unknown_or_cyclic_prerequisites() {
+        let cyclic = r#"[
+            {"id": 1, "name": "A", "field": "Physics", "prerequisite_discoveries": [3]},
+            {"id": 2, "name": "B", "field": "Physics", "prerequisite_discoveries": [1]},
+            {"id": 3, "name": "C", "field": "Physics", "prerequisite_discoveries": [2]}
+        ]"#;
+        let mut registry = GreatDiscoveryRegistry::default();
+        let err = registry
+            .load_catalog_from_str(cyclic)
+            .expect_err("cyclic catalog must be rejected");
+        assert!(matches!(
+            err,
+            GreatDiscoveryCatalogError::PrerequisiteCycle { .. }
+        ));
+        assert_eq!(
+            registry.definitions().count(),
+            0,
+            "rejected catalog leaves registry untouched"
+        );
+
+        let unknown = r#"[
+            {"id": 1, "name": "A", "field": "Physics", "prerequisite_discoveries": [99]}
+        ]"#;
+        let err = registry
+            .load_catalog_from_str(unknown)
+            .expect_err("unknown prerequisite must be rejected");
+        assert!(matches!(
+            err,
+            GreatDiscoveryCatalogError::UnknownPrerequisite {
+                id: 1,
+                prerequisite: 99
+            }
+        ));
+    }
+
+    #[test]
+    fn catalog_builds_on_serializes_constellation_edges() {
+        let json = r#"[{
+            "id": 7,
+            "name": "Constellation",
+            "field": "Physics",
+            "requirements": [
+                {"discovery_id": 10, "weight": 2.0},
+                {"discovery_id": 11, "weight": 1.0, "builds_on": [10]},
+                {"discovery_id": 12, "weight": 1.0, "builds_on": [10, 11]}
+            ]
+        }]"#;
+        let mut registry = GreatDiscoveryRegistry::default();
+        registry
+            .load_catalog_from_str(json)
+            .expect("catalog parses");
+
+        let states = snapshot_definitions(&registry);
+        let edges: Vec<(u32, u32)> = states[0]
+            .edges
+            .iter()
+            .map(|edge| (edge.from, edge.to))
+            .collect();
+        assert_eq!(edges, vec![(10, 11), (10, 12), (11, 12)]);
+        // Edge weight is the prerequisite's share of the constellation (2 / 4 and 1 / 4).
+        assert!((states[0].edges[0].weight - 0.5).abs() < 1e-6);
+        assert!((states[0].edges[2].weight - 0.25).abs() < 1e-6);
+
+        let mut restored = GreatDiscoveryRegistry::default();
+        restored.restore_from_states(&states);
+        assert_eq!(
+            snapshot_definitions(&restored)[0].edges,
+            states[0].edges,
+            "edges survive a snapshot restore"
+        );
+    }
+
+    #[test]
+    fn catalog_rejects_unknown_or_cyclic_requirement_dependencies() {
+        let unknown = r#"[{
+            "id": 8, "name": "Dangling", "field": "Physics",
+            "requirements": [{"discovery_id": 10, "builds_on": [99]}]
+        }]"#;
+        let mut registry = GreatDiscoveryRegistry::default();
+        let err = registry
+            .load_catalog_from_str(unknown)
+            .expect_err("a dependency outside the constellation must be rejected");
+        assert!(matches!(
+            err,
+            GreatDiscoveryCatalogError::UnknownRequirementDependency {
+                id: 8,
+                requirement: 10,
+                builds_on: 99
+            }
+        ));
+
+        let cyclic = r#"[{
+            "id": 9, "name": "Loop", "field": "Physics",
+            "requirements": [
+                {"discovery_id": 10},
+                {"discovery_id": 11, "builds_on": [12]},
+                {"discovery_id": 12, "builds_on": [11]}
+            ]
+        }]"#;
+        let err = registry
+            .load_catalog_from_str(cyclic)
+            .expect_err("cyclic requirements must be rejected");
+        assert!(matches!(
+            err,
+            GreatDiscoveryCatalogError::RequirementCycle {
+                id: 9,
+                requirement: 11
+            }
+        ));
+        assert_eq!(registry.definitions().count(), 0);
+    }
+
+    #[test]
+    fn catalog_parses_leak_profiles_and_falls_back_to_stable() {
+        let json = r#"[
+            {"id": 1, "name": "Loud", "field": "Physics", "leak_profile": "Volatile"},
+            {"id": 2, "name": "Launch", "field": "Physics", "leak_profile": "forced-publication"},
+            {"id": 3, "name": "Quiet", "field": "Physics"},
+            {"id": 4, "name": "Odd", "field": "Physics", "leak_profile": "mostly harmless"}
+        ]"#;
+        let mut registry = GreatDiscoveryRegistry::default();
+        registry
+            .load_catalog_from_str(json)
+            .expect("catalog parses");
+
+        let profile = |id| {
+            registry
+                .definition(&GreatDiscoveryId(id))
+                .unwrap()
+                .leak_profile
+        };
+        assert_eq!(profile(1), LeakProfile::Volatile);
+        assert_eq!(profile(2), LeakProfile::ForcedPublication);
+        assert_eq!(profile(3), LeakProfile::Stable);
+        assert_eq!(profile(4), LeakProfile::Stable);
+        assert!(registry
+            .definition(&GreatDiscoveryId(2))
+            .unwrap()
+            .publishes_on_resolution());
+
+        let states = snapshot_definitions(&registry);
+        let mut restored = GreatDiscoveryRegistry::default();
+        restored.restore_from_states(&states);
+        assert_eq!(
+            restored
+                .definition(&GreatDiscoveryId(1))
+                .unwrap()
+                .leak_profile,
+            LeakProfile::Volatile
+        );
+    }
+
+    #[test]
+    fn volatile_discovery_goes_public_faster_than_stable() {
+        const TRIALS: u32 = 200;
+        const TURNS: u64 = 60;
+        let stable = GreatDiscoveryId(31);
+        let volatile = GreatDiscoveryId(32);
+
+        let mut app = App::new();
+        app.add_event::<GreatDiscoveryResolvedEvent>();
+        app.insert_resource(DiscoveryProgressLedger::default());
+        app.insert_resource(SimulationTick(0));
+
+        let mut registry = GreatDiscoveryRegistry::default();
+        for (id, profile) in [
+            (stable, LeakProfile::Stable),
+            (volatile, LeakProfile::Volatile),
+        ] {
+            registry.register(
+                GreatDiscoveryDefinition::new(
+                    id,
+                    "Leak",
+                    KnowledgeField::Physics,
+                    Vec::new(),
+                    0,
+                    0,
+                    None,
+                    0,
+                    true,
+                )
+                .with_leak_profile(profile),
+            );
+        }
+        app.insert_resource(registry);
+
+        // Each faction is one seeded trial: the roll seed mixes tick, faction and discovery.
+        let mut ledger = GreatDiscoveryLedger::default();
+        for faction in 0..TRIALS {
+            for id in [stable, volatile] {
+                ledger.push(GreatDiscoveryRecord {
+                    id,
+                    faction: FactionId(faction),
+                    field: KnowledgeField::Physics,
+                    tick: 0,
+                    publicly_deployed: false,
+                    effect_flags: 0,
+                });
+            }
+        }
+        app.insert_resource(ledger);
+
+        let mut published_at: HashMap<(FactionId, GreatDiscoveryId), u64> = HashMap::new();
+        for turn in 1..=TURNS {
+            app.world.resource_mut::<SimulationTick>().0 = turn;
+            app.world.run_system_once(propagate_diffusion_impacts);
+            for record in app.world.resource::<GreatDiscoveryLedger>().records() {
+                if record.publicly_deployed {
+                    published_at
+                        .entry((record.faction, record.id))
+                        .or_insert(turn);
+                }
+            }
+        }
+
+        let mean_turns = |id: GreatDiscoveryId| {
+            let total: u64 = (0..TRIALS)
+                .map(|faction| {
+                    published_at
+                        .get(&(FactionId(faction), id))
+                        .copied()
+                        .unwrap_or(TURNS + 1)
+                })
+                .sum();
+            total as f64 / TRIALS as f64
+        };
+        let stable_mean = mean_turns(stable);
+        let volatile_mean = mean_turns(volatile);
+        assert!(
+            volatile_mean * 2.0 < stable_mean,
+            "volatile mean {volatile_mean:.2} should be well under stable mean {stable_mean:.2}"
+        );
+        assert!(
+            published_at.keys().any(|(_, id)| *id == stable),
+            "stable discoveries still leak eventually"
+        );
+    }
 }
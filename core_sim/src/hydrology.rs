@@ -9,13 +9,16 @@ use crate::{
     grid_utils::{hex_neighbor, HEX_CORNER_COUNT, HEX_DIRECTION_COUNT},
     heightfield::ElevationField,
     map_preset::{
-        default_river_base_runoff, default_river_channel_min_discharge,
+        default_lake_min_depth, default_river_base_runoff, default_river_channel_min_discharge,
         default_river_class_major_min_discharge, default_river_class_navigable_min_discharge,
         default_river_fill_epsilon, default_river_flat_jitter, default_river_moisture_weight,
         MapPresetsHandle,
     },
     mapgen::WorldGenSeed,
-    resources::{MoistureRaster, SimulationConfig, TileRegistry},
+    resources::{
+        season_phase, MoistureRaster, RiverFlowConfig, SimulationConfig, SimulationTick,
+        TileRegistry,
+    },
     terrain::terrain_definition,
 };
 
@@ -357,6 +360,13 @@ pub struct RiverSegment {
     /// lands on). `None` when it emitted no edges (nothing to hand over) or when it never reaches a
     /// navigable channel at all.
     pub navigable_inflow: Option<RiverInflow>,
+    /// Corner flow accumulation at the river's most downstream corner — its generated mouth
+    /// discharge, in the same units as `RiverEdge::discharge`. The baseline `flow_volume` swings
+    /// around.
+    pub base_discharge: f32,
+    /// This turn's flow: `base_discharge` scaled by basin moisture and season
+    /// (`HydrologyState::update_flow`). Equals `base_discharge` until the first update.
+    pub flow_volume: f32,
 }
 
 impl RiverSegment {
@@ -376,6 +386,45 @@ impl RiverSegment {
     }
 }
 
+/// A closed depression filled to its spill point: the standing water the tile-level fill leaves
+/// behind in a basin the ocean can only be reached from by climbing out over a saddle.
+///
+/// Lakes are a **hydrology** record, like rivers — detecting one does not re-terrain its hexes (a
+/// below-sea-level `InlandSea` body is simply also a lake here), but `generate_hydrology` tags
+/// every lake hex `WATER | FRESHWATER` so tag readers see the standing water. The surface moves
+/// with basin moisture between the bed's lowest hex and the spill point; it can never stand above
+/// the spill, because past it the basin overflows down its outlet.
+#[derive(Debug, Clone)]
+pub struct Lake {
+    pub id: u32,
+    /// Every hex the filled basin covers, in tile-index order.
+    pub tiles: Vec<UVec2>,
+    /// Raw elevation of each hex in `tiles` — the lake bed.
+    pub bed: Vec<f32>,
+    /// Elevation of the saddle the basin spills over: the fill level, and the highest the surface
+    /// can stand.
+    pub spill_level: f32,
+    /// This turn's water surface, in `[floor, spill_level]` (`HydrologyState::update_lake_levels`).
+    /// Equals `spill_level` until the first update.
+    pub level: f32,
+}
+
+impl Lake {
+    /// The bed's lowest elevation — where the last of the water stands in a drought.
+    pub fn floor(&self) -> f32 {
+        self.bed.iter().copied().fold(self.spill_level, f32::min)
+    }
+
+    /// Water standing over each hex at this turn's `level`, parallel to `tiles`; `0` where the bed
+    /// has dried out.
+    pub fn depths(&self) -> impl Iterator<Item = (UVec2, f32)> + '_ {
+        self.tiles
+            .iter()
+            .zip(&self.bed)
+            .map(|(pos, bed)| (*pos, (self.level - bed).max(0.0)))
+    }
+}
+
 /// The discharge thresholds that turn corner flow accumulation into a per-edge `RiverClass` — and,
 /// past the top threshold, into a `NavigableRiver` hex chain.
 ///
@@ -421,6 +470,7 @@ fn is_water_terrain(terrain: TerrainType) -> bool {
 #[derive(Resource, Debug, Clone, Default)]
 pub struct HydrologyState {
     pub rivers: Vec<RiverSegment>,
+    pub lakes: Vec<Lake>,
 }
 
 impl HydrologyState {
@@ -438,6 +488,177 @@ impl HydrologyState {
         }
         mask
     }
+
+    /// Recomputes every river's `flow_volume` from the mean moisture over the hexes it drains and
+    /// the season at `tick`. Without a usable moisture raster the moisture term is neutral, so flow
+    /// follows the season alone.
+    pub fn update_flow(
+        &mut self,
+        moisture: Option<&MoistureRaster>,
+        tick: u64,
+        cfg: &RiverFlowConfig,
+        width: u32,
+        height: u32,
+        wrap_horizontal: bool,
+    ) {
+        let grid = HexGrid {
+            width,
+            height,
+            wrap_horizontal,
+        };
+        let expected = (width as usize) * (height as usize);
+        let moisture = moisture
+            .filter(|m| m.width == width && m.height == height && m.values.len() == expected);
+        let season = season_factor(tick, cfg);
+        for river in &mut self.rivers {
+            let hexes: Vec<UVec2> = river
+                .touched_hexes(width, height, wrap_horizontal)
+                .into_iter()
+                .filter(|pos| pos.x < width && pos.y < height)
+                .collect();
+            let wetness = match moisture {
+                Some(raster) if !hexes.is_empty() => {
+                    let basin_moisture = hexes
+                        .iter()
+                        .map(|hex| raster.values[grid.tile_index(*hex)].clamp(0.0, 1.0))
+                        .sum::<f32>()
+                        / hexes.len() as f32;
+                    1.0 + cfg.moisture_weight * (2.0 * basin_moisture - 1.0)
+                }
+                _ => 1.0,
+            };
+            river.flow_volume = (river.base_discharge * wetness * season).max(0.0);
+        }
+    }
+
+    /// Recomputes every lake's `level` from the mean moisture over its own hexes and the season at
+    /// `tick`, on the same wetness curve as `update_flow`. A basin at or above neutral wetness is
+    /// full to its spill point; a drier one drops proportionally toward its floor.
+    pub fn update_lake_levels(
+        &mut self,
+        moisture: Option<&MoistureRaster>,
+        tick: u64,
+        cfg: &RiverFlowConfig,
+        width: u32,
+        height: u32,
+    ) {
+        let expected = (width as usize) * (height as usize);
+        let moisture = moisture
+            .filter(|m| m.width == width && m.height == height && m.values.len() == expected);
+        let season = season_factor(tick, cfg);
+        for lake in &mut self.lakes {
+            let wetness = match moisture {
+                Some(raster) if !lake.tiles.is_empty() => {
+                    let basin_moisture = lake
+                        .tiles
+                        .iter()
+                        .map(|pos| raster.values[(pos.y * width + pos.x) as usize].clamp(0.0, 1.0))
+                        .sum::<f32>()
+                        / lake.tiles.len() as f32;
+                    1.0 + cfg.moisture_weight * (2.0 * basin_moisture - 1.0)
+                }
+                _ => 1.0,
+            };
+            let fullness = (wetness * season).clamp(0.0, 1.0);
+            let floor = lake.floor();
+            // Measured down from the spill, so a full basin stands at exactly `spill_level`.
+            lake.level = lake.spill_level - (lake.spill_level - floor) * (1.0 - fullness);
+        }
+    }
+
+    /// Per-tile lake depth: water standing over each hex at its lake's current level, `0` off-lake
+    /// and on a lake hex that has dried out.
+    pub fn lake_depth_by_tile(&self, width: u32, height: u32) -> Vec<f32> {
+        let mut depths = vec![0.0f32; (width as usize) * (height as usize)];
+        for lake in &self.lakes {
+            for (pos, depth) in lake.depths() {
+                if pos.x < width && pos.y < height {
+                    depths[(pos.y * width + pos.x) as usize] = depth;
+                }
+            }
+        }
+        depths
+    }
+
+    /// Per-tile river flow: the largest `flow_volume` of any river touching each hex, `0` off-river.
+    pub fn flow_by_tile(&self, width: u32, height: u32, wrap_horizontal: bool) -> Vec<f32> {
+        let mut flows = vec![0.0f32; (width as usize) * (height as usize)];
+        for river in &self.rivers {
+            for pos in river.touched_hexes(width, height, wrap_horizontal) {
+                if pos.x < width && pos.y < height {
+                    let slot = &mut flows[(pos.y * width + pos.x) as usize];
+                    *slot = slot.max(river.flow_volume);
+                }
+            }
+        }
+        flows
+    }
+
+    /// Per-tile forage regrowth multiplier from river flow: `1 + weight · (flow / base − 1)` for
+    /// the wettest river touching each hex (floored at 0), `1` off-river or at `weight` 0.
+    pub fn regrowth_factor_by_tile(
+        &self,
+        width: u32,
+        height: u32,
+        wrap_horizontal: bool,
+        weight: f32,
+    ) -> Vec<f32> {
+        let mut ratios: Vec<Option<f32>> = vec![None; (width as usize) * (height as usize)];
+        for river in self
+            .rivers
+            .iter()
+            .filter(|river| river.base_discharge > 0.0)
+        {
+            let ratio = river.flow_volume / river.base_discharge;
+            for pos in river.touched_hexes(width, height, wrap_horizontal) {
+                if pos.x < width && pos.y < height {
+                    let slot = &mut ratios[(pos.y * width + pos.x) as usize];
+                    *slot = Some(slot.map_or(ratio, |current| current.max(ratio)));
+                }
+            }
+        }
+        ratios
+            .into_iter()
+            .map(|ratio| ratio.map_or(1.0, |ratio| (1.0 + weight * (ratio - 1.0)).max(0.0)))
+            .collect()
+    }
+}
+
+/// The seasonal multiplier on river flow at `tick`: one sine cycle per `season_period_ticks` (the
+/// configured year length).
+fn season_factor(tick: u64, cfg: &RiverFlowConfig) -> f32 {
+    if cfg.season_period_ticks == 0 {
+        return 1.0;
+    }
+    let phase = season_phase(tick, cfg.season_period_ticks);
+    1.0 + cfg.season_amplitude * (phase * std::f32::consts::TAU).sin()
+}
+
+/// Per-turn river flow and lake level update (`TurnStage::Logistics`, beside `simulate_materials`).
+pub fn update_river_flow(
+    config: Res<SimulationConfig>,
+    tick: Res<SimulationTick>,
+    moisture: Option<Res<MoistureRaster>>,
+    hydrology: Option<ResMut<HydrologyState>>,
+) {
+    let Some(mut hydrology) = hydrology else {
+        return;
+    };
+    hydrology.update_flow(
+        moisture.as_deref(),
+        tick.0,
+        &config.river_flow,
+        config.grid_size.x,
+        config.grid_size.y,
+        config.map_topology.wrap_horizontal,
+    );
+    hydrology.update_lake_levels(
+        moisture.as_deref(),
+        tick.0,
+        &config.river_flow,
+        config.grid_size.x,
+        config.grid_size.y,
+    );
 }
 
 // ---------------------------------------------------------------------------
@@ -1646,6 +1867,8 @@ struct HydrologyLevers {
     /// The gentle-coast gate deltas are stamped under — the shelf's own threshold, so "gentle coast"
     /// means one thing across worldgen.
     coast_height_threshold: f32,
+    /// The shallowest filled depression (spill point to floor) that still stands as a lake.
+    lake_min_depth: f32,
 }
 
 impl HydrologyLevers {
@@ -1721,6 +1944,11 @@ impl HydrologyLevers {
             coast_height_threshold: preset
                 .map(|p| p.shelf.coast_height_threshold)
                 .unwrap_or(DEFAULT_COAST_HEIGHT_THRESHOLD),
+            lake_min_depth: lever(
+                overrides.lake_min_depth,
+                preset.map(|p| p.lake_min_depth),
+                default_lake_min_depth(),
+            ),
         }
     }
 }
@@ -1757,6 +1985,106 @@ fn build_seamask(
     seamask
 }
 
+// ---------------------------------------------------------------------------
+// Lakes: a tile-level priority flood from the ocean
+// ---------------------------------------------------------------------------
+
+/// Fill every closed depression to its spill point and return each basin deeper than `min_depth`
+/// as a `Lake`.
+///
+/// This is the corner field's priority flood run on **hexes** and without the epsilon gradient: a
+/// hex's fill level is the lowest saddle on any path from it to the ocean, so `filled - elevation`
+/// is exactly the water a closed basin holds there. Adjacent flooded hexes always share one fill
+/// level (water would otherwise run from the higher pool into the lower), so each hex-connected
+/// component of flooded hexes is one basin with one spill point. As with the corner field, a map
+/// with no ocean reaches nothing and holds no lakes.
+fn detect_lakes(tiles: &TileWorld, elevation_field: &ElevationField, min_depth: f32) -> Vec<Lake> {
+    let grid = tiles.grid;
+    let total = (grid.width * grid.height) as usize;
+    let elevation: Vec<f32> = (0..total)
+        .map(|idx| elevation_field.sample(idx as u32 % grid.width, idx as u32 / grid.width))
+        .collect();
+    let mut filled = vec![f32::INFINITY; total];
+    let mut heap = BinaryHeap::new();
+    for idx in 0..total {
+        if tiles.is_ocean(idx) {
+            filled[idx] = elevation[idx];
+            heap.push(HeapEntry {
+                key: elevation[idx],
+                idx,
+            });
+        }
+    }
+    while let Some(HeapEntry { key, idx }) = heap.pop() {
+        if key > filled[idx] {
+            continue;
+        }
+        let pos = UVec2::new(idx as u32 % grid.width, idx as u32 / grid.width);
+        for dir in 0..HEX_DIRECTION_COUNT as u8 {
+            let Some(next) = grid.neighbor(pos, dir) else {
+                continue;
+            };
+            let next_idx = grid.tile_index(next);
+            let candidate = elevation[next_idx].max(filled[idx]);
+            if candidate < filled[next_idx] {
+                filled[next_idx] = candidate;
+                heap.push(HeapEntry {
+                    key: candidate,
+                    idx: next_idx,
+                });
+            }
+        }
+    }
+
+    let flooded = |idx: usize| {
+        filled[idx].is_finite() && filled[idx] > elevation[idx] && !tiles.is_ocean(idx)
+    };
+    let mut visited = vec![false; total];
+    let mut lakes = Vec::new();
+    for start in 0..total {
+        if visited[start] || !flooded(start) {
+            continue;
+        }
+        visited[start] = true;
+        let mut basin = vec![start];
+        let mut queue = VecDeque::from([start]);
+        while let Some(idx) = queue.pop_front() {
+            let pos = UVec2::new(idx as u32 % grid.width, idx as u32 / grid.width);
+            for dir in 0..HEX_DIRECTION_COUNT as u8 {
+                let Some(next) = grid.neighbor(pos, dir) else {
+                    continue;
+                };
+                let next_idx = grid.tile_index(next);
+                if !visited[next_idx] && flooded(next_idx) {
+                    visited[next_idx] = true;
+                    basin.push(next_idx);
+                    queue.push_back(next_idx);
+                }
+            }
+        }
+        basin.sort_unstable();
+        let spill_level = filled[start];
+        let floor = basin
+            .iter()
+            .map(|&idx| elevation[idx])
+            .fold(spill_level, f32::min);
+        if spill_level - floor < min_depth {
+            continue;
+        }
+        lakes.push(Lake {
+            id: lakes.len() as u32 + 1,
+            tiles: basin
+                .iter()
+                .map(|&idx| UVec2::new(idx as u32 % grid.width, idx as u32 / grid.width))
+                .collect(),
+            bed: basin.iter().map(|&idx| elevation[idx]).collect(),
+            spill_level,
+            level: spill_level,
+        });
+    }
+    lakes
+}
+
 // ---------------------------------------------------------------------------
 // The worldgen pass
 // ---------------------------------------------------------------------------
@@ -1841,12 +2169,15 @@ pub fn generate_hydrology(world: &mut World) {
                 for pos in &traced.navigable_hexes {
                     navigable_tiles.insert(grid.tile_index(*pos));
                 }
+                let base_discharge = field.accumulation[traced.end_corner];
                 rivers.push(RiverSegment {
                     id: rivers.len() as u32 + 1,
                     order: network.order[traced.end_corner].max(1),
                     edges: traced.edges,
                     navigable_hexes: traced.navigable_hexes,
                     navigable_inflow: traced.navigable_inflow,
+                    base_discharge,
+                    flow_volume: base_discharge,
                 });
             }
         }
@@ -1858,6 +2189,9 @@ pub fn generate_hydrology(world: &mut World) {
         seamask: &seamask,
         terrain: &tile_terrain,
     };
+    // Read off the pre-stamp terrain: deltas and navigable hexes are never ocean, so stamping them
+    // below cannot move a spill point.
+    let lakes = detect_lakes(&tiles, &elevation_field, levers.lake_min_depth);
     // Gentle coast: the same `elevation.sample - sea_level < coast_height_threshold` test
     // `classify_bands` / `reconcile_coastal_shelf` use to split gentle from cliff coasts. A river
     // that meets the water at a cliff has no delta (it is an estuary).
@@ -2075,6 +2409,11 @@ pub fn generate_hydrology(world: &mut World) {
         Vec::new()
     };
 
+    let lake_set: HashSet<usize> = lakes
+        .iter()
+        .flat_map(|lake| lake.tiles.iter().map(|&pos| grid.tile_index(pos)))
+        .collect();
+    let mut lake_tiles_tagged = 0usize;
     let navigable_tags = terrain_definition(TerrainType::NavigableRiver).tags;
     for (idx, entity) in updates {
         let Some(mut tile) = world.get_mut::<Tile>(entity) else {
@@ -2106,6 +2445,13 @@ pub fn generate_hydrology(world: &mut World) {
             tile.terrain_tags = terrain_definition(TerrainType::RiverDelta).tags;
             delta_tiles_applied += 1;
         }
+        if lake_set.contains(&idx) {
+            // The basin keeps its terrain (see `Lake`), but the hex stands under fresh water. Tagged
+            // after the stamps above, which replace the tags wholesale. `FRESHWATER` keeps it off
+            // `TileWorld::is_ocean`.
+            tile.terrain_tags |= TerrainTags::WATER | TerrainTags::FRESHWATER;
+            lake_tiles_tagged += 1;
+        }
     }
 
     let river_count = rivers.len();
@@ -2128,6 +2474,9 @@ pub fn generate_hydrology(world: &mut World) {
         .remove_resource::<HydrologyState>()
         .unwrap_or_default();
     state.rivers = rivers;
+    let lake_count = lakes.len();
+    let lake_tiles: usize = lakes.iter().map(|lake| lake.tiles.len()).sum();
+    state.lakes = lakes;
     world.insert_resource(state);
 
     tracing::info!(
@@ -2145,6 +2494,9 @@ pub fn generate_hydrology(world: &mut World) {
         channel_min = levers.channel_min,
         class_major_min = levers.thresholds.major_min,
         class_navigable_min = levers.thresholds.navigable_min,
+        lakes = lake_count,
+        lake_tiles,
+        lake_tiles_tagged,
         "hydrology.generated"
     );
 }
@@ -3017,9 +3369,102 @@ mod tests {
         assert!(wrapped.corner_hexes(UVec2::new(0, 2), CORNER_TOP).is_some());
     }
 
+    /// A slope rising away from an ocean row, with a two-hex bowl sunk into row 4. The bowl's lowest
+    /// way out is over its row-3 neighbours, so that row's elevation is the spill point.
+    fn bowl_map() -> (HexGrid, Vec<f32>, [UVec2; 2]) {
+        let (width, height) = (7u32, 7u32);
+        let g = grid(width, height, false);
+        let bowl = [UVec2::new(3, 4), UVec2::new(4, 4)];
+        let mut elevations = vec![0.0f32; (width * height) as usize];
+        for y in 0..height {
+            for x in 0..width {
+                elevations[idx(width, x, y)] = if y == 0 { 0.2 } else { 0.5 + 0.05 * y as f32 };
+            }
+        }
+        elevations[idx(width, 3, 4)] = 0.50;
+        elevations[idx(width, 4, 4)] = 0.55;
+        (g, elevations, bowl)
+    }
+
+    #[test]
+    fn a_closed_depression_fills_to_its_spill_point_and_nothing_else_is_a_lake() {
+        let (g, elevations, bowl) = bowl_map();
+        let spill = elevations[idx(g.width, 3, 3)];
+        let fixture = Fixture::new(g, elevations, &|_, y| y == 0, &ocean_row_terrain);
+        let tiles = fixture.tiles();
+
+        let lakes = detect_lakes(&tiles, &fixture.elevation, default_lake_min_depth());
+        assert_eq!(lakes.len(), 1, "only the bowl holds water: {lakes:?}");
+        let lake = &lakes[0];
+        assert_eq!(lake.id, 1);
+        assert_eq!(lake.tiles, bowl.to_vec());
+        assert_eq!(lake.spill_level, spill);
+        assert_eq!(lake.level, spill, "a fresh lake stands full");
+        assert!((lake.floor() - 0.50).abs() < 1e-6);
+
+        // A bowl shallower than the depth gate is fill noise, not a lake.
+        assert!(detect_lakes(&tiles, &fixture.elevation, spill - 0.45).is_empty());
+    }
+
+    #[test]
+    fn lake_level_falls_with_basin_moisture_and_refills_to_the_spill() {
+        let (g, elevations, bowl) = bowl_map();
+        let fixture = Fixture::new(g, elevations, &|_, y| y == 0, &ocean_row_terrain);
+        let lakes = detect_lakes(
+            &fixture.tiles(),
+            &fixture.elevation,
+            default_lake_min_depth(),
+        );
+        let mut hydro = HydrologyState {
+            lakes,
+            ..Default::default()
+        };
+        let (width, height) = (g.width, g.height);
+        let spill = hydro.lakes[0].spill_level;
+        let steady = RiverFlowConfig {
+            moisture_weight: 0.9,
+            season_period_ticks: 0,
+            season_amplitude: 0.0,
+            forage_regrowth_weight: 0.0,
+        };
+        let raster =
+            |value: f32| MoistureRaster::new(width, height, vec![value; (width * height) as usize]);
+        let depth_at = |hydro: &HydrologyState, pos: UVec2| {
+            hydro.lake_depth_by_tile(width, height)[idx(width, pos.x, pos.y)]
+        };
+
+        hydro.update_lake_levels(Some(&raster(0.5)), 0, &steady, width, height);
+        assert_eq!(hydro.lakes[0].level, spill, "a neutral basin stays full");
+        assert!((depth_at(&hydro, bowl[0]) - (spill - 0.50)).abs() < 1e-6);
+
+        // Bone dry: wetness 0.1, so the surface sits a tenth of the way up from the floor — below
+        // the shallow hex's bed, which dries out.
+        hydro.update_lake_levels(Some(&raster(0.0)), 0, &steady, width, height);
+        let low = hydro.lakes[0].level;
+        assert!((low - (0.50 + 0.1 * (spill - 0.50))).abs() < 1e-5, "{low}");
+        assert!(depth_at(&hydro, bowl[0]) > 0.0);
+        assert_eq!(depth_at(&hydro, bowl[1]), 0.0, "the shallow end dries out");
+
+        // A wet turn refills it — but never past the spill point, where the basin overflows.
+        hydro.update_lake_levels(Some(&raster(1.0)), 0, &steady, width, height);
+        assert_eq!(hydro.lakes[0].level, spill);
+        assert!(depth_at(&hydro, bowl[1]) > 0.0);
+        assert_eq!(
+            depth_at(&hydro, UVec2::new(3, 3)),
+            0.0,
+            "off-lake hexes read 0"
+        );
+    }
+
     /// Spawn a 7x7 world with an ocean row and a valley, run the real `generate_hydrology`, and
     /// return the world so the invariants can be checked against the actual ECS state.
     fn generate_small_world() -> World {
+        let (_, elevations) = valley_map(7, 7);
+        generate_world_over(elevations)
+    }
+
+    /// [`generate_small_world`] over an arbitrary 7x7 elevation field.
+    fn generate_world_over(elevations: Vec<f32>) -> World {
         let width = 7u32;
         let height = 7u32;
 
@@ -3033,8 +3478,6 @@ mod tests {
         config.hydrology.river_density = Some(1.0);
         world.insert_resource(config);
         world.insert_resource(WorldGenSeed(0));
-
-        let (_, elevations) = valley_map(width, height);
         world.insert_resource(ElevationField::new(width, height, elevations));
 
         let mut tiles = Vec::with_capacity((width * height) as usize);
@@ -3075,6 +3518,167 @@ mod tests {
         world
     }
 
+    #[test]
+    fn generated_lake_hexes_are_tagged_fresh_water() {
+        let (_, elevations, bowl) = bowl_map();
+        let mut world = generate_world_over(elevations);
+        let lake_tiles: Vec<UVec2> = world
+            .resource::<HydrologyState>()
+            .lakes
+            .iter()
+            .flat_map(|lake| lake.tiles.iter().copied())
+            .collect();
+        for pos in bowl {
+            assert!(lake_tiles.contains(&pos), "the bowl at {pos} is a lake");
+        }
+
+        let mut tiles = world.query::<&Tile>();
+        for tile in tiles.iter(&world) {
+            let fresh = TerrainTags::WATER | TerrainTags::FRESHWATER;
+            if lake_tiles.contains(&tile.position) {
+                assert!(
+                    tile.terrain_tags.contains(fresh),
+                    "lake hex {} is tagged {:?}",
+                    tile.position,
+                    tile.terrain_tags
+                );
+            } else if tile.terrain == TerrainType::MixedWoodland {
+                assert!(
+                    !tile.terrain_tags.contains(TerrainTags::WATER),
+                    "dry hex {} is not water",
+                    tile.position
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn river_flow_rises_with_basin_moisture_and_follows_the_season() {
+        use bevy_ecs::system::RunSystemOnce;
+
+        let mut world = generate_small_world();
+        let (width, height) = (7u32, 7u32);
+        let mut hydro = world.resource::<HydrologyState>().clone();
+        assert!(!hydro.rivers.is_empty(), "expected at least one river");
+        for river in &hydro.rivers {
+            assert!(river.base_discharge > 0.0);
+            assert_eq!(river.flow_volume, river.base_discharge);
+        }
+
+        let steady = RiverFlowConfig {
+            moisture_weight: 0.5,
+            season_period_ticks: 0,
+            season_amplitude: 0.0,
+            forage_regrowth_weight: 0.0,
+        };
+        let raster =
+            |value: f32| MoistureRaster::new(width, height, vec![value; (width * height) as usize]);
+        let flows = |hydro: &HydrologyState| -> Vec<f32> {
+            hydro.rivers.iter().map(|river| river.flow_volume).collect()
+        };
+
+        hydro.update_flow(Some(&raster(0.1)), 0, &steady, width, height, false);
+        let dry = flows(&hydro);
+        hydro.update_flow(Some(&raster(0.5)), 0, &steady, width, height, false);
+        let neutral = flows(&hydro);
+        hydro.update_flow(Some(&raster(0.9)), 0, &steady, width, height, false);
+        let wet = flows(&hydro);
+        for (i, river) in hydro.rivers.iter().enumerate() {
+            assert!(
+                dry[i] < neutral[i] && neutral[i] < wet[i],
+                "river {}",
+                river.id
+            );
+            assert!((neutral[i] - river.base_discharge).abs() < 1e-3);
+            assert!(
+                river.flow_volume > river.base_discharge,
+                "a saturated basin runs above baseline"
+            );
+        }
+
+        // A 12-turn cycle peaks a quarter of the way in and bottoms out at three quarters.
+        let seasonal = RiverFlowConfig {
+            season_period_ticks: 12,
+            season_amplitude: 0.3,
+            ..steady
+        };
+        hydro.update_flow(Some(&raster(0.5)), 3, &seasonal, width, height, false);
+        let high_water = flows(&hydro);
+        hydro.update_flow(Some(&raster(0.5)), 9, &seasonal, width, height, false);
+        let low_water = flows(&hydro);
+        for i in 0..hydro.rivers.len() {
+            assert!((high_water[i] / neutral[i] - 1.3).abs() < 1e-3);
+            assert!((low_water[i] / neutral[i] - 0.7).abs() < 1e-3);
+        }
+
+        // The system wires the same update to the live resources.
+        world.insert_resource(SimulationTick(3));
+        world.insert_resource(raster(0.9));
+        world.resource_mut::<SimulationConfig>().river_flow = seasonal;
+        world.run_system_once(update_river_flow);
+        let live = flows(world.resource::<HydrologyState>());
+        for i in 0..live.len() {
+            assert!(
+                live[i] > high_water[i],
+                "wet basin at high water beats a neutral one"
+            );
+        }
+    }
+
+    #[test]
+    fn a_trunk_carries_more_than_its_tributary_even_from_a_drier_basin() {
+        // A tributary (order 1) joins a trunk (order 2) at the trunk's head; the trunk's mouth
+        // discharge is the tributary's plus its own catchment, as the corner accumulation makes it.
+        let edge = |x: u32, y: u32, discharge: f32| RiverEdge {
+            hex: UVec2::new(x, y),
+            dir: DIR_E,
+            class: RiverClass::Minor,
+            discharge,
+        };
+        let segment =
+            |id: u32, order: u8, edges: Vec<RiverEdge>, base_discharge: f32| RiverSegment {
+                id,
+                order,
+                edges,
+                navigable_hexes: Vec::new(),
+                navigable_inflow: None,
+                base_discharge,
+                flow_volume: base_discharge,
+            };
+        let mut hydro = HydrologyState {
+            rivers: vec![
+                segment(1, 2, vec![edge(0, 3, 12.0), edge(2, 3, 20.0)], 24.0),
+                segment(2, 1, vec![edge(0, 0, 4.0), edge(2, 0, 8.0)], 10.0),
+            ],
+            ..Default::default()
+        };
+        // The tributary's rows are soaked; the trunk's are dry.
+        let (width, height) = (5u32, 5u32);
+        let values = (0..height)
+            .flat_map(|y| (0..width).map(move |_| if y <= 1 { 0.95 } else { 0.2 }))
+            .collect();
+        let moisture = MoistureRaster::new(width, height, values);
+        let cfg = RiverFlowConfig {
+            moisture_weight: 0.5,
+            season_period_ticks: 12,
+            season_amplitude: 0.3,
+            forage_regrowth_weight: 0.0,
+        };
+
+        for tick in 0..12 {
+            hydro.update_flow(Some(&moisture), tick, &cfg, width, height, false);
+            let (trunk, tributary) = (&hydro.rivers[0], &hydro.rivers[1]);
+            assert!(tributary.flow_volume > tributary.base_discharge);
+            assert!(trunk.flow_volume < trunk.base_discharge * 1.3);
+            assert!(
+                trunk.flow_volume > tributary.flow_volume,
+                "tick {tick}: trunk {} vs tributary {}",
+                trunk.flow_volume,
+                tributary.flow_volume
+            );
+        }
+    }
+
     #[test]
     fn generates_river_reaching_the_ocean_on_a_small_grid() {
         let world = generate_small_world();
@@ -4,20 +4,22 @@ use std::str::FromStr;
 use std::sync::Arc;
 
 use bevy::prelude::*;
-use rand::{rngs::SmallRng, Rng, SeedableRng};
-use serde::Deserialize;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
 
 use crate::{
     components::PopulationCohort,
     culture::{CultureTraitAxis, CULTURE_TRAIT_AXES},
     generations::{GenerationId, GenerationRegistry},
     resources::SentimentAxisBias,
+    rng_stream::{RngStream, RngStreamState},
     scalar::{scalar_from_f32, scalar_one, scalar_zero, Scalar},
 };
 use sim_runtime::{
     influence_domain_mask, CultureTraitAxis as SchemaCultureTraitAxis, InfluenceDomain,
     InfluenceLifecycle, InfluenceScopeKind, InfluencerCultureResonanceEntry,
-    InfluentialIndividualState,
+    InfluentialIndividualState, SupportAction,
 };
 
 pub type InfluentialId = u32;
@@ -42,12 +44,57 @@ pub struct InfluencerBalanceConfig {
     support_channel_max: f32,
     notoriety_min: f32,
     notoriety_max: f32,
+    /// Effective influence (influence × coherence factor) an influencer needs to be listed in
+    /// snapshots; weaker ones still simulate but are only counted. `0.0` lists everyone.
+    snapshot_min_influence: f32,
     scope_thresholds: ScopeThresholdConfig,
 }
 
 impl InfluencerBalanceConfig {
-    pub fn from_json_str(json: &str) -> Result<Self, serde_json::Error> {
-        serde_json::from_str(json)
+    pub fn from_json_str(json: &str) -> Result<Self, InfluencerConfigError> {
+        let config: InfluencerBalanceConfig = serde_json::from_str(json)?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Each scope's `demote_coherence` must be a finite fraction (coherence is clamped to `0..=1`,
+    /// so anything outside either retires every Active influencer or none) and its
+    /// `dormancy_grace_ticks` at least 1 (the count includes the tick that first dips).
+    pub fn validate(&self) -> Result<(), InfluencerConfigError> {
+        let scopes = [
+            (
+                "scope_thresholds.local.demote_coherence",
+                "scope_thresholds.local.dormancy_grace_ticks",
+                &self.scope_thresholds.local,
+            ),
+            (
+                "scope_thresholds.regional.demote_coherence",
+                "scope_thresholds.regional.dormancy_grace_ticks",
+                &self.scope_thresholds.regional,
+            ),
+            (
+                "scope_thresholds.global.demote_coherence",
+                "scope_thresholds.global.dormancy_grace_ticks",
+                &self.scope_thresholds.global,
+            ),
+        ];
+        for (coherence_field, grace_field, values) in scopes {
+            if !(0.0..=1.0).contains(&values.demote_coherence) {
+                return Err(InfluencerConfigError::Invalid {
+                    field: coherence_field,
+                    constraint: "be within 0..=1".to_string(),
+                    value: values.demote_coherence.to_string(),
+                });
+            }
+            if values.dormancy_grace_ticks == 0 {
+                return Err(InfluencerConfigError::Invalid {
+                    field: grace_field,
+                    constraint: "be at least 1".to_string(),
+                    value: values.dormancy_grace_ticks.to_string(),
+                });
+            }
+        }
+        Ok(())
     }
 
     pub fn roster_cap(&self) -> usize {
@@ -110,6 +157,10 @@ impl InfluencerBalanceConfig {
         self.notoriety_max
     }
 
+    pub fn snapshot_min_influence(&self) -> f32 {
+        self.snapshot_min_influence
+    }
+
     pub(crate) fn scope_threshold(&self, scope: InfluenceScopeKind) -> ScopeThreshold {
         match scope {
             InfluenceScopeKind::Local => ScopeThreshold::from(&self.scope_thresholds.local),
@@ -139,6 +190,7 @@ impl Default for InfluencerBalanceConfig {
             support_channel_max: 1.5,
             notoriety_min: 0.05,
             notoriety_max: 5.0,
+            snapshot_min_influence: 0.0,
             scope_thresholds: ScopeThresholdConfig::default(),
         }
     }
@@ -152,6 +204,18 @@ struct ScopeThresholdConfig {
     global: ScopeThresholdValues,
 }
 
+#[derive(Debug, Error)]
+pub enum InfluencerConfigError {
+    #[error("failed to parse influencer config: {0}")]
+    Parse(#[from] serde_json::Error),
+    #[error("invalid influencer config: `{field}` must {constraint}, got {value}")]
+    Invalid {
+        field: &'static str,
+        constraint: String,
+        value: String,
+    },
+}
+
 impl Default for ScopeThresholdConfig {
     fn default() -> Self {
         Self {
@@ -161,6 +225,7 @@ impl Default for ScopeThresholdConfig {
                 demote_coherence: 0.25,
                 promote_ticks: 3,
                 demote_ticks: 6,
+                dormancy_grace_ticks: 1,
             },
             regional: ScopeThresholdValues {
                 promote_coherence: 0.60,
@@ -168,6 +233,7 @@ impl Default for ScopeThresholdConfig {
                 demote_coherence: 0.30,
                 promote_ticks: 4,
                 demote_ticks: 7,
+                dormancy_grace_ticks: 1,
             },
             global: ScopeThresholdValues {
                 promote_coherence: 0.72,
@@ -175,6 +241,7 @@ impl Default for ScopeThresholdConfig {
                 demote_coherence: 0.35,
                 promote_ticks: 5,
                 demote_ticks: 8,
+                dormancy_grace_ticks: 1,
             },
         }
     }
@@ -185,9 +252,14 @@ impl Default for ScopeThresholdConfig {
 struct ScopeThresholdValues {
     promote_coherence: f32,
     promote_notoriety: f32,
+    /// An Active influencer whose coherence is below this (0..=1) ...
     demote_coherence: f32,
     promote_ticks: u16,
+    /// ... once it has been Active this long ...
     demote_ticks: u16,
+    /// ... for this many consecutive ticks goes Dormant. A tick back at or above the threshold
+    /// restarts the count; `1` demotes on the first tick below it. At least 1.
+    dormancy_grace_ticks: u16,
 }
 
 impl Default for ScopeThresholdValues {
@@ -198,6 +270,7 @@ impl Default for ScopeThresholdValues {
             demote_coherence: 0.3,
             promote_ticks: 4,
             demote_ticks: 6,
+            dormancy_grace_ticks: 1,
         }
     }
 }
@@ -210,6 +283,7 @@ impl From<&ScopeThresholdValues> for ScopeThreshold {
             demote_coherence: values.demote_coherence,
             promote_ticks: values.promote_ticks,
             demote_ticks: values.demote_ticks,
+            dormancy_grace_ticks: values.dormancy_grace_ticks,
         }
     }
 }
@@ -224,7 +298,7 @@ impl InfluencerConfigHandle {
 
     pub fn load_builtin() -> Self {
         let parsed = InfluencerBalanceConfig::from_json_str(BUILTIN_INFLUENCER_CONFIG)
-            .unwrap_or_else(|err| panic!("failed to parse builtin influencer config: {err}"));
+            .unwrap_or_else(|err| panic!("failed to load builtin influencer config: {err}"));
         Self(Arc::new(parsed))
     }
 
@@ -235,7 +309,7 @@ impl InfluencerConfigHandle {
     pub fn replace_from_json(
         &mut self,
         json: &str,
-    ) -> Result<Arc<InfluencerBalanceConfig>, serde_json::Error> {
+    ) -> Result<Arc<InfluencerBalanceConfig>, InfluencerConfigError> {
         let parsed = InfluencerBalanceConfig::from_json_str(json)?;
         let shared = Arc::new(parsed);
         self.0 = Arc::clone(&shared);
@@ -357,6 +431,8 @@ struct InfluentialIndividual {
     status: InfluencerStatus,
     coherence: Scalar,
     ticks_in_status: u16,
+    /// Consecutive ticks an Active influencer has spent below `dormancy_coherence`.
+    ticks_below_dormancy: u16,
     influence: Scalar,
     baseline_growth: Scalar,
     growth_rate: Scalar,
@@ -419,6 +495,7 @@ pub(crate) struct ScopeThreshold {
     demote_coherence: f32,
     promote_ticks: u16,
     demote_ticks: u16,
+    dormancy_grace_ticks: u16,
 }
 
 fn next_scope(scope: InfluenceScopeKind) -> Option<InfluenceScopeKind> {
@@ -456,6 +533,7 @@ impl InfluentialIndividual {
             status: InfluencerStatus::from(state.lifecycle),
             coherence: Scalar::from_raw(state.coherence),
             ticks_in_status: state.ticks_in_status,
+            ticks_below_dormancy: state.ticks_below_dormancy,
             influence: Scalar::from_raw(state.influence),
             baseline_growth: Scalar::from_raw(state.baseline_growth),
             growth_rate: Scalar::from_raw(state.growth_rate),
@@ -551,6 +629,7 @@ impl InfluentialIndividual {
             lifecycle: InfluenceLifecycle::from(self.status),
             coherence: self.coherence.raw(),
             ticks_in_status: self.ticks_in_status,
+            ticks_below_dormancy: self.ticks_below_dormancy,
             audience_generations: self.audience_generations.clone(),
             support_popular: self.channel_support[SupportChannel::Popular as usize].raw(),
             support_peer: self.channel_support[SupportChannel::Peer as usize].raw(),
@@ -577,9 +656,18 @@ impl InfluentialIndividual {
     }
 }
 
+/// Roster state a snapshot leaves out: where the spawn stream stands and the counters the next
+/// spawn reads. Carried by `crate::world_save`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct InfluentialRosterSave {
+    rng: RngStreamState,
+    next_id: InfluentialId,
+    spawn_cooldown: u32,
+}
+
 #[derive(Resource)]
 pub struct InfluentialRoster {
-    rng: SmallRng,
+    rng: RngStream,
     individuals: Vec<InfluentialIndividual>,
     next_id: InfluentialId,
     spawn_cooldown: u32,
@@ -598,7 +686,7 @@ impl InfluentialRoster {
         config: Arc<InfluencerBalanceConfig>,
     ) -> Self {
         let mut roster = Self {
-            rng: SmallRng::seed_from_u64(seed),
+            rng: RngStream::new(seed),
             individuals: Vec::new(),
             next_id: 1,
             spawn_cooldown: config.spawn_interval_min(),
@@ -801,11 +889,17 @@ impl InfluentialRoster {
                     }
                 }
                 InfluencerStatus::Active => {
-                    if coherence < thresholds.demote_coherence
+                    individual.ticks_below_dormancy = if coherence < thresholds.demote_coherence {
+                        individual.ticks_below_dormancy.saturating_add(1)
+                    } else {
+                        0
+                    };
+                    if individual.ticks_below_dormancy >= thresholds.dormancy_grace_ticks
                         && individual.ticks_in_status >= thresholds.demote_ticks
                     {
                         individual.status = InfluencerStatus::Dormant;
                         individual.ticks_in_status = 0;
+                        individual.ticks_below_dormancy = 0;
                     } else if let Some(next_scope) = next_scope(individual.scope) {
                         let next_threshold = self.config.scope_threshold(next_scope);
                         if individual.ticks_in_status >= thresholds.promote_ticks
@@ -815,6 +909,7 @@ impl InfluentialRoster {
                             individual.scope = next_scope;
                             individual.status = InfluencerStatus::Potential;
                             individual.ticks_in_status = 0;
+                            individual.ticks_below_dormancy = 0;
                         }
                     }
                 }
@@ -955,6 +1050,7 @@ impl InfluentialRoster {
             status: InfluencerStatus::Potential,
             coherence: scalar_from_f32(0.18),
             ticks_in_status: 0,
+            ticks_below_dormancy: 0,
             influence,
             baseline_growth,
             growth_rate: baseline_growth,
@@ -1065,6 +1161,37 @@ impl InfluentialRoster {
         }
     }
 
+    /// Backs or suppresses `id` through one channel: the general pressure moves exactly as
+    /// [`Self::apply_support`] / [`Self::apply_suppress`] move it, and the chosen channel's boost is
+    /// raised (support) or drained (suppress) by the same channel gain
+    /// [`Self::apply_channel_support`] uses. Returns `false` for an unknown id.
+    pub fn apply_channel_action(
+        &mut self,
+        id: InfluentialId,
+        action: SupportAction,
+        channel: SupportChannel,
+        magnitude: Scalar,
+    ) -> bool {
+        let applied = match action {
+            SupportAction::Support => self.apply_support(id, magnitude),
+            SupportAction::Suppress => self.apply_suppress(id, magnitude),
+        };
+        if !applied {
+            return false;
+        }
+        let shift = magnitude * scalar_from_f32(self.config.support_channel_gain());
+        let max_boost = scalar_from_f32(self.config.support_channel_max());
+        if let Some(individual) = self.individuals.iter_mut().find(|item| item.id == id) {
+            let boost = &mut individual.channel_boosts[channel as usize];
+            *boost = match action {
+                SupportAction::Support => *boost + shift,
+                SupportAction::Suppress => *boost - shift,
+            }
+            .clamp(scalar_zero(), max_boost);
+        }
+        true
+    }
+
     pub fn states(&self) -> Vec<InfluentialIndividualState> {
         let mut states: Vec<_> = self
             .individuals
@@ -1082,6 +1209,51 @@ impl InfluentialRoster {
         states
     }
 
+    /// [`states`](Self::states) split into `(listed, latent)` by the config's
+    /// `snapshot_min_influence`. Latent influencers keep simulating; snapshots only count them.
+    pub fn snapshot_states(
+        &self,
+    ) -> (
+        Vec<InfluentialIndividualState>,
+        Vec<InfluentialIndividualState>,
+    ) {
+        let minimum = self.config.snapshot_min_influence();
+        if minimum <= 0.0 {
+            return (self.states(), Vec::new());
+        }
+        let minimum = scalar_from_f32(minimum);
+        let latent: HashSet<InfluentialId> = self
+            .individuals
+            .iter()
+            .filter(|individual| individual.influence * individual.coherence_factor() < minimum)
+            .map(|individual| individual.id)
+            .collect();
+        self.states()
+            .into_iter()
+            .partition(|state| !latent.contains(&state.id))
+    }
+
+    /// Influencers reaching `generation`: those scoped to it or listing it in their audience,
+    /// strongest effective influence (influence × lifecycle coherence factor) first, ties by id.
+    pub fn influencers_for_generation(&self, generation: GenerationId) -> Vec<InfluentialId> {
+        let mut matches: Vec<(Scalar, InfluentialId)> = self
+            .individuals
+            .iter()
+            .filter(|individual| {
+                individual.generation_scope == Some(generation)
+                    || individual.audience_generations.contains(&generation)
+            })
+            .map(|individual| {
+                (
+                    individual.influence * individual.coherence_factor(),
+                    individual.id,
+                )
+            })
+            .collect();
+        matches.sort_unstable_by(|a, b| b.0.cmp(&a.0).then_with(|| a.1.cmp(&b.1)));
+        matches.into_iter().map(|(_, id)| id).collect()
+    }
+
     pub fn sentiment_totals(&self) -> [Scalar; 4] {
         self.last_sentiment
     }
@@ -1111,6 +1283,22 @@ impl InfluentialRoster {
             .clamp(min, if max >= min { max } else { min });
     }
 
+    pub(crate) fn save_state(&self) -> InfluentialRosterSave {
+        InfluentialRosterSave {
+            rng: self.rng.state(),
+            next_id: self.next_id,
+            spawn_cooldown: self.spawn_cooldown,
+        }
+    }
+
+    /// Resume the spawn stream and counters from a save. Apply after `update_from_states`, which
+    /// resets both counters.
+    pub(crate) fn restore_save_state(&mut self, state: &InfluentialRosterSave) {
+        self.rng = RngStream::resume(state.rng);
+        self.next_id = state.next_id;
+        self.spawn_cooldown = state.spawn_cooldown;
+    }
+
     pub fn update_from_states(&mut self, states: &[InfluentialIndividualState]) {
         self.individuals = states
             .iter()
@@ -1210,7 +1398,7 @@ fn clamp_culture_array(
     values
 }
 
-fn select_domains(rng: &mut SmallRng) -> Vec<InfluenceDomain> {
+fn select_domains(rng: &mut RngStream) -> Vec<InfluenceDomain> {
     let domain_pool = [
         InfluenceDomain::Sentiment,
         InfluenceDomain::Discovery,
@@ -1229,7 +1417,7 @@ fn select_domains(rng: &mut SmallRng) -> Vec<InfluenceDomain> {
     domains
 }
 
-fn generate_sentiment_weights(rng: &mut SmallRng, domains: &[InfluenceDomain]) -> [Scalar; 4] {
+fn generate_sentiment_weights(rng: &mut RngStream, domains: &[InfluenceDomain]) -> [Scalar; 4] {
     let mut weights = [scalar_zero(); 4];
     for weight in &mut weights {
         let base = rng.gen_range(-0.2..0.2);
@@ -1263,7 +1451,7 @@ fn generate_sentiment_weights(rng: &mut SmallRng, domains: &[InfluenceDomain]) -
 }
 
 fn push_culture_weight(
-    rng: &mut SmallRng,
+    rng: &mut RngStream,
     weights: &mut [Scalar; CULTURE_TRAIT_AXES],
     axis: CultureTraitAxis,
     min: f32,
@@ -1276,7 +1464,7 @@ fn push_culture_weight(
 }
 
 fn generate_culture_resonance_weights(
-    rng: &mut SmallRng,
+    rng: &mut RngStream,
     domains: &[InfluenceDomain],
 ) -> [Scalar; CULTURE_TRAIT_AXES] {
     let mut weights = [scalar_zero(); CULTURE_TRAIT_AXES];
@@ -1410,7 +1598,7 @@ fn generate_culture_resonance_weights(
 }
 
 fn domain_weight(
-    rng: &mut SmallRng,
+    rng: &mut RngStream,
     domains: &[InfluenceDomain],
     target: InfluenceDomain,
     min: f32,
@@ -1423,7 +1611,7 @@ fn domain_weight(
     }
 }
 
-fn generate_name(rng: &mut SmallRng, scope: InfluenceScopeKind) -> String {
+fn generate_name(rng: &mut RngStream, scope: InfluenceScopeKind) -> String {
     const HONORIFICS: &[&str] = &[
         "Archivist",
         "Marshal",
@@ -1556,34 +1744,46 @@ fn local_axis_to_schema(axis: CultureTraitAxis) -> SchemaCultureTraitAxis {
     }
 }
 
-pub fn tick_influencers(
-    mut roster: ResMut<InfluentialRoster>,
-    registry: Res<GenerationRegistry>,
-    cohorts: Query<&PopulationCohort>,
-    mut impacts: ResMut<InfluencerImpacts>,
-    mut axis_bias: ResMut<SentimentAxisBias>,
-) {
-    let manual_axes = axis_bias.manual_environment();
-
+/// Each generation's share of the head-count from `(generation, size)` pairs; empty when nobody
+/// is alive.
+pub(crate) fn generation_shares(
+    cohorts: impl IntoIterator<Item = (GenerationId, u32)>,
+) -> HashMap<GenerationId, f32> {
     let mut generation_totals: HashMap<GenerationId, u64> = HashMap::new();
     let mut total_population: u64 = 0;
-    for cohort in cohorts.iter() {
-        let entry = generation_totals.entry(cohort.generation).or_insert(0);
-        *entry += cohort.size as u64;
-        total_population += cohort.size as u64;
+    for (generation, size) in cohorts {
+        *generation_totals.entry(generation).or_insert(0) += u64::from(size);
+        total_population += u64::from(size);
     }
 
-    let mut generation_shares: HashMap<GenerationId, f32> = HashMap::new();
+    let mut shares: HashMap<GenerationId, f32> = HashMap::new();
     if total_population > 0 {
         for (gen, value) in generation_totals {
-            generation_shares.insert(
+            shares.insert(
                 gen,
                 (value as f32 / total_population as f32).clamp(0.0, 1.0),
             );
         }
     }
+    shares
+}
+
+pub fn tick_influencers(
+    mut roster: ResMut<InfluentialRoster>,
+    registry: Res<GenerationRegistry>,
+    cohorts: Query<&PopulationCohort>,
+    mut impacts: ResMut<InfluencerImpacts>,
+    mut axis_bias: ResMut<SentimentAxisBias>,
+) {
+    let manual_axes = axis_bias.manual_environment();
+    let generation_shares = generation_shares(
+        cohorts
+            .iter()
+            .map(|cohort| (cohort.generation, cohort.size)),
+    );
 
     roster.tick(&registry, manual_axes, &generation_shares);
+    axis_bias.set_generation_contributions(registry.sentiment_contributions(&generation_shares));
 
     let sentiment = roster.sentiment_totals();
     let logistics = roster.logistics_total();
@@ -1595,3 +1795,249 @@ pub fn tick_influencers(
     impacts.set_from_totals(logistics, morale, power);
     impacts.set_culture_resonance(culture);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn roster_with(
+        specs: &[(
+            InfluenceScopeKind,
+            Option<GenerationId>,
+            &[GenerationId],
+            f32,
+            InfluenceLifecycle,
+        )],
+    ) -> InfluentialRoster {
+        let registry = GenerationRegistry::with_seed(7, 4);
+        let mut roster = InfluentialRoster::with_seed(
+            7,
+            &registry,
+            InfluencerConfigHandle::load_builtin().get(),
+        );
+        let template = roster.states()[0].clone();
+        let states: Vec<_> = specs
+            .iter()
+            .enumerate()
+            .map(
+                |(idx, (scope, generation, audience, influence, lifecycle))| {
+                    let mut state = template.clone();
+                    state.id = idx as u32 + 1;
+                    state.scope = *scope;
+                    state.generation_scope =
+                        generation.unwrap_or(InfluentialIndividualState::NO_GENERATION_SCOPE);
+                    state.audience_generations = audience.to_vec();
+                    state.influence = Scalar::from_f32(*influence).raw();
+                    state.lifecycle = *lifecycle;
+                    state.coherence = Scalar::from_f32(0.5).raw();
+                    state
+                },
+            )
+            .collect();
+        roster.update_from_states(&states);
+        roster
+    }
+
+    #[test]
+    fn influencers_for_generation_matches_scope_and_audience_by_effective_influence() {
+        use InfluenceLifecycle::{Active, Potential};
+        use InfluenceScopeKind::{Generation, Global, Local};
+
+        let roster = roster_with(&[
+            // 1: global, audience elsewhere — not reaching generation 2.
+            (Global, None, &[0, 1], 3.0, Active),
+            // 2: generation-scoped to 2 with an empty audience list.
+            (Generation, Some(2), &[], 1.0, Active),
+            // 3: global, audience includes 2.
+            (Global, None, &[1, 2], 2.0, Active),
+            // 4: local, audience 2, but only a Potential (coherence 0.5 → effective 1.5).
+            (Local, None, &[2], 3.0, Potential),
+            // 5: generation-scoped to another generation.
+            (Generation, Some(3), &[3], 2.5, Active),
+        ]);
+
+        assert_eq!(roster.influencers_for_generation(2), vec![3, 4, 2]);
+        assert_eq!(roster.influencers_for_generation(3), vec![5]);
+        assert_eq!(roster.influencers_for_generation(0), vec![1]);
+        assert!(roster.influencers_for_generation(9).is_empty());
+    }
+
+    #[test]
+    fn channel_support_raises_effective_support_next_tick() {
+        let spec = [(
+            InfluenceScopeKind::Global,
+            None,
+            &[0][..],
+            1.0,
+            InfluenceLifecycle::Active,
+        )];
+        let mut control = roster_with(&spec);
+        let mut backed = roster_with(&spec);
+        let registry = GenerationRegistry::with_seed(7, 4);
+        assert!(backed.apply_channel_action(
+            1,
+            SupportAction::Support,
+            SupportChannel::Peer,
+            scalar_from_f32(2.0),
+        ));
+        assert!(!backed.apply_channel_action(
+            99,
+            SupportAction::Support,
+            SupportChannel::Peer,
+            scalar_one(),
+        ));
+
+        for roster in [&mut control, &mut backed] {
+            roster.tick(&registry, [scalar_zero(); 4], &HashMap::new());
+        }
+        let state = |roster: &InfluentialRoster| {
+            roster
+                .states()
+                .into_iter()
+                .find(|state| state.id == 1)
+                .expect("influencer 1")
+        };
+        let (before, after) = (state(&control), state(&backed));
+        assert!(after.supported);
+        assert!(after.support_peer > before.support_peer);
+        assert!(after.growth_rate > before.growth_rate);
+
+        let mut suppressed = roster_with(&spec);
+        assert!(suppressed.apply_channel_action(
+            1,
+            SupportAction::Suppress,
+            SupportChannel::Peer,
+            scalar_from_f32(2.0),
+        ));
+        suppressed.tick(&registry, [scalar_zero(); 4], &HashMap::new());
+        let after = state(&suppressed);
+        assert!(after.suppressed);
+        assert!(after.growth_rate < before.growth_rate);
+    }
+
+    #[test]
+    fn incoherent_influencer_goes_dormant_only_after_the_grace_period() {
+        let mut roster = roster_with(&[(
+            InfluenceScopeKind::Global,
+            None,
+            &[0][..],
+            1.0,
+            InfluenceLifecycle::Active,
+        )]);
+        // No channel weight → coherence recomputes to 0 every tick.
+        let mut states = roster.states();
+        let state = &mut states[0];
+        state.weight_popular = 0;
+        state.weight_peer = 0;
+        state.weight_institutional = 0;
+        state.weight_humanitarian = 0;
+        roster.update_from_states(&states);
+
+        let config = |json: &str| Arc::new(InfluencerBalanceConfig::from_json_str(json).unwrap());
+        let strict = config(
+            r#"{ "scope_thresholds": { "global":
+                { "demote_coherence": 0.3, "demote_ticks": 0, "dormancy_grace_ticks": 3 } } }"#,
+        );
+        roster.apply_config(strict.clone());
+        let registry = GenerationRegistry::with_seed(7, 4);
+        let tick = |roster: &mut InfluentialRoster| {
+            roster.tick(&registry, [scalar_zero(); 4], &HashMap::new());
+            roster
+                .states()
+                .into_iter()
+                .find(|state| state.id == 1)
+                .expect("influencer 1")
+        };
+
+        for expected in 1..=2 {
+            let state = tick(&mut roster);
+            assert_eq!(state.lifecycle, InfluenceLifecycle::Active);
+            assert_eq!(state.ticks_below_dormancy, expected);
+        }
+        // A tick at or above the threshold restarts the grace period.
+        roster.apply_config(config(
+            r#"{ "scope_thresholds": { "global": { "demote_coherence": 0.0, "demote_ticks": 0 } } }"#,
+        ));
+        assert_eq!(tick(&mut roster).ticks_below_dormancy, 0);
+
+        roster.apply_config(strict);
+        for _ in 0..2 {
+            assert_eq!(tick(&mut roster).lifecycle, InfluenceLifecycle::Active);
+        }
+        let state = tick(&mut roster);
+        assert_eq!(state.lifecycle, InfluenceLifecycle::Dormant);
+        assert_eq!(state.ticks_below_dormancy, 0);
+    }
+
+    #[test]
+    fn dormancy_settings_are_validated_at_load() {
+        let builtin = InfluencerConfigHandle::load_builtin().get();
+        let demotion = |scope| {
+            let threshold = builtin.scope_threshold(scope);
+            (
+                threshold.demote_coherence,
+                threshold.demote_ticks,
+                threshold.dormancy_grace_ticks,
+            )
+        };
+        assert_eq!(demotion(InfluenceScopeKind::Local), (0.25, 6, 1));
+        assert_eq!(demotion(InfluenceScopeKind::Regional), (0.3, 7, 1));
+        assert_eq!(demotion(InfluenceScopeKind::Global), (0.35, 8, 1));
+
+        for (json, field) in [
+            (
+                r#"{ "scope_thresholds": { "local": { "demote_coherence": 1.5 } } }"#,
+                "scope_thresholds.local.demote_coherence",
+            ),
+            (
+                r#"{ "scope_thresholds": { "global": { "demote_coherence": -0.1 } } }"#,
+                "scope_thresholds.global.demote_coherence",
+            ),
+            (
+                r#"{ "scope_thresholds": { "regional": { "dormancy_grace_ticks": 0 } } }"#,
+                "scope_thresholds.regional.dormancy_grace_ticks",
+            ),
+        ] {
+            match InfluencerBalanceConfig::from_json_str(json) {
+                Err(InfluencerConfigError::Invalid { field: got, .. }) => assert_eq!(got, field),
+                other => panic!("{json} should be rejected, got {other:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn snapshot_states_leave_weak_influencers_latent() {
+        use InfluenceLifecycle::{Active, Dormant, Potential};
+        use InfluenceScopeKind::Global;
+
+        let mut roster = roster_with(&[
+            (Global, None, &[], 2.0, Active),
+            // Potential at coherence 0.5: effective 0.8 × 0.5 = 0.4.
+            (Global, None, &[], 0.8, Potential),
+            (Global, None, &[], 0.5, Active),
+            // Dormant influencers have no effective influence.
+            (Global, None, &[], 3.0, Dormant),
+        ]);
+
+        let (listed, latent) = roster.snapshot_states();
+        assert_eq!(listed.len(), 4, "the builtin config lists everyone");
+        assert!(latent.is_empty());
+
+        let config = InfluencerBalanceConfig::from_json_str(r#"{ "snapshot_min_influence": 0.5 }"#)
+            .expect("override parses");
+        roster.apply_config(Arc::new(config));
+        let (listed, latent) = roster.snapshot_states();
+        let ids = |states: &[InfluentialIndividualState]| {
+            let mut ids: Vec<_> = states.iter().map(|state| state.id).collect();
+            ids.sort_unstable();
+            ids
+        };
+        assert_eq!(ids(&listed), vec![1, 3]);
+        assert_eq!(ids(&latent), vec![2, 4]);
+        assert_eq!(
+            roster.states().len(),
+            4,
+            "latent influencers stay on the roster"
+        );
+    }
+}
@@ -1,23 +1,26 @@
-use std::collections::{HashMap, VecDeque};
+use std::collections::{BTreeMap, HashMap, VecDeque};
 use std::sync::Arc;
 
 use bevy::prelude::*;
-use serde::Deserialize;
+use serde::{de::Error as _, Deserialize};
 use sim_runtime::knowledge::{
     KnowledgeTelemetryEvent, KnowledgeTelemetryFrame, KnowledgeTelemetryMission,
     KNOWLEDGE_TELEMETRY_TOPIC,
 };
 use sim_runtime::{
     encode_knowledge_ledger_key, KnowledgeCountermeasureKind, KnowledgeCountermeasureState,
-    KnowledgeInfiltrationState, KnowledgeLeakFlags, KnowledgeLedgerEntryState,
-    KnowledgeMetricsState, KnowledgeModifierBreakdownState, KnowledgeModifierSource,
-    KnowledgeSecurityPosture, KnowledgeTimelineEventKind, KnowledgeTimelineEventState,
-    WorldSnapshot,
+    KnowledgeEarlyWarningEntryState, KnowledgeEarlyWarningState, KnowledgeInfiltrationState,
+    KnowledgeLeakFlags, KnowledgeLedgerEntryState, KnowledgeMetricsState,
+    KnowledgeModifierBreakdownState, KnowledgeModifierSource, KnowledgeSecurityPosture,
+    KnowledgeTimelineEventKind, KnowledgeTimelineEventState, WorldSnapshot,
 };
+use thiserror::Error;
 use tracing::info;
 
 use crate::{
-    espionage::{EspionageCatalog, EspionageMissionKind, EspionageMissionTemplate},
+    espionage::{
+        CounterIntelBudgets, EspionageCatalog, EspionageMissionKind, EspionageMissionTemplate,
+    },
     metrics::SimulationMetrics,
     orders::FactionId,
     resources::SimulationTick,
@@ -32,6 +35,7 @@ pub struct KnowledgeLedgerConfig {
     timeline_capacity: usize,
     default_half_life_ticks: u16,
     default_time_to_cascade: u16,
+    early_warning_horizon_ticks: u16,
     max_suspicion: f32,
     suspicion_decay: f32,
     suspicion_retention_threshold: f32,
@@ -40,11 +44,22 @@ pub struct KnowledgeLedgerConfig {
     infiltration_cells_weight: f32,
     infiltration_fidelity_weight: f32,
     max_progress_per_tick: i32,
+    posture_half_life_multipliers: PostureHalfLifeMultipliers,
+    posture_upkeep: PostureUpkeep,
 }
 
 impl KnowledgeLedgerConfig {
     pub fn from_json_str(json: &str) -> Result<Self, serde_json::Error> {
-        serde_json::from_str(json)
+        let parsed: Self = serde_json::from_str(json)?;
+        parsed
+            .posture_half_life_multipliers
+            .validate()
+            .map_err(serde_json::Error::custom)?;
+        parsed
+            .posture_upkeep
+            .validate()
+            .map_err(serde_json::Error::custom)?;
+        Ok(parsed)
     }
 
     pub fn timeline_capacity(&self) -> usize {
@@ -59,6 +74,12 @@ impl KnowledgeLedgerConfig {
         self.default_time_to_cascade
     }
 
+    /// Secrets with `time_to_cascade` at or below this many ticks land in their owner's
+    /// early-warning list.
+    pub fn early_warning_horizon_ticks(&self) -> u16 {
+        self.early_warning_horizon_ticks
+    }
+
     pub fn max_suspicion(&self) -> Scalar {
         Scalar::from_f32(self.max_suspicion)
     }
@@ -90,6 +111,16 @@ impl KnowledgeLedgerConfig {
     pub fn max_progress_per_tick(&self) -> i32 {
         self.max_progress_per_tick
     }
+
+    /// Scales an entry's base half-life by its owner's security posture before modifiers apply.
+    pub fn half_life_multiplier(&self, posture: KnowledgeSecurityPosture) -> f32 {
+        self.posture_half_life_multipliers.multiplier(posture)
+    }
+
+    /// Counter-intel reserve an entry held at `posture` costs per turn.
+    pub fn posture_upkeep(&self, posture: KnowledgeSecurityPosture) -> Scalar {
+        Scalar::from_f32(self.posture_upkeep.upkeep(posture))
+    }
 }
 
 impl Default for KnowledgeLedgerConfig {
@@ -98,6 +129,7 @@ impl Default for KnowledgeLedgerConfig {
             timeline_capacity: 64,
             default_half_life_ticks: 10,
             default_time_to_cascade: 10,
+            early_warning_horizon_ticks: 5,
             max_suspicion: 5.0,
             suspicion_decay: 0.05,
             suspicion_retention_threshold: 0.05,
@@ -106,7 +138,107 @@ impl Default for KnowledgeLedgerConfig {
             infiltration_cells_weight: 1.0,
             infiltration_fidelity_weight: 2.0,
             max_progress_per_tick: 25,
+            posture_half_life_multipliers: PostureHalfLifeMultipliers::default(),
+            posture_upkeep: PostureUpkeep::default(),
+        }
+    }
+}
+
+/// Half-life multiplier per [`KnowledgeSecurityPosture`]: larger values slow the leak, so a
+/// BlackVault secret outlasts a Hardened one under identical pressure.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct PostureHalfLifeMultipliers {
+    minimal: f32,
+    standard: f32,
+    hardened: f32,
+    black_vault: f32,
+}
+
+impl Default for PostureHalfLifeMultipliers {
+    fn default() -> Self {
+        Self {
+            minimal: 0.75,
+            standard: 1.0,
+            hardened: 1.5,
+            black_vault: 3.0,
+        }
+    }
+}
+
+impl PostureHalfLifeMultipliers {
+    fn multiplier(&self, posture: KnowledgeSecurityPosture) -> f32 {
+        match posture {
+            KnowledgeSecurityPosture::Minimal => self.minimal,
+            KnowledgeSecurityPosture::Standard => self.standard,
+            KnowledgeSecurityPosture::Hardened => self.hardened,
+            KnowledgeSecurityPosture::BlackVault => self.black_vault,
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        for (name, value) in [
+            ("minimal", self.minimal),
+            ("standard", self.standard),
+            ("hardened", self.hardened),
+            ("black_vault", self.black_vault),
+        ] {
+            if !value.is_finite() || value <= 0.0 {
+                return Err(format!(
+                    "posture_half_life_multipliers.{name} must be finite and greater than 0, got {value}"
+                ));
+            }
         }
+        Ok(())
+    }
+}
+
+/// Per-turn counter-intel reserve cost of holding an entry at each [`KnowledgeSecurityPosture`],
+/// charged by [`charge_posture_upkeep`]. Minimal and Standard are free by default.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct PostureUpkeep {
+    minimal: f32,
+    standard: f32,
+    hardened: f32,
+    black_vault: f32,
+}
+
+impl Default for PostureUpkeep {
+    fn default() -> Self {
+        Self {
+            minimal: 0.0,
+            standard: 0.0,
+            hardened: 0.25,
+            black_vault: 0.75,
+        }
+    }
+}
+
+impl PostureUpkeep {
+    fn upkeep(&self, posture: KnowledgeSecurityPosture) -> f32 {
+        match posture {
+            KnowledgeSecurityPosture::Minimal => self.minimal,
+            KnowledgeSecurityPosture::Standard => self.standard,
+            KnowledgeSecurityPosture::Hardened => self.hardened,
+            KnowledgeSecurityPosture::BlackVault => self.black_vault,
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        for (name, value) in [
+            ("minimal", self.minimal),
+            ("standard", self.standard),
+            ("hardened", self.hardened),
+            ("black_vault", self.black_vault),
+        ] {
+            if !value.is_finite() || value < 0.0 {
+                return Err(format!(
+                    "posture_upkeep.{name} must be finite and non-negative, got {value}"
+                ));
+            }
+        }
+        Ok(())
     }
 }
 
@@ -235,6 +367,43 @@ pub struct KnowledgeTimelineEvent {
     pub note: Option<String>,
 }
 
+/// Why [`KnowledgeLedger::set_security_posture`] refused a posture change.
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum PostureChangeError {
+    #[error("faction {faction} has no ledger entry for discovery {discovery_id}")]
+    UnknownEntry {
+        faction: FactionId,
+        discovery_id: u32,
+    },
+    #[error(
+        "faction {faction} cannot afford {posture:?} upkeep of {upkeep:.2} per turn (reserve {available:.2})"
+    )]
+    Unaffordable {
+        faction: FactionId,
+        posture: KnowledgeSecurityPosture,
+        upkeep: f32,
+        available: f32,
+    },
+}
+
+/// Which side of a [`KnowledgeLedger::compare`] row is further along.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiscoveryLeader {
+    A,
+    B,
+    Tied,
+}
+
+/// One discovery in a side-by-side ledger comparison. A `None` percent means that faction has no
+/// ledger entry for the discovery at all; it counts as 0% when picking the leader.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiscoveryComparison {
+    pub discovery_id: u32,
+    pub faction_a_percent: Option<u16>,
+    pub faction_b_percent: Option<u16>,
+    pub leader: DiscoveryLeader,
+}
+
 #[derive(Resource, Debug)]
 pub struct KnowledgeLedger {
     entries: HashMap<(FactionId, u32), KnowledgeLedgerEntry>,
@@ -416,6 +585,50 @@ impl KnowledgeLedger {
         true
     }
 
+    /// Move an existing entry to `posture`; the next [`knowledge_ledger_tick`] decays it at the new
+    /// half-life. `reserve` is the owner's counter-intel reserve and must cover one turn of the new
+    /// posture's upkeep. Logs a `CounterIntel` timeline event and returns the previous posture;
+    /// re-selecting the current posture changes nothing.
+    pub fn set_security_posture(
+        &mut self,
+        owner: FactionId,
+        discovery_id: u32,
+        posture: KnowledgeSecurityPosture,
+        reserve: Scalar,
+        tick: u64,
+    ) -> Result<KnowledgeSecurityPosture, PostureChangeError> {
+        let upkeep = self.config.posture_upkeep(posture);
+        let entry = self.entries.get_mut(&(owner, discovery_id)).ok_or(
+            PostureChangeError::UnknownEntry {
+                faction: owner,
+                discovery_id,
+            },
+        )?;
+        let previous = entry.security_posture;
+        if previous == posture {
+            return Ok(previous);
+        }
+        if reserve < upkeep {
+            return Err(PostureChangeError::Unaffordable {
+                faction: owner,
+                posture,
+                upkeep: upkeep.to_f32(),
+                available: reserve.to_f32(),
+            });
+        }
+        entry.security_posture = posture;
+        self.push_timeline_event(KnowledgeTimelineEvent {
+            tick,
+            kind: KnowledgeTimelineEventKind::CounterIntel,
+            source_faction: Some(owner),
+            delta_percent: None,
+            note: Some(format!(
+                "Discovery {discovery_id} posture {previous:?} -> {posture:?}"
+            )),
+        });
+        Ok(previous)
+    }
+
     pub fn remove_entry(
         &mut self,
         owner: FactionId,
@@ -441,6 +654,32 @@ impl KnowledgeLedger {
         self.entries.get(&(faction, discovery_id))
     }
 
+    /// Every discovery either faction holds, ordered by discovery id, with both sides' progress.
+    pub fn compare(&self, faction_a: FactionId, faction_b: FactionId) -> Vec<DiscoveryComparison> {
+        let mut rows: BTreeMap<u32, (Option<u16>, Option<u16>)> = BTreeMap::new();
+        for entry in self.entries_for_faction(faction_a) {
+            rows.entry(entry.discovery_id).or_default().0 = Some(entry.progress_percent);
+        }
+        for entry in self.entries_for_faction(faction_b) {
+            rows.entry(entry.discovery_id).or_default().1 = Some(entry.progress_percent);
+        }
+        rows.into_iter()
+            .map(|(discovery_id, (a, b))| {
+                let leader = match a.unwrap_or(0).cmp(&b.unwrap_or(0)) {
+                    std::cmp::Ordering::Greater => DiscoveryLeader::A,
+                    std::cmp::Ordering::Less => DiscoveryLeader::B,
+                    std::cmp::Ordering::Equal => DiscoveryLeader::Tied,
+                };
+                DiscoveryComparison {
+                    discovery_id,
+                    faction_a_percent: a,
+                    faction_b_percent: b,
+                    leader,
+                }
+            })
+            .collect()
+    }
+
     pub fn push_timeline_event(&mut self, event: KnowledgeTimelineEvent) {
         if self.timeline.len() >= self.max_timeline_events {
             self.timeline.pop_front();
@@ -479,6 +718,40 @@ impl KnowledgeLedger {
         }
     }
 
+    /// Each faction's own secrets within `early_warning_horizon_ticks` of cascading, most urgent
+    /// first. Secrets already common knowledge are past warning and skipped.
+    pub fn early_warnings(&self) -> Vec<KnowledgeEarlyWarningState> {
+        let horizon = self.config.early_warning_horizon_ticks();
+        let mut by_faction: BTreeMap<u32, Vec<KnowledgeEarlyWarningEntryState>> = BTreeMap::new();
+        for entry in self.entries.values() {
+            if entry.flags.contains(KnowledgeLeakFlags::COMMON_KNOWLEDGE)
+                || entry.time_to_cascade > horizon
+            {
+                continue;
+            }
+            by_faction.entry(entry.owner_faction.0).or_default().push(
+                KnowledgeEarlyWarningEntryState {
+                    discovery_id: entry.discovery_id,
+                    time_to_cascade: entry.time_to_cascade,
+                    progress_percent: entry.progress_percent,
+                },
+            );
+        }
+        by_faction
+            .into_iter()
+            .map(|(faction, mut entries)| {
+                entries.sort_by_key(|entry| {
+                    (
+                        entry.time_to_cascade,
+                        std::cmp::Reverse(entry.progress_percent),
+                        entry.discovery_id,
+                    )
+                });
+                KnowledgeEarlyWarningState { faction, entries }
+            })
+            .collect()
+    }
+
     pub fn snapshot_payload(&self) -> KnowledgeSnapshotPayload {
         let mut ledger_states: Vec<_> = self.entries.values().map(to_contract_entry).collect();
         ledger_states.sort_by_key(|state| (state.owner_faction, state.discovery_id));
@@ -492,6 +765,7 @@ impl KnowledgeLedger {
             entries: ledger_states,
             timeline: timeline_states,
             metrics,
+            early_warnings: self.early_warnings(),
         }
     }
 
@@ -523,6 +797,7 @@ pub struct KnowledgeSnapshotPayload {
     pub entries: Vec<KnowledgeLedgerEntryState>,
     pub timeline: Vec<KnowledgeTimelineEventState>,
     pub metrics: KnowledgeMetricsState,
+    pub early_warnings: Vec<KnowledgeEarlyWarningState>,
 }
 
 fn to_contract_entry(entry: &KnowledgeLedgerEntry) -> KnowledgeLedgerEntryState {
@@ -721,6 +996,60 @@ impl KnowledgeLedger {
     }
 }
 
+/// Charge every entry's posture upkeep against its owner's counter-intel reserve, in ledger-key
+/// order. An entry whose owner cannot pay lapses one step toward free: to Standard, or to Minimal
+/// if Standard itself carries upkeep. Runs after the reserve regenerates and before sweeps queue.
+pub fn charge_posture_upkeep(
+    tick: Res<SimulationTick>,
+    catalog: Res<EspionageCatalog>,
+    mut ledger: ResMut<KnowledgeLedger>,
+    mut budgets: ResMut<CounterIntelBudgets>,
+) {
+    let config = ledger.config();
+    let budget_config = catalog.config().counter_intel_budget().clone();
+    let mut keys: Vec<(FactionId, u32)> = ledger.entries.keys().copied().collect();
+    keys.sort_unstable();
+
+    let mut lapses: Vec<KnowledgeTimelineEvent> = Vec::new();
+    for key in keys {
+        let Some(entry) = ledger.entries.get_mut(&key) else {
+            continue;
+        };
+        let upkeep = config.posture_upkeep(entry.security_posture);
+        if upkeep <= Scalar::zero() {
+            continue;
+        }
+        let owner = entry.owner_faction;
+        if budgets.available(owner) >= upkeep {
+            budgets.adjust_reserve(owner, -upkeep, &budget_config);
+            continue;
+        }
+        let previous = entry.security_posture;
+        let fallback = match previous {
+            // Nothing is cheaper than Minimal; a priced Minimal simply goes unpaid.
+            KnowledgeSecurityPosture::Minimal => continue,
+            KnowledgeSecurityPosture::Standard => KnowledgeSecurityPosture::Minimal,
+            KnowledgeSecurityPosture::Hardened | KnowledgeSecurityPosture::BlackVault => {
+                KnowledgeSecurityPosture::Standard
+            }
+        };
+        entry.security_posture = fallback;
+        lapses.push(KnowledgeTimelineEvent {
+            tick: tick.0,
+            kind: KnowledgeTimelineEventKind::CounterIntel,
+            source_faction: Some(owner),
+            delta_percent: None,
+            note: Some(format!(
+                "Discovery {} posture {previous:?} lapsed to {fallback:?} (upkeep unpaid)",
+                entry.discovery_id
+            )),
+        });
+    }
+    for event in lapses {
+        ledger.push_timeline_event(event);
+    }
+}
+
 pub fn knowledge_ledger_tick(
     tick: Res<SimulationTick>,
     mut ledger: ResMut<KnowledgeLedger>,
@@ -733,7 +1062,9 @@ pub fn knowledge_ledger_tick(
 
     for entry in ledger.entries.values_mut() {
         let cfg = config.as_ref();
-        let base_half_life = entry.half_life_ticks.max(2) as i32;
+        let posture_multiplier = cfg.half_life_multiplier(entry.security_posture);
+        let base_half_life =
+            ((entry.half_life_ticks.max(2) as f32 * posture_multiplier).round() as i32).max(2);
         let modifier_half_life: i32 = entry
             .modifiers
             .iter()
@@ -956,6 +1287,112 @@ mod tests {
 
     use crate::{EspionageCatalog, SimulationMetrics, SimulationTick};
 
+    #[test]
+    fn compare_lists_union_of_discoveries_with_per_row_leader() {
+        let mut ledger = KnowledgeLedger::default();
+        let config = KnowledgeLedgerConfig::default();
+        let alpha = FactionId(1);
+        let beta = FactionId(2);
+        for (owner, discovery, percent) in [
+            (alpha, 10, 80),
+            (beta, 10, 35),
+            (alpha, 20, 15),
+            (beta, 20, 60),
+            (alpha, 30, 40),
+            (beta, 30, 40),
+            (beta, 40, 5),
+            (FactionId(9), 50, 100),
+        ] {
+            let mut entry = KnowledgeLedgerEntry::new(owner, discovery, &config);
+            entry.progress_percent = percent;
+            ledger.upsert_entry(entry);
+        }
+
+        let rows = ledger.compare(alpha, beta);
+        let summary: Vec<_> = rows
+            .iter()
+            .map(|row| {
+                (
+                    row.discovery_id,
+                    row.faction_a_percent,
+                    row.faction_b_percent,
+                    row.leader,
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (10, Some(80), Some(35), DiscoveryLeader::A),
+                (20, Some(15), Some(60), DiscoveryLeader::B),
+                (30, Some(40), Some(40), DiscoveryLeader::Tied),
+                (40, None, Some(5), DiscoveryLeader::B),
+            ]
+        );
+
+        let swapped = ledger.compare(beta, alpha);
+        assert_eq!(swapped[0].leader, DiscoveryLeader::B);
+        assert_eq!(swapped[3].faction_a_percent, Some(5));
+    }
+
+    #[test]
+    fn early_warnings_list_only_secrets_inside_the_horizon() {
+        let mut ledger = KnowledgeLedger::default();
+        let config = KnowledgeLedgerConfig::default();
+        let horizon = config.early_warning_horizon_ticks();
+        let owner = FactionId(4);
+        for (discovery, time_to_cascade, progress) in
+            [(11, horizon - 1, 92), (12, horizon + 10, 20)]
+        {
+            let mut entry = KnowledgeLedgerEntry::new(owner, discovery, &config);
+            entry.time_to_cascade = time_to_cascade;
+            entry.progress_percent = progress;
+            ledger.upsert_entry(entry);
+        }
+        let mut cascaded = KnowledgeLedgerEntry::new(owner, 13, &config);
+        cascaded.time_to_cascade = 0;
+        cascaded.progress_percent = 100;
+        cascaded.flags.insert(KnowledgeLeakFlags::COMMON_KNOWLEDGE);
+        ledger.upsert_entry(cascaded);
+        let mut other = KnowledgeLedgerEntry::new(FactionId(2), 11, &config);
+        other.time_to_cascade = 1;
+        ledger.upsert_entry(other);
+
+        let warnings = ledger.snapshot_payload().early_warnings;
+        assert_eq!(
+            warnings.iter().map(|w| w.faction).collect::<Vec<_>>(),
+            vec![2, 4]
+        );
+        assert_eq!(
+            warnings[1].entries,
+            vec![KnowledgeEarlyWarningEntryState {
+                discovery_id: 11,
+                time_to_cascade: horizon - 1,
+                progress_percent: 92,
+            }],
+            "only the near-cascade secret warns; the safe and cascaded ones do not"
+        );
+    }
+
+    #[test]
+    fn early_warnings_order_by_urgency() {
+        let mut ledger = KnowledgeLedger::default();
+        let config = KnowledgeLedgerConfig::default();
+        for (discovery, time_to_cascade, progress) in [(1, 3, 80), (2, 1, 60), (3, 3, 95)] {
+            let mut entry = KnowledgeLedgerEntry::new(FactionId(0), discovery, &config);
+            entry.time_to_cascade = time_to_cascade;
+            entry.progress_percent = progress;
+            ledger.upsert_entry(entry);
+        }
+
+        let order: Vec<u32> = ledger.early_warnings()[0]
+            .entries
+            .iter()
+            .map(|entry| entry.discovery_id)
+            .collect();
+        assert_eq!(order, vec![2, 3, 1]);
+    }
+
     #[test]
     fn record_probe_creates_entry() {
         let mut ledger = KnowledgeLedger::default();
@@ -1063,4 +1500,163 @@ mod tests {
             "common knowledge flag should stay set without re-adding cascade pending"
         );
     }
+
+    fn ticks_to_cascade(posture: KnowledgeSecurityPosture) -> u64 {
+        let mut app = App::new();
+        let config = KnowledgeLedgerConfigHandle::load_builtin().get();
+        let mut ledger = KnowledgeLedger::with_config(Arc::clone(&config));
+        let owner = FactionId(1);
+        let discovery = 7;
+        let mut entry = KnowledgeLedgerEntry::new(owner, discovery, config.as_ref());
+        entry.security_posture = posture;
+        ledger.upsert_entry(entry);
+
+        app.insert_resource(SimulationTick(0));
+        app.insert_resource(SimulationMetrics::default());
+        app.insert_resource(ledger);
+        app.insert_resource(EspionageCatalog::load_builtin().expect("catalog parses"));
+
+        for tick in 1..=200 {
+            app.world.resource_mut::<SimulationTick>().0 = tick;
+            app.world.run_system_once(knowledge_ledger_tick);
+            let ledger = app.world.resource::<KnowledgeLedger>();
+            let entry = ledger.entry(owner, discovery).expect("entry exists");
+            if entry.flags.contains(KnowledgeLeakFlags::COMMON_KNOWLEDGE) {
+                return tick;
+            }
+        }
+        panic!("{posture:?} entry never cascaded");
+    }
+
+    #[test]
+    fn stricter_postures_delay_the_cascade_and_black_vault_persists_longest() {
+        let minimal = ticks_to_cascade(KnowledgeSecurityPosture::Minimal);
+        let standard = ticks_to_cascade(KnowledgeSecurityPosture::Standard);
+        let hardened = ticks_to_cascade(KnowledgeSecurityPosture::Hardened);
+        let black_vault = ticks_to_cascade(KnowledgeSecurityPosture::BlackVault);
+
+        // Builtin half-life 10 × {0.75, 1.0, 1.5, 3.0} → ceil(100 / hl) progress per tick.
+        assert_eq!((minimal, standard, hardened, black_vault), (8, 10, 15, 25));
+        assert!(black_vault > hardened && hardened > standard && standard > minimal);
+    }
+
+    fn posture_test_app(postures: &[(u32, KnowledgeSecurityPosture)]) -> App {
+        let mut app = App::new();
+        let config = KnowledgeLedgerConfigHandle::load_builtin().get();
+        let mut ledger = KnowledgeLedger::with_config(Arc::clone(&config));
+        for (discovery, posture) in postures {
+            let mut entry = KnowledgeLedgerEntry::new(FactionId(1), *discovery, config.as_ref());
+            entry.security_posture = *posture;
+            ledger.upsert_entry(entry);
+        }
+        let catalog = EspionageCatalog::load_builtin().expect("catalog parses");
+        let budgets =
+            CounterIntelBudgets::new(&[FactionId(1)], catalog.config().counter_intel_budget());
+        app.insert_resource(SimulationTick(1));
+        app.insert_resource(SimulationMetrics::default());
+        app.insert_resource(ledger);
+        app.insert_resource(budgets);
+        app.insert_resource(catalog);
+        app
+    }
+
+    #[test]
+    fn posture_change_slows_decay_next_tick_and_logs_the_change() {
+        use KnowledgeSecurityPosture::{BlackVault, Standard};
+        let owner = FactionId(1);
+        let mut app = posture_test_app(&[(1, Standard), (2, Standard)]);
+
+        {
+            let mut ledger = app.world.resource_mut::<KnowledgeLedger>();
+            assert_eq!(
+                ledger.set_security_posture(owner, 2, BlackVault, Scalar::from_f32(4.0), 1),
+                Ok(Standard)
+            );
+            assert_eq!(
+                ledger.set_security_posture(owner, 9, BlackVault, Scalar::from_f32(4.0), 1),
+                Err(PostureChangeError::UnknownEntry {
+                    faction: owner,
+                    discovery_id: 9,
+                })
+            );
+            assert!(matches!(
+                ledger.set_security_posture(owner, 1, BlackVault, Scalar::from_f32(0.5), 1),
+                Err(PostureChangeError::Unaffordable { .. })
+            ));
+        }
+
+        app.world.run_system_once(knowledge_ledger_tick);
+
+        let ledger = app.world.resource::<KnowledgeLedger>();
+        // Builtin half-life 10: Standard leaks ceil(100 / 10), BlackVault ceil(100 / 30).
+        let progress = |discovery| ledger.entry(owner, discovery).unwrap().progress_percent;
+        assert_eq!((progress(1), progress(2)), (10, 4));
+        assert_eq!(ledger.entry(owner, 1).unwrap().security_posture, Standard);
+        let change = ledger
+            .timeline
+            .iter()
+            .find(|event| event.kind == KnowledgeTimelineEventKind::CounterIntel)
+            .expect("posture change is logged");
+        assert_eq!(change.source_faction, Some(owner));
+        assert_eq!(
+            change.note.as_deref(),
+            Some("Discovery 2 posture Standard -> BlackVault")
+        );
+    }
+
+    #[test]
+    fn unpaid_posture_upkeep_lapses_to_standard() {
+        use KnowledgeSecurityPosture::{BlackVault, Hardened, Standard};
+        let owner = FactionId(1);
+        let mut app = posture_test_app(&[(1, Hardened), (2, BlackVault)]);
+
+        // Builtin reserve 4.0 covers 0.25 + 0.75.
+        app.world.run_system_once(charge_posture_upkeep);
+        let available = app.world.resource::<CounterIntelBudgets>().available(owner);
+        assert!((available.to_f32() - 3.0).abs() < 1e-4, "{available:?}");
+
+        // 0.5 pays the Hardened entry (key order) and leaves the BlackVault one short.
+        let budget_config = app
+            .world
+            .resource::<EspionageCatalog>()
+            .config()
+            .counter_intel_budget()
+            .clone();
+        app.world.resource_mut::<CounterIntelBudgets>().set_reserve(
+            owner,
+            Scalar::from_f32(0.5),
+            &budget_config,
+        );
+        app.world.run_system_once(charge_posture_upkeep);
+
+        let ledger = app.world.resource::<KnowledgeLedger>();
+        assert_eq!(ledger.entry(owner, 1).unwrap().security_posture, Hardened);
+        assert_eq!(ledger.entry(owner, 2).unwrap().security_posture, Standard);
+        assert!(ledger.timeline.iter().any(|event| event
+            .note
+            .as_deref()
+            .is_some_and(|note| note.contains("lapsed to Standard"))));
+    }
+
+    #[test]
+    fn config_rejects_non_positive_posture_multipliers() {
+        for value in ["0.0", "-1.0"] {
+            let json =
+                format!(r#"{{"posture_half_life_multipliers": {{"black_vault": {value}}}}}"#);
+            let err = KnowledgeLedgerConfig::from_json_str(&json).expect_err("should reject");
+            assert!(err.to_string().contains("black_vault"), "{err}");
+        }
+        let config = KnowledgeLedgerConfig::from_json_str(
+            r#"{"posture_half_life_multipliers": {"hardened": 2.0}}"#,
+        )
+        .expect("partial table keeps defaults");
+        assert_eq!(
+            config.half_life_multiplier(KnowledgeSecurityPosture::Hardened),
+            2.0
+        );
+        assert_eq!(
+            config.half_life_multiplier(KnowledgeSecurityPosture::Standard),
+            1.0
+        );
+    }
 }
@@ -53,7 +53,10 @@ mod orders;
 pub mod port_alloc;
 mod power;
 mod provinces;
+mod region_inspection;
+mod replay_check;
 mod resources;
+mod rng_stream;
 mod scalar;
 mod sedentarization;
 mod sedentarization_config;
@@ -62,6 +65,8 @@ mod sites;
 mod sites_config;
 mod snapshot;
 mod snapshot_overlays_config;
+mod spatial_index;
+mod spawn_rules_config;
 mod start_profile;
 mod supply;
 mod supply_network_config;
@@ -74,6 +79,7 @@ mod visibility;
 mod visibility_config;
 mod visibility_systems;
 mod wellbeing_config;
+mod world_save;
 
 use std::sync::Arc;
 
@@ -104,35 +110,38 @@ pub use creatures_config::{
 pub use crisis::{
     ActiveCrisisLedger, CrisisGaugeSnapshot, CrisisMetricKind, CrisisMetricsSnapshot,
     CrisisOverlayCache, CrisisSeverityBand, CrisisTelemetry, CrisisTelemetrySample,
-    CrisisTrendSample,
+    CrisisThresholdEvent, CrisisTrendSample,
 };
 pub use crisis_config::{
     load_crisis_archetypes_from_env, load_crisis_modifiers_from_env,
     load_crisis_telemetry_config_from_env, CrisisArchetype, CrisisArchetypeCatalog,
     CrisisArchetypeCatalogHandle, CrisisArchetypeCatalogMetadata, CrisisModifier,
     CrisisModifierCatalog, CrisisModifierCatalogHandle, CrisisModifierCatalogMetadata,
+    CrisisModifierStacking, CrisisModifierStackingConfig, CrisisModifierStackingRule,
     CrisisTelemetryConfig, CrisisTelemetryConfigHandle, CrisisTelemetryConfigMetadata,
     CrisisTelemetryThreshold, BUILTIN_CRISIS_ARCHETYPES, BUILTIN_CRISIS_MODIFIERS,
-    BUILTIN_CRISIS_TELEMETRY_CONFIG,
+    BUILTIN_CRISIS_TELEMETRY_CONFIG, DEFAULT_CRISIS_ESCALATION_DEPTH,
 };
 pub use culture::{
     reconcile_culture_layers, CultureEffectsCache, CultureLayer, CultureLayerId, CultureLayerScope,
-    CultureManager, CultureOwner, CultureSchismEvent, CultureTensionEvent, CultureTensionKind,
-    CultureTensionRecord, CultureTraitAxis, CultureTraitVector, CULTURE_TRAIT_AXES,
+    CultureManager, CultureMergeError, CultureOwner, CultureSchismEvent, CultureTensionEvent,
+    CultureTensionKind, CultureTensionRecord, CultureTraitAxis, CultureTraitVector,
+    CULTURE_TRAIT_AXES,
 };
 pub use culture_corruption_config::{
-    CorruptionSeverityConfig, CultureCorruptionConfig, CultureCorruptionConfigHandle,
-    CultureSeverityConfig, CultureTensionTuning, BUILTIN_CULTURE_CORRUPTION_CONFIG,
+    CorruptionAuditConfig, CorruptionSeverityConfig, CultureCorruptionConfig,
+    CultureCorruptionConfigHandle, CultureSeverityConfig, CultureTensionTuning,
+    BUILTIN_CULTURE_CORRUPTION_CONFIG,
 };
 pub use demographics_config::{
     load_demographics_config_from_env, DemographicsConfig, DemographicsConfigHandle,
     DemographicsConfigMetadata,
 };
 pub use espionage::{
-    AgentAssignment, CounterIntelBudgets, EspionageAgentHandle, EspionageCatalog,
-    EspionageMissionId, EspionageMissionInstanceId, EspionageMissionKind, EspionageMissionState,
-    EspionageMissionTemplate, EspionageRoster, FactionSecurityPolicies, QueueMissionError,
-    QueueMissionParams, SecurityPolicy,
+    estimate_success, AgentAssignment, AgentExperienceTuning, CounterIntelBudgets,
+    EspionageAgentHandle, EspionageCatalog, EspionageMissionId, EspionageMissionInstanceId,
+    EspionageMissionKind, EspionageMissionState, EspionageMissionTemplate, EspionageRoster,
+    FactionSecurityPolicies, QueueMissionError, QueueMissionParams, SecurityPolicy,
 };
 pub use expedition_config::{
     load_expedition_config_from_env, ExpeditionConfig, ExpeditionConfigHandle,
@@ -144,8 +153,9 @@ pub use fauna::{
     hunt_policy_rate, hunt_provisions, hunt_source_yield_preview, pen_upkeep,
     project_arrivals_hunt, project_realized_hunt, quantise_animal_take, repopulate_fauna,
     spawn_initial_herds, AnimalTake, EcologyPhase, Herd, HerdDensityMap, HerdRegistry,
-    HerdTelemetry, HerdTelemetryEntry, RoamState, SourceYieldForecast, FODDERING_DISCOVERY_ID,
-    FULLY_HERDED, HERDING_DISCOVERY_ID, MSY_BIOMASS_FRACTION, PENNING_DISCOVERY_ID,
+    HerdTelemetry, HerdTelemetryEntry, RoamState, SourceYieldForecast, DEBUG_HERD_ID_PREFIX,
+    FODDERING_DISCOVERY_ID, FULLY_HERDED, HERDING_DISCOVERY_ID, MSY_BIOMASS_FRACTION,
+    PENNING_DISCOVERY_ID,
 };
 pub use fauna_config::{
     load_fauna_config_from_env, Diet, EcologyConfig, FaunaConfig, FaunaConfigHandle,
@@ -157,8 +167,8 @@ pub use flora_config::{
     FloraConfigMetadata, FloraDef, FloraRole, FloraShare, YieldVector, BUILTIN_FLORA_CONFIG,
 };
 pub use food::{
-    classify_food_module, classify_food_module_from_traits, FoodModule, FoodModuleTag,
-    FoodSiteKind, DEFAULT_HARVEST_TRAVEL_TILES_PER_TURN, DEFAULT_HARVEST_WORK_TURNS,
+    advance_food_seasons, classify_food_module, classify_food_module_from_traits, FoodModule,
+    FoodModuleTag, FoodSiteKind, DEFAULT_HARVEST_TRAVEL_TILES_PER_TURN, DEFAULT_HARVEST_WORK_TURNS,
 };
 pub use forage::{
     advance_cultivation, advance_forage_regrowth, commit_payoff, commit_yield_ratio,
@@ -171,20 +181,25 @@ pub use forage::{
     CANNOT_CLIMB_RATIO, CULTIVATION_DISCOVERY_ID, FULL_TILE_CONCENTRATION, NO_CONCENTRATION,
     NO_FORAGE_SEASON, SEED_SELECTION_DISCOVERY_ID,
 };
-pub use generations::{GenerationBias, GenerationId, GenerationProfile, GenerationRegistry};
+pub use generations::{
+    GenerationBias, GenerationId, GenerationProfile, GenerationRegistry,
+    GenerationSentimentContribution,
+};
 pub use graze::{advance_graze_regrowth, spawn_initial_graze, GrazePatch, GrazeRegistry};
 pub use great_discovery::{
     ConstellationRequirement, GreatDiscoveryCandidateEvent, GreatDiscoveryDefinition,
-    GreatDiscoveryEffectEvent, GreatDiscoveryEffectKind, GreatDiscoveryFlag, GreatDiscoveryId,
-    GreatDiscoveryLedger, GreatDiscoveryReadiness, GreatDiscoveryRegistry,
-    GreatDiscoveryResolvedEvent, GreatDiscoveryTelemetry, ObservationLedger,
+    GreatDiscoveryEffectAudit, GreatDiscoveryEffectEvent, GreatDiscoveryEffectKind,
+    GreatDiscoveryFlag, GreatDiscoveryId, GreatDiscoveryLedger, GreatDiscoveryReadiness,
+    GreatDiscoveryRegistry, GreatDiscoveryResolvedEvent, GreatDiscoveryTelemetry, LeakProfile,
+    ObservationLedger,
 };
-pub use hydrology::{generate_hydrology, HydrologyState};
+pub use hydrology::{generate_hydrology, update_river_flow, HydrologyState, Lake, RiverSegment};
 // The drainage-network measurement instrument (consumed by the `#[ignore]`d census test).
 pub use hydrology::{debug_drainage_census, DrainageCensus};
 pub use influencers::{
-    tick_influencers, InfluencerBalanceConfig, InfluencerConfigHandle, InfluencerCultureResonance,
-    InfluencerImpacts, InfluentialId, InfluentialRoster, SupportChannel, BUILTIN_INFLUENCER_CONFIG,
+    tick_influencers, InfluencerBalanceConfig, InfluencerConfigError, InfluencerConfigHandle,
+    InfluencerCultureResonance, InfluencerImpacts, InfluentialId, InfluentialRoster,
+    SupportChannel, BUILTIN_INFLUENCER_CONFIG,
 };
 pub use intensification::{
     knows, load_intensification_ladder_from_env, LadderConfig, LadderConfigHandle,
@@ -193,15 +208,17 @@ pub use intensification::{
     RUNG_COMPLETE, RUNG_TIMESCALE_UNSCALED, SITE_ACCEPTED,
 };
 pub use knowledge_ledger::{
-    CounterIntelSweepEvent, EspionageProbeEvent, KnowledgeCountermeasure, KnowledgeLedger,
-    KnowledgeLedgerConfig, KnowledgeLedgerConfigHandle, KnowledgeLedgerEntry, KnowledgeModifier,
-    KnowledgeTimelineEvent, BUILTIN_KNOWLEDGE_LEDGER_CONFIG,
+    CounterIntelSweepEvent, DiscoveryComparison, DiscoveryLeader, EspionageProbeEvent,
+    KnowledgeCountermeasure, KnowledgeLedger, KnowledgeLedgerConfig, KnowledgeLedgerConfigHandle,
+    KnowledgeLedgerEntry, KnowledgeModifier, KnowledgeTimelineEvent, PostureChangeError,
+    BUILTIN_KNOWLEDGE_LEDGER_CONFIG,
 };
 pub use labor_config::{
     load_labor_config_from_env, LaborConfig, LaborConfigHandle, LaborConfigMetadata,
     BUILTIN_LABOR_CONFIG, NO_FORAGE_CAPACITY,
 };
 pub use map_preset::{ErosionConfig, MapPreset, MapPresets, MapPresetsHandle, BUILTIN_MAP_PRESETS};
+pub use mapgen::{landmass_labels, suggest_settlement_sites, Landmass, LandmassStats};
 pub use sedentarization::{
     sedentarization_tick, SedentarizationEntry, SedentarizationScore, SedentarizationStage,
 };
@@ -223,14 +240,22 @@ pub use sites_config::{
 };
 pub use snapshot_overlays_config::{
     load_snapshot_overlays_config_from_env, CorruptionOverlayConfig, CultureOverlayConfig,
-    FogOverlayConfig, MilitaryOverlayConfig, SnapshotOverlaysConfig, SnapshotOverlaysConfigHandle,
+    FogOverlayConfig, MilitaryOverlayConfig, OverlayChannel, OverlayChannelsConfig,
+    OverlayPrecisionConfig, SnapshotOverlaysConfig, SnapshotOverlaysConfigHandle,
     SnapshotOverlaysConfigMetadata, BUILTIN_SNAPSHOT_OVERLAYS_CONFIG,
 };
+pub use spatial_index::{refresh_tile_spatial_index, TileSpatialIndex};
+pub use spawn_rules_config::{
+    load_spawn_rules_config_from_env, terrain_tag_from_name, SpawnRule, SpawnRulesConfig,
+    SpawnRulesConfigError, SpawnRulesConfigHandle, SpawnRulesConfigMetadata,
+    BUILTIN_SPAWN_RULES_CONFIG,
+};
 pub use start_profile::{
-    resolve_active_profile, snapshot_profiles, ActiveStartProfile, CampaignLabel, FogMode,
-    StartProfile, StartProfileKnowledgeTags, StartProfileKnowledgeTagsHandle,
-    StartProfileKnowledgeTagsMetadata, StartProfileLookup, StartProfileOverrides,
-    StartProfilesHandle, StartProfilesMetadata, StartingUnitSpec,
+    resolve_active_profile, snapshot_profiles, swap_active_profile, ActiveStartProfile,
+    CampaignLabel, FogMode, StartProfile, StartProfileKnowledgeTags,
+    StartProfileKnowledgeTagsHandle, StartProfileKnowledgeTagsMetadata, StartProfileLookup,
+    StartProfileOverrides, StartProfileSwapError, StartProfilesHandle, StartProfilesMetadata,
+    StartingUnitSpawnOutcome, StartingUnitSpawnReport, StartingUnitSpec,
 };
 pub use supply::{balance_supply_networks, SupplyNetworkMembership};
 pub use supply_network_config::{
@@ -244,7 +269,7 @@ pub use turn_pipeline_config::{
 };
 pub use victory::{
     load_victory_config_from_env, VictoryConfigHandle, VictoryModeId, VictoryModeKind,
-    VictoryModeState, VictoryState,
+    VictoryModeState, VictoryResult, VictoryState,
 };
 pub use visibility::{
     FactionVisibilityMap, TileVisibility, ViewerFaction, VisibilityLedger, VisibilitySource,
@@ -259,37 +284,50 @@ pub use wellbeing_config::{
     load_wellbeing_config_from_env, DiscontentConfig, MigrationConfig, ProductivityConfig,
     WellbeingConfig, WellbeingConfigHandle, WellbeingConfigMetadata, BUILTIN_WELLBEING_CONFIG,
 };
+pub use world_save::{load_world, restore_world, save_world, WorldSaveError, WORLD_SAVE_VERSION};
 
 pub use biome_palette::{BiomePalette, PALETTE_SEED_SALT};
 pub use climate::{climate_band_for_temperature, ClimateBand};
-pub use metrics::SimulationMetrics;
+pub use metrics::{
+    DetailedMetrics, Histogram, MetricAnomalies, SimulationMetrics, StageTiming, TurnTimingClock,
+    TurnTimings, METRIC_HISTOGRAM_BUCKETS,
+};
 pub use orders::{
-    FactionId, FactionOrders, FactionRegistry, Order, SubmitError, SubmitOutcome, TurnQueue,
+    FactionId, FactionOrders, FactionRegistry, Order, ScheduleError, ScheduledOrder, SubmitError,
+    SubmitOutcome, TurnQueue, DEFAULT_SCHEDULE_HORIZON,
 };
 pub use power::{
-    PowerDiscoveryEffects, PowerGridNodeTelemetry, PowerGridState, PowerIncident,
-    PowerIncidentSeverity, PowerNodeId, PowerTopology,
+    shed_load, LoadSheddingPolicy, PowerDiscoveryEffects, PowerGridNodeTelemetry, PowerGridState,
+    PowerIncident, PowerIncidentSeverity, PowerNodeId, PowerNodeKind, PowerTopology, SheddableLoad,
 };
 pub use provinces::{ProvinceId, ProvinceMap};
+pub use region_inspection::{inspect_region, OverlayStats, RegionInspection, RegionRect};
+pub use replay_check::{compare_runs, compare_runs_with};
 pub use resources::{
-    apply_port_base, apply_port_base_override, port_base_override, CapabilityFlags,
-    CommandEventEntry, CommandEventKind, CommandEventLog, CorruptionLedgers, CorruptionTelemetry,
-    DiplomacyLeverage, DiscoveryProgressLedger, FactionInventory, FogRevealLedger, FoodSiteEntry,
-    FoodSiteRegistry, HydrologyOverrides, MapTopology, PendingCrisisSeeds, PendingCrisisSpawns,
-    SentimentAxisBias, SimulationConfig, SimulationConfigMetadata, SimulationTick, StartLocation,
-    TileRegistry, TradeDiffusionRecord, TradeTelemetry, WorldEpoch,
-};
-pub use scalar::{scalar_from_f32, scalar_one, scalar_zero, Scalar};
+    apply_port_base, apply_port_base_override, calendar_at, difficulty_preset_override,
+    port_base_override, season_phase, AnomalyBound, CapabilityFlags, CommandEventEntry,
+    CommandEventKind, CommandEventLog, ConfigHash, CorruptionLedgers, CorruptionTelemetry,
+    CrisisContainmentConfig, CrisisContainmentOrder, CrisisFollowOn, DifficultyPreset,
+    DiplomacyLeverage, DiscoveryProgressLedger, DiscoveryThresholdScaling, FactionInventory,
+    FlowSummary, FogRevealLedger, FoodSeasonConfig, FoodSiteEntry, FoodSiteRegistry, GridDims,
+    HydrologyOverrides, MapTopology, MetricsAnomalyConfig, PendingCrisisContainment,
+    PendingCrisisSeeds, PendingCrisisSpawns, SentimentAxisBias, SimulationConfig,
+    SimulationConfigMetadata, SimulationTick, StartLocation, TileRegistry, TradeDiffusionRecord,
+    TradeFlowRecord, TradeTelemetry, TurnWarnings, WorldEpoch, BUILTIN_SIMULATION_CONFIG,
+};
+pub use scalar::{
+    bulk_clamp, bulk_normalize, bulk_scale, scalar_from_f32, scalar_one, scalar_zero, Scalar,
+};
 pub use snapshot::{
-    command_events_to_state, recapture_snapshot_in_place, restore_world_from_snapshot,
-    SnapshotHistory, StoredSnapshot,
+    command_events_to_state, load_snapshot_dir, recapture_snapshot_in_place, refresh_config_hash,
+    restore_world_from_snapshot, snapshot_cadence_due, SnapshotHistory, StoredSnapshot,
 };
 pub use systems::spawn_initial_world;
 pub use systems::{
     advance_band_movement, advance_expeditions, advance_labor_allocation,
     expedition_take_provisions, hunt_per_worker_provisions, hunt_take, hunt_trip_forecast,
     output_multiplier, simulate_power, HuntTripForecast, MigrationKnowledgeEvent, PowerSimParams,
-    TradeDiffusionEvent,
+    TileChangedEvent, TileField, TradeDiffusionEvent,
 };
 pub use systems::{apply_biome_palette_clamp, apply_tag_budget_solver, reconcile_coastal_shelf};
 pub use telling::{
@@ -300,8 +338,9 @@ pub use telling::{
     SignalSample, WardrobeEntry, TELLING_SEED_SALT,
 };
 pub use terrain::{
-    biome_must_have, biome_niche, classify_terrain, terrain_definition, terrain_for_position,
-    BathymetryContext, BiomeNiche, MovementProfile, TerrainDefinition, TerrainResourceBias,
+    biome_must_have, biome_niche, classify_terrain, resource_profile, terrain_definition,
+    terrain_for_position, BathymetryContext, BiomeNiche, MovementProfile, ResourceProfile,
+    TerrainDefinition, TerrainResourceBias,
 };
 
 #[derive(SystemSet, Debug, Hash, PartialEq, Eq, Clone)]
@@ -322,11 +361,54 @@ pub enum TurnStage {
     Snapshot,
 }
 
+impl TurnStage {
+    /// Every stage, in pipeline order.
+    pub const ALL: [TurnStage; 11] = [
+        TurnStage::Influence,
+        TurnStage::Logistics,
+        TurnStage::Knowledge,
+        TurnStage::GreatDiscovery,
+        TurnStage::Population,
+        TurnStage::Visibility,
+        TurnStage::Crisis,
+        TurnStage::Telling,
+        TurnStage::Finalize,
+        TurnStage::Victory,
+        TurnStage::Snapshot,
+    ];
+
+    /// Stable snake_case name, used as the `stage` metrics label.
+    pub fn label(&self) -> &'static str {
+        match self {
+            TurnStage::Influence => "influence",
+            TurnStage::Logistics => "logistics",
+            TurnStage::Knowledge => "knowledge",
+            TurnStage::GreatDiscovery => "great_discovery",
+            TurnStage::Population => "population",
+            TurnStage::Visibility => "visibility",
+            TurnStage::Crisis => "crisis",
+            TurnStage::Telling => "telling",
+            TurnStage::Finalize => "finalize",
+            TurnStage::Victory => "victory",
+            TurnStage::Snapshot => "snapshot",
+        }
+    }
+}
+
 /// Construct a Bevy [`App`] configured with the Shadow-Scale turn pipeline.
 pub fn build_headless_app() -> App {
+    let (config, config_metadata) = resources::load_simulation_config_from_env();
+    build_headless_app_with_config(config, config_metadata)
+}
+
+/// [`build_headless_app`] around a config the caller already holds (a world save's, say) instead
+/// of the environment's. Start-profile overrides are still resolved from the profile catalog.
+pub fn build_headless_app_with_config(
+    mut config: SimulationConfig,
+    config_metadata: SimulationConfigMetadata,
+) -> App {
     let mut app = App::new();
 
-    let (mut config, config_metadata) = resources::load_simulation_config_from_env();
     let (map_presets, map_presets_metadata) = load_map_presets_from_env();
     let victory_config = load_victory_config_from_env();
     let preset_count = map_presets.len();
@@ -381,7 +463,10 @@ pub fn build_headless_app() -> App {
     let profile_lookup = StartProfileLookup::new(active_profile.id.clone());
 
     let faction_registry = orders::FactionRegistry::default();
-    let turn_queue = orders::TurnQueue::new(faction_registry.factions.clone());
+    let turn_queue = orders::TurnQueue::new(faction_registry.factions.clone())
+        .with_schedule_horizon(config.order_schedule_horizon)
+        .with_order_rate_limit(config.max_orders_per_turn)
+        .with_order_shuffle(config.shuffle_turn_order);
     let snapshot_history = SnapshotHistory::with_capacity(config.snapshot_history_limit.max(1));
     let generation_registry = GenerationRegistry::with_seed(0xC0FEBABE, 6);
     let influencer_config = Arc::new(
@@ -462,6 +547,9 @@ pub fn build_headless_app() -> App {
         supply_network_config::load_supply_network_config_from_env();
     let supply_network_handle =
         supply_network_config::SupplyNetworkConfigHandle::new(supply_network_config);
+    let (spawn_rules_config, spawn_rules_metadata) =
+        spawn_rules_config::load_spawn_rules_config_from_env();
+    let spawn_rules_handle = spawn_rules_config::SpawnRulesConfigHandle::new(spawn_rules_config);
     let (wellbeing_config, wellbeing_metadata) = wellbeing_config::load_wellbeing_config_from_env();
     let wellbeing_handle = wellbeing_config::WellbeingConfigHandle::new(wellbeing_config);
     let culture_effects = CultureEffectsCache::default();
@@ -478,6 +566,9 @@ pub fn build_headless_app() -> App {
         espionage::SecurityPolicy::Standard,
     );
 
+    // Fingerprint the config as built, start-profile overrides resolved.
+    let config_hash = ConfigHash(config.config_hash());
+
     app.insert_resource(config)
         .insert_resource(config_metadata)
         .insert_resource(MapPresetsHandle::new(map_presets.clone()))
@@ -499,8 +590,10 @@ pub fn build_headless_app() -> App {
         // Default epoch (0) so `capture_snapshot` always finds the resource. The server overwrites
         // it with the live counter on every world (re)build; the idle boot app never captures.
         .insert_resource(WorldEpoch::default())
+        .insert_resource(config_hash)
         .insert_resource(CapabilityFlags::default())
         .insert_resource(SimulationMetrics::default())
+        .insert_resource(TurnTimingClock::default())
         .insert_resource(crisis_telemetry_resource)
         .insert_resource(SentimentAxisBias::default())
         .insert_resource(knowledge_config_handle)
@@ -545,6 +638,8 @@ pub fn build_headless_app() -> App {
         .insert_resource(demographics_metadata)
         .insert_resource(supply_network_handle)
         .insert_resource(supply_network_metadata)
+        .insert_resource(spawn_rules_handle)
+        .insert_resource(spawn_rules_metadata)
         .insert_resource(wellbeing_handle)
         .insert_resource(wellbeing_metadata)
         .insert_resource(supply::SupplyNetworkMembership::default())
@@ -592,17 +687,23 @@ pub fn build_headless_app() -> App {
         .insert_resource(PowerDiscoveryEffects::default())
         .insert_resource(PendingCrisisSeeds::default())
         .insert_resource(PendingCrisisSpawns::default())
+        .insert_resource(PendingCrisisContainment::default())
+        .insert_resource(TurnWarnings::default())
+        .insert_resource(TileSpatialIndex::default())
+        .insert_resource(StartingUnitSpawnReport::default())
         .insert_resource(faction_registry)
         .insert_resource(turn_queue)
         .add_event::<CultureTensionEvent>()
         .add_event::<CultureSchismEvent>()
         .add_event::<systems::TradeDiffusionEvent>()
+        .add_event::<systems::TileChangedEvent>()
         .add_event::<systems::MigrationKnowledgeEvent>()
         .add_event::<EspionageProbeEvent>()
         .add_event::<CounterIntelSweepEvent>()
         .add_event::<GreatDiscoveryCandidateEvent>()
         .add_event::<GreatDiscoveryResolvedEvent>()
         .add_event::<great_discovery::GreatDiscoveryEffectEvent>()
+        .add_event::<CrisisThresholdEvent>()
         .add_plugins(MinimalPlugins)
         .configure_sets(
             Update,
@@ -625,6 +726,7 @@ pub fn build_headless_app() -> App {
             Startup,
             (
                 systems::spawn_initial_world,
+                systems::verify_starting_units,
                 systems::apply_starting_inventory_effects,
                 hydrology::generate_hydrology,
                 systems::apply_tag_budget_solver,
@@ -638,6 +740,12 @@ pub fn build_headless_app() -> App {
             )
                 .chain(),
         )
+        .add_systems(
+            Update,
+            refresh_tile_spatial_index
+                .in_set(TurnStage::Influence)
+                .before(tick_influencers),
+        )
         .add_systems(
             Update,
             (
@@ -653,6 +761,8 @@ pub fn build_headless_app() -> App {
             Update,
             (
                 systems::simulate_materials,
+                hydrology::update_river_flow,
+                food::advance_food_seasons,
                 systems::simulate_logistics,
                 advance_herds,
                 advance_herd_grazing,
@@ -677,6 +787,7 @@ pub fn build_headless_app() -> App {
             Update,
             (
                 espionage::refresh_counter_intel_budgets,
+                knowledge_ledger::charge_posture_upkeep,
                 espionage::schedule_counter_intel_missions,
                 espionage::resolve_espionage_missions,
                 knowledge_ledger::process_espionage_events,
@@ -758,6 +869,7 @@ pub fn build_headless_app() -> App {
             (
                 systems::simulate_power,
                 systems::process_corruption,
+                systems::update_diplomacy_leverage,
                 systems::decay_fog_reveals,
             )
                 .chain()
@@ -771,7 +883,8 @@ pub fn build_headless_app() -> App {
             (
                 metrics::collect_metrics,
                 systems::advance_tick,
-                snapshot::capture_snapshot,
+                snapshot::refresh_config_hash,
+                snapshot::capture_snapshot.run_if(snapshot::snapshot_cadence_due),
             )
                 .chain()
                 .in_set(TurnStage::Snapshot),
@@ -779,6 +892,25 @@ pub fn build_headless_app() -> App {
 
     app.add_systems(Update, victory::victory_tick.in_set(TurnStage::Victory));
 
+    // Stage timing probes sit between the chained stages; they are no-ops unless
+    // `turn_stage_timing` is set.
+    app.add_systems(
+        Update,
+        metrics::begin_turn_timing.before(TurnStage::Influence),
+    );
+    for pair in TurnStage::ALL.windows(2) {
+        app.add_systems(
+            Update,
+            metrics::lap_turn_stage(pair[0].clone())
+                .after(pair[0].clone())
+                .before(pair[1].clone()),
+        );
+    }
+    app.add_systems(
+        Update,
+        metrics::finish_turn_timing.after(TurnStage::Snapshot),
+    );
+
     {
         // Log chosen map preset id; worldgen consumes later.
         if let Some(preset) = map_presets.get(
@@ -799,27 +931,140 @@ pub fn build_headless_app() -> App {
                 "mapgen.preset.missing_using_first"
             );
         }
-        let mut registry = app.world.resource_mut::<GreatDiscoveryRegistry>();
-        let loaded = registry
-            .load_catalog_from_str(great_discovery::BUILTIN_GREAT_DISCOVERY_CATALOG)
-            .expect("Great Discovery catalog should parse");
-        tracing::info!(
-            target: "shadow_scale::great_discovery",
-            loaded_definitions = loaded,
-            "great_discovery.catalog.loaded"
-        );
+        load_great_discovery_catalog(&mut app.world);
     }
 
     app
 }
 
+/// Replace the [`GreatDiscoveryRegistry`] with the builtin catalog, observation thresholds scaled
+/// to the configured map (`SimulationConfig::great_discovery_threshold_scaling` over
+/// `grid_size`). [`build_headless_app`] calls this; call it again after swapping in a config with
+/// a different `grid_size`.
+pub fn load_great_discovery_catalog(world: &mut World) {
+    let config = world.resource::<resources::SimulationConfig>();
+    let scaling = config.great_discovery_threshold_scaling;
+    let tiles = config.grid_size.x.saturating_mul(config.grid_size.y);
+    let mut registry = GreatDiscoveryRegistry::default();
+    let loaded = registry
+        .load_catalog_for_map(
+            great_discovery::BUILTIN_GREAT_DISCOVERY_CATALOG,
+            &scaling,
+            tiles,
+        )
+        .expect("Great Discovery catalog should parse");
+    world.insert_resource(registry);
+    tracing::info!(
+        target: "shadow_scale::great_discovery",
+        loaded_definitions = loaded,
+        tiles,
+        threshold_scaling = scaling.enabled,
+        "great_discovery.catalog.loaded"
+    );
+}
+
+/// Summary of a single [`run_turn`] call, aggregated from resources the turn already updates.
+#[derive(Debug, Clone, Default)]
+pub struct TurnReport {
+    /// Tick that was resolved (the value of [`SimulationTick`] before the turn advanced it).
+    pub tick: u64,
+    /// Set when a victory was declared during this turn.
+    pub winner: Option<VictoryResult>,
+    pub new_crises: usize,
+    pub new_great_discoveries: usize,
+    /// Entries pushed to the [`CommandEventLog`] during the turn.
+    pub new_events: usize,
+    /// Non-fatal problems collected in [`TurnWarnings`] while the turn resolved.
+    pub warnings: Vec<String>,
+    /// Order the factions' orders resolved in this turn ([`orders::TurnQueue::resolution_order`]);
+    /// empty when the app has no turn queue.
+    pub faction_order: Vec<FactionId>,
+}
+
+impl TurnReport {
+    pub fn winner_declared(&self) -> bool {
+        self.winner.is_some()
+    }
+}
+
+#[derive(Default)]
+struct TurnCounters {
+    tick: u64,
+    winner_declared: bool,
+    crises: usize,
+    great_discoveries: usize,
+    events: u64,
+}
+
+impl TurnCounters {
+    fn read(world: &World) -> Self {
+        Self {
+            tick: world
+                .get_resource::<SimulationTick>()
+                .map(|tick| tick.0)
+                .unwrap_or(0),
+            winner_declared: world
+                .get_resource::<VictoryState>()
+                .is_some_and(|state| state.winner.is_some()),
+            crises: world
+                .get_resource::<ActiveCrisisLedger>()
+                .map(|ledger| ledger.len())
+                .unwrap_or(0),
+            great_discoveries: world
+                .get_resource::<GreatDiscoveryLedger>()
+                .map(|ledger| ledger.records().len())
+                .unwrap_or(0),
+            events: world
+                .get_resource::<CommandEventLog>()
+                .map(|log| log.pushed_total())
+                .unwrap_or(0),
+        }
+    }
+}
+
 /// Execute a single simulation turn.
 ///
 /// Each call processes the chained systems configured in [`build_headless_app`]
 /// (materials → logistics → population → power → tick increment → snapshot).
 /// Callers are responsible for snapshot broadcasting and command handling.
-pub fn run_turn(app: &mut App) {
+pub fn run_turn(app: &mut App) -> TurnReport {
+    if let Some(mut warnings) = app.world.get_resource_mut::<TurnWarnings>() {
+        warnings.clear();
+    }
+    let before = TurnCounters::read(&app.world);
+    let faction_order = app
+        .world
+        .get_resource::<orders::TurnQueue>()
+        .map(|queue| queue.resolution_order(app.world.resource::<SimulationConfig>().map_seed))
+        .unwrap_or_default();
+
     app.update();
+
+    let after = TurnCounters::read(&app.world);
+    let winner = if !before.winner_declared && after.winner_declared {
+        app.world
+            .get_resource::<VictoryState>()
+            .and_then(|state| state.winner.clone())
+    } else {
+        None
+    };
+    let warnings = app
+        .world
+        .get_resource_mut::<TurnWarnings>()
+        .map(|mut warnings| warnings.drain())
+        .unwrap_or_default();
+
+    TurnReport {
+        tick: before.tick,
+        winner,
+        new_crises: after.crises.saturating_sub(before.crises),
+        new_great_discoveries: after
+            .great_discoveries
+            .saturating_sub(before.great_discoveries),
+        new_events: after.events.saturating_sub(before.events) as usize,
+        warnings,
+        faction_order,
+    }
 }
 
 fn capability_enabled(flags: CapabilityFlags) -> impl FnMut(Res<CapabilityFlags>) -> bool {
@@ -28,6 +28,11 @@ pub struct MapPreset {
     #[serde(default)]
     pub map_seed: Option<u64>,
     pub dimensions: MapPresetDimensions,
+    /// Land-mask threshold on the normalized 0..1 heightfield: a tile is land iff its elevation
+    /// exceeds it, and the coastal ring, shelf and `WATER`/`COASTAL` tags are all derived from
+    /// that mask. With `erosion.anchor_contour_to_sea_level` on (every shipped preset) the field
+    /// is rescaled so the `macro_land.target_land_pct` quantile sits exactly here — so this sets
+    /// the *height* of the coastline, and `target_land_pct` sets the ocean *fraction*.
     pub sea_level: f32,
     pub continent_scale: f32,
     pub mountain_scale: f32,
@@ -91,6 +96,10 @@ pub struct MapPreset {
     /// 1- or 2-hex navigable dead-end is not a waterway.
     #[serde(default = "default_river_navigable_min_hexes")]
     pub river_navigable_min_hexes: usize,
+    /// How deep a closed depression must be, from its spill point down to its lowest hex, before
+    /// hydrology records it as a lake. Shallower hollows are fill noise, not standing water.
+    #[serde(default = "default_lake_min_depth")]
+    pub lake_min_depth: f32,
 
     #[serde(default)]
     pub macro_land: MacroLandConfig,
@@ -489,6 +498,12 @@ pub(crate) const fn default_river_navigable_min_hexes() -> usize {
     3
 }
 
+/// Half the ~1e-2 relief of real terrain and 10× `river_flat_jitter`: a hollow the tie-break jitter
+/// could have dug is never a lake.
+pub(crate) const fn default_lake_min_depth() -> f32 {
+    5.0e-3
+}
+
 /// Stream-power fluvial erosion on the **base heightfield**, run at the end of
 /// `heightfield::build_elevation_field` — i.e. *before* the land mask, which is what makes it
 /// work: `mapgen::generate_land_mask` ranks tiles by elevation, so the coastline is an
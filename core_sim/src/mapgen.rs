@@ -3,8 +3,11 @@ use std::{cmp::Ordering, collections::VecDeque};
 use bevy::prelude::*;
 use rand::{rngs::SmallRng, seq::SliceRandom, Rng, SeedableRng};
 
+use sim_runtime::TerrainTags;
+
 use crate::{
-    grid_utils::{hex_neighbors_wrapped, neighbors4_wrapped},
+    components::Tile,
+    grid_utils::{hex_distance_wrapped, hex_neighbors_wrapped, neighbors4_wrapped},
     heightfield::ElevationField,
     map_preset::{BiomeTransitionConfig, IslandConfig, MacroLandConfig, OceanConfig, ShelfConfig},
 };
@@ -2423,6 +2426,228 @@ fn apply_coastal_smoothing(
     }
 }
 
+/// One connected landmass found by [`landmass_labels`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Landmass {
+    /// Label, assigned in row-major order of each landmass's first tile.
+    pub id: u32,
+    pub tile_count: u32,
+    /// Inclusive bounding box in grid coordinates. A landmass spanning the horizontal wrap seam
+    /// reports the raw column extent, so its box can cover the full map width.
+    pub min: UVec2,
+    pub max: UVec2,
+}
+
+/// Post-worldgen landmass census: how many distinct islands/continents a map has and their sizes,
+/// for validating a preset.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LandmassStats {
+    /// Per-tile landmass id in row-major order (`y * width + x`); `None` for water or missing tiles.
+    /// Doubles as a landmass-id overlay.
+    pub labels: Vec<Option<u32>>,
+    /// Every landmass, indexed by `id`.
+    pub landmasses: Vec<Landmass>,
+}
+
+impl LandmassStats {
+    pub fn count(&self) -> usize {
+        self.landmasses.len()
+    }
+
+    /// The landmass with the most tiles (lowest id on a tie).
+    pub fn largest(&self) -> Option<&Landmass> {
+        self.landmasses
+            .iter()
+            .min_by_key(|mass| (std::cmp::Reverse(mass.tile_count), mass.id))
+    }
+
+    /// The landmass with the fewest tiles (lowest id on a tie).
+    pub fn smallest(&self) -> Option<&Landmass> {
+        self.landmasses
+            .iter()
+            .min_by_key(|mass| (mass.tile_count, mass.id))
+    }
+}
+
+/// Label connected components of land (tiles without `TerrainTags::WATER`) over the odd-r hex
+/// neighbourhood, honouring horizontal wrap. Tiles may arrive in any order; positions outside
+/// `grid` are ignored.
+pub fn landmass_labels(tiles: &[Tile], grid: UVec2, wrap_horizontal: bool) -> LandmassStats {
+    let width = grid.x as usize;
+    let height = grid.y as usize;
+    let mut land = vec![false; width * height];
+    for tile in tiles {
+        let (x, y) = (tile.position.x as usize, tile.position.y as usize);
+        if x < width && y < height {
+            land[y * width + x] = !tile.terrain_tags.contains(TerrainTags::WATER);
+        }
+    }
+
+    let mut labels: Vec<Option<u32>> = vec![None; width * height];
+    let mut landmasses = Vec::new();
+    let mut queue = VecDeque::new();
+    for start in 0..land.len() {
+        if !land[start] || labels[start].is_some() {
+            continue;
+        }
+        let id = landmasses.len() as u32;
+        let origin = UVec2::new((start % width) as u32, (start / width) as u32);
+        let mut mass = Landmass {
+            id,
+            tile_count: 0,
+            min: origin,
+            max: origin,
+        };
+        labels[start] = Some(id);
+        queue.push_back(start);
+        while let Some(idx) = queue.pop_front() {
+            let pos = UVec2::new((idx % width) as u32, (idx / width) as u32);
+            mass.tile_count += 1;
+            mass.min = mass.min.min(pos);
+            mass.max = mass.max.max(pos);
+            for (nx, ny) in hex_neighbors_wrapped(pos.x, pos.y, grid.x, grid.y, wrap_horizontal) {
+                let n = ny as usize * width + nx as usize;
+                if land[n] && labels[n].is_none() {
+                    labels[n] = Some(id);
+                    queue.push_back(n);
+                }
+            }
+        }
+        landmasses.push(mass);
+    }
+
+    LandmassStats { labels, landmasses }
+}
+
+/// Settlement-site score for a tile that *is* freshwater (a delta or floodplain) or has a river
+/// running along one of its sides.
+const SITE_FRESHWATER_ON_TILE: f32 = 3.0;
+/// ... or, failing that, borders a freshwater hex (lake shore, river bank across a channel).
+const SITE_FRESHWATER_ADJACENT: f32 = 2.0;
+/// Extra for a freshwater site that also touches salt water: the river mouth.
+const SITE_RIVER_MOUTH: f32 = 2.0;
+const SITE_COASTAL: f32 = 1.0;
+const SITE_FERTILE: f32 = 2.0;
+/// Scaled by the share of fertile neighbours — a fertile tile in a fertile plain beats a lone one.
+const SITE_FERTILE_NEIGHBOURHOOD: f32 = 1.0;
+/// Scaled by how far the tile rises above its neighbours' mean (normalized above-sea height).
+const SITE_DEFENSIBILITY: f32 = 4.0;
+const SITE_HOSTILE_PENALTY: f32 = 2.0;
+
+/// Suggest up to `count` settlement sites on a generated map, best first, as `(x, y)`.
+///
+/// Every land tile is scored on freshwater (on the tile, beside it, and the river-mouth bonus when
+/// it also meets salt water), fertility (its own `FERTILE` tag and its neighbourhood's), coast
+/// access, and defensibility (how far it stands above its neighbours on `elevation`); `ARID`,
+/// `POLAR`, `HAZARDOUS` and mountain tiles are marked down. Sites are picked greedily by score
+/// (row-major on ties) and no two are hex-adjacent, so the list spreads across the good ground
+/// instead of naming one delta six times. Only tiles with a positive score are suggested, so the
+/// result may be shorter than `count`. A designer aid for `StartProfile` placement; the sim does
+/// not read it.
+pub fn suggest_settlement_sites(
+    tiles: &[Tile],
+    elevation: &ElevationField,
+    wrap_horizontal: bool,
+    count: usize,
+) -> Vec<(u32, u32)> {
+    let (width, height) = (elevation.width, elevation.height);
+    let mut grid: Vec<Option<&Tile>> = vec![None; (width * height) as usize];
+    for tile in tiles {
+        let UVec2 { x, y } = tile.position;
+        if x < width && y < height {
+            grid[(y * width + x) as usize] = Some(tile);
+        }
+    }
+
+    let mut scored: Vec<(f32, UVec2)> = Vec::new();
+    for tile in grid.iter().flatten() {
+        if tile.terrain_tags.contains(TerrainTags::WATER) {
+            continue;
+        }
+        let pos = tile.position;
+        let neighbours: Vec<&Tile> =
+            hex_neighbors_wrapped(pos.x, pos.y, width, height, wrap_horizontal)
+                .filter_map(|(nx, ny)| grid[(ny * width + nx) as usize])
+                .collect();
+        let tags = tile.terrain_tags;
+        let any_neighbour =
+            |wanted: TerrainTags| neighbours.iter().any(|n| n.terrain_tags.contains(wanted));
+
+        let mut score = 0.0;
+        let freshwater = if tags.contains(TerrainTags::FRESHWATER) || tile.has_any_river_edge() {
+            score += SITE_FRESHWATER_ON_TILE;
+            true
+        } else if any_neighbour(TerrainTags::FRESHWATER) {
+            score += SITE_FRESHWATER_ADJACENT;
+            true
+        } else {
+            false
+        };
+        let salt_water = neighbours.iter().any(|n| {
+            n.terrain_tags.contains(TerrainTags::WATER)
+                && !n.terrain_tags.contains(TerrainTags::FRESHWATER)
+        });
+        if freshwater && salt_water {
+            score += SITE_RIVER_MOUTH;
+        }
+        if salt_water || tags.contains(TerrainTags::COASTAL) {
+            score += SITE_COASTAL;
+        }
+        if tags.contains(TerrainTags::FERTILE) {
+            score += SITE_FERTILE;
+        }
+        if !neighbours.is_empty() {
+            let fertile = neighbours
+                .iter()
+                .filter(|n| n.terrain_tags.contains(TerrainTags::FERTILE))
+                .count();
+            score += SITE_FERTILE_NEIGHBOURHOOD * fertile as f32 / neighbours.len() as f32;
+
+            let here = elevation.above_sea_normalized(pos.x, pos.y);
+            let around = neighbours
+                .iter()
+                .map(|n| elevation.above_sea_normalized(n.position.x, n.position.y))
+                .sum::<f32>()
+                / neighbours.len() as f32;
+            score += SITE_DEFENSIBILITY * (here - around).max(0.0);
+        }
+        for hostile in [
+            TerrainTags::ARID,
+            TerrainTags::POLAR,
+            TerrainTags::HAZARDOUS,
+        ] {
+            if tags.contains(hostile) {
+                score -= SITE_HOSTILE_PENALTY;
+            }
+        }
+        if tile.mountain.is_some() {
+            score -= SITE_HOSTILE_PENALTY;
+        }
+        if score > 0.0 {
+            scored.push((score, pos));
+        }
+    }
+
+    scored.sort_by(|a, b| {
+        b.0.partial_cmp(&a.0)
+            .unwrap_or(Ordering::Equal)
+            .then_with(|| (a.1.y, a.1.x).cmp(&(b.1.y, b.1.x)))
+    });
+    let mut picked: Vec<UVec2> = Vec::with_capacity(count);
+    for (_, pos) in scored {
+        if picked.len() == count {
+            break;
+        }
+        if picked
+            .iter()
+            .all(|&other| hex_distance_wrapped(pos, other, width, wrap_horizontal) > 1)
+        {
+            picked.push(pos);
+        }
+    }
+    picked.into_iter().map(|pos| (pos.x, pos.y)).collect()
+}
+
 pub fn validate_bands(bands: &BandsResult, grid: UVec2) {
     let w = grid.x as usize;
     let h = grid.y as usize;
@@ -2520,6 +2745,111 @@ mod tests {
     };
     use bevy::math::UVec2;
 
+    fn land_tile(x: u32, y: u32) -> Tile {
+        Tile {
+            position: UVec2::new(x, y),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn landmass_labels_separates_two_islands() {
+        let grid = UVec2::new(10, 6);
+        let mut tiles = Vec::new();
+        for y in 0..grid.y {
+            for x in 0..grid.x {
+                let mut tile = land_tile(x, y);
+                tile.terrain_tags = TerrainTags::WATER;
+                tiles.push(tile);
+            }
+        }
+        let island_a = [(1, 1), (2, 1), (1, 2), (2, 2)];
+        let island_b = [(6, 3), (7, 3), (8, 3), (7, 4), (8, 4), (6, 4)];
+        for &(x, y) in island_a.iter().chain(island_b.iter()) {
+            tiles[(y * grid.x + x) as usize].terrain_tags = TerrainTags::empty();
+        }
+
+        let stats = landmass_labels(&tiles, grid, false);
+        assert_eq!(stats.count(), 2);
+        assert_eq!(stats.landmasses[0].tile_count, island_a.len() as u32);
+        assert_eq!(stats.landmasses[0].min, UVec2::new(1, 1));
+        assert_eq!(stats.landmasses[0].max, UVec2::new(2, 2));
+        assert_eq!(stats.landmasses[1].tile_count, island_b.len() as u32);
+        assert_eq!(stats.largest().map(|mass| mass.id), Some(1));
+        assert_eq!(stats.smallest().map(|mass| mass.id), Some(0));
+        assert_eq!(stats.labels[(grid.x + 1) as usize], Some(0));
+        assert_eq!(stats.labels[0], None);
+    }
+
+    /// A flat 12x8 plain with the sea down the right edge and a river running east along row 4
+    /// into a three-hex delta on the coast.
+    #[test]
+    fn settlement_sites_favour_the_river_delta_and_spread_out() {
+        let grid = UVec2::new(12, 8);
+        let mut tiles = Vec::new();
+        for y in 0..grid.y {
+            for x in 0..grid.x {
+                let mut tile = land_tile(x, y);
+                if x == grid.x - 1 {
+                    tile.terrain = sim_runtime::TerrainType::ContinentalShelf;
+                    tile.terrain_tags = TerrainTags::WATER;
+                }
+                tiles.push(tile);
+            }
+        }
+        let delta = [(10, 3), (10, 4), (10, 5)];
+        for &(x, y) in &delta {
+            let tile = &mut tiles[(y * grid.x + x) as usize];
+            tile.terrain = sim_runtime::TerrainType::RiverDelta;
+            tile.terrain_tags = TerrainTags::COASTAL
+                | TerrainTags::WETLAND
+                | TerrainTags::FERTILE
+                | TerrainTags::FRESHWATER;
+        }
+        for x in 2..10 {
+            tiles[(4 * grid.x + x) as usize]
+                .set_river_class_on_side(0, sim_runtime::RiverClass::Minor);
+        }
+        let elevation = ElevationField::new(grid.x, grid.y, vec![0.7; (grid.x * grid.y) as usize]);
+
+        let sites = suggest_settlement_sites(&tiles, &elevation, false, 4);
+        assert_eq!(sites.len(), 4);
+        assert!(
+            delta.contains(&sites[0]),
+            "the delta ranks first: {sites:?}"
+        );
+        for (i, &a) in sites.iter().enumerate() {
+            assert!(!tiles[(a.1 * grid.x + a.0) as usize]
+                .terrain_tags
+                .contains(TerrainTags::WATER));
+            for &b in &sites[i + 1..] {
+                let distance =
+                    hex_distance_wrapped(UVec2::new(a.0, a.1), UVec2::new(b.0, b.1), grid.x, false);
+                assert!(distance > 1, "{a:?} and {b:?} are clustered");
+            }
+        }
+
+        // Raising a hill makes it the defensible pick over the bare plain.
+        let mut raised = vec![0.7; (grid.x * grid.y) as usize];
+        raised[(grid.x + 3) as usize] = 0.95;
+        let hill = ElevationField::new(grid.x, grid.y, raised);
+        let sites = suggest_settlement_sites(&tiles, &hill, false, 12);
+        assert!(sites.contains(&(3, 1)), "{sites:?}");
+    }
+
+    #[test]
+    fn landmass_labels_joins_across_wrap_seam() {
+        let grid = UVec2::new(6, 2);
+        let tiles: Vec<_> = [(0, 0), (5, 0)]
+            .into_iter()
+            .map(|(x, y)| land_tile(x, y))
+            .collect();
+        assert_eq!(landmass_labels(&tiles, grid, false).count(), 2);
+        let wrapped = landmass_labels(&tiles, grid, true);
+        assert_eq!(wrapped.count(), 1);
+        assert_eq!(wrapped.landmasses[0].tile_count, 2);
+    }
+
     #[derive(Debug)]
     struct RegressionMetrics {
         land_ratio: f32,
@@ -3402,6 +3732,69 @@ mod tests {
         assert!(volcanic > 0, "expected volcanic terrain");
     }
 
+    /// `sea_level` is the land-mask threshold on a given heightfield: raising it over the **same**
+    /// field floods more tiles, never drains one, and the coastal ring is re-derived from the new
+    /// mask (so it retreats inland with the water). Islands are disabled so the only coastline
+    /// edits are the threshold's own.
+    #[test]
+    fn higher_sea_level_floods_the_same_heightfield_and_moves_the_coast() {
+        let presets = crate::map_preset::MapPresets::builtin();
+        let preset = presets
+            .get("polar_contrast")
+            .expect("polar_contrast preset");
+        let islands = crate::map_preset::IslandConfig {
+            continental_density: 0.0,
+            oceanic_density: 0.0,
+            ..preset.islands.clone()
+        };
+        let elevation = polar_fixture_elevation();
+        let bands_at = |sea_level: f32| {
+            build_bands(
+                &elevation,
+                sea_level,
+                &preset.macro_land,
+                &preset.shelf,
+                &islands,
+                &preset.ocean,
+                preset.moisture_scale,
+                &preset.biomes,
+                preset.map_seed.unwrap_or(99),
+                preset.mountain_scale,
+                &preset.mountains,
+                false,
+            )
+        };
+        let low = bands_at(0.45);
+        let high = bands_at(0.65);
+
+        let water = |bands: &BandsResult| bands.land_mask.iter().filter(|&&land| !land).count();
+        assert!(
+            water(&high) > water(&low),
+            "raising sea level must flood tiles: {} -> {}",
+            water(&low),
+            water(&high)
+        );
+        for (i, (&land_high, &land_low)) in high.land_mask.iter().zip(&low.land_mask).enumerate() {
+            assert!(
+                !land_high || land_low,
+                "tile {i} is land only at the higher sea level"
+            );
+        }
+
+        for bands in [&low, &high] {
+            for (i, &coastal) in bands.coastal_land.iter().enumerate() {
+                assert!(
+                    !coastal || bands.land_mask[i],
+                    "coastal tile {i} must be land"
+                );
+            }
+        }
+        assert!(
+            (0..high.coastal_land.len()).any(|i| high.coastal_land[i] && !low.coastal_land[i]),
+            "the coast should retreat onto former interior land"
+        );
+    }
+
     /// Prints every regression metric for both shipped presets, so a deliberate re-pin can read all
     /// the new centres at once. The `*_regression_metrics_stable` assertions stop at the **first**
     /// drift, which otherwise turns a re-pin into a one-metric-per-run loop.
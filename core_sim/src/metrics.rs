@@ -1,14 +1,244 @@
+use std::{
+    fmt::{Display, Write},
+    time::Instant,
+};
+
 use bevy::prelude::*;
 
 use crate::{
     components::{LogisticsLink, PopulationCohort, Tile, TradeLink},
-    crisis::CrisisTelemetry,
+    crisis::{CrisisMetricKind, CrisisTelemetry},
+    culture::CultureManager,
     fauna::HerdDensityMap,
     power::PowerGridState,
-    resources::{SimulationConfig, SimulationTick},
+    resources::{CorruptionLedgers, SimulationConfig, SimulationTick, TurnWarnings},
     scalar::{scalar_from_u32, Scalar},
+    TurnStage,
 };
 
+/// Buckets per metric histogram. Kept small so the distributions stay cheap to carry around.
+pub const METRIC_HISTOGRAM_BUCKETS: usize = 10;
+
+/// Fixed-width distribution over `[min, max]`. Values outside the range land in the first or last
+/// bucket rather than being dropped, so `total()` always equals the number of recorded samples;
+/// non-finite values are ignored.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Histogram {
+    min: f32,
+    max: f32,
+    counts: Vec<u32>,
+}
+
+impl Histogram {
+    /// `buckets` equal-width buckets spanning `[min, max]`; at least one bucket is always kept.
+    pub fn uniform(min: f32, max: f32, buckets: usize) -> Self {
+        Self {
+            min,
+            max: max.max(min),
+            counts: vec![0; buckets.max(1)],
+        }
+    }
+
+    /// The `[0, 1]` histogram every built-in metric uses.
+    pub fn unit() -> Self {
+        Self::uniform(0.0, 1.0, METRIC_HISTOGRAM_BUCKETS)
+    }
+
+    pub fn record(&mut self, value: f32) {
+        if !value.is_finite() || self.counts.is_empty() {
+            return;
+        }
+        let last = self.counts.len() - 1;
+        let span = self.max - self.min;
+        let bucket = if span <= 0.0 || value <= self.min {
+            0
+        } else {
+            (((value - self.min) / span) * self.counts.len() as f32) as usize
+        };
+        self.counts[bucket.min(last)] += 1;
+    }
+
+    pub fn clear(&mut self) {
+        self.counts.iter_mut().for_each(|count| *count = 0);
+    }
+
+    pub fn counts(&self) -> &[u32] {
+        &self.counts
+    }
+
+    pub fn total(&self) -> u32 {
+        self.counts.iter().sum()
+    }
+
+    /// `(lower, upper)` edges of bucket `index`.
+    pub fn bucket_range(&self, index: usize) -> (f32, f32) {
+        let width = (self.max - self.min) / self.counts.len().max(1) as f32;
+        let lower = self.min + width * index as f32;
+        (lower, lower + width)
+    }
+}
+
+/// Metrics too costly to gather every turn. Collected only on turns where
+/// [`SimulationConfig::detailed_metrics_due`] holds; in between, the last collection is kept.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DetailedMetrics {
+    /// Tick these were collected on.
+    pub tick: u64,
+    /// Cohort morale across every population cohort.
+    pub morale_histogram: Histogram,
+    /// Per-node stability from the latest power phase.
+    pub power_stability_histogram: Histogram,
+    /// Divergence magnitude of every regional and local culture layer from its parent; layers
+    /// past 1.0 count in the top bucket.
+    pub culture_divergence_histogram: Histogram,
+}
+
+impl DetailedMetrics {
+    fn empty(tick: u64) -> Self {
+        Self {
+            tick,
+            morale_histogram: Histogram::unit(),
+            power_stability_histogram: Histogram::unit(),
+            culture_divergence_histogram: Histogram::unit(),
+        }
+    }
+}
+
+/// Weight of the newest turn in the rolling averages of [`TurnTimings`].
+const TURN_TIMING_SMOOTHING: f64 = 0.2;
+
+/// Wall-clock cost of one [`TurnStage`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct StageTiming {
+    pub stage: TurnStage,
+    /// Milliseconds spent in the stage on the latest timed turn.
+    pub last_ms: f64,
+    /// Exponential moving average of `last_ms`, seeded by the first timed turn.
+    pub avg_ms: f64,
+}
+
+/// Per-stage breakdown of the turn pipeline, collected while
+/// [`SimulationConfig::turn_stage_timing`] is set. A stage's lap runs from the end of the previous
+/// stage to its own end, so scheduler overhead between stages is charged to the later one and the
+/// laps always sum to `total_ms`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TurnTimings {
+    /// One entry per stage, in pipeline order.
+    pub stages: Vec<StageTiming>,
+    pub total_ms: f64,
+    pub total_avg_ms: f64,
+    /// Timed turns folded into the averages.
+    pub samples: u64,
+}
+
+impl TurnTimings {
+    pub fn stage(&self, stage: TurnStage) -> Option<&StageTiming> {
+        self.stages.iter().find(|timing| timing.stage == stage)
+    }
+
+    fn record(&mut self, laps: &[(TurnStage, f64)]) {
+        let smooth = |avg: f64, last: f64| avg + (last - avg) * TURN_TIMING_SMOOTHING;
+        for (stage, ms) in laps {
+            match self.stages.iter_mut().find(|timing| timing.stage == *stage) {
+                Some(timing) => {
+                    timing.last_ms = *ms;
+                    timing.avg_ms = smooth(timing.avg_ms, *ms);
+                }
+                None => self.stages.push(StageTiming {
+                    stage: stage.clone(),
+                    last_ms: *ms,
+                    avg_ms: *ms,
+                }),
+            }
+        }
+        self.total_ms = laps.iter().map(|(_, ms)| ms).sum();
+        self.total_avg_ms = if self.samples == 0 {
+            self.total_ms
+        } else {
+            smooth(self.total_avg_ms, self.total_ms)
+        };
+        self.samples += 1;
+    }
+}
+
+/// Lap clock behind [`TurnTimings`]. Running only between `begin_turn_timing` and
+/// `finish_turn_timing` on a timed turn.
+#[derive(Resource, Debug, Default)]
+pub struct TurnTimingClock {
+    lap_start: Option<Instant>,
+    laps: Vec<(TurnStage, f64)>,
+}
+
+impl TurnTimingClock {
+    fn lap(&mut self, stage: TurnStage) {
+        let Some(start) = self.lap_start else {
+            return;
+        };
+        let now = Instant::now();
+        self.laps
+            .push((stage, now.duration_since(start).as_secs_f64() * 1000.0));
+        self.lap_start = Some(now);
+    }
+}
+
+/// Start the clock ahead of `TurnStage::Influence` when stage timing is enabled.
+pub fn begin_turn_timing(config: Res<SimulationConfig>, mut clock: ResMut<TurnTimingClock>) {
+    clock.laps.clear();
+    clock.lap_start = config.turn_stage_timing.then(Instant::now);
+}
+
+/// Boundary probe closing `stage`'s lap; scheduled between `stage` and the one after it.
+pub fn lap_turn_stage(stage: TurnStage) -> impl FnMut(ResMut<TurnTimingClock>) {
+    move |mut clock: ResMut<TurnTimingClock>| clock.lap(stage.clone())
+}
+
+/// Close the `Snapshot` lap and fold the turn into `SimulationMetrics::turn_timings`. Clears the
+/// breakdown once timing is switched off so stale numbers are not exported.
+pub fn finish_turn_timing(
+    mut clock: ResMut<TurnTimingClock>,
+    mut metrics: ResMut<SimulationMetrics>,
+) {
+    clock.lap(TurnStage::Snapshot);
+    if clock.lap_start.take().is_none() {
+        metrics.turn_timings = None;
+        return;
+    }
+    let laps = std::mem::take(&mut clock.laps);
+    metrics
+        .turn_timings
+        .get_or_insert_with(TurnTimings::default)
+        .record(&laps);
+}
+
+/// Runaway-subsystem flags, raised by `collect_metrics` against
+/// [`SimulationConfig::metrics_anomaly`]. Recomputed every turn, so a flag drops once its metric is
+/// back inside the bounds; each is logged and added to the turn's warnings on the turn it rises.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MetricAnomalies {
+    /// Head-count above `metrics_anomaly.population.max`.
+    pub population_over_bound: bool,
+    /// Head-count grew `metrics_anomaly.population.max_growth` times or more this turn.
+    pub population_runaway: bool,
+    pub corruption_over_bound: bool,
+    pub corruption_runaway: bool,
+}
+
+impl MetricAnomalies {
+    pub fn any(&self) -> bool {
+        self.flags().iter().any(|(_, raised)| *raised)
+    }
+
+    /// Every flag under its stable name (the `kind` label on the Prometheus export).
+    pub fn flags(&self) -> [(&'static str, bool); 4] {
+        [
+            ("population_over_bound", self.population_over_bound),
+            ("population_runaway", self.population_runaway),
+            ("corruption_over_bound", self.corruption_over_bound),
+            ("corruption_runaway", self.corruption_runaway),
+        ]
+    }
+}
+
 #[derive(Resource, Default, Debug, Clone)]
 pub struct SimulationMetrics {
     pub turn: u64,
@@ -34,6 +264,222 @@ pub struct SimulationMetrics {
     pub herd_density_avg: f32,
     pub herd_density_peak: f32,
     pub herd_density_ratio: f32,
+    /// Summed intensity of every open corruption incident (`1.0` = one at full strength).
+    pub corruption_intensity_total: f32,
+    pub anomalies: MetricAnomalies,
+    /// The detailed tier; `None` while `detailed_metrics_every_n_ticks` is `0` or before its
+    /// first due turn.
+    pub detailed: Option<DetailedMetrics>,
+    /// Per-stage turn timings; `None` while `turn_stage_timing` is off.
+    pub turn_timings: Option<TurnTimings>,
+}
+
+/// Prefix shared by every exported metric name.
+const PROMETHEUS_PREFIX: &str = "shadow_scale";
+
+impl SimulationMetrics {
+    /// Render the basic tier in the Prometheus text exposition format, one `# HELP` / `# TYPE`
+    /// header per family. Metric names are stable; scrapers may rely on them. Only counters carry
+    /// the `_total` suffix, per Prometheus naming. Crisis gauges share one family labelled by
+    /// `kind`.
+    pub fn to_prometheus(&self) -> String {
+        let mut out = String::with_capacity(2048);
+        let mut family = |name: &str, kind: &str, help: &str, value: &dyn Display| {
+            // Writing into a `String` cannot fail.
+            let _ = writeln!(out, "# HELP {PROMETHEUS_PREFIX}_{name} {help}");
+            let _ = writeln!(out, "# TYPE {PROMETHEUS_PREFIX}_{name} {kind}");
+            let _ = writeln!(out, "{PROMETHEUS_PREFIX}_{name} {value}");
+        };
+
+        family("turns_total", "counter", "Turns resolved.", &self.turn);
+        family(
+            "population",
+            "gauge",
+            "Head-count across every population cohort.",
+            &self.population_total,
+        );
+        family(
+            "population_morale_avg",
+            "gauge",
+            "Mean cohort morale.",
+            &prometheus_float(self.population_morale_avg.into()),
+        );
+        family(
+            "avg_temperature",
+            "gauge",
+            "Mean tile temperature.",
+            &prometheus_float(self.avg_temperature),
+        );
+        family(
+            "power_grid_stress_avg",
+            "gauge",
+            "Mean power-grid stress.",
+            &prometheus_float(self.grid_stress_avg.into()),
+        );
+        family(
+            "power_surplus_margin",
+            "gauge",
+            "Grid surplus as a fraction of demand.",
+            &prometheus_float(self.grid_surplus_margin.into()),
+        );
+        family(
+            "power_instability_alerts",
+            "gauge",
+            "Power nodes below the stability alert threshold.",
+            &self.instability_alerts,
+        );
+        family(
+            "crisis_warnings_active",
+            "gauge",
+            "Crisis gauges in the warn band.",
+            &self.crisis.warnings_active,
+        );
+        family(
+            "crisis_criticals_active",
+            "gauge",
+            "Crisis gauges in the critical band.",
+            &self.crisis.criticals_active,
+        );
+        family(
+            "crisis_modifiers_active",
+            "gauge",
+            "Active crisis modifiers.",
+            &self.crisis.modifiers_active,
+        );
+        family(
+            "knowledge_leak_warnings",
+            "gauge",
+            "Knowledge ledger entries in the leak warning band.",
+            &self.knowledge_leak_warnings,
+        );
+        family(
+            "knowledge_leak_criticals",
+            "gauge",
+            "Knowledge ledger entries in the leak critical band.",
+            &self.knowledge_leak_criticals,
+        );
+        family(
+            "knowledge_countermeasures_active",
+            "gauge",
+            "Active knowledge countermeasures.",
+            &self.knowledge_countermeasures_active,
+        );
+        family(
+            "knowledge_common_knowledge",
+            "gauge",
+            "Discoveries that have become common knowledge.",
+            &self.knowledge_common_knowledge_total,
+        );
+        family(
+            "great_discoveries",
+            "gauge",
+            "Great discoveries on record.",
+            &self.great_discoveries_total,
+        );
+        family(
+            "trade_openness_avg",
+            "gauge",
+            "Mean trade-link openness.",
+            &prometheus_float(self.trade_openness_avg.into()),
+        );
+        family(
+            "logistics_flow_avg",
+            "gauge",
+            "Mean logistics-link flow.",
+            &prometheus_float(self.logistics_flow_avg.into()),
+        );
+        family(
+            "herd_density_avg",
+            "gauge",
+            "Mean herd biomass per tile.",
+            &prometheus_float(self.herd_density_avg.into()),
+        );
+        family(
+            "corruption_intensity",
+            "gauge",
+            "Summed intensity of open corruption incidents.",
+            &prometheus_float(self.corruption_intensity_total.into()),
+        );
+
+        let name = format!("{PROMETHEUS_PREFIX}_metric_anomaly");
+        let _ = writeln!(
+            out,
+            "# HELP {name} Runaway-subsystem flag by kind (1 = raised)."
+        );
+        let _ = writeln!(out, "# TYPE {name} gauge");
+        for (kind, raised) in self.anomalies.flags() {
+            let _ = writeln!(out, "{name}{{kind=\"{kind}\"}} {}", u8::from(raised));
+        }
+
+        let name = format!("{PROMETHEUS_PREFIX}_crisis_gauge_ema");
+        let _ = writeln!(out, "# HELP {name} Smoothed crisis gauge value by kind.");
+        let _ = writeln!(out, "# TYPE {name} gauge");
+        for gauge in &self.crisis.gauges {
+            let _ = writeln!(
+                out,
+                "{name}{{kind=\"{}\"}} {}",
+                crisis_metric_label(gauge.kind),
+                prometheus_float(gauge.ema.into())
+            );
+        }
+
+        if let Some(timings) = &self.turn_timings {
+            let families: [(&str, &str, fn(&StageTiming) -> f64); 2] = [
+                (
+                    "turn_stage_ms",
+                    "Wall-clock milliseconds per turn stage on the latest turn.",
+                    |timing| timing.last_ms,
+                ),
+                (
+                    "turn_stage_avg_ms",
+                    "Smoothed wall-clock milliseconds per turn stage.",
+                    |timing| timing.avg_ms,
+                ),
+            ];
+            for (suffix, help, value) in families {
+                let name = format!("{PROMETHEUS_PREFIX}_{suffix}");
+                let _ = writeln!(out, "# HELP {name} {help}");
+                let _ = writeln!(out, "# TYPE {name} gauge");
+                for timing in &timings.stages {
+                    let _ = writeln!(
+                        out,
+                        "{name}{{stage=\"{}\"}} {}",
+                        timing.stage.label(),
+                        prometheus_float(value(timing))
+                    );
+                }
+            }
+            let name = format!("{PROMETHEUS_PREFIX}_turn_duration_ms");
+            let _ = writeln!(
+                out,
+                "# HELP {name} Wall-clock milliseconds for the latest turn."
+            );
+            let _ = writeln!(out, "# TYPE {name} gauge");
+            let _ = writeln!(out, "{name} {}", prometheus_float(timings.total_ms));
+        }
+        out
+    }
+}
+
+/// Prometheus spells the non-finite values `NaN`, `+Inf` and `-Inf`; Rust's `Display` does not.
+fn prometheus_float(value: f64) -> String {
+    if value.is_nan() {
+        "NaN".to_string()
+    } else if value.is_infinite() {
+        if value > 0.0 { "+Inf" } else { "-Inf" }.to_string()
+    } else {
+        value.to_string()
+    }
+}
+
+fn crisis_metric_label(kind: CrisisMetricKind) -> &'static str {
+    match kind {
+        CrisisMetricKind::R0 => "r0",
+        CrisisMetricKind::GridStressPct => "grid_stress_pct",
+        CrisisMetricKind::UnauthorizedQueuePct => "unauthorized_queue_pct",
+        CrisisMetricKind::SwarmsActive => "swarms_active",
+        CrisisMetricKind::PhageDensity => "phage_density",
+    }
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -48,8 +494,14 @@ pub fn collect_metrics(
     trade_links: Query<&TradeLink>,
     logistics_links: Query<&LogisticsLink>,
     herd_density: Res<HerdDensityMap>,
+    culture: Option<Res<CultureManager>>,
+    corruption: Option<Res<CorruptionLedgers>>,
+    mut turn_warnings: Option<ResMut<TurnWarnings>>,
 ) {
     metrics.turn += 1;
+    let mut detailed = config
+        .detailed_metrics_due(tick.0)
+        .then(|| DetailedMetrics::empty(tick.0));
     let mut total_mass = 0i128;
     let mut total_temp = 0f64;
     let mut count = 0u64;
@@ -72,6 +524,13 @@ pub fn collect_metrics(
         metrics.grid_stress_avg = power_state.grid_stress_avg;
         metrics.grid_surplus_margin = power_state.surplus_margin;
         metrics.instability_alerts = power_state.instability_alerts;
+        if let Some(detailed) = detailed.as_mut() {
+            for node in power_state.nodes.values() {
+                detailed
+                    .power_stability_histogram
+                    .record(node.stability.to_f32());
+            }
+        }
     } else {
         metrics.grid_stress_avg = 0.0;
         metrics.grid_surplus_margin = 0.0;
@@ -88,7 +547,11 @@ pub fn collect_metrics(
         population_total = population_total.saturating_add(cohort.size as u64);
         morale_total += cohort.morale;
         cohort_count = cohort_count.saturating_add(1);
+        if let Some(detailed) = detailed.as_mut() {
+            detailed.morale_histogram.record(cohort.morale.to_f32());
+        }
     }
+    let previous_population = metrics.population_total;
     metrics.population_total = population_total;
     metrics.population_morale_avg = if cohort_count > 0 {
         (morale_total / scalar_from_u32(cohort_count))
@@ -129,4 +592,451 @@ pub fn collect_metrics(
     metrics.herd_density_avg = herd_density.average_density();
     metrics.herd_density_peak = herd_density.max_density();
     metrics.herd_density_ratio = herd_density.normalized_average();
+
+    let previous_corruption = metrics.corruption_intensity_total;
+    let corruption_total = corruption.map_or(0.0, |ledgers| {
+        let raw: i64 = ledgers
+            .ledger()
+            .entries
+            .iter()
+            .map(|entry| entry.intensity.max(0))
+            .sum();
+        Scalar::from_raw(raw).to_f32()
+    });
+    metrics.corruption_intensity_total = corruption_total;
+
+    let bounds = &config.metrics_anomaly;
+    let before = metrics.anomalies;
+    let population_levels = (previous_population as f64, population_total as f64);
+    let corruption_levels = (f64::from(previous_corruption), f64::from(corruption_total));
+    let mut flag =
+        |kind: &'static str, was_raised: bool, raised: bool, (previous, value): (f64, f64)| {
+            if raised && !was_raised {
+                warn!(
+                    target: "shadow_scale::metrics",
+                    tick = tick.0,
+                    kind,
+                    previous,
+                    value,
+                    "metrics.anomaly"
+                );
+                if let Some(warnings) = turn_warnings.as_mut() {
+                    warnings.push(format!(
+                        "metrics anomaly {kind}: {value} (previous turn {previous})"
+                    ));
+                }
+            }
+            raised
+        };
+    metrics.anomalies = MetricAnomalies {
+        population_over_bound: flag(
+            "population_over_bound",
+            before.population_over_bound,
+            bounds.population.exceeded(population_levels.1),
+            population_levels,
+        ),
+        population_runaway: flag(
+            "population_runaway",
+            before.population_runaway,
+            bounds
+                .population
+                .runaway(population_levels.0, population_levels.1),
+            population_levels,
+        ),
+        corruption_over_bound: flag(
+            "corruption_over_bound",
+            before.corruption_over_bound,
+            bounds.corruption.exceeded(corruption_levels.1),
+            corruption_levels,
+        ),
+        corruption_runaway: flag(
+            "corruption_runaway",
+            before.corruption_runaway,
+            bounds
+                .corruption
+                .runaway(corruption_levels.0, corruption_levels.1),
+            corruption_levels,
+        ),
+    };
+
+    let Some(mut detailed) = detailed else {
+        if config.detailed_metrics_every_n_ticks == 0 {
+            metrics.detailed = None;
+        }
+        return;
+    };
+    if let Some(culture) = culture {
+        for layer in culture.regional_layers().chain(culture.local_layers()) {
+            detailed
+                .culture_divergence_histogram
+                .record(layer.divergence.magnitude.to_f32());
+        }
+    }
+    metrics.detailed = Some(detailed);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        components::{LocalStore, MoraleCause, MoraleContributions},
+        orders::FactionId,
+        power::{PowerGridNodeTelemetry, PowerNodeId},
+        scalar::{scalar_from_f32, scalar_zero},
+    };
+    use bevy_ecs::system::RunSystemOnce;
+
+    #[test]
+    fn histogram_buckets_known_values() {
+        let mut histogram = Histogram::uniform(0.0, 1.0, 4);
+        for value in [0.0, 0.1, 0.24, 0.25, 0.5, 0.74, 0.99, 1.0] {
+            histogram.record(value);
+        }
+        assert_eq!(histogram.counts(), &[3, 1, 2, 2]);
+        assert_eq!(histogram.total(), 8);
+        assert_eq!(histogram.bucket_range(1), (0.25, 0.5));
+    }
+
+    #[test]
+    fn histogram_clamps_out_of_range_and_skips_non_finite() {
+        let mut histogram = Histogram::uniform(0.0, 1.0, 2);
+        for value in [-3.0, 7.5, f32::NAN, f32::INFINITY] {
+            histogram.record(value);
+        }
+        assert_eq!(histogram.counts(), &[1, 1]);
+
+        histogram.clear();
+        assert_eq!(histogram.total(), 0);
+        assert_eq!(histogram.counts().len(), 2);
+    }
+
+    /// Parse Prometheus text exposition into `(series, value)` pairs, asserting the grammar as it
+    /// goes: every sample belongs to a family declared by a preceding `# TYPE` line.
+    fn parse_prometheus(text: &str) -> Vec<(String, f64)> {
+        let valid_name = |name: &str| {
+            !name.is_empty()
+                && !name.starts_with(|c: char| c.is_ascii_digit())
+                && name
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == ':')
+        };
+        let mut typed = std::collections::HashSet::new();
+        let mut samples = Vec::new();
+        for line in text.lines() {
+            if let Some(rest) = line.strip_prefix("# TYPE ") {
+                let (name, kind) = rest.split_once(' ').expect("TYPE names a kind");
+                assert!(valid_name(name), "bad metric name in {line:?}");
+                assert!(
+                    ["counter", "gauge"].contains(&kind),
+                    "unknown type in {line:?}"
+                );
+                typed.insert(name.to_string());
+                continue;
+            }
+            if line.starts_with("# HELP ") {
+                continue;
+            }
+            let (series, value) = line.rsplit_once(' ').expect("sample has a value");
+            let name = series.split('{').next().unwrap_or_default();
+            assert!(valid_name(name), "bad metric name in {line:?}");
+            assert!(typed.contains(name), "{name} sampled before its TYPE line");
+            if let Some(labels) = series.strip_prefix(name) {
+                if !labels.is_empty() {
+                    assert!(
+                        labels.starts_with('{') && labels.ends_with('}') && labels.contains("=\""),
+                        "malformed labels in {line:?}"
+                    );
+                }
+            }
+            let value = match value {
+                "NaN" => f64::NAN,
+                "+Inf" => f64::INFINITY,
+                "-Inf" => f64::NEG_INFINITY,
+                other => other
+                    .parse()
+                    .unwrap_or_else(|_| panic!("unparseable value in {line:?}")),
+            };
+            samples.push((series.to_string(), value));
+        }
+        samples
+    }
+
+    #[test]
+    fn prometheus_export_is_valid_text_with_stable_names() {
+        let metrics = SimulationMetrics {
+            turn: 42,
+            population_total: 1200,
+            population_morale_avg: 0.5,
+            grid_surplus_margin: f32::INFINITY,
+            knowledge_leak_warnings: 3,
+            crisis: crate::crisis::CrisisMetricsSnapshot {
+                criticals_active: 1,
+                gauges: vec![crate::crisis::CrisisGaugeSnapshot {
+                    kind: CrisisMetricKind::R0,
+                    raw: 1.5,
+                    ema: 1.25,
+                    trend_5t: 0.0,
+                    band: crate::crisis::CrisisSeverityBand::Critical,
+                    raw_band: crate::crisis::CrisisSeverityBand::Critical,
+                    ema_band: crate::crisis::CrisisSeverityBand::Critical,
+                    last_updated_tick: 42,
+                    stale_ticks: 0,
+                    warn_threshold: 0.9,
+                    critical_threshold: 1.2,
+                    history: Vec::new(),
+                }],
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let text = metrics.to_prometheus();
+        let samples: std::collections::HashMap<String, f64> =
+            parse_prometheus(&text).into_iter().collect();
+        let value = |series: &str| {
+            *samples
+                .get(series)
+                .unwrap_or_else(|| panic!("{series} missing from:\n{text}"))
+        };
+        assert_eq!(value("shadow_scale_turns_total"), 42.0);
+        assert_eq!(value("shadow_scale_population"), 1200.0);
+        assert_eq!(value("shadow_scale_population_morale_avg"), 0.5);
+        assert_eq!(value("shadow_scale_power_surplus_margin"), f64::INFINITY);
+        assert_eq!(value("shadow_scale_crisis_criticals_active"), 1.0);
+        assert_eq!(value("shadow_scale_knowledge_leak_warnings"), 3.0);
+        assert_eq!(value("shadow_scale_crisis_gauge_ema{kind=\"r0\"}"), 1.25);
+        assert!(text.contains("# TYPE shadow_scale_turns_total counter\n"));
+        for line in text.lines().filter(|line| line.starts_with("# TYPE ")) {
+            let mut parts = line.split_whitespace().skip(2);
+            let (name, kind) = (parts.next().unwrap(), parts.next().unwrap());
+            assert_eq!(
+                name.ends_with("_total"),
+                kind == "counter",
+                "only counters end in _total: {line}"
+            );
+        }
+    }
+
+    fn metrics_world(detailed_every: u64) -> World {
+        let mut world = World::new();
+        let mut config = SimulationConfig::builtin();
+        config.detailed_metrics_every_n_ticks = detailed_every;
+        world.insert_resource(config);
+        world.insert_resource(SimulationMetrics::default());
+        world.insert_resource(CrisisTelemetry::default());
+        world.insert_resource(SimulationTick(0));
+        world.insert_resource(HerdDensityMap::default());
+        let mut power = PowerGridState {
+            grid_stress_avg: 0.25,
+            ..Default::default()
+        };
+        for (id, stability) in [(0, 0.15), (1, 0.95)] {
+            power.nodes.insert(
+                PowerNodeId(id),
+                PowerGridNodeTelemetry {
+                    node_id: PowerNodeId(id),
+                    stability: Scalar::from_f32(stability),
+                    ..Default::default()
+                },
+            );
+        }
+        world.insert_resource(power);
+        world
+    }
+
+    #[test]
+    fn detailed_metrics_absent_when_disabled() {
+        let mut world = metrics_world(0);
+        world.run_system_once(collect_metrics);
+
+        let metrics = world.resource::<SimulationMetrics>();
+        assert_eq!(metrics.grid_stress_avg, 0.25, "basic tier still collected");
+        assert!(metrics.detailed.is_none());
+    }
+
+    #[test]
+    fn detailed_metrics_follow_cadence() {
+        let mut world = metrics_world(3);
+        let mut expected = [0u32; METRIC_HISTOGRAM_BUCKETS];
+        expected[1] = 1;
+        expected[9] = 1;
+
+        for tick in 0..4 {
+            world.resource_mut::<SimulationTick>().0 = tick;
+            world.run_system_once(collect_metrics);
+            let detailed = world
+                .resource::<SimulationMetrics>()
+                .detailed
+                .clone()
+                .expect("collected on tick 0 and kept between due turns");
+            assert_eq!(detailed.tick, if tick < 3 { 0 } else { 3 });
+            assert_eq!(detailed.power_stability_histogram.counts(), &expected);
+        }
+    }
+
+    fn cohort(size: u32) -> PopulationCohort {
+        let tile = Entity::from_raw(0);
+        PopulationCohort {
+            home: tile,
+            current_tile: tile,
+            size,
+            children: scalar_zero(),
+            working: scalar_from_f32(size as f32),
+            elders: scalar_zero(),
+            stores: LocalStore::new(),
+            morale: scalar_from_f32(0.5),
+            last_food_consumption: 0.0,
+            last_morale_delta: scalar_zero(),
+            last_morale_cause: MoraleCause::None,
+            last_morale_contributions: MoraleContributions::default(),
+            discontent_fraction: scalar_zero(),
+            grievance: scalar_zero(),
+            last_emigrated: 0,
+            last_immigrated: 0,
+            age_turns: 0,
+            generation: 0,
+            faction: FactionId(0),
+            knowledge: Vec::new(),
+            migration: None,
+        }
+    }
+
+    /// Collect one turn per entry of `sizes`, the band's head-count that turn; returns the
+    /// anomalies and warnings of each turn.
+    fn collect_with_population(sizes: &[u32]) -> Vec<(MetricAnomalies, Vec<String>)> {
+        let mut world = metrics_world(0);
+        world.insert_resource(TurnWarnings::default());
+        let band = world.spawn(cohort(sizes[0])).id();
+        sizes
+            .iter()
+            .enumerate()
+            .map(|(tick, &size)| {
+                world.resource_mut::<SimulationTick>().0 = tick as u64;
+                world.get_mut::<PopulationCohort>(band).unwrap().size = size;
+                world.run_system_once(collect_metrics);
+                let warnings = world.resource_mut::<TurnWarnings>().drain();
+                (world.resource::<SimulationMetrics>().anomalies, warnings)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn runaway_population_growth_raises_the_anomaly_and_warns_once() {
+        let turns = collect_with_population(&[1_000, 1_050, 3_000, 3_050]);
+
+        let (anomalies, warnings) = &turns[2];
+        assert!(anomalies.population_runaway, "1 050 → 3 000 in one turn");
+        assert!(!anomalies.population_over_bound);
+        assert_eq!(warnings.len(), 1, "{warnings:?}");
+        assert!(warnings[0].contains("population_runaway"), "{warnings:?}");
+
+        let (anomalies, warnings) = &turns[3];
+        assert!(!anomalies.any(), "the flag drops once growth settles");
+        assert!(warnings.is_empty());
+    }
+
+    #[test]
+    fn population_past_the_bound_is_flagged_while_it_stays_there() {
+        let mut world = metrics_world(0);
+        world
+            .resource_mut::<SimulationConfig>()
+            .metrics_anomaly
+            .population
+            .max = 2_000.0;
+        world.insert_resource(TurnWarnings::default());
+        world.spawn(cohort(2_500));
+        for tick in 0..3 {
+            world.resource_mut::<SimulationTick>().0 = tick;
+            world.run_system_once(collect_metrics);
+            assert!(
+                world
+                    .resource::<SimulationMetrics>()
+                    .anomalies
+                    .population_over_bound
+            );
+        }
+        assert_eq!(
+            world.resource::<TurnWarnings>().iter().count(),
+            1,
+            "reported on the turn it rose, not every turn after"
+        );
+        assert!(world
+            .resource::<SimulationMetrics>()
+            .to_prometheus()
+            .contains("shadow_scale_metric_anomaly{kind=\"population_over_bound\"} 1\n"));
+    }
+
+    #[test]
+    fn steady_growth_stays_clean() {
+        let sizes: Vec<u32> = (0..20).map(|turn| 1_000 + turn * 40).collect();
+        for (turn, (anomalies, warnings)) in collect_with_population(&sizes).iter().enumerate() {
+            assert!(!anomalies.any(), "turn {turn}: {anomalies:?}");
+            assert!(warnings.is_empty(), "turn {turn}: {warnings:?}");
+        }
+        let small_band = collect_with_population(&[10, 40]);
+        assert!(
+            !small_band[1].0.any(),
+            "a band under the growth floor is not a runaway"
+        );
+    }
+
+    #[test]
+    fn collect_metrics_fills_power_and_culture_histograms() {
+        let mut world = World::new();
+        let mut config = SimulationConfig::builtin();
+        config.detailed_metrics_every_n_ticks = 1;
+        world.insert_resource(config);
+        world.insert_resource(SimulationMetrics::default());
+        world.insert_resource(CrisisTelemetry::default());
+        world.insert_resource(SimulationTick(0));
+        world.insert_resource(HerdDensityMap::default());
+
+        let mut power = PowerGridState::default();
+        for (id, stability) in [(0, 0.05), (1, 0.55), (2, 0.58), (3, 1.0)] {
+            power.nodes.insert(
+                PowerNodeId(id),
+                PowerGridNodeTelemetry {
+                    node_id: PowerNodeId(id),
+                    stability: Scalar::from_f32(stability),
+                    ..Default::default()
+                },
+            );
+        }
+        world.insert_resource(power);
+
+        let mut culture = CultureManager::new();
+        for (region, magnitude) in [(0, 0.12), (1, 0.15), (2, 1.8)] {
+            culture.upsert_regional(region);
+            culture
+                .regional_layer_mut_by_region(region)
+                .expect("regional layer")
+                .divergence
+                .magnitude = Scalar::from_f32(magnitude);
+        }
+        world.insert_resource(culture);
+
+        world.run_system_once(collect_metrics);
+
+        let metrics = world
+            .resource::<SimulationMetrics>()
+            .detailed
+            .as_ref()
+            .expect("detailed metrics collected every turn");
+        let mut stability = [0u32; METRIC_HISTOGRAM_BUCKETS];
+        stability[0] = 1;
+        stability[5] = 2;
+        stability[9] = 1;
+        assert_eq!(metrics.power_stability_histogram.counts(), &stability);
+
+        let mut divergence = [0u32; METRIC_HISTOGRAM_BUCKETS];
+        divergence[1] = 2;
+        divergence[9] = 1;
+        assert_eq!(metrics.culture_divergence_histogram.counts(), &divergence);
+
+        assert_eq!(metrics.morale_histogram.total(), 0);
+        assert_eq!(
+            metrics.morale_histogram.counts().len(),
+            METRIC_HISTOGRAM_BUCKETS
+        );
+    }
 }
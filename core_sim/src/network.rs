@@ -1,44 +1,280 @@
+use std::collections::VecDeque;
 use std::io::{self, Write};
 use std::net::{TcpListener, TcpStream};
-use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Condvar, Mutex};
 use std::thread;
 
 use crossbeam_channel::{unbounded, Sender};
+use sim_runtime::{encode_delta, WorldDelta};
 
 use crate::snapshot::SnapshotHistory;
 
+/// A frame queued for broadcast. Deltas keep their typed form next to the encoded bytes so a
+/// subscriber's backlog can be coalesced before it is written.
+#[derive(Clone)]
+pub enum OutboundFrame {
+    Raw(Arc<Vec<u8>>),
+    Delta {
+        delta: Arc<WorldDelta>,
+        encoded: Arc<Vec<u8>>,
+    },
+}
+
+impl OutboundFrame {
+    fn bytes(&self) -> &Arc<Vec<u8>> {
+        match self {
+            OutboundFrame::Raw(bytes) => bytes,
+            OutboundFrame::Delta { encoded, .. } => encoded,
+        }
+    }
+}
+
 pub struct SnapshotServer {
-    sender: Sender<Vec<u8>>,
+    sender: Sender<OutboundFrame>,
     latest_frame: Arc<Mutex<Option<Vec<u8>>>>,
+    /// Largest encoded delta frame this server writes; `0` never splits.
+    max_delta_bytes: usize,
 }
 
 impl SnapshotServer {
     pub fn broadcast(&self, bytes: &[u8]) {
+        self.queue(OutboundFrame::Raw(Arc::new(bytes.to_vec())));
+    }
+
+    /// Broadcast a delta that a lagging subscriber may receive merged with the deltas queued
+    /// around it (see [`SubscriberQueue::take_coalesced`]). A delta whose encoding exceeds the
+    /// server's frame cap goes out as its [`WorldDelta::split`] parts.
+    pub fn broadcast_delta(&self, delta: Arc<WorldDelta>, encoded: Arc<Vec<u8>>) {
+        if self.max_delta_bytes == 0 || encoded.len() <= self.max_delta_bytes {
+            self.queue(OutboundFrame::Delta { delta, encoded });
+            return;
+        }
+        match split_delta_frames(delta.as_ref().clone(), self.max_delta_bytes) {
+            Ok(parts) => {
+                log::debug!(
+                    "Split a {}-byte snapshot delta into {} frames",
+                    encoded.len(),
+                    parts.len()
+                );
+                for (delta, encoded) in parts {
+                    self.queue(OutboundFrame::Delta { delta, encoded });
+                }
+            }
+            Err(err) => {
+                log::warn!("Failed to encode split delta, sending it whole: {}", err);
+                self.queue(OutboundFrame::Delta { delta, encoded });
+            }
+        }
+    }
+
+    fn queue(&self, frame: OutboundFrame) {
         {
             let mut guard = self
                 .latest_frame
                 .lock()
                 .expect("latest snapshot frame mutex poisoned");
-            *guard = Some(bytes.to_vec());
+            *guard = Some(frame.bytes().as_ref().clone());
         }
-        if let Err(err) = self.sender.send(bytes.to_vec()) {
+        if let Err(err) = self.sender.send(frame) {
             log::error!("Failed to queue snapshot delta: {}", err);
         }
     }
 }
 
+/// Frames waiting for one subscriber. Each subscriber drains its own queue on a writer thread,
+/// so a slow client backs up only itself; whatever piled up meanwhile is coalesced on the next
+/// drain.
+#[derive(Default)]
+pub struct SubscriberQueue {
+    pending: Mutex<VecDeque<OutboundFrame>>,
+    ready: Condvar,
+    closed: AtomicBool,
+    /// Frame cap a coalesced delta is split back under; `0` never splits.
+    max_delta_bytes: usize,
+}
+
+impl SubscriberQueue {
+    pub fn with_max_delta_bytes(max_delta_bytes: usize) -> Self {
+        Self {
+            max_delta_bytes,
+            ..Self::default()
+        }
+    }
+
+    pub fn push(&self, frame: OutboundFrame) {
+        self.pending
+            .lock()
+            .expect("subscriber queue mutex poisoned")
+            .push_back(frame);
+        self.ready.notify_one();
+    }
+
+    pub fn is_closed(&self) -> bool {
+        self.closed.load(Ordering::Acquire)
+    }
+
+    fn close(&self) {
+        self.closed.store(true, Ordering::Release);
+    }
+
+    /// Take everything queued, merging each run of consecutive deltas into one combined
+    /// `WorldDelta` (`WorldDelta::merge`), split again if it outgrows the frame cap. Raw frames,
+    /// such as full snapshots, are kept in order and break a run. Only whole deltas are merged:
+    /// the tail of a split delta begun in an earlier drain, and the head of one whose remaining
+    /// parts are still to come, go out as they were queued.
+    pub fn take_coalesced(&self) -> Vec<Arc<Vec<u8>>> {
+        let backlog: Vec<OutboundFrame> = self
+            .pending
+            .lock()
+            .expect("subscriber queue mutex poisoned")
+            .drain(..)
+            .collect();
+        coalesce_frames(backlog, self.max_delta_bytes)
+    }
+
+    /// Block until at least one frame is queued, then take the coalesced backlog.
+    fn wait_coalesced(&self) -> Vec<Arc<Vec<u8>>> {
+        let mut pending = self
+            .pending
+            .lock()
+            .expect("subscriber queue mutex poisoned");
+        while pending.is_empty() {
+            pending = self
+                .ready
+                .wait(pending)
+                .expect("subscriber queue mutex poisoned");
+        }
+        let backlog: Vec<OutboundFrame> = pending.drain(..).collect();
+        drop(pending);
+        coalesce_frames(backlog, self.max_delta_bytes)
+    }
+}
+
+/// `delta`'s [`WorldDelta::split`] parts under `max_bytes`, each with its encoding.
+fn split_delta_frames(
+    delta: WorldDelta,
+    max_bytes: usize,
+) -> bincode::Result<Vec<(Arc<WorldDelta>, Arc<Vec<u8>>)>> {
+    delta
+        .split(max_bytes)
+        .into_iter()
+        .map(|part| {
+            let encoded = encode_delta(&part)?;
+            Ok((Arc::new(part), Arc::new(encoded)))
+        })
+        .collect()
+}
+
+fn coalesce_frames(backlog: Vec<OutboundFrame>, max_delta_bytes: usize) -> Vec<Arc<Vec<u8>>> {
+    let mut frames = Vec::with_capacity(backlog.len());
+    let mut run: Vec<(Arc<WorldDelta>, Arc<Vec<u8>>)> = Vec::new();
+    for frame in backlog {
+        match frame {
+            OutboundFrame::Delta { delta, encoded } => run.push((delta, encoded)),
+            OutboundFrame::Raw(bytes) => {
+                flush_delta_run(&mut run, &mut frames, max_delta_bytes);
+                frames.push(bytes);
+            }
+        }
+    }
+    flush_delta_run(&mut run, &mut frames, max_delta_bytes);
+    frames
+}
+
+/// Whether `delta` is the last (or only) frame of the delta it belongs to.
+fn completes_delta(delta: &WorldDelta) -> bool {
+    delta.header.delta_part + 1 >= delta.header.delta_part_count
+}
+
+fn flush_delta_run(
+    run: &mut Vec<(Arc<WorldDelta>, Arc<Vec<u8>>)>,
+    frames: &mut Vec<Arc<Vec<u8>>>,
+    max_delta_bytes: usize,
+) {
+    // Merge only between split boundaries: from the first part `0` to the last frame that
+    // completes its delta. Anything outside passes through as queued.
+    let start = run
+        .iter()
+        .position(|(delta, _)| delta.header.delta_part == 0)
+        .unwrap_or(run.len());
+    let end = run
+        .iter()
+        .rposition(|(delta, _)| completes_delta(delta))
+        .map_or(start, |last| (last + 1).max(start));
+    let trailing: Vec<_> = run.drain(end..).collect();
+    frames.extend(run.drain(..start).map(|(_, encoded)| encoded));
+    merge_whole_deltas(run, frames, max_delta_bytes);
+    frames.extend(trailing.into_iter().map(|(_, encoded)| encoded));
+}
+
+/// Merge `run`, a sequence of whole deltas, into one frame (split again past the frame cap).
+fn merge_whole_deltas(
+    run: &mut Vec<(Arc<WorldDelta>, Arc<Vec<u8>>)>,
+    frames: &mut Vec<Arc<Vec<u8>>>,
+    max_delta_bytes: usize,
+) {
+    if run.len() <= 1 {
+        frames.extend(run.drain(..).map(|(_, encoded)| encoded));
+        return;
+    }
+    // Every part `0` opens one delta; each covers its own span of the stream.
+    let span: u32 = run
+        .iter()
+        .filter(|(delta, _)| delta.header.delta_part == 0)
+        .map(|(delta, _)| delta.header.sequence_span.max(1))
+        .sum();
+    let mut deltas = run.iter().map(|(delta, _)| delta);
+    let mut combined = deltas
+        .next()
+        .map(|first| first.as_ref().clone())
+        .unwrap_or_default();
+    for delta in deltas {
+        combined.merge(delta.as_ref().clone());
+    }
+    // The merged header is the last delta's: its tick and `sequence`, with a span reaching back
+    // over every delta folded in so the client's gap check still lines up. The run ends on a
+    // completed delta, but a last split part's marks no longer describe the combined frame.
+    combined.header.sequence_span = span;
+    combined.header.delta_part = 0;
+    combined.header.delta_part_count = 0;
+    let encoded = if max_delta_bytes == 0 {
+        encode_delta(&combined).map(|bytes| vec![Arc::new(bytes)])
+    } else {
+        split_delta_frames(combined, max_delta_bytes)
+            .map(|parts| parts.into_iter().map(|(_, encoded)| encoded).collect())
+    };
+    match encoded {
+        Ok(bytes) => {
+            log::debug!(
+                "Coalesced {} snapshot deltas for a lagging client",
+                run.len()
+            );
+            run.clear();
+            frames.extend(bytes);
+        }
+        Err(err) => {
+            log::warn!(
+                "Failed to encode coalesced delta, sending backlog as-is: {}",
+                err
+            );
+            frames.extend(run.drain(..).map(|(_, encoded)| encoded));
+        }
+    }
+}
+
 /// Starts the snapshot broadcaster on an already-bound listener.
 ///
 /// The listener is bound up front by `port_alloc::allocate`, so binding can no
 /// longer fail here — a busy port is caught before the server starts rather
-/// than silently disabling broadcasting on a running server.
-pub fn start_snapshot_server(listener: TcpListener) -> SnapshotServer {
-    let (sender, receiver) = unbounded::<Vec<u8>>();
+/// than silently disabling broadcasting on a running server. Delta frames are held to
+/// `max_delta_bytes` (`SimulationConfig::snapshot_delta_max_bytes`; `0` never splits).
+pub fn start_snapshot_server(listener: TcpListener, max_delta_bytes: usize) -> SnapshotServer {
+    let (sender, receiver) = unbounded::<OutboundFrame>();
     listener
         .set_nonblocking(true)
         .expect("set nonblocking failed");
-    let clients: Arc<Mutex<Vec<TcpStream>>> = Arc::new(Mutex::new(Vec::new()));
-    let accept_clients = Arc::clone(&clients);
+    let mut subscribers: Vec<Arc<SubscriberQueue>> = Vec::new();
     let latest_frame: Arc<Mutex<Option<Vec<u8>>>> = Arc::new(Mutex::new(None));
     let accept_latest = Arc::clone(&latest_frame);
 
@@ -70,10 +306,11 @@ pub fn start_snapshot_server(listener: TcpListener) -> SnapshotServer {
                         continue;
                     }
                 }
-                accept_clients
-                    .lock()
-                    .expect("clients mutex poisoned")
-                    .push(stream);
+                subscribers.push(spawn_subscriber_writer(
+                    stream,
+                    addr.to_string(),
+                    max_delta_bytes,
+                ));
             }
             Err(ref err) if err.kind() == io::ErrorKind::WouldBlock => {
                 thread::sleep(std::time::Duration::from_millis(50));
@@ -84,14 +321,18 @@ pub fn start_snapshot_server(listener: TcpListener) -> SnapshotServer {
             }
         }
 
+        subscribers.retain(|queue| !queue.is_closed());
         while let Ok(frame) = receiver.try_recv() {
-            broadcast_frame(&clients, &frame);
+            for queue in &subscribers {
+                queue.push(frame.clone());
+            }
         }
     });
 
     SnapshotServer {
         sender,
         latest_frame,
+        max_delta_bytes,
     }
 }
 
@@ -100,8 +341,12 @@ pub fn broadcast_latest(
     flat_server: &SnapshotServer,
     history: &SnapshotHistory,
 ) {
-    if let Some(bytes) = history.encoded_delta.as_ref() {
-        bincode_server.broadcast(bytes.as_ref());
+    match (history.last_delta.as_ref(), history.encoded_delta.as_ref()) {
+        (Some(delta), Some(bytes)) => {
+            bincode_server.broadcast_delta(Arc::clone(delta), Arc::clone(bytes));
+        }
+        (None, Some(bytes)) => bincode_server.broadcast(bytes.as_ref()),
+        _ => {}
     }
     if let Some(bytes) = history.encoded_snapshot_flat.as_ref() {
         flat_server.broadcast(bytes.as_ref());
@@ -116,13 +361,182 @@ fn write_frame(stream: &mut TcpStream, frame: &[u8]) -> io::Result<()> {
     stream.write_all(&buffer)
 }
 
-fn broadcast_frame(clients: &Arc<Mutex<Vec<TcpStream>>>, frame: &[u8]) {
-    let mut guard = clients.lock().expect("clients mutex poisoned");
-    guard.retain_mut(|stream| match write_frame(stream, frame) {
-        Ok(_) => true,
-        Err(err) => {
-            log::warn!("Dropping snapshot client: {}", err);
-            false
+fn spawn_subscriber_writer(
+    mut stream: TcpStream,
+    addr: String,
+    max_delta_bytes: usize,
+) -> Arc<SubscriberQueue> {
+    let queue = Arc::new(SubscriberQueue::with_max_delta_bytes(max_delta_bytes));
+    let writer_queue = Arc::clone(&queue);
+    thread::spawn(move || loop {
+        for frame in writer_queue.wait_coalesced() {
+            if let Err(err) = write_frame(&mut stream, &frame) {
+                log::warn!("Dropping snapshot client {}: {}", addr, err);
+                writer_queue.close();
+                return;
+            }
         }
     });
+    queue
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sim_runtime::LogisticsLinkState;
+
+    fn link(entity: u64, flow: i64) -> LogisticsLinkState {
+        LogisticsLinkState {
+            entity,
+            from: 1,
+            to: 2,
+            capacity: 10,
+            flow,
+        }
+    }
+
+    fn delta_frame(delta: WorldDelta) -> OutboundFrame {
+        let encoded = Arc::new(encode_delta(&delta).expect("delta encodes"));
+        OutboundFrame::Delta {
+            delta: Arc::new(delta),
+            encoded,
+        }
+    }
+
+    fn delta(tick: u64, edit: impl FnOnce(&mut WorldDelta)) -> WorldDelta {
+        let mut delta = WorldDelta::default();
+        delta.header.tick = tick;
+        edit(&mut delta);
+        delta
+    }
+
+    #[test]
+    fn lagging_subscriber_receives_one_coalesced_delta() {
+        let queue = SubscriberQueue::default();
+        queue.push(delta_frame(delta(1, |d| {
+            d.logistics = vec![link(1, 1), link(2, 1), link(3, 1)];
+            d.removed_logistics = vec![10];
+            d.capability_flags = Some(1);
+        })));
+        queue.push(delta_frame(delta(2, |d| {
+            d.logistics = vec![link(2, 2)];
+            d.removed_logistics = vec![3, 11];
+        })));
+        queue.push(delta_frame(delta(3, |d| {
+            d.logistics = vec![link(1, 3), link(4, 3)];
+            d.removed_logistics = vec![10, 12];
+        })));
+
+        let frames = queue.take_coalesced();
+        assert_eq!(frames.len(), 1, "three queued deltas go out as one frame");
+        let combined: WorldDelta = bincode::deserialize(&frames[0]).expect("frame decodes");
+
+        assert_eq!(combined.header.tick, 3, "the header is the latest delta's");
+        assert_eq!(
+            combined.header.sequence_span, 3,
+            "the frame covers all three stream positions"
+        );
+        assert_eq!(
+            combined.logistics,
+            vec![link(1, 3), link(2, 2), link(4, 3)],
+            "each entity carries its final state; a later removal drops its update"
+        );
+        let mut removed = combined.removed_logistics.clone();
+        removed.sort_unstable();
+        assert_eq!(removed, vec![3, 10, 11, 12], "removed ids are the union");
+        assert_eq!(
+            combined.capability_flags,
+            Some(1),
+            "an optional section unchanged later keeps its last value"
+        );
+        assert!(queue.take_coalesced().is_empty(), "the backlog was drained");
+    }
+
+    #[test]
+    fn a_coalesced_backlog_is_split_back_under_the_frame_cap() {
+        let one_link = encode_delta(&delta(1, |d| d.logistics = vec![link(1, 1)]))
+            .expect("delta encodes")
+            .len();
+        let cap = one_link + 64;
+        let queue = SubscriberQueue::with_max_delta_bytes(cap);
+        for tick in 1..=8 {
+            queue.push(delta_frame(delta(tick, |d| {
+                d.logistics = vec![link(tick, tick as i64)];
+            })));
+        }
+
+        let frames = queue.take_coalesced();
+        assert!(frames.len() > 1, "eight links do not fit one capped frame");
+        let mut merged = WorldDelta::default();
+        for frame in &frames {
+            assert!(frame.len() <= cap, "frame of {} bytes", frame.len());
+            merged.merge(bincode::deserialize(frame).expect("frame decodes"));
+        }
+        assert_eq!(merged.logistics.len(), 8);
+        assert_eq!(merged.header.tick, 8);
+    }
+
+    #[test]
+    fn only_whole_deltas_are_coalesced() {
+        let part = |sequence: u64, index: u32, count: u32| {
+            delta(sequence, |d| {
+                d.header.sequence = sequence;
+                d.header.delta_part = index;
+                d.header.delta_part_count = count;
+                d.logistics = vec![link(sequence * 10 + u64::from(index), 1)];
+            })
+        };
+        let queue = SubscriberQueue::default();
+        // The writer already sent part 0 of delta 5; parts 1 and 2 are still queued.
+        queue.push(delta_frame(part(5, 1, 3)));
+        queue.push(delta_frame(part(5, 2, 3)));
+        queue.push(delta_frame(part(6, 0, 0)));
+        queue.push(delta_frame(part(7, 0, 2)));
+        queue.push(delta_frame(part(7, 1, 2)));
+        // Delta 8's second part has not been queued yet.
+        queue.push(delta_frame(part(8, 0, 2)));
+
+        let frames: Vec<WorldDelta> = queue
+            .take_coalesced()
+            .iter()
+            .map(|frame| bincode::deserialize(frame).expect("frame decodes"))
+            .collect();
+        let marks: Vec<_> = frames
+            .iter()
+            .map(|d| {
+                let h = &d.header;
+                (
+                    h.sequence,
+                    h.delta_part,
+                    h.delta_part_count,
+                    h.sequence_span,
+                )
+            })
+            .collect();
+        assert_eq!(
+            marks,
+            vec![(5, 1, 3, 0), (5, 2, 3, 0), (7, 0, 0, 2), (8, 0, 2, 0)],
+            "split tails pass through, deltas 6 and 7 merge, delta 8's head waits for its rest"
+        );
+        assert_eq!(frames[2].logistics.len(), 3, "delta 6 and both parts of 7");
+    }
+
+    #[test]
+    fn raw_frames_split_delta_runs_and_readded_ids_leave_the_removed_list() {
+        let queue = SubscriberQueue::default();
+        let snapshot = Arc::new(vec![0xAB; 4]);
+        queue.push(delta_frame(delta(1, |d| d.removed_logistics = vec![5])));
+        queue.push(delta_frame(delta(2, |d| d.logistics = vec![link(5, 2)])));
+        queue.push(OutboundFrame::Raw(Arc::clone(&snapshot)));
+        queue.push(delta_frame(delta(3, |d| d.logistics = vec![link(6, 3)])));
+
+        let frames = queue.take_coalesced();
+        assert_eq!(frames.len(), 3);
+        let merged: WorldDelta = bincode::deserialize(&frames[0]).expect("frame decodes");
+        assert_eq!(merged.logistics, vec![link(5, 2)]);
+        assert!(merged.removed_logistics.is_empty());
+        assert_eq!(frames[1], snapshot, "a full frame passes through untouched");
+        let single: WorldDelta = bincode::deserialize(&frames[2]).expect("frame decodes");
+        assert_eq!(single.header.tick, 3);
+    }
 }
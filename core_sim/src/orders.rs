@@ -2,6 +2,9 @@ use std::collections::{HashMap, HashSet};
 use std::fmt;
 
 use bevy::prelude::Resource;
+use rand::{rngs::SmallRng, seq::SliceRandom, SeedableRng};
+
+use crate::scalar::Scalar;
 
 /// Identifier for a faction participating in the turn loop.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
@@ -69,8 +72,63 @@ pub enum SubmitError {
     UnknownFaction(FactionId),
     #[error("orders for faction {0} already submitted")]
     DuplicateSubmission(FactionId),
+    #[error(
+        "faction {faction} submitted {attempted} orders with {used} of {limit} already used this turn"
+    )]
+    RateLimited {
+        faction: FactionId,
+        attempted: usize,
+        used: usize,
+        limit: usize,
+    },
+}
+
+/// An order held by the [`TurnQueue`] until the turn it was scheduled for, then injected into
+/// that faction's submission.
+#[derive(Debug, Clone)]
+pub struct ScheduledOrder {
+    pub faction: FactionId,
+    pub order: Order,
+    pub execute_at_tick: u64,
+    /// Cost recorded against the faction while the order waits, summed by
+    /// [`TurnQueue::reserved_cost`]. Bookkeeping only: nothing is debited from `FactionInventory`,
+    /// so activation or [`TurnQueue::undo_last`] just stops counting it.
+    pub reserved_cost: Scalar,
+    /// Submission sequence, so undo can tell which of a faction's orders came last.
+    sequence: u64,
+    /// Turn the order was scheduled on, whose rate-limit allowance it holds.
+    scheduled_turn: u64,
+}
+
+/// Error that can occur when scheduling an order for a future turn.
+#[derive(Debug, thiserror::Error)]
+pub enum ScheduleError {
+    #[error("faction {0} is not registered")]
+    UnknownFaction(FactionId),
+    #[error("tick {tick} is not in the future (current turn {current})")]
+    NotInFuture { tick: u64, current: u64 },
+    #[error(
+        "tick {tick} is beyond the scheduling horizon of {horizon} turns (current turn {current})"
+    )]
+    BeyondHorizon {
+        tick: u64,
+        current: u64,
+        horizon: u64,
+    },
+    #[error("faction {faction} already holds {used} of {limit} orders this turn")]
+    RateLimited {
+        faction: FactionId,
+        used: usize,
+        limit: usize,
+    },
 }
 
+/// Turns ahead an order may be scheduled when no horizon is configured.
+pub const DEFAULT_SCHEDULE_HORIZON: u64 = 20;
+
+/// Orders one faction may submit per turn when no cap is configured.
+pub const DEFAULT_MAX_ORDERS_PER_TURN: usize = 64;
+
 /// Tracks turn collection and resolution state.
 #[derive(Resource, Debug, Clone)]
 pub struct TurnQueue {
@@ -78,6 +136,20 @@ pub struct TurnQueue {
     awaiting: HashSet<FactionId>,
     submissions: HashMap<FactionId, FactionOrders>,
     current_turn: u64,
+    scheduled: Vec<ScheduledOrder>,
+    schedule_horizon: u64,
+    shuffle_order: bool,
+    /// Sequence stamped on each submission and scheduled order; only ever increases.
+    next_sequence: u64,
+    /// Sequence of each faction's submission this turn.
+    submitted_at: HashMap<FactionId, u64>,
+    max_orders_per_turn: usize,
+    /// Orders each faction holds against this turn's allowance: submitted or scheduled this turn,
+    /// less any withdrawn by undo. Submit/undo cycles therefore never hold more than the cap.
+    orders_this_turn: HashMap<FactionId, usize>,
+    /// Factions whose submission this turn is a lone `EndTurn`, which the rate limit exempts so a
+    /// faction that spent its allowance on scheduled orders can still end its turn.
+    exempt_submissions: HashSet<FactionId>,
 }
 
 impl TurnQueue {
@@ -88,7 +160,69 @@ impl TurnQueue {
             awaiting,
             submissions: HashMap::new(),
             current_turn: 0,
+            scheduled: Vec::new(),
+            schedule_horizon: DEFAULT_SCHEDULE_HORIZON,
+            shuffle_order: true,
+            next_sequence: 0,
+            submitted_at: HashMap::new(),
+            max_orders_per_turn: DEFAULT_MAX_ORDERS_PER_TURN,
+            orders_this_turn: HashMap::new(),
+            exempt_submissions: HashSet::new(),
+        }
+    }
+
+    /// Whether [`TurnQueue::resolution_order`] shuffles factions each turn (the default) or keeps
+    /// ascending faction-id order.
+    pub fn with_order_shuffle(mut self, enabled: bool) -> Self {
+        self.shuffle_order = enabled;
+        self
+    }
+
+    /// The order factions' orders resolve in this turn. With shuffling enabled this is a
+    /// permutation seeded from `world_seed + current_turn`, so no faction systematically resolves
+    /// first, yet a replay of the same world reproduces it exactly.
+    pub fn resolution_order(&self, world_seed: u64) -> Vec<FactionId> {
+        let mut order = self.factions.clone();
+        order.sort();
+        if self.shuffle_order {
+            let mut rng = SmallRng::seed_from_u64(world_seed.wrapping_add(self.current_turn));
+            order.shuffle(&mut rng);
         }
+        order
+    }
+
+    /// Caps how many turns ahead [`TurnQueue::schedule_order`] accepts; `0` is clamped to `1`.
+    pub fn with_schedule_horizon(mut self, horizon: u64) -> Self {
+        self.set_schedule_horizon(horizon);
+        self
+    }
+
+    /// [`TurnQueue::with_schedule_horizon`] on a live queue, for config reloads. Orders already
+    /// scheduled past a shorter horizon stay queued.
+    pub fn set_schedule_horizon(&mut self, horizon: u64) {
+        self.schedule_horizon = horizon.max(1);
+    }
+
+    pub fn schedule_horizon(&self) -> u64 {
+        self.schedule_horizon
+    }
+
+    /// Caps the orders one faction may hold per turn, submitted through
+    /// [`TurnQueue::submit_orders`] or scheduled; `0` is clamped to `1`. A submission of a lone
+    /// `EndTurn` is exempt, so a faction can always end its turn.
+    pub fn with_order_rate_limit(mut self, max_orders_per_turn: usize) -> Self {
+        self.set_order_rate_limit(max_orders_per_turn);
+        self
+    }
+
+    /// [`TurnQueue::with_order_rate_limit`] on a live queue, for config reloads. Orders a faction
+    /// already holds past a lower cap stay queued; it just cannot add more this turn.
+    pub fn set_order_rate_limit(&mut self, max_orders_per_turn: usize) {
+        self.max_orders_per_turn = max_orders_per_turn.max(1);
+    }
+
+    pub fn max_orders_per_turn(&self) -> usize {
+        self.max_orders_per_turn
     }
 
     pub fn current_turn(&self) -> u64 {
@@ -110,7 +244,24 @@ impl TurnQueue {
         if self.submissions.contains_key(&faction) {
             return Err(SubmitError::DuplicateSubmission(faction));
         }
+        if matches!(orders.orders.as_slice(), [Order::EndTurn]) {
+            self.exempt_submissions.insert(faction);
+        } else {
+            let used = self.orders_held(faction);
+            let attempted = orders.orders.len();
+            if used + attempted > self.max_orders_per_turn {
+                return Err(SubmitError::RateLimited {
+                    faction,
+                    attempted,
+                    used,
+                    limit: self.max_orders_per_turn,
+                });
+            }
+            self.orders_this_turn.insert(faction, used + attempted);
+        }
         self.submissions.insert(faction, orders);
+        let sequence = self.bump_sequence();
+        self.submitted_at.insert(faction, sequence);
         self.awaiting.remove(&faction);
         if self.awaiting.is_empty() {
             Ok(SubmitOutcome::ReadyToResolve)
@@ -121,17 +272,167 @@ impl TurnQueue {
         }
     }
 
+    /// Holds `order` until turn `execute_at_tick`, which must be after the current turn and no
+    /// more than the scheduling horizon ahead of it. It counts against this turn's rate limit.
+    pub fn schedule_order(
+        &mut self,
+        faction: FactionId,
+        order: Order,
+        execute_at_tick: u64,
+    ) -> Result<(), ScheduleError> {
+        self.schedule_order_with_cost(faction, order, execute_at_tick, Scalar::zero())
+    }
+
+    /// [`TurnQueue::schedule_order`] for an order that records `reserved_cost` until it activates
+    /// or is withdrawn.
+    pub fn schedule_order_with_cost(
+        &mut self,
+        faction: FactionId,
+        order: Order,
+        execute_at_tick: u64,
+        reserved_cost: Scalar,
+    ) -> Result<(), ScheduleError> {
+        if !self.factions.contains(&faction) {
+            return Err(ScheduleError::UnknownFaction(faction));
+        }
+        let current = self.current_turn;
+        if execute_at_tick <= current {
+            return Err(ScheduleError::NotInFuture {
+                tick: execute_at_tick,
+                current,
+            });
+        }
+        if execute_at_tick - current > self.schedule_horizon {
+            return Err(ScheduleError::BeyondHorizon {
+                tick: execute_at_tick,
+                current,
+                horizon: self.schedule_horizon,
+            });
+        }
+        let used = self.orders_held(faction);
+        if used >= self.max_orders_per_turn {
+            return Err(ScheduleError::RateLimited {
+                faction,
+                used,
+                limit: self.max_orders_per_turn,
+            });
+        }
+        self.orders_this_turn.insert(faction, used + 1);
+        let sequence = self.bump_sequence();
+        self.scheduled.push(ScheduledOrder {
+            faction,
+            order,
+            execute_at_tick,
+            reserved_cost,
+            sequence,
+            scheduled_turn: current,
+        });
+        Ok(())
+    }
+
+    pub fn scheduled(&self) -> &[ScheduledOrder] {
+        &self.scheduled
+    }
+
+    /// Total cost `faction` has reserved on scheduled orders that have not activated yet.
+    pub fn reserved_cost(&self, faction: FactionId) -> Scalar {
+        self.scheduled
+            .iter()
+            .filter(|scheduled| scheduled.faction == faction)
+            .fold(Scalar::zero(), |total, scheduled| {
+                total + scheduled.reserved_cost
+            })
+    }
+
+    /// Withdraws `faction`'s most recently submitted order that is still pending: the last order
+    /// of this turn's submission or a scheduled order still waiting for its tick, whichever came
+    /// later. A withdrawn scheduled order drops out of [`TurnQueue::reserved_cost`]; emptying the
+    /// submission puts the faction back on the awaiting list. A withdrawn order held against this
+    /// turn's rate limit hands its slot back. Orders already drained for resolution, including
+    /// scheduled orders that activated, are out of reach.
+    pub fn undo_last(&mut self, faction: FactionId) -> Option<Order> {
+        let submitted_at = self
+            .submissions
+            .get(&faction)
+            .filter(|orders| !orders.orders.is_empty())
+            .and_then(|_| self.submitted_at.get(&faction).copied());
+        let latest_scheduled = self
+            .scheduled
+            .iter()
+            .enumerate()
+            .filter(|(_, scheduled)| scheduled.faction == faction)
+            .max_by_key(|(_, scheduled)| scheduled.sequence)
+            .map(|(idx, scheduled)| (idx, scheduled.sequence));
+
+        if let Some((idx, sequence)) = latest_scheduled {
+            if submitted_at.map_or(true, |submitted| sequence > submitted) {
+                let withdrawn = self.scheduled.remove(idx);
+                if withdrawn.scheduled_turn == self.current_turn {
+                    self.release_allowance(faction);
+                }
+                return Some(withdrawn.order);
+            }
+        }
+
+        if submitted_at.is_none() {
+            return None;
+        }
+        let orders = self.submissions.get_mut(&faction)?;
+        let order = orders.orders.pop();
+        let emptied = orders.orders.is_empty();
+        if !self.exempt_submissions.contains(&faction) {
+            self.release_allowance(faction);
+        }
+        if emptied {
+            self.submissions.remove(&faction);
+            self.submitted_at.remove(&faction);
+            self.exempt_submissions.remove(&faction);
+            self.awaiting.insert(faction);
+        }
+        order
+    }
+
+    /// Orders `faction` holds against this turn's rate limit.
+    fn orders_held(&self, faction: FactionId) -> usize {
+        self.orders_this_turn.get(&faction).copied().unwrap_or(0)
+    }
+
+    fn release_allowance(&mut self, faction: FactionId) {
+        if let Some(used) = self.orders_this_turn.get_mut(&faction) {
+            *used = used.saturating_sub(1);
+        }
+    }
+
     pub fn is_ready(&self) -> bool {
         self.awaiting.is_empty()
     }
 
-    pub fn drain_ready_orders(&mut self) -> Vec<(FactionId, FactionOrders)> {
+    /// Takes this turn's submissions (plus any scheduled orders now due) in
+    /// [`TurnQueue::resolution_order`].
+    pub fn drain_ready_orders(&mut self, world_seed: u64) -> Vec<(FactionId, FactionOrders)> {
         debug_assert!(
             self.awaiting.is_empty(),
             "orders requested before all submissions"
         );
+        let current = self.current_turn;
+        let (due, pending): (Vec<_>, Vec<_>) = std::mem::take(&mut self.scheduled)
+            .into_iter()
+            .partition(|scheduled| scheduled.execute_at_tick <= current);
+        self.scheduled = pending;
+        for scheduled in due {
+            self.submissions
+                .entry(scheduled.faction)
+                .or_insert_with(|| FactionOrders {
+                    orders: Vec::new(),
+                    note: None,
+                })
+                .orders
+                .push(scheduled.order);
+        }
+        let order = self.resolution_order(world_seed);
+        self.submitted_at.clear();
         let mut collected: Vec<_> = self.submissions.drain().collect();
-        collected.sort_by_key(|(id, _)| *id);
+        collected.sort_by_key(|(id, _)| order.iter().position(|faction| faction == id));
         collected
     }
 
@@ -139,18 +440,329 @@ impl TurnQueue {
         self.current_turn = self.current_turn.wrapping_add(1);
         self.awaiting = self.factions.iter().copied().collect();
         self.submissions.clear();
+        self.submitted_at.clear();
+        self.orders_this_turn.clear();
+        self.exempt_submissions.clear();
     }
 
     pub fn force_submit_all<F>(&mut self, mut builder: F)
     where
         F: FnMut(FactionId) -> FactionOrders,
     {
-        for faction in &self.factions {
-            if !self.submissions.contains_key(faction) {
-                let orders = builder(*faction);
-                self.submissions.insert(*faction, orders);
-                self.awaiting.remove(faction);
+        for idx in 0..self.factions.len() {
+            let faction = self.factions[idx];
+            if !self.submissions.contains_key(&faction) {
+                let orders = builder(faction);
+                self.submissions.insert(faction, orders);
+                let sequence = self.bump_sequence();
+                self.submitted_at.insert(faction, sequence);
+                self.awaiting.remove(&faction);
             }
         }
     }
+
+    fn bump_sequence(&mut self) -> u64 {
+        self.next_sequence += 1;
+        self.next_sequence
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn resolve(queue: &mut TurnQueue) -> usize {
+        queue.force_submit_all(|_| FactionOrders::end_turn());
+        let drained = queue.drain_ready_orders(0);
+        queue.advance_turn();
+        drained.iter().map(|(_, orders)| orders.orders.len()).sum()
+    }
+
+    #[test]
+    fn scheduled_order_activates_on_its_tick_and_not_before() {
+        let faction = FactionId(0);
+        let mut queue = TurnQueue::new(vec![faction]);
+        queue
+            .schedule_order(faction, Order::EndTurn, 3)
+            .expect("three turns out is schedulable");
+
+        // Turns 0..=2 carry only the regular end-turn submission.
+        for turn in 0..3 {
+            assert_eq!(queue.current_turn(), turn);
+            assert_eq!(
+                resolve(&mut queue),
+                1,
+                "turn {turn} should not see the order"
+            );
+            assert_eq!(queue.scheduled().len(), 1);
+        }
+
+        assert_eq!(queue.current_turn(), 3);
+        assert_eq!(resolve(&mut queue), 2, "turn 3 injects the scheduled order");
+        assert!(queue.scheduled().is_empty());
+        assert_eq!(resolve(&mut queue), 1);
+    }
+
+    #[test]
+    fn schedule_order_rejects_past_and_out_of_horizon_ticks() {
+        let faction = FactionId(0);
+        let mut queue = TurnQueue::new(vec![faction]).with_schedule_horizon(5);
+        resolve(&mut queue);
+        resolve(&mut queue);
+
+        assert!(matches!(
+            queue.schedule_order(faction, Order::EndTurn, 2),
+            Err(ScheduleError::NotInFuture {
+                tick: 2,
+                current: 2
+            })
+        ));
+        assert!(matches!(
+            queue.schedule_order(faction, Order::EndTurn, 8),
+            Err(ScheduleError::BeyondHorizon { horizon: 5, .. })
+        ));
+        assert!(matches!(
+            queue.schedule_order(FactionId(9), Order::EndTurn, 3),
+            Err(ScheduleError::UnknownFaction(FactionId(9)))
+        ));
+        queue
+            .schedule_order(faction, Order::EndTurn, 7)
+            .expect("the horizon edge is accepted");
+
+        queue.set_schedule_horizon(10);
+        queue
+            .schedule_order(faction, Order::EndTurn, 8)
+            .expect("a reloaded horizon applies to the live queue");
+        queue.set_schedule_horizon(0);
+        assert_eq!(queue.schedule_horizon(), 1);
+        assert_eq!(
+            queue.scheduled().len(),
+            2,
+            "already scheduled orders stay queued"
+        );
+    }
+
+    #[test]
+    fn undo_last_withdraws_only_the_latest_order_and_releases_its_reservation() {
+        let faction = FactionId(0);
+        let mut queue = TurnQueue::new(vec![faction, FactionId(1)]);
+        queue
+            .schedule_order_with_cost(faction, Order::EndTurn, 2, Scalar::from_f32(1.5))
+            .expect("first order schedules");
+        queue
+            .schedule_order_with_cost(faction, Order::EndTurn, 4, Scalar::from_f32(2.0))
+            .expect("second order schedules");
+        assert_eq!(queue.reserved_cost(faction), Scalar::from_f32(3.5));
+
+        assert!(matches!(queue.undo_last(faction), Some(Order::EndTurn)));
+        assert_eq!(queue.scheduled().len(), 1);
+        assert_eq!(
+            queue.scheduled()[0].execute_at_tick,
+            2,
+            "the first order stays"
+        );
+        assert_eq!(queue.reserved_cost(faction), Scalar::from_f32(1.5));
+        assert!(queue.undo_last(FactionId(1)).is_none(), "nothing queued");
+
+        // A later end-turn submission is now the latest order; withdrawing it un-readies the
+        // faction and leaves the scheduled order alone.
+        queue
+            .submit_orders(faction, FactionOrders::end_turn())
+            .expect("submission accepted");
+        assert!(matches!(queue.undo_last(faction), Some(Order::EndTurn)));
+        assert!(queue.awaiting().contains(&faction));
+        assert_eq!(queue.scheduled().len(), 1);
+        assert_eq!(queue.reserved_cost(faction), Scalar::from_f32(1.5));
+    }
+
+    #[test]
+    fn undo_last_cannot_reach_resolved_or_activated_orders() {
+        let faction = FactionId(0);
+        let mut queue = TurnQueue::new(vec![faction]);
+        queue
+            .schedule_order_with_cost(faction, Order::EndTurn, 1, Scalar::one())
+            .expect("next turn is schedulable");
+
+        resolve(&mut queue);
+        assert_eq!(
+            resolve(&mut queue),
+            2,
+            "turn 1 activates the scheduled order"
+        );
+        assert!(queue.scheduled().is_empty());
+        assert_eq!(queue.reserved_cost(faction), Scalar::zero());
+        assert!(queue.undo_last(faction).is_none());
+
+        // Submitted and drained for resolution, but the turn has not advanced yet.
+        queue.force_submit_all(|_| FactionOrders::end_turn());
+        queue.drain_ready_orders(0);
+        assert!(queue.undo_last(faction).is_none());
+    }
+
+    fn four_factions() -> Vec<FactionId> {
+        (0..4).map(FactionId).collect()
+    }
+
+    #[test]
+    fn resolution_order_is_reproducible_and_varies_across_turns() {
+        let mut queue = TurnQueue::new(four_factions());
+        let mut orders = Vec::new();
+        for _ in 0..8 {
+            let order = queue.resolution_order(42);
+            assert_eq!(order, queue.resolution_order(42), "same seed + tick");
+            let mut sorted = order.clone();
+            sorted.sort();
+            assert_eq!(sorted, four_factions(), "order is a permutation");
+            orders.push(order);
+            resolve(&mut queue);
+        }
+        assert!(
+            orders.windows(2).any(|pair| pair[0] != pair[1]),
+            "order should change between turns: {orders:?}"
+        );
+
+        let replay = TurnQueue::new(four_factions());
+        assert_eq!(replay.resolution_order(42), orders[0]);
+    }
+
+    #[test]
+    fn each_faction_leads_roughly_equally_often() {
+        let mut queue = TurnQueue::new(four_factions());
+        let turns = 4000;
+        let mut leads = [0usize; 4];
+        for _ in 0..turns {
+            leads[queue.resolution_order(7)[0].0 as usize] += 1;
+            queue.advance_turn();
+        }
+        let expected = turns / 4;
+        for (faction, count) in leads.iter().enumerate() {
+            assert!(
+                count.abs_diff(expected) < expected / 5,
+                "faction {faction} led {count} of {turns} turns: {leads:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn drain_follows_resolution_order_and_shuffle_can_be_disabled() {
+        let mut queue = TurnQueue::new(four_factions());
+        queue.force_submit_all(|_| FactionOrders::end_turn());
+        let expected = queue.resolution_order(99);
+        let drained: Vec<_> = queue
+            .drain_ready_orders(99)
+            .into_iter()
+            .map(|(faction, _)| faction)
+            .collect();
+        assert_eq!(drained, expected);
+
+        let fixed = TurnQueue::new(vec![FactionId(2), FactionId(0), FactionId(1)])
+            .with_order_shuffle(false);
+        assert_eq!(
+            fixed.resolution_order(99),
+            vec![FactionId(0), FactionId(1), FactionId(2)]
+        );
+    }
+
+    fn end_turns(count: usize) -> FactionOrders {
+        FactionOrders {
+            orders: vec![Order::EndTurn; count],
+            note: None,
+        }
+    }
+
+    #[test]
+    fn submissions_past_the_rate_limit_are_rejected() {
+        let faction = FactionId(0);
+        let mut queue = TurnQueue::new(vec![faction, FactionId(1)]).with_order_rate_limit(3);
+
+        assert!(matches!(
+            queue.submit_orders(faction, end_turns(5)),
+            Err(SubmitError::RateLimited {
+                attempted: 5,
+                used: 0,
+                limit: 3,
+                ..
+            })
+        ));
+        assert!(queue.awaiting().contains(&faction), "the flood was dropped");
+
+        queue
+            .submit_orders(faction, end_turns(3))
+            .expect("a submission at the cap is accepted");
+        // Withdrawing orders hands their allowance back.
+        for _ in 0..3 {
+            queue.undo_last(faction).expect("order withdrawn");
+        }
+        // Scheduled orders draw on the same allowance.
+        queue
+            .schedule_order(faction, Order::EndTurn, 2)
+            .expect("the allowance was refunded");
+        assert!(matches!(
+            queue.submit_orders(faction, end_turns(3)),
+            Err(SubmitError::RateLimited {
+                attempted: 3,
+                used: 1,
+                ..
+            })
+        ));
+        queue
+            .submit_orders(faction, end_turns(2))
+            .expect("two slots are left");
+        assert!(matches!(
+            queue.schedule_order(faction, Order::EndTurn, 3),
+            Err(ScheduleError::RateLimited {
+                used: 3,
+                limit: 3,
+                ..
+            })
+        ));
+        queue
+            .undo_last(faction)
+            .expect("the submission's last order");
+        queue
+            .schedule_order(faction, Order::EndTurn, 3)
+            .expect("the withdrawn order's slot is free again");
+
+        // Other factions keep their own allowance, and a lone end-turn is never refused: a
+        // faction that scheduled its whole allowance can still end its turn.
+        let other = FactionId(1);
+        for tick in 1..=3 {
+            queue
+                .schedule_order(other, Order::EndTurn, tick)
+                .expect("within the other faction's allowance");
+        }
+        assert!(queue.submit_orders(other, end_turns(2)).is_err());
+        assert!(queue.submit_orders(other, end_turns(1)).is_ok());
+    }
+
+    #[test]
+    fn the_rate_limit_resets_each_turn() {
+        let faction = FactionId(0);
+        let mut queue = TurnQueue::new(vec![faction]).with_order_rate_limit(2);
+        queue
+            .schedule_order(faction, Order::EndTurn, 5)
+            .expect("within the cap");
+        queue
+            .schedule_order(faction, Order::EndTurn, 6)
+            .expect("at the cap");
+        assert!(matches!(
+            queue.submit_orders(faction, end_turns(2)),
+            Err(SubmitError::RateLimited { used: 2, .. })
+        ));
+        resolve(&mut queue);
+        // An order scheduled on an earlier turn holds that turn's allowance, not this one's.
+        queue.undo_last(faction).expect("the later scheduled order");
+
+        assert_eq!(
+            queue.submit_orders(faction, end_turns(2)).unwrap(),
+            SubmitOutcome::ReadyToResolve,
+            "the next turn starts with a full allowance"
+        );
+        assert_eq!(
+            TurnQueue::new(vec![faction])
+                .with_order_rate_limit(0)
+                .max_orders_per_turn(),
+            1
+        );
+    }
 }
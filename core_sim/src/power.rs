@@ -1,6 +1,7 @@
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
 
 use bevy::prelude::*;
+use serde::Deserialize;
 
 use crate::{
     great_discovery::GreatDiscoveryId,
@@ -18,6 +19,155 @@ impl PowerNodeId {
     }
 }
 
+/// Role a power node plays on the grid. `Hybrid` both generates and buffers locally, which is
+/// how every node behaved before roles existed, so it stays the default.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum PowerNodeKind {
+    /// Produces power but holds no local reserve; surplus is pushed to neighbouring storage.
+    Generator,
+    /// Buffers surplus from itself and its neighbours; produces nothing.
+    Storage,
+    /// Only draws power.
+    Consumer,
+    #[default]
+    Hybrid,
+}
+
+impl PowerNodeKind {
+    #[inline]
+    pub fn generates(self) -> bool {
+        matches!(self, PowerNodeKind::Generator | PowerNodeKind::Hybrid)
+    }
+
+    #[inline]
+    pub fn stores(self) -> bool {
+        matches!(self, PowerNodeKind::Storage | PowerNodeKind::Hybrid)
+    }
+}
+
+/// How the power phase assigns the shortfall the grid could not route or cover from storage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LoadSheddingPolicy {
+    /// Every node sheds its own uncovered deficit.
+    #[default]
+    Local,
+    /// Each connected grid's pooled shortfall is cut from its lowest-priority loads first; a
+    /// tier that only partly goes dark shares its cut in proportion to demand.
+    Priority,
+    /// Each connected grid's pooled shortfall is shared across its loads in proportion to demand.
+    Proportional,
+}
+
+/// A node's load as the shedding pass sees it. Nodes left in surplus carry zero demand: they
+/// serve themselves and are never shed.
+#[derive(Debug, Clone, Copy)]
+pub struct SheddableLoad {
+    pub demand: Scalar,
+    pub priority: u8,
+    pub deficit: Scalar,
+    /// Connected grid the node sits on. Pooled policies never move a shortfall between grids
+    /// that no line joins.
+    pub component: u32,
+}
+
+/// Power cut from each load under `policy`, in input order. The pooled policies shed the same
+/// total per connected grid as `Local` (the sum of its deficits); they only change who goes dark.
+pub fn shed_load(policy: LoadSheddingPolicy, loads: &[SheddableLoad]) -> Vec<Scalar> {
+    let mut shed = vec![scalar_zero(); loads.len()];
+    if policy == LoadSheddingPolicy::Local {
+        for (slot, load) in shed.iter_mut().zip(loads) {
+            *slot = load.deficit;
+        }
+        return shed;
+    }
+
+    let mut components: BTreeMap<u32, Vec<usize>> = BTreeMap::new();
+    for (idx, load) in loads.iter().enumerate() {
+        components.entry(load.component).or_default().push(idx);
+    }
+    for members in components.values() {
+        let shortfall = members
+            .iter()
+            .fold(scalar_zero(), |total, &idx| total + loads[idx].deficit);
+        if policy == LoadSheddingPolicy::Priority {
+            shed_by_priority(shortfall, members, loads, &mut shed);
+        } else {
+            share_shortfall(shortfall, members, loads, &mut shed);
+        }
+    }
+    shed
+}
+
+/// Cut `shortfall` from `members` tier by tier, lowest priority first.
+fn shed_by_priority(
+    shortfall: Scalar,
+    members: &[usize],
+    loads: &[SheddableLoad],
+    shed: &mut [Scalar],
+) {
+    let mut tiers: Vec<u8> = members.iter().map(|&idx| loads[idx].priority).collect();
+    tiers.sort_unstable();
+    tiers.dedup();
+    let mut remaining = shortfall;
+    for tier in tiers {
+        if remaining <= scalar_zero() {
+            break;
+        }
+        let tier_members: Vec<usize> = members
+            .iter()
+            .copied()
+            .filter(|&idx| loads[idx].priority == tier)
+            .collect();
+        let tier_demand = tier_members
+            .iter()
+            .fold(scalar_zero(), |total, &idx| total + loads[idx].demand);
+        if tier_demand <= remaining {
+            for &idx in &tier_members {
+                shed[idx] = loads[idx].demand;
+            }
+            remaining -= tier_demand;
+        } else {
+            share_shortfall(remaining, &tier_members, loads, shed);
+            remaining = scalar_zero();
+        }
+    }
+}
+
+/// Split `amount` across `members` in proportion to demand. The last member with demand takes
+/// the fixed-point rounding residue so the split sums exactly.
+fn share_shortfall(
+    amount: Scalar,
+    members: &[usize],
+    loads: &[SheddableLoad],
+    shed: &mut [Scalar],
+) {
+    let total_demand = members
+        .iter()
+        .fold(scalar_zero(), |total, &idx| total + loads[idx].demand);
+    if total_demand <= scalar_zero() || amount <= scalar_zero() {
+        return;
+    }
+    let Some(&last) = members
+        .iter()
+        .rev()
+        .find(|&&idx| loads[idx].demand > scalar_zero())
+    else {
+        return;
+    };
+    let mut assigned = scalar_zero();
+    for &idx in members {
+        if idx == last {
+            continue;
+        }
+        let cut =
+            (amount * (loads[idx].demand / total_demand)).clamp(scalar_zero(), loads[idx].demand);
+        shed[idx] = cut;
+        assigned += cut;
+    }
+    shed[last] = (amount - assigned).clamp(scalar_zero(), loads[last].demand);
+}
+
 /// Telemetry captured for each power node after the power phase resolves.
 #[derive(Debug, Clone)]
 pub struct PowerGridNodeTelemetry {
@@ -30,6 +180,8 @@ pub struct PowerGridNodeTelemetry {
     pub stability: Scalar,
     pub surplus: Scalar,
     pub deficit: Scalar,
+    /// Load cut from this node this turn under the configured `LoadSheddingPolicy`.
+    pub shed: Scalar,
     pub incident_count: u32,
 }
 
@@ -45,6 +197,7 @@ impl Default for PowerGridNodeTelemetry {
             stability: Scalar::one(),
             surplus: scalar_zero(),
             deficit: scalar_zero(),
+            shed: scalar_zero(),
             incident_count: 0,
         }
     }
@@ -1,9 +1,15 @@
-use std::collections::VecDeque;
+use std::collections::{HashMap, VecDeque};
 
 use bevy::prelude::*;
 use rand::{rngs::SmallRng, seq::SliceRandom, SeedableRng};
 use sim_runtime::TerrainTags;
 
+use crate::{
+    components::PopulationCohort,
+    grid_utils::{hex_neighbors_wrapped, HEX_DIRECTION_COUNT},
+    resources::TileRegistry,
+};
+
 pub type ProvinceId = u32;
 
 #[derive(Resource, Debug, Clone)]
@@ -14,6 +20,11 @@ pub struct ProvinceMap {
     land_tiles: usize,
     province_count: ProvinceId,
     province_tiles: Vec<usize>,
+    /// Settlement id seating each province (`seats[id - 1]`); empty for generated maps.
+    seats: Vec<u64>,
+    /// Row-major index of each tile entity, so cohorts (which only know their tile) can be placed.
+    /// Filled by [`Self::index_tiles`]; empty until then for generated maps.
+    tile_index: HashMap<Entity, usize>,
 }
 
 impl ProvinceMap {
@@ -116,7 +127,132 @@ impl ProvinceMap {
             land_tiles,
             province_count,
             province_tiles,
+            seats: Vec::new(),
+            tile_index: HashMap::new(),
+        }
+    }
+
+    /// Partitions the grid into one province per settlement: every passable tile joins the
+    /// settlement it is closest to.
+    ///
+    /// **Distance** is the number of odd-r hex steps along a path through passable tiles (a
+    /// multi-source BFS from all settlements at once), not straight-line distance — so water
+    /// (`TerrainTags::WATER`, the map's impassable terrain) acts as a barrier and a tile on the
+    /// far side of a lake belongs to whoever can walk to it soonest. Ties go to the settlement
+    /// listed first. Like [`Self::generate`], the grid does not wrap horizontally.
+    ///
+    /// Settlement `i` seats province `i + 1` ([`Self::seat`] maps back). A settlement that is
+    /// off the grid, on water, or on a tile an earlier settlement already holds claims nothing;
+    /// passable tiles no settlement can reach (other landmasses) stay unassigned. `tags` is the
+    /// row-major terrain-tag grid for `tiles`.
+    pub fn partition_from_settlements(
+        settlements: &[(u64, (u32, u32))],
+        tiles: &TileRegistry,
+        tags: &[TerrainTags],
+    ) -> Self {
+        let width = tiles.width;
+        let height = tiles.height;
+        let total = (width as usize).saturating_mul(height as usize);
+        let passable = |idx: usize| {
+            tags.get(idx)
+                .map(|tag| !tag.contains(TerrainTags::WATER))
+                .unwrap_or(false)
+        };
+        let land_tiles = (0..total).filter(|idx| passable(*idx)).count();
+
+        let mut assignments = vec![None; total];
+        let mut frontier = VecDeque::new();
+        for (seat, &(_, (x, y))) in settlements.iter().enumerate() {
+            if x >= width || y >= height {
+                continue;
+            }
+            let idx = (y * width + x) as usize;
+            if !passable(idx) || assignments[idx].is_some() {
+                continue;
+            }
+            let province_id = seat as ProvinceId + 1;
+            assignments[idx] = Some(province_id);
+            frontier.push_back((x, y, province_id));
+        }
+
+        let mut neighbors = Vec::with_capacity(HEX_DIRECTION_COUNT);
+        while let Some((x, y, province_id)) = frontier.pop_front() {
+            neighbors.clear();
+            neighbors.extend(hex_neighbors_wrapped(x, y, width, height, false));
+            for &(nx, ny) in &neighbors {
+                let nidx = (ny * width + nx) as usize;
+                if !passable(nidx) || assignments[nidx].is_some() {
+                    continue;
+                }
+                assignments[nidx] = Some(province_id);
+                frontier.push_back((nx, ny, province_id));
+            }
         }
+
+        let province_count = settlements.len() as ProvinceId;
+        let mut province_tiles = vec![0usize; province_count as usize + 1];
+        for assignment in assignments.iter().flatten() {
+            province_tiles[*assignment as usize] += 1;
+        }
+        let mut map = ProvinceMap {
+            width,
+            height,
+            assignments,
+            land_tiles,
+            province_count,
+            province_tiles,
+            seats: settlements.iter().map(|(id, _)| *id).collect(),
+            tile_index: HashMap::new(),
+        };
+        map.index_tiles(&tiles.tiles);
+        map
+    }
+
+    /// Records the row-major tile entities (`TileRegistry::tiles`) so [`Self::province_of_tile`]
+    /// can resolve an entity. Worldgen calls this once the tiles are spawned.
+    pub fn index_tiles(&mut self, tiles: &[Entity]) {
+        self.tile_index = tiles
+            .iter()
+            .enumerate()
+            .map(|(idx, &tile)| (tile, idx))
+            .collect();
+    }
+
+    pub fn province_of_tile(&self, tile: Entity) -> Option<ProvinceId> {
+        self.tile_index
+            .get(&tile)
+            .and_then(|&idx| self.province_at_index(idx))
+    }
+
+    /// Fraction of each province's head-count whose morale sits below `morale_threshold` (raw
+    /// fixed-point), for the unrest overlay. Cohorts count where they stand (`current_tile`);
+    /// provinces with no population, and cohorts off any province, are left out.
+    pub fn unrest(
+        &self,
+        populations: &[PopulationCohort],
+        morale_threshold: i64,
+    ) -> HashMap<ProvinceId, f32> {
+        let mut heads: HashMap<ProvinceId, (u64, u64)> = HashMap::new();
+        for cohort in populations.iter().filter(|cohort| cohort.size > 0) {
+            let Some(province) = self.province_of_tile(cohort.current_tile) else {
+                continue;
+            };
+            let (total, restless) = heads.entry(province).or_default();
+            *total += u64::from(cohort.size);
+            if cohort.morale.raw() < morale_threshold {
+                *restless += u64::from(cohort.size);
+            }
+        }
+        heads
+            .into_iter()
+            .map(|(province, (total, restless))| (province, restless as f32 / total as f32))
+            .collect()
+    }
+
+    /// Settlement seating `province_id`, for maps built by [`Self::partition_from_settlements`].
+    pub fn seat(&self, province_id: ProvinceId) -> Option<u64> {
+        let idx = (province_id as usize).checked_sub(1)?;
+        self.seats.get(idx).copied()
     }
 
     pub fn province_at_index(&self, idx: usize) -> Option<ProvinceId> {
@@ -149,3 +285,126 @@ impl ProvinceMap {
             .unwrap_or(0)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        components::{LocalStore, MoraleCause, MoraleContributions},
+        scalar::{scalar_from_f32, scalar_zero},
+        FactionId,
+    };
+
+    const WIDTH: u32 = 10;
+    const HEIGHT: u32 = 5;
+    const WEST: u64 = 100;
+    const EAST: u64 = 200;
+
+    fn registry() -> TileRegistry {
+        TileRegistry {
+            tiles: (0..WIDTH * HEIGHT).map(Entity::from_raw).collect(),
+            width: WIDTH,
+            height: HEIGHT,
+        }
+    }
+
+    fn partition(tags: &[TerrainTags]) -> ProvinceMap {
+        ProvinceMap::partition_from_settlements(
+            &[(WEST, (1, 2)), (EAST, (8, 2))],
+            &registry(),
+            tags,
+        )
+    }
+
+    fn cohort(x: u32, y: u32, size: u32, morale: f32) -> PopulationCohort {
+        let tile = Entity::from_raw(y * WIDTH + x);
+        PopulationCohort {
+            home: tile,
+            current_tile: tile,
+            size,
+            children: scalar_zero(),
+            working: scalar_from_f32(size as f32),
+            elders: scalar_zero(),
+            stores: LocalStore::new(),
+            morale: scalar_from_f32(morale),
+            last_food_consumption: 0.0,
+            last_morale_delta: scalar_zero(),
+            last_morale_cause: MoraleCause::None,
+            last_morale_contributions: MoraleContributions::default(),
+            discontent_fraction: scalar_zero(),
+            grievance: scalar_zero(),
+            last_emigrated: 0,
+            last_immigrated: 0,
+            age_turns: 0,
+            generation: 0,
+            faction: FactionId(0),
+            knowledge: Vec::new(),
+            migration: None,
+        }
+    }
+
+    fn owner(map: &ProvinceMap, x: u32, y: u32) -> Option<u64> {
+        map.province_at(x, y)
+            .and_then(|province| map.seat(province))
+    }
+
+    #[test]
+    fn partition_assigns_tiles_to_the_nearest_settlement() {
+        let tags = vec![TerrainTags::empty(); (WIDTH * HEIGHT) as usize];
+        let map = partition(&tags);
+
+        assert_eq!(map.province_count(), 2);
+        assert_eq!(map.land_tiles(), (WIDTH * HEIGHT) as usize);
+        assert_eq!(
+            map.province_tile_count(1) + map.province_tile_count(2),
+            map.land_tiles()
+        );
+        assert_eq!(owner(&map, 0, 2), Some(WEST));
+        assert_eq!(owner(&map, 4, 2), Some(WEST));
+        assert_eq!(owner(&map, 6, 2), Some(EAST));
+        assert_eq!(owner(&map, 9, 2), Some(EAST));
+    }
+
+    #[test]
+    fn impassable_barrier_bends_the_partition_boundary() {
+        // A water wall down column 3 with a gap only on the bottom row: the west settlement must
+        // detour around it, so (4, 2) — three steps from it in the open — falls to the east.
+        let mut tags = vec![TerrainTags::empty(); (WIDTH * HEIGHT) as usize];
+        for y in 0..HEIGHT - 1 {
+            tags[(y * WIDTH + 3) as usize] = TerrainTags::WATER;
+        }
+        let map = partition(&tags);
+
+        assert_eq!(owner(&map, 4, 2), Some(EAST));
+        assert_eq!(owner(&map, 2, 2), Some(WEST));
+        assert_eq!(owner(&map, 3, 4), Some(WEST), "the gap stays with the west");
+        assert_eq!(map.province_at(3, 1), None, "water is never assigned");
+        assert_eq!(map.land_tiles(), (WIDTH * HEIGHT - (HEIGHT - 1)) as usize);
+    }
+
+    #[test]
+    fn unrest_is_the_restless_share_of_each_province() {
+        let tags = vec![TerrainTags::empty(); (WIDTH * HEIGHT) as usize];
+        let map = partition(&tags);
+        let threshold = scalar_from_f32(0.4).raw();
+        let populations = [
+            // West: 30 of 120 heads below the threshold.
+            cohort(0, 2, 30, 0.2),
+            cohort(1, 1, 50, 0.9),
+            cohort(2, 3, 40, 0.4),
+            // East: everyone restless; the empty cohort does not count.
+            cohort(9, 0, 25, 0.1),
+            cohort(8, 2, 0, 0.9),
+        ];
+
+        let unrest = map.unrest(&populations, threshold);
+        assert_eq!(unrest.len(), 2);
+        assert!((unrest[&1] - 0.25).abs() < 1e-6, "{unrest:?}");
+        assert!((unrest[&2] - 1.0).abs() < 1e-6, "{unrest:?}");
+
+        // A province nobody stands in is omitted rather than reported calm.
+        let unrest = map.unrest(&populations[..3], threshold);
+        assert_eq!(unrest.keys().copied().collect::<Vec<_>>(), vec![1]);
+        assert!(map.unrest(&[], threshold).is_empty());
+    }
+}
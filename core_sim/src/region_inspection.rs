@@ -0,0 +1,271 @@
+//! Aggregate stats over a rectangular map region for the `inspect_region` debug command.
+
+use std::{cmp::Reverse, collections::HashMap};
+
+use bevy::math::UVec2;
+use sim_runtime::{ScalarRasterState, TerrainType, WorldSnapshot};
+
+use crate::scalar::Scalar;
+
+/// Inclusive tile rectangle, already clamped to the map and ordered so `min <= max`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RegionRect {
+    pub min: UVec2,
+    pub max: UVec2,
+}
+
+impl RegionRect {
+    /// Order the corners and clamp them to a `width` x `height` map. `None` when the map is empty.
+    pub fn clamped(x0: u32, y0: u32, x1: u32, y1: u32, width: u32, height: u32) -> Option<Self> {
+        if width == 0 || height == 0 {
+            return None;
+        }
+        let clamp = |x: u32, y: u32| UVec2::new(x.min(width - 1), y.min(height - 1));
+        Some(Self {
+            min: clamp(x0.min(x1), y0.min(y1)),
+            max: clamp(x0.max(x1), y0.max(y1)),
+        })
+    }
+
+    pub fn contains(&self, x: u32, y: u32) -> bool {
+        (self.min.x..=self.max.x).contains(&x) && (self.min.y..=self.max.y).contains(&y)
+    }
+
+    pub fn tile_count(&self) -> u32 {
+        (self.max.x - self.min.x + 1) * (self.max.y - self.min.y + 1)
+    }
+}
+
+/// Mean and extremes of one overlay raster inside the region, in overlay units.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OverlayStats {
+    pub mean: f32,
+    pub min: f32,
+    pub max: f32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RegionInspection {
+    pub rect: RegionRect,
+    /// `(overlay name, stats)` for every overlay raster that covers the region.
+    pub overlays: Vec<(&'static str, OverlayStats)>,
+    /// Head-count of cohorts currently standing inside the region.
+    pub population: u64,
+    /// Most common terrain; ties go to the lower terrain id.
+    pub dominant_terrain: Option<TerrainType>,
+    /// Archetype ids of the active crises with a hotspot or contagion tile inside the region.
+    pub crises: Vec<String>,
+    /// Tick of the snapshot the inspection was computed from.
+    pub tick: u64,
+}
+
+/// Aggregate `snapshot` over the region spanned by two corners. Everything, crises included, is
+/// read from the one snapshot so the figures describe the same tick. Corners may arrive in any
+/// order and past the map edge; `None` only for an empty map.
+pub fn inspect_region(
+    snapshot: &WorldSnapshot,
+    corners: (u32, u32, u32, u32),
+    grid_size: UVec2,
+) -> Option<RegionInspection> {
+    let (x0, y0, x1, y1) = corners;
+    let rect = RegionRect::clamped(x0, y0, x1, y1, grid_size.x, grid_size.y)?;
+
+    let rasters: [(&'static str, &ScalarRasterState); 7] = [
+        ("logistics", &snapshot.logistics_raster),
+        ("sentiment", &snapshot.sentiment_raster),
+        ("corruption", &snapshot.corruption_raster),
+        ("fog", &snapshot.fog_raster),
+        ("culture", &snapshot.culture_raster),
+        ("military", &snapshot.military_raster),
+        ("crisis", &snapshot.crisis_overlay.heatmap),
+    ];
+    let overlays = rasters
+        .into_iter()
+        .filter_map(|(name, raster)| raster_stats(raster, &rect).map(|stats| (name, stats)))
+        .collect();
+
+    let population = snapshot
+        .populations
+        .iter()
+        .filter(|cohort| rect.contains(cohort.current_x, cohort.current_y))
+        .map(|cohort| u64::from(cohort.size))
+        .sum();
+
+    let mut terrain_counts: HashMap<TerrainType, u32> = HashMap::new();
+    for tile in snapshot
+        .tiles
+        .iter()
+        .filter(|tile| rect.contains(tile.x, tile.y))
+    {
+        *terrain_counts.entry(tile.terrain).or_default() += 1;
+    }
+    let dominant_terrain = terrain_counts
+        .into_iter()
+        .max_by_key(|&(terrain, count)| (count, Reverse(terrain as u16)))
+        .map(|(terrain, _)| terrain);
+
+    Some(RegionInspection {
+        rect,
+        overlays,
+        population,
+        dominant_terrain,
+        crises: snapshot
+            .crisis_overlay
+            .crises
+            .iter()
+            .filter(|crisis| {
+                crisis
+                    .tiles
+                    .chunks_exact(2)
+                    .any(|tile| rect.contains(tile[0], tile[1]))
+            })
+            .map(|crisis| crisis.archetype.clone())
+            .collect(),
+        tick: snapshot.header.tick,
+    })
+}
+
+/// Stats over the raster samples inside `rect`; `None` if the raster does not reach the region.
+fn raster_stats(raster: &ScalarRasterState, rect: &RegionRect) -> Option<OverlayStats> {
+    let mut count = 0u32;
+    let mut sum = 0f64;
+    let mut min = i64::MAX;
+    let mut max = i64::MIN;
+    for y in rect.min.y..=rect.max.y.min(raster.height.saturating_sub(1)) {
+        for x in rect.min.x..=rect.max.x.min(raster.width.saturating_sub(1)) {
+            let Some(&sample) = raster.samples.get((y * raster.width + x) as usize) else {
+                continue;
+            };
+            count += 1;
+            sum += sample as f64;
+            min = min.min(sample);
+            max = max.max(sample);
+        }
+    }
+    if count == 0 {
+        return None;
+    }
+    let mean = Scalar::from_raw((sum / count as f64).round() as i64);
+    Some(OverlayStats {
+        mean: mean.to_f32(),
+        min: Scalar::from_raw(min).to_f32(),
+        max: Scalar::from_raw(max).to_f32(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sim_runtime::{
+        CrisisLedgerEntryState, MountainKind, PopulationCohortState, TerrainTags, TileState,
+        GRAZE_PHASE_NONE,
+    };
+
+    fn tile(x: u32, y: u32, terrain: TerrainType) -> TileState {
+        TileState {
+            entity: u64::from(y * 3 + x),
+            x,
+            y,
+            element: 0,
+            mass: 0,
+            temperature: 0,
+            terrain,
+            terrain_tags: TerrainTags::empty(),
+            culture_layer: 0,
+            mountain_kind: MountainKind::None,
+            mountain_relief: 1.0,
+            habitability: 0,
+            graze_biomass: 0.0,
+            graze_capacity: 0.0,
+            graze_ecology_phase: GRAZE_PHASE_NONE,
+            forage_capacity: 0.0,
+            underlying_terrain: terrain,
+            river_edges: 0,
+            river_inflow: 0,
+            river_channel: 0,
+            river_flow: 0.0,
+            lake_depth: 0.0,
+        }
+    }
+
+    fn raster(width: u32, height: u32, values: &[f32]) -> ScalarRasterState {
+        ScalarRasterState {
+            width,
+            height,
+            samples: values.iter().map(|v| Scalar::from_f32(*v).raw()).collect(),
+        }
+    }
+
+    #[test]
+    fn rect_orders_corners_and_clamps_to_the_map() {
+        let rect = RegionRect::clamped(9, 1, 2, 0, 4, 3).expect("non-empty map");
+        assert_eq!(rect.min, UVec2::new(2, 0));
+        assert_eq!(rect.max, UVec2::new(3, 1));
+        assert_eq!(rect.tile_count(), 4);
+        assert!(RegionRect::clamped(0, 0, 1, 1, 0, 3).is_none());
+    }
+
+    #[test]
+    fn inspection_matches_a_hand_computed_region() {
+        // 3x2 map; the region is the right-hand 2x2 block, requested with inverted corners
+        // that overshoot the map.
+        let mut snapshot = WorldSnapshot {
+            sentiment_raster: raster(3, 2, &[9.0, 0.2, 0.4, 9.0, 0.6, 0.8]),
+            ..Default::default()
+        };
+        let terrains = [
+            TerrainType::DeepOcean,
+            TerrainType::AlluvialPlain,
+            TerrainType::TidalFlat,
+            TerrainType::DeepOcean,
+            TerrainType::AlluvialPlain,
+            TerrainType::AlluvialPlain,
+        ];
+        snapshot.tiles = terrains
+            .iter()
+            .enumerate()
+            .map(|(idx, terrain)| tile(idx as u32 % 3, idx as u32 / 3, *terrain))
+            .collect();
+        snapshot.populations = [(1, 0, 120), (2, 1, 30), (0, 1, 500)]
+            .into_iter()
+            .map(|(x, y, size)| PopulationCohortState {
+                current_x: x,
+                current_y: y,
+                size,
+                ..Default::default()
+            })
+            .collect();
+        // One crisis reaches into the region at (2, 1); the other sits wholly at (0, 0).
+        snapshot.crisis_overlay.crises =
+            [("plague_bloom", vec![0, 0, 2, 1]), ("blight", vec![0, 0])]
+                .into_iter()
+                .map(|(archetype, tiles)| CrisisLedgerEntryState {
+                    archetype: archetype.to_string(),
+                    tiles,
+                    ..Default::default()
+                })
+                .collect();
+        snapshot.header.tick = 9;
+
+        let inspection =
+            inspect_region(&snapshot, (7, 5, 1, 0), UVec2::new(3, 2)).expect("region inspects");
+
+        assert_eq!(inspection.rect.min, UVec2::new(1, 0));
+        assert_eq!(inspection.rect.max, UVec2::new(2, 1));
+        assert_eq!(inspection.population, 150);
+        assert_eq!(
+            inspection.dominant_terrain,
+            Some(TerrainType::AlluvialPlain)
+        );
+        assert_eq!(inspection.crises, vec!["plague_bloom".to_string()]);
+        assert_eq!(inspection.tick, 9);
+
+        // Only the sentiment raster covers the map; the empty defaults are skipped.
+        assert_eq!(inspection.overlays.len(), 1);
+        let (name, stats) = inspection.overlays[0];
+        assert_eq!(name, "sentiment");
+        assert!((stats.mean - 0.5).abs() < 1e-5, "mean {}", stats.mean);
+        assert!((stats.min - 0.2).abs() < 1e-5);
+        assert!((stats.max - 0.8).abs() < 1e-5);
+    }
+}
@@ -0,0 +1,65 @@
+//! Lockstep replay of one config in two apps, reporting where their snapshots first diverge.
+//!
+//! The driver lives here rather than in `sim_runtime` because building an app needs the Bevy
+//! pipeline; the structural diff itself is `sim_runtime::diff_snapshots`.
+
+use bevy::prelude::{App, World};
+use sim_runtime::{diff_snapshots, DivergenceReport, WorldSnapshot};
+
+use crate::{
+    build_headless_app,
+    resources::{SimulationConfig, SimulationConfigMetadata},
+    snapshot::{recapture_snapshot_in_place, SnapshotHistory},
+};
+
+/// Run `config` in two fresh apps for `turns` turns, comparing snapshot hashes after every turn.
+/// Returns the first divergence, or `None` when both runs stayed identical. Seed randomisation is
+/// disabled, so `config.map_seed` is what both runs use.
+pub fn compare_runs(config: &SimulationConfig, turns: u32) -> Option<DivergenceReport> {
+    compare_runs_with(config, turns, |_, _, _| {})
+}
+
+/// [`compare_runs`] with a hook called on each world before every turn as `(run, turn, world)`,
+/// where `run` is 0 or 1 and `turn` counts from 1. Used to inject a known divergence.
+pub fn compare_runs_with(
+    config: &SimulationConfig,
+    turns: u32,
+    mut before_turn: impl FnMut(usize, u32, &mut World),
+) -> Option<DivergenceReport> {
+    let mut runs = [replay_app(config), replay_app(config)];
+    for turn in 1..=turns {
+        for (run, app) in runs.iter_mut().enumerate() {
+            before_turn(run, turn, &mut app.world);
+            app.update();
+            // The turn path only captures on the configured cadence; compare every turn.
+            recapture_snapshot_in_place(&mut app.world);
+        }
+        let (a, b) = (latest_snapshot(&runs[0]), latest_snapshot(&runs[1]));
+        if a.header.hash != b.header.hash {
+            return Some(diff_snapshots(&a, &b).unwrap_or_else(|| DivergenceReport {
+                tick: a.header.tick,
+                section: "header".to_string(),
+                entity: Some("hash".to_string()),
+            }));
+        }
+    }
+    None
+}
+
+fn replay_app(config: &SimulationConfig) -> App {
+    let mut app = build_headless_app();
+    if let Some(mut metadata) = app.world.get_resource_mut::<SimulationConfigMetadata>() {
+        metadata.set_seed_random(false);
+    }
+    app.world.insert_resource(config.clone());
+    app
+}
+
+fn latest_snapshot(app: &App) -> WorldSnapshot {
+    app.world
+        .resource::<SnapshotHistory>()
+        .last_snapshot
+        .as_ref()
+        .map(|snapshot| (**snapshot).clone())
+        .expect("recapture leaves a snapshot")
+}
@@ -1,6 +1,8 @@
 use std::{
-    collections::HashMap,
-    env, fs, io,
+    collections::{BTreeMap, HashMap},
+    env, fs,
+    hash::Hasher,
+    io,
     net::SocketAddr,
     path::{Path, PathBuf},
     str::FromStr,
@@ -8,11 +10,18 @@ use std::{
 
 use bevy::{math::UVec2, prelude::*};
 use serde::{Deserialize, Serialize};
-use sim_runtime::{CorruptionLedger, CorruptionSubsystem, FloatRasterState};
+use sim_runtime::{
+    CalendarState, CorruptionLedger, CorruptionSubsystem, CrisisFollowOnState, EventSeverity,
+    FloatRasterState, TerrainOverlayState, TerrainSample, SEASONS_PER_YEAR,
+};
 use thiserror::Error;
 
 use crate::{
+    components::ElementKind,
     culture::CultureTensionRecord,
+    generations::GenerationSentimentContribution,
+    hashing::FnvHasher,
+    heightfield::ElevationField,
     orders::FactionId,
     scalar::{scalar_from_f32, Scalar},
     start_profile::{FogMode, StartProfileOverrides},
@@ -22,7 +31,7 @@ use bitflags::bitflags;
 
 /// Per-map hydrology levers, each overriding the active preset's `river_*` key (overrides > preset
 /// > default). Every field is documented on `MapPreset`.
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct HydrologyOverrides {
     /// How wet the map reads: a multiplier on the channel-extraction threshold.
     pub river_density: Option<f32>,
@@ -47,10 +56,12 @@ pub struct HydrologyOverrides {
     /// The shortest navigable hex chain that still reads as a river; below this it is demoted to the
     /// river's edge (`Major`) form.
     pub navigable_min_hexes: Option<usize>,
+    /// How deep a filled depression must be at its lowest hex to stand as a lake.
+    pub lake_min_depth: Option<f32>,
 }
 
 /// Configuration for map topology (wrapping behavior).
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub struct MapTopology {
     /// Whether the map wraps horizontally (east-west edges connect).
     pub wrap_horizontal: bool,
@@ -62,13 +73,16 @@ pub struct MapTopology {
 /// `latitude_base(y) − elevation_lapse(elev) + element_jitter(element)` (see `systems::climate_temperature`),
 /// replacing the old `(x+y)%4` element checkerboard. Stored as `f32` because the climate math is
 /// pure floating-point and only the final temperature is converted to `Scalar`.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 pub struct ClimateConfig {
     /// Temperature (°) at the equator (center row).
     pub equator_temp: f32,
     /// Temperature (°) at the poles (top/bottom rows).
     pub polar_temp: f32,
-    /// How much colder (°) a full-height mountain is than sea level at the same latitude.
+    /// The environmental lapse rate: how much colder (°) a full-height mountain is than sea level
+    /// at the same latitude. Applied linearly over `ElevationField::above_sea_normalized`, so a tile
+    /// halfway up the field's headroom is `span / 2` colder. In fixed point that is
+    /// `span × Scalar::SCALE` raw units off `Tile::temperature` at the peak (12.0 → 12_000_000).
     pub elevation_lapse_span: f32,
     /// Multiplier applied to the element's `thermal_bias` to keep it a small local jitter (~±1.5°)
     /// rather than the temperature driver.
@@ -87,9 +101,217 @@ pub struct ClimateConfig {
     pub temperate_max_temp: f32,
 }
 
-/// Global configuration parameters for the headless simulation prototype.
-#[derive(Resource, Debug, Clone)]
+/// Per-turn river flow levers. A river's `flow_volume` is its generated mouth discharge scaled by
+/// its basin's moisture and a seasonal cycle (see `HydrologyState::update_flow`). Lake levels swing
+/// on the same two terms (`HydrologyState::update_lake_levels`).
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct RiverFlowConfig {
+    /// How strongly basin moisture swings flow: a bone-dry basin runs at `1 − weight`, a saturated
+    /// one at `1 + weight`, and a basin at 0.5 moisture at exactly its generated discharge.
+    pub moisture_weight: f32,
+    /// Length of one wet/dry cycle in turns, resolved from `SimulationConfig::ticks_per_year`.
+    /// `0` disables the seasonal term.
+    pub season_period_ticks: u32,
+    /// Peak fractional swing of the seasonal term: flow ranges over `1 ± amplitude`.
+    pub season_amplitude: f32,
+    /// How strongly a river's flow, relative to its generated discharge, scales forage regrowth on
+    /// the hexes it touches: the rate is multiplied by `1 + weight · (flow / base − 1)`, floored at
+    /// 0. `0` leaves regrowth independent of flow.
+    #[serde(default = "default_river_flow_forage_regrowth_weight")]
+    pub forage_regrowth_weight: f32,
+}
+
+/// How curated food sites' gather weights swing over the year (see `advance_food_seasons`). A
+/// site's `FoodModuleTag::seasonal_weight` is its worldgen `base_weight` times
+/// `1 + amplitude · cos(2π · (phase − peak_phase))`, so every site peaks at the same fraction of
+/// the year whatever `ticks_per_year` is.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct FoodSeasonConfig {
+    /// Peak fractional swing: weights range over `base × (1 ± amplitude)`. `0` keeps them flat.
+    pub amplitude: f32,
+    /// Fraction of the year (`0..1`) at which weights peak.
+    pub peak_phase: f32,
+}
+
+impl FoodSeasonConfig {
+    /// Multiplier on a site's base weight at `phase` (a fraction of the year).
+    pub fn factor(&self, phase: f32) -> f32 {
+        let offset = (phase - self.peak_phase) * std::f32::consts::TAU;
+        (1.0 + self.amplitude * offset.cos()).max(0.0)
+    }
+}
+
+/// Map-size scaling of Great Discovery observation thresholds, applied once when the catalog loads
+/// (see `GreatDiscoveryRegistry::load_catalog_for_map`). Catalog thresholds are tuned for a map of
+/// `reference_tiles` tiles; with `enabled`, a threshold `t` on a map of `tiles` tiles becomes
+///
+/// `max(1, round(t · (tiles / reference_tiles) ^ exponent))`
+///
+/// and a zero threshold stays zero. Disabled, thresholds load exactly as the catalog writes them.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct DiscoveryThresholdScaling {
+    pub enabled: bool,
+    /// Tile count the catalog thresholds were tuned for (the default 80×52 map).
+    pub reference_tiles: u32,
+    /// How closely thresholds follow map area: `1` scales with tile count, `0.5` (the default)
+    /// with the map's linear size, `0` not at all.
+    pub exponent: f32,
+}
+
+impl Default for DiscoveryThresholdScaling {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            reference_tiles: default_discovery_reference_tiles(),
+            exponent: default_discovery_threshold_exponent(),
+        }
+    }
+}
+
+impl DiscoveryThresholdScaling {
+    /// Effective threshold for a catalog `threshold` on a map of `tiles` tiles.
+    pub fn scale(&self, threshold: u32, tiles: u32) -> u32 {
+        if !self.enabled || threshold == 0 {
+            return threshold;
+        }
+        let ratio = tiles.max(1) as f64 / self.reference_tiles.max(1) as f64;
+        let scaled = (threshold as f64 * ratio.powf(self.exponent as f64)).round();
+        scaled.clamp(1.0, u32::MAX as f64) as u32
+    }
+}
+
+/// Sanity bounds on one metric, checked every turn by `collect_metrics`. A `0` in either limit
+/// switches that check off.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct AnomalyBound {
+    /// Flag the metric once it exceeds this.
+    pub max: f64,
+    /// Flag one turn multiplying the metric by at least this (`2.0` = doubling in a turn).
+    pub max_growth: f64,
+    /// Growth is judged only once last turn's value reaches this, so a handful of people doubling
+    /// is not a runaway.
+    pub growth_floor: f64,
+}
+
+impl AnomalyBound {
+    pub fn exceeded(&self, value: f64) -> bool {
+        self.max > 0.0 && value > self.max
+    }
+
+    pub fn runaway(&self, previous: f64, value: f64) -> bool {
+        self.max_growth > 0.0
+            && previous > 0.0
+            && previous >= self.growth_floor
+            && value >= previous * self.max_growth
+    }
+}
+
+/// Runaway-subsystem guards for long headless runs (see `SimulationMetrics::anomalies`).
+/// `population` reads the total head-count; `corruption` the summed intensity of every open
+/// incident, where `1.0` is one incident at full strength.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct MetricsAnomalyConfig {
+    pub population: AnomalyBound,
+    pub corruption: AnomalyBound,
+}
+
+impl Default for MetricsAnomalyConfig {
+    fn default() -> Self {
+        MetricsAnomalyConfigData::default().into_config()
+    }
+}
+
+/// Tuning for the `contain_crisis` command. Each point of effort costs `cost_per_effort` of
+/// `cost_item` from the issuing faction's stockpile and adds `containment_per_effort` (capped at
+/// `max_containment`) to the crisis' containment, which cuts its growth and R0 by that fraction
+/// for `duration_turns` turns. A fresh order refreshes the duration.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CrisisContainmentConfig {
+    pub cost_item: String,
+    pub cost_per_effort: i64,
+    pub containment_per_effort: f32,
+    pub max_containment: f32,
+    pub duration_turns: u32,
+}
+
+impl Default for CrisisContainmentConfig {
+    fn default() -> Self {
+        CrisisContainmentConfigData::default().into_config()
+    }
+}
+
+impl CrisisContainmentConfig {
+    /// Stockpile units an order of `effort` costs.
+    pub fn cost(&self, effort: i64) -> i64 {
+        effort.saturating_mul(self.cost_per_effort)
+    }
+}
+
+/// Fraction of the in-world year elapsed at `tick`, in `[0, 1)`: `(tick % ticks_per_year) /
+/// ticks_per_year`. Always `0.0` when `ticks_per_year` is `0` (no calendar).
+pub fn season_phase(tick: u64, ticks_per_year: u32) -> f32 {
+    if ticks_per_year == 0 {
+        return 0.0;
+    }
+    (tick % u64::from(ticks_per_year)) as f32 / ticks_per_year as f32
+}
+
+/// The in-world calendar at `tick`. `phase` is [`season_phase`] itself, so the calendar the client
+/// shows is the one the seasonal food weights and river flow run on; the season is the quarter of
+/// the year that phase falls in.
+pub fn calendar_at(tick: u64, ticks_per_year: u32) -> CalendarState {
+    if ticks_per_year == 0 {
+        return CalendarState::default();
+    }
+    let phase = season_phase(tick, ticks_per_year);
+    let season = (phase * f32::from(SEASONS_PER_YEAR)) as u8;
+    CalendarState {
+        year: tick / u64::from(ticks_per_year),
+        season: season.min(SEASONS_PER_YEAR - 1),
+        day_in_year: (tick % u64::from(ticks_per_year)) as u32,
+        ticks_per_year,
+        phase,
+    }
+}
+
+/// One element's phase behavior, applied by `simulate_materials` to `WATER`-tagged tiles. The
+/// `POLAR` tag is the solid phase: a liquid tile at or below `freeze_point` freezes (gains `POLAR`)
+/// and releases `latent_heat` degrees; a frozen tile above `melt_point` thaws and absorbs it. A
+/// liquid tile at or above `boil_point` loses `boil_mass_fraction` of its mass to vapor each turn,
+/// absorbing `latent_heat` as it does.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PhaseTransition {
+    pub freeze_point: f32,
+    /// Never below `freeze_point`; the gap between the two is the hysteresis band that keeps a
+    /// tile sitting on the threshold from flipping every turn.
+    pub melt_point: f32,
+    pub boil_point: f32,
+    pub latent_heat: f32,
+    pub boil_mass_fraction: f32,
+}
+
+/// Per-[`ElementKind`] [`PhaseTransition`]s. An element without an entry has no phase behavior;
+/// the builtin config ships the table empty.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct PhaseTransitionTable {
+    entries: [Option<PhaseTransition>; 4],
+}
+
+impl PhaseTransitionTable {
+    pub fn get(&self, element: ElementKind) -> Option<&PhaseTransition> {
+        self.entries[element as usize].as_ref()
+    }
+
+    pub fn set(&mut self, element: ElementKind, transition: Option<PhaseTransition>) {
+        self.entries[element as usize] = transition;
+    }
+}
+
+/// Global configuration parameters for the headless simulation prototype. Serializable so a world
+/// save can carry the exact config it ran under (see `world_save`).
+#[derive(Resource, Debug, Clone, Serialize, Deserialize)]
 pub struct SimulationConfig {
+    #[serde(with = "uvec2_pair")]
     pub grid_size: UVec2,
     pub map_topology: MapTopology,
     pub map_preset_id: String,
@@ -101,6 +323,15 @@ pub struct SimulationConfig {
     pub temperature_lerp: Scalar,
     /// Latitude + elevation climate model levers (see `ClimateConfig`).
     pub climate: ClimateConfig,
+    /// In-world calendar length in turns. Every seasonal cycle (river flow, lake levels, food-site
+    /// weights) takes its phase from `tick % ticks_per_year`; `0` disables seasons.
+    pub ticks_per_year: u32,
+    /// Seasonal/moisture river flow levers (see `RiverFlowConfig`).
+    pub river_flow: RiverFlowConfig,
+    /// Food-site seasonal weight swing (see `FoodSeasonConfig`).
+    pub food_season: FoodSeasonConfig,
+    /// Freeze/melt/boil behavior per element (see `PhaseTransition`).
+    pub phase_transitions: PhaseTransitionTable,
     pub logistics_flow_gain: Scalar,
     pub base_link_capacity: Scalar,
     pub mass_bounds: (Scalar, Scalar),
@@ -125,9 +356,15 @@ pub struct SimulationConfig {
     pub power_instability_warn: Scalar,
     pub power_instability_critical: Scalar,
     pub mass_flux_epsilon: Scalar,
+    /// Smallest single-turn change to a tracked tile field (temperature, mass) that emits a
+    /// `TileChangedEvent`; smaller drift is silent so listeners are not flooded every turn.
+    pub tile_change_min_delta: Scalar,
     pub base_trade_tariff: Scalar,
     pub base_trade_openness: Scalar,
     pub trade_openness_decay: Scalar,
+    /// Fraction of every faction pair's [`DiplomacyLeverage`] shed each turn (at least one point),
+    /// so leverage drifts back to neutral unless trade or espionage keeps reinforcing it.
+    pub diplomacy_leverage_decay: f32,
     pub trade_leak_min_ticks: u32,
     pub trade_leak_max_ticks: u32,
     pub trade_leak_exponent: f32,
@@ -141,8 +378,132 @@ pub struct SimulationConfig {
     pub snapshot_flat_bind: SocketAddr,
     pub command_bind: SocketAddr,
     pub log_bind: SocketAddr,
+    /// Refuse commands on a connection until the client has sent a `Handshake` frame with a
+    /// matching protocol version (see `sim_runtime::handshake`). Off by default so clients that
+    /// predate the handshake keep working; a handshake that is sent is verified either way.
+    pub command_handshake_required: bool,
+    /// Shared token every command handshake must present. Setting one requires the handshake
+    /// even with `command_handshake_required` off. `None` (the default) checks only the protocol
+    /// version.
+    pub command_token: Option<String>,
     pub snapshot_history_limit: usize,
+    /// Largest encoded delta frame the snapshot broadcast writes; bigger deltas (and coalesced
+    /// backlogs) go out as `WorldDelta::split` parts. `0` never splits. Read once at server start.
+    pub snapshot_delta_max_bytes: usize,
+    /// Snapshot capture cadence in turns: `capture_snapshot` (and the turn broadcast) runs only on
+    /// turns whose pre-increment tick is a multiple of this, so a headless server can resolve many
+    /// turns between client updates. The tick counter and metrics still advance every turn. `1`
+    /// (the default) captures every turn; `0` is clamped to `1`.
+    pub snapshot_every_n_ticks: u64,
+    /// Cadence in turns for the detailed metrics tier (`SimulationMetrics::detailed`, the
+    /// morale / power-stability / culture-divergence histograms). `0` (the default) never collects
+    /// them, keeping production turns to the cheap basic tier; `1` collects every turn.
+    pub detailed_metrics_every_n_ticks: u64,
+    /// Time each `TurnStage` with the wall clock and publish the breakdown as
+    /// `SimulationMetrics::turn_timings`. Off by default; the probes cost two `Instant::now()`
+    /// calls per stage.
+    pub turn_stage_timing: bool,
+    /// Upper bound on the turns a single `advance` command resolves, so one request cannot hang
+    /// the server. Larger requests are clamped (and reported as capped); `0` is clamped to `1`.
+    pub max_advance_turns: u32,
+    /// How many turns ahead an order may be scheduled on the `TurnQueue`; `0` is clamped to `1`.
+    pub order_schedule_horizon: u64,
+    /// Orders one faction may hold per turn, submitted or scheduled, before the `TurnQueue`
+    /// answers `RateLimited`; undo frees a slot, a lone `EndTurn` is exempt, `0` is clamped to `1`.
+    pub max_orders_per_turn: usize,
+    /// Shuffle which faction's orders resolve first each turn (seeded from `map_seed + tick`) so
+    /// the lowest faction id gets no standing advantage; `false` keeps ascending id order.
+    pub shuffle_turn_order: bool,
     pub crisis_auto_seed: bool,
+    /// The [`DifficultyPreset`] this config was resolved from; fields the source set explicitly
+    /// override the preset's bundle.
+    pub difficulty_preset: DifficultyPreset,
+    /// Multiplier on every victory mode's `threshold` when deciding `achieved` (progress itself is
+    /// unscaled), so `> 1` demands a stronger showing to win. Set by the difficulty preset.
+    pub victory_threshold_scale: f32,
+    /// Map-size scaling of Great Discovery observation thresholds (off by default).
+    pub great_discovery_threshold_scaling: DiscoveryThresholdScaling,
+    /// Cost and strength of `contain_crisis` orders.
+    pub crisis_containment: CrisisContainmentConfig,
+    /// Bounds `collect_metrics` flags runaway population and corruption against.
+    pub metrics_anomaly: MetricsAnomalyConfig,
+}
+
+/// Named bundles of the difficulty-relevant [`SimulationConfig`] fields, for players who would
+/// rather pick "sandbox" or "challenge" than hand-tune each lever:
+///
+/// | preset      | `crisis_auto_seed` | corruption penalties (logistics / trade / military) | `victory_threshold_scale` |
+/// |-------------|--------------------|------------------------------------------------------|---------------------------|
+/// | `sandbox`   | `false`            | 0.15 / 0.15 / 0.2                                    | 0.75                      |
+/// | `standard`  | `false`            | 0.35 / 0.3 / 0.4                                     | 1.0                       |
+/// | `challenge` | `true`             | 0.5 / 0.45 / 0.55                                    | 1.25                      |
+///
+/// `standard` reproduces the historical builtin values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DifficultyPreset {
+    Sandbox,
+    #[default]
+    Standard,
+    Challenge,
+}
+
+impl DifficultyPreset {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DifficultyPreset::Sandbox => "sandbox",
+            DifficultyPreset::Standard => "standard",
+            DifficultyPreset::Challenge => "challenge",
+            CommandEventKind::RegionInspected => "region_inspected",
+            CommandEventKind::TurnsAdvanced => "turns_advanced",
+            CommandEventKind::OrderUndone => "order_undone",
+            CommandEventKind::KnowledgePosture => "knowledge_posture",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "sandbox" => Some(DifficultyPreset::Sandbox),
+            "standard" => Some(DifficultyPreset::Standard),
+            "challenge" => Some(DifficultyPreset::Challenge),
+            _ => None,
+        }
+    }
+
+    fn settings(self) -> DifficultySettings {
+        match self {
+            DifficultyPreset::Sandbox => DifficultySettings {
+                crisis_auto_seed: false,
+                corruption_logistics_penalty: 0.15,
+                corruption_trade_penalty: 0.15,
+                corruption_military_penalty: 0.2,
+                victory_threshold_scale: 0.75,
+            },
+            DifficultyPreset::Standard => DifficultySettings {
+                crisis_auto_seed: false,
+                corruption_logistics_penalty: 0.35,
+                corruption_trade_penalty: 0.3,
+                corruption_military_penalty: 0.4,
+                victory_threshold_scale: 1.0,
+            },
+            DifficultyPreset::Challenge => DifficultySettings {
+                crisis_auto_seed: true,
+                corruption_logistics_penalty: 0.5,
+                corruption_trade_penalty: 0.45,
+                corruption_military_penalty: 0.55,
+                victory_threshold_scale: 1.25,
+            },
+        }
+    }
+}
+
+/// The field bundle a [`DifficultyPreset`] stands for.
+struct DifficultySettings {
+    crisis_auto_seed: bool,
+    corruption_logistics_penalty: f32,
+    corruption_trade_penalty: f32,
+    corruption_military_penalty: f32,
+    victory_threshold_scale: f32,
 }
 
 #[derive(Resource, Debug, Clone, Default)]
@@ -174,6 +535,7 @@ impl MoistureRaster {
             width: self.width,
             height: self.height,
             samples: self.values.clone(),
+            ..Default::default()
         }
     }
 }
@@ -192,22 +554,120 @@ impl SimulationConfig {
             .expect("builtin simulation config should parse")
     }
 
+    /// Whether the turn that resolved at `resolved_tick` (the tick *before* `advance_tick`) falls
+    /// on the `snapshot_every_n_ticks` cadence. Turn 0 — the world-build turn — always captures.
+    pub fn snapshot_due(&self, resolved_tick: u64) -> bool {
+        resolved_tick % self.snapshot_every_n_ticks.max(1) == 0
+    }
+
+    /// Whether `collect_metrics` gathers the detailed tier on `tick`; never when
+    /// `detailed_metrics_every_n_ticks` is `0`.
+    pub fn detailed_metrics_due(&self, tick: u64) -> bool {
+        self.detailed_metrics_every_n_ticks > 0 && tick % self.detailed_metrics_every_n_ticks == 0
+    }
+
+    /// Fraction of the in-world year elapsed at `tick` (see [`season_phase`]).
+    pub fn season_phase(&self, tick: u64) -> f32 {
+        season_phase(tick, self.ticks_per_year)
+    }
+
+    /// The in-world calendar at `tick` (see [`calendar_at`]).
+    pub fn calendar(&self, tick: u64) -> CalendarState {
+        calendar_at(tick, self.ticks_per_year)
+    }
+
+    /// Deterministic FNV-1a fingerprint of the config, resolved start-profile overrides included,
+    /// stamped onto every snapshot header as `config_hash` so two sessions can be checked for the
+    /// same config lineage. The four network binds and the command handshake settings are excluded
+    /// (they vary per deployment and never touch the sim; the token must not leak into a value
+    /// every client sees), and the `ai_profile_overrides` map is hashed in key order so the
+    /// `HashMap`'s iteration order cannot leak into the value.
+    pub fn config_hash(&self) -> u64 {
+        let mut canonical = self.clone();
+        let placeholder: SocketAddr = ([0, 0, 0, 0], 0).into();
+        canonical.snapshot_bind = placeholder;
+        canonical.snapshot_flat_bind = placeholder;
+        canonical.command_bind = placeholder;
+        canonical.log_bind = placeholder;
+        canonical.command_handshake_required = false;
+        canonical.command_token = None;
+        let ai_overrides: BTreeMap<String, serde_json::Value> =
+            std::mem::take(&mut canonical.start_profile_overrides.ai_profile_overrides)
+                .into_iter()
+                .collect();
+
+        let mut hasher = FnvHasher::new();
+        hasher.write(format!("{canonical:?}").as_bytes());
+        hasher.write(format!("{ai_overrides:?}").as_bytes());
+        hasher.finish()
+    }
+
+    /// Overwrites every field in `preset`'s bundle (see [`DifficultyPreset`]), discarding any
+    /// explicit values. Parsing applies presets underneath explicit fields instead — see
+    /// [`Self::from_json_str_with_preset`].
+    pub fn with_preset(mut self, preset: DifficultyPreset) -> Self {
+        let settings = preset.settings();
+        self.difficulty_preset = preset;
+        self.crisis_auto_seed = settings.crisis_auto_seed;
+        self.corruption_logistics_penalty = scalar_from_f32(settings.corruption_logistics_penalty);
+        self.corruption_trade_penalty = scalar_from_f32(settings.corruption_trade_penalty);
+        self.corruption_military_penalty = scalar_from_f32(settings.corruption_military_penalty);
+        self.victory_threshold_scale = settings.victory_threshold_scale;
+        self
+    }
+
     pub fn from_json_str(json: &str) -> Result<Self, SimulationConfigError> {
-        let data: SimulationConfigData = serde_json::from_str(json)?;
+        Self::from_json_str_with_preset(json, None)
+    }
+
+    /// Parses `json`, resolving the preset-controlled fields the JSON leaves out from `preset`
+    /// (falling back to the JSON's own `difficulty_preset`, then `standard`). Fields the JSON sets
+    /// explicitly always win over the preset.
+    pub fn from_json_str_with_preset(
+        json: &str,
+        preset: Option<DifficultyPreset>,
+    ) -> Result<Self, SimulationConfigError> {
+        let mut data: SimulationConfigData = serde_json::from_str(json)?;
+        if preset.is_some() {
+            data.difficulty_preset = preset;
+        }
         data.into_config()
     }
 
     pub fn from_file(path: &Path) -> Result<Self, SimulationConfigError> {
+        Self::from_file_with_preset(path, None)
+    }
+
+    pub fn from_file_with_preset(
+        path: &Path,
+        preset: Option<DifficultyPreset>,
+    ) -> Result<Self, SimulationConfigError> {
         let contents =
             fs::read_to_string(path).map_err(|source| SimulationConfigError::ReadFailed {
                 path: path.to_path_buf(),
                 source,
             })?;
-        let config = SimulationConfig::from_json_str(&contents)?;
+        let config = SimulationConfig::from_json_str_with_preset(&contents, preset)?;
         Ok(config)
     }
 }
 
+/// Serde adapter writing `SimulationConfig::grid_size` as a `(width, height)` pair; the bevy build
+/// does not enable glam's own serde support.
+mod uvec2_pair {
+    use bevy::math::UVec2;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S: Serializer>(value: &UVec2, serializer: S) -> Result<S::Ok, S::Error> {
+        (value.x, value.y).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<UVec2, D::Error> {
+        let (x, y) = <(u32, u32)>::deserialize(deserializer)?;
+        Ok(UVec2::new(x, y))
+    }
+}
+
 #[derive(Debug, Error)]
 pub enum SimulationConfigError {
     #[error("failed to parse simulation config: {0}")]
@@ -224,6 +684,11 @@ pub enum SimulationConfigError {
         #[source]
         source: io::Error,
     },
+    #[error("`{field}` is no longer read; set `{replacement}` instead")]
+    RetiredField {
+        field: &'static str,
+        replacement: &'static str,
+    },
 }
 
 #[derive(Debug, Deserialize, Default)]
@@ -251,6 +716,14 @@ struct SimulationConfigData {
     temperature_lerp: f32,
     #[serde(default)]
     climate: ClimateConfigData,
+    #[serde(default = "default_ticks_per_year")]
+    ticks_per_year: u32,
+    #[serde(default)]
+    river_flow: RiverFlowConfigData,
+    #[serde(default)]
+    food_season: FoodSeasonConfigData,
+    #[serde(default)]
+    phase_transitions: PhaseTransitionTableData,
     logistics_flow_gain: f32,
     base_link_capacity: f32,
     mass_bounds: MassBoundsData,
@@ -274,25 +747,62 @@ struct SimulationConfigData {
     power_instability_warn: f32,
     power_instability_critical: f32,
     mass_flux_epsilon: f32,
+    #[serde(default = "default_tile_change_min_delta")]
+    tile_change_min_delta: f32,
     base_trade_tariff: f32,
     base_trade_openness: f32,
     trade_openness_decay: f32,
+    #[serde(default = "default_diplomacy_leverage_decay")]
+    diplomacy_leverage_decay: f32,
     trade_leak_min_ticks: u32,
     trade_leak_max_ticks: u32,
     trade_leak_exponent: f32,
     trade_leak_progress: f32,
     migration_fragment_scaling: f32,
     migration_fidelity_floor: f32,
-    corruption_logistics_penalty: f32,
-    corruption_trade_penalty: f32,
-    corruption_military_penalty: f32,
+    #[serde(default)]
+    difficulty_preset: Option<DifficultyPreset>,
+    #[serde(default)]
+    corruption_logistics_penalty: Option<f32>,
+    #[serde(default)]
+    corruption_trade_penalty: Option<f32>,
+    #[serde(default)]
+    corruption_military_penalty: Option<f32>,
+    #[serde(default)]
+    victory_threshold_scale: Option<f32>,
     snapshot_bind: String,
     snapshot_flat_bind: String,
     command_bind: String,
     log_bind: String,
+    #[serde(default)]
+    command_handshake_required: bool,
+    #[serde(default)]
+    command_token: Option<String>,
     snapshot_history_limit: usize,
+    #[serde(default = "default_snapshot_delta_max_bytes")]
+    snapshot_delta_max_bytes: usize,
+    #[serde(default = "default_snapshot_every_n_ticks")]
+    snapshot_every_n_ticks: u64,
     #[serde(default)]
-    crisis_auto_seed: bool,
+    detailed_metrics_every_n_ticks: u64,
+    #[serde(default)]
+    turn_stage_timing: bool,
+    #[serde(default = "default_max_advance_turns")]
+    max_advance_turns: u32,
+    #[serde(default = "default_order_schedule_horizon")]
+    order_schedule_horizon: u64,
+    #[serde(default = "default_max_orders_per_turn")]
+    max_orders_per_turn: usize,
+    #[serde(default = "default_shuffle_turn_order")]
+    shuffle_turn_order: bool,
+    #[serde(default)]
+    crisis_auto_seed: Option<bool>,
+    #[serde(default)]
+    great_discovery_threshold_scaling: DiscoveryThresholdScalingData,
+    #[serde(default)]
+    crisis_containment: CrisisContainmentConfigData,
+    #[serde(default)]
+    metrics_anomaly: MetricsAnomalyConfigData,
 }
 
 #[derive(Debug, Deserialize)]
@@ -347,6 +857,295 @@ impl ClimateConfigData {
     }
 }
 
+#[derive(Debug, Clone, Deserialize)]
+struct RiverFlowConfigData {
+    #[serde(default = "default_river_flow_moisture_weight")]
+    moisture_weight: f32,
+    #[serde(default = "default_river_flow_season_amplitude")]
+    season_amplitude: f32,
+    #[serde(default = "default_river_flow_forage_regrowth_weight")]
+    forage_regrowth_weight: f32,
+    /// Retired in favour of the top-level `ticks_per_year`; only read so a config that still
+    /// sets it is refused instead of silently running on a different season length.
+    #[serde(default)]
+    season_period_ticks: Option<u32>,
+}
+
+impl Default for RiverFlowConfigData {
+    fn default() -> Self {
+        Self {
+            moisture_weight: default_river_flow_moisture_weight(),
+            season_amplitude: default_river_flow_season_amplitude(),
+            forage_regrowth_weight: default_river_flow_forage_regrowth_weight(),
+            season_period_ticks: None,
+        }
+    }
+}
+
+impl RiverFlowConfigData {
+    fn into_config(self, ticks_per_year: u32) -> RiverFlowConfig {
+        RiverFlowConfig {
+            moisture_weight: self.moisture_weight.clamp(0.0, 1.0),
+            season_period_ticks: ticks_per_year,
+            season_amplitude: self.season_amplitude.clamp(0.0, 1.0),
+            forage_regrowth_weight: self.forage_regrowth_weight.max(0.0),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct FoodSeasonConfigData {
+    #[serde(default)]
+    amplitude: f32,
+    #[serde(default = "default_food_season_peak_phase")]
+    peak_phase: f32,
+}
+
+impl Default for FoodSeasonConfigData {
+    fn default() -> Self {
+        Self {
+            amplitude: 0.0,
+            peak_phase: default_food_season_peak_phase(),
+        }
+    }
+}
+
+impl FoodSeasonConfigData {
+    fn into_config(self) -> FoodSeasonConfig {
+        FoodSeasonConfig {
+            amplitude: self.amplitude.clamp(0.0, 1.0),
+            peak_phase: self.peak_phase.rem_euclid(1.0),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct DiscoveryThresholdScalingData {
+    #[serde(default)]
+    enabled: bool,
+    #[serde(default = "default_discovery_reference_tiles")]
+    reference_tiles: u32,
+    #[serde(default = "default_discovery_threshold_exponent")]
+    exponent: f32,
+}
+
+impl Default for DiscoveryThresholdScalingData {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            reference_tiles: default_discovery_reference_tiles(),
+            exponent: default_discovery_threshold_exponent(),
+        }
+    }
+}
+
+impl DiscoveryThresholdScalingData {
+    fn into_config(self) -> DiscoveryThresholdScaling {
+        DiscoveryThresholdScaling {
+            enabled: self.enabled,
+            reference_tiles: self.reference_tiles.max(1),
+            exponent: self.exponent.max(0.0),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+struct AnomalyBoundData {
+    #[serde(default)]
+    max: f64,
+    #[serde(default)]
+    max_growth: f64,
+    #[serde(default)]
+    growth_floor: f64,
+}
+
+impl AnomalyBoundData {
+    fn into_config(self) -> AnomalyBound {
+        AnomalyBound {
+            max: self.max.max(0.0),
+            max_growth: self.max_growth.max(0.0),
+            growth_floor: self.growth_floor.max(0.0),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct MetricsAnomalyConfigData {
+    #[serde(default = "default_population_anomaly_bound")]
+    population: AnomalyBoundData,
+    #[serde(default = "default_corruption_anomaly_bound")]
+    corruption: AnomalyBoundData,
+}
+
+impl Default for MetricsAnomalyConfigData {
+    fn default() -> Self {
+        Self {
+            population: default_population_anomaly_bound(),
+            corruption: default_corruption_anomaly_bound(),
+        }
+    }
+}
+
+impl MetricsAnomalyConfigData {
+    fn into_config(self) -> MetricsAnomalyConfig {
+        MetricsAnomalyConfig {
+            population: self.population.into_config(),
+            corruption: self.corruption.into_config(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct CrisisContainmentConfigData {
+    #[serde(default = "default_containment_cost_item")]
+    cost_item: String,
+    #[serde(default = "default_containment_cost_per_effort")]
+    cost_per_effort: i64,
+    #[serde(default = "default_containment_per_effort")]
+    containment_per_effort: f32,
+    #[serde(default = "default_max_containment")]
+    max_containment: f32,
+    #[serde(default = "default_containment_duration_turns")]
+    duration_turns: u32,
+}
+
+impl Default for CrisisContainmentConfigData {
+    fn default() -> Self {
+        Self {
+            cost_item: default_containment_cost_item(),
+            cost_per_effort: default_containment_cost_per_effort(),
+            containment_per_effort: default_containment_per_effort(),
+            max_containment: default_max_containment(),
+            duration_turns: default_containment_duration_turns(),
+        }
+    }
+}
+
+impl CrisisContainmentConfigData {
+    fn into_config(self) -> CrisisContainmentConfig {
+        CrisisContainmentConfig {
+            cost_item: self.cost_item,
+            cost_per_effort: self.cost_per_effort.max(0),
+            containment_per_effort: self.containment_per_effort.max(0.0),
+            max_containment: self.max_containment.clamp(0.0, 1.0),
+            duration_turns: self.duration_turns.max(1),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+struct PhaseTransitionData {
+    freeze_point: f32,
+    melt_point: f32,
+    boil_point: f32,
+    latent_heat: f32,
+    boil_mass_fraction: f32,
+}
+
+impl PhaseTransitionData {
+    fn into_transition(self) -> PhaseTransition {
+        PhaseTransition {
+            freeze_point: self.freeze_point,
+            melt_point: self.melt_point.max(self.freeze_point),
+            boil_point: self.boil_point.max(self.melt_point),
+            latent_heat: self.latent_heat.max(0.0),
+            boil_mass_fraction: self.boil_mass_fraction.clamp(0.0, 1.0),
+        }
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct PhaseTransitionTableData {
+    #[serde(default)]
+    ferrite: Option<PhaseTransitionData>,
+    #[serde(default)]
+    arborite: Option<PhaseTransitionData>,
+    #[serde(default)]
+    zephyrite: Option<PhaseTransitionData>,
+    #[serde(default)]
+    lumina: Option<PhaseTransitionData>,
+}
+
+impl PhaseTransitionTableData {
+    fn into_table(self) -> PhaseTransitionTable {
+        let mut table = PhaseTransitionTable::default();
+        for (element, data) in [
+            (ElementKind::Ferrite, self.ferrite),
+            (ElementKind::Arborite, self.arborite),
+            (ElementKind::Zephyrite, self.zephyrite),
+            (ElementKind::Lumina, self.lumina),
+        ] {
+            table.set(element, data.map(PhaseTransitionData::into_transition));
+        }
+        table
+    }
+}
+
+fn default_river_flow_moisture_weight() -> f32 {
+    0.5
+}
+
+fn default_ticks_per_year() -> u32 {
+    12
+}
+
+fn default_food_season_peak_phase() -> f32 {
+    0.25
+}
+
+fn default_discovery_reference_tiles() -> u32 {
+    80 * 52
+}
+
+fn default_discovery_threshold_exponent() -> f32 {
+    0.5
+}
+
+fn default_population_anomaly_bound() -> AnomalyBoundData {
+    AnomalyBoundData {
+        max: 10_000_000.0,
+        max_growth: 2.0,
+        growth_floor: 100.0,
+    }
+}
+
+fn default_corruption_anomaly_bound() -> AnomalyBoundData {
+    AnomalyBoundData {
+        max: 50.0,
+        max_growth: 2.0,
+        growth_floor: 5.0,
+    }
+}
+
+fn default_containment_cost_item() -> String {
+    "trade_goods".to_string()
+}
+
+fn default_containment_cost_per_effort() -> i64 {
+    2
+}
+
+fn default_containment_per_effort() -> f32 {
+    0.08
+}
+
+fn default_max_containment() -> f32 {
+    0.8
+}
+
+fn default_containment_duration_turns() -> u32 {
+    3
+}
+
+fn default_river_flow_season_amplitude() -> f32 {
+    0.3
+}
+
+fn default_river_flow_forage_regrowth_weight() -> f32 {
+    0.5
+}
+
 fn default_equator_temp() -> f32 {
     30.0
 }
@@ -414,6 +1213,7 @@ struct HydrologyOverridesData {
     river_class_navigable_min_discharge: Option<f32>,
     river_navigable_enabled: Option<bool>,
     navigable_min_hexes: Option<usize>,
+    lake_min_depth: Option<f32>,
 }
 
 impl HydrologyOverridesData {
@@ -430,12 +1230,21 @@ impl HydrologyOverridesData {
             class_navigable_min_discharge: self.river_class_navigable_min_discharge,
             navigable_enabled: self.river_navigable_enabled,
             navigable_min_hexes: self.navigable_min_hexes,
+            lake_min_depth: self.lake_min_depth,
         }
     }
 }
 
 impl SimulationConfigData {
     fn into_config(self) -> Result<SimulationConfig, SimulationConfigError> {
+        if self.river_flow.season_period_ticks.is_some() {
+            return Err(SimulationConfigError::RetiredField {
+                field: "river_flow.season_period_ticks",
+                replacement: "ticks_per_year",
+            });
+        }
+        let difficulty_preset = self.difficulty_preset.unwrap_or_default();
+        let preset = difficulty_preset.settings();
         Ok(SimulationConfig {
             grid_size: UVec2::new(self.grid_size.x, self.grid_size.y),
             map_topology: MapTopology {
@@ -453,6 +1262,10 @@ impl SimulationConfigData {
             ambient_temperature: scalar_from_f32(self.ambient_temperature),
             temperature_lerp: scalar_from_f32(self.temperature_lerp),
             climate: self.climate.into_config(),
+            ticks_per_year: self.ticks_per_year,
+            river_flow: self.river_flow.into_config(self.ticks_per_year),
+            food_season: self.food_season.into_config(),
+            phase_transitions: self.phase_transitions.into_table(),
             logistics_flow_gain: scalar_from_f32(self.logistics_flow_gain),
             base_link_capacity: scalar_from_f32(self.base_link_capacity),
             mass_bounds: (
@@ -478,28 +1291,88 @@ impl SimulationConfigData {
             power_instability_warn: scalar_from_f32(self.power_instability_warn),
             power_instability_critical: scalar_from_f32(self.power_instability_critical),
             mass_flux_epsilon: scalar_from_f32(self.mass_flux_epsilon),
+            tile_change_min_delta: scalar_from_f32(self.tile_change_min_delta.max(0.0)),
             base_trade_tariff: scalar_from_f32(self.base_trade_tariff),
             base_trade_openness: scalar_from_f32(self.base_trade_openness),
             trade_openness_decay: scalar_from_f32(self.trade_openness_decay),
+            diplomacy_leverage_decay: self.diplomacy_leverage_decay.clamp(0.0, 1.0),
             trade_leak_min_ticks: self.trade_leak_min_ticks,
             trade_leak_max_ticks: self.trade_leak_max_ticks,
             trade_leak_exponent: self.trade_leak_exponent,
             trade_leak_progress: scalar_from_f32(self.trade_leak_progress),
             migration_fragment_scaling: scalar_from_f32(self.migration_fragment_scaling),
             migration_fidelity_floor: scalar_from_f32(self.migration_fidelity_floor),
-            corruption_logistics_penalty: scalar_from_f32(self.corruption_logistics_penalty),
-            corruption_trade_penalty: scalar_from_f32(self.corruption_trade_penalty),
-            corruption_military_penalty: scalar_from_f32(self.corruption_military_penalty),
+            corruption_logistics_penalty: scalar_from_f32(
+                self.corruption_logistics_penalty
+                    .unwrap_or(preset.corruption_logistics_penalty),
+            ),
+            corruption_trade_penalty: scalar_from_f32(
+                self.corruption_trade_penalty
+                    .unwrap_or(preset.corruption_trade_penalty),
+            ),
+            corruption_military_penalty: scalar_from_f32(
+                self.corruption_military_penalty
+                    .unwrap_or(preset.corruption_military_penalty),
+            ),
             snapshot_bind: parse_socket(self.snapshot_bind, "snapshot_bind")?,
             snapshot_flat_bind: parse_socket(self.snapshot_flat_bind, "snapshot_flat_bind")?,
             command_bind: parse_socket(self.command_bind, "command_bind")?,
             log_bind: parse_socket(self.log_bind, "log_bind")?,
+            command_handshake_required: self.command_handshake_required,
+            command_token: self.command_token.filter(|token| !token.is_empty()),
             snapshot_history_limit: self.snapshot_history_limit,
-            crisis_auto_seed: self.crisis_auto_seed,
+            snapshot_delta_max_bytes: self.snapshot_delta_max_bytes,
+            snapshot_every_n_ticks: self.snapshot_every_n_ticks.max(1),
+            detailed_metrics_every_n_ticks: self.detailed_metrics_every_n_ticks,
+            turn_stage_timing: self.turn_stage_timing,
+            max_advance_turns: self.max_advance_turns.max(1),
+            order_schedule_horizon: self.order_schedule_horizon.max(1),
+            max_orders_per_turn: self.max_orders_per_turn.max(1),
+            shuffle_turn_order: self.shuffle_turn_order,
+            crisis_auto_seed: self.crisis_auto_seed.unwrap_or(preset.crisis_auto_seed),
+            difficulty_preset,
+            victory_threshold_scale: self
+                .victory_threshold_scale
+                .unwrap_or(preset.victory_threshold_scale),
+            great_discovery_threshold_scaling: self.great_discovery_threshold_scaling.into_config(),
+            crisis_containment: self.crisis_containment.into_config(),
+            metrics_anomaly: self.metrics_anomaly.into_config(),
         })
     }
 }
 
+fn default_snapshot_delta_max_bytes() -> usize {
+    1 << 20
+}
+
+fn default_snapshot_every_n_ticks() -> u64 {
+    1
+}
+
+fn default_max_advance_turns() -> u32 {
+    100
+}
+
+fn default_order_schedule_horizon() -> u64 {
+    crate::orders::DEFAULT_SCHEDULE_HORIZON
+}
+
+fn default_max_orders_per_turn() -> usize {
+    crate::orders::DEFAULT_MAX_ORDERS_PER_TURN
+}
+
+fn default_shuffle_turn_order() -> bool {
+    true
+}
+
+fn default_tile_change_min_delta() -> f32 {
+    1.0
+}
+
+fn default_diplomacy_leverage_decay() -> f32 {
+    0.1
+}
+
 fn default_map_preset_id() -> String {
     "earthlike".to_string()
 }
@@ -613,8 +1486,20 @@ pub fn apply_port_base_override(config: &mut SimulationConfig) {
     }
 }
 
+/// Reads the optional `SIM_DIFFICULTY_PRESET` env override (`sandbox` / `standard` /
+/// `challenge`). An unknown name is warned and ignored.
+pub fn difficulty_preset_override() -> Option<DifficultyPreset> {
+    let raw = env::var("SIM_DIFFICULTY_PRESET").ok()?;
+    let preset = DifficultyPreset::parse(&raw);
+    if preset.is_none() {
+        tracing::warn!(target: "shadow_scale::config", value = %raw, "sim_difficulty_preset.invalid=ignored");
+    }
+    preset
+}
+
 pub fn load_simulation_config_from_env() -> (SimulationConfig, SimulationConfigMetadata) {
     let override_path = env::var("SIM_CONFIG_PATH").ok().map(PathBuf::from);
+    let preset = difficulty_preset_override();
 
     let default_path =
         PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("src/data/simulation_config.json");
@@ -625,7 +1510,7 @@ pub fn load_simulation_config_from_env() -> (SimulationConfig, SimulationConfigM
     };
 
     for path in candidates {
-        match SimulationConfig::from_file(&path) {
+        match SimulationConfig::from_file_with_preset(&path, preset) {
             Ok(mut config) => {
                 tracing::info!(
                     target: "shadow_scale::config",
@@ -650,9 +1535,11 @@ pub fn load_simulation_config_from_env() -> (SimulationConfig, SimulationConfigM
         }
     }
 
-    let mut config = SimulationConfig::builtin();
+    let mut config = SimulationConfig::from_json_str_with_preset(BUILTIN_SIMULATION_CONFIG, preset)
+        .expect("builtin simulation config should parse");
     tracing::info!(
         target: "shadow_scale::config",
+        difficulty = config.difficulty_preset.as_str(),
         "simulation_config.loaded=builtin"
     );
     apply_port_base_override(&mut config);
@@ -673,6 +1560,12 @@ pub struct SimulationTick(pub u64);
 #[derive(Resource, Default, Debug, Clone, Copy, PartialEq, Eq)]
 pub struct WorldEpoch(pub u32);
 
+/// The active [`SimulationConfig::config_hash`], computed at app build and refreshed by
+/// `snapshot::refresh_config_hash` whenever the config resource changes (the server swaps in the
+/// `new_game` config after building the app). `capture_snapshot` stamps it onto the header.
+#[derive(Resource, Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConfigHash(pub u64);
+
 bitflags! {
     #[derive(Resource, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
     pub struct CapabilityFlags: u32 {
@@ -788,19 +1681,67 @@ impl FogRevealLedger {
     pub fn is_empty(&self) -> bool {
         self.reveals.is_empty()
     }
+
+    /// Tiles a viewer at `origin` sees within `radius` when terrain occludes: the line-of-sight
+    /// reveal shape `FogMode::LineOfSight` selects in place of the flat radius. The disc matches the
+    /// radius shape (`dx² + dy² ≤ r²`, unwrapped); a tile is visible when no tile stepped along the
+    /// line to it rises above the sightline from the viewer's eye (`LOS_EYE_HEIGHT` over the
+    /// origin's elevation) to the target's ground. The origin and its neighbours are always
+    /// visible. Tiles outside `heightfield` are skipped.
+    pub fn reveal_los(origin: UVec2, radius: u32, heightfield: &ElevationField) -> Vec<UVec2> {
+        let (width, height) = (heightfield.width, heightfield.height);
+        if origin.x >= width || origin.y >= height {
+            return Vec::new();
+        }
+        let radius = radius.min(i32::MAX as u32) as i32;
+        let radius_sq = i64::from(radius) * i64::from(radius);
+        let eye = heightfield.sample(origin.x, origin.y) + LOS_EYE_HEIGHT;
+        let (ox, oy) = (origin.x as i32, origin.y as i32);
+
+        let mut visible = Vec::new();
+        for y in (oy - radius).max(0)..=(oy + radius).min(height as i32 - 1) {
+            for x in (ox - radius).max(0)..=(ox + radius).min(width as i32 - 1) {
+                let (dx, dy) = (x - ox, y - oy);
+                if i64::from(dx).pow(2) + i64::from(dy).pow(2) > radius_sq {
+                    continue;
+                }
+                let target_ground = heightfield.sample(x as u32, y as u32);
+                let steps = dx.abs().max(dy.abs());
+                let occluded = (1..steps).any(|step| {
+                    let t = step as f32 / steps as f32;
+                    let sx = (ox as f32 + dx as f32 * t).round() as u32;
+                    let sy = (oy as f32 + dy as f32 * t).round() as u32;
+                    let sightline = eye + (target_ground - eye) * t;
+                    heightfield.sample(sx, sy) > sightline
+                });
+                if !occluded {
+                    visible.push(UVec2::new(x as u32, y as u32));
+                }
+            }
+        }
+        visible
+    }
 }
 
+/// Height (on the elevation field's normalized 0..1 scale) a viewer's eye sits above its own tile
+/// for [`FogRevealLedger::reveal_los`] — enough to see over gentle undulation, not over a ridge.
+pub const LOS_EYE_HEIGHT: f32 = 0.02;
+
 /// Authoritative sentiment axis bias values applied across factions.
 ///
 /// Sentiment is composed of three categories of forces:
 /// - **Policy levers** (`policy`): long-lived adjustments driven by enacted reforms or manual tweaks.
 /// - **Incident deltas** (`incidents`): short-lived shocks produced by exposed scandals, crises, etc.
 /// - **Influencer output** (`influencer`): procedurally generated contributions from the influencer roster.
+/// - **Generation lean** (`generations`): each generation's bias weighted by its population share,
+///   kept per generation so telemetry can show who pulled which way. Reported only; it is not part
+///   of [`SentimentAxisBias::combined`].
 #[derive(Resource, Debug, Clone)]
 pub struct SentimentAxisBias {
     policy: [Scalar; 4],
     incidents: [Scalar; 4],
     influencer: [Scalar; 4],
+    generations: Vec<GenerationSentimentContribution>,
 }
 
 impl Default for SentimentAxisBias {
@@ -809,6 +1750,7 @@ impl Default for SentimentAxisBias {
             policy: [Scalar::zero(); 4],
             incidents: [Scalar::zero(); 4],
             influencer: [Scalar::zero(); 4],
+            generations: Vec::new(),
         }
     }
 }
@@ -840,6 +1782,28 @@ impl SentimentAxisBias {
         self.incidents
     }
 
+    pub fn set_generation_contributions(
+        &mut self,
+        contributions: Vec<GenerationSentimentContribution>,
+    ) {
+        self.generations = contributions;
+    }
+
+    pub fn generation_contributions(&self) -> &[GenerationSentimentContribution] {
+        &self.generations
+    }
+
+    /// Sum of the per-generation contributions on each axis.
+    pub fn generation_values(&self) -> [Scalar; 4] {
+        let mut result = [Scalar::zero(); 4];
+        for contribution in &self.generations {
+            for (slot, value) in result.iter_mut().zip(contribution.axes) {
+                *slot += value;
+            }
+        }
+        result
+    }
+
     pub fn apply_incident_delta(&mut self, axis: usize, delta: Scalar) {
         if let Some(slot) = self.incidents.get_mut(axis) {
             *slot = (*slot + delta).clamp(Scalar::from_f32(-2.0), Scalar::from_f32(2.0));
@@ -877,8 +1841,45 @@ impl SentimentAxisBias {
     }
 }
 
+/// Width and height of a tile grid, in cells.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GridDims {
+    pub width: u32,
+    pub height: u32,
+}
+
+impl GridDims {
+    pub fn new(width: u32, height: u32) -> Self {
+        Self { width, height }
+    }
+
+    /// The smallest grid covering both `self` and `other`, never narrower than one cell.
+    pub fn covering(self, other: GridDims) -> Self {
+        Self {
+            width: self.width.max(other.width).max(1),
+            height: self.height.max(other.height).max(1),
+        }
+    }
+
+    /// Row-major index of `(x, y)`, or `None` outside the grid.
+    pub fn index(self, x: u32, y: u32) -> Option<usize> {
+        (x < self.width && y < self.height)
+            .then(|| (y as usize) * (self.width as usize) + x as usize)
+    }
+
+    pub fn cell_count(self) -> usize {
+        (self.width as usize).saturating_mul(self.height as usize)
+    }
+}
+
+impl From<UVec2> for GridDims {
+    fn from(size: UVec2) -> Self {
+        Self::new(size.x, size.y)
+    }
+}
+
 /// Index of tile entities for reuse by other systems.
-#[derive(Resource, Debug, Clone)]
+#[derive(Resource, Debug, Clone, Default)]
 pub struct TileRegistry {
     pub tiles: Vec<Entity>,
     pub width: u32,
@@ -894,6 +1895,37 @@ impl TileRegistry {
             None
         }
     }
+
+    pub fn dims(&self) -> GridDims {
+        GridDims::new(self.width, self.height)
+    }
+
+    /// The terrain overlay `capture_snapshot` ships, laid out over the grid covering `dims` and
+    /// the registry: row-major, the tile at `(x, y)` landing at `y * width + x`. `sample` resolves
+    /// a tile entity to its cell; any cell it cannot resolve keeps the default sample.
+    /// `palette_hash` is left at 0 (not computed) for the snapshot layer to fill in.
+    pub fn terrain_overlay(
+        &self,
+        dims: GridDims,
+        sample: impl Fn(Entity) -> Option<TerrainSample>,
+    ) -> TerrainOverlayState {
+        let layout = dims.covering(self.dims());
+        let mut samples = vec![TerrainSample::default(); layout.cell_count()];
+        for y in 0..self.height {
+            for x in 0..self.width {
+                let cell = self.index(x, y).and_then(&sample);
+                if let (Some(cell), Some(idx)) = (cell, layout.index(x, y)) {
+                    samples[idx] = cell;
+                }
+            }
+        }
+        TerrainOverlayState {
+            width: layout.width,
+            height: layout.height,
+            samples,
+            palette_hash: 0,
+        }
+    }
 }
 
 /// Tracks corruption intensity across subsystems for snapshot export.
@@ -947,15 +1979,77 @@ impl CorruptionTelemetry {
     }
 }
 
+/// Leverage a faction gains over the partner its trade carried knowledge to (per
+/// `TradeDiffusionEvent`).
+pub const DIPLOMACY_LEVERAGE_TRADE_GAIN: i64 = 10;
+/// Leverage an infiltrator gains over the faction it probed (per `EspionageProbeEvent`).
+pub const DIPLOMACY_LEVERAGE_ESPIONAGE_GAIN: i64 = 25;
+
 #[derive(Resource, Debug, Clone, Default)]
 pub struct DiplomacyLeverage {
     pub recent: Vec<CorruptionExposureRecord>,
     pub max_entries: usize,
     pub culture_signals: Vec<CultureTensionRecord>,
     pub great_discoveries: Vec<(FactionId, u16)>,
+    /// Net leverage per unordered faction pair, keyed `(low, high)` by id and signed from the
+    /// lower id's side. Pairs at neutral are not stored.
+    pairs: BTreeMap<(FactionId, FactionId), i64>,
 }
 
 impl DiplomacyLeverage {
+    /// Net leverage `a` holds over `b`: positive when `a` has the upper hand, negative when `b`
+    /// does, `0` when neutral. Antisymmetric, so `between(b, a) == -between(a, b)`.
+    pub fn between(&self, a: FactionId, b: FactionId) -> i64 {
+        match a.cmp(&b) {
+            std::cmp::Ordering::Less => self.pairs.get(&(a, b)).copied().unwrap_or(0),
+            std::cmp::Ordering::Greater => -self.pairs.get(&(b, a)).copied().unwrap_or(0),
+            std::cmp::Ordering::Equal => 0,
+        }
+    }
+
+    /// Credits `holder` with `amount` leverage over `target` (a negative `amount` cedes it).
+    pub fn reinforce(&mut self, holder: FactionId, target: FactionId, amount: i64) {
+        let (key, signed) = match holder.cmp(&target) {
+            std::cmp::Ordering::Less => ((holder, target), amount),
+            std::cmp::Ordering::Greater => ((target, holder), -amount),
+            std::cmp::Ordering::Equal => return,
+        };
+        let value = self.pairs.entry(key).or_insert(0);
+        *value = value.saturating_add(signed);
+        if *value == 0 {
+            self.pairs.remove(&key);
+        }
+    }
+
+    /// Moves every pair toward neutral by `rate` of its magnitude, at least one point so small
+    /// values still settle, and forgets pairs that reach zero. `rate` is clamped to `0..=1`.
+    pub fn apply_decay(&mut self, rate: f32) {
+        let rate = rate.clamp(0.0, 1.0) as f64;
+        if rate == 0.0 {
+            return;
+        }
+        self.pairs.retain(|_, value| {
+            let magnitude = value.unsigned_abs();
+            let cut = ((magnitude as f64 * rate).ceil() as u64).clamp(1, magnitude);
+            let remaining = (magnitude - cut) as i64;
+            *value = if *value < 0 { -remaining } else { remaining };
+            *value != 0
+        });
+    }
+
+    /// Every non-neutral pair as `(a, b, between(a, b))` with `a < b`, in ascending id order.
+    pub fn pairs(&self) -> impl Iterator<Item = (FactionId, FactionId, i64)> + '_ {
+        self.pairs.iter().map(|(&(a, b), &value)| (a, b, value))
+    }
+
+    /// Replaces the pairwise matrix wholesale (rollback restore).
+    pub fn set_pairs(&mut self, pairs: impl IntoIterator<Item = (FactionId, FactionId, i64)>) {
+        self.pairs.clear();
+        for (a, b, value) in pairs {
+            self.reinforce(a, b, value);
+        }
+    }
+
     pub fn push(&mut self, record: CorruptionExposureRecord) {
         if self.max_entries == 0 {
             self.max_entries = 16;
@@ -990,9 +2084,19 @@ impl DiplomacyLeverage {
     }
 }
 
+/// A follow-on crisis queued by an escalating parent (see `CrisisArchetype::escalates_to`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CrisisFollowOn {
+    pub faction: FactionId,
+    pub archetype: String,
+    /// Follow-ons between this crisis and its root; the root is `0`.
+    pub chain_depth: u8,
+}
+
 #[derive(Resource, Debug, Clone, Default)]
 pub struct PendingCrisisSeeds {
     pub seeds: Vec<(FactionId, u16)>,
+    pub follow_ons: Vec<CrisisFollowOn>,
 }
 
 impl PendingCrisisSeeds {
@@ -1000,8 +2104,46 @@ impl PendingCrisisSeeds {
         self.seeds.push((faction, discovery_id));
     }
 
+    /// Queued seeds sorted by faction then discovery, with repeats dropped. Seeds naming
+    /// different discoveries may still resolve to one archetype; `advance_crisis_system`
+    /// de-duplicates by `(faction, archetype)` across seeds and spawns.
     pub fn drain(&mut self) -> Vec<(FactionId, u16)> {
-        std::mem::take(&mut self.seeds)
+        let mut seeds = std::mem::take(&mut self.seeds);
+        seeds.sort_unstable();
+        seeds.dedup();
+        seeds
+    }
+
+    pub fn push_follow_on(&mut self, follow_on: CrisisFollowOn) {
+        self.follow_ons.push(follow_on);
+    }
+
+    pub fn drain_follow_ons(&mut self) -> Vec<CrisisFollowOn> {
+        std::mem::take(&mut self.follow_ons)
+    }
+
+    /// Queued follow-ons in queue order, as the snapshot carries them.
+    pub fn follow_on_states(&self) -> Vec<CrisisFollowOnState> {
+        self.follow_ons
+            .iter()
+            .map(|follow_on| CrisisFollowOnState {
+                faction: follow_on.faction.0,
+                archetype: follow_on.archetype.clone(),
+                chain_depth: follow_on.chain_depth,
+            })
+            .collect()
+    }
+
+    /// Replace the follow-on queue with a snapshot's.
+    pub fn restore_follow_ons(&mut self, states: &[CrisisFollowOnState]) {
+        self.follow_ons = states
+            .iter()
+            .map(|state| CrisisFollowOn {
+                faction: FactionId(state.faction),
+                archetype: state.archetype.clone(),
+                chain_depth: state.chain_depth,
+            })
+            .collect();
     }
 }
 
@@ -1015,8 +2157,70 @@ impl PendingCrisisSpawns {
         self.spawns.push((faction, archetype_id.into()));
     }
 
+    /// Queued spawns with archetype ids lowercased, sorted by faction then archetype, and
+    /// repeats dropped, so the same request from several producers spawns one crisis and the
+    /// ledger order does not depend on which system pushed first.
     pub fn drain(&mut self) -> Vec<(FactionId, String)> {
-        std::mem::take(&mut self.spawns)
+        let mut spawns: Vec<_> = std::mem::take(&mut self.spawns)
+            .into_iter()
+            .map(|(faction, archetype_id)| (faction, archetype_id.to_ascii_lowercase()))
+            .collect();
+        spawns.sort_unstable();
+        spawns.dedup();
+        spawns
+    }
+}
+
+/// A `contain_crisis` order, paid for when it was accepted and applied by
+/// `crisis::advance_crisis_system` on the next turn.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CrisisContainmentOrder {
+    pub faction: FactionId,
+    pub crisis_id: u64,
+    pub effort: i64,
+    /// Stockpile item and amount taken from `faction` when the order was accepted, refunded if
+    /// the crisis is gone by the time it applies.
+    pub cost_item: String,
+    pub cost: i64,
+}
+
+#[derive(Resource, Debug, Clone, Default)]
+pub struct PendingCrisisContainment {
+    pub orders: Vec<CrisisContainmentOrder>,
+}
+
+impl PendingCrisisContainment {
+    pub fn push(&mut self, order: CrisisContainmentOrder) {
+        self.orders.push(order);
+    }
+
+    pub fn drain(&mut self) -> Vec<CrisisContainmentOrder> {
+        std::mem::take(&mut self.orders)
+    }
+}
+
+/// Non-fatal problems raised while a turn resolves. Cleared at the start of every
+/// [`crate::run_turn`] and drained into its [`crate::TurnReport`].
+#[derive(Resource, Debug, Clone, Default)]
+pub struct TurnWarnings {
+    entries: Vec<String>,
+}
+
+impl TurnWarnings {
+    pub fn push<S: Into<String>>(&mut self, warning: S) {
+        self.entries.push(warning.into());
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &String> {
+        self.entries.iter()
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    pub fn drain(&mut self) -> Vec<String> {
+        std::mem::take(&mut self.entries)
     }
 }
 
@@ -1055,11 +2259,49 @@ pub struct TradeDiffusionRecord {
     pub herd_density: f32,
 }
 
+/// One trade link's goods movement for the current turn, recorded by `trade_knowledge_diffusion`.
+/// `throughput` is signed like the underlying `LogisticsLink::flow`: positive moves goods
+/// `from → to`, negative moves them back.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TradeFlowRecord {
+    pub link: u64,
+    pub from: FactionId,
+    pub to: FactionId,
+    pub throughput: Scalar,
+}
+
+/// Dashboard view of one link's flow, oriented so `exporter` is the side goods left. `value` is the
+/// absolute throughput in raw fixed-point units (`Scalar::raw`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FlowSummary {
+    pub link: u64,
+    pub exporter: FactionId,
+    pub importer: FactionId,
+    pub value: i64,
+}
+
+impl From<&TradeFlowRecord> for FlowSummary {
+    fn from(record: &TradeFlowRecord) -> Self {
+        let (exporter, importer) = if record.throughput.raw() >= 0 {
+            (record.from, record.to)
+        } else {
+            (record.to, record.from)
+        };
+        Self {
+            link: record.link,
+            exporter,
+            importer,
+            value: record.throughput.raw().saturating_abs(),
+        }
+    }
+}
+
 #[derive(Resource, Debug, Clone, Default)]
 pub struct TradeTelemetry {
     pub tech_diffusion_applied: u32,
     pub migration_transfers: u32,
     pub records: Vec<TradeDiffusionRecord>,
+    pub flows: Vec<TradeFlowRecord>,
 }
 
 impl TradeTelemetry {
@@ -1067,11 +2309,37 @@ impl TradeTelemetry {
         self.tech_diffusion_applied = 0;
         self.migration_transfers = 0;
         self.records.clear();
+        self.flows.clear();
     }
 
     pub fn push_record(&mut self, record: TradeDiffusionRecord) {
         self.records.push(record);
     }
+
+    pub fn push_flow(&mut self, flow: TradeFlowRecord) {
+        self.flows.push(flow);
+    }
+
+    /// The `limit` busiest links this turn, by absolute throughput (ties broken by link id so the
+    /// ranking is deterministic).
+    pub fn top_flows(&self, limit: usize) -> Vec<FlowSummary> {
+        let mut summaries: Vec<FlowSummary> = self.flows.iter().map(FlowSummary::from).collect();
+        summaries.sort_by(|a, b| b.value.cmp(&a.value).then(a.link.cmp(&b.link)));
+        summaries.truncate(limit);
+        summaries
+    }
+
+    /// Net exports minus imports per faction this turn, in raw fixed-point value. Every link
+    /// credits its exporter and debits its importer by the same amount, so the balances across the
+    /// whole network sum to zero.
+    pub fn faction_balance(&self) -> HashMap<FactionId, i64> {
+        let mut balance: HashMap<FactionId, i64> = HashMap::new();
+        for summary in self.flows.iter().map(FlowSummary::from) {
+            *balance.entry(summary.exporter).or_insert(0) += summary.value;
+            *balance.entry(summary.importer).or_insert(0) -= summary.value;
+        }
+        balance
+    }
 }
 
 /// Per-faction stockpile of abstracted inventory items granted by start profiles.
@@ -1080,6 +2348,10 @@ pub struct FactionInventory {
     stockpiles: HashMap<FactionId, HashMap<String, i64>>,
 }
 
+/// A curated food site picked at worldgen: where a module's gathering is *offered*, not how much
+/// is left. Depletion and regrowth live on the tile's `ForagePatch` (`crate::forage`): gathering
+/// draws it down via `forage_take` and `advance_forage_regrowth` regrows it toward capacity, so
+/// this entry deliberately carries no stock of its own.
 #[derive(Clone, Debug)]
 pub struct FoodSiteEntry {
     pub position: UVec2,
@@ -1148,6 +2420,8 @@ impl FactionInventory {
         self.stockpiles.get(&faction)
     }
 
+    /// Stockpiles in hash order. Anything that leaves the sim (snapshots, hashes) must sort first;
+    /// see `snapshot::snapshot_faction_inventory`.
     pub fn iter(&self) -> impl Iterator<Item = (&FactionId, &HashMap<String, i64>)> {
         self.stockpiles.iter()
     }
@@ -1192,9 +2466,59 @@ pub enum CommandEventKind {
     /// it to its defer choice). The chosen line joins the story record rather than the decision
     /// being a silent state change.
     NarrativeFork,
+    /// Designer debug verbs (`spawn_herd` / `cull_herd`) that mutate `HerdRegistry` directly.
+    HerdSpawned,
+    HerdCulled,
+    /// The `influence` verb backed or suppressed an influencer through a support channel.
+    InfluencerAction,
+    /// A `contain_crisis` order was paid for and queued against an active crisis.
+    ContainCrisis,
+    /// The `inspect_region` debug verb's aggregate over a map rectangle, read from one snapshot.
+    RegionInspected,
+    /// The `advance <count>` verb's outcome: turns resolved of those requested, the cap, a winner.
+    TurnsAdvanced,
+    /// `undo_order` withdrew a faction's latest pending order; the detail names it and the
+    /// faction's remaining reserved cost.
+    OrderUndone,
+    /// `knowledge_posture` changed a discovery's security posture, or was refused.
+    KnowledgePosture,
 }
 
 impl CommandEventKind {
+    /// Every kind, in declaration order.
+    pub const ALL: [CommandEventKind; 30] = [
+        CommandEventKind::Scout,
+        CommandEventKind::FollowHerd,
+        CommandEventKind::FoundSettlement,
+        CommandEventKind::CampaignFounded,
+        CommandEventKind::CampaignMilestone,
+        CommandEventKind::CampaignVictory,
+        CommandEventKind::Forage,
+        CommandEventKind::Hunt,
+        CommandEventKind::Tame,
+        CommandEventKind::Cultivate,
+        CommandEventKind::Sow,
+        CommandEventKind::Corral,
+        CommandEventKind::HuntDanger,
+        CommandEventKind::CancelOrder,
+        CommandEventKind::SedentarizationPrompt,
+        CommandEventKind::SiteDiscovered,
+        CommandEventKind::ExpeditionSent,
+        CommandEventKind::ExpeditionArrived,
+        CommandEventKind::ExpeditionRecalled,
+        CommandEventKind::ExpeditionReturned,
+        CommandEventKind::NarrativeBeat,
+        CommandEventKind::NarrativeFork,
+        CommandEventKind::HerdSpawned,
+        CommandEventKind::HerdCulled,
+        CommandEventKind::InfluencerAction,
+        CommandEventKind::ContainCrisis,
+        CommandEventKind::RegionInspected,
+        CommandEventKind::TurnsAdvanced,
+        CommandEventKind::OrderUndone,
+        CommandEventKind::KnowledgePosture,
+    ];
+
     pub fn as_str(&self) -> &'static str {
         match self {
             CommandEventKind::Scout => "scout",
@@ -1219,6 +2543,49 @@ impl CommandEventKind {
             CommandEventKind::ExpeditionReturned => "expedition_returned",
             CommandEventKind::NarrativeBeat => "narrative_beat",
             CommandEventKind::NarrativeFork => "narrative_fork",
+            CommandEventKind::HerdSpawned => "herd_spawned",
+            CommandEventKind::HerdCulled => "herd_culled",
+            CommandEventKind::InfluencerAction => "influencer_action",
+            CommandEventKind::ContainCrisis => "contain_crisis",
+        }
+    }
+
+    /// Feed severity an entry of this kind gets unless the pusher overrides it
+    /// ([`CommandEventEntry::with_severity`]): order echoes are routine, story and discovery
+    /// beats notable, danger and pending decisions alerts, victory major.
+    pub fn severity(&self) -> EventSeverity {
+        match self {
+            CommandEventKind::Scout
+            | CommandEventKind::FollowHerd
+            | CommandEventKind::Forage
+            | CommandEventKind::Hunt
+            | CommandEventKind::Tame
+            | CommandEventKind::Cultivate
+            | CommandEventKind::Sow
+            | CommandEventKind::Corral
+            | CommandEventKind::CancelOrder
+            | CommandEventKind::ExpeditionSent
+            | CommandEventKind::ExpeditionRecalled
+            | CommandEventKind::HerdSpawned
+            | CommandEventKind::HerdCulled
+            | CommandEventKind::InfluencerAction
+            | CommandEventKind::ContainCrisis
+            | CommandEventKind::RegionInspected
+            | CommandEventKind::TurnsAdvanced
+            | CommandEventKind::OrderUndone
+            | CommandEventKind::KnowledgePosture => EventSeverity::Routine,
+            CommandEventKind::FoundSettlement
+            | CommandEventKind::CampaignFounded
+            | CommandEventKind::CampaignMilestone
+            | CommandEventKind::SiteDiscovered
+            | CommandEventKind::ExpeditionArrived
+            | CommandEventKind::ExpeditionReturned
+            | CommandEventKind::NarrativeBeat
+            | CommandEventKind::NarrativeFork => EventSeverity::Notable,
+            CommandEventKind::HuntDanger | CommandEventKind::SedentarizationPrompt => {
+                EventSeverity::Alert
+            }
+            CommandEventKind::CampaignVictory => EventSeverity::Major,
         }
     }
 }
@@ -1230,6 +2597,8 @@ pub struct CommandEventEntry {
     pub faction: FactionId,
     pub label: String,
     pub detail: Option<String>,
+    /// Defaults to [`CommandEventKind::severity`]; see [`Self::with_severity`].
+    pub severity: EventSeverity,
 }
 
 impl CommandEventEntry {
@@ -1246,14 +2615,23 @@ impl CommandEventEntry {
             faction,
             label: label.into(),
             detail,
+            severity: kind.severity(),
         }
     }
+
+    /// Override the kind's default severity, for kinds whose entries range from an order echo to
+    /// a completed build or a loss (the rung verbs share one kind for their whole life).
+    pub fn with_severity(mut self, severity: EventSeverity) -> Self {
+        self.severity = severity;
+        self
+    }
 }
 
 #[derive(Resource, Debug, Clone)]
 pub struct CommandEventLog {
     entries: Vec<CommandEventEntry>,
     max_entries: usize,
+    pushed_total: u64,
 }
 
 impl Default for CommandEventLog {
@@ -1261,6 +2639,7 @@ impl Default for CommandEventLog {
         Self {
             entries: Vec::new(),
             max_entries: 32,
+            pushed_total: 0,
         }
     }
 }
@@ -1272,6 +2651,12 @@ impl CommandEventLog {
             self.entries.drain(0..overflow);
         }
         self.entries.push(entry);
+        self.pushed_total = self.pushed_total.saturating_add(1);
+    }
+
+    /// Lifetime count of pushed entries; unlike `iter().count()` it is not capped by the ring.
+    pub fn pushed_total(&self) -> u64 {
+        self.pushed_total
     }
 
     pub fn iter(&self) -> impl Iterator<Item = &CommandEventEntry> {
@@ -1282,7 +2667,133 @@ impl CommandEventLog {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::net::Ipv4Addr;
+    use std::{collections::HashSet, net::Ipv4Addr};
+
+    fn flow(link: u64, from: u32, to: u32, throughput: f32) -> TradeFlowRecord {
+        TradeFlowRecord {
+            link,
+            from: FactionId(from),
+            to: FactionId(to),
+            throughput: Scalar::from_f32(throughput),
+        }
+    }
+
+    fn sample_trade_telemetry() -> TradeTelemetry {
+        let mut telemetry = TradeTelemetry::default();
+        telemetry.push_flow(flow(1, 0, 1, 0.5));
+        telemetry.push_flow(flow(2, 1, 2, 2.0));
+        telemetry.push_flow(flow(3, 2, 0, -1.25));
+        telemetry.push_flow(flow(4, 0, 2, 0.5));
+        telemetry
+    }
+
+    #[test]
+    fn trade_top_flows_rank_by_absolute_throughput() {
+        let telemetry = sample_trade_telemetry();
+        let ranked = telemetry.top_flows(3);
+        let links: Vec<u64> = ranked.iter().map(|summary| summary.link).collect();
+        assert_eq!(links, vec![2, 3, 1], "ties resolve by link id");
+
+        // A negative flow runs `to → from`, so link 3 exports from faction 0 into faction 2.
+        assert_eq!(ranked[1].exporter, FactionId(0));
+        assert_eq!(ranked[1].importer, FactionId(2));
+        assert_eq!(ranked[1].value, Scalar::from_f32(1.25).raw());
+
+        assert_eq!(telemetry.top_flows(10).len(), 4);
+        assert!(telemetry.top_flows(0).is_empty());
+    }
+
+    #[test]
+    fn trade_faction_balances_sum_to_zero() {
+        let telemetry = sample_trade_telemetry();
+        let balance = telemetry.faction_balance();
+        assert_eq!(balance.values().sum::<i64>(), 0);
+        assert_eq!(
+            balance[&FactionId(0)],
+            Scalar::from_f32(0.5 + 1.25 + 0.5).raw()
+        );
+        assert_eq!(balance[&FactionId(1)], Scalar::from_f32(0.5 - 2.0).raw());
+        assert_eq!(
+            balance[&FactionId(2)],
+            Scalar::from_f32(2.0 - 1.25 - 0.5).raw()
+        );
+    }
+
+    #[test]
+    fn difficulty_presets_set_their_documented_fields() {
+        for (preset, auto_seed, corruption, victory_scale) in [
+            (DifficultyPreset::Sandbox, false, [0.15, 0.15, 0.2], 0.75),
+            (DifficultyPreset::Standard, false, [0.35, 0.3, 0.4], 1.0),
+            (DifficultyPreset::Challenge, true, [0.5, 0.45, 0.55], 1.25),
+        ] {
+            let config = SimulationConfig::builtin().with_preset(preset);
+            assert_eq!(config.difficulty_preset, preset);
+            assert_eq!(config.crisis_auto_seed, auto_seed, "{preset:?}");
+            assert_eq!(
+                [
+                    config.corruption_logistics_penalty,
+                    config.corruption_trade_penalty,
+                    config.corruption_military_penalty,
+                ],
+                corruption.map(scalar_from_f32),
+                "{preset:?}"
+            );
+            assert_eq!(config.victory_threshold_scale, victory_scale, "{preset:?}");
+            assert_eq!(DifficultyPreset::parse(preset.as_str()), Some(preset));
+        }
+        assert_eq!(DifficultyPreset::parse("Nightmare"), None);
+    }
+
+    #[test]
+    fn a_retired_river_season_period_is_refused() {
+        let mut json: serde_json::Value =
+            serde_json::from_str(BUILTIN_SIMULATION_CONFIG).expect("builtin parses");
+        json["river_flow"]["season_period_ticks"] = serde_json::json!(24);
+
+        let err = SimulationConfig::from_json_str(&json.to_string()).expect_err("refused");
+        assert!(matches!(
+            err,
+            SimulationConfigError::RetiredField {
+                field: "river_flow.season_period_ticks",
+                replacement: "ticks_per_year",
+            }
+        ));
+    }
+
+    #[test]
+    fn builtin_config_resolves_to_the_standard_preset() {
+        let builtin = SimulationConfig::builtin();
+        let standard = builtin.clone().with_preset(DifficultyPreset::Standard);
+        assert_eq!(builtin.difficulty_preset, DifficultyPreset::Standard);
+        assert_eq!(builtin.config_hash(), standard.config_hash());
+    }
+
+    #[test]
+    fn explicit_fields_win_over_the_difficulty_preset() {
+        let mut json: serde_json::Value =
+            serde_json::from_str(BUILTIN_SIMULATION_CONFIG).expect("builtin parses");
+        json["corruption_trade_penalty"] = serde_json::json!(0.05);
+        json["crisis_auto_seed"] = serde_json::json!(false);
+        let json = json.to_string();
+
+        let config =
+            SimulationConfig::from_json_str_with_preset(&json, Some(DifficultyPreset::Challenge))
+                .expect("config parses");
+        assert_eq!(config.difficulty_preset, DifficultyPreset::Challenge);
+        assert_eq!(config.corruption_trade_penalty, scalar_from_f32(0.05));
+        assert!(!config.crisis_auto_seed);
+        // Fields the JSON leaves to the preset still take the challenge bundle.
+        assert_eq!(config.corruption_logistics_penalty, scalar_from_f32(0.5));
+        assert_eq!(config.victory_threshold_scale, 1.25);
+
+        // The JSON's own `difficulty_preset` applies when the caller passes none.
+        let mut sandbox: serde_json::Value =
+            serde_json::from_str(BUILTIN_SIMULATION_CONFIG).expect("builtin parses");
+        sandbox["difficulty_preset"] = serde_json::json!("sandbox");
+        let config = SimulationConfig::from_json_str(&sandbox.to_string()).expect("config parses");
+        assert_eq!(config.difficulty_preset, DifficultyPreset::Sandbox);
+        assert_eq!(config.victory_threshold_scale, 0.75);
+    }
 
     #[test]
     fn apply_port_base_overrides_ports_and_preserves_hosts() {
@@ -1355,4 +2866,89 @@ mod tests {
             before
         );
     }
+
+    /// A 9×3 valley with a ridge down column 4: the viewer at (1, 1) sees up to and onto the
+    /// ridge but nothing in the valley behind it, while a flat field reveals the whole disc.
+    fn ridge_field() -> ElevationField {
+        let (width, height) = (9u32, 3u32);
+        let values = (0..height)
+            .flat_map(|_| (0..width).map(|x| if x == 4 { 0.8 } else { 0.1 }))
+            .collect();
+        ElevationField::new(width, height, values)
+    }
+
+    #[test]
+    fn reveal_los_hides_tiles_behind_a_ridge() {
+        let origin = UVec2::new(1, 1);
+        let visible = FogRevealLedger::reveal_los(origin, 7, &ridge_field());
+
+        for seen in [origin, UVec2::new(2, 1), UVec2::new(3, 1), UVec2::new(4, 1)] {
+            assert!(visible.contains(&seen), "{seen:?} is in line of sight");
+        }
+        for hidden in [UVec2::new(5, 1), UVec2::new(6, 1), UVec2::new(8, 1)] {
+            assert!(!visible.contains(&hidden), "{hidden:?} is behind the ridge");
+        }
+
+        let flat = ElevationField::new(9, 3, vec![0.1; 27]);
+        let disc = FogRevealLedger::reveal_los(origin, 8, &flat);
+        assert!(disc.contains(&UVec2::new(8, 0)));
+        assert_eq!(disc.len(), 27, "nothing occludes on flat ground");
+    }
+
+    #[test]
+    fn command_event_severity_defaults_by_kind_and_can_be_overridden() {
+        // Spelled out per variant with no wildcard, so a new kind fails to compile here until its
+        // severity is decided.
+        let expected = |kind| match kind {
+            CommandEventKind::Scout
+            | CommandEventKind::FollowHerd
+            | CommandEventKind::Forage
+            | CommandEventKind::Hunt
+            | CommandEventKind::Tame
+            | CommandEventKind::Cultivate
+            | CommandEventKind::Sow
+            | CommandEventKind::Corral
+            | CommandEventKind::CancelOrder
+            | CommandEventKind::ExpeditionSent
+            | CommandEventKind::ExpeditionRecalled
+            | CommandEventKind::HerdSpawned
+            | CommandEventKind::HerdCulled
+            | CommandEventKind::InfluencerAction
+            | CommandEventKind::ContainCrisis
+            | CommandEventKind::RegionInspected
+            | CommandEventKind::TurnsAdvanced
+            | CommandEventKind::OrderUndone
+            | CommandEventKind::KnowledgePosture => EventSeverity::Routine,
+            CommandEventKind::FoundSettlement
+            | CommandEventKind::CampaignFounded
+            | CommandEventKind::CampaignMilestone
+            | CommandEventKind::SiteDiscovered
+            | CommandEventKind::ExpeditionArrived
+            | CommandEventKind::ExpeditionReturned
+            | CommandEventKind::NarrativeBeat
+            | CommandEventKind::NarrativeFork => EventSeverity::Notable,
+            CommandEventKind::HuntDanger | CommandEventKind::SedentarizationPrompt => {
+                EventSeverity::Alert
+            }
+            CommandEventKind::CampaignVictory => EventSeverity::Major,
+        };
+        let entry = |kind| CommandEventEntry::new(3, kind, FactionId(0), "label", None);
+
+        let labels: HashSet<_> = CommandEventKind::ALL
+            .iter()
+            .map(CommandEventKind::as_str)
+            .collect();
+        assert_eq!(
+            labels.len(),
+            CommandEventKind::ALL.len(),
+            "ALL repeats a kind"
+        );
+        for kind in CommandEventKind::ALL {
+            assert_eq!(entry(kind).severity, expected(kind), "{}", kind.as_str());
+        }
+
+        let escaped = entry(CommandEventKind::Corral).with_severity(EventSeverity::Alert);
+        assert_eq!(escaped.kind, CommandEventKind::Corral);
+        assert_eq!(escaped.severity, EventSeverity::Alert);
+    }
 }
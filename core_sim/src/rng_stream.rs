@@ -0,0 +1,124 @@
+//! Seeded RNG stream that can be saved mid-sequence.
+//!
+//! `SmallRng` has no stable serialized form, so a resource whose stream has to survive a world
+//! save draws from an [`RngStream`] instead: it wraps the same generator, counts the words handed
+//! out, and [`RngStream::resume`] replays that many words from the seed. The draws are identical
+//! to a bare `SmallRng::seed_from_u64`; replay cost is linear in the draws, which suits the
+//! low-volume streams kept in resources.
+
+use rand::{rngs::SmallRng, RngCore, SeedableRng};
+use serde::{Deserialize, Serialize};
+
+/// Position of an [`RngStream`]: its seed and how many words of each width it has drawn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RngStreamState {
+    pub seed: u64,
+    pub words32: u64,
+    /// Includes the words backing `fill_bytes`.
+    pub words64: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct RngStream {
+    rng: SmallRng,
+    state: RngStreamState,
+}
+
+impl RngStream {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            rng: SmallRng::seed_from_u64(seed),
+            state: RngStreamState {
+                seed,
+                words32: 0,
+                words64: 0,
+            },
+        }
+    }
+
+    pub fn state(&self) -> RngStreamState {
+        self.state
+    }
+
+    /// The stream positioned where `state` was taken. Each word advances the generator by a
+    /// fixed number of steps, so replaying the counts in any order lands on the same position.
+    pub fn resume(state: RngStreamState) -> Self {
+        let mut rng = SmallRng::seed_from_u64(state.seed);
+        for _ in 0..state.words64 {
+            rng.next_u64();
+        }
+        for _ in 0..state.words32 {
+            rng.next_u32();
+        }
+        Self { rng, state }
+    }
+}
+
+impl RngCore for RngStream {
+    fn next_u32(&mut self) -> u32 {
+        self.state.words32 += 1;
+        self.rng.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.state.words64 += 1;
+        self.rng.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for chunk in dest.chunks_mut(8) {
+            let word = self.next_u64().to_le_bytes();
+            chunk.copy_from_slice(&word[..chunk.len()]);
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::Rng;
+
+    fn draw(rng: &mut impl Rng) -> (u32, u64, f32, bool, [u8; 5]) {
+        let mut bytes = [0u8; 5];
+        rng.fill(&mut bytes);
+        (
+            rng.gen_range(0..100),
+            rng.gen(),
+            rng.gen_range(0.2..0.6),
+            rng.gen_bool(0.3),
+            bytes,
+        )
+    }
+
+    #[test]
+    fn draws_match_the_bare_generator() {
+        let mut stream = RngStream::new(0xA51C_E55E);
+        let mut bare = SmallRng::seed_from_u64(0xA51C_E55E);
+        for _ in 0..32 {
+            assert_eq!(stream.gen_range(0..1000u32), bare.gen_range(0..1000u32));
+            assert_eq!(stream.gen::<u64>(), bare.gen::<u64>());
+            assert_eq!(
+                stream.gen_range(0.01..0.04f32),
+                bare.gen_range(0.01..0.04f32)
+            );
+        }
+    }
+
+    #[test]
+    fn a_resumed_stream_continues_the_sequence() {
+        let mut stream = RngStream::new(17);
+        for _ in 0..9 {
+            draw(&mut stream);
+        }
+        let mut resumed = RngStream::resume(stream.state());
+        for _ in 0..9 {
+            assert_eq!(draw(&mut resumed), draw(&mut stream));
+        }
+        assert_eq!(resumed.state(), stream.state());
+    }
+}
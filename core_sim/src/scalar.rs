@@ -3,7 +3,9 @@ use std::fmt;
 use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 
 /// Fixed-point scalar with 6 decimal places of precision.
-#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+#[derive(
+    Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default, serde::Serialize, serde::Deserialize,
+)]
 pub struct Scalar(pub i64);
 
 impl Scalar {
@@ -167,3 +169,120 @@ pub fn scalar_zero() -> Scalar {
 pub fn scalar_one() -> Scalar {
     Scalar::one()
 }
+
+// Bulk raster helpers. Each is a tight loop over the raw `i64`s with no per-element branching the
+// optimizer cannot turn into a select, so they auto-vectorize; each matches its element-wise
+// `Scalar` counterpart exactly (see the tests).
+
+/// Min-max stretches `values` onto `[0, 1]` in place. A flat (or empty) slice becomes all zeros,
+/// matching the client's overlay normalization.
+pub fn bulk_normalize(values: &mut [Scalar]) {
+    let Some(first) = values.first().map(|value| value.0) else {
+        return;
+    };
+    let (min, max) = values.iter().fold((first, first), |(lo, hi), value| {
+        (lo.min(value.0), hi.max(value.0))
+    });
+    let range = max.abs_diff(min);
+    if range == 0 {
+        values.fill(Scalar::zero());
+        return;
+    }
+    if range <= (i64::MAX / Scalar::SCALE) as u64 {
+        // `(value - min) * SCALE` fits in i64: the vectorizable fast path.
+        let range = range as i64;
+        for value in values.iter_mut() {
+            value.0 = ((value.0 - min) * Scalar::SCALE) / range;
+        }
+    } else {
+        let range = i128::from(range);
+        for value in values.iter_mut() {
+            value.0 = ((i128::from(value.0) - i128::from(min)) * i128::from(Scalar::SCALE) / range)
+                as i64;
+        }
+    }
+}
+
+/// Multiplies every value by `factor` in place (`value * factor`).
+pub fn bulk_scale(values: &mut [Scalar], factor: Scalar) {
+    for value in values.iter_mut() {
+        value.0 = (value.0 * factor.0) / Scalar::SCALE;
+    }
+}
+
+/// Clamps every value into `[lo, hi]` in place (`value.clamp(lo, hi)`).
+pub fn bulk_clamp(values: &mut [Scalar], lo: Scalar, hi: Scalar) {
+    let (lo, hi) = (lo.0, hi.0);
+    for value in values.iter_mut() {
+        let raw = value.0;
+        value.0 = if raw < lo {
+            lo
+        } else if raw > hi {
+            hi
+        } else {
+            raw
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A deterministic spread of raw values: negatives, zero, repeats, and sub-unit fractions.
+    fn raster(len: usize) -> Vec<Scalar> {
+        (0..len as i64)
+            .map(|i| Scalar::from_raw((i * 7_919 % 4_001 - 2_000) * 1_337))
+            .collect()
+    }
+
+    #[test]
+    fn bulk_normalize_matches_element_wise_min_max() {
+        let mut values = raster(1_000);
+        let min = *values.iter().min().unwrap();
+        let max = *values.iter().max().unwrap();
+        let naive: Vec<Scalar> = values.iter().map(|&v| (v - min) / (max - min)).collect();
+
+        bulk_normalize(&mut values);
+        assert_eq!(values, naive);
+        assert_eq!(*values.iter().min().unwrap(), Scalar::zero());
+        assert_eq!(*values.iter().max().unwrap(), Scalar::one());
+    }
+
+    #[test]
+    fn bulk_normalize_flattens_uniform_and_handles_wide_ranges() {
+        let mut flat = vec![Scalar::from_f32(3.5); 16];
+        bulk_normalize(&mut flat);
+        assert!(flat.iter().all(|&v| v == Scalar::zero()));
+
+        let mut empty: Vec<Scalar> = Vec::new();
+        bulk_normalize(&mut empty);
+        assert!(empty.is_empty());
+
+        // A range too wide for the i64 fast path still lands on [0, 1].
+        let mut wide = vec![
+            Scalar::from_raw(-i64::MAX / 2),
+            Scalar::from_raw(0),
+            Scalar::from_raw(i64::MAX / 2),
+        ];
+        bulk_normalize(&mut wide);
+        assert_eq!(wide[0], Scalar::zero());
+        assert_eq!(wide[1], Scalar::from_f32(0.5));
+        assert_eq!(wide[2], Scalar::one());
+    }
+
+    #[test]
+    fn bulk_scale_and_clamp_match_element_wise_ops() {
+        let factor = Scalar::from_f32(0.37);
+        let (lo, hi) = (Scalar::from_f32(-0.5), Scalar::from_f32(0.75));
+        let mut scaled = raster(1_000);
+        let mut clamped = scaled.clone();
+        let naive_scaled: Vec<Scalar> = scaled.iter().map(|&v| v * factor).collect();
+        let naive_clamped: Vec<Scalar> = clamped.iter().map(|&v| v.clamp(lo, hi)).collect();
+
+        bulk_scale(&mut scaled, factor);
+        bulk_clamp(&mut clamped, lo, hi);
+        assert_eq!(scaled, naive_scaled);
+        assert_eq!(clamped, naive_clamped);
+    }
+}
@@ -12,6 +12,14 @@ pub(crate) fn victory_snapshot_from_resource(state: &VictoryState) -> VictorySna
             progress: mode.progress,
             threshold: mode.threshold,
             achieved: mode.achieved,
+            per_faction_progress: mode
+                .per_faction_progress
+                .iter()
+                .map(|(faction, progress)| VictoryFactionProgressState {
+                    faction: faction.0,
+                    progress: *progress,
+                })
+                .collect(),
         })
         .collect();
 
@@ -116,6 +124,7 @@ pub fn command_events_to_state(log: &CommandEventLog) -> Vec<CommandEventState>
             faction: entry.faction.0,
             label: entry.label.clone(),
             detail: entry.detail.clone(),
+            severity: entry.severity,
         })
         .collect()
 }
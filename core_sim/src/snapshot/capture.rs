@@ -1,3 +1,8 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
 use super::*;
 
 pub(crate) type EncodedBuffers = (Arc<Vec<u8>>, Arc<Vec<u8>>);
@@ -20,9 +25,15 @@ pub struct SnapshotContext<'w> {
     /// freshly-generated world from a stale one the snapshot server replays. Always present (the
     /// idle boot app inserts a default `0`; the server overwrites it per world (re)build).
     pub world_epoch: Res<'w, WorldEpoch>,
+    /// Fingerprint of the active `SimulationConfig` (see `ConfigHash`), stamped onto the header.
+    pub config_hash: Res<'w, ConfigHash>,
     pub overlays: Res<'w, SnapshotOverlaysConfigHandle>,
     pub metrics: Res<'w, SimulationMetrics>,
     pub crisis_overlay: Res<'w, CrisisOverlayCache>,
+    /// Active crises and queued follow-ons, captured so a rollback keeps each crisis's escalation
+    /// progress. Optional so bare test worlds without the crisis system still capture.
+    pub crisis_ledger: Option<Res<'w, ActiveCrisisLedger>>,
+    pub crisis_seeds: Option<Res<'w, PendingCrisisSeeds>>,
     pub start_location: Res<'w, StartLocation>,
     pub herds: Res<'w, HerdTelemetry>,
     /// Authoritative herd sim state, captured into the rollback snapshot (`herd_registry`) so a
@@ -42,14 +53,21 @@ pub struct SnapshotContext<'w> {
     pub fog_reveals: Res<'w, FogRevealLedger>,
     pub elevation: Res<'w, ElevationField>,
     pub moisture: Option<Res<'w, MoistureRaster>>,
+    /// Tile entity index the terrain overlay is laid out from; absent before the first world build.
+    pub tile_registry: Option<Res<'w, TileRegistry>>,
+    /// River network and lakes, read for each tile's current `river_flow` and `lake_depth`.
+    pub hydrology: Option<Res<'w, HydrologyState>>,
     #[allow(dead_code)]
     pub map_presets: Res<'w, MapPresetsHandle>,
     pub campaign_label: Option<Res<'w, CampaignLabel>>,
     pub start_profiles: Res<'w, StartProfilesHandle>,
     pub victory: Res<'w, VictoryState>,
     pub faction_inventory: Res<'w, FactionInventory>,
+    pub diplomacy: Res<'w, DiplomacyLeverage>,
     pub sedentarization: Res<'w, SedentarizationScore>,
     pub discovered_sites: Res<'w, DiscoveredSites>,
+    /// Agent rosters, for each agent's earned experience; absent in worlds without espionage.
+    pub espionage_roster: Option<Res<'w, EspionageRoster>>,
     pub sites_config: Res<'w, SitesConfigHandle>,
     pub food_sites: Res<'w, FoodSiteRegistry>,
     pub command_events: Res<'w, CommandEventLog>,
@@ -75,6 +93,9 @@ pub struct SnapshotContext<'w> {
     /// Scouting & Hunting Expeditions → Snapshot).
     pub fauna: Res<'w, crate::fauna_config::FaunaConfigHandle>,
     pub expedition: Res<'w, crate::expedition_config::ExpeditionConfigHandle>,
+    /// LOS settings for a scouting party's projected reveal footprint — the same geometry
+    /// `advance_expeditions` observes with. Optional so bare test worlds fall back to the builtin.
+    pub visibility_config: Option<Res<'w, crate::visibility_config::VisibilityConfigHandle>>,
     pub settlement_stage: Res<'w, crate::settlement_stage_config::SettlementStageConfigHandle>,
     pub supply_membership: Res<'w, SupplyNetworkMembership>,
     pub pipeline_config: Res<'w, TurnPipelineConfigHandle>,
@@ -141,6 +162,7 @@ pub struct SnapshotHistory {
     great_discovery_telemetry: GreatDiscoveryTelemetryState,
     knowledge_ledger: HashMap<u64, KnowledgeLedgerEntryState>,
     knowledge_metrics: KnowledgeMetricsState,
+    knowledge_early_warnings: Vec<KnowledgeEarlyWarningState>,
     knowledge_timeline: Vec<KnowledgeTimelineEventState>,
     crisis_telemetry: CrisisTelemetryState,
     crisis_overlay: CrisisOverlayState,
@@ -155,24 +177,31 @@ pub struct SnapshotHistory {
     visibility_raster: ScalarRasterState,
     culture_raster: ScalarRasterState,
     military_raster: ScalarRasterState,
+    population_density_raster: ScalarRasterState,
     moisture_raster: FloatRasterState,
     elevation_overlay: ElevationOverlayState,
     climate_bands: ClimateBandsState,
     corruption: CorruptionLedger,
+    diplomacy_leverage: Vec<DiplomacyLeverageState>,
     victory: VictorySnapshotState,
     capability_flags: u32,
     faction_inventory: Vec<SchemaFactionInventoryState>,
     sedentarization: Vec<SchemaSedentarizationState>,
     discovered_sites: Vec<SchemaDiscoveredSitesState>,
+    espionage_agents: Vec<SchemaEspionageAgentState>,
     demographics: Vec<SchemaPopulationDemographicsState>,
+    morale_summary: Vec<MoraleSummaryState>,
     forage_patches: Vec<ForagePatchState>,
     intensification_knowledge: Vec<IntensificationKnowledgeState>,
     command_events: Vec<CommandEventState>,
     pending_forks: Vec<PendingForksState>,
     stance_axes: Vec<StanceState>,
     voice_medium: Vec<VoiceMediumState>,
+    calendar: CalendarState,
     herds: Vec<HerdTelemetryState>,
     food_modules: Vec<FoodModuleState>,
+    /// Sequence of the latest broadcast delta (see `SnapshotHeader::sequence`); 0 before the first.
+    sequence: u64,
     history: VecDeque<StoredSnapshot>,
 }
 
@@ -209,6 +238,7 @@ impl SnapshotHistory {
             great_discovery_telemetry: GreatDiscoveryTelemetryState::default(),
             knowledge_ledger: HashMap::new(),
             knowledge_metrics: KnowledgeMetricsState::default(),
+            knowledge_early_warnings: Vec::new(),
             knowledge_timeline: Vec::new(),
             crisis_telemetry: CrisisTelemetryState::default(),
             crisis_overlay: CrisisOverlayState::default(),
@@ -223,24 +253,30 @@ impl SnapshotHistory {
             visibility_raster: ScalarRasterState::default(),
             culture_raster: ScalarRasterState::default(),
             military_raster: ScalarRasterState::default(),
+            population_density_raster: ScalarRasterState::default(),
             moisture_raster: FloatRasterState::default(),
             elevation_overlay: ElevationOverlayState::default(),
             climate_bands: ClimateBandsState::default(),
             corruption: CorruptionLedger::default(),
+            diplomacy_leverage: Vec::new(),
             victory: VictorySnapshotState::default(),
             capability_flags: 0,
             faction_inventory: Vec::new(),
             sedentarization: Vec::new(),
             discovered_sites: Vec::new(),
+            espionage_agents: Vec::new(),
             demographics: Vec::new(),
+            morale_summary: Vec::new(),
             forage_patches: Vec::new(),
             intensification_knowledge: Vec::new(),
             command_events: Vec::new(),
             pending_forks: Vec::new(),
             stance_axes: Vec::new(),
             voice_medium: Vec::new(),
+            calendar: CalendarState::default(),
             herds: Vec::new(),
             food_modules: Vec::new(),
+            sequence: 0,
             history: VecDeque::new(),
         }
     }
@@ -266,6 +302,13 @@ impl SnapshotHistory {
         self.history.back().cloned()
     }
 
+    /// Tick of the most recent ring entry, without cloning it. The server compares this to the
+    /// live `SimulationTick` to tell whether the turn just resolved captured (and so should
+    /// broadcast) under a coarser `snapshot_every_n_ticks` cadence.
+    pub fn latest_tick(&self) -> Option<u64> {
+        self.history.back().map(|entry| entry.tick)
+    }
+
     pub fn entry(&self, tick: u64) -> Option<StoredSnapshot> {
         self.history
             .iter()
@@ -273,7 +316,47 @@ impl SnapshotHistory {
             .cloned()
     }
 
-    pub fn update(&mut self, snapshot: WorldSnapshot) {
+    /// Writes every `every`-th retained snapshot (oldest first) into `path` for offline replay or
+    /// analysis, returning how many were written.
+    ///
+    /// Each snapshot lands as `snapshot_<tick>.fb` (the FlatBuffer frame exactly as broadcast, for
+    /// external tooling) plus `snapshot_<tick>.json` (the complete typed twin [`load_snapshot_dir`]
+    /// prefers over the frame). `path` is created if missing; files from an earlier export of the same tick are
+    /// overwritten and anything else in the directory is left alone.
+    pub fn export_to_dir(&self, path: &Path, every: u32) -> io::Result<usize> {
+        if every == 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "snapshot export cadence must be at least 1",
+            ));
+        }
+        fs::create_dir_all(path)?;
+        let mut written = 0;
+        for entry in self.history.iter().step_by(every as usize) {
+            fs::write(
+                path.join(format!("snapshot_{}.fb", entry.tick)),
+                entry.encoded_snapshot_flat.as_slice(),
+            )?;
+            let json = encode_snapshot_json(entry.snapshot.as_ref())
+                .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+            fs::write(path.join(format!("snapshot_{}.json", entry.tick)), json)?;
+            written += 1;
+        }
+        Ok(written)
+    }
+
+    /// Sequence of the latest broadcast delta; a full snapshot sent now should carry this value.
+    pub fn sequence(&self) -> u64 {
+        self.sequence
+    }
+
+    fn next_sequence(&mut self) -> u64 {
+        self.sequence += 1;
+        self.sequence
+    }
+
+    pub fn update(&mut self, mut snapshot: WorldSnapshot) {
+        snapshot.header.sequence = self.next_sequence();
         let mut tiles_index = HashMap::with_capacity(snapshot.tiles.len());
         for state in &snapshot.tiles {
             tiles_index.insert(state.entity, state.clone());
@@ -425,6 +508,13 @@ impl SnapshotHistory {
             Some(military_raster_state.clone())
         };
 
+        let density_raster_state = snapshot.population_density_raster.clone();
+        let density_raster_delta = if self.population_density_raster == density_raster_state {
+            None
+        } else {
+            Some(density_raster_state.clone())
+        };
+
         let mut great_discovery_definitions_index =
             HashMap::with_capacity(snapshot.great_discovery_definitions.len());
         for state in &snapshot.great_discovery_definitions {
@@ -470,6 +560,13 @@ impl SnapshotHistory {
             Some(corruption_state.clone())
         };
 
+        let diplomacy_leverage_state = snapshot.diplomacy_leverage.clone();
+        let diplomacy_leverage_delta = if self.diplomacy_leverage == diplomacy_leverage_state {
+            None
+        } else {
+            Some(diplomacy_leverage_state.clone())
+        };
+
         let mut knowledge_ledger_index = HashMap::with_capacity(snapshot.knowledge_ledger.len());
         for entry in &snapshot.knowledge_ledger {
             knowledge_ledger_index.insert(
@@ -484,6 +581,13 @@ impl SnapshotHistory {
         } else {
             Some(knowledge_metrics_state.clone())
         };
+        let knowledge_early_warnings_state = snapshot.knowledge_early_warnings.clone();
+        let knowledge_early_warnings_delta =
+            if self.knowledge_early_warnings == knowledge_early_warnings_state {
+                None
+            } else {
+                Some(knowledge_early_warnings_state.clone())
+            };
 
         let knowledge_timeline_delta = if self.knowledge_timeline == snapshot.knowledge_timeline {
             Vec::new()
@@ -529,12 +633,24 @@ impl SnapshotHistory {
         } else {
             Some(discovered_sites_state.clone())
         };
+        let espionage_agents_state = snapshot.espionage_agents.clone();
+        let espionage_agents_delta = if self.espionage_agents == espionage_agents_state {
+            None
+        } else {
+            Some(espionage_agents_state.clone())
+        };
         let demographics_state = snapshot.demographics.clone();
         let demographics_delta = if self.demographics == demographics_state {
             None
         } else {
             Some(demographics_state.clone())
         };
+        let morale_summary_state = snapshot.morale_summary.clone();
+        let morale_summary_delta = if self.morale_summary == morale_summary_state {
+            None
+        } else {
+            Some(morale_summary_state.clone())
+        };
         let forage_patches_state = snapshot.forage_patches.clone();
         let forage_patches_delta = if self.forage_patches == forage_patches_state {
             None
@@ -572,6 +688,12 @@ impl SnapshotHistory {
         } else {
             Some(voice_medium_state.clone())
         };
+        let calendar_state = snapshot.calendar;
+        let calendar_delta = if self.calendar == calendar_state {
+            None
+        } else {
+            Some(calendar_state)
+        };
         let capability_flags_state = snapshot.capability_flags;
         let capability_flags_delta = if self.capability_flags == capability_flags_state {
             None
@@ -614,16 +736,20 @@ impl SnapshotHistory {
             knowledge_ledger: diff_new(&self.knowledge_ledger, &knowledge_ledger_index),
             removed_knowledge_ledger: diff_removed(&self.knowledge_ledger, &knowledge_ledger_index),
             knowledge_metrics: knowledge_metrics_delta.clone(),
+            knowledge_early_warnings: knowledge_early_warnings_delta.clone(),
             victory: victory_delta.clone(),
             capability_flags: capability_flags_delta,
             command_events: command_events_delta.clone(),
             pending_forks: pending_forks_delta.clone(),
             stance_axes: stance_axes_delta.clone(),
             voice_medium: voice_medium_delta.clone(),
+            calendar: calendar_delta,
             faction_inventory: faction_inventory_delta.clone(),
             sedentarization: sedentarization_delta.clone(),
             discovered_sites: discovered_sites_delta.clone(),
+            espionage_agents: espionage_agents_delta.clone(),
             demographics: demographics_delta.clone(),
+            morale_summary: morale_summary_delta.clone(),
             forage_patches: forage_patches_delta.clone(),
             intensification_knowledge: intensification_knowledge_delta.clone(),
             herds: herds_delta.clone(),
@@ -640,6 +766,7 @@ impl SnapshotHistory {
             generations: diff_new(&self.generations, &generations_index),
             removed_generations: diff_removed(&self.generations, &generations_index),
             corruption: corruption_delta.clone(),
+            diplomacy_leverage: diplomacy_leverage_delta.clone(),
             influencers: diff_new(&self.influencers, &influencers_index),
             removed_influencers: diff_removed(&self.influencers, &influencers_index),
             terrain: terrain_delta.clone(),
@@ -654,6 +781,7 @@ impl SnapshotHistory {
             culture_tensions: delta_culture_tensions.clone(),
             discovery_progress: diff_new(&self.discovery_progress, &discovery_index),
             visibility_raster: visibility_raster_delta.clone(),
+            population_density_raster: density_raster_delta.clone(),
         };
 
         let snapshot_arc = Arc::new(snapshot);
@@ -672,6 +800,7 @@ impl SnapshotHistory {
         self.great_discovery_telemetry = great_discovery_telemetry_state;
         self.knowledge_ledger = knowledge_ledger_index;
         self.knowledge_metrics = knowledge_metrics_state;
+        self.knowledge_early_warnings = knowledge_early_warnings_state;
         self.knowledge_timeline = snapshot_arc.knowledge_timeline.clone();
         self.crisis_telemetry = crisis_telemetry_state;
         self.crisis_overlay = crisis_overlay_state;
@@ -691,8 +820,10 @@ impl SnapshotHistory {
         self.visibility_raster = visibility_raster_state;
         self.culture_raster = culture_raster_state;
         self.military_raster = military_raster_state;
+        self.population_density_raster = density_raster_state;
         self.moisture_raster = moisture_state;
         self.corruption = corruption_state;
+        self.diplomacy_leverage = diplomacy_leverage_state;
         self.culture_tensions = culture_tensions_state;
         self.discovery_progress = discovery_index;
         self.victory = victory_state;
@@ -700,13 +831,16 @@ impl SnapshotHistory {
         self.faction_inventory = faction_inventory_state;
         self.sedentarization = sedentarization_state;
         self.discovered_sites = discovered_sites_state;
+        self.espionage_agents = espionage_agents_state;
         self.demographics = demographics_state;
+        self.morale_summary = morale_summary_state;
         self.forage_patches = forage_patches_state;
         self.intensification_knowledge = intensification_knowledge_state;
         self.command_events = command_events_state;
         self.pending_forks = pending_forks_state;
         self.stance_axes = stance_axes_state;
         self.voice_medium = voice_medium_state;
+        self.calendar = calendar_state;
         self.herds = herd_state;
         self.food_modules = food_modules_state;
         self.last_snapshot = Some(snapshot_arc);
@@ -763,6 +897,7 @@ impl SnapshotHistory {
             .map(|state| (state.id, state.clone()))
             .collect();
         self.corruption = entry.snapshot.corruption.clone();
+        self.diplomacy_leverage = entry.snapshot.diplomacy_leverage.clone();
         self.axis_bias = entry.snapshot.axis_bias.clone();
         self.sentiment = entry.snapshot.sentiment.clone();
         self.terrain_overlay = entry.snapshot.terrain.clone();
@@ -773,6 +908,7 @@ impl SnapshotHistory {
         self.visibility_raster = entry.snapshot.visibility_raster.clone();
         self.culture_raster = entry.snapshot.culture_raster.clone();
         self.military_raster = entry.snapshot.military_raster.clone();
+        self.population_density_raster = entry.snapshot.population_density_raster.clone();
         self.moisture_raster = entry.snapshot.moisture_raster.clone();
         self.culture_tensions = entry.snapshot.culture_tensions.clone();
         self.discovery_progress = entry
@@ -785,13 +921,16 @@ impl SnapshotHistory {
         self.faction_inventory = entry.snapshot.faction_inventory.clone();
         self.sedentarization = entry.snapshot.sedentarization.clone();
         self.discovered_sites = entry.snapshot.discovered_sites.clone();
+        self.espionage_agents = entry.snapshot.espionage_agents.clone();
         self.demographics = entry.snapshot.demographics.clone();
+        self.morale_summary = entry.snapshot.morale_summary.clone();
         self.forage_patches = entry.snapshot.forage_patches.clone();
         self.intensification_knowledge = entry.snapshot.intensification_knowledge.clone();
         self.command_events = entry.snapshot.command_events.clone();
         self.pending_forks = entry.snapshot.pending_forks.clone();
         self.stance_axes = entry.snapshot.stance_axes.clone();
         self.voice_medium = entry.snapshot.voice_medium.clone();
+        self.calendar = entry.snapshot.calendar;
         self.herds = entry.snapshot.herds.clone();
         self.food_modules = entry.snapshot.food_modules.clone();
         self.great_discoveries = entry
@@ -819,6 +958,7 @@ impl SnapshotHistory {
             })
             .collect();
         self.knowledge_metrics = entry.snapshot.knowledge_metrics.clone();
+        self.knowledge_early_warnings = entry.snapshot.knowledge_early_warnings.clone();
         self.knowledge_timeline = entry.snapshot.knowledge_timeline.clone();
         self.crisis_telemetry = entry.snapshot.crisis_telemetry.clone();
         self.crisis_overlay = entry.snapshot.crisis_overlay.clone();
@@ -826,6 +966,9 @@ impl SnapshotHistory {
         self.start_marker = entry.snapshot.start_marker.clone();
         self.capability_flags = entry.snapshot.capability_flags;
 
+        // The rollback broadcasts this entry's stored snapshot, so the stream resumes from its
+        // sequence: the next delta is `entry + 1`, not a gap relative to the pre-rollback stream.
+        self.sequence = entry.snapshot.header.sequence;
         self.last_snapshot = Some(entry.snapshot.clone());
         self.last_delta = Some(entry.delta.clone());
         self.encoded_snapshot = Some(entry.encoded_snapshot.clone());
@@ -849,11 +992,12 @@ impl SnapshotHistory {
 
         self.axis_bias = bias.clone();
 
-        let header = self
+        let mut header = self
             .last_snapshot
             .as_ref()
             .map(|snapshot| snapshot.header.clone())
             .unwrap_or_default();
+        header.sequence = self.next_sequence();
 
         let delta = WorldDelta {
             header,
@@ -875,18 +1019,22 @@ impl SnapshotHistory {
             knowledge_ledger: Vec::new(),
             removed_knowledge_ledger: Vec::new(),
             knowledge_metrics: None,
+            knowledge_early_warnings: None,
             victory: None,
             capability_flags: None,
             command_events: None,
             pending_forks: None,
             stance_axes: None,
             voice_medium: None,
+            calendar: None,
             herds: None,
             food_modules: None,
             faction_inventory: None,
             sedentarization: None,
             discovered_sites: None,
+            espionage_agents: None,
             demographics: None,
+            morale_summary: None,
             forage_patches: None,
             intensification_knowledge: None,
             knowledge_timeline: Vec::new(),
@@ -907,6 +1055,7 @@ impl SnapshotHistory {
             generations: Vec::new(),
             removed_generations: Vec::new(),
             corruption: None,
+            diplomacy_leverage: None,
             influencers: Vec::new(),
             removed_influencers: Vec::new(),
             terrain: None,
@@ -915,6 +1064,7 @@ impl SnapshotHistory {
             culture_tensions: Vec::new(),
             discovery_progress: Vec::new(),
             visibility_raster: None,
+            population_density_raster: None,
         };
 
         let delta_arc = Arc::new(delta);
@@ -928,6 +1078,7 @@ impl SnapshotHistory {
         if let Some(previous_snapshot) = self.last_snapshot.take() {
             let mut snapshot = (*previous_snapshot).clone();
             snapshot.axis_bias = bias.clone();
+            snapshot.header.sequence = self.sequence;
             let snapshot = snapshot.finalize();
             let encoded_snapshot =
                 Arc::new(encode_snapshot(&snapshot).expect("axis bias snapshot encoding failed"));
@@ -997,7 +1148,8 @@ impl SnapshotHistory {
     /// — so the rollback ring stays one-entry-per-tick and the next turn's delta still carries these
     /// structural changes (a redundant but idempotent re-send on top of this full snapshot). Never
     /// advances the turn or the `TurnQueue`.
-    pub fn refresh_latest(&mut self, snapshot: WorldSnapshot) -> Option<EncodedBuffers> {
+    pub fn refresh_latest(&mut self, mut snapshot: WorldSnapshot) -> Option<EncodedBuffers> {
+        snapshot.header.sequence = self.sequence;
         let snapshot_arc = Arc::new(snapshot);
         let encoded_snapshot = Arc::new(
             encode_snapshot(snapshot_arc.as_ref()).expect("recapture snapshot encoding failed"),
@@ -1015,16 +1167,28 @@ impl SnapshotHistory {
 
         Some((encoded_snapshot, encoded_snapshot_flat))
     }
+    /// Publish a roster change. `states` are the listed influencers and `latent` the ones below
+    /// the snapshot minimum (see `InfluentialRoster::snapshot_states`), which only the header
+    /// count and the rollback record carry.
     pub fn update_influencers(
         &mut self,
         states: Vec<InfluentialIndividualState>,
+        mut latent: Vec<InfluentialIndividualState>,
     ) -> Option<EncodedBuffers> {
+        // Match the id order `capture_snapshot` stores, so an unchanged roster compares equal.
+        latent.sort_unstable_by_key(|state| state.id);
         let mut index = HashMap::with_capacity(states.len());
         for state in &states {
             index.insert(state.id, state.clone());
         }
 
-        if index == self.influencers {
+        let latent_unchanged = self
+            .last_snapshot
+            .as_ref()
+            .map_or(latent.is_empty(), |snapshot| {
+                snapshot.latent_influencers == latent
+            });
+        if index == self.influencers && latent_unchanged {
             return None;
         }
 
@@ -1037,6 +1201,8 @@ impl SnapshotHistory {
             .map(|snapshot| snapshot.header.clone())
             .unwrap_or_default();
         header.influencer_count = states.len() as u32;
+        header.influencers_omitted = latent.len() as u32;
+        header.sequence = self.next_sequence();
 
         let delta = WorldDelta {
             header,
@@ -1058,18 +1224,22 @@ impl SnapshotHistory {
             knowledge_ledger: Vec::new(),
             removed_knowledge_ledger: Vec::new(),
             knowledge_metrics: None,
+            knowledge_early_warnings: None,
             victory: None,
             capability_flags: None,
             command_events: None,
             pending_forks: None,
             stance_axes: None,
             voice_medium: None,
+            calendar: None,
             herds: None,
             food_modules: None,
             faction_inventory: None,
             sedentarization: None,
             discovered_sites: None,
+            espionage_agents: None,
             demographics: None,
+            morale_summary: None,
             forage_patches: None,
             intensification_knowledge: None,
             knowledge_timeline: Vec::new(),
@@ -1090,6 +1260,7 @@ impl SnapshotHistory {
             generations: Vec::new(),
             removed_generations: Vec::new(),
             corruption: None,
+            diplomacy_leverage: None,
             influencers: added.clone(),
             removed_influencers: removed.clone(),
             terrain: None,
@@ -1098,6 +1269,7 @@ impl SnapshotHistory {
             culture_tensions: Vec::new(),
             discovery_progress: Vec::new(),
             visibility_raster: None,
+            population_density_raster: None,
         };
 
         let delta_arc = Arc::new(delta);
@@ -1112,6 +1284,9 @@ impl SnapshotHistory {
             let mut snapshot = (*previous_snapshot).clone();
             snapshot.influencers = states.clone();
             snapshot.header.influencer_count = states.len() as u32;
+            snapshot.header.influencers_omitted = latent.len() as u32;
+            snapshot.latent_influencers = latent;
+            snapshot.header.sequence = self.sequence;
             let snapshot = snapshot.finalize();
             let encoded_snapshot =
                 Arc::new(encode_snapshot(&snapshot).expect("influencer snapshot encoding failed"));
@@ -1145,11 +1320,12 @@ impl SnapshotHistory {
 
         self.corruption = ledger.clone();
 
-        let header = self
+        let mut header = self
             .last_snapshot
             .as_ref()
             .map(|snapshot| snapshot.header.clone())
             .unwrap_or_default();
+        header.sequence = self.next_sequence();
 
         let delta = WorldDelta {
             header,
@@ -1171,18 +1347,22 @@ impl SnapshotHistory {
             knowledge_ledger: Vec::new(),
             removed_knowledge_ledger: Vec::new(),
             knowledge_metrics: None,
+            knowledge_early_warnings: None,
             victory: None,
             capability_flags: None,
             command_events: None,
             pending_forks: None,
             stance_axes: None,
             voice_medium: None,
+            calendar: None,
             herds: None,
             food_modules: None,
             faction_inventory: None,
             sedentarization: None,
             discovered_sites: None,
+            espionage_agents: None,
             demographics: None,
+            morale_summary: None,
             forage_patches: None,
             intensification_knowledge: None,
             knowledge_timeline: Vec::new(),
@@ -1203,6 +1383,7 @@ impl SnapshotHistory {
             generations: Vec::new(),
             removed_generations: Vec::new(),
             corruption: Some(ledger.clone()),
+            diplomacy_leverage: None,
             influencers: Vec::new(),
             removed_influencers: Vec::new(),
             terrain: None,
@@ -1211,6 +1392,7 @@ impl SnapshotHistory {
             culture_tensions: Vec::new(),
             discovery_progress: Vec::new(),
             visibility_raster: None,
+            population_density_raster: None,
         };
 
         let delta_arc = Arc::new(delta);
@@ -1224,6 +1406,7 @@ impl SnapshotHistory {
         if let Some(previous_snapshot) = self.last_snapshot.take() {
             let mut snapshot = (*previous_snapshot).clone();
             snapshot.corruption = ledger.clone();
+            snapshot.header.sequence = self.sequence;
             let snapshot = snapshot.finalize();
             let encoded_snapshot =
                 Arc::new(encode_snapshot(&snapshot).expect("corruption snapshot encoding failed"));
@@ -1290,9 +1473,12 @@ pub fn capture_snapshot(
         config,
         tick,
         world_epoch,
+        config_hash,
         overlays,
         metrics,
         crisis_overlay,
+        crisis_ledger,
+        crisis_seeds,
         start_location,
         herds,
         herd_registry,
@@ -1302,13 +1488,17 @@ pub fn capture_snapshot(
         fog_reveals,
         elevation,
         moisture,
+        tile_registry,
+        hydrology,
         map_presets: _,
         campaign_label,
         start_profiles,
         victory,
         faction_inventory,
+        diplomacy,
         sedentarization,
         discovered_sites,
+        espionage_roster,
         sites_config,
         food_sites,
         command_events,
@@ -1322,6 +1512,7 @@ pub fn capture_snapshot(
         ladder,
         fauna,
         expedition,
+        visibility_config,
         settlement_stage,
         supply_membership,
         pipeline_config,
@@ -1361,14 +1552,39 @@ pub fn capture_snapshot(
     // pass so the refusal sweep below is one more read of the same query rather than a second walk of
     // the world.
     let mut tile_tags: HashMap<UVec2, sim_runtime::TerrainTags> = HashMap::new();
+    let river_flows = hydrology.as_ref().map(|hydrology| {
+        hydrology.flow_by_tile(
+            config.grid_size.x,
+            config.grid_size.y,
+            config.map_topology.wrap_horizontal,
+        )
+    });
+    let lake_depths = hydrology
+        .as_ref()
+        .map(|hydrology| hydrology.lake_depth_by_tile(config.grid_size.x, config.grid_size.y));
     for (entity, tile, food_module) in tiles.iter() {
-        tile_states.push(tile_state(
+        let mut state = tile_state(
             entity,
             tile,
             &morale_pressure_cfg,
             graze_registry.patch(tile.position),
             &labor_config.forage,
-        ));
+        );
+        if tile.position.x < config.grid_size.x {
+            let idx = (tile.position.y * config.grid_size.x + tile.position.x) as usize;
+            // Snapped onto the `Scalar` grid: a last-ULP wobble in the moisture mean must not mark
+            // an otherwise unchanged tile dirty in the delta.
+            let snap = |value: f32| Scalar::from_f32(value).to_f32();
+            state.river_flow = river_flows
+                .as_ref()
+                .and_then(|flows| flows.get(idx).copied())
+                .map_or(0.0, snap);
+            state.lake_depth = lake_depths
+                .as_ref()
+                .and_then(|depths| depths.get(idx).copied())
+                .map_or(0.0, snap);
+        }
+        tile_states.push(state);
         tile_tags.insert(tile.position, tile.terrain_tags);
         if let Some(module) = food_module {
             seasonal_weights.insert(tile.position, module.seasonal_weight);
@@ -1491,7 +1707,7 @@ pub fn capture_snapshot(
         });
     }
     for tile in tile_states.iter_mut() {
-        let owner = CultureOwner(tile.entity);
+        let owner = CultureOwner::from_ref(CultureOwnerRef::Tile(tile.entity));
         if let Some(layer) = culture.local_layer_by_owner(owner) {
             tile.culture_layer = layer.id;
         }
@@ -1531,6 +1747,7 @@ pub fn capture_snapshot(
         hunt_per_worker_provisions: hunt_per_worker_provisions(&labor_config, &fauna_config),
         hunt_viability_warn_turns: expedition_cfg.hunt.viability_warn_turns,
         band_move_tiles_per_turn: labor_config.band_move_tiles_per_turn,
+        observe_sight_range: expedition_cfg.observe_sight_range,
     };
     // A cohort → live-tile map so an in-flight expedition can find its home band's CURRENT tile
     // (bands are nomadic). The `populations` query is read-only, so iterating it twice is fine.
@@ -1543,6 +1760,26 @@ pub fn capture_snapshot(
                 .map(|p| (entity, p))
         })
         .collect();
+    // Scout reveal preview inputs — the LOS terrain grid is O(w×h), so build it only when a scouting
+    // party is actually on the map (the common capture has none).
+    let has_scout = populations.iter().any(|(_, _, _, _, expedition)| {
+        expedition.is_some_and(|exp| matches!(exp.mission, ExpeditionMission::Scout))
+    });
+    let scout_reveal_inputs = has_scout.then(|| {
+        let vis_cfg = visibility_config
+            .as_ref()
+            .map(|handle| handle.get())
+            .unwrap_or_else(crate::visibility_config::VisibilityConfig::builtin);
+        let terrain_tags = crate::visibility_systems::build_terrain_tags_grid(
+            tiles.iter().map(|(_, tile, _)| tile),
+            elevation.width,
+            elevation.height,
+        );
+        let blocking_tags = crate::visibility_systems::parse_blocking_tags(
+            &vis_cfg.line_of_sight.blocking_terrain_tags,
+        );
+        (vis_cfg, terrain_tags, blocking_tags)
+    });
     let mut population_states: Vec<PopulationCohortState> = populations
         .iter()
         .map(|(entity, cohort, allocation, travel, expedition)| {
@@ -1581,6 +1818,31 @@ pub fn capture_snapshot(
                     config.map_topology.wrap_horizontal,
                 )
             });
+            // A scouting party's reveal preview: what it will observe standing on its travel
+            // target (or its current tile once arrived) — the exact `visible_tiles_in_range` call
+            // `advance_expeditions` makes, so the preview matches the buffered reveal.
+            let expedition_reveal_footprint = match (expedition, &scout_reveal_inputs) {
+                (Some(exp), Some((vis_cfg, terrain_tags, blocking_tags)))
+                    if matches!(exp.mission, ExpeditionMission::Scout) =>
+                {
+                    travel_target
+                        .or(current_pos)
+                        .map(|center| {
+                            crate::visibility_systems::visible_tiles_in_range(
+                                center,
+                                expedition_cfg.observe_sight_range,
+                                &elevation,
+                                vis_cfg.line_of_sight.enabled,
+                                terrain_tags,
+                                &vis_cfg.terrain_modifiers,
+                                *blocking_tags,
+                                config.map_topology.wrap_horizontal,
+                            )
+                        })
+                        .unwrap_or_default()
+                }
+                _ => Vec::new(),
+            };
             population_state(PopulationStateInputs {
                 entity,
                 cohort,
@@ -1602,6 +1864,7 @@ pub fn capture_snapshot(
                 travel_target,
                 hunt_reach,
                 expedition_delivery,
+                expedition_reveal_footprint,
             })
         })
         .collect();
@@ -1618,14 +1881,16 @@ pub fn capture_snapshot(
         entries: knowledge_ledger_states,
         timeline: knowledge_timeline_states,
         metrics: knowledge_metrics_state,
+        early_warnings: knowledge_early_warnings_state,
     } = knowledge_ledger.snapshot_payload();
 
     let mut generation_states: Vec<GenerationState> =
         registry.profiles().iter().map(generation_state).collect();
     generation_states.sort_unstable_by_key(|state| state.id);
 
-    let mut influencer_states: Vec<InfluentialIndividualState> = roster.states();
+    let (mut influencer_states, mut latent_influencer_states) = roster.snapshot_states();
     influencer_states.sort_unstable_by_key(|state| state.id);
+    latent_influencer_states.sort_unstable_by_key(|state| state.id);
 
     let mut culture_layer_states: Vec<CultureLayerState> = Vec::new();
     if let Some(global_layer) = culture.global_layer() {
@@ -1654,50 +1919,85 @@ pub fn capture_snapshot(
     let great_discovery_progress_states = snapshot_progress(&gds.readiness);
     let great_discovery_telemetry_state = snapshot_telemetry(&gds.ledger, &gds.telemetry);
 
-    let terrain_overlay = terrain_overlay_from_tiles(&tile_states, config.grid_size);
+    let empty_registry = TileRegistry::default();
+    let mut terrain_overlay = tile_registry
+        .as_deref()
+        .unwrap_or(&empty_registry)
+        .terrain_overlay(config.grid_size.into(), |entity| {
+            tiles
+                .get(entity)
+                .ok()
+                .map(|(_, tile, _)| terrain_sample(tile))
+        });
+    terrain_overlay.palette_hash = terrain_palette_hash(&terrain_overlay.samples);
+    // The logistics raster is built whatever its channel toggle says, because the corruption and
+    // military rasters read it; the toggle only decides whether it ships (below).
+    let channels = overlays_config.channels();
     let logistics_raster =
         logistics_raster_from_links(&tile_states, &logistics_states, config.grid_size);
-    let sentiment_raster =
-        sentiment_raster_from_populations(&tile_states, &population_states, config.grid_size);
-    let corruption_raster = corruption_raster_from_simulation(CorruptionRasterInputs {
-        tiles: &tile_states,
-        trade_links: &trade_states,
-        populations: &population_states,
-        power_nodes: &power_states,
-        logistics_raster: &logistics_raster,
-        corruption_signals: CorruptionSignals {
-            ledger: corruption_ledgers.ledger(),
-            telemetry: &corruption_telemetry,
-        },
-        grid_size: config.grid_size,
-        overlays: overlays_config.as_ref(),
+    let sentiment_raster = overlay_channel(channels.enabled(OverlayChannel::Sentiment), || {
+        sentiment_raster_from_populations(&tile_states, &population_states, config.grid_size)
     });
-    let fog_raster = fog_raster_from_discoveries(FogRasterInputs {
-        tiles: &tile_states,
-        populations: &population_states,
-        discovery: &discovery_progress,
-        grid_size: config.grid_size,
-        overlays: overlays_config.as_ref(),
-        start_location: start_location.as_ref(),
-        fog_reveals: fog_reveals.as_ref(),
-        tick: tick.0,
+    let population_density_raster =
+        overlay_channel(channels.enabled(OverlayChannel::PopulationDensity), || {
+            population_density_raster_from_populations(
+                &tile_states,
+                &population_states,
+                config.grid_size,
+            )
+        });
+    let corruption_raster = overlay_channel(channels.enabled(OverlayChannel::Corruption), || {
+        corruption_raster_from_simulation(CorruptionRasterInputs {
+            tiles: &tile_states,
+            trade_links: &trade_states,
+            populations: &population_states,
+            power_nodes: &power_states,
+            logistics_raster: &logistics_raster,
+            corruption_signals: CorruptionSignals {
+                ledger: corruption_ledgers.ledger(),
+                telemetry: &corruption_telemetry,
+            },
+            grid_size: config.grid_size,
+            overlays: overlays_config.as_ref(),
+        })
+    });
+    let fog_raster = overlay_channel(channels.enabled(OverlayChannel::Fog), || {
+        fog_raster_from_discoveries(FogRasterInputs {
+            tiles: &tile_states,
+            populations: &population_states,
+            discovery: &discovery_progress,
+            grid_size: config.grid_size,
+            overlays: overlays_config.as_ref(),
+            start_location: start_location.as_ref(),
+            fog_reveals: fog_reveals.as_ref(),
+            elevation: Some(elevation.as_ref()),
+            tick: tick.0,
+        })
+    });
+    let culture_raster = overlay_channel(channels.enabled(OverlayChannel::Culture), || {
+        culture_raster_from_layers(
+            &tile_states,
+            culture.as_ref(),
+            config.grid_size,
+            overlays_config.as_ref(),
+        )
+    });
+    let military_raster = overlay_channel(channels.enabled(OverlayChannel::Military), || {
+        military_raster_from_state(
+            &tile_states,
+            &population_states,
+            &power_states,
+            &logistics_raster,
+            config.grid_size,
+            overlays_config.as_ref(),
+        )
+    });
+    let visibility_raster = overlay_channel(channels.enabled(OverlayChannel::Visibility), || {
+        visibility_raster_from_ledger(&visibility_ledger, viewer_faction.0, config.grid_size)
+    });
+    let logistics_raster = overlay_channel(channels.enabled(OverlayChannel::Logistics), || {
+        logistics_raster
     });
-    let culture_raster = culture_raster_from_layers(
-        &tile_states,
-        culture.as_ref(),
-        config.grid_size,
-        overlays_config.as_ref(),
-    );
-    let military_raster = military_raster_from_state(
-        &tile_states,
-        &population_states,
-        &power_states,
-        &logistics_raster,
-        config.grid_size,
-        overlays_config.as_ref(),
-    );
-    let visibility_raster =
-        visibility_raster_from_ledger(&visibility_ledger, viewer_faction.0, config.grid_size);
 
     let policy_axes = axis_bias.policy_values();
     let incident_axes = axis_bias.incident_values();
@@ -1708,6 +2008,7 @@ pub fn capture_snapshot(
     let incident_raw = incident_axes.map(Scalar::raw);
     let influencer_raw = influencer_axes.map(Scalar::raw);
     let combined_raw = combined_axes.map(Scalar::raw);
+    let generation_raw = axis_bias.generation_values().map(Scalar::raw);
 
     let mut axis_drivers: [Vec<SentimentDriverState>; 4] = std::array::from_fn(|_| Vec::new());
 
@@ -1775,6 +2076,11 @@ pub fn capture_snapshot(
         }
     }
 
+    // The generation lean is reported beside each axis, not in its total, so its breakdown stays
+    // out of `drivers`.
+    let [knowledge_generations, trust_generations, equity_generations, agency_generations] =
+        generation_sentiment_drivers(&axis_bias, &registry);
+
     let mut drivers_iter = axis_drivers.into_iter();
     let knowledge_drivers = drivers_iter.next().unwrap_or_default();
     let trust_drivers = drivers_iter.next().unwrap_or_default();
@@ -1788,6 +2094,8 @@ pub fn capture_snapshot(
             influencers: influencer_raw[0],
             total: combined_raw[0],
             drivers: knowledge_drivers,
+            generations: generation_raw[0],
+            generation_drivers: knowledge_generations,
         },
         trust: SentimentAxisTelemetry {
             policy: policy_raw[1],
@@ -1795,6 +2103,8 @@ pub fn capture_snapshot(
             influencers: influencer_raw[1],
             total: combined_raw[1],
             drivers: trust_drivers,
+            generations: generation_raw[1],
+            generation_drivers: trust_generations,
         },
         equity: SentimentAxisTelemetry {
             policy: policy_raw[2],
@@ -1802,6 +2112,8 @@ pub fn capture_snapshot(
             influencers: influencer_raw[2],
             total: combined_raw[2],
             drivers: equity_drivers,
+            generations: generation_raw[2],
+            generation_drivers: equity_generations,
         },
         agency: SentimentAxisTelemetry {
             policy: policy_raw[3],
@@ -1809,6 +2121,8 @@ pub fn capture_snapshot(
             influencers: influencer_raw[3],
             total: combined_raw[3],
             drivers: agency_drivers,
+            generations: generation_raw[3],
+            generation_drivers: agency_generations,
         },
     };
 
@@ -1817,6 +2131,14 @@ pub fn capture_snapshot(
     let crisis_overlay_state = CrisisOverlayState {
         heatmap: crisis_overlay.raster.clone(),
         annotations: crisis_overlay.annotations.clone(),
+        crises: crisis_ledger
+            .as_deref()
+            .map(ActiveCrisisLedger::entry_states)
+            .unwrap_or_default(),
+        pending_follow_ons: crisis_seeds
+            .as_deref()
+            .map(PendingCrisisSeeds::follow_on_states)
+            .unwrap_or_default(),
     };
 
     let mut header = SnapshotHeader::new(
@@ -1831,6 +2153,8 @@ pub fn capture_snapshot(
     header.wrap_horizontal = config.map_topology.wrap_horizontal;
     header.server_build = crate::BUILD_ID.to_string();
     header.world_epoch = world_epoch.0;
+    header.config_hash = config_hash.0;
+    header.influencers_omitted = latent_influencer_states.len() as u32;
 
     if let Some(label_res) = campaign_label.as_ref() {
         let label = label_res.as_ref();
@@ -1841,11 +2165,23 @@ pub fn capture_snapshot(
         .position()
         .map(|pos| StartMarkerState { x: pos.x, y: pos.y });
 
-    let moisture_overlay_state =
-        moisture_overlay_from_resource(moisture.as_ref().map(|res| res.as_ref()), config.grid_size);
+    let moisture_overlay_state = moisture_overlay_from_resource(
+        moisture.as_ref().map(|res| res.as_ref()),
+        config.grid_size,
+        overlays_config.precision().moisture(),
+    );
 
     let elevation_overlay_state =
         elevation_overlay_from_field(elevation.as_ref(), config.grid_size);
+    // Elevation always comes off the field; crisis and moisture only once their source exists.
+    let mut computed_channels = channels.enabled_flags() | OverlayChannelFlags::ELEVATION;
+    computed_channels.set(OverlayChannelFlags::CRISIS, crisis_overlay.raster.width > 0);
+    computed_channels.set(
+        OverlayChannelFlags::MOISTURE,
+        moisture_overlay_state.width > 0,
+    );
+    header.computed_channels = computed_channels;
+
     // The climate-band cut points ride the snapshot beside the other worldgen overlays
     // (`docs/plan_climate_authority.md` §8.3): the sim owns them, the client renders the band it is
     // told. A per-map constant read straight off the active `ClimateConfig`.
@@ -1889,7 +2225,15 @@ pub fn capture_snapshot(
     let faction_inventory_state = snapshot_faction_inventory(&faction_inventory);
     let sedentarization_state = snapshot_sedentarization(&sedentarization);
     let discovered_sites_state = snapshot_discovered_sites(&discovered_sites, &sites_config);
+    let espionage_agents_state = espionage_roster
+        .as_deref()
+        .map(snapshot_espionage_agents)
+        .unwrap_or_default();
     let demographics_state = snapshot_demographics(&population_states);
+    let morale_summary_state = snapshot_morale_summary(
+        &population_states,
+        Scalar::from_f32(wellbeing_config.migration.morale_threshold),
+    );
     let forage_patches_state = snapshot_forage_patches(
         &forage_registry,
         &labor_config.forage,
@@ -1905,6 +2249,7 @@ pub fn capture_snapshot(
     let pending_forks_state = snapshot_pending_forks(&beat_ledger);
     let stance_axes_state = snapshot_stance_axes(&beat_ledger);
     let voice_medium_state = snapshot_voice_medium(&beat_ledger);
+    let calendar_state = config.calendar(tick.0);
     let victory_snapshot_state = victory_snapshot_from_resource(&victory);
     let capability_bits = capability_flags.bits();
 
@@ -1924,6 +2269,7 @@ pub fn capture_snapshot(
         culture_raster: culture_raster.clone(),
         military_raster: military_raster.clone(),
         visibility_raster: visibility_raster.clone(),
+        population_density_raster: population_density_raster.clone(),
         moisture_raster: moisture_overlay_state.clone(),
         elevation_overlay: elevation_overlay_state.clone(),
         climate_bands: climate_bands_state,
@@ -1939,19 +2285,24 @@ pub fn capture_snapshot(
         faction_inventory: faction_inventory_state.clone(),
         sedentarization: sedentarization_state.clone(),
         discovered_sites: discovered_sites_state.clone(),
+        espionage_agents: espionage_agents_state,
         demographics: demographics_state.clone(),
+        morale_summary: morale_summary_state.clone(),
         forage_patches: forage_patches_state.clone(),
         intensification_knowledge: intensification_knowledge_state.clone(),
         command_events: command_events_state.clone(),
         pending_forks: pending_forks_state.clone(),
         stance_axes: stance_axes_state.clone(),
         voice_medium: voice_medium_state.clone(),
+        calendar: calendar_state,
         capability_flags: capability_bits,
         axis_bias: axis_bias_state,
         sentiment: sentiment_state,
         generations: generation_states,
         corruption: corruption_ledgers.ledger().clone(),
+        diplomacy_leverage: snapshot_diplomacy_leverage(&diplomacy),
         influencers: influencer_states,
+        latent_influencers: latent_influencer_states,
         culture_layers: culture_layer_states,
         culture_tensions: culture_tension_states,
         discovery_progress: discovery_states,
@@ -1962,6 +2313,7 @@ pub fn capture_snapshot(
         knowledge_ledger: knowledge_ledger_states,
         knowledge_timeline: knowledge_timeline_states,
         knowledge_metrics: knowledge_metrics_state,
+        knowledge_early_warnings: knowledge_early_warnings_state,
         crisis_telemetry: crisis_telemetry_state.clone(),
         crisis_overlay: crisis_overlay_state.clone(),
     }
@@ -1978,6 +2330,33 @@ pub fn capture_snapshot(
     }
 }
 
+/// Builds an overlay raster only when its channel is enabled; a disabled channel ships the empty
+/// default raster.
+fn overlay_channel(enabled: bool, build: impl FnOnce() -> ScalarRasterState) -> ScalarRasterState {
+    if enabled {
+        build()
+    } else {
+        ScalarRasterState::default()
+    }
+}
+
+/// Recompute [`ConfigHash`] when the `SimulationConfig` resource changed since the last turn, so a
+/// config swapped in after app build (the server's `new_game` path, a reload) is what the next
+/// captured header reports. Chained ahead of [`capture_snapshot`] in the Snapshot stage.
+pub fn refresh_config_hash(config: Res<SimulationConfig>, mut config_hash: ResMut<ConfigHash>) {
+    if config.is_changed() {
+        config_hash.0 = config.config_hash();
+    }
+}
+
+/// Run condition gating the turn-path [`capture_snapshot`] on
+/// `SimulationConfig::snapshot_every_n_ticks`. Evaluated after `advance_tick`, so the turn that just
+/// resolved is `tick - 1`; the world-build turn (resolved tick 0) therefore always captures. The
+/// post-command [`recapture_snapshot_in_place`] path bypasses it via `run_system_once`.
+pub fn snapshot_cadence_due(config: Res<SimulationConfig>, tick: Res<SimulationTick>) -> bool {
+    config.snapshot_due(tick.0.saturating_sub(1))
+}
+
 /// Selects how [`capture_snapshot`] writes its result: the normal turn path records a fresh ring
 /// entry (`false`); the post-command re-capture path refreshes the latest broadcast snapshot in
 /// place (`true`) so a world-mutating command is reflected immediately without corrupting the
@@ -1999,6 +2378,53 @@ pub fn recapture_snapshot_in_place(world: &mut World) {
     world.resource_mut::<SnapshotCaptureMode>().refresh_in_place = false;
 }
 
+/// Loads every snapshot written by [`SnapshotHistory::export_to_dir`] from `path`, ordered by tick.
+///
+/// A tick's `snapshot_<tick>.json` wins when present, since it is the complete typed record. A tick
+/// with only its `snapshot_<tick>.fb` frame is decoded from the frame, which lacks the snapshot-only
+/// fields the wire never carries (the sim registries, the beat ledger, latent influencers): fine
+/// for analysis, not for a rollback restore. Unrelated files are ignored; a file that fails to
+/// decode is an `InvalidData` error naming the file.
+pub fn load_snapshot_dir(path: &Path) -> io::Result<Vec<WorldSnapshot>> {
+    let mut exports: BTreeMap<u64, PathBuf> = BTreeMap::new();
+    for dir_entry in fs::read_dir(path)? {
+        let file = dir_entry?.path();
+        let Some(tick) = file
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .and_then(|stem| stem.strip_prefix("snapshot_"))
+            .and_then(|tick| tick.parse::<u64>().ok())
+        else {
+            continue;
+        };
+        match file.extension().and_then(|ext| ext.to_str()) {
+            Some("json") => {
+                exports.insert(tick, file);
+            }
+            Some("fb") => {
+                exports.entry(tick).or_insert(file);
+            }
+            _ => {}
+        }
+    }
+    exports
+        .into_values()
+        .map(|file| {
+            let decoded = if file.extension().is_some_and(|ext| ext == "json") {
+                decode_snapshot_json(&fs::read_to_string(&file)?).map_err(|err| err.to_string())
+            } else {
+                decode_snapshot_flatbuffer(&fs::read(&file)?).map_err(|err| err.to_string())
+            };
+            decoded.map_err(|err| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("{}: {err}", file.display()),
+                )
+            })
+        })
+        .collect()
+}
+
 pub fn restore_world_from_snapshot(world: &mut World, snapshot: &WorldSnapshot) {
     let knowledge_config = if let Some(handle) = world.get_resource::<KnowledgeLedgerConfigHandle>()
     {
@@ -2074,6 +2500,18 @@ pub fn restore_world_from_snapshot(world: &mut World, snapshot: &WorldSnapshot)
         world.insert_resource(discovered);
     }
 
+    // Agents keep the roster they have; only the experience earned after the restore point is
+    // taken back, so a rolled-back success no longer counts.
+    if let Some(mut roster) = world.get_resource_mut::<EspionageRoster>() {
+        for state in &snapshot.espionage_agents {
+            if let Some(agent) =
+                roster.agent_mut(FactionId(state.faction), EspionageAgentHandle(state.handle))
+            {
+                agent.experience = state.experience;
+            }
+        }
+    }
+
     // Despawn existing entities.
     let existing_tiles: Vec<Entity> = {
         let mut query = world.query_filtered::<Entity, With<Tile>>();
@@ -2144,6 +2582,7 @@ pub fn restore_world_from_snapshot(world: &mut World, snapshot: &WorldSnapshot)
             let demand = Scalar::from_raw(power_state.demand);
             entity_mut.insert(PowerNode {
                 id: PowerNodeId(power_state.node_id),
+                kind: power_node_kind_from_schema(power_state.kind),
                 base_generation: generation,
                 base_demand: demand,
                 generation,
@@ -2155,6 +2594,8 @@ pub fn restore_world_from_snapshot(world: &mut World, snapshot: &WorldSnapshot)
                 surplus: Scalar::from_raw(power_state.surplus),
                 deficit: Scalar::from_raw(power_state.deficit),
                 incident_count: power_state.incident_count,
+                priority: power_state.priority,
+                shed: Scalar::from_raw(power_state.shed),
             });
         }
 
@@ -2379,6 +2820,14 @@ pub fn restore_world_from_snapshot(world: &mut World, snapshot: &WorldSnapshot)
         parsed
     };
 
+    // Latent influencers are omitted from the client list but still part of the sim.
+    let roster_states: Vec<InfluentialIndividualState> = snapshot
+        .influencers
+        .iter()
+        .chain(&snapshot.latent_influencers)
+        .cloned()
+        .collect();
+
     let roster_sentiment;
     let roster_logistics;
     let roster_morale;
@@ -2387,14 +2836,14 @@ pub fn restore_world_from_snapshot(world: &mut World, snapshot: &WorldSnapshot)
         let generation_registry_clone = world.resource::<GenerationRegistry>().clone();
         if let Some(mut roster) = world.get_resource_mut::<InfluentialRoster>() {
             roster.apply_config(influencer_config.clone());
-            roster.update_from_states(&snapshot.influencers);
+            roster.update_from_states(&roster_states);
         } else {
             let mut roster = InfluentialRoster::with_seed(
                 0xA51C_E55E,
                 &generation_registry_clone,
                 influencer_config.clone(),
             );
-            roster.update_from_states(&snapshot.influencers);
+            roster.update_from_states(&roster_states);
             world.insert_resource(roster);
         }
     }
@@ -2422,6 +2871,21 @@ pub fn restore_world_from_snapshot(world: &mut World, snapshot: &WorldSnapshot)
         world.insert_resource(ledgers);
     }
 
+    let leverage_pairs = snapshot.diplomacy_leverage.iter().map(|pair| {
+        (
+            FactionId(pair.faction_a),
+            FactionId(pair.faction_b),
+            pair.leverage,
+        )
+    });
+    if let Some(mut diplomacy) = world.get_resource_mut::<DiplomacyLeverage>() {
+        diplomacy.set_pairs(leverage_pairs);
+    } else {
+        let mut diplomacy = DiplomacyLeverage::default();
+        diplomacy.set_pairs(leverage_pairs);
+        world.insert_resource(diplomacy);
+    }
+
     if let Some(new_effects) =
         world
             .get_resource_mut::<CultureManager>()
@@ -2451,13 +2915,42 @@ pub fn restore_world_from_snapshot(world: &mut World, snapshot: &WorldSnapshot)
         Scalar::from_raw(snapshot.sentiment.agency.incidents),
     ];
 
+    if let Some(mut crisis_ledger) = world.get_resource_mut::<ActiveCrisisLedger>() {
+        crisis_ledger.restore_entry_states(&snapshot.crisis_overlay.crises);
+    }
+    if let Some(mut crisis_seeds) = world.get_resource_mut::<PendingCrisisSeeds>() {
+        crisis_seeds.restore_follow_ons(&snapshot.crisis_overlay.pending_follow_ons);
+    }
+    // The gauges smooth and band across turns, so a rollback resumes their EMA, history and
+    // hysteresis rather than re-seeding them; the metrics copy follows so a recapture matches.
+    if let Some(mut telemetry) = world.get_resource_mut::<CrisisTelemetry>() {
+        telemetry.restore_save_state(&crisis_telemetry_save_from_state(
+            &snapshot.crisis_telemetry,
+        ));
+        let restored = telemetry.snapshot(snapshot.header.tick);
+        if let Some(mut metrics) = world.get_resource_mut::<SimulationMetrics>() {
+            metrics.crisis = restored;
+        }
+    }
+
+    let generation_contributions = world
+        .resource::<GenerationRegistry>()
+        .sentiment_contributions(&generation_shares(
+            snapshot
+                .populations
+                .iter()
+                .map(|cohort| (cohort.generation, cohort.size)),
+        ));
+
     if let Some(mut bias_res) = world.get_resource_mut::<SentimentAxisBias>() {
         bias_res.reset_to_state(policy_bias, incident_bias);
         bias_res.set_influencer(roster_sentiment);
+        bias_res.set_generation_contributions(generation_contributions);
     } else {
         let mut bias_res = SentimentAxisBias::default();
         bias_res.reset_to_state(policy_bias, incident_bias);
         bias_res.set_influencer(roster_sentiment);
+        bias_res.set_generation_contributions(generation_contributions);
         world.insert_resource(bias_res);
     }
 
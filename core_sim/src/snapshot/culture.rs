@@ -71,6 +71,35 @@ pub(crate) fn influencer_driver_weight(state: &InfluentialIndividualState) -> i6
     clamped.raw()
 }
 
+/// `Generation` sentiment drivers per axis: one per populated generation with a non-zero pull on
+/// that axis, labelled by the generation's name and weighted by its population share. They break
+/// down the axis' generation lean, so they ship as `generation_drivers`, apart from the `drivers`
+/// that make up its total.
+pub(crate) fn generation_sentiment_drivers(
+    bias: &SentimentAxisBias,
+    registry: &GenerationRegistry,
+) -> [Vec<SentimentDriverState>; 4] {
+    let mut drivers: [Vec<SentimentDriverState>; 4] = std::array::from_fn(|_| Vec::new());
+    for contribution in bias.generation_contributions() {
+        let name = registry
+            .profile(contribution.generation)
+            .map(|profile| profile.name.clone())
+            .unwrap_or_else(|| format!("#{}", contribution.generation));
+        for (idx, value) in contribution.axes.iter().enumerate() {
+            if value.raw() == 0 {
+                continue;
+            }
+            drivers[idx].push(SentimentDriverState {
+                category: SentimentDriverCategory::Generation,
+                label: format!("Generation {} · {}", name, AXIS_NAMES[idx]),
+                value: value.raw(),
+                weight: contribution.share.raw(),
+            });
+        }
+    }
+    drivers
+}
+
 pub(crate) fn culture_layer_state(layer: &CultureLayer) -> CultureLayerState {
     let baseline = layer.traits.baseline();
     let modifier = layer.traits.modifier();
@@ -199,7 +228,7 @@ pub(crate) fn culture_raster_from_layers(
         if idx >= samples.len() {
             continue;
         }
-        let owner = CultureOwner(tile.entity);
+        let owner = CultureOwner::from_ref(CultureOwnerRef::Tile(tile.entity));
         let Some(layer) = culture.local_layer_by_owner(owner) else {
             continue;
         };
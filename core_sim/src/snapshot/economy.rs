@@ -106,6 +106,9 @@ pub(crate) fn trade_link_from_state(state: &TradeLinkState) -> TradeLink {
     }
 }
 
+/// Flatten the inventory for the wire: factions ascending by id, each faction's items ascending by
+/// name, and empty stockpiles dropped. `FactionInventory` is hash-backed, so this ordering is what
+/// keeps `faction_inventory` — and the snapshot hash — identical across runs.
 pub(crate) fn snapshot_faction_inventory(
     inventory: &FactionInventory,
 ) -> Vec<SchemaFactionInventoryState> {
@@ -1,5 +1,20 @@
 use super::*;
 
+/// Flatten the pairwise leverage matrix for the wire, ascending by `(faction_a, faction_b)`
+/// (`DiplomacyLeverage` is BTree-backed, so the order — and the snapshot hash — is stable).
+pub(crate) fn snapshot_diplomacy_leverage(
+    diplomacy: &DiplomacyLeverage,
+) -> Vec<DiplomacyLeverageState> {
+    diplomacy
+        .pairs()
+        .map(|(a, b, leverage)| DiplomacyLeverageState {
+            faction_a: a.0,
+            faction_b: b.0,
+            leverage,
+        })
+        .collect()
+}
+
 pub(crate) fn crisis_metric_kind_to_schema(
     kind: InternalCrisisMetricKind,
 ) -> SchemaCrisisMetricKind {
@@ -24,6 +39,26 @@ pub(crate) fn crisis_severity_band_to_schema(
     }
 }
 
+fn crisis_metric_kind_from_schema(kind: SchemaCrisisMetricKind) -> InternalCrisisMetricKind {
+    match kind {
+        SchemaCrisisMetricKind::R0 => InternalCrisisMetricKind::R0,
+        SchemaCrisisMetricKind::GridStressPct => InternalCrisisMetricKind::GridStressPct,
+        SchemaCrisisMetricKind::UnauthorizedQueuePct => {
+            InternalCrisisMetricKind::UnauthorizedQueuePct
+        }
+        SchemaCrisisMetricKind::SwarmsActive => InternalCrisisMetricKind::SwarmsActive,
+        SchemaCrisisMetricKind::PhageDensity => InternalCrisisMetricKind::PhageDensity,
+    }
+}
+
+fn crisis_severity_band_from_schema(band: SchemaCrisisSeverityBand) -> InternalCrisisSeverityBand {
+    match band {
+        SchemaCrisisSeverityBand::Safe => InternalCrisisSeverityBand::Safe,
+        SchemaCrisisSeverityBand::Warn => InternalCrisisSeverityBand::Warn,
+        SchemaCrisisSeverityBand::Critical => InternalCrisisSeverityBand::Critical,
+    }
+}
+
 pub(crate) fn crisis_history_to_schema(
     history: &[InternalCrisisTrendSample],
 ) -> Vec<SchemaCrisisTrendSample> {
@@ -53,6 +88,8 @@ pub(crate) fn crisis_telemetry_state_from_metrics(
             stale_ticks: gauge.stale_ticks,
             band: crisis_severity_band_to_schema(gauge.band),
             history: crisis_history_to_schema(&gauge.history),
+            raw_band: crisis_severity_band_to_schema(gauge.raw_band),
+            ema_band: crisis_severity_band_to_schema(gauge.ema_band),
         })
         .collect();
 
@@ -66,6 +103,36 @@ pub(crate) fn crisis_telemetry_state_from_metrics(
     }
 }
 
+/// The inverse of [`crisis_telemetry_state_from_metrics`] for a rollback: the snapshot ships each
+/// gauge's history newest first, and an EMA only once the gauge has a sample.
+pub(crate) fn crisis_telemetry_save_from_state(
+    state: &CrisisTelemetryState,
+) -> CrisisTelemetrySave {
+    CrisisTelemetrySave {
+        gauges: state
+            .gauges
+            .iter()
+            .map(|gauge| CrisisGaugeSave {
+                kind: crisis_metric_kind_from_schema(gauge.kind),
+                raw: gauge.raw,
+                ema: (!gauge.history.is_empty()).then_some(gauge.ema),
+                history: gauge
+                    .history
+                    .iter()
+                    .rev()
+                    .map(|sample| (sample.tick, sample.value))
+                    .collect(),
+                last_updated_tick: gauge.last_updated_tick,
+                last_band: crisis_severity_band_from_schema(gauge.raw_band),
+                ema_band: crisis_severity_band_from_schema(gauge.ema_band),
+            })
+            .collect(),
+        modifiers_active: state.modifiers_active,
+        foreshock_incidents: state.foreshock_incidents,
+        containment_incidents: state.containment_incidents,
+    }
+}
+
 pub(crate) const CORRUPTION_SUBSYSTEM_COUNT: usize = 4;
 
 pub(crate) struct CorruptionSignals<'a> {
@@ -270,32 +337,20 @@ pub(crate) fn corruption_raster_from_simulation(
     }
 }
 
+/// Each weight over the largest, negatives as zero; all zeros when nothing is positive.
 pub(crate) fn normalize_weights_to_scalar(weights: &[i64]) -> Vec<Scalar> {
-    if weights.is_empty() {
-        return Vec::new();
-    }
-    let max_weight = weights.iter().copied().max().unwrap_or(0);
-    if max_weight <= 0 {
-        return vec![Scalar::zero(); weights.len()];
-    }
-    let max_value = i128::from(max_weight);
-    weights
-        .iter()
-        .map(|&weight| {
-            if weight <= 0 {
-                Scalar::zero()
-            } else {
-                let mut ratio = (i128::from(weight) * i128::from(Scalar::SCALE)) / max_value;
-                if ratio > i128::from(Scalar::SCALE) {
-                    ratio = i128::from(Scalar::SCALE);
-                }
-                if ratio < 0 {
-                    ratio = 0;
-                }
-                Scalar::from_raw(ratio as i64)
-            }
-        })
-        .collect()
+    // A leading zero anchors `bulk_normalize`'s min-max stretch at zero, so it divides by the
+    // largest weight alone.
+    let mut values = Vec::with_capacity(weights.len() + 1);
+    values.push(Scalar::zero());
+    values.extend(
+        weights
+            .iter()
+            .map(|&weight| Scalar::from_raw(weight.max(0))),
+    );
+    bulk_normalize(&mut values);
+    values.remove(0);
+    values
 }
 
 pub(crate) fn scale_spike(value: i64, multiplier: f32) -> i64 {
@@ -399,6 +454,27 @@ pub(crate) fn power_state(entity: Entity, node: &PowerNode) -> PowerNodeState {
         surplus: node.surplus.raw(),
         deficit: node.deficit.raw(),
         incident_count: node.incident_count,
+        kind: power_node_kind_to_schema(node.kind),
+        priority: node.priority,
+        shed: node.shed.raw(),
+    }
+}
+
+pub(crate) fn power_node_kind_to_schema(kind: GridNodeKind) -> PowerNodeKind {
+    match kind {
+        GridNodeKind::Generator => PowerNodeKind::Generator,
+        GridNodeKind::Storage => PowerNodeKind::Storage,
+        GridNodeKind::Consumer => PowerNodeKind::Consumer,
+        GridNodeKind::Hybrid => PowerNodeKind::Hybrid,
+    }
+}
+
+pub(crate) fn power_node_kind_from_schema(kind: PowerNodeKind) -> GridNodeKind {
+    match kind {
+        PowerNodeKind::Generator => GridNodeKind::Generator,
+        PowerNodeKind::Storage => GridNodeKind::Storage,
+        PowerNodeKind::Consumer => GridNodeKind::Consumer,
+        PowerNodeKind::Hybrid => GridNodeKind::Hybrid,
     }
 }
 
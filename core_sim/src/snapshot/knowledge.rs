@@ -21,6 +21,27 @@ pub(crate) fn discovery_progress_entries(
     entries
 }
 
+/// Every faction's espionage agents for the snapshot, ordered by `(faction, handle)`.
+pub(crate) fn snapshot_espionage_agents(
+    roster: &EspionageRoster,
+) -> Vec<SchemaEspionageAgentState> {
+    let mut agents: Vec<SchemaEspionageAgentState> = roster
+        .iter_sorted()
+        .into_iter()
+        .flat_map(|(faction, agents)| {
+            agents.iter().map(move |agent| SchemaEspionageAgentState {
+                faction: faction.0,
+                handle: agent.handle.0,
+                template_id: agent.template_id.0.clone(),
+                name: agent.name.clone(),
+                experience: agent.experience,
+            })
+        })
+        .collect();
+    agents.sort_unstable_by_key(|agent| (agent.faction, agent.handle));
+    agents
+}
+
 /// Per-faction discovered-sites registry for the snapshot. Each record's `category`/`display_name`/
 /// `glyph` is resolved from the sites catalog (missing entries fall back to the raw `site_id` so a
 /// pruned catalog never drops a discovery). Records are emitted in a stable `(y, x, site_id)` order
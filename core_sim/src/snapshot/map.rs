@@ -1,38 +1,41 @@
+use std::hash::Hasher;
+
+use sim_runtime::{RasterPrecision, TerrainType};
+
 use super::*;
+use crate::hashing::FnvHasher;
 
-pub(crate) fn terrain_overlay_from_tiles(
-    tiles: &[TileState],
-    grid_size: UVec2,
-) -> TerrainOverlayState {
-    let mut max_x = 0u32;
-    let mut max_y = 0u32;
-    for tile in tiles {
-        max_x = max_x.max(tile.x);
-        max_y = max_y.max(tile.y);
+/// The terrain overlay cell for `tile` — what `capture_snapshot` has
+/// [`TileRegistry::terrain_overlay`] place at its position in the snapshot.
+pub(crate) fn terrain_sample(tile: &Tile) -> TerrainSample {
+    let (mountain_kind, relief_scale) = match tile.mountain {
+        Some(meta) => (map_mountain_kind(meta.kind), meta.relief),
+        None => (MountainKind::None, 1.0),
+    };
+    TerrainSample {
+        terrain: tile.terrain,
+        tags: tile.terrain_tags,
+        mountain_kind,
+        relief_scale,
     }
-    let width = grid_size.x.max(max_x.saturating_add(1)).max(1);
-    let height = grid_size.y.max(max_y.saturating_add(1)).max(1);
-    let total = (width as usize).saturating_mul(height as usize).max(1);
-    let mut samples = vec![TerrainSample::default(); total];
-    for tile in tiles {
-        if tile.x >= width || tile.y >= height {
-            continue;
-        }
-        let idx = (tile.y as usize) * (width as usize) + tile.x as usize;
-        if idx < samples.len() {
-            samples[idx] = TerrainSample {
-                terrain: tile.terrain,
-                tags: tile.terrain_tags,
-                mountain_kind: tile.mountain_kind,
-                relief_scale: tile.mountain_relief,
-            };
+}
+
+/// FNV-1a over the sorted distinct terrain types in `samples` (see snapshot.fbs `paletteHash`).
+///
+/// Only the *set* of types matters: tiles trading one present type for another leave the hash
+/// alone, while a reclassification that introduces or retires a type changes it.
+pub(crate) fn terrain_palette_hash(samples: &[TerrainSample]) -> u64 {
+    let mut present = [false; TerrainType::VALUES.len()];
+    for sample in samples {
+        if let Some(slot) = present.get_mut(sample.terrain as usize) {
+            *slot = true;
         }
     }
-    TerrainOverlayState {
-        width,
-        height,
-        samples,
+    let mut hasher = FnvHasher::new();
+    for (id, _) in present.iter().enumerate().filter(|(_, seen)| **seen) {
+        hasher.write_u16(id as u16);
     }
+    hasher.finish()
 }
 
 /// The u16 lattice the elevation overlay's samples are quantized onto.
@@ -111,10 +114,14 @@ pub(crate) fn elevation_overlay_from_field(
 pub(crate) fn moisture_overlay_from_resource(
     moisture: Option<&MoistureRaster>,
     grid_size: UVec2,
+    precision: RasterPrecision,
 ) -> FloatRasterState {
     if let Some(raster) = moisture {
         if raster.width == grid_size.x && raster.height == grid_size.y {
-            return raster.as_state();
+            return FloatRasterState {
+                precision,
+                ..raster.as_state()
+            };
         }
     }
     FloatRasterState::default()
@@ -127,10 +134,11 @@ pub(crate) fn tile_state(
     graze: Option<&GrazePatch>,
     forage: &ForageLaborConfig,
 ) -> TileState {
-    let (mountain_kind, mountain_relief) = match tile.mountain {
-        Some(meta) => (map_mountain_kind(meta.kind), meta.relief),
-        None => (MountainKind::None, 1.0),
-    };
+    let TerrainSample {
+        mountain_kind,
+        relief_scale: mountain_relief,
+        ..
+    } = terrain_sample(tile);
     // Band-independent tile harshness — the same `tile_morale_pressure` the sim applies to morale.
     let habitability = tile_morale_pressure(
         &terrain_definition(tile.terrain),
@@ -174,6 +182,9 @@ pub(crate) fn tile_state(
         river_edges: tile.river_edges,
         river_inflow: tile.river_inflow,
         river_channel: tile.river_channel,
+        // Filled from `HydrologyState` by the capture loop; a lone tile has no river context.
+        river_flow: 0.0,
+        lake_depth: 0.0,
     }
 }
 
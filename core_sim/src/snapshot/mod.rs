@@ -9,32 +9,36 @@ use bevy::{
 };
 use log::warn;
 use sim_runtime::{
-    encode_delta, encode_delta_flatbuffer, encode_snapshot, encode_snapshot_flatbuffer,
-    AccessibleStockpileEntryState, AccessibleStockpileState, AxisBiasState, ClimateBandsState,
-    CohortStoreState, CommandEventState, CorruptionLedger, CorruptionSubsystem, CrisisGaugeState,
-    CrisisMetricKind as SchemaCrisisMetricKind, CrisisOverlayState,
+    decode_snapshot_flatbuffer, decode_snapshot_json, encode_delta, encode_delta_flatbuffer,
+    encode_snapshot, encode_snapshot_flatbuffer, encode_snapshot_json,
+    AccessibleStockpileEntryState, AccessibleStockpileState, AxisBiasState, CalendarState,
+    ClimateBandsState, CohortStoreState, CommandEventState, CorruptionLedger, CorruptionSubsystem,
+    CrisisGaugeState, CrisisMetricKind as SchemaCrisisMetricKind, CrisisOverlayState,
     CrisisSeverityBand as SchemaCrisisSeverityBand, CrisisTelemetryState,
-    CrisisTrendSample as SchemaCrisisTrendSample, CultureLayerState, CultureTensionState,
-    CultureTraitEntry, DiscoveredSiteState as SchemaDiscoveredSiteState,
+    CrisisTrendSample as SchemaCrisisTrendSample, CultureLayerState, CultureOwnerRef,
+    CultureTensionState, CultureTraitEntry, DiplomacyLeverageState,
+    DiscoveredSiteState as SchemaDiscoveredSiteState,
     DiscoveredSitesState as SchemaDiscoveredSitesState, DiscoveryProgressEntry, EcologyState,
-    ElevationOverlayState, FactionInventoryEntryState as SchemaFactionInventoryEntryState,
+    ElevationOverlayState, EspionageAgentState as SchemaEspionageAgentState,
+    FactionInventoryEntryState as SchemaFactionInventoryEntryState,
     FactionInventoryState as SchemaFactionInventoryState, FloatRasterState, FloraShareInfo,
     FoodModuleState, ForagePatchState, ForageState, ForkChoiceState, GenerationState,
     GlossEntryState, GrazeState, GreatDiscoveryDefinitionState, GreatDiscoveryProgressState,
     GreatDiscoveryState, GreatDiscoveryTelemetryState, HerdRoamState, HerdState,
     HerdTelemetryState, HuntPolicyCeilingState, HuntTripEstimateState, InfluentialIndividualState,
-    IntensificationKnowledgeState, KnowledgeLedgerEntryState, KnowledgeMetricsState,
-    KnowledgeTimelineEventState, LaborAssignmentState, LogisticsLinkState, MountainKind,
-    PendingForkState, PendingForksState, PendingMigrationState, PopulationCohortState,
+    IntensificationKnowledgeState, KnowledgeEarlyWarningState, KnowledgeLedgerEntryState,
+    KnowledgeMetricsState, KnowledgeTimelineEventState, LaborAssignmentState, LogisticsLinkState,
+    MoraleSummaryState, MountainKind, OverlayChannelFlags, PendingForkState, PendingForksState,
+    PendingMigrationState, PopulationCohortState,
     PopulationDemographicsState as SchemaPopulationDemographicsState, PowerIncidentSeverity,
-    PowerIncidentState, PowerNodeState, PowerTelemetryState, ScalarRasterState,
+    PowerIncidentState, PowerNodeKind, PowerNodeState, PowerTelemetryState, ScalarRasterState,
     SedentarizationState as SchemaSedentarizationState, SentimentAxisTelemetry,
     SentimentDriverCategory, SentimentDriverState, SentimentTelemetryState,
     SettlementStageViewState, SnapshotHeader, StanceAxisState, StanceState, StartMarkerState,
     TerrainOverlayState, TerrainSample, TileState, TradeLinkKnowledge, TradeLinkState,
-    VictoryModeSnapshotState, VictoryResultState, VictorySnapshotState, VoiceLineState,
-    VoiceMediumState, WorldDelta, WorldSnapshot, GRAZE_PHASE_COLLAPSING, GRAZE_PHASE_NONE,
-    GRAZE_PHASE_STRESSED, GRAZE_PHASE_THRIVING,
+    VictoryFactionProgressState, VictoryModeSnapshotState, VictoryResultState,
+    VictorySnapshotState, VoiceLineState, VoiceMediumState, WorldDelta, WorldSnapshot,
+    GRAZE_PHASE_COLLAPSING, GRAZE_PHASE_NONE, GRAZE_PHASE_STRESSED, GRAZE_PHASE_THRIVING,
 };
 
 use crate::{
@@ -51,6 +55,7 @@ use crate::{
         CultureTensionRecord, CultureTraitAxis as SimCultureTraitAxis,
     },
     demographics_config::{DemographicsConfig, DemographicsConfigHandle},
+    espionage::{EspionageAgentHandle, EspionageRoster},
     expedition_config::ExpeditionConfig,
     fauna::{
         herd_herders_needed, hunt_forecast, pen_upkeep, EcologyPhase, Herd, HerdDensityMap,
@@ -74,9 +79,10 @@ use crate::{
         GreatDiscoveryTelemetry,
     },
     heightfield::ElevationField,
+    hydrology::HydrologyState,
     influencers::{
-        InfluencerBalanceConfig, InfluencerConfigHandle, InfluencerImpacts, InfluentialRoster,
-        BUILTIN_INFLUENCER_CONFIG,
+        generation_shares, InfluencerBalanceConfig, InfluencerConfigHandle, InfluencerImpacts,
+        InfluentialRoster, BUILTIN_INFLUENCER_CONFIG,
     },
     intensification::{LadderConfig, RungKey, SiteRefusal, SITE_ACCEPTED},
     knowledge_ledger::{
@@ -87,19 +93,24 @@ use crate::{
     map_preset::MapPresetsHandle,
     metrics::SimulationMetrics,
     orders::FactionId,
-    power::{PowerGridState, PowerIncidentSeverity as GridIncidentSeverity, PowerNodeId},
+    power::{
+        PowerGridState, PowerIncidentSeverity as GridIncidentSeverity, PowerNodeId,
+        PowerNodeKind as GridNodeKind,
+    },
     resources::FoodSiteRegistry,
     resources::{
-        CapabilityFlags, CommandEventLog, CorruptionLedgers, CorruptionTelemetry,
-        DiscoveryProgressLedger, FactionInventory, FogRevealLedger, MoistureRaster,
-        SentimentAxisBias, SimulationConfig, SimulationTick, StartLocation, TileRegistry,
-        WorldEpoch,
+        CapabilityFlags, CommandEventLog, ConfigHash, CorruptionLedgers, CorruptionTelemetry,
+        DiplomacyLeverage, DiscoveryProgressLedger, FactionInventory, FogRevealLedger,
+        MoistureRaster, PendingCrisisSeeds, SentimentAxisBias, SimulationConfig, SimulationTick,
+        StartLocation, TileRegistry, WorldEpoch,
     },
-    scalar::{scalar_zero, Scalar},
+    scalar::{bulk_normalize, scalar_zero, Scalar},
     sedentarization::SedentarizationScore,
     sites::DiscoveredSites,
     sites_config::SitesConfigHandle,
-    snapshot_overlays_config::{SnapshotOverlaysConfig, SnapshotOverlaysConfigHandle},
+    snapshot_overlays_config::{
+        OverlayChannel, SnapshotOverlaysConfig, SnapshotOverlaysConfigHandle,
+    },
     start_profile::{snapshot_profiles, CampaignLabel, FogMode, StartProfilesHandle},
     supply::SupplyNetworkMembership,
     systems::{
@@ -115,9 +126,9 @@ use crate::{
 use crate::mapgen::MountainType;
 
 use crate::crisis::{
-    CrisisMetricKind as InternalCrisisMetricKind,
+    ActiveCrisisLedger, CrisisGaugeSave, CrisisMetricKind as InternalCrisisMetricKind,
     CrisisMetricsSnapshot as InternalCrisisMetricsSnapshot, CrisisOverlayCache,
-    CrisisSeverityBand as InternalCrisisSeverityBand,
+    CrisisSeverityBand as InternalCrisisSeverityBand, CrisisTelemetry, CrisisTelemetrySave,
     CrisisTrendSample as InternalCrisisTrendSample,
 };
 
@@ -194,7 +205,7 @@ mod tests {
         labor_config::LaborConfig,
         orders::FactionId,
         power::PowerIncidentSeverity as GridIncidentSeverity,
-        resources::{CorruptionTelemetry, DiscoveryProgressLedger},
+        resources::{CorruptionTelemetry, DiscoveryProgressLedger, GridDims},
         scalar::Scalar,
         start_profile::StartProfileOverrides,
         PowerIncident,
@@ -324,6 +335,8 @@ mod tests {
             river_edges: 0,
             river_inflow: 0,
             river_channel: 0,
+            river_flow: 0.0,
+            lake_depth: 0.0,
         }
     }
 
@@ -384,6 +397,80 @@ mod tests {
         assert_eq!(capture(TerrainType::DeepOcean, None), 0.0);
     }
 
+    /// `TileRegistry::terrain_overlay` lays every tile out row-major at `y * width + x` with its
+    /// own terrain, tags and mountain metadata, and pads a grid larger than the registry with
+    /// default samples.
+    #[test]
+    fn registry_terrain_overlay_places_each_tile_at_its_row_major_index() {
+        let terrains = [
+            TerrainType::DeepOcean,
+            TerrainType::PrairieSteppe,
+            TerrainType::Glacier,
+            TerrainType::MixedWoodland,
+            TerrainType::ContinentalShelf,
+            TerrainType::PrairieSteppe,
+        ];
+        let (width, height) = (3u32, 2u32);
+        let mut world = World::new();
+        let mut entities = Vec::new();
+        for (idx, terrain) in terrains.iter().enumerate() {
+            let position = UVec2::new(idx as u32 % width, idx as u32 / width);
+            let mountain = (idx == 3).then_some(MountainMetadata {
+                kind: MountainType::Fold,
+                relief: 1.5,
+            });
+            entities.push(
+                world
+                    .spawn(Tile {
+                        position,
+                        element: ElementKind::Arborite,
+                        mass: Scalar::zero(),
+                        temperature: Scalar::zero(),
+                        terrain: *terrain,
+                        terrain_tags: TerrainTags::new(1 << idx),
+                        underlying_terrain: None,
+                        mountain,
+                        river_edges: 0,
+                        river_inflow: 0,
+                        river_channel: 0,
+                    })
+                    .id(),
+            );
+        }
+        let registry = TileRegistry {
+            tiles: entities,
+            width,
+            height,
+        };
+        let sample = |entity: Entity| world.get::<Tile>(entity).map(terrain_sample);
+
+        let overlay = registry.terrain_overlay(GridDims::new(width, height), sample);
+        assert_eq!((overlay.width, overlay.height), (width, height));
+        assert_eq!(overlay.samples.len(), terrains.len());
+        for y in 0..height {
+            for x in 0..width {
+                let tile = world
+                    .get::<Tile>(registry.index(x, y).expect("tile registered"))
+                    .expect("tile spawned");
+                assert_eq!(tile.position, UVec2::new(x, y));
+                let cell = &overlay.samples[(y * width + x) as usize];
+                assert_eq!(cell.terrain, tile.terrain, "terrain at ({x}, {y})");
+                assert_eq!(cell.tags, tile.terrain_tags, "tags at ({x}, {y})");
+            }
+        }
+        assert_eq!(overlay.samples[3].mountain_kind, MountainKind::Fold);
+        assert_eq!(overlay.samples[3].relief_scale, 1.5);
+        assert_eq!(overlay.samples[0].mountain_kind, MountainKind::None);
+        // Hashing the legend is the snapshot layer's job, not the registry's.
+        assert_eq!(overlay.palette_hash, 0);
+
+        let padded = registry.terrain_overlay(GridDims::new(4, 3), sample);
+        assert_eq!((padded.width, padded.height), (4, 3));
+        assert_eq!(padded.samples[4 + 2].terrain, terrains[5]);
+        assert_eq!(padded.samples[3], TerrainSample::default());
+        assert_eq!(padded.samples[2 * 4], TerrainSample::default());
+    }
+
     fn snapshot_with_overlay(
         tick: u64,
         tile: TileState,
@@ -406,6 +493,7 @@ mod tests {
             knowledge_ledger: Vec::new(),
             knowledge_timeline: Vec::new(),
             knowledge_metrics: KnowledgeMetricsState::default(),
+            knowledge_early_warnings: Vec::new(),
             victory: VictorySnapshotState::default(),
             crisis_telemetry: CrisisTelemetryState::default(),
             crisis_overlay: CrisisOverlayState::default(),
@@ -415,6 +503,7 @@ mod tests {
             pending_forks: Vec::new(),
             stance_axes: Vec::new(),
             voice_medium: Vec::new(),
+            calendar: CalendarState::default(),
             herds: Vec::new(),
             herd_registry: Vec::new(),
             forage_registry: Vec::new(),
@@ -424,7 +513,9 @@ mod tests {
             faction_inventory: Vec::new(),
             sedentarization: Vec::new(),
             discovered_sites: Vec::new(),
+            espionage_agents: Vec::new(),
             demographics: Vec::new(),
+            morale_summary: Vec::new(),
             forage_patches: Vec::new(),
             intensification_knowledge: Vec::new(),
             terrain: overlay,
@@ -442,11 +533,14 @@ mod tests {
             sentiment: SentimentTelemetryState::default(),
             generations: Vec::new(),
             corruption: CorruptionLedger::default(),
+            diplomacy_leverage: Vec::new(),
             influencers: Vec::new(),
+            latent_influencers: Vec::new(),
             culture_layers: Vec::new(),
             culture_tensions: Vec::new(),
             discovery_progress: Vec::new(),
             visibility_raster: ScalarRasterState::default(),
+            population_density_raster: ScalarRasterState::default(),
         }
         .finalize()
     }
@@ -473,6 +567,7 @@ mod tests {
             knowledge_ledger: Vec::new(),
             knowledge_timeline: Vec::new(),
             knowledge_metrics: KnowledgeMetricsState::default(),
+            knowledge_early_warnings: Vec::new(),
             victory: VictorySnapshotState::default(),
             crisis_telemetry: CrisisTelemetryState::default(),
             crisis_overlay: CrisisOverlayState::default(),
@@ -482,6 +577,7 @@ mod tests {
             pending_forks: Vec::new(),
             stance_axes: Vec::new(),
             voice_medium: Vec::new(),
+            calendar: CalendarState::default(),
             herds: Vec::new(),
             herd_registry: Vec::new(),
             forage_registry: Vec::new(),
@@ -491,7 +587,9 @@ mod tests {
             faction_inventory: Vec::new(),
             sedentarization: Vec::new(),
             discovered_sites: Vec::new(),
+            espionage_agents: Vec::new(),
             demographics: Vec::new(),
+            morale_summary: Vec::new(),
             forage_patches: Vec::new(),
             intensification_knowledge: Vec::new(),
             moisture_raster: FloatRasterState::default(),
@@ -509,11 +607,14 @@ mod tests {
             sentiment: SentimentTelemetryState::default(),
             generations: Vec::new(),
             corruption: CorruptionLedger::default(),
+            diplomacy_leverage: Vec::new(),
             influencers: Vec::new(),
+            latent_influencers: Vec::new(),
             culture_layers: Vec::new(),
             culture_tensions: Vec::new(),
             discovery_progress: Vec::new(),
             visibility_raster: ScalarRasterState::default(),
+            population_density_raster: ScalarRasterState::default(),
         }
         .finalize()
     }
@@ -535,6 +636,7 @@ mod tests {
             knowledge_ledger: Vec::new(),
             knowledge_timeline: Vec::new(),
             knowledge_metrics: KnowledgeMetricsState::default(),
+            knowledge_early_warnings: Vec::new(),
             victory: VictorySnapshotState::default(),
             crisis_telemetry: CrisisTelemetryState::default(),
             crisis_overlay: CrisisOverlayState::default(),
@@ -544,6 +646,7 @@ mod tests {
             pending_forks: Vec::new(),
             stance_axes: Vec::new(),
             voice_medium: Vec::new(),
+            calendar: CalendarState::default(),
             herds: Vec::new(),
             herd_registry: Vec::new(),
             forage_registry: Vec::new(),
@@ -553,7 +656,9 @@ mod tests {
             faction_inventory: Vec::new(),
             sedentarization: Vec::new(),
             discovered_sites: Vec::new(),
+            espionage_agents: Vec::new(),
             demographics: Vec::new(),
+            morale_summary: Vec::new(),
             forage_patches: Vec::new(),
             intensification_knowledge: Vec::new(),
             moisture_raster: FloatRasterState::default(),
@@ -571,11 +676,14 @@ mod tests {
             sentiment: SentimentTelemetryState::default(),
             generations: Vec::new(),
             corruption: CorruptionLedger::default(),
+            diplomacy_leverage: Vec::new(),
             influencers: Vec::new(),
+            latent_influencers: Vec::new(),
             culture_layers: Vec::new(),
             culture_tensions: Vec::new(),
             discovery_progress: Vec::new(),
             visibility_raster: ScalarRasterState::default(),
+            population_density_raster: ScalarRasterState::default(),
         }
         .finalize()
     }
@@ -632,6 +740,7 @@ mod tests {
             hunt_per_worker_provisions: 0.0,
             hunt_viability_warn_turns: 0,
             band_move_tiles_per_turn: 0,
+            observe_sight_range: 0,
         };
         population_state(PopulationStateInputs {
             entity: Entity::from_raw(1),
@@ -654,6 +763,7 @@ mod tests {
             travel_target: None,
             hunt_reach: 0,
             expedition_delivery: None,
+            expedition_reveal_footprint: Vec::new(),
         })
     }
 
@@ -799,6 +909,28 @@ mod tests {
         assert_eq!(restored.assignments[0], assignment, "policy round-trips");
     }
 
+    #[test]
+    fn crisis_gauges_resume_from_their_snapshot_state() {
+        let mut telemetry = CrisisTelemetry::default();
+        for (tick, r0) in [(1, 1.4), (2, 1.15), (3, 0.95)] {
+            telemetry.record_metric(tick, InternalCrisisMetricKind::R0, r0);
+        }
+        let state = crisis_telemetry_state_from_metrics(&telemetry.snapshot(3));
+
+        let mut restored = CrisisTelemetry::default();
+        restored.restore_save_state(&crisis_telemetry_save_from_state(&state));
+        assert_eq!(restored.snapshot(3), telemetry.snapshot(3));
+
+        // The next sample lands in the same bands on both, hysteresis included.
+        telemetry.record_metric(4, InternalCrisisMetricKind::R0, 1.1);
+        restored.record_metric(4, InternalCrisisMetricKind::R0, 1.1);
+        assert_eq!(restored.snapshot(4), telemetry.snapshot(4));
+        assert_eq!(
+            restored.drain_threshold_crossings(),
+            telemetry.drain_threshold_crossings()
+        );
+    }
+
     #[test]
     fn power_metrics_from_grid_tracks_totals() {
         let mut grid = PowerGridState {
@@ -875,6 +1007,8 @@ mod tests {
             river_edges: 0,
             river_inflow: 0,
             river_channel: 0,
+            river_flow: 0.0,
+            lake_depth: 0.0,
         };
         let base_overlay = TerrainOverlayState {
             width: 1,
@@ -885,6 +1019,7 @@ mod tests {
                 mountain_kind: base_tile.mountain_kind,
                 relief_scale: base_tile.mountain_relief,
             }],
+            palette_hash: 0,
         };
         let base_snapshot = snapshot_with_overlay(1, base_tile.clone(), base_overlay);
 
@@ -905,6 +1040,7 @@ mod tests {
                 mountain_kind: updated_tile.mountain_kind,
                 relief_scale: updated_tile.mountain_relief,
             }],
+            palette_hash: 0,
         };
         let updated_snapshot =
             snapshot_with_overlay(2, updated_tile.clone(), updated_overlay.clone());
@@ -990,6 +1126,119 @@ mod tests {
         assert_eq!(latest_snapshot.power_metrics, updated_metrics);
     }
 
+    #[test]
+    fn snapshot_history_sends_the_calendar_when_it_moves() {
+        let calendar_snapshot = |tick: u64, ticks_per_year: u32| WorldSnapshot {
+            calendar: crate::resources::calendar_at(tick, ticks_per_year),
+            ..snapshot_with_power_metrics(tick, PowerTelemetryState::default())
+        };
+        let mut history = SnapshotHistory::default();
+        history.update(calendar_snapshot(3, 4));
+        history.update(calendar_snapshot(4, 4));
+        let calendar = history
+            .last_delta
+            .as_ref()
+            .and_then(|delta| delta.calendar)
+            .expect("the calendar moved");
+        assert_eq!((calendar.year, calendar.day_in_year), (1, 0));
+
+        history.update(calendar_snapshot(5, 0));
+        history.update(calendar_snapshot(6, 0));
+        let delta = history.last_delta.as_ref().expect("delta captured");
+        assert_eq!(delta.calendar, None, "no calendar, nothing to resend");
+    }
+
+    #[test]
+    fn snapshot_history_sequences_every_delta_and_rewinds_on_rollback() {
+        let mut history = SnapshotHistory::default();
+        history.update(snapshot_with_power_metrics(
+            1,
+            PowerTelemetryState::default(),
+        ));
+        history.update(snapshot_with_power_metrics(
+            2,
+            PowerTelemetryState::default(),
+        ));
+        let rollback_target = history.entry(1).expect("tick 1 retained");
+
+        history.update_axis_bias(AxisBiasState {
+            knowledge: 5,
+            ..AxisBiasState::default()
+        });
+        let delta = history.last_delta.as_ref().expect("axis bias delta");
+        let snapshot = history.last_snapshot.as_ref().expect("latest snapshot");
+        assert_eq!(
+            (rollback_target.delta.header.sequence, delta.header.sequence),
+            (1, 3)
+        );
+        assert_eq!(
+            snapshot.header.sequence, 3,
+            "the refreshed snapshot folds in the latest delta"
+        );
+        let mut resequenced = (**snapshot).clone();
+        resequenced.header.sequence = 99;
+        assert_eq!(
+            sim_runtime::hash_snapshot(&resequenced),
+            snapshot.header.hash,
+            "sequence does not perturb the content hash"
+        );
+
+        history.reset_to_entry(&rollback_target);
+        history.update(snapshot_with_power_metrics(
+            2,
+            PowerTelemetryState::default(),
+        ));
+        assert_eq!(
+            history.sequence(),
+            2,
+            "the stream resumes after the rollback entry"
+        );
+    }
+
+    #[test]
+    fn snapshot_history_exports_every_nth_snapshot_and_reloads_them() {
+        let mut history = SnapshotHistory::default();
+        for tick in 1..=5 {
+            history.update(snapshot_with_power_metrics(
+                tick,
+                PowerTelemetryState::default(),
+            ));
+        }
+        let dir = std::env::temp_dir().join(format!(
+            "shadow_scale_snapshot_export_{}",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_dir_all(&dir);
+
+        assert_eq!(history.export_to_dir(&dir, 2).expect("export"), 3);
+        // Re-exporting overwrites the same files rather than duplicating them.
+        assert_eq!(history.export_to_dir(&dir, 2).expect("re-export"), 3);
+        assert!(dir.join("snapshot_3.fb").is_file());
+
+        let loaded = load_snapshot_dir(&dir).expect("reload");
+        let ticks: Vec<u64> = loaded.iter().map(|s| s.header.tick).collect();
+        assert_eq!(ticks, vec![1, 3, 5]);
+        for snapshot in &loaded {
+            let retained = history
+                .entry(snapshot.header.tick)
+                .expect("exported tick is retained");
+            assert_eq!(snapshot.header.hash, retained.snapshot.header.hash);
+            assert_eq!(snapshot.header.sequence, retained.snapshot.header.sequence);
+        }
+        assert!(history.export_to_dir(&dir, 0).is_err());
+
+        // A tick whose JSON twin is gone is decoded from its broadcast frame instead.
+        std::fs::remove_file(dir.join("snapshot_5.json")).expect("drop json twin");
+        let loaded = load_snapshot_dir(&dir).expect("reload from frame");
+        let ticks: Vec<u64> = loaded.iter().map(|s| s.header.tick).collect();
+        assert_eq!(ticks, vec![1, 3, 5]);
+        let retained = history.entry(5).expect("tick 5 is retained");
+        assert_eq!(loaded[2].header.hash, retained.snapshot.header.hash);
+        assert_eq!(loaded[2].header.sequence, retained.snapshot.header.sequence);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
     #[test]
     fn great_discovery_snapshot_delta_tracks_changes() {
         let mut history = SnapshotHistory::default();
@@ -1017,11 +1266,13 @@ mod tests {
             observation_deficit: 2,
             eta_ticks: 4,
             covert: false,
+            progress_delta: None,
         };
         let telemetry = GreatDiscoveryTelemetryState {
             total_resolved: 1,
             pending_candidates: 2,
             active_constellations: 1,
+            effect_audit: Vec::new(),
         };
 
         let updated = snapshot_with_discoveries(
@@ -1050,6 +1301,35 @@ mod tests {
         assert_eq!(latest.great_discovery_telemetry, telemetry);
     }
 
+    #[test]
+    fn population_density_raster_sums_cohorts_on_their_home_tiles() {
+        let tiles = vec![tile(1, 0, 0), tile(2, 2, 0), tile(3, 1, 1)];
+        let cohort = |entity: u64, home: u64, size: u32| PopulationCohortState {
+            entity,
+            home,
+            size,
+            ..Default::default()
+        };
+        // Two bands share tile 2; tile 1 holds one; tile 3 is empty; the last band's home is not
+        // a known tile and is dropped.
+        let populations = vec![
+            cohort(100, 1, 40),
+            cohort(101, 2, 25),
+            cohort(102, 2, 15),
+            cohort(103, 99, 500),
+        ];
+
+        let raster =
+            population_density_raster_from_populations(&tiles, &populations, UVec2::new(3, 2));
+
+        assert_eq!((raster.width, raster.height), (3, 2));
+        let people = |x: usize, y: usize| Scalar::from_raw(raster.samples[y * 3 + x]).to_f32();
+        assert_eq!(people(0, 0), 40.0);
+        assert_eq!(people(2, 0), 40.0, "cohorts sharing a tile are summed");
+        assert_eq!(people(1, 1), 0.0);
+        assert_eq!(raster.samples.iter().filter(|s| **s != 0).count(), 2);
+    }
+
     #[test]
     fn corruption_raster_allocates_intensity_and_baseline() {
         let tiles = vec![tile(1, 0, 0), tile(2, 1, 0)];
@@ -1142,6 +1422,9 @@ mod tests {
                 surplus: Scalar::zero().raw(),
                 deficit: Scalar::zero().raw(),
                 incident_count: 0,
+                kind: PowerNodeKind::Hybrid,
+                priority: 0,
+                shed: 0,
             },
             PowerNodeState {
                 entity: 2,
@@ -1155,6 +1438,9 @@ mod tests {
                 surplus: Scalar::zero().raw(),
                 deficit: Scalar::zero().raw(),
                 incident_count: 0,
+                kind: PowerNodeKind::Hybrid,
+                priority: 0,
+                shed: 0,
             },
         ];
 
@@ -1275,6 +1561,7 @@ mod tests {
             overlays: &overlays_config,
             start_location: &start_location,
             fog_reveals: &fog_reveals,
+            elevation: None,
             tick: 0,
         });
 
@@ -1305,6 +1592,7 @@ mod tests {
             overlays: &overlays_config,
             start_location: &start_location,
             fog_reveals: &fog_reveals,
+            elevation: None,
             tick: 0,
         });
 
@@ -1314,6 +1602,93 @@ mod tests {
             .all(|sample| *sample == Scalar::zero().raw()));
     }
 
+    #[test]
+    fn swapping_start_profile_mid_game_changes_fog_on_the_next_turn() {
+        use crate::start_profile::{
+            ActiveStartProfile, FogMode, StartProfile, StartProfileLookup, StartProfileSwapError,
+        };
+        use bevy::prelude::{Mut, World};
+
+        let tiles = vec![tile(1, 0, 0), tile(2, 1, 0)];
+        let populations = Vec::new();
+        let discovery = DiscoveryProgressLedger::default();
+        let overlays_config = SnapshotOverlaysConfig::default();
+        let fog_reveals = FogRevealLedger::default();
+        let fog_for = |world: &World| {
+            fog_raster_from_discoveries(FogRasterInputs {
+                tiles: &tiles,
+                populations: &populations,
+                discovery: &discovery,
+                grid_size: UVec2::new(2, 1),
+                overlays: &overlays_config,
+                start_location: world.resource::<StartLocation>(),
+                fog_reveals: &fog_reveals,
+                elevation: None,
+                tick: 1,
+            })
+        };
+
+        let standard = StartProfile::placeholder("standard");
+        let mut world = World::new();
+        world.insert_resource(SimulationConfig::builtin());
+        world.insert_resource(StartLocation::from_profile(
+            Some(UVec2::new(0, 0)),
+            &standard.overrides,
+        ));
+        world.insert_resource(StartProfileLookup::new("standard"));
+        world.insert_resource(ActiveStartProfile::new(standard));
+
+        // Standard fog: only the start tile is surveyed, the neighbour stays fogged.
+        assert_eq!(fog_for(&world).samples[1], Scalar::one().raw());
+
+        let mut revealed = StartProfile::placeholder("revealed");
+        revealed.overrides.fog_mode = Some(FogMode::Revealed);
+        world
+            .resource_scope(|world, mut active: Mut<ActiveStartProfile>| {
+                active.swap(revealed, world)
+            })
+            .expect("swap onto a built world");
+
+        assert!(matches!(
+            world.resource::<StartLocation>().fog_mode(),
+            FogMode::Revealed
+        ));
+        assert_eq!(
+            world.resource::<StartLocation>().position(),
+            Some(UVec2::new(0, 0))
+        );
+        assert_eq!(world.resource::<StartProfileLookup>().id, "revealed");
+        assert_eq!(
+            world.resource::<SimulationConfig>().start_profile_id,
+            "revealed"
+        );
+        assert!(fog_for(&world)
+            .samples
+            .iter()
+            .all(|sample| *sample == Scalar::zero().raw()));
+
+        // A profile preferring a food module the map never placed is refused outright.
+        let mut coastal = StartProfile::placeholder("coastal");
+        coastal.overrides.food_modules.primary = Some(crate::food::FoodModule::CoastalLittoral);
+        let refused = world.resource_scope(|world, mut active: Mut<ActiveStartProfile>| {
+            active.swap(coastal, world)
+        });
+        assert_eq!(
+            refused,
+            Err(StartProfileSwapError::FoodModuleUnavailable {
+                profile: "coastal".to_string()
+            })
+        );
+        assert_eq!(
+            world.resource::<ActiveStartProfile>().profile().id,
+            "revealed"
+        );
+        assert!(matches!(
+            world.resource::<StartLocation>().fog_mode(),
+            FogMode::Revealed
+        ));
+    }
+
     #[test]
     fn fog_raster_shroud_only_reveals_radius() {
         let tiles = vec![tile(1, 0, 0), tile(2, 1, 0)];
@@ -1336,6 +1711,7 @@ mod tests {
             overlays: &overlays_config,
             start_location: &start_location,
             fog_reveals: &fog_reveals,
+            elevation: None,
             tick: 0,
         });
 
@@ -1343,6 +1719,44 @@ mod tests {
         assert_eq!(fog.samples[1], Scalar::one().raw());
     }
 
+    #[test]
+    fn fog_raster_line_of_sight_mode_keeps_tiles_behind_a_ridge_fogged() {
+        // One row: valley, valley, valley, ridge, valley, valley.
+        let elevation = ElevationField::new(6, 1, vec![0.1, 0.1, 0.1, 0.8, 0.1, 0.1]);
+        let fog_for = |fog_mode: FogMode| {
+            let overrides = StartProfileOverrides {
+                fog_mode: Some(fog_mode),
+                survey_radius: Some(5),
+                ..Default::default()
+            };
+            let start_location = StartLocation::from_profile(Some(UVec2::new(0, 0)), &overrides);
+            fog_raster_from_discoveries(FogRasterInputs {
+                tiles: &[],
+                populations: &[],
+                discovery: &DiscoveryProgressLedger::default(),
+                grid_size: UVec2::new(6, 1),
+                overlays: &SnapshotOverlaysConfig::default(),
+                start_location: &start_location,
+                fog_reveals: &FogRevealLedger::default(),
+                elevation: Some(&elevation),
+                tick: 0,
+            })
+            .samples
+        };
+
+        let clear = Scalar::zero().raw();
+        let fogged = Scalar::one().raw();
+        assert_eq!(
+            fog_for(FogMode::LineOfSight),
+            vec![clear, clear, clear, clear, fogged, fogged]
+        );
+        assert_eq!(
+            fog_for(FogMode::Standard),
+            vec![clear; 6],
+            "the default radius reveal ignores terrain"
+        );
+    }
+
     fn demographics_cohort(
         faction: u32,
         size: u32,
@@ -1413,6 +1827,58 @@ mod tests {
         assert_eq!(d.elders, 0);
     }
 
+    fn morale_cohort(faction: u32, size: u32, morale: f32) -> PopulationCohortState {
+        PopulationCohortState {
+            faction,
+            size,
+            morale: Scalar::from_f32(morale).raw(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn snapshot_morale_summary_spreads_each_faction() {
+        let threshold = Scalar::from_f32(0.25);
+        let cohorts = vec![
+            morale_cohort(4, 30, 0.8),
+            morale_cohort(4, 10, 0.2),
+            // Faction 1 sorts first and sits entirely above the threshold.
+            morale_cohort(1, 20, 0.5),
+            morale_cohort(1, 20, 0.7),
+        ];
+        let summary = snapshot_morale_summary(&cohorts, threshold);
+        assert_eq!(
+            summary.iter().map(|s| s.faction).collect::<Vec<_>>(),
+            vec![1, 4],
+            "one entry per faction with cohorts, in faction order"
+        );
+
+        let f1 = &summary[0];
+        assert_eq!(f1.cohorts, 2);
+        assert_eq!(f1.min, Scalar::from_f32(0.5).raw());
+        assert_eq!(f1.max, Scalar::from_f32(0.7).raw());
+        assert_eq!(f1.mean, Scalar::from_f32(0.6).raw());
+        assert_eq!(f1.below_threshold, 0.0);
+
+        // Head-count weighted: (30 × 0.8 + 10 × 0.2) / 40 = 0.65; 10 of 40 sit below 0.25.
+        let f4 = &summary[1];
+        assert_eq!(f4.min, Scalar::from_f32(0.2).raw());
+        assert_eq!(f4.max, Scalar::from_f32(0.8).raw());
+        assert_eq!(f4.mean, Scalar::from_f32(0.65).raw());
+        assert_eq!(f4.threshold, threshold.raw());
+        assert!((f4.below_threshold - 0.25).abs() < 1e-6);
+    }
+
+    #[test]
+    fn snapshot_morale_summary_counts_empty_bands_equally() {
+        let cohorts = vec![morale_cohort(2, 0, 0.1), morale_cohort(2, 0, 0.5)];
+        let summary = snapshot_morale_summary(&cohorts, Scalar::from_f32(0.25));
+        assert_eq!(summary.len(), 1);
+        assert_eq!(summary[0].mean, Scalar::from_f32(0.3).raw());
+        assert!((summary[0].below_threshold - 0.5).abs() < 1e-6);
+        assert!(snapshot_morale_summary(&[], Scalar::from_f32(0.25)).is_empty());
+    }
+
     #[test]
     fn snapshot_forage_patches_reports_cultivation_and_owner() {
         let mut registry = ForageRegistry::default();
@@ -1799,4 +2265,121 @@ mod tests {
             mobile.pen_upkeep
         );
     }
+
+    #[test]
+    fn faction_inventory_serializes_in_a_stable_order() {
+        let items = [("timber", 4), ("grain", 9), ("ore", 2), ("hides", 7)];
+        let build = |factions: &[u32], reversed: bool| {
+            let mut inventory = FactionInventory::default();
+            for &faction in factions {
+                let order: Vec<_> = if reversed {
+                    items.iter().rev().collect()
+                } else {
+                    items.iter().collect()
+                };
+                for (item, quantity) in order {
+                    inventory.add_stockpile(FactionId(faction), *item, *quantity);
+                }
+            }
+            inventory
+        };
+        let first = build(&[3, 0, 7], false);
+        let second = build(&[7, 3, 0], true);
+
+        let first_state = snapshot_faction_inventory(&first);
+        let second_state = snapshot_faction_inventory(&second);
+        assert_eq!(
+            serde_json::to_string(&first_state).unwrap(),
+            serde_json::to_string(&second_state).unwrap()
+        );
+        let factions: Vec<u32> = first_state.iter().map(|state| state.faction).collect();
+        assert_eq!(factions, vec![0, 3, 7]);
+        let names: Vec<&str> = first_state[0]
+            .inventory
+            .iter()
+            .map(|entry| entry.item.as_str())
+            .collect();
+        assert_eq!(names, vec!["grain", "hides", "ore", "timber"]);
+
+        let home = Some(UVec2::new(2, 2));
+        let accessible = |inventory: &FactionInventory| {
+            accessible_stockpile_state(inventory, FactionId(3), home, home, 0)
+                .expect("home stockpile is in reach")
+                .entries
+        };
+        assert_eq!(accessible(&first), accessible(&second));
+        assert_eq!(accessible(&first)[0].item, "grain");
+    }
+
+    /// Two generations of opposing bias, split 3:1 by population: each shows up as a `Generation`
+    /// driver carrying its bias × share with its own sign, weighted by its share, and together
+    /// they are exactly the generation lean, which stays out of the axis total.
+    #[test]
+    fn opposing_generation_biases_appear_as_share_weighted_drivers() {
+        let generation = |id: u16, name: &str, knowledge: i64, trust: i64| GenerationState {
+            id,
+            name: name.to_string(),
+            bias_knowledge: knowledge,
+            bias_trust: trust,
+            bias_equity: 0,
+            bias_agency: 0,
+        };
+        let registry = GenerationRegistry::from_states(&[
+            generation(0, "Elders", 120_000, -80_000),
+            generation(1, "Youth", -120_000, 80_000),
+        ]);
+
+        let mut world = World::new();
+        world.insert_resource(InfluentialRoster::with_seed(
+            7,
+            &registry,
+            crate::influencers::InfluencerConfigHandle::load_builtin().get(),
+        ));
+        world.insert_resource(registry.clone());
+        world.insert_resource(crate::influencers::InfluencerImpacts::default());
+        world.insert_resource(SentimentAxisBias::default());
+        for (generation, size) in [(0, 30), (1, 10)] {
+            let (mut cohort, _) = food_test_cohort(
+                Scalar::zero(),
+                Scalar::one(),
+                Scalar::zero(),
+                LaborAllocation::default(),
+            );
+            cohort.generation = generation;
+            cohort.size = size;
+            world.spawn(cohort);
+        }
+        world.run_system_once(crate::influencers::tick_influencers);
+
+        let bias = world.resource::<SentimentAxisBias>();
+        let [knowledge, trust, equity, agency] = generation_sentiment_drivers(bias, &registry);
+        let summary = |drivers: &[SentimentDriverState]| -> Vec<(String, i64, i64)> {
+            drivers
+                .iter()
+                .map(|driver| {
+                    assert_eq!(driver.category, SentimentDriverCategory::Generation);
+                    (driver.label.clone(), driver.value, driver.weight)
+                })
+                .collect()
+        };
+        assert_eq!(
+            summary(&knowledge),
+            vec![
+                ("Generation Elders · Knowledge".to_string(), 90_000, 750_000),
+                ("Generation Youth · Knowledge".to_string(), -30_000, 250_000),
+            ]
+        );
+        assert_eq!(
+            summary(&trust),
+            vec![
+                ("Generation Elders · Trust".to_string(), -60_000, 750_000),
+                ("Generation Youth · Trust".to_string(), 20_000, 250_000),
+            ]
+        );
+        assert!(equity.is_empty() && agency.is_empty());
+
+        let lean = bias.generation_values().map(Scalar::raw);
+        assert_eq!(lean, [60_000, -40_000, 0, 0]);
+        assert_eq!(bias.combined(), bias.influencer_values());
+    }
 }
@@ -207,6 +207,9 @@ pub(crate) struct ExpeditionLevers {
     /// can add a raid's round-trip travel (`ceil(2 × hex_distance / this)`) to the band-agnostic
     /// pre-launch `huntTripEstimates`. Same global-config-surfaced-per-band idiom as the others.
     pub(crate) band_move_tiles_per_turn: u32,
+    /// `expedition_config.observe_sight_range` — a scouting party's sight radius, echoed onto scout
+    /// parties beside their projected reveal footprint.
+    pub(crate) observe_sight_range: u32,
 }
 
 pub(crate) struct PopulationStateInputs<'a> {
@@ -230,6 +233,8 @@ pub(crate) struct PopulationStateInputs<'a> {
     pub(crate) travel_target: Option<UVec2>,
     pub(crate) hunt_reach: u32,
     pub(crate) expedition_delivery: Option<crate::systems::ExpeditionDelivery>,
+    /// A scouting party's projected reveal footprint (empty for hunt parties and normal bands).
+    pub(crate) expedition_reveal_footprint: Vec<UVec2>,
 }
 
 pub(crate) fn population_state(inputs: PopulationStateInputs<'_>) -> PopulationCohortState {
@@ -254,6 +259,7 @@ pub(crate) fn population_state(inputs: PopulationStateInputs<'_>) -> PopulationC
         travel_target,
         hunt_reach,
         expedition_delivery,
+        expedition_reveal_footprint,
     } = inputs;
     let migration = cohort.migration.as_ref().map(pending_migration_to_state);
     let (travel_target_x, travel_target_y) = travel_target.map(|t| (t.x, t.y)).unwrap_or((0, 0));
@@ -379,6 +385,14 @@ pub(crate) fn population_state(inputs: PopulationStateInputs<'_>) -> PopulationC
         }
         _ => 0.0,
     };
+    // Scout reveal preview: the sight radius echoes the global lever (scouts only), the footprint
+    // is the capture-projected tile set, zipped like `pending_reveal_*`.
+    let expedition_reveal_radius = match expedition {
+        Some(exp) if matches!(exp.mission, ExpeditionMission::Scout) => {
+            expedition_levers.observe_sight_range
+        }
+        _ => 0,
+    };
     PopulationCohortState {
         entity: entity.to_bits(),
         home: cohort.home.to_bits(),
@@ -481,6 +495,10 @@ pub(crate) fn population_state(inputs: PopulationStateInputs<'_>) -> PopulationC
         // provisions ride, surfaced as a scalar so the client can show it beside the food reserve. It
         // also rides the full `stores` list above, but a named scalar spares the client a key lookup.
         fodder_store: cohort.stores.get(FODDER).to_f32(),
+        expedition_reveal_radius,
+        expedition_reveal_footprint_x: expedition_reveal_footprint.iter().map(|p| p.x).collect(),
+        expedition_reveal_footprint_y: expedition_reveal_footprint.iter().map(|p| p.y).collect(),
+        morale_diet: cohort.last_morale_contributions.diet.raw(),
     }
 }
 
@@ -511,6 +529,8 @@ pub(crate) fn accessible_stockpile_state(
     if entries.is_empty() {
         return None;
     }
+    // The stockpile is a hash map; sort so the snapshot (and its hash) is stable across runs.
+    entries.sort_by(|a, b| a.item.cmp(&b.item));
     Some(AccessibleStockpileState { radius, entries })
 }
 
@@ -576,6 +596,102 @@ pub(crate) fn snapshot_demographics(
         .collect()
 }
 
+/// Per-faction morale spread for the HUD. Min/max are taken over the faction's cohorts; the mean
+/// and the share below `threshold` are weighted by each cohort's `size`, so a handful of stragglers
+/// does not read as a collapse. A faction whose cohorts are all empty weights each cohort equally.
+/// Factions with no cohorts never appear (there is no spread to report).
+pub(crate) fn snapshot_morale_summary(
+    cohorts: &[PopulationCohortState],
+    threshold: Scalar,
+) -> Vec<MoraleSummaryState> {
+    let mut by_faction: std::collections::BTreeMap<u32, Vec<&PopulationCohortState>> =
+        std::collections::BTreeMap::new();
+    for cohort in cohorts {
+        by_faction.entry(cohort.faction).or_default().push(cohort);
+    }
+    let threshold = threshold.raw();
+    by_faction
+        .into_iter()
+        .map(|(faction, members)| {
+            let weighted = members.iter().any(|cohort| cohort.size > 0);
+            let weight = |cohort: &PopulationCohortState| {
+                if weighted {
+                    i128::from(cohort.size)
+                } else {
+                    1
+                }
+            };
+            let total: i128 = members.iter().map(|cohort| weight(cohort)).sum();
+            let morale_mass: i128 = members
+                .iter()
+                .map(|cohort| weight(cohort) * i128::from(cohort.morale))
+                .sum();
+            let below: i128 = members
+                .iter()
+                .filter(|cohort| cohort.morale < threshold)
+                .map(|cohort| weight(cohort))
+                .sum();
+            MoraleSummaryState {
+                faction,
+                cohorts: members.len() as u32,
+                min: members
+                    .iter()
+                    .map(|cohort| cohort.morale)
+                    .min()
+                    .unwrap_or(0),
+                mean: (morale_mass / total) as i64,
+                max: members
+                    .iter()
+                    .map(|cohort| cohort.morale)
+                    .max()
+                    .unwrap_or(0),
+                threshold,
+                below_threshold: (below as f64 / total as f64) as f32,
+            }
+        })
+        .collect()
+}
+
+/// Head-count per tile: every cohort's `size` summed onto its **home** tile (a band in transit
+/// still counts where it lives). Samples are fixed-point people, so `1.0` on the client is one
+/// person — the overlay normalizes against the densest tile itself.
+pub(crate) fn population_density_raster_from_populations(
+    tiles: &[TileState],
+    populations: &[PopulationCohortState],
+    grid_size: UVec2,
+) -> ScalarRasterState {
+    let mut tile_positions = HashMap::with_capacity(tiles.len());
+    let mut max_x = 0u32;
+    let mut max_y = 0u32;
+    for tile in tiles {
+        tile_positions.insert(tile.entity, (tile.x, tile.y));
+        max_x = max_x.max(tile.x);
+        max_y = max_y.max(tile.y);
+    }
+
+    let width = grid_size.x.max(max_x.saturating_add(1)).max(1);
+    let height = grid_size.y.max(max_y.saturating_add(1)).max(1);
+    let total = (width as usize).saturating_mul(height as usize).max(1);
+    let mut samples = vec![0i64; total];
+
+    for cohort in populations {
+        let Some(&(x, y)) = tile_positions.get(&cohort.home) else {
+            continue;
+        };
+        let idx = (y as usize) * (width as usize) + x as usize;
+        if idx >= samples.len() {
+            continue;
+        }
+        samples[idx] = samples[idx].saturating_add(Scalar::from_u32(cohort.size).raw());
+    }
+
+    ScalarRasterState {
+        width,
+        height,
+        samples,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -594,6 +710,7 @@ mod tests {
             hunt_per_worker_provisions: 0.0,
             hunt_viability_warn_turns: cfg.hunt.viability_warn_turns,
             band_move_tiles_per_turn: 1,
+            observe_sight_range: cfg.observe_sight_range,
         }
     }
 
@@ -654,6 +771,7 @@ mod tests {
             travel_target: None,
             hunt_reach: 0,
             expedition_delivery: None,
+            expedition_reveal_footprint: Vec::new(),
         })
         .turns_of_food
     }
@@ -8,6 +8,8 @@ pub(crate) struct FogRasterInputs<'a> {
     pub(crate) overlays: &'a SnapshotOverlaysConfig,
     pub(crate) start_location: &'a StartLocation,
     pub(crate) fog_reveals: &'a FogRevealLedger,
+    /// Terrain for `FogMode::LineOfSight` reveals; `None` falls back to the flat radius.
+    pub(crate) elevation: Option<&'a ElevationField>,
     pub(crate) tick: u64,
 }
 
@@ -20,6 +22,7 @@ pub(crate) fn fog_raster_from_discoveries(inputs: FogRasterInputs<'_>) -> Scalar
         overlays,
         start_location,
         fog_reveals,
+        elevation,
         tick,
     } = inputs;
     let mut max_x = 0u32;
@@ -175,8 +178,10 @@ pub(crate) fn fog_raster_from_discoveries(inputs: FogRasterInputs<'_>) -> Scalar
         }
     }
 
-    apply_start_location_reveal(&mut samples, width, height, start_location);
-    apply_scout_reveals(&mut samples, width, height, fog_reveals, tick);
+    // Line-of-sight mode swaps the reveal shape only; coverage above is the Standard path.
+    let occluder = elevation.filter(|_| matches!(start_location.fog_mode(), FogMode::LineOfSight));
+    apply_start_location_reveal(&mut samples, width, height, start_location, occluder);
+    apply_scout_reveals(&mut samples, width, height, fog_reveals, tick, occluder);
 
     ScalarRasterState {
         width,
@@ -190,12 +195,13 @@ pub(crate) fn apply_start_location_reveal(
     width: u32,
     height: u32,
     start_location: &StartLocation,
+    occluder: Option<&ElevationField>,
 ) {
     let Some(center) = start_location.position() else {
         return;
     };
     let radius = start_location.survey_radius().unwrap_or(0);
-    clear_circle(samples, width, height, center, radius);
+    clear_reveal(samples, width, height, center, radius, occluder);
 }
 
 pub(crate) fn apply_scout_reveals(
@@ -204,12 +210,45 @@ pub(crate) fn apply_scout_reveals(
     height: u32,
     fog_reveals: &FogRevealLedger,
     tick: u64,
+    occluder: Option<&ElevationField>,
 ) {
     if fog_reveals.is_empty() {
         return;
     }
     for reveal in fog_reveals.iter_active(tick) {
-        clear_circle(samples, width, height, reveal.center, reveal.radius);
+        clear_reveal(
+            samples,
+            width,
+            height,
+            reveal.center,
+            reveal.radius,
+            occluder,
+        );
+    }
+}
+
+/// Clear one reveal: the flat disc, or — given an `occluder` — only the tiles
+/// [`FogRevealLedger::reveal_los`] finds in sight.
+fn clear_reveal(
+    samples: &mut [i64],
+    width: u32,
+    height: u32,
+    center: UVec2,
+    radius: u32,
+    occluder: Option<&ElevationField>,
+) {
+    let Some(heightfield) = occluder else {
+        clear_circle(samples, width, height, center, radius);
+        return;
+    };
+    for tile in FogRevealLedger::reveal_los(center, radius, heightfield) {
+        if tile.x >= width || tile.y >= height {
+            continue;
+        }
+        let idx = tile.y as usize * width as usize + tile.x as usize;
+        if idx < samples.len() {
+            samples[idx] = 0;
+        }
     }
 }
 
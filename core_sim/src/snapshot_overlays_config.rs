@@ -7,6 +7,7 @@ use std::{
 
 use bevy::prelude::Resource;
 use serde::Deserialize;
+use sim_runtime::{OverlayChannelFlags, RasterPrecision};
 use thiserror::Error;
 
 use crate::{
@@ -25,6 +26,8 @@ pub struct SnapshotOverlaysConfig {
     military: MilitaryOverlayConfig,
     fog: FogOverlayConfig,
     food: FoodOverlayConfig,
+    channels: OverlayChannelsConfig,
+    precision: OverlayPrecisionConfig,
 }
 
 impl SnapshotOverlaysConfig {
@@ -68,6 +71,18 @@ impl SnapshotOverlaysConfig {
     pub fn food(&self) -> &FoodOverlayConfig {
         &self.food
     }
+
+    pub fn channels(&self) -> &OverlayChannelsConfig {
+        &self.channels
+    }
+
+    pub fn channel_enabled(&self, channel: OverlayChannel) -> bool {
+        self.channels.enabled(channel)
+    }
+
+    pub fn precision(&self) -> &OverlayPrecisionConfig {
+        &self.precision
+    }
 }
 
 #[derive(Debug, Error)]
@@ -333,6 +348,115 @@ impl Default for FoodOverlayConfig {
     }
 }
 
+/// A raster overlay `capture_snapshot` can produce.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OverlayChannel {
+    Logistics,
+    Sentiment,
+    PopulationDensity,
+    Corruption,
+    Fog,
+    Culture,
+    Military,
+    Visibility,
+}
+
+impl OverlayChannel {
+    pub const ALL: [OverlayChannel; 8] = [
+        OverlayChannel::Logistics,
+        OverlayChannel::Sentiment,
+        OverlayChannel::PopulationDensity,
+        OverlayChannel::Corruption,
+        OverlayChannel::Fog,
+        OverlayChannel::Culture,
+        OverlayChannel::Military,
+        OverlayChannel::Visibility,
+    ];
+
+    /// This channel's bit in the snapshot header's `computed_channels`.
+    pub fn flag(self) -> OverlayChannelFlags {
+        match self {
+            OverlayChannel::Logistics => OverlayChannelFlags::LOGISTICS,
+            OverlayChannel::Sentiment => OverlayChannelFlags::SENTIMENT,
+            OverlayChannel::PopulationDensity => OverlayChannelFlags::POPULATION_DENSITY,
+            OverlayChannel::Corruption => OverlayChannelFlags::CORRUPTION,
+            OverlayChannel::Fog => OverlayChannelFlags::FOG,
+            OverlayChannel::Culture => OverlayChannelFlags::CULTURE,
+            OverlayChannel::Military => OverlayChannelFlags::MILITARY,
+            OverlayChannel::Visibility => OverlayChannelFlags::VISIBILITY,
+        }
+    }
+}
+
+/// Per-channel switches. A disabled channel is not computed and ships as an empty (default)
+/// raster, which clients already treat as "no data"; every channel defaults to enabled.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct OverlayChannelsConfig {
+    logistics: bool,
+    sentiment: bool,
+    population_density: bool,
+    corruption: bool,
+    fog: bool,
+    culture: bool,
+    military: bool,
+    visibility: bool,
+}
+
+impl OverlayChannelsConfig {
+    pub fn enabled(&self, channel: OverlayChannel) -> bool {
+        match channel {
+            OverlayChannel::Logistics => self.logistics,
+            OverlayChannel::Sentiment => self.sentiment,
+            OverlayChannel::PopulationDensity => self.population_density,
+            OverlayChannel::Corruption => self.corruption,
+            OverlayChannel::Fog => self.fog,
+            OverlayChannel::Culture => self.culture,
+            OverlayChannel::Military => self.military,
+            OverlayChannel::Visibility => self.visibility,
+        }
+    }
+
+    /// Bits of every enabled channel — the ones `capture_snapshot` computes.
+    pub fn enabled_flags(&self) -> OverlayChannelFlags {
+        OverlayChannel::ALL
+            .into_iter()
+            .filter(|channel| self.enabled(*channel))
+            .fold(OverlayChannelFlags::empty(), |flags, channel| {
+                flags | channel.flag()
+            })
+    }
+}
+
+impl Default for OverlayChannelsConfig {
+    fn default() -> Self {
+        Self {
+            logistics: true,
+            sentiment: true,
+            population_density: true,
+            corruption: true,
+            fog: true,
+            culture: true,
+            military: true,
+            visibility: true,
+        }
+    }
+}
+
+/// Wire precision for the float rasters. Only affects the FlatBuffers encoding — the snapshot
+/// state (and rollback) keep full `f32` samples. Defaults to unquantized `float32`.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct OverlayPrecisionConfig {
+    moisture: RasterPrecision,
+}
+
+impl OverlayPrecisionConfig {
+    pub fn moisture(&self) -> RasterPrecision {
+        self.moisture
+    }
+}
+
 #[derive(Resource, Debug, Clone)]
 pub struct SnapshotOverlaysConfigHandle(pub Arc<SnapshotOverlaysConfig>);
 
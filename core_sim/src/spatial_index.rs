@@ -0,0 +1,193 @@
+//! Grid-bucketed spatial index over the tile grid for radius queries.
+//!
+//! [`TileRegistry`] answers "which entity is at (x, y)", but a radius query against it still has
+//! to walk every tile or rebuild a coordinate box per call. [`TileSpatialIndex`] groups tiles into
+//! `BUCKET_SIZE`-square buckets once (rebuilt only when the registry changes), so
+//! [`TileSpatialIndex::query_radius`] touches just the buckets overlapping the query's bounding
+//! box — roughly `O(r²)` regardless of map size.
+//!
+//! The radius is the map's own metric: odd-r hex distance, wrap-aware horizontally
+//! ([`hex_distance_wrapped`]), so a query returns exactly the tiles
+//! [`crate::grid_utils::hex_range_tiles`] would.
+
+use bevy::prelude::{Res, ResMut, Resource, UVec2};
+
+use crate::{
+    grid_utils::{hex_distance_wrapped, wrap_x},
+    resources::{SimulationConfig, TileRegistry},
+};
+
+/// Edge length, in tiles, of one bucket. Small enough that a typical sight/search radius touches
+/// a handful of buckets, large enough that bucket bookkeeping stays negligible.
+const BUCKET_SIZE: u32 = 8;
+
+#[derive(Resource, Debug, Clone, Default)]
+pub struct TileSpatialIndex {
+    width: u32,
+    height: u32,
+    wrap_horizontal: bool,
+    buckets_wide: u32,
+    buckets_high: u32,
+    /// Row-major buckets; each holds `(position, tile entity bits)` for the tiles inside it.
+    buckets: Vec<Vec<(UVec2, u64)>>,
+}
+
+impl TileSpatialIndex {
+    pub fn build(registry: &TileRegistry, wrap_horizontal: bool) -> Self {
+        let width = registry.width;
+        let height = registry.height;
+        let buckets_wide = width.div_ceil(BUCKET_SIZE);
+        let buckets_high = height.div_ceil(BUCKET_SIZE);
+        let mut buckets =
+            vec![Vec::new(); (buckets_wide as usize).saturating_mul(buckets_high as usize)];
+        if width > 0 {
+            for (idx, entity) in registry.tiles.iter().enumerate() {
+                let x = idx as u32 % width;
+                let y = idx as u32 / width;
+                if y >= height {
+                    break;
+                }
+                let bucket = (y / BUCKET_SIZE) * buckets_wide + x / BUCKET_SIZE;
+                buckets[bucket as usize].push((UVec2::new(x, y), entity.to_bits()));
+            }
+        }
+        Self {
+            width,
+            height,
+            wrap_horizontal,
+            buckets_wide,
+            buckets_high,
+            buckets,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.buckets.is_empty()
+    }
+
+    /// Tile entity bits within hex distance `radius` of `center`, in bucket order. `radius == 0`
+    /// yields just `center`; an off-grid `center` yields nothing.
+    pub fn query_radius(&self, center: UVec2, radius: u32) -> impl Iterator<Item = u64> + '_ {
+        let buckets = if center.x < self.width && center.y < self.height {
+            self.overlapping_buckets(center, radius)
+        } else {
+            Vec::new()
+        };
+        buckets.into_iter().flat_map(move |bucket| {
+            self.buckets[bucket]
+                .iter()
+                .filter(move |(pos, _)| {
+                    hex_distance_wrapped(center, *pos, self.width, self.wrap_horizontal) <= radius
+                })
+                .map(|(_, id)| *id)
+        })
+    }
+
+    /// Bucket indices covering the `[-radius, radius]²` box around `center` — a superset of the
+    /// hex disc, since one hex step moves at most one column and one row. Only called for an
+    /// on-grid `center`, so the grid is non-empty.
+    fn overlapping_buckets(&self, center: UVec2, radius: u32) -> Vec<usize> {
+        let radius = radius.min(self.width.max(self.height));
+        let r = radius as i32;
+        let mut columns: Vec<u32> = if self.wrap_horizontal && 2 * radius + 1 >= self.width {
+            (0..self.buckets_wide).collect()
+        } else {
+            let mut columns = Vec::new();
+            for raw_x in (center.x as i32 - r)..=(center.x as i32 + r) {
+                let x = if self.wrap_horizontal {
+                    wrap_x(raw_x, self.width, true)
+                } else if raw_x < 0 || raw_x >= self.width as i32 {
+                    continue;
+                } else {
+                    raw_x as u32
+                };
+                columns.push(x / BUCKET_SIZE);
+            }
+            columns
+        };
+        columns.sort_unstable();
+        columns.dedup();
+
+        let max_y = center.y.saturating_add(radius).min(self.height - 1);
+        let min_row = center.y.saturating_sub(radius) / BUCKET_SIZE;
+        let max_row = (max_y / BUCKET_SIZE).min(self.buckets_high - 1);
+        let mut buckets = Vec::with_capacity(columns.len() * (max_row - min_row + 1) as usize);
+        for row in min_row..=max_row {
+            for &column in &columns {
+                buckets.push((row * self.buckets_wide + column) as usize);
+            }
+        }
+        buckets
+    }
+}
+
+/// Rebuilds [`TileSpatialIndex`] whenever the tile registry is (re)inserted or mutated — once per
+/// world build in practice. Runs at the head of the turn so every stage queries a current index.
+pub fn refresh_tile_spatial_index(
+    registry: Option<Res<TileRegistry>>,
+    config: Res<SimulationConfig>,
+    mut index: ResMut<TileSpatialIndex>,
+) {
+    let Some(registry) = registry else {
+        return;
+    };
+    if registry.is_changed() || config.is_changed() || index.is_empty() {
+        *index = TileSpatialIndex::build(&registry, config.map_topology.wrap_horizontal);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::prelude::Entity;
+
+    fn registry(width: u32, height: u32) -> TileRegistry {
+        TileRegistry {
+            tiles: (0..width * height).map(Entity::from_raw).collect(),
+            width,
+            height,
+        }
+    }
+
+    fn brute_force(registry: &TileRegistry, center: UVec2, radius: u32, wrap: bool) -> Vec<u64> {
+        let mut ids: Vec<u64> = (0..registry.height)
+            .flat_map(|y| (0..registry.width).map(move |x| UVec2::new(x, y)))
+            .filter(|pos| hex_distance_wrapped(center, *pos, registry.width, wrap) <= radius)
+            .map(|pos| registry.index(pos.x, pos.y).unwrap().to_bits())
+            .collect();
+        ids.sort_unstable();
+        ids
+    }
+
+    #[test]
+    fn query_radius_matches_brute_force_scan() {
+        // 37x21 is deliberately not a bucket multiple, so the ragged edge buckets are exercised.
+        let registry = registry(37, 21);
+        for wrap in [false, true] {
+            let index = TileSpatialIndex::build(&registry, wrap);
+            for center in [
+                UVec2::new(0, 0),
+                UVec2::new(36, 20),
+                UVec2::new(18, 10),
+                UVec2::new(1, 19),
+                UVec2::new(35, 3),
+            ] {
+                for radius in [0, 1, 3, 7, 12, 40] {
+                    let mut got: Vec<u64> = index.query_radius(center, radius).collect();
+                    got.sort_unstable();
+                    assert_eq!(
+                        got,
+                        brute_force(&registry, center, radius, wrap),
+                        "center {center:?} radius {radius} wrap {wrap}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn query_radius_off_grid_center_is_empty() {
+        let index = TileSpatialIndex::build(&registry(8, 8), false);
+        assert_eq!(index.query_radius(UVec2::new(8, 0), 3).count(), 0);
+    }
+}
@@ -0,0 +1,357 @@
+//! Data-driven terrain-tag placement rules for startup resource spawns.
+//!
+//! Loaded from `data/spawn_rules_config.json`. Two tables: `food_sites` keyed by `FoodSiteKind`
+//! (snake_case, e.g. `arctic_fishing`) and `species` keyed by the `fauna_config.json` species key.
+//! Each rule names the `TerrainTags` a spawn tile must carry (`required`, any one of them),
+//! the tags it must not carry (`forbidden`, none of them), and a relative `weight`. A kind or
+//! species without a rule spawns wherever it did before, at weight `1.0`. Mirrors the
+//! `fauna_config.rs` loader (baked-in builtin + optional file/env override, validated on every
+//! load path).
+
+use std::{
+    collections::BTreeMap,
+    env, fs, io,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use bevy::prelude::Resource;
+use serde::Deserialize;
+use sim_runtime::TerrainTags;
+use thiserror::Error;
+
+use crate::food::FoodSiteKind;
+
+pub const BUILTIN_SPAWN_RULES_CONFIG: &str = include_str!("data/spawn_rules_config.json");
+
+/// Weight a kind or species spawns at when no rule names it.
+const DEFAULT_SPAWN_WEIGHT: f32 = 1.0;
+
+/// Resolve an upper-case tag name (`"WATER"`, `"COASTAL"`, ...) to its `TerrainTags` bit.
+pub fn terrain_tag_from_name(name: &str) -> Option<TerrainTags> {
+    let tag = match name {
+        "WATER" => TerrainTags::WATER,
+        "FRESHWATER" => TerrainTags::FRESHWATER,
+        "COASTAL" => TerrainTags::COASTAL,
+        "WETLAND" => TerrainTags::WETLAND,
+        "FERTILE" => TerrainTags::FERTILE,
+        "ARID" => TerrainTags::ARID,
+        "POLAR" => TerrainTags::POLAR,
+        "HIGHLAND" => TerrainTags::HIGHLAND,
+        "VOLCANIC" => TerrainTags::VOLCANIC,
+        "HAZARDOUS" => TerrainTags::HAZARDOUS,
+        "SUBSURFACE" => TerrainTags::SUBSURFACE,
+        "HYDROTHERMAL" => TerrainTags::HYDROTHERMAL,
+        _ => return None,
+    };
+    Some(tag)
+}
+
+/// One placement rule. The tag lists are resolved to masks at load, so `allows` is two bit tests.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct SpawnRule {
+    /// The tile must carry at least one of these tags (empty = no requirement).
+    pub required: Vec<String>,
+    /// The tile must carry none of these tags.
+    pub forbidden: Vec<String>,
+    /// Relative spawn weight; `0` disables the kind or species outright.
+    pub weight: f32,
+    #[serde(skip)]
+    required_tags: TerrainTags,
+    #[serde(skip)]
+    forbidden_tags: TerrainTags,
+}
+
+impl Default for SpawnRule {
+    fn default() -> Self {
+        Self {
+            required: Vec::new(),
+            forbidden: Vec::new(),
+            weight: DEFAULT_SPAWN_WEIGHT,
+            required_tags: TerrainTags::empty(),
+            forbidden_tags: TerrainTags::empty(),
+        }
+    }
+}
+
+impl SpawnRule {
+    /// Whether a tile carrying `tags` may host this spawn.
+    pub fn allows(&self, tags: TerrainTags) -> bool {
+        if self.weight <= 0.0 {
+            return false;
+        }
+        let required = self.required_tags.bits() == 0 || (tags & self.required_tags).bits() != 0;
+        required && (tags & self.forbidden_tags).bits() == 0
+    }
+
+    fn resolve(&mut self, rule: &str) -> Result<(), SpawnRulesConfigError> {
+        if !self.weight.is_finite() || self.weight < 0.0 {
+            return Err(SpawnRulesConfigError::InvalidWeight {
+                rule: rule.to_string(),
+                weight: self.weight,
+            });
+        }
+        self.required_tags = resolve_tags(rule, &self.required)?;
+        self.forbidden_tags = resolve_tags(rule, &self.forbidden)?;
+        Ok(())
+    }
+}
+
+fn resolve_tags(rule: &str, names: &[String]) -> Result<TerrainTags, SpawnRulesConfigError> {
+    let mut tags = TerrainTags::empty();
+    for name in names {
+        tags |= terrain_tag_from_name(name).ok_or_else(|| SpawnRulesConfigError::UnknownTag {
+            rule: rule.to_string(),
+            tag: name.clone(),
+        })?;
+    }
+    Ok(tags)
+}
+
+/// Root spawn-rules configuration.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct SpawnRulesConfig {
+    /// Food-site placement rules keyed by site kind.
+    pub food_sites: BTreeMap<FoodSiteKind, SpawnRule>,
+    /// Herd placement rules keyed by fauna species key.
+    pub species: BTreeMap<String, SpawnRule>,
+}
+
+impl SpawnRulesConfig {
+    pub fn builtin() -> Arc<Self> {
+        Arc::new(
+            Self::from_json_str(BUILTIN_SPAWN_RULES_CONFIG)
+                .expect("builtin spawn rules config should parse and validate"),
+        )
+    }
+
+    pub fn from_json_str(json: &str) -> Result<Self, SpawnRulesConfigError> {
+        let mut config: SpawnRulesConfig = serde_json::from_str(json)?;
+        config.resolve()?;
+        Ok(config)
+    }
+
+    pub fn from_file(path: &Path) -> Result<Self, SpawnRulesConfigError> {
+        let contents = fs::read_to_string(path).map_err(|source| SpawnRulesConfigError::Read {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        SpawnRulesConfig::from_json_str(&contents)
+    }
+
+    /// Validate weights and resolve every rule's tag names to masks.
+    fn resolve(&mut self) -> Result<(), SpawnRulesConfigError> {
+        for (kind, rule) in self.food_sites.iter_mut() {
+            rule.resolve(kind.as_str())?;
+        }
+        for (key, rule) in self.species.iter_mut() {
+            rule.resolve(key)?;
+        }
+        Ok(())
+    }
+
+    /// Whether a food site of `kind` may be seeded on a tile carrying `tags`.
+    pub fn allows_food_site(&self, kind: FoodSiteKind, tags: TerrainTags) -> bool {
+        self.food_sites
+            .get(&kind)
+            .map_or(true, |rule| rule.allows(tags))
+    }
+
+    pub fn food_site_weight(&self, kind: FoodSiteKind) -> f32 {
+        self.food_sites
+            .get(&kind)
+            .map_or(DEFAULT_SPAWN_WEIGHT, |rule| rule.weight)
+    }
+
+    /// Whether a herd of `species` may spawn on a tile carrying `tags`.
+    pub fn allows_species(&self, species: &str, tags: TerrainTags) -> bool {
+        self.species
+            .get(species)
+            .map_or(true, |rule| rule.allows(tags))
+    }
+
+    pub fn species_weight(&self, species: &str) -> f32 {
+        self.species
+            .get(species)
+            .map_or(DEFAULT_SPAWN_WEIGHT, |rule| rule.weight)
+    }
+
+    /// Whether `species` carries a rule at all (an unruled species keeps its legacy placement).
+    pub fn has_species_rule(&self, species: &str) -> bool {
+        self.species.contains_key(species)
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum SpawnRulesConfigError {
+    #[error("failed to read spawn rules config from {path:?}: {source}")]
+    Read {
+        path: PathBuf,
+        #[source]
+        source: io::Error,
+    },
+    #[error("failed to parse spawn rules config: {0}")]
+    Parse(#[from] serde_json::Error),
+    #[error("invalid spawn rules config: rule `{rule}` names unknown terrain tag `{tag}`")]
+    UnknownTag { rule: String, tag: String },
+    #[error(
+        "invalid spawn rules config: rule `{rule}` weight must be finite and >= 0, got {weight}"
+    )]
+    InvalidWeight { rule: String, weight: f32 },
+}
+
+/// Handle for accessing the spawn-rules configuration.
+#[derive(Resource, Debug, Clone)]
+pub struct SpawnRulesConfigHandle(pub Arc<SpawnRulesConfig>);
+
+impl SpawnRulesConfigHandle {
+    pub fn new(config: Arc<SpawnRulesConfig>) -> Self {
+        Self(config)
+    }
+
+    pub fn get(&self) -> Arc<SpawnRulesConfig> {
+        Arc::clone(&self.0)
+    }
+
+    pub fn replace(&mut self, config: Arc<SpawnRulesConfig>) {
+        self.0 = config;
+    }
+}
+
+impl Default for SpawnRulesConfigHandle {
+    fn default() -> Self {
+        Self(SpawnRulesConfig::builtin())
+    }
+}
+
+/// Metadata about the spawn-rules configuration source.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct SpawnRulesConfigMetadata {
+    path: Option<PathBuf>,
+}
+
+impl SpawnRulesConfigMetadata {
+    pub fn new(path: Option<PathBuf>) -> Self {
+        Self { path }
+    }
+
+    pub fn path(&self) -> Option<&PathBuf> {
+        self.path.as_ref()
+    }
+}
+
+/// Load spawn-rules config from environment (`SPAWN_RULES_CONFIG_PATH`) or the default data path,
+/// falling back to the baked-in builtin.
+pub fn load_spawn_rules_config_from_env() -> (Arc<SpawnRulesConfig>, SpawnRulesConfigMetadata) {
+    let override_path = env::var("SPAWN_RULES_CONFIG_PATH").ok().map(PathBuf::from);
+    let default_path =
+        PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("src/data/spawn_rules_config.json");
+
+    let candidates: Vec<PathBuf> = match override_path {
+        Some(ref path) => vec![path.clone()],
+        None => vec![default_path.clone()],
+    };
+
+    for path in candidates {
+        match SpawnRulesConfig::from_file(&path) {
+            Ok(config) => {
+                tracing::info!(
+                    target: "shadow_scale::config",
+                    path = %path.display(),
+                    "spawn_rules_config.loaded=file"
+                );
+                return (Arc::new(config), SpawnRulesConfigMetadata::new(Some(path)));
+            }
+            Err(
+                err @ (SpawnRulesConfigError::UnknownTag { .. }
+                | SpawnRulesConfigError::InvalidWeight { .. }),
+            ) => {
+                tracing::error!(
+                    target: "shadow_scale::config",
+                    path = %path.display(),
+                    error = %err,
+                    "spawn_rules_config.invalid_rejected"
+                );
+            }
+            Err(err) => {
+                tracing::warn!(
+                    target: "shadow_scale::config",
+                    path = %path.display(),
+                    error = %err,
+                    "spawn_rules_config.load_failed"
+                );
+            }
+        }
+    }
+
+    let config = SpawnRulesConfig::builtin();
+    tracing::info!(
+        target: "shadow_scale::config",
+        "spawn_rules_config.loaded=builtin"
+    );
+    (config, SpawnRulesConfigMetadata::new(None))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builtin_config_parses() {
+        let config = SpawnRulesConfig::builtin();
+        assert!(config.allows_food_site(FoodSiteKind::ArcticFishing, TerrainTags::POLAR));
+        assert_eq!(
+            config.species_weight("no_such_species"),
+            DEFAULT_SPAWN_WEIGHT
+        );
+    }
+
+    #[test]
+    fn rules_gate_on_required_and_forbidden_tags() {
+        let config = SpawnRulesConfig::from_json_str(
+            r#"{
+                "food_sites": {
+                    "arctic_fishing": { "required": ["WATER", "COASTAL"], "forbidden": ["HAZARDOUS"] }
+                },
+                "species": { "seal": { "weight": 0.0 } }
+            }"#,
+        )
+        .expect("rules parse");
+        let fishing = FoodSiteKind::ArcticFishing;
+        assert!(config.allows_food_site(fishing, TerrainTags::COASTAL | TerrainTags::POLAR));
+        assert!(config.allows_food_site(fishing, TerrainTags::WATER));
+        assert!(!config.allows_food_site(fishing, TerrainTags::POLAR));
+        assert!(!config.allows_food_site(fishing, TerrainTags::WATER | TerrainTags::HAZARDOUS));
+        // Kinds without a rule are unconstrained.
+        assert!(config.allows_food_site(FoodSiteKind::ScrubRoots, TerrainTags::empty()));
+        // A zero weight disables the species everywhere.
+        assert!(!config.allows_species("seal", TerrainTags::COASTAL));
+        assert!(config.allows_species("deer", TerrainTags::empty()));
+    }
+
+    #[test]
+    fn rules_must_name_known_tags_and_sane_weights() {
+        let unknown = SpawnRulesConfig::from_json_str(
+            r#"{ "species": { "deer": { "required": ["FOREST"] } } }"#,
+        );
+        assert!(matches!(
+            unknown,
+            Err(SpawnRulesConfigError::UnknownTag { ref rule, ref tag })
+                if rule == "deer" && tag == "FOREST"
+        ));
+
+        let negative = SpawnRulesConfig::from_json_str(
+            r#"{ "food_sites": { "river_garden": { "weight": -1.0 } } }"#,
+        );
+        assert!(matches!(
+            negative,
+            Err(SpawnRulesConfigError::InvalidWeight { ref rule, .. }) if rule == "river_garden"
+        ));
+
+        let unknown_kind =
+            SpawnRulesConfig::from_json_str(r#"{ "food_sites": { "fish_farm": {} } }"#);
+        assert!(matches!(unknown_kind, Err(SpawnRulesConfigError::Parse(_))));
+    }
+}
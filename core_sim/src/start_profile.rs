@@ -5,8 +5,8 @@ use std::{
     sync::Arc,
 };
 
-use bevy::prelude::Resource;
-use serde::Deserialize;
+use bevy::prelude::{Mut, Resource, World};
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use sim_schema::{
     CampaignInventoryEntryState, CampaignLabel as SchemaCampaignLabel, CampaignProfileState,
@@ -14,12 +14,20 @@ use sim_schema::{
 };
 use thiserror::Error;
 
-use crate::food::FoodModule;
+use crate::{
+    components::FOOD,
+    food::FoodModule,
+    resources::{FoodSiteRegistry, SimulationConfig, StartLocation},
+};
 
 pub const BUILTIN_START_PROFILES: &str = include_str!("data/start_profiles.json");
 pub const BUILTIN_START_PROFILE_KNOWLEDGE_TAGS: &str =
     include_str!("data/start_profile_knowledge_tags.json");
 
+/// Stockpile items a start profile may seed: the ones some system actually reads out of
+/// `FactionInventory`. Anything else would sit in the stockpile unused, so resolution drops it.
+pub const KNOWN_INVENTORY_ITEMS: &[&str] = &[FOOD, "trade_goods"];
+
 #[derive(Debug, Clone, Deserialize)]
 struct StartProfilesData {
     profiles: Vec<StartProfile>,
@@ -62,7 +70,7 @@ impl DisplayText {
     }
 }
 
-#[derive(Debug, Clone, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 #[serde(default)]
 pub struct FoodModulePreference {
     pub primary: Option<FoodModule>,
@@ -90,6 +98,10 @@ pub struct StartProfile {
     pub display_subtitle: Option<DisplayText>,
     #[serde(flatten)]
     pub overrides: StartProfileOverrides,
+    /// Inventory items [`resolve_active_profile`] dropped because they are not in
+    /// [`KNOWN_INVENTORY_ITEMS`], in profile order.
+    #[serde(skip)]
+    pub unknown_inventory_items: Vec<String>,
 }
 
 impl StartProfile {
@@ -100,15 +112,30 @@ impl StartProfile {
             display_title: None,
             display_subtitle: None,
             overrides: StartProfileOverrides::default(),
+            unknown_inventory_items: Vec::new(),
         }
     }
 
     pub fn overrides(&self) -> &StartProfileOverrides {
         &self.overrides
     }
+
+    /// Drop inventory entries naming items outside [`KNOWN_INVENTORY_ITEMS`], recording them in
+    /// `unknown_inventory_items`. Returns how many were dropped.
+    pub fn validate_inventory(&mut self) -> usize {
+        let unknown = &mut self.unknown_inventory_items;
+        self.overrides.inventory.retain(|entry| {
+            let known = KNOWN_INVENTORY_ITEMS.contains(&entry.item.as_str());
+            if !known {
+                unknown.push(entry.item.clone());
+            }
+            known
+        });
+        unknown.len()
+    }
 }
 
-#[derive(Debug, Clone, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct StartProfileOverrides {
     #[serde(default)]
     pub starting_units: Vec<StartingUnitSpec>,
@@ -224,7 +251,7 @@ pub enum KnowledgeTagCatalogError {
 /// not a 900-person settlement. Overridable per starting unit via `band_size`.
 pub const DEFAULT_STARTING_BAND_SIZE: u32 = 30;
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StartingUnitSpec {
     pub kind: String,
     #[serde(default = "default_unit_count")]
@@ -265,19 +292,99 @@ fn default_unit_count() -> u32 {
     1
 }
 
-#[derive(Debug, Clone, Deserialize, Default)]
+/// Requested vs spawned starting units of one `kind`, summed over every spec of that kind.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StartingUnitSpawnOutcome {
+    pub kind: String,
+    /// `Σ count.max(1)` over the kind's specs — what `spawn_profile_population` tries to place.
+    pub requested: u32,
+    /// `StartingUnit` entities of this kind that actually exist after spawn.
+    pub spawned: u32,
+}
+
+impl StartingUnitSpawnOutcome {
+    pub fn is_discrepancy(&self) -> bool {
+        self.requested != self.spawned
+    }
+}
+
+/// Post-spawn check of a profile's `starting_units` against what worldgen actually placed, built by
+/// `verify_starting_units` at Startup. A unit whose target (and every tile reachable from it) is
+/// off-map or water is dropped by placement; this is where that drop becomes visible.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct StartingUnitSpawnReport {
+    /// One entry per distinct `kind`, in the profile's spec order.
+    pub outcomes: Vec<StartingUnitSpawnOutcome>,
+    /// No spec placed a single unit, so worldgen spawned its default population clusters instead.
+    pub fell_back_to_default_clusters: bool,
+}
+
+impl StartingUnitSpawnReport {
+    /// Compares `specs` against the kinds of the `StartingUnit` entities that were spawned.
+    pub fn compare<'a>(
+        specs: &[StartingUnitSpec],
+        spawned_kinds: impl IntoIterator<Item = &'a str>,
+    ) -> Self {
+        let mut outcomes: Vec<StartingUnitSpawnOutcome> = Vec::new();
+        for spec in specs {
+            match outcomes
+                .iter_mut()
+                .find(|outcome| outcome.kind == spec.kind)
+            {
+                Some(outcome) => outcome.requested += spec.count.max(1),
+                None => outcomes.push(StartingUnitSpawnOutcome {
+                    kind: spec.kind.clone(),
+                    requested: spec.count.max(1),
+                    spawned: 0,
+                }),
+            }
+        }
+        let mut total_spawned = 0u32;
+        for kind in spawned_kinds {
+            total_spawned += 1;
+            match outcomes.iter_mut().find(|outcome| outcome.kind == kind) {
+                Some(outcome) => outcome.spawned += 1,
+                None => outcomes.push(StartingUnitSpawnOutcome {
+                    kind: kind.to_string(),
+                    requested: 0,
+                    spawned: 1,
+                }),
+            }
+        }
+        Self {
+            outcomes,
+            fell_back_to_default_clusters: !specs.is_empty() && total_spawned == 0,
+        }
+    }
+
+    /// Kinds whose spawned count differs from the profile's request.
+    pub fn discrepancies(&self) -> impl Iterator<Item = &StartingUnitSpawnOutcome> {
+        self.outcomes
+            .iter()
+            .filter(|outcome| outcome.is_discrepancy())
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.discrepancies().next().is_none()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct InventoryEntry {
     pub item: String,
     pub quantity: i64,
 }
 
-#[derive(Debug, Clone, Copy, Deserialize, Default)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default)]
 #[serde(rename_all = "snake_case")]
 pub enum FogMode {
     #[default]
     Standard,
     Revealed,
     Shroud,
+    /// Standard coverage, but survey and scout reveals are occluded by terrain
+    /// (`FogRevealLedger::reveal_los`) instead of clearing a flat radius.
+    LineOfSight,
 }
 
 impl FogMode {
@@ -286,6 +393,7 @@ impl FogMode {
             FogMode::Standard => "standard",
             FogMode::Revealed => "revealed",
             FogMode::Shroud => "shroud",
+            FogMode::LineOfSight => "line_of_sight",
         }
     }
 }
@@ -651,6 +759,80 @@ impl ActiveStartProfile {
     pub fn profile(&self) -> &StartProfile {
         &self.inner
     }
+
+    /// Moves a running campaign onto `new` without rebuilding the world: re-derives
+    /// `StartProfileOverrides` onto `SimulationConfig`, retargets `StartLocation`'s fog mode and
+    /// survey radius (the start position stays put), and refreshes `StartProfileLookup` and
+    /// `CampaignLabel`. World-build fields (starting units, inventory, knowledge tags) are recorded
+    /// but never re-applied. Call through `World::resource_scope`, since `self` lives in `world`.
+    ///
+    /// Refuses — leaving everything untouched — when no world has been built yet, or when `new`
+    /// prefers food modules that have no site on the existing map (sites are placed once, at
+    /// worldgen, so the preference could never be honoured).
+    pub fn swap(
+        &mut self,
+        new: StartProfile,
+        world: &mut World,
+    ) -> Result<(), StartProfileSwapError> {
+        if !world.contains_resource::<StartLocation>() {
+            return Err(StartProfileSwapError::WorldNotBuilt);
+        }
+        let overrides = StartProfileOverrides::from_profile(&new);
+        let preference = &overrides.food_modules;
+        if preference.any() {
+            let placed = world
+                .get_resource::<FoodSiteRegistry>()
+                .is_some_and(|sites| sites.iter().any(|site| preference.matches(site.module)));
+            if !placed {
+                return Err(StartProfileSwapError::FoodModuleUnavailable {
+                    profile: new.id.clone(),
+                });
+            }
+        }
+
+        if let Some(mut location) = world.get_resource_mut::<StartLocation>() {
+            location.set_fog_mode(overrides.fog_mode.unwrap_or(FogMode::Standard));
+            location.set_survey_radius(overrides.survey_radius);
+        }
+        if let Some(mut config) = world.get_resource_mut::<SimulationConfig>() {
+            config.start_profile_id = new.id.clone();
+            config.start_profile_overrides = overrides;
+        }
+        if let Some(mut lookup) = world.get_resource_mut::<StartProfileLookup>() {
+            lookup.id = new.id.clone();
+        }
+        world.insert_resource(CampaignLabel::from_profile(&new));
+
+        tracing::info!(
+            target: "shadow_scale::campaign",
+            from = %self.inner.id,
+            to = %new.id,
+            "start_profile.swapped"
+        );
+        self.inner = new;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum StartProfileSwapError {
+    #[error("cannot swap start profile before the world is built")]
+    WorldNotBuilt,
+    #[error("start profile {profile} prefers food modules with no site on the current map")]
+    FoodModuleUnavailable { profile: String },
+}
+
+/// Resolves `profile_id` the same way campaign selection does (falling back to the first profile)
+/// and swaps the running campaign onto it. Returns whether the fallback was used.
+pub fn swap_active_profile(
+    world: &mut World,
+    profile_id: &str,
+) -> Result<bool, StartProfileSwapError> {
+    let handle = world.resource::<StartProfilesHandle>().clone();
+    let (profile, used_fallback) = resolve_active_profile(&handle, profile_id);
+    world
+        .resource_scope(|world, mut active: Mut<ActiveStartProfile>| active.swap(profile, world))?;
+    Ok(used_fallback)
 }
 
 #[derive(Resource, Debug, Clone)]
@@ -664,20 +846,35 @@ impl StartProfileLookup {
     }
 }
 
+/// The profile `profile_id` names, or the first profile (flagged as a fallback) when it names none.
+/// Inventory entries for unknown items are dropped and warned about; the resolved profile lists them
+/// in `unknown_inventory_items`.
 pub fn resolve_active_profile(
     handle: &StartProfilesHandle,
     profile_id: &str,
 ) -> (StartProfile, bool) {
     let profiles = handle.get();
-    if let Some(found) = profiles.get(profile_id) {
-        return (found.clone(), false);
+    let (mut profile, used_fallback) = match profiles.get(profile_id) {
+        Some(found) => (found.clone(), false),
+        None => (
+            profiles
+                .first()
+                .cloned()
+                .unwrap_or_else(|| StartProfile::placeholder(profile_id.to_string())),
+            true,
+        ),
+    };
+    if profile.validate_inventory() > 0 {
+        for item in &profile.unknown_inventory_items {
+            tracing::warn!(
+                target: "shadow_scale::campaign",
+                profile = %profile.id,
+                item = %item,
+                "start_profile.inventory.unknown_item"
+            );
+        }
     }
-
-    let fallback = profiles
-        .first()
-        .cloned()
-        .unwrap_or_else(|| StartProfile::placeholder(profile_id.to_string()));
-    (fallback, true)
+    (profile, used_fallback)
 }
 
 pub fn snapshot_profiles(handle: &StartProfilesHandle) -> Vec<CampaignProfileSnapshot> {
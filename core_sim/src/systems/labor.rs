@@ -455,16 +455,19 @@ pub fn advance_labor_allocation(
                         if accrual > 0.0 {
                             patch.accrue_cultivation(faction, accrual);
                             if patch.is_cultivated() {
-                                event_log.push(CommandEventEntry::new(
-                                    tick.0,
-                                    CommandEventKind::Cultivate,
-                                    faction,
-                                    format!("Cultivated patch at ({}, {})", tile.x, tile.y),
-                                    Some(format!(
-                                        "status=complete action=cultivate x={} y={}",
-                                        tile.x, tile.y
-                                    )),
-                                ));
+                                event_log.push(
+                                    CommandEventEntry::new(
+                                        tick.0,
+                                        CommandEventKind::Cultivate,
+                                        faction,
+                                        format!("Cultivated patch at ({}, {})", tile.x, tile.y),
+                                        Some(format!(
+                                            "status=complete action=cultivate x={} y={}",
+                                            tile.x, tile.y
+                                        )),
+                                    )
+                                    .with_severity(EventSeverity::Notable),
+                                );
                             }
                         }
                     }
@@ -936,13 +939,19 @@ pub fn advance_labor_allocation(
                         if accrual > 0.0 {
                             herd.accrue_domestication(faction, accrual);
                             if herd.is_domesticated() {
-                                event_log.push(CommandEventEntry::new(
-                                    tick.0,
-                                    CommandEventKind::Tame,
-                                    faction,
-                                    format!("Tamed the {} herd", herd.species),
-                                    Some(format!("status=complete action=tame herd={}", herd.id)),
-                                ));
+                                event_log.push(
+                                    CommandEventEntry::new(
+                                        tick.0,
+                                        CommandEventKind::Tame,
+                                        faction,
+                                        format!("Tamed the {} herd", herd.species),
+                                        Some(format!(
+                                            "status=complete action=tame herd={}",
+                                            herd.id
+                                        )),
+                                    )
+                                    .with_severity(EventSeverity::Notable),
+                                );
                             }
                         }
                     }
@@ -975,19 +984,22 @@ pub fn advance_labor_allocation(
                         if accrual > 0.0 {
                             let pen_tile = herd.position();
                             if herd.accrue_corral(faction, accrual, pen_tile) {
-                                event_log.push(CommandEventEntry::new(
-                                    tick.0,
-                                    CommandEventKind::Corral,
-                                    faction,
-                                    format!(
-                                        "Corralled {} at ({}, {})",
-                                        fauna_id, pen_tile.x, pen_tile.y
-                                    ),
-                                    Some(format!(
-                                        "status=complete action=corral herd={} x={} y={}",
-                                        fauna_id, pen_tile.x, pen_tile.y
-                                    )),
-                                ));
+                                event_log.push(
+                                    CommandEventEntry::new(
+                                        tick.0,
+                                        CommandEventKind::Corral,
+                                        faction,
+                                        format!(
+                                            "Corralled {} at ({}, {})",
+                                            fauna_id, pen_tile.x, pen_tile.y
+                                        ),
+                                        Some(format!(
+                                            "status=complete action=corral herd={} x={} y={}",
+                                            fauna_id, pen_tile.x, pen_tile.y
+                                        )),
+                                    )
+                                    .with_severity(EventSeverity::Notable),
+                                );
                             }
                         }
                     }
@@ -1296,16 +1308,19 @@ fn accrue_field(
     }
     patch.accrue_field(faction, accrual);
     if patch.is_field() {
-        event_log.push(CommandEventEntry::new(
-            tick,
-            CommandEventKind::Sow,
-            faction,
-            format!("Field sown at ({}, {})", tile.x, tile.y),
-            Some(format!(
-                "status=complete action=sow x={} y={}",
-                tile.x, tile.y
-            )),
-        ));
+        event_log.push(
+            CommandEventEntry::new(
+                tick,
+                CommandEventKind::Sow,
+                faction,
+                format!("Field sown at ({}, {})", tile.x, tile.y),
+                Some(format!(
+                    "status=complete action=sow x={} y={}",
+                    tile.x, tile.y
+                )),
+            )
+            .with_severity(EventSeverity::Notable),
+        );
     }
 }
 
@@ -1607,11 +1622,11 @@ mod labor_yield_tests {
             })
             .collect();
         let source_tile = tiles[0];
-        world.entity_mut(source_tile).insert(FoodModuleTag {
-            module: FoodModule::SavannaGrassland,
-            seasonal_weight: 1.0,
-            kind: FoodSiteKind::SavannaTrack,
-        });
+        world.entity_mut(source_tile).insert(FoodModuleTag::new(
+            FoodModule::SavannaGrassland,
+            1.0,
+            FoodSiteKind::SavannaTrack,
+        ));
         world.insert_resource(TileRegistry {
             tiles,
             width: 3,
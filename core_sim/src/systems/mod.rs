@@ -27,9 +27,9 @@ use crate::{
     },
     creatures_config::CreaturesConfigHandle,
     culture::{
-        CultureEffectsCache, CultureLayerId, CultureManager, CultureSchismEvent,
+        CultureEffectsCache, CultureLayerId, CultureManager, CultureOwner, CultureSchismEvent,
         CultureTensionEvent, CultureTensionKind, CultureTensionRecord, CultureTraitAxis,
-        CULTURE_TRAIT_AXES,
+        CultureTraitVector, CULTURE_TRAIT_AXES,
     },
     culture_corruption_config::{CorruptionSeverityConfig, CultureCorruptionConfigHandle},
     demographics_config::{DemographicsConfig, DemographicsConfigHandle, DemographicsConsumption},
@@ -55,13 +55,14 @@ use crate::{
     intensification::{
         knows, LadderConfig, LadderConfigHandle, RungDef, RungKey, RUNG_TIMESCALE_UNSCALED,
     },
+    knowledge_ledger::EspionageProbeEvent,
     labor_config::{LaborConfig, LaborConfigHandle},
     mapgen::MountainType,
     mapgen::{build_bands, validate_bands, TerrainBand, WorldGenSeed},
     orders::{FactionId, FactionRegistry},
     power::{
-        PowerGridNodeTelemetry, PowerGridState, PowerIncident, PowerIncidentSeverity, PowerNodeId,
-        PowerTopology,
+        shed_load, PowerGridNodeTelemetry, PowerGridState, PowerIncident, PowerIncidentSeverity,
+        PowerNodeId, PowerNodeKind, PowerTopology, SheddableLoad,
     },
     provinces::{ProvinceId, ProvinceMap},
     resources::{
@@ -69,21 +70,23 @@ use crate::{
         CorruptionExposureRecord, CorruptionLedgers, CorruptionTelemetry, DiplomacyLeverage,
         DiscoveryProgressLedger, FactionInventory, FogRevealLedger, FoodSiteEntry,
         FoodSiteRegistry, MoistureRaster, SentimentAxisBias, SimulationConfig, SimulationTick,
-        StartLocation, TileRegistry, TradeDiffusionRecord, TradeTelemetry,
+        StartLocation, TileRegistry, TradeDiffusionRecord, TradeFlowRecord, TradeTelemetry,
+        DIPLOMACY_LEVERAGE_ESPIONAGE_GAIN, DIPLOMACY_LEVERAGE_TRADE_GAIN,
     },
     scalar::{scalar_from_f32, scalar_from_u32, scalar_one, scalar_zero, Scalar},
     snapshot_overlays_config::SnapshotOverlaysConfigHandle,
+    spawn_rules_config::SpawnRulesConfigHandle,
     start_profile::{
         FoodModulePreference, StartProfileKnowledgeTags, StartProfileKnowledgeTagsHandle,
-        StartProfileOverrides, StartingUnitSpec,
+        StartProfileOverrides, StartingUnitSpawnReport, StartingUnitSpec,
     },
     terrain::{terrain_definition, terrain_for_position_with_classifier, TerrainDefinition},
     turn_pipeline_config::TurnPipelineConfigHandle,
-    wellbeing_config::{ProductivityConfig, WellbeingConfig, WellbeingConfigHandle},
+    wellbeing_config::{DietConfig, ProductivityConfig, WellbeingConfig, WellbeingConfigHandle},
 };
 use sim_runtime::{
     apply_openness_decay, merge_fragment_payload, scale_migration_fragments, CorruptionSubsystem,
-    TradeLeakCurve,
+    EventSeverity, TradeLeakCurve,
 };
 
 const HERD_TRADE_DIFFUSION_BONUS: f32 = 0.25;
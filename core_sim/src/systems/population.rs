@@ -258,6 +258,17 @@ pub fn output_multiplier(cohort: &PopulationCohort, cfg: &WellbeingConfig) -> Sc
     m
 }
 
+/// Layer 1 (wellbeing) — the `diet` morale contribution of a band whose culture resolves to
+/// `traits` eating from `module`: `morale_weight × (preference − 1)`, positive for a culturally
+/// matched diet and negative for a mismatched one.
+pub fn diet_morale_contribution(
+    traits: &CultureTraitVector,
+    module: FoodModule,
+    cfg: &DietConfig,
+) -> Scalar {
+    scalar_from_f32(cfg.morale_weight * (cfg.preference(traits, module) - 1.0))
+}
+
 /// Layer 3b (wellbeing) — migration's morale-scaled move fraction (decoupled from
 /// `discontent_fraction`, which is productivity-only): `max_rate × clamp((morale_threshold − morale)
 /// / morale_threshold, 0, 1)`. `0` at morale ≥ `morale_threshold` (0.25), ramping to `max_rate`
@@ -283,7 +294,8 @@ pub fn simulate_population(
     pipeline_config: Res<TurnPipelineConfigHandle>,
     demographics: Res<DemographicsConfigHandle>,
     wellbeing_config: Res<WellbeingConfigHandle>,
-    tiles: Query<&Tile>,
+    culture: Res<CultureManager>,
+    tiles: Query<(&Tile, Option<&FoodModuleTag>)>,
     // `With<ResidentBand>`: demographics run on real bands only — a detached expedition manages its
     // own larder/consumption in `advance_expeditions` and never grows/starves/migrates.
     mut cohorts: Query<&mut PopulationCohort, With<ResidentBand>>,
@@ -308,7 +320,7 @@ pub fn simulate_population(
         // Age the band every turn (before any early-out) so the migration gate below sees an
         // accurate settled duration even for cohorts whose home tile briefly can't be resolved.
         cohort.age_turns = cohort.age_turns.saturating_add(1);
-        let Ok(tile) = tiles.get(cohort.home) else {
+        let Ok((tile, food_module)) = tiles.get(cohort.home) else {
             cohort.morale = scalar_zero();
             continue;
         };
@@ -321,12 +333,23 @@ pub fn simulate_population(
         // Layer 1 (wellbeing): the morale delta is the signed sum of named contributors, so a
         // future factor is a new `MoraleFactor` variant + one field here — not a rewrite. The
         // contribution set doubles as the client's per-band morale breakdown. `unrest` = crisis
-        // impacts + cultural sentiment (signed; may be positive).
+        // impacts + cultural sentiment (signed; may be positive). `diet` reads the home tile's local
+        // culture layer (global as fallback) against the module the tile yields.
+        let diet = food_module
+            .zip(
+                culture
+                    .local_layer_by_owner(CultureOwner::from_entity(cohort.home))
+                    .or_else(|| culture.global_layer()),
+            )
+            .map_or(scalar_zero(), |(module, layer)| {
+                diet_morale_contribution(&layer.traits, module.module, &wellbeing.diet)
+            });
         let contributions = MoraleContributions {
             settling: config.population_growth_rate,
             terrain: -pressure.terrain,
             climate: -pressure.cold,
             unrest: impacts.morale_delta + effects.morale_bias,
+            diet,
         };
         let morale_delta = contributions.total();
         // Attribute the dominant *negative* driver when morale fell (else `None`). Starvation is
@@ -675,19 +698,22 @@ mod demographics_tests {
 #[cfg(test)]
 mod wellbeing_tests {
     use super::{
-        advance_population_migration, discontent_fraction, discontent_output_modifier,
-        migration_move_fraction, output_multiplier,
+        advance_population_migration, diet_morale_contribution, discontent_fraction,
+        discontent_output_modifier, migration_move_fraction, output_multiplier,
     };
     use crate::components::{
         MoraleCause, MoraleContributions, PopulationCohort, ResidentBand, Tile,
     };
+    use crate::culture::{CultureTraitAxis, CultureTraitVector, CULTURE_TRAIT_AXES};
+    use crate::food::FoodModule;
     use crate::orders::FactionId;
     use crate::resources::{SimulationConfig, TileRegistry};
     use crate::scalar::{scalar_from_f32, scalar_one, scalar_zero};
-    use crate::wellbeing_config::{WellbeingConfig, WellbeingConfigHandle};
+    use crate::wellbeing_config::{DietConfig, WellbeingConfig, WellbeingConfigHandle};
     use crate::LocalStore;
     use bevy::prelude::{Entity, World};
     use bevy_ecs::system::RunSystemOnce;
+    use std::collections::HashMap;
 
     fn cfg() -> WellbeingConfig {
         WellbeingConfig::default()
@@ -759,6 +785,58 @@ mod wellbeing_tests {
         );
     }
 
+    /// Layer 1 diet factor: per the configured matrix, a culture leaning toward the fishing grounds
+    /// gains morale on a fishing module and loses it on a farming one, and a mismatched diet is a
+    /// named cause of falling morale. Locks 0.01 × (1 ± 0.8 × 0.4 − 1) = ±0.0032.
+    #[test]
+    fn fishing_culture_prefers_a_fishing_module() {
+        let mut diet = DietConfig::default();
+        diet.affinity.insert(
+            CultureTraitAxis::ExpansionistInsular,
+            HashMap::from([
+                (FoodModule::CoastalLittoral, 0.4),
+                (FoodModule::RiverineDelta, -0.4),
+            ]),
+        );
+        let mut baseline = [scalar_zero(); CULTURE_TRAIT_AXES];
+        baseline[CultureTraitAxis::ExpansionistInsular.index()] = scalar_from_f32(0.8);
+        let fishers = CultureTraitVector::with_baseline(baseline);
+
+        let fishing = diet_morale_contribution(&fishers, FoodModule::CoastalLittoral, &diet);
+        let farming = diet_morale_contribution(&fishers, FoodModule::RiverineDelta, &diet);
+        assert!((fishing.to_f32() - 0.0032).abs() < 1e-6, "{fishing:?}");
+        assert!((farming.to_f32() + 0.0032).abs() < 1e-6, "{farming:?}");
+        let morale = scalar_from_f32(0.5);
+        assert!(morale + fishing > morale + farming);
+
+        // Unlisted modules and a neutral culture are indifferent.
+        assert_eq!(
+            diet_morale_contribution(&fishers, FoodModule::MontaneHighland, &diet),
+            scalar_zero()
+        );
+        assert_eq!(
+            diet_morale_contribution(
+                &CultureTraitVector::neutral(),
+                FoodModule::CoastalLittoral,
+                &diet
+            ),
+            scalar_zero()
+        );
+
+        // The preference is clamped: a 1.1 ceiling caps the fishing bonus at 0.001.
+        diet.max_preference = 1.1;
+        let capped = diet_morale_contribution(&fishers, FoodModule::CoastalLittoral, &diet);
+        assert!((capped.to_f32() - 0.001).abs() < 1e-6, "{capped:?}");
+
+        // The farming drain joins the morale sum and, as the only drain, is the named cause.
+        let contributions = MoraleContributions {
+            diet: farming,
+            ..Default::default()
+        };
+        assert_eq!(contributions.total(), farming);
+        assert_eq!(contributions.dominant_negative_cause(), MoraleCause::Diet);
+    }
+
     fn band(home: Entity, faction: u32, morale: f32, working: f32) -> PopulationCohort {
         let m = scalar_from_f32(morale);
         let mut cohort = PopulationCohort {
@@ -19,12 +19,14 @@ pub fn simulate_power(mut params: PowerSimParams) {
     struct NodeCalc {
         entity: Entity,
         id: PowerNodeId,
+        kind: PowerNodeKind,
         generation: Scalar,
         demand: Scalar,
         storage_capacity: Scalar,
         storage_level: Scalar,
         net: Scalar,
         incident_count: u32,
+        priority: u8,
     }
 
     #[derive(Clone, Copy)]
@@ -33,6 +35,7 @@ pub fn simulate_power(mut params: PowerSimParams) {
         id: PowerNodeId,
         surplus: Scalar,
         deficit: Scalar,
+        shed: Scalar,
         storage_level: Scalar,
         storage_capacity: Scalar,
         stability: Scalar,
@@ -72,16 +75,25 @@ pub fn simulate_power(mut params: PowerSimParams) {
             scalar_from_f32(config.max_power_influence),
         );
 
-        let effective_generation = (node.base_generation * node.efficiency + influence_bonus)
-            .clamp(scalar_zero(), config.max_power_generation);
+        // Storage and consumer nodes never produce, whatever their base generation says.
+        let generates = node.kind.generates();
+        let effective_generation = if generates {
+            (node.base_generation * node.efficiency + influence_bonus)
+                .clamp(scalar_zero(), config.max_power_generation)
+        } else {
+            scalar_zero()
+        };
         let target_demand = (node.base_demand
             - influence_bonus * power_cfg.influence_demand_reduction())
         .clamp(scalar_zero(), config.max_power_generation);
         let net = (effective_generation - target_demand) * corruption_factor;
 
-        node.generation = (node.base_generation
-            + net * scalar_from_f32(config.power_generation_adjust_rate))
-        .clamp(scalar_zero(), config.max_power_generation);
+        node.generation = if generates {
+            (node.base_generation + net * scalar_from_f32(config.power_generation_adjust_rate))
+                .clamp(scalar_zero(), config.max_power_generation)
+        } else {
+            scalar_zero()
+        };
         node.demand = (node.base_demand - net * scalar_from_f32(config.power_demand_adjust_rate))
             .clamp(scalar_zero(), config.max_power_generation);
 
@@ -91,12 +103,14 @@ pub fn simulate_power(mut params: PowerSimParams) {
         node_calcs.push(NodeCalc {
             entity,
             id: node.id,
+            kind: node.kind,
             generation: node.generation,
             demand: node.demand,
             storage_capacity: node.storage_capacity,
             storage_level: node.storage_level,
             net: net_supply,
             incident_count: node.incident_count,
+            priority: node.priority,
         });
         node_index.insert(node.id, next_index);
     }
@@ -115,6 +129,18 @@ pub fn simulate_power(mut params: PowerSimParams) {
                 .clamp(scalar_zero(), node.storage_capacity)
         })
         .collect();
+    // Only dedicated storage nodes accept neighbours' surplus; hybrids just buffer their own.
+    let mut storage_headroom: Vec<Scalar> = node_calcs
+        .iter()
+        .zip(&storage_levels)
+        .map(|(node, level)| {
+            if node.kind == PowerNodeKind::Storage {
+                (node.storage_capacity - *level).max(scalar_zero())
+            } else {
+                scalar_zero()
+            }
+        })
+        .collect();
 
     if topology.node_count() == node_count {
         for idx in 0..node_count {
@@ -147,6 +173,27 @@ pub fn simulate_power(mut params: PowerSimParams) {
                 }
             }
         }
+
+        // Whatever surplus is left after covering neighbours' deficits is banked in adjacent
+        // storage nodes, which charge it in the local pass below.
+        for idx in 0..node_count {
+            if nets[idx] <= scalar_zero() || node_calcs[idx].kind == PowerNodeKind::Storage {
+                continue;
+            }
+            for neighbour in topology.neighbours(node_calcs[idx].id) {
+                let Some(&n_idx) = node_index.get(neighbour) else {
+                    continue;
+                };
+                let transfer = nets[idx]
+                    .min(storage_headroom[n_idx])
+                    .min(topology.default_capacity);
+                if transfer > scalar_zero() {
+                    nets[idx] -= transfer;
+                    nets[n_idx] += transfer;
+                    storage_headroom[n_idx] -= transfer;
+                }
+            }
+        }
     }
 
     let storage_efficiency = config.power_storage_efficiency.clamp(
@@ -158,7 +205,9 @@ pub fn simulate_power(mut params: PowerSimParams) {
         .clamp(scalar_zero(), power_cfg.storage_bleed_max());
 
     for idx in 0..node_count {
-        if nets[idx] > scalar_zero() {
+        // Generators and consumers keep no local reserve.
+        let stores = node_calcs[idx].kind.stores();
+        if stores && nets[idx] > scalar_zero() {
             let capacity_left = (node_calcs[idx].storage_capacity - storage_levels[idx])
                 .clamp(scalar_zero(), node_calcs[idx].storage_capacity);
             if capacity_left > scalar_zero() {
@@ -168,7 +217,7 @@ pub fn simulate_power(mut params: PowerSimParams) {
                     .clamp(scalar_zero(), node_calcs[idx].storage_capacity);
                 nets[idx] -= charge;
             }
-        } else if nets[idx] < scalar_zero() && storage_levels[idx] > scalar_zero() {
+        } else if stores && nets[idx] < scalar_zero() && storage_levels[idx] > scalar_zero() {
             let needed = (-nets[idx]).clamp(scalar_zero(), node_calcs[idx].storage_capacity);
             let discharge = storage_levels[idx].min(needed);
             let delivered = discharge * storage_efficiency;
@@ -184,6 +233,53 @@ pub fn simulate_power(mut params: PowerSimParams) {
         }
     }
 
+    // Label each node with the connected grid it sits on. Without a matching topology nothing was
+    // routed, so every node is its own grid.
+    let mut components: Vec<u32> = (0..node_count as u32).collect();
+    if topology.node_count() == node_count {
+        let mut labelled = vec![false; node_count];
+        let mut stack = Vec::new();
+        for root in 0..node_count {
+            if labelled[root] {
+                continue;
+            }
+            labelled[root] = true;
+            stack.push(root);
+            while let Some(idx) = stack.pop() {
+                components[idx] = root as u32;
+                for neighbour in topology.neighbours(node_calcs[idx].id) {
+                    let Some(&n_idx) = node_index.get(neighbour) else {
+                        continue;
+                    };
+                    if !labelled[n_idx] {
+                        labelled[n_idx] = true;
+                        stack.push(n_idx);
+                    }
+                }
+            }
+        }
+    }
+
+    // Whatever deficit survives routing and storage is each grid's shortfall; the policy decides
+    // which of its loads absorb it.
+    let loads: Vec<SheddableLoad> = node_calcs
+        .iter()
+        .zip(&nets)
+        .enumerate()
+        .map(|(idx, (node, net))| SheddableLoad {
+            demand: if *net > scalar_zero() {
+                scalar_zero()
+            } else {
+                node.demand
+                    .clamp(scalar_zero(), config.max_power_generation)
+            },
+            priority: node.priority,
+            deficit: (-*net).max(scalar_zero()),
+            component: components[idx],
+        })
+        .collect();
+    let shed = shed_load(power_cfg.load_shedding(), &loads);
+
     let warn_threshold = config
         .power_instability_warn
         .clamp(scalar_zero(), Scalar::one());
@@ -214,11 +310,7 @@ pub fn simulate_power(mut params: PowerSimParams) {
             scalar_zero()
         };
 
-        let deficit = if nets[idx] < scalar_zero() {
-            -nets[idx]
-        } else {
-            scalar_zero()
-        };
+        let deficit = shed[idx];
 
         let fulfilled = if deficit >= demand {
             scalar_zero()
@@ -273,6 +365,7 @@ pub fn simulate_power(mut params: PowerSimParams) {
             id: node_calcs[idx].id,
             surplus,
             deficit,
+            shed: shed[idx],
             storage_level: storage_levels[idx],
             storage_capacity: node_calcs[idx].storage_capacity,
             stability,
@@ -307,6 +400,7 @@ pub fn simulate_power(mut params: PowerSimParams) {
                 stability: node.stability,
                 surplus: node.surplus,
                 deficit: node.deficit,
+                shed: node.shed,
                 incident_count: node.incident_count,
             },
         );
@@ -324,6 +418,7 @@ pub fn simulate_power(mut params: PowerSimParams) {
             node.stability = result.stability;
             node.surplus = result.surplus;
             node.deficit = result.deficit;
+            node.shed = result.shed;
             node.incident_count = result.incident_count;
         }
     }
@@ -390,10 +485,11 @@ pub fn process_corruption(
     let corruption_cfg = severity_config.config().corruption();
     let trust_idx = corruption_cfg.trust_axis();
     let (delta_min, delta_max) = corruption_cfg.sentiment_delta_bounds();
+    let exposure_step = corruption_cfg.audit().exposure_step(ledger.audit_capacity);
 
     for entry in ledger.entries.iter_mut() {
         if entry.exposure_timer > 0 {
-            entry.exposure_timer = entry.exposure_timer.saturating_sub(1);
+            entry.exposure_timer = entry.exposure_timer.saturating_sub(exposure_step);
         }
 
         if entry.exposure_timer == 0 {
@@ -424,15 +520,37 @@ pub fn process_corruption(
     telemetry.active_incidents = ledger.entry_count();
 }
 
+/// Decay every faction pair's diplomatic leverage toward neutral, then credit this turn's
+/// reinforcements: knowledge a faction's trade carried to a partner, and each espionage probe that
+/// landed against a rival.
+pub fn update_diplomacy_leverage(
+    config: Res<SimulationConfig>,
+    mut diplomacy: ResMut<DiplomacyLeverage>,
+    mut trade_events: EventReader<TradeDiffusionEvent>,
+    mut probe_events: EventReader<EspionageProbeEvent>,
+) {
+    diplomacy.apply_decay(config.diplomacy_leverage_decay);
+    for event in trade_events.read() {
+        diplomacy.reinforce(event.from, event.to, DIPLOMACY_LEVERAGE_TRADE_GAIN);
+    }
+    for probe in probe_events.read() {
+        diplomacy.reinforce(
+            probe.infiltrator,
+            probe.owner,
+            DIPLOMACY_LEVERAGE_ESPIONAGE_GAIN,
+        );
+    }
+}
+
 #[cfg(test)]
 mod power_tests {
     use super::*;
     use crate::{CultureCorruptionConfig, TurnPipelineConfig};
     use bevy::{
-        ecs::system::SystemState,
+        ecs::system::{RunSystemOnce, SystemState},
         prelude::{App, Entity, UVec2, World},
     };
-    use sim_runtime::{TerrainTags, TerrainType};
+    use sim_runtime::{CorruptionEntry, TerrainTags, TerrainType};
     use std::sync::Arc;
 
     #[derive(Clone, Copy)]
@@ -442,6 +560,8 @@ mod power_tests {
         storage_capacity: f32,
         storage_level: f32,
         incident_count: u32,
+        kind: PowerNodeKind,
+        priority: u8,
     }
 
     impl NodeSpec {
@@ -452,8 +572,21 @@ mod power_tests {
                 storage_capacity: 0.0,
                 storage_level: 0.0,
                 incident_count: 0,
+                kind: PowerNodeKind::Hybrid,
+                priority: 0,
             }
         }
+
+        fn with_kind(mut self, kind: PowerNodeKind, storage_capacity: f32) -> Self {
+            self.kind = kind;
+            self.storage_capacity = storage_capacity;
+            self
+        }
+
+        fn with_priority(mut self, priority: u8) -> Self {
+            self.priority = priority;
+            self
+        }
     }
 
     fn configure_simulation(app: &mut App, grid_size: UVec2) {
@@ -515,6 +648,7 @@ mod power_tests {
                         },
                         PowerNode {
                             id: PowerNodeId(idx as u32),
+                            kind: spec.kind,
                             base_generation: Scalar::from_f32(spec.base_generation),
                             base_demand: Scalar::from_f32(spec.base_demand),
                             generation: Scalar::from_f32(spec.base_generation),
@@ -526,6 +660,8 @@ mod power_tests {
                             surplus: scalar_zero(),
                             deficit: scalar_zero(),
                             incident_count: spec.incident_count,
+                            priority: spec.priority,
+                            shed: scalar_zero(),
                         },
                     ))
                     .id();
@@ -645,4 +781,256 @@ mod power_tests {
         assert!(node_c.surplus.to_f32().abs() < 1e-6);
         assert_eq!(grid_state.instability_alerts, 0);
     }
+
+    #[test]
+    fn simulate_power_applies_node_kind_roles() {
+        let mut app = App::new();
+        configure_simulation(&mut app, UVec2::new(2, 2));
+
+        // Generator and storage sit side by side on the top row; the consumer is below the
+        // generator. Storage and consumer carry base generation that their role must ignore.
+        let specs = vec![
+            NodeSpec::new(12.0, 2.0).with_kind(PowerNodeKind::Generator, 0.0),
+            NodeSpec::new(5.0, 1.0).with_kind(PowerNodeKind::Storage, 20.0),
+            NodeSpec::new(6.0, 3.0).with_kind(PowerNodeKind::Consumer, 10.0),
+            NodeSpec::new(4.0, 4.0),
+        ];
+
+        let entities = spawn_power_nodes(&mut app.world, 2, 2, &specs);
+        let topology = PowerTopology::from_grid(&entities, 2, 2, scalar_from_f32(4.0));
+        app.insert_resource(topology);
+
+        run_power_system(&mut app);
+
+        let grid_state = app.world.resource::<PowerGridState>();
+        let telemetry = |id: u32| grid_state.nodes.get(&PowerNodeId(id)).expect("telemetry");
+        let (generator, storage, consumer, hybrid) =
+            (telemetry(0), telemetry(1), telemetry(2), telemetry(3));
+
+        // 10 surplus: 1 covers storage's own draw, 3 the consumer's, 4 (link capacity) is
+        // banked next door and the last 2 stays on the generator.
+        assert!((generator.supply.to_f32() - 12.0).abs() < 1e-6);
+        assert!((generator.surplus.to_f32() - 2.0).abs() < 1e-6);
+        assert!(generator.storage_level.to_f32().abs() < 1e-6);
+
+        assert!(storage.supply.to_f32().abs() < 1e-6);
+        assert!((storage.storage_level.to_f32() - 4.0).abs() < 1e-6);
+        assert!(storage.deficit.to_f32().abs() < 1e-6);
+
+        assert!(consumer.supply.to_f32().abs() < 1e-6);
+        assert!(consumer.deficit.to_f32().abs() < 1e-6);
+        assert!(consumer.storage_level.to_f32().abs() < 1e-6);
+
+        assert!((hybrid.supply.to_f32() - 4.0).abs() < 1e-6);
+        assert_eq!(grid_state.instability_alerts, 0);
+
+        let consumer_node = app
+            .world
+            .entity(entities[2])
+            .get::<PowerNode>()
+            .expect("consumer component");
+        assert!(consumer_node.generation.to_f32().abs() < 1e-6);
+    }
+
+    fn shed_under(policy: &str) -> (Scalar, Scalar) {
+        shed_across(policy, true)
+    }
+
+    fn shed_across(policy: &str, linked: bool) -> (Scalar, Scalar) {
+        let mut app = App::new();
+        configure_simulation(&mut app, UVec2::new(2, 1));
+        let json = format!(r#"{{"power": {{"load_shedding": "{policy}"}}}}"#);
+        app.insert_resource(TurnPipelineConfigHandle::new(Arc::new(
+            TurnPipelineConfig::from_json_str(&json).expect("policy parses"),
+        )));
+
+        // Neither node can cover itself and the links carry nothing: 2 + 5 = 7 short in total.
+        let specs = vec![
+            NodeSpec::new(8.0, 10.0).with_priority(0),
+            NodeSpec::new(0.0, 5.0).with_priority(3),
+        ];
+        let entities = spawn_power_nodes(&mut app.world, 2, 1, &specs);
+        let mut topology = PowerTopology::from_grid(&entities, 2, 1, scalar_zero());
+        if !linked {
+            topology.adjacency.iter_mut().for_each(Vec::clear);
+        }
+        app.insert_resource(topology);
+
+        run_power_system(&mut app);
+
+        let shed = |entity: Entity| {
+            app.world
+                .entity(entity)
+                .get::<PowerNode>()
+                .expect("power node")
+                .shed
+        };
+        (shed(entities[0]), shed(entities[1]))
+    }
+
+    #[test]
+    fn priority_shedding_cuts_low_priority_loads_first() {
+        let (low, high) = shed_under("local");
+        assert!((low.to_f32() - 2.0).abs() < 1e-4);
+        assert!((high.to_f32() - 5.0).abs() < 1e-4);
+
+        let (low, high) = shed_under("priority");
+        assert!((low.to_f32() - 7.0).abs() < 1e-4, "low tier absorbs it all");
+        assert_eq!(high, scalar_zero());
+    }
+
+    #[test]
+    fn pooled_shedding_stays_within_a_connected_grid() {
+        for policy in ["priority", "proportional"] {
+            let (low, high) = shed_across(policy, false);
+            assert!((low.to_f32() - 2.0).abs() < 1e-4, "{policy}");
+            assert!((high.to_f32() - 5.0).abs() < 1e-4, "{policy}");
+        }
+    }
+
+    #[test]
+    fn proportional_shedding_shares_the_deficit_by_demand() {
+        let (large, small) = shed_under("proportional");
+        assert!((large.to_f32() - 7.0 * 10.0 / 15.0).abs() < 1e-4);
+        assert!((small.to_f32() - 7.0 * 5.0 / 15.0).abs() < 1e-4);
+        assert_eq!(large + small, scalar_from_f32(7.0));
+    }
+
+    fn ticks_until_exposure(audit_capacity: u16) -> u32 {
+        let mut app = App::new();
+        app.insert_resource(CorruptionLedgers::default());
+        app.insert_resource(SentimentAxisBias::default());
+        app.insert_resource(CorruptionTelemetry::default());
+        app.insert_resource(DiplomacyLeverage::default());
+        app.insert_resource(CultureCorruptionConfigHandle::new(Arc::new(
+            CultureCorruptionConfig::default(),
+        )));
+        app.insert_resource(SimulationTick(0));
+        {
+            let mut ledgers = app.world.resource_mut::<CorruptionLedgers>();
+            let ledger = ledgers.ledger_mut();
+            ledger.audit_capacity = audit_capacity;
+            ledger.register_incident(CorruptionEntry {
+                subsystem: CorruptionSubsystem::Trade,
+                intensity: scalar_from_f32(0.2).raw(),
+                incident_id: 7,
+                exposure_timer: 12,
+                restitution_window: 0,
+                last_update_tick: 0,
+            });
+        }
+
+        for turn in 1..=32 {
+            app.world.run_system_once(process_corruption);
+            if app.world.resource::<CorruptionTelemetry>().exposures_total > 0 {
+                return turn;
+            }
+        }
+        panic!("incident never exposed at audit capacity {audit_capacity}");
+    }
+
+    #[test]
+    fn audit_capacity_accelerates_corruption_exposure() {
+        let low = ticks_until_exposure(0);
+        let high = ticks_until_exposure(25);
+        assert_eq!(low, 12, "base step counts down one tick per turn");
+        // Defaults: 1 + 25 / 10 = 3 ticks per turn.
+        assert_eq!(high, 4);
+        assert!(high < low);
+
+        // The step is capped, so absurd capacity cannot expose instantly.
+        assert_eq!(ticks_until_exposure(u16::MAX), 3);
+    }
+
+    #[test]
+    fn audit_config_rejects_inverted_step_bounds() {
+        let json =
+            r#"{"corruption": {"audit": {"base_exposure_step": 3, "max_exposure_step": 2}}}"#;
+        let err = CultureCorruptionConfig::from_json_str(json).expect_err("inverted bounds");
+        assert!(err.to_string().contains("max_exposure_step"));
+
+        let json = r#"{"corruption": {"audit": {"capacity_per_extra_step": 0}}}"#;
+        assert!(CultureCorruptionConfig::from_json_str(json).is_err());
+    }
+
+    fn leverage_app(seed: i64) -> App {
+        let mut app = App::new();
+        let mut config = SimulationConfig::default();
+        config.diplomacy_leverage_decay = 0.1;
+        app.insert_resource(config);
+        let mut diplomacy = DiplomacyLeverage::default();
+        diplomacy.reinforce(FactionId(0), FactionId(1), seed);
+        app.insert_resource(diplomacy);
+        app.add_event::<TradeDiffusionEvent>();
+        app.add_event::<EspionageProbeEvent>();
+        app.add_systems(Update, update_diplomacy_leverage);
+        app
+    }
+
+    fn leverage(app: &App) -> i64 {
+        app.world
+            .resource::<DiplomacyLeverage>()
+            .between(FactionId(0), FactionId(1))
+    }
+
+    #[test]
+    fn diplomacy_leverage_decays_toward_neutral() {
+        let mut app = leverage_app(100);
+        let mut previous = leverage(&app);
+        for _ in 0..10 {
+            app.update();
+            let current = leverage(&app);
+            assert!(current < previous, "{current} should fall below {previous}");
+            assert!(current >= 0, "decay never overshoots neutral");
+            previous = current;
+        }
+        for _ in 0..50 {
+            app.update();
+        }
+        let diplomacy = app.world.resource::<DiplomacyLeverage>();
+        assert_eq!(diplomacy.between(FactionId(0), FactionId(1)), 0);
+        assert_eq!(diplomacy.pairs().count(), 0, "neutral pairs are forgotten");
+    }
+
+    #[test]
+    fn trade_and_espionage_reinforce_leverage_against_decay() {
+        let mut decayed = leverage_app(100);
+        let mut reinforced = leverage_app(100);
+        for tick in 0..5 {
+            decayed.update();
+            reinforced.world.send_event(TradeDiffusionEvent {
+                tick,
+                from: FactionId(0),
+                to: FactionId(1),
+                discovery_id: 1,
+                delta: scalar_from_f32(0.1),
+                via_migration: false,
+            });
+            reinforced.update();
+        }
+        assert_eq!(
+            leverage(&reinforced),
+            100,
+            "one trade per turn offsets a 10% decay at 100"
+        );
+        assert!(leverage(&reinforced) > leverage(&decayed));
+
+        reinforced.world.send_event(EspionageProbeEvent {
+            owner: FactionId(0),
+            discovery_id: 1,
+            infiltrator: FactionId(1),
+            fidelity_gain: scalar_from_f32(0.1),
+            suspicion_gain: scalar_from_f32(0.1),
+            cells: 1,
+            tick: 5,
+            note: None,
+        });
+        reinforced.update();
+        let diplomacy = reinforced.world.resource::<DiplomacyLeverage>();
+        assert_eq!(
+            diplomacy.between(FactionId(1), FactionId(0)),
+            -(100 - 10) + DIPLOMACY_LEVERAGE_ESPIONAGE_GAIN,
+            "the infiltrator claws leverage back from the faction it probed"
+        );
+    }
 }
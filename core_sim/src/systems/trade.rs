@@ -1,4 +1,8 @@
+use std::collections::BTreeMap;
+
 use super::*;
+use crate::resources::PhaseTransition;
+use sim_runtime::TerrainTags;
 
 #[derive(Event, Debug, Clone)]
 pub struct TradeDiffusionEvent {
@@ -10,6 +14,45 @@ pub struct TradeDiffusionEvent {
     pub via_migration: bool,
 }
 
+/// Tile fields whose per-turn changes are published as [`TileChangedEvent`]s.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TileField {
+    Temperature,
+    Mass,
+}
+
+/// A tracked tile field moved by at least `SimulationConfig::tile_change_min_delta` in one step, so
+/// listeners (audio cues, overlays) can react without diffing snapshots. Every writer of a tracked
+/// field reports through it: `simulate_materials` (temperature relaxation, mass flux, phase
+/// changes), `simulate_logistics` (mass moved along links, net per tile) and the server's `heat`
+/// command.
+#[derive(Event, Debug, Clone, PartialEq)]
+pub struct TileChangedEvent {
+    pub entity: u64,
+    pub field: TileField,
+    pub old: Scalar,
+    pub new: Scalar,
+}
+
+impl TileChangedEvent {
+    /// The event for `field` moving from `old` to `new`, or `None` when the move is smaller than
+    /// `min_delta`.
+    pub fn between(
+        entity: Entity,
+        field: TileField,
+        old: Scalar,
+        new: Scalar,
+        min_delta: Scalar,
+    ) -> Option<Self> {
+        ((new - old).abs() >= min_delta).then(|| Self {
+            entity: entity.to_bits(),
+            field,
+            old,
+            new,
+        })
+    }
+}
+
 #[derive(SystemParam)]
 pub struct LogisticsSimParams<'w, 's> {
     pub config: Res<'w, SimulationConfig>,
@@ -20,6 +63,7 @@ pub struct LogisticsSimParams<'w, 's> {
     pub pipeline_config: Res<'w, TurnPipelineConfigHandle>,
     pub links: Query<'w, 's, (Entity, &'static mut LogisticsLink)>,
     pub tiles: Query<'w, 's, &'static mut Tile>,
+    pub changes: EventWriter<'w, TileChangedEvent>,
 }
 
 #[derive(SystemParam)]
@@ -32,7 +76,7 @@ pub struct TradeDiffusionParams<'w, 's> {
     pub pipeline_config: Res<'w, TurnPipelineConfigHandle>,
     pub tick: Res<'w, SimulationTick>,
     pub events: EventWriter<'w, TradeDiffusionEvent>,
-    pub links: Query<'w, 's, (&'static LogisticsLink, &'static mut TradeLink)>,
+    pub links: Query<'w, 's, (Entity, &'static LogisticsLink, &'static mut TradeLink)>,
     pub tiles: Query<'w, 's, &'static Tile>,
     pub herd_density: Res<'w, HerdDensityMap>,
 }
@@ -41,13 +85,22 @@ pub struct TradeDiffusionParams<'w, 's> {
 /// the tile's latitude + elevation + jitter climate temperature (recomputed deterministically from
 /// its position/elevation/element), so the field converges to the climate model rather than the old
 /// element checkerboard. Worldgen seeds each tile at exactly this value, so turn 1 has no jump.
+/// Elements with a `SimulationConfig::phase_transitions` entry then freeze, thaw or boil off
+/// (see [`apply_phase_transition`]).
 pub fn simulate_materials(
     config: Res<SimulationConfig>,
     elevation: Res<ElevationField>,
-    mut tiles: Query<&mut Tile>,
+    mut tiles: Query<(Entity, &mut Tile)>,
+    mut changes: EventWriter<TileChangedEvent>,
 ) {
     let grid_height = config.grid_size.y;
-    for mut tile in tiles.iter_mut() {
+    let min_delta = config.tile_change_min_delta;
+    let mut report = |entity: Entity, field, old: Scalar, new: Scalar| {
+        if let Some(event) = TileChangedEvent::between(entity, field, old, new, min_delta) {
+            changes.send(event);
+        }
+    };
+    for (entity, mut tile) in tiles.iter_mut() {
         let above_sea = elevation.above_sea_normalized(tile.position.x, tile.position.y);
         let target = climate_temperature(
             tile.position.y,
@@ -58,14 +111,52 @@ pub fn simulate_materials(
         );
         let delta = (target - tile.temperature) * config.temperature_lerp;
         let conductivity = tile.element.conductivity();
+        let old_temperature = tile.temperature;
+        let old_mass = tile.mass;
         tile.temperature += delta * conductivity;
+        if let Some(phase) = config.phase_transitions.get(tile.element) {
+            apply_phase_transition(&mut tile, phase);
+        }
+        report(
+            entity,
+            TileField::Temperature,
+            old_temperature,
+            tile.temperature,
+        );
         let flux = tile.element.mass_flux() * config.mass_flux_epsilon;
         let new_mass = tile.mass + flux;
         tile.mass = new_mass.clamp(config.mass_bounds.0, config.mass_bounds.1);
+        report(entity, TileField::Mass, old_mass, tile.mass);
     }
 }
 
-/// Move resources along logistics links based on mass gradients.
+/// Moves a `WATER`-tagged tile between phases per `phase`. The solid phase is the `POLAR` tag
+/// (ice): freezing adds it and releases latent heat, thawing clears it and absorbs latent heat, and
+/// a liquid tile past its boil point evaporates part of its mass, absorbing latent heat. At most one
+/// transition happens per turn. Tiles without `WATER` carry no phase and are left alone.
+pub(crate) fn apply_phase_transition(tile: &mut Tile, phase: &PhaseTransition) {
+    if !tile.terrain_tags.contains(TerrainTags::WATER) {
+        return;
+    }
+    let latent = scalar_from_f32(phase.latent_heat);
+    let temperature = tile.temperature.to_f32();
+    if tile.terrain_tags.contains(TerrainTags::POLAR) {
+        if temperature > phase.melt_point {
+            tile.terrain_tags &= TerrainTags::new(!TerrainTags::POLAR.bits());
+            tile.temperature -= latent;
+        }
+    } else if temperature <= phase.freeze_point {
+        tile.terrain_tags |= TerrainTags::POLAR;
+        tile.temperature += latent;
+    } else if temperature >= phase.boil_point {
+        let boiled = tile.mass * scalar_from_f32(phase.boil_mass_fraction);
+        tile.mass -= boiled;
+        tile.temperature -= latent;
+    }
+}
+
+/// Move resources along logistics links based on mass gradients. A tile on several links reports
+/// one [`TileChangedEvent`] for its net mass change across all of them.
 pub fn simulate_logistics(mut params: LogisticsSimParams) {
     let logistics_cfg = params.pipeline_config.config().logistics();
     let corruption_cfg = params.severity_config.config().corruption();
@@ -82,6 +173,7 @@ pub fn simulate_logistics(mut params: LogisticsSimParams) {
         .clamp(logistics_cfg.flow_gain_min(), logistics_cfg.flow_gain_max());
     let mut links: Vec<_> = params.links.iter_mut().collect();
     links.sort_by_key(|(entity, _)| entity.to_bits());
+    let mut starting_mass: BTreeMap<Entity, Scalar> = BTreeMap::new();
     for (_, mut link) in links {
         let Ok([mut source, mut target]) = params.tiles.get_many_mut([link.from, link.to]) else {
             link.flow = scalar_zero();
@@ -102,10 +194,24 @@ pub fn simulate_logistics(mut params: LogisticsSimParams) {
         let gradient = source.mass - target.mass;
         let transfer_raw = (gradient * effective_gain).clamp(-capacity, capacity);
         let delivered = transfer_raw * (Scalar::one() - attrition_scalar);
+        starting_mass.entry(link.from).or_insert(source.mass);
+        starting_mass.entry(link.to).or_insert(target.mass);
         source.mass -= transfer_raw;
         target.mass += delivered;
         link.flow = delivered;
     }
+
+    let min_delta = params.config.tile_change_min_delta;
+    for (entity, old) in starting_mass {
+        let Ok(tile) = params.tiles.get(entity) else {
+            continue;
+        };
+        if let Some(event) =
+            TileChangedEvent::between(entity, TileField::Mass, old, tile.mass, min_delta)
+        {
+            params.changes.send(event);
+        }
+    }
 }
 
 /// Diffuse knowledge along trade links using openness-derived leak timers.
@@ -126,8 +232,14 @@ pub fn trade_knowledge_diffusion(mut params: TradeDiffusionParams) {
     let trade_cfg = params.pipeline_config.config().trade();
     let tariff_base = params.config.base_trade_tariff;
 
-    for (logistics, mut trade) in params.links.iter_mut() {
+    for (entity, logistics, mut trade) in params.links.iter_mut() {
         trade.throughput = logistics.flow * trade_multiplier;
+        params.telemetry.push_flow(TradeFlowRecord {
+            link: entity.to_bits(),
+            from: trade.from_faction,
+            to: trade.to_faction,
+            throughput: trade.throughput,
+        });
         let tariff_max = tariff_base * trade_cfg.tariff_max_scalar();
         trade.tariff = (tariff_base * trade_multiplier).clamp(trade_cfg.tariff_min(), tariff_max);
         trade.openness = trade.openness.clamp(scalar_zero(), scalar_one());
@@ -240,3 +352,231 @@ pub fn publish_trade_telemetry(telemetry: Res<TradeTelemetry>, tick: Res<Simulat
         ),
     }
 }
+
+#[cfg(test)]
+mod tile_change_tests {
+    use super::*;
+    use crate::turn_pipeline_config::TurnPipelineConfig;
+    use bevy_ecs::system::RunSystemOnce;
+    use sim_runtime::{TerrainTags, TerrainType};
+
+    #[test]
+    fn temperature_jump_past_threshold_emits_one_event() {
+        let mut config = SimulationConfig::builtin();
+        config.grid_size = UVec2::new(2, 1);
+        config.tile_change_min_delta = scalar_from_f32(1.0);
+        let mut world = World::new();
+        world.insert_resource(config);
+        world.insert_resource(ElevationField::new(2, 1, vec![0.5, 0.5]));
+        world.init_resource::<Events<TileChangedEvent>>();
+        let tile = |x: u32| Tile {
+            position: UVec2::new(x, 0),
+            element: ElementKind::Zephyrite,
+            mass: scalar_from_f32(1.0),
+            temperature: scalar_zero(),
+            terrain: TerrainType::AlluvialPlain,
+            terrain_tags: TerrainTags::empty(),
+            underlying_terrain: None,
+            mountain: None,
+            river_edges: 0,
+            river_inflow: 0,
+            river_channel: 0,
+        };
+        let calm = world.spawn(tile(0)).id();
+        let hot = world.spawn(tile(1)).id();
+
+        // Settle both tiles so the relaxation step is far below the threshold, then drain the
+        // settling events.
+        for _ in 0..600 {
+            world.run_system_once(simulate_materials);
+        }
+        world.resource_mut::<Events<TileChangedEvent>>().clear();
+        world.run_system_once(simulate_materials);
+        assert!(world.resource::<Events<TileChangedEvent>>().is_empty());
+
+        let scorched = scalar_from_f32(250.0);
+        world.get_mut::<Tile>(hot).unwrap().temperature = scorched;
+        world.run_system_once(simulate_materials);
+
+        let events: Vec<TileChangedEvent> = world
+            .resource_mut::<Events<TileChangedEvent>>()
+            .drain()
+            .collect();
+        assert_eq!(
+            events.len(),
+            1,
+            "only the scorched tile crosses: {events:?}"
+        );
+        let event = &events[0];
+        assert_eq!(event.entity, hot.to_bits());
+        assert_ne!(event.entity, calm.to_bits());
+        assert_eq!(event.field, TileField::Temperature);
+        assert_eq!(event.old, scorched);
+        assert_eq!(event.new, world.get::<Tile>(hot).unwrap().temperature);
+        assert!(event.new < event.old);
+    }
+
+    #[test]
+    fn logistics_reports_each_tile_once_for_its_net_mass_change() {
+        let mut config = SimulationConfig::builtin();
+        config.tile_change_min_delta = scalar_from_f32(0.01);
+        let mut world = World::new();
+        world.insert_resource(config);
+        world.insert_resource(InfluencerImpacts::default());
+        world.insert_resource(CultureEffectsCache::default());
+        world.insert_resource(CorruptionLedgers::default());
+        world.insert_resource(CultureCorruptionConfigHandle::load_builtin());
+        world.insert_resource(TurnPipelineConfigHandle::new(TurnPipelineConfig::builtin()));
+        world.init_resource::<Events<TileChangedEvent>>();
+        let tile = |x: u32, mass: f32| Tile {
+            position: UVec2::new(x, 0),
+            element: ElementKind::Zephyrite,
+            mass: scalar_from_f32(mass),
+            temperature: scalar_zero(),
+            terrain: TerrainType::AlluvialPlain,
+            terrain_tags: TerrainTags::empty(),
+            underlying_terrain: None,
+            mountain: None,
+            river_edges: 0,
+            river_inflow: 0,
+            river_channel: 0,
+        };
+        let hub = world.spawn(tile(0, 10.0)).id();
+        let spokes = [
+            world.spawn(tile(1, 0.0)).id(),
+            world.spawn(tile(2, 0.0)).id(),
+        ];
+        for spoke in spokes {
+            world.spawn(LogisticsLink {
+                from: hub,
+                to: spoke,
+                capacity: scalar_from_f32(5.0),
+                flow: scalar_zero(),
+            });
+        }
+
+        world.run_system_once(simulate_logistics);
+
+        let events: Vec<TileChangedEvent> = world
+            .resource_mut::<Events<TileChangedEvent>>()
+            .drain()
+            .collect();
+        assert_eq!(events.len(), 3, "one per tile: {events:?}");
+        let hub_events: Vec<_> = events
+            .iter()
+            .filter(|event| event.entity == hub.to_bits())
+            .collect();
+        assert_eq!(hub_events.len(), 1);
+        assert_eq!(hub_events[0].field, TileField::Mass);
+        assert_eq!(hub_events[0].old, scalar_from_f32(10.0));
+        assert_eq!(hub_events[0].new, world.get::<Tile>(hub).unwrap().mass);
+        for spoke in spokes {
+            let event = events
+                .iter()
+                .find(|event| event.entity == spoke.to_bits())
+                .expect("spoke reported");
+            assert_eq!(event.old, scalar_zero());
+            assert!(event.new > event.old);
+        }
+    }
+}
+
+#[cfg(test)]
+mod phase_transition_tests {
+    use super::*;
+    use bevy_ecs::system::RunSystemOnce;
+    use sim_runtime::TerrainType;
+
+    const WATER_PHASES: PhaseTransition = PhaseTransition {
+        freeze_point: 0.0,
+        melt_point: 1.0,
+        boil_point: 100.0,
+        latent_heat: 1.5,
+        boil_mass_fraction: 0.1,
+    };
+
+    /// A world whose only per-turn change is the phase step: no relaxation toward the climate
+    /// target and no mass flux.
+    fn world_with(temperature: f32, tags: TerrainTags) -> (World, Entity) {
+        let mut config = SimulationConfig::builtin();
+        config.grid_size = UVec2::new(1, 1);
+        config.temperature_lerp = scalar_zero();
+        config.mass_flux_epsilon = scalar_zero();
+        config
+            .phase_transitions
+            .set(ElementKind::Zephyrite, Some(WATER_PHASES));
+        let mut world = World::new();
+        world.insert_resource(config);
+        world.insert_resource(ElevationField::new(1, 1, vec![0.0]));
+        world.init_resource::<Events<TileChangedEvent>>();
+        let tile = world
+            .spawn(Tile {
+                position: UVec2::new(0, 0),
+                element: ElementKind::Zephyrite,
+                mass: scalar_from_f32(10.0),
+                temperature: scalar_from_f32(temperature),
+                terrain: TerrainType::DeepOcean,
+                terrain_tags: tags,
+                underlying_terrain: None,
+                mountain: None,
+                river_edges: 0,
+                river_inflow: 0,
+                river_channel: 0,
+            })
+            .id();
+        (world, tile)
+    }
+
+    fn tile(world: &World, entity: Entity) -> &Tile {
+        world.get::<Tile>(entity).unwrap()
+    }
+
+    #[test]
+    fn water_below_freezing_turns_to_ice_and_releases_latent_heat() {
+        let (mut world, entity) = world_with(-3.0, TerrainTags::WATER);
+        world.run_system_once(simulate_materials);
+
+        let frozen = tile(&world, entity);
+        assert!(frozen.terrain_tags.contains(TerrainTags::POLAR));
+        assert_eq!(
+            frozen.temperature,
+            scalar_from_f32(-1.5),
+            "latent heat warms it"
+        );
+        assert_eq!(
+            frozen.mass,
+            scalar_from_f32(10.0),
+            "freezing keeps the mass"
+        );
+
+        // Inside the hysteresis band nothing changes.
+        world.run_system_once(simulate_materials);
+        assert_eq!(tile(&world, entity).temperature, scalar_from_f32(-1.5));
+
+        world.get_mut::<Tile>(entity).unwrap().temperature = scalar_from_f32(2.0);
+        world.run_system_once(simulate_materials);
+        let thawed = tile(&world, entity);
+        assert!(!thawed.terrain_tags.contains(TerrainTags::POLAR));
+        assert!(thawed.terrain_tags.contains(TerrainTags::WATER));
+        assert_eq!(
+            thawed.temperature,
+            scalar_from_f32(0.5),
+            "thawing absorbs it"
+        );
+    }
+
+    #[test]
+    fn boiling_water_loses_mass_and_dry_tiles_have_no_phase() {
+        let (mut world, entity) = world_with(120.0, TerrainTags::WATER);
+        world.run_system_once(simulate_materials);
+        let boiled = tile(&world, entity);
+        assert_eq!(boiled.mass, scalar_from_f32(9.0));
+        assert_eq!(boiled.temperature, scalar_from_f32(118.5));
+
+        let (mut world, entity) = world_with(-3.0, TerrainTags::empty());
+        world.run_system_once(simulate_materials);
+        let dry = tile(&world, entity);
+        assert_eq!(dry.terrain_tags, TerrainTags::empty());
+        assert_eq!(dry.temperature, scalar_from_f32(-3.0));
+    }
+}
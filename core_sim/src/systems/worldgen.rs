@@ -64,6 +64,9 @@ pub fn spawn_initial_world(
     mut faction_inventory: ResMut<FactionInventory>,
     snapshot_overlays: Res<SnapshotOverlaysConfigHandle>,
     tile_registry: Option<Res<TileRegistry>>,
+    // Optional like `tile_registry`: a hand-built world without a rules table seeds food sites
+    // exactly as before.
+    spawn_rules: Option<Res<SpawnRulesConfigHandle>>,
 ) {
     // Guard FIRST: the starting inventory, knowledge and culture seeding below all run ahead of any
     // tile work, so a guard placed lower would still double the start profile's grants. Unlike the
@@ -335,7 +338,7 @@ pub fn spawn_initial_world(
         }
     }
 
-    let province_map = ProvinceMap::generate(
+    let mut province_map = ProvinceMap::generate(
         config.grid_size.x,
         config.grid_size.y,
         &tags_grid,
@@ -347,7 +350,6 @@ pub fn spawn_initial_world(
         land_tiles = province_map.land_tiles(),
         "mapgen.provinces.generated"
     );
-    commands.insert_resource(province_map.clone());
 
     let food_module_grid: Vec<Option<FoodModule>> =
         prototypes.iter().map(|proto| proto.food_module).collect();
@@ -355,6 +357,7 @@ pub fn spawn_initial_world(
     let overlays_cfg = snapshot_overlays.get();
     let food_overlay_cfg = overlays_cfg.food();
     let preference = &config.start_profile_overrides.food_modules;
+    let spawn_rules = spawn_rules.map(|handle| handle.get()).unwrap_or_default();
     let land_tiles = province_map.land_tiles().max(1);
     let baseline_total = food_overlay_cfg.max_total_sites();
     let scaled_total = (land_tiles / 120).max(24);
@@ -389,8 +392,12 @@ pub fn spawn_initial_world(
             river_inflow: 0,
             river_channel: 0,
         };
+        let module = proto
+            .food_module
+            .or_else(|| classify_food_module(&tile_component));
         let power_component = PowerNode {
             id: node_id,
+            kind: PowerNodeKind::Hybrid,
             base_generation: generation,
             base_demand: demand,
             generation,
@@ -402,28 +409,32 @@ pub fn spawn_initial_world(
             surplus: scalar_zero(),
             deficit: scalar_zero(),
             incident_count: 0,
+            // Food-producing tiles keep power longer than bare land under priority shedding.
+            priority: u8::from(module.is_some()),
+            shed: scalar_zero(),
         };
         let mut entity_commands = commands.spawn((tile_component.clone(), power_component));
-        let module = proto
-            .food_module
-            .or_else(|| classify_food_module(&tile_component));
         if let Some(module) = module {
             let site_kind = module.site_kind();
             let seasonal_weight = 1.0;
             entity_commands.insert(FoodModuleTag::new(module, seasonal_weight, site_kind));
-            module_candidates
-                .entry(module)
-                .or_default()
-                .push(FoodSiteCandidate {
-                    entry: FoodSiteEntry {
-                        position: proto.position,
-                        module,
-                        kind: site_kind,
-                        seasonal_weight,
-                    },
-                    seasonal_weight,
-                    preferred: preference.matches(module),
-                });
+            // The tile keeps its module either way; the spawn rules only decide whether it may
+            // also be offered as a curated food site, and how strongly it ranks in its bucket.
+            if spawn_rules.allows_food_site(site_kind, proto.tags) {
+                module_candidates
+                    .entry(module)
+                    .or_default()
+                    .push(FoodSiteCandidate {
+                        entry: FoodSiteEntry {
+                            position: proto.position,
+                            module,
+                            kind: site_kind,
+                            seasonal_weight,
+                        },
+                        seasonal_weight: seasonal_weight * spawn_rules.food_site_weight(site_kind),
+                        preferred: preference.matches(module),
+                    });
+            }
         }
         let tile_entity = entity_commands.id();
         tiles.push(tile_entity);
@@ -439,6 +450,8 @@ pub fn spawn_initial_world(
         let modifiers = seeded_modifiers_for_position(proto.position);
         culture.apply_initial_modifiers(tile_entity, modifiers);
     }
+    province_map.index_tiles(&tiles);
+    commands.insert_resource(province_map);
 
     for y in 0..height {
         for x in 0..width {
@@ -859,6 +872,34 @@ pub fn spawn_initial_world(
     let _ = culture.take_tension_events();
 }
 
+/// Compare the start profile's `starting_units` against the `StartingUnit` bands worldgen actually
+/// spawned, publish the result as [`StartingUnitSpawnReport`], and warn on every kind that came up
+/// short (placement drops a unit with no reachable land tile) rather than letting it vanish.
+pub fn verify_starting_units(
+    config: Res<SimulationConfig>,
+    mut report: ResMut<StartingUnitSpawnReport>,
+    units: Query<&StartingUnit>,
+) {
+    let specs = &config.start_profile_overrides.starting_units;
+    *report = StartingUnitSpawnReport::compare(specs, units.iter().map(|unit| unit.kind.as_str()));
+    for outcome in report.discrepancies() {
+        tracing::warn!(
+            target: "shadow_scale::campaign",
+            kind = %outcome.kind,
+            requested = outcome.requested,
+            spawned = outcome.spawned,
+            "start_profile.units.discrepancy"
+        );
+    }
+    if report.fell_back_to_default_clusters {
+        tracing::warn!(
+            target: "shadow_scale::campaign",
+            specs = specs.len(),
+            "start_profile.units.fallback_default_clusters"
+        );
+    }
+}
+
 /// Seed each freshly spawned cohort's demographics (age brackets + a carried food larder) and
 /// apply the starting trade-goods bonus. Food is band-local from day one — every band opens the
 /// game carrying its own reserve, so there is no faction provisions pool to distribute.
@@ -2860,6 +2901,59 @@ mod climate_model_tests {
             "mountain {peak:?} should be colder than sea {sea:?}"
         );
     }
+
+    #[test]
+    fn simulate_materials_settles_a_peak_colder_by_the_lapse_span() {
+        use crate::{components::Tile, resources::SimulationConfig};
+        use bevy::prelude::{Events, UVec2, World};
+        use bevy_ecs::system::RunSystemOnce;
+        use sim_runtime::{TerrainTags, TerrainType};
+
+        let mut config = SimulationConfig::builtin();
+        config.grid_size = UVec2::new(2, H);
+        let span = config.climate.elevation_lapse_span;
+        assert!(span > 0.0, "builtin climate must cool high ground");
+
+        // Sea level on the left column, the field's maximum on the right; every other row is a
+        // sea-level filler so the peak is the tallest point on the field.
+        let sea_level = DEFAULT_SEA_LEVEL;
+        let mut values = vec![sea_level; (2 * H) as usize];
+        values[(H / 2 * 2 + 1) as usize] = 1.0;
+        let field = ElevationField::new(2, H, values).with_sea_level(sea_level);
+
+        let mut world = World::new();
+        world.insert_resource(config);
+        world.insert_resource(field);
+        world.init_resource::<Events<TileChangedEvent>>();
+        let tile = |x: u32| Tile {
+            position: UVec2::new(x, H / 2),
+            element: ElementKind::Zephyrite,
+            mass: scalar_from_f32(1.0),
+            temperature: scalar_zero(),
+            terrain: TerrainType::AlluvialPlain,
+            terrain_tags: TerrainTags::empty(),
+            underlying_terrain: None,
+            mountain: None,
+            river_edges: 0,
+            river_inflow: 0,
+            river_channel: 0,
+        };
+        let sea = world.spawn(tile(0)).id();
+        let peak = world.spawn(tile(1)).id();
+
+        // Both tiles relax from 0° toward their climate target; a few hundred turns is equilibrium.
+        for _ in 0..600 {
+            world.run_system_once(simulate_materials);
+        }
+
+        let temperature = |entity| world.get::<Tile>(entity).unwrap().temperature;
+        let gap = temperature(sea) - temperature(peak);
+        assert!(
+            (gap.to_f32() - span).abs() < 1e-3,
+            "peak should sit {span}° below sea level, got {:?}",
+            gap
+        );
+    }
 }
 
 #[cfg(test)]
@@ -3459,6 +3553,78 @@ mod terrain_tag_tests {
         );
     }
 
+    /// Curated food sites honour the spawn rules against the tags each tile was spawned with: a
+    /// forbidden tag keeps a kind off a tile, a required one keeps it on matching tiles only, and a
+    /// zero weight removes the kind from the curated set.
+    #[test]
+    fn food_sites_spawn_only_where_their_tag_rules_allow() {
+        use crate::food::FoodSiteKind;
+        use crate::resources::FoodSiteRegistry;
+        use crate::spawn_rules_config::{SpawnRulesConfig, SpawnRulesConfigHandle};
+
+        let presets = MapPresets::builtin();
+        let preset = presets.get("earthlike").expect("earthlike preset").clone();
+        let mut config = SimulationConfig::builtin();
+        config.map_preset_id = preset.id.clone();
+        config.map_seed = 0xE47E_51DE_2024u64;
+        config.grid_size = UVec2::new(preset.dimensions.width, preset.dimensions.height);
+        let rules = SpawnRulesConfig::from_json_str(
+            r#"{ "food_sites": {
+                "river_garden": { "forbidden": ["WATER"] },
+                "littoral_gathering": { "required": ["COASTAL"] },
+                "savanna_track": { "weight": 0.0 }
+            } }"#,
+        )
+        .expect("rules parse");
+
+        let mut world = World::default();
+        world.insert_resource(config);
+        world.insert_resource(SimulationTick::default());
+        world.insert_resource(CultureManager::default());
+        world.insert_resource(GenerationRegistry::with_seed(0xFACE_FEED, 6));
+        world.insert_resource(MapPresetsHandle::new(presets));
+        world.insert_resource(StartProfileKnowledgeTagsHandle::new(
+            StartProfileKnowledgeTags::builtin(),
+        ));
+        world.insert_resource(DiscoveryProgressLedger::default());
+        world.insert_resource(FactionInventory::default());
+        world.insert_resource(SnapshotOverlaysConfigHandle::new(
+            SnapshotOverlaysConfig::builtin(),
+        ));
+        world.insert_resource(SpawnRulesConfigHandle::new(Arc::new(rules)));
+        world.run_system_once(crate::systems::spawn_initial_world);
+
+        let registry = world.resource::<TileRegistry>().clone();
+        let sites = world.resource::<FoodSiteRegistry>().sites().to_vec();
+        assert!(
+            !sites.is_empty(),
+            "the rules must leave some sites to check"
+        );
+        let mut query = world.query::<&Tile>();
+        for site in &sites {
+            let entity = registry
+                .index(site.position.x, site.position.y)
+                .expect("site sits on the map");
+            let tags = query.get(&world, entity).expect("site tile").terrain_tags;
+            match site.kind {
+                FoodSiteKind::RiverGarden => assert!(
+                    !tags.contains(TerrainTags::WATER),
+                    "river garden on forbidden water at {:?}",
+                    site.position
+                ),
+                FoodSiteKind::LittoralGathering => assert!(
+                    tags.contains(TerrainTags::COASTAL),
+                    "littoral site off the coast at {:?}",
+                    site.position
+                ),
+                FoodSiteKind::SavannaTrack => {
+                    panic!("zero-weight savanna track seeded at {:?}", site.position)
+                }
+                _ => {}
+            }
+        }
+    }
+
     #[test]
     #[ignore]
     fn debug_earthlike_ratios() {
@@ -3479,11 +3645,13 @@ mod inventory_effect_tests {
         map_preset::{MapPresets, MapPresetsHandle},
         resources::{SimulationConfig, SimulationTick},
         start_profile::{
-            InventoryEntry, StartProfileKnowledgeTags, StartProfileKnowledgeTagsHandle,
+            resolve_active_profile, InventoryEntry, StartProfileKnowledgeTags,
+            StartProfileKnowledgeTagsHandle, StartProfiles, StartProfilesHandle,
         },
     };
     use bevy::prelude::World;
     use bevy_ecs::system::RunSystemOnce;
+    use std::sync::Arc;
 
     fn configured_world(provisions: i64, trade_goods: i64) -> World {
         let mut config = SimulationConfig::builtin();
@@ -3550,6 +3718,34 @@ mod inventory_effect_tests {
         );
     }
 
+    #[test]
+    fn unknown_starting_items_are_reported_and_never_seeded() {
+        let profiles = StartProfiles::from_json_str(
+            r#"{"profiles": [{"id": "typo", "inventory": [
+                {"item": "trade_goods", "quantity": 30},
+                {"item": "trade_godos", "quantity": 12}
+            ]}]}"#,
+        )
+        .expect("profile parses");
+        let handle = StartProfilesHandle::new(Arc::new(profiles));
+        let (profile, used_fallback) = resolve_active_profile(&handle, "typo");
+        assert!(!used_fallback);
+        assert_eq!(profile.unknown_inventory_items, vec!["trade_godos"]);
+
+        let mut world = configured_world(0, 0);
+        world
+            .resource_mut::<SimulationConfig>()
+            .start_profile_overrides = StartProfileOverrides::from_profile(&profile);
+        world.run_system_once(crate::systems::spawn_initial_world);
+        let stockpile = world
+            .resource::<FactionInventory>()
+            .stockpile(PLAYER_FACTION)
+            .cloned()
+            .unwrap_or_default();
+        assert_eq!(stockpile.get("trade_goods"), Some(&30));
+        assert!(!stockpile.contains_key("trade_godos"));
+    }
+
     #[test]
     #[ignore = "TradeLinks are now only created when trade routes are established, not at world spawn"]
     fn trade_goods_raise_openness() {
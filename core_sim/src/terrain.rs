@@ -1,7 +1,10 @@
 use bevy::prelude::UVec2;
 use sim_runtime::{TerrainTags, TerrainType};
 
-use crate::{climate::ClimateBand, map_preset::TerrainClassifierConfig, mapgen::MountainType};
+use crate::{
+    climate::ClimateBand, components::Tile, map_preset::TerrainClassifierConfig,
+    mapgen::MountainType,
+};
 
 #[derive(Debug, Clone, Copy)]
 pub struct MovementProfile {
@@ -604,6 +607,88 @@ pub fn terrain_definition(terrain: TerrainType) -> TerrainDefinition {
     }
 }
 
+/// Multiplier each point of [`TerrainResourceBias`] moves a resource away from `1.0`.
+const RESOURCE_BIAS_STEP: f32 = 0.1;
+/// Bounds every [`ResourceProfile`] multiplier is clamped into.
+const RESOURCE_PROFILE_MIN: f32 = 0.0;
+const RESOURCE_PROFILE_MAX: f32 = 2.0;
+
+/// A tile's full resource-yield bias — what a "what does this tile produce" tooltip shows.
+/// Each field is a multiplier around `1.0` (an unremarkable tile), clamped to `[0, 2]`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ResourceProfile {
+    pub ore: f32,
+    pub organics: f32,
+    pub energy: f32,
+}
+
+impl ResourceProfile {
+    fn scale(&mut self, ore: f32, organics: f32, energy: f32) {
+        self.ore *= ore;
+        self.organics *= organics;
+        self.energy *= energy;
+    }
+
+    fn clamped(self) -> Self {
+        let clamp = |value: f32| value.clamp(RESOURCE_PROFILE_MIN, RESOURCE_PROFILE_MAX);
+        Self {
+            ore: clamp(self.ore),
+            organics: clamp(self.organics),
+            energy: clamp(self.energy),
+        }
+    }
+}
+
+/// Resource-yield profile for `tile`, read for previews only — no system consumes it.
+///
+/// Layers, in order: the **base bias** of [`Tile::resource_terrain`] (so a navigable river
+/// previews the valley it cut, as its yields do), then the tile's **live tags** (hydrology can
+/// restamp them: `FERTILE`/`FRESHWATER`/`WETLAND` lift organics, `ARID` cuts them,
+/// `VOLCANIC`/`SUBSURFACE`/`HYDROTHERMAL` lift ore or energy), then the **overlays** stamped
+/// onto the tile — a mountain lifts ore by its relief, a river along any side lifts organics.
+pub fn resource_profile(tile: &Tile) -> ResourceProfile {
+    let bias = terrain_definition(tile.resource_terrain()).resource_bias;
+    let step = |points: i8| 1.0 + f32::from(points) * RESOURCE_BIAS_STEP;
+    let mut profile = ResourceProfile {
+        ore: step(bias.ore),
+        organics: step(bias.organics),
+        energy: step(bias.energy),
+    };
+
+    let tags = tile.terrain_tags;
+    if tags.contains(TerrainTags::FERTILE) {
+        profile.scale(1.0, 1.25, 1.0);
+    }
+    if tags.contains(TerrainTags::FRESHWATER) {
+        profile.scale(1.0, 1.1, 1.0);
+    }
+    if tags.contains(TerrainTags::WETLAND) {
+        profile.scale(1.0, 1.1, 0.9);
+    }
+    if tags.contains(TerrainTags::ARID) {
+        profile.scale(1.0, 0.75, 1.0);
+    }
+    if tags.contains(TerrainTags::VOLCANIC) {
+        profile.scale(1.15, 1.0, 1.1);
+    }
+    if tags.contains(TerrainTags::SUBSURFACE) {
+        profile.scale(1.15, 1.0, 1.0);
+    }
+    if tags.contains(TerrainTags::HYDROTHERMAL) {
+        profile.scale(1.0, 1.0, 1.2);
+    }
+
+    if let Some(mountain) = tile.mountain {
+        // Relief runs ~1 (foothill) to ~2.2 (fold-belt core); only the excess above flat counts.
+        profile.scale(1.0 + 0.1 * (mountain.relief - 1.0).max(0.0), 1.0, 1.0);
+    }
+    if tile.has_any_river_edge() {
+        profile.scale(1.0, 1.1, 1.0);
+    }
+
+    profile.clamped()
+}
+
 /// Anomaly ("discovery" biome) hashing. The **rarity roll** — whether an eligible lowland
 /// tile becomes an anomaly at all — reads a fresh 8-bit field (bits 16-23), disjoint from
 /// the humidity field (bits 8-15), and is compared against `classifier.anomaly_fraction`.
@@ -983,6 +1068,7 @@ fn pick(noise: u32, options: &[TerrainType]) -> TerrainType {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::components::MountainMetadata;
 
     /// The relief a **fold-belt core** actually reaches: `apply_belt_relief` floors a belt tile at
     /// `1 + relief_belt_gain * strength / (belt_width + 1)`, which peaks at `1 + relief_belt_gain`
@@ -1274,4 +1360,51 @@ mod tests {
             }
         }
     }
+
+    fn profile_tile(terrain: TerrainType) -> Tile {
+        Tile {
+            terrain,
+            terrain_tags: terrain_definition(terrain).tags,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn fertile_floodplain_outyields_salt_flat_in_organics_and_trails_it_in_ore() {
+        let floodplain = resource_profile(&profile_tile(TerrainType::Floodplain));
+        let salt_flat = resource_profile(&profile_tile(TerrainType::SaltFlat));
+
+        assert!(floodplain.organics > 1.5, "{floodplain:?}");
+        assert!(salt_flat.organics < 1.0, "{salt_flat:?}");
+        assert!(floodplain.organics > 2.5 * salt_flat.organics);
+        assert!(salt_flat.ore > floodplain.ore);
+        assert!(salt_flat.energy > floodplain.energy);
+        for profile in [floodplain, salt_flat] {
+            for value in [profile.ore, profile.organics, profile.energy] {
+                assert!((RESOURCE_PROFILE_MIN..=RESOURCE_PROFILE_MAX).contains(&value));
+            }
+        }
+    }
+
+    #[test]
+    fn resource_profile_layers_overlays_over_the_resource_terrain() {
+        let plain = resource_profile(&profile_tile(TerrainType::AlluvialPlain));
+
+        // A navigable river previews the valley it cut, not open water.
+        let mut river = profile_tile(TerrainType::NavigableRiver);
+        river.underlying_terrain = Some(TerrainType::AlluvialPlain);
+        river.terrain_tags = terrain_definition(TerrainType::AlluvialPlain).tags;
+        assert_eq!(resource_profile(&river), plain);
+
+        let mut riverside = profile_tile(TerrainType::AlluvialPlain);
+        riverside.river_edges = 1;
+        assert!(resource_profile(&riverside).organics > plain.organics);
+
+        let mut ridge = profile_tile(TerrainType::AlluvialPlain);
+        ridge.mountain = Some(MountainMetadata {
+            kind: MountainType::Fold,
+            relief: BELT_CORE_RELIEF,
+        });
+        assert!(resource_profile(&ridge).ore > plain.ore);
+    }
 }
@@ -8,7 +8,10 @@ use bevy::prelude::Resource;
 use serde::Deserialize;
 use thiserror::Error;
 
-use crate::scalar::{scalar_from_f32, Scalar};
+use crate::{
+    power::LoadSheddingPolicy,
+    scalar::{scalar_from_f32, Scalar},
+};
 
 pub const BUILTIN_TURN_PIPELINE_CONFIG: &str = include_str!("data/turn_pipeline_config.json");
 
@@ -237,6 +240,7 @@ pub struct PowerPhaseConfig {
     storage_efficiency_min: f32,
     storage_efficiency_max: f32,
     storage_bleed_max: f32,
+    load_shedding: LoadSheddingPolicy,
 }
 
 impl PowerPhaseConfig {
@@ -263,6 +267,10 @@ impl PowerPhaseConfig {
     pub fn storage_bleed_max(&self) -> Scalar {
         scalar_from_f32(self.storage_bleed_max)
     }
+
+    pub fn load_shedding(&self) -> LoadSheddingPolicy {
+        self.load_shedding
+    }
 }
 
 impl Default for PowerPhaseConfig {
@@ -274,6 +282,7 @@ impl Default for PowerPhaseConfig {
             storage_efficiency_min: 0.1,
             storage_efficiency_max: 1.0,
             storage_bleed_max: 0.25,
+            load_shedding: LoadSheddingPolicy::Local,
         }
     }
 }
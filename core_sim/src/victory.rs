@@ -1,15 +1,22 @@
 use std::{
+    collections::BTreeMap,
     env, fs, io,
     path::{Path, PathBuf},
     sync::Arc,
 };
 
-use bevy::prelude::{Res, ResMut, Resource};
+use bevy::prelude::{Query, Res, ResMut, Resource};
 use serde::Deserialize;
 use thiserror::Error;
 
 use crate::{
-    crisis::CrisisMetricKind, metrics::SimulationMetrics, orders::FactionId, SimulationTick,
+    components::PopulationCohort,
+    crisis::CrisisMetricKind,
+    great_discovery::GreatDiscoveryLedger,
+    metrics::SimulationMetrics,
+    orders::FactionId,
+    resources::{SimulationConfig, TradeTelemetry},
+    SimulationTick,
 };
 
 pub const BUILTIN_VICTORY_CONFIG: &str = include_str!("data/victory_config.json");
@@ -48,6 +55,15 @@ impl VictoryModeKind {
             VictoryModeKind::Survival => "survival",
         }
     }
+
+    /// Whether factions race each other on this mode. Global modes (diplomatic, stewardship,
+    /// survival) track a shared world condition and carry no per-faction breakdown.
+    pub fn is_competitive(&self) -> bool {
+        matches!(
+            self,
+            VictoryModeKind::Hegemony | VictoryModeKind::Ascension | VictoryModeKind::Economic
+        )
+    }
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -118,6 +134,9 @@ pub struct VictoryModeState {
     pub progress: f32,
     pub threshold: f32,
     pub achieved: bool,
+    /// Each faction's slice of `progress`, highest first (ties by faction id), for the client
+    /// leaderboard. Empty for global modes and while no faction has contributed.
+    pub per_faction_progress: Vec<(FactionId, f32)>,
 }
 
 impl Default for VictoryModeState {
@@ -128,6 +147,7 @@ impl Default for VictoryModeState {
             progress: 0.0,
             threshold: 1.0,
             achieved: false,
+            per_faction_progress: Vec::new(),
         }
     }
 }
@@ -140,6 +160,7 @@ impl VictoryModeState {
             progress: 0.0,
             threshold: def.threshold.max(0.0001),
             achieved: false,
+            per_faction_progress: Vec::new(),
         }
     }
 }
@@ -220,13 +241,19 @@ fn read_victory_config_from_str(data: &str) -> Result<VictoryConfig, VictoryConf
     })
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn victory_tick(
     config: Res<VictoryConfigHandle>,
     metrics: Res<SimulationMetrics>,
     mut state: ResMut<VictoryState>,
     tick: Res<SimulationTick>,
+    cohorts: Query<&PopulationCohort>,
+    discoveries: Option<Res<GreatDiscoveryLedger>>,
+    trade: Option<Res<TradeTelemetry>>,
+    sim_config: Option<Res<SimulationConfig>>,
 ) {
     let cfg = config.get();
+    let threshold_scale = sim_config.map_or(1.0, |config| config.victory_threshold_scale);
     state.continue_after_win = cfg.continue_after_win;
     if state.winner.is_some() && !state.continue_after_win {
         return;
@@ -239,11 +266,23 @@ pub fn victory_tick(
             None => (VictoryModeState::from_definition(def), false),
         };
 
-        entry.threshold = def.threshold.max(0.0001);
+        // Progress still tracks the authored threshold; the difficulty scale only moves the bar.
+        entry.threshold = (def.threshold * threshold_scale).max(0.0001);
 
         let evaluated = evaluate_mode_progress(&entry, def, &metrics, !existed);
         entry.progress = evaluated.clamp(0.0, entry.threshold);
         entry.achieved = entry.progress >= entry.threshold;
+        entry.per_faction_progress = if entry.kind.is_competitive() {
+            let weights = faction_weights(
+                &entry.kind,
+                &cohorts,
+                discoveries.as_deref(),
+                trade.as_deref(),
+            );
+            split_progress(entry.progress, weights)
+        } else {
+            Vec::new()
+        };
 
         if entry.achieved && state.winner.is_none() {
             state.winner = Some(VictoryResult {
@@ -351,6 +390,55 @@ fn evaluate_mode_progress(
     smoothing * entry.progress + (1.0 - smoothing) * safe_candidate * def.threshold
 }
 
+/// Raw per-faction contribution to a competitive mode: population for hegemony, great
+/// discoveries for ascension, net export surplus for economic.
+fn faction_weights(
+    kind: &VictoryModeKind,
+    cohorts: &Query<&PopulationCohort>,
+    discoveries: Option<&GreatDiscoveryLedger>,
+    trade: Option<&TradeTelemetry>,
+) -> BTreeMap<FactionId, f32> {
+    let mut weights: BTreeMap<FactionId, f32> = BTreeMap::new();
+    match kind {
+        VictoryModeKind::Hegemony => {
+            for cohort in cohorts.iter() {
+                *weights.entry(cohort.faction).or_insert(0.0) += cohort.size as f32;
+            }
+        }
+        VictoryModeKind::Ascension => {
+            for record in discoveries.map(|ledger| ledger.records()).unwrap_or(&[]) {
+                *weights.entry(record.faction).or_insert(0.0) += 1.0;
+            }
+        }
+        VictoryModeKind::Economic => {
+            if let Some(trade) = trade {
+                for (faction, balance) in trade.faction_balance() {
+                    if balance > 0 {
+                        weights.insert(faction, balance as f32);
+                    }
+                }
+            }
+        }
+        VictoryModeKind::Diplomatic | VictoryModeKind::Stewardship | VictoryModeKind::Survival => {}
+    }
+    weights
+}
+
+/// Apportions `progress` by each faction's share of the total weight, sorted for display.
+fn split_progress(progress: f32, weights: BTreeMap<FactionId, f32>) -> Vec<(FactionId, f32)> {
+    let total: f32 = weights.values().sum();
+    if !total.is_finite() || total <= 0.0 {
+        return Vec::new();
+    }
+    let mut breakdown: Vec<(FactionId, f32)> = weights
+        .into_iter()
+        .filter(|(_, weight)| *weight > 0.0)
+        .map(|(faction, weight)| (faction, progress * weight / total))
+        .collect();
+    breakdown.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.0.cmp(&b.0)));
+    breakdown
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -396,6 +484,89 @@ mod tests {
         assert_eq!(state.winner.as_ref().unwrap().mode.0, "test_heg");
     }
 
+    #[test]
+    fn victory_threshold_scale_moves_the_bar_not_the_progress() {
+        use crate::resources::DifficultyPreset;
+
+        // Fresh hegemony candidate: 0.45 + 0.25·0.9 + 0.2·0.9 + 0.1·0.85 = 0.94.
+        let achieved = |preset: DifficultyPreset| {
+            let mut world = World::default();
+            world.insert_resource(config_with_mode(hegemony_definition(), true));
+            world.insert_resource(SimulationMetrics {
+                population_total: 5_000,
+                population_morale_avg: 0.9,
+                grid_stress_avg: 0.1,
+                grid_surplus_margin: 0.4,
+                logistics_flow_avg: 0.8,
+                ..Default::default()
+            });
+            world.insert_resource(SimulationConfig::builtin().with_preset(preset));
+            world.insert_resource(VictoryState::new(true));
+            world.insert_resource(SimulationTick(1));
+            world.run_system_once(victory_tick);
+            let state = world.resource::<VictoryState>();
+            let mode = &state.modes[0];
+            (mode.threshold, mode.achieved)
+        };
+
+        assert_eq!(achieved(DifficultyPreset::Sandbox), (0.75, true));
+        assert_eq!(achieved(DifficultyPreset::Standard), (1.0, false));
+        assert_eq!(achieved(DifficultyPreset::Challenge), (1.25, false));
+    }
+
+    #[test]
+    fn victory_tick_splits_competitive_progress_per_faction() {
+        use crate::great_discovery::{GreatDiscoveryId, GreatDiscoveryRecord};
+
+        let ascension = VictoryModeDefinition {
+            id: VictoryModeId("test_asc".to_string()),
+            kind: VictoryModeKind::Ascension,
+            ..hegemony_definition()
+        };
+        let survival = VictoryModeDefinition {
+            id: VictoryModeId("test_surv".to_string()),
+            kind: VictoryModeKind::Survival,
+            ..hegemony_definition()
+        };
+        let mut world = World::default();
+        world.insert_resource(VictoryConfigHandle::new(Arc::new(VictoryConfig {
+            modes: vec![ascension, survival],
+            continue_after_win: true,
+        })));
+        world.insert_resource(SimulationMetrics {
+            great_discoveries_total: 3,
+            population_morale_avg: 0.5,
+            ..Default::default()
+        });
+        let mut ledger = GreatDiscoveryLedger::default();
+        for (faction, id) in [(1, 10), (2, 11), (2, 12)] {
+            ledger.push(GreatDiscoveryRecord {
+                id: GreatDiscoveryId(id),
+                faction: FactionId(faction),
+                field: Default::default(),
+                tick: 0,
+                publicly_deployed: false,
+                effect_flags: 0,
+            });
+        }
+        world.insert_resource(ledger);
+        world.insert_resource(VictoryState::new(true));
+        world.insert_resource(SimulationTick(4));
+        world.run_system_once(victory_tick);
+
+        let state = world.resource::<VictoryState>();
+        let asc = state.modes.iter().find(|m| m.id.0 == "test_asc").unwrap();
+        let factions: Vec<FactionId> = asc.per_faction_progress.iter().map(|e| e.0).collect();
+        assert_eq!(factions, vec![FactionId(2), FactionId(1)], "leader first");
+        assert!((asc.per_faction_progress[0].1 - asc.progress * 2.0 / 3.0).abs() < 1e-5);
+        assert!((asc.per_faction_progress[1].1 - asc.progress / 3.0).abs() < 1e-5);
+        let surv = state.modes.iter().find(|m| m.id.0 == "test_surv").unwrap();
+        assert!(
+            surv.per_faction_progress.is_empty(),
+            "global modes carry no breakdown"
+        );
+    }
+
     #[test]
     fn victory_tick_halts_when_continue_disabled() {
         let mut world = World::default();
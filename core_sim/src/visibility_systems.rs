@@ -461,13 +461,13 @@ fn scout_vantage_tiles(
 }
 
 /// Build a grid of terrain tags from tile entities.
-pub(crate) fn build_terrain_tags_grid(
-    tiles: &Query<&Tile>,
+pub(crate) fn build_terrain_tags_grid<'a>(
+    tiles: impl IntoIterator<Item = &'a Tile>,
     width: u32,
     height: u32,
 ) -> Vec<TerrainTags> {
     let mut grid = vec![TerrainTags::empty(); (width * height) as usize];
-    for tile in tiles.iter() {
+    for tile in tiles {
         let idx = (tile.position.y * width + tile.position.x) as usize;
         if idx < grid.len() {
             grid[idx] = tile.terrain_tags;
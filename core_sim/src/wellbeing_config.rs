@@ -9,11 +9,13 @@
 //!   Π(modifiers)`); future education/tech/government modifiers slot in alongside it.
 //! - `migration` — tech-gated relocation: discontented people move to a better reachable
 //!   same-faction band or stay (population conserved within the faction).
+//! - `diet` — the culture × food-module compatibility matrix behind the `diet` morale factor.
 //!
 //! Mirrors the `demographics_config.rs` / `sedentarization_config.rs` loader (baked-in builtin +
 //! optional file/env override).
 
 use std::{
+    collections::HashMap,
     env, fs, io,
     path::{Path, PathBuf},
     sync::Arc,
@@ -23,6 +25,11 @@ use bevy::prelude::Resource;
 use serde::Deserialize;
 use thiserror::Error;
 
+use crate::{
+    culture::{CultureTraitAxis, CultureTraitVector},
+    food::FoodModule,
+};
+
 pub const BUILTIN_WELLBEING_CONFIG: &str = include_str!("data/wellbeing_config.json");
 
 /// Layer 2 — discontent tuning. `discontent_fraction = clamp((content_morale − morale) /
@@ -108,6 +115,52 @@ impl Default for MigrationConfig {
     }
 }
 
+/// Layer 1 — dietary compatibility between a band's culture and the food module its home tile
+/// gathers from (`FoodModuleTag`). `affinity[axis][module]` is the preference one unit of a trait
+/// axis adds for that module (negative favours the axis's other pole; unlisted pairs weigh 0). A
+/// band's preference is `clamp(1 + Σ trait × affinity, min_preference, max_preference)` over the
+/// home tile's local culture layer, and its `diet` morale contribution is `morale_weight ×
+/// (preference − 1)` per turn: a matched diet lifts morale, a mismatched one drags it. A home tile
+/// with no food module contributes nothing.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct DietConfig {
+    pub morale_weight: f32,
+    pub min_preference: f32,
+    pub max_preference: f32,
+    pub affinity: HashMap<CultureTraitAxis, HashMap<FoodModule, f32>>,
+}
+
+impl Default for DietConfig {
+    fn default() -> Self {
+        Self {
+            morale_weight: 0.01,
+            min_preference: 0.5,
+            max_preference: 1.5,
+            affinity: HashMap::new(),
+        }
+    }
+}
+
+impl DietConfig {
+    /// Preference multiplier of a culture for food from `module`; `1.0` is indifferent. Axes are
+    /// summed in `CultureTraitAxis::ALL` order so the result does not depend on map iteration.
+    pub fn preference(&self, traits: &CultureTraitVector, module: FoodModule) -> f32 {
+        let values = traits.values();
+        let lean: f32 = CultureTraitAxis::ALL
+            .iter()
+            .filter_map(|axis| {
+                let weight = self.affinity.get(axis)?.get(&module)?;
+                Some(values[axis.index()].to_f32() * weight)
+            })
+            .sum();
+        (1.0 + lean).clamp(
+            self.min_preference,
+            self.max_preference.max(self.min_preference),
+        )
+    }
+}
+
 /// Root wellbeing configuration.
 #[derive(Debug, Clone, Default, Deserialize)]
 #[serde(default)]
@@ -115,6 +168,7 @@ pub struct WellbeingConfig {
     pub discontent: DiscontentConfig,
     pub productivity: ProductivityConfig,
     pub migration: MigrationConfig,
+    pub diet: DietConfig,
 }
 
 impl WellbeingConfig {
@@ -256,5 +310,12 @@ mod tests {
         assert!((0.0..=1.0).contains(&m.morale_threshold));
         assert!((0.0..=1.0).contains(&m.dependent_weight));
         assert!((0.0..=1.0).contains(&m.attractive_morale));
+        let diet = &config.diet;
+        assert!(diet.morale_weight >= 0.0);
+        assert!(diet.min_preference <= 1.0 && 1.0 <= diet.max_preference);
+        assert!(
+            !diet.affinity.is_empty(),
+            "the shipped matrix maps at least one axis"
+        );
     }
 }
@@ -0,0 +1,184 @@
+//! Whole-world save and load.
+//!
+//! [`restore_world_from_snapshot`] rebuilds everything a `WorldSnapshot` carries, which is enough
+//! to roll back inside a running app. A save has to come back in a **fresh** app, so it also
+//! carries the live tick, the full [`SimulationConfig`] the run used (map settings included, so
+//! worldgen regenerates the same static world), and the resources the snapshot leaves out: the
+//! influencer roster's spawn stream and counters, the espionage rosters, mission queue,
+//! counter-intel ledgers and security policies, the crisis ledger (every active crisis, its
+//! spread stream and escalation state, and the next id) with its seed, spawn, follow-on and
+//! containment queues, and the crisis gauges' smoothing and hysteresis state. A save whose snapshot `config_hash` differs from the app it is loaded into
+//! is refused rather than resumed under different rules.
+//!
+//! Lives here rather than in `sim_runtime` for the same reason as `replay_check`: it needs the
+//! Bevy world.
+
+use bevy::prelude::{App, World};
+use serde::{Deserialize, Serialize};
+use sim_runtime::WorldSnapshot;
+use thiserror::Error;
+
+use crate::{
+    build_headless_app_with_config,
+    crisis::{CrisisSave, CrisisTelemetry, CrisisTelemetrySave},
+    espionage::EspionageSave,
+    influencers::{InfluentialRoster, InfluentialRosterSave},
+    resources::{ConfigHash, SimulationConfig, SimulationConfigMetadata, SimulationTick},
+    snapshot::{recapture_snapshot_in_place, restore_world_from_snapshot, SnapshotHistory},
+};
+
+/// Bumped whenever [`WorldSave`]'s layout changes; older saves are refused rather than misread.
+pub const WORLD_SAVE_VERSION: u32 = 3;
+
+#[derive(Debug, Error)]
+pub enum WorldSaveError {
+    #[error("world save version {found} is not supported (expected {WORLD_SAVE_VERSION})")]
+    UnsupportedVersion { found: u32 },
+    #[error("world save could not be decoded: {0}")]
+    Decode(#[from] bincode::Error),
+    #[error("world save config could not be decoded: {0}")]
+    Config(#[from] serde_json::Error),
+    #[error("world save config {saved:#018x} does not match the app's config {loaded:#018x}")]
+    ConfigMismatch { saved: u64, loaded: u64 },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct WorldSave {
+    /// First field, so the version can be read before the rest of the layout is trusted.
+    version: u32,
+    tick: u64,
+    /// `SimulationConfig` as JSON: bincode cannot read back the free-form values in the start
+    /// profile's `ai_profile_overrides`.
+    config: String,
+    snapshot: WorldSnapshot,
+    influencers: Option<InfluentialRosterSave>,
+    espionage: Option<EspionageSave>,
+    crises: Option<CrisisSave>,
+    crisis_telemetry: Option<CrisisTelemetrySave>,
+}
+
+/// Serialize the simulation so [`load_world`] can resume it in a fresh app. Recaptures the
+/// snapshot first, since the turn path only captures on the configured cadence.
+pub fn save_world(app: &mut App) -> Vec<u8> {
+    recapture_snapshot_in_place(&mut app.world);
+    let world = &app.world;
+    let config = world.resource::<SimulationConfig>();
+    let snapshot = world
+        .resource::<SnapshotHistory>()
+        .last_snapshot
+        .as_ref()
+        .map(|snapshot| (**snapshot).clone())
+        .expect("recapture leaves a snapshot");
+    let save = WorldSave {
+        version: WORLD_SAVE_VERSION,
+        tick: world.resource::<SimulationTick>().0,
+        config: serde_json::to_string(config).expect("simulation config serialization"),
+        snapshot,
+        influencers: world
+            .get_resource::<InfluentialRoster>()
+            .map(InfluentialRoster::save_state),
+        espionage: EspionageSave::capture(world),
+        crises: CrisisSave::capture(world),
+        crisis_telemetry: world
+            .get_resource::<CrisisTelemetry>()
+            .map(CrisisTelemetry::save_state),
+    };
+    bincode::serialize(&save).expect("world save serialization")
+}
+
+/// Build a headless app from the save's own config, regenerate the saved map, and restore the
+/// save into it. The app has run its startup pass and is ready for the next turn. Refused if the
+/// rebuilt config does not hash to the one the save was taken under (a start profile that has
+/// changed since, say).
+pub fn load_world(bytes: &[u8]) -> Result<App, WorldSaveError> {
+    let save = decode(bytes)?;
+    let config: SimulationConfig = serde_json::from_str(&save.config)?;
+    let mut app =
+        build_headless_app_with_config(config, SimulationConfigMetadata::new(None, false));
+    check_config(&app.world, &save)?;
+    app.update();
+    apply(&mut app.world, &save);
+    Ok(app)
+}
+
+/// Restore a save into an existing world built from the same config and map.
+pub fn restore_world(world: &mut World, bytes: &[u8]) -> Result<(), WorldSaveError> {
+    let save = decode(bytes)?;
+    check_config(world, &save)?;
+    apply(world, &save);
+    Ok(())
+}
+
+fn decode(bytes: &[u8]) -> Result<WorldSave, WorldSaveError> {
+    let version: u32 = bincode::deserialize(bytes)?;
+    if version != WORLD_SAVE_VERSION {
+        return Err(WorldSaveError::UnsupportedVersion { found: version });
+    }
+    Ok(bincode::deserialize(bytes)?)
+}
+
+fn check_config(world: &World, save: &WorldSave) -> Result<(), WorldSaveError> {
+    let saved = save.snapshot.header.config_hash;
+    let loaded = world
+        .get_resource::<ConfigHash>()
+        .map_or(saved, |hash| hash.0);
+    if saved != loaded {
+        return Err(WorldSaveError::ConfigMismatch { saved, loaded });
+    }
+    Ok(())
+}
+
+fn apply(world: &mut World, save: &WorldSave) {
+    restore_world_from_snapshot(world, &save.snapshot);
+    world.resource_mut::<SimulationTick>().0 = save.tick;
+    if let (Some(state), Some(mut roster)) = (
+        save.influencers.as_ref(),
+        world.get_resource_mut::<InfluentialRoster>(),
+    ) {
+        roster.restore_save_state(state);
+    }
+    if let Some(espionage) = save.espionage.as_ref() {
+        espionage.restore(world);
+    }
+    if let Some(crises) = save.crises.as_ref() {
+        crises.restore(world);
+    }
+    if let (Some(state), Some(mut telemetry)) = (
+        save.crisis_telemetry.as_ref(),
+        world.get_resource_mut::<CrisisTelemetry>(),
+    ) {
+        telemetry.restore_save_state(state);
+    }
+    recapture_snapshot_in_place(world);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_save_from_another_version_is_refused() {
+        let mut bytes = bincode::serialize(&WorldSave {
+            version: WORLD_SAVE_VERSION,
+            tick: 3,
+            config: serde_json::to_string(&SimulationConfig::builtin()).unwrap(),
+            snapshot: WorldSnapshot::default(),
+            influencers: None,
+            espionage: None,
+            crises: None,
+            crisis_telemetry: None,
+        })
+        .unwrap();
+        assert_eq!(decode(&bytes).unwrap().tick, 3);
+
+        bytes[..4].copy_from_slice(&(WORLD_SAVE_VERSION + 1).to_le_bytes());
+        assert!(matches!(
+            decode(&bytes),
+            Err(WorldSaveError::UnsupportedVersion { found }) if found == WORLD_SAVE_VERSION + 1
+        ));
+        assert!(matches!(
+            decode(&bytes[..2]),
+            Err(WorldSaveError::Decode(_))
+        ));
+    }
+}
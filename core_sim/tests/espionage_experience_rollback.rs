@@ -0,0 +1,65 @@
+//! Espionage agent experience travels in the snapshot (`espionage_agents`), so a rollback takes
+//! back experience earned after the restore point.
+
+use core_sim::{
+    build_headless_app, recapture_snapshot_in_place, restore_world_from_snapshot, EspionageRoster,
+    FactionId, SimulationConfig, SnapshotHistory,
+};
+use sim_runtime::WorldSnapshot;
+
+fn capture(app: &mut bevy::app::App) -> WorldSnapshot {
+    recapture_snapshot_in_place(&mut app.world);
+    app.world
+        .resource::<SnapshotHistory>()
+        .last_snapshot
+        .as_ref()
+        .map(|s| (**s).clone())
+        .expect("a snapshot was captured")
+}
+
+#[test]
+fn rollback_restores_agent_experience() {
+    let mut app = build_headless_app();
+    let mut config = app.world.resource::<SimulationConfig>().clone();
+    config.map_preset_id = "earthlike".to_string();
+    config.map_seed = 119304647;
+    app.world.insert_resource(config);
+    app.update();
+
+    let faction = FactionId(0);
+    let handle = app
+        .world
+        .resource::<EspionageRoster>()
+        .agents_for(faction)
+        .first()
+        .map(|agent| agent.handle)
+        .expect("the catalog seeds an agent");
+    app.world
+        .resource_mut::<EspionageRoster>()
+        .agent_mut(faction, handle)
+        .expect("seeded agent")
+        .experience = 2;
+
+    let snapshot = capture(&mut app);
+    let state = snapshot
+        .espionage_agents
+        .iter()
+        .find(|state| state.faction == faction.0 && state.handle == handle.0)
+        .expect("the agent is on the snapshot");
+    assert_eq!(state.experience, 2);
+
+    app.world
+        .resource_mut::<EspionageRoster>()
+        .agent_mut(faction, handle)
+        .expect("seeded agent")
+        .experience = 5;
+    restore_world_from_snapshot(&mut app.world, &snapshot);
+
+    let restored = app
+        .world
+        .resource::<EspionageRoster>()
+        .agent(faction, handle)
+        .expect("the agent survives the rollback")
+        .experience;
+    assert_eq!(restored, 2);
+}
@@ -0,0 +1,150 @@
+//! Scouting-expedition reveal preview (`expeditionRevealFootprintX/Y`).
+//!
+//! The snapshot projects the tiles a scouting party will observe on reaching its travel target so the
+//! client can outline them before it arrives. The preview is pinned to a real party run: whatever the
+//! capture promises must be exactly what `advance_expeditions` buffers on the arrival turn.
+
+use bevy::ecs::system::RunSystemOnce;
+use bevy::math::UVec2;
+use bevy::prelude::{App, Entity};
+
+use core_sim::{
+    advance_band_movement, advance_expeditions, build_headless_app, recapture_snapshot_in_place,
+    scalar_from_f32, scalar_one, scalar_zero, BandTravel, Expedition, ExpeditionConfigHandle,
+    ExpeditionMission, ExpeditionPhase, FactionId, GenerationId, LaborAllocation, LocalStore,
+    MoraleCause, PopulationCohort, ResidentBand, SnapshotHistory, StartingUnit, TileRegistry,
+};
+
+fn tile_at(app: &App, pos: UVec2) -> Entity {
+    app.world
+        .resource::<TileRegistry>()
+        .index(pos.x, pos.y)
+        .expect("tile resolves")
+}
+
+fn cohort(tile: Entity, working: u32) -> PopulationCohort {
+    PopulationCohort {
+        home: tile,
+        current_tile: tile,
+        size: 30,
+        children: scalar_zero(),
+        working: scalar_from_f32(working as f32),
+        elders: scalar_zero(),
+        stores: LocalStore::new(),
+        morale: scalar_one(),
+        last_food_consumption: 0.0,
+        last_morale_delta: scalar_zero(),
+        last_morale_cause: MoraleCause::None,
+        last_morale_contributions: Default::default(),
+        discontent_fraction: scalar_zero(),
+        grievance: scalar_zero(),
+        last_emigrated: 0,
+        last_immigrated: 0,
+        age_turns: 0,
+        generation: 0 as GenerationId,
+        faction: FactionId(0),
+        knowledge: Vec::new(),
+        migration: None,
+    }
+}
+
+#[test]
+fn scout_footprint_matches_the_tiles_it_reveals_on_arrival() {
+    let mut app = build_headless_app();
+    app.update();
+
+    let (width, height) = {
+        let registry = app.world.resource::<TileRegistry>();
+        (registry.width, registry.height)
+    };
+    let start = UVec2::new(width / 2, height / 2);
+    let target = UVec2::new(start.x + 1, start.y);
+
+    // The home band sits far away (beyond comm range), so nothing flushes the party's buffer and it
+    // holds exactly what the party observed.
+    let home_tile = tile_at(&app, UVec2::new((start.x + width / 3) % width, start.y));
+    let home = app.world.spawn((cohort(home_tile, 10), ResidentBand)).id();
+    let party_tile = tile_at(&app, start);
+    let party = app
+        .world
+        .spawn((
+            cohort(party_tile, 2),
+            LaborAllocation::default(),
+            StartingUnit::new("expedition".to_string(), Vec::new()),
+            BandTravel { target },
+            Expedition {
+                home_band: home,
+                mission: ExpeditionMission::Scout,
+                phase: ExpeditionPhase::Outbound,
+                announced: false,
+                pending_reveal: Vec::new(),
+            },
+        ))
+        .id();
+
+    recapture_snapshot_in_place(&mut app.world);
+    let (radius, mut footprint) = {
+        let snapshot = app
+            .world
+            .resource::<SnapshotHistory>()
+            .latest_entry()
+            .expect("a snapshot was captured")
+            .snapshot;
+        let state = snapshot
+            .populations
+            .iter()
+            .find(|p| p.entity == party.to_bits())
+            .expect("the scouting party is in the snapshot");
+        assert_eq!(
+            state.expedition_reveal_footprint_x.len(),
+            state.expedition_reveal_footprint_y.len()
+        );
+        let footprint: Vec<UVec2> = state
+            .expedition_reveal_footprint_x
+            .iter()
+            .zip(&state.expedition_reveal_footprint_y)
+            .map(|(&x, &y)| UVec2::new(x, y))
+            .collect();
+        (state.expedition_reveal_radius, footprint)
+    };
+    let sight = app
+        .world
+        .resource::<ExpeditionConfigHandle>()
+        .get()
+        .observe_sight_range;
+    assert_eq!(radius, sight);
+    assert!(
+        footprint.contains(&target),
+        "the party sees the tile it stands on"
+    );
+
+    // One turn: the party steps onto its target and observes from there.
+    app.world.run_system_once(advance_band_movement);
+    app.world.run_system_once(advance_expeditions);
+
+    let expedition = app.world.get::<Expedition>(party).expect("party alive");
+    let mut revealed = expedition.pending_reveal.clone();
+    let key = |p: &UVec2| (p.y, p.x);
+    footprint.sort_by_key(key);
+    revealed.sort_by_key(key);
+    assert_eq!(
+        footprint, revealed,
+        "the projected footprint is exactly what the party buffers on arrival"
+    );
+
+    // Hunt parties and resident bands carry no preview.
+    recapture_snapshot_in_place(&mut app.world);
+    let snapshot = app
+        .world
+        .resource::<SnapshotHistory>()
+        .latest_entry()
+        .expect("a snapshot was captured")
+        .snapshot;
+    let band = snapshot
+        .populations
+        .iter()
+        .find(|p| p.entity == home.to_bits())
+        .expect("the home band is in the snapshot");
+    assert_eq!(band.expedition_reveal_radius, 0);
+    assert!(band.expedition_reveal_footprint_x.is_empty());
+}
@@ -0,0 +1,67 @@
+//! Snapshot minimum influence (`InfluencerBalanceConfig::snapshot_min_influence`).
+//!
+//! Influencers whose effective influence is below the minimum are left off the snapshot's
+//! `influencers` list and only counted in `header.influencers_omitted`; they keep simulating and
+//! travel in the rollback-only `latent_influencers`, so a restore brings the full roster back.
+
+use std::sync::Arc;
+
+use core_sim::{
+    build_headless_app, recapture_snapshot_in_place, restore_world_from_snapshot,
+    InfluencerBalanceConfig, InfluentialRoster, SimulationConfig, SnapshotHistory,
+};
+use sim_runtime::WorldSnapshot;
+
+fn spawn_world() -> bevy::app::App {
+    let mut app = build_headless_app();
+    let mut config = app.world.resource::<SimulationConfig>().clone();
+    config.map_preset_id = "earthlike".to_string();
+    config.map_seed = 119304647;
+    app.world.insert_resource(config);
+    app.update();
+    app
+}
+
+fn capture(app: &mut bevy::app::App) -> WorldSnapshot {
+    recapture_snapshot_in_place(&mut app.world);
+    app.world
+        .resource::<SnapshotHistory>()
+        .last_snapshot
+        .as_ref()
+        .map(|s| (**s).clone())
+        .expect("a snapshot was captured")
+}
+
+#[test]
+fn sub_threshold_influencers_are_counted_not_listed() {
+    let mut app = spawn_world();
+    let roster_size = app.world.resource::<InfluentialRoster>().states().len();
+    assert!(roster_size > 0, "the roster seeds influencers");
+
+    let snapshot = capture(&mut app);
+    assert_eq!(snapshot.influencers.len(), roster_size);
+    assert_eq!(snapshot.header.influencers_omitted, 0);
+
+    // A minimum no influencer can reach: the whole roster goes latent.
+    let config = InfluencerBalanceConfig::from_json_str(r#"{ "snapshot_min_influence": 1000.0 }"#)
+        .expect("override parses");
+    app.world
+        .resource_mut::<InfluentialRoster>()
+        .apply_config(Arc::new(config));
+    let snapshot = capture(&mut app);
+
+    assert!(snapshot.influencers.is_empty());
+    assert_eq!(snapshot.header.influencer_count, 0);
+    assert_eq!(snapshot.header.influencers_omitted as usize, roster_size);
+    assert_eq!(snapshot.latent_influencers.len(), roster_size);
+    snapshot
+        .validate()
+        .expect("header counts match the listed section");
+
+    // Omitted is not deleted: restoring the snapshot rebuilds the full roster.
+    restore_world_from_snapshot(&mut app.world, &snapshot);
+    assert_eq!(
+        app.world.resource::<InfluentialRoster>().states().len(),
+        roster_size
+    );
+}
@@ -0,0 +1,56 @@
+//! Lockstep replay harness (`compare_runs`).
+//!
+//! Two apps built from the same config must stay hash-identical turn for turn; when they do not,
+//! the report names the first tick and snapshot section that differ. The injected divergence moves
+//! the start marker in one run only: `StartLocation` is read by worldgen and by snapshot capture,
+//! so nothing else in the snapshot can pick up the difference first.
+
+use bevy::math::UVec2;
+
+use core_sim::{
+    build_headless_app, compare_runs, compare_runs_with, SimulationConfig, SimulationTick,
+    StartLocation,
+};
+
+fn earthlike_config() -> SimulationConfig {
+    let mut config = build_headless_app()
+        .world
+        .resource::<SimulationConfig>()
+        .clone();
+    config.map_preset_id = "earthlike".to_string();
+    config.map_seed = 119304647;
+    config
+}
+
+#[test]
+fn identical_runs_report_no_divergence() {
+    assert_eq!(compare_runs(&earthlike_config(), 3), None);
+}
+
+#[test]
+fn an_injected_divergence_is_pinpointed() {
+    let mut diverged_from = None;
+    let report = compare_runs_with(&earthlike_config(), 5, |run, turn, world| {
+        if turn != 3 {
+            return;
+        }
+        if run == 0 {
+            diverged_from = Some(world.resource::<SimulationTick>().0);
+            return;
+        }
+        let mut start = world.resource_mut::<StartLocation>();
+        let position = start.position().expect("earthlike places a start");
+        let shifted = if position.x > 0 {
+            position.x - 1
+        } else {
+            position.x + 1
+        };
+        start.relocate(UVec2::new(shifted, position.y));
+    })
+    .expect("the runs diverge");
+
+    // The turn after the hook advances the tick once before capturing.
+    assert_eq!(Some(report.tick), diverged_from.map(|tick| tick + 1));
+    assert_eq!(report.section, "start_marker");
+    assert_eq!(report.entity.as_deref(), Some("x"));
+}
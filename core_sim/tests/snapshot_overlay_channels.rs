@@ -0,0 +1,129 @@
+//! Per-channel overlay switches (`SnapshotOverlaysConfig::channels`).
+//!
+//! A disabled channel is skipped in `capture_snapshot` and ships as the empty default raster the
+//! client already treats as "no data"; every other channel is still computed as usual. This goes
+//! through the real capture path on a generated world rather than calling the raster builders.
+
+use std::sync::Arc;
+
+use core_sim::{
+    build_headless_app, recapture_snapshot_in_place, SimulationConfig, SnapshotHistory,
+    SnapshotOverlaysConfig, SnapshotOverlaysConfigHandle,
+};
+use sim_runtime::{OverlayChannelFlags, ScalarRasterState, WorldSnapshot};
+
+fn capture_with(overlays: SnapshotOverlaysConfig) -> WorldSnapshot {
+    let mut app = build_headless_app();
+    let mut config = app.world.resource::<SimulationConfig>().clone();
+    config.map_preset_id = "earthlike".to_string();
+    config.map_seed = 119304647;
+    app.world.insert_resource(config);
+    app.update();
+
+    app.world
+        .insert_resource(SnapshotOverlaysConfigHandle::new(Arc::new(overlays)));
+    recapture_snapshot_in_place(&mut app.world);
+    app.world
+        .resource::<SnapshotHistory>()
+        .last_snapshot
+        .as_ref()
+        .map(|s| (**s).clone())
+        .expect("a snapshot was captured")
+}
+
+#[test]
+fn disabled_culture_channel_ships_a_placeholder_raster() {
+    let overlays = SnapshotOverlaysConfig::from_json_str(r#"{ "channels": { "culture": false } }"#)
+        .expect("channels override parses");
+    let snapshot = capture_with(overlays);
+
+    assert_eq!(snapshot.culture_raster, ScalarRasterState::default());
+
+    let grid = (snapshot.fog_raster.width, snapshot.fog_raster.height);
+    assert!(grid.0 > 0 && grid.1 > 0, "fog is still computed");
+    for (name, raster) in [
+        ("logistics", &snapshot.logistics_raster),
+        ("sentiment", &snapshot.sentiment_raster),
+        ("corruption", &snapshot.corruption_raster),
+        ("military", &snapshot.military_raster),
+        ("population_density", &snapshot.population_density_raster),
+    ] {
+        assert_eq!(
+            (raster.width, raster.height),
+            grid,
+            "{name} raster should still cover the grid"
+        );
+        assert_eq!(raster.samples.len(), (grid.0 * grid.1) as usize);
+    }
+}
+
+/// Bits for the sources that are not switchable channels, read off what the snapshot carries.
+fn source_flags(snapshot: &WorldSnapshot) -> OverlayChannelFlags {
+    let mut flags = OverlayChannelFlags::ELEVATION;
+    flags.set(
+        OverlayChannelFlags::CRISIS,
+        snapshot.crisis_overlay.heatmap.width > 0,
+    );
+    flags.set(
+        OverlayChannelFlags::MOISTURE,
+        snapshot.moisture_raster.width > 0,
+    );
+    flags
+}
+
+#[test]
+fn computed_channels_flag_exactly_the_rasters_that_were_computed() {
+    let overlays = SnapshotOverlaysConfig::from_json_str(
+        r#"{ "channels": { "culture": false, "fog": false, "visibility": false } }"#,
+    )
+    .expect("channels override parses");
+    let snapshot = capture_with(overlays);
+
+    let expected = OverlayChannelFlags::LOGISTICS
+        | OverlayChannelFlags::SENTIMENT
+        | OverlayChannelFlags::POPULATION_DENSITY
+        | OverlayChannelFlags::CORRUPTION
+        | OverlayChannelFlags::MILITARY
+        | source_flags(&snapshot);
+    assert_eq!(snapshot.header.computed_channels, expected);
+    for (name, flag) in [
+        ("culture", OverlayChannelFlags::CULTURE),
+        ("fog", OverlayChannelFlags::FOG),
+        ("visibility", OverlayChannelFlags::VISIBILITY),
+    ] {
+        assert!(
+            !snapshot.header.computed_channels.contains(flag),
+            "disabled {name} channel must read as never computed"
+        );
+    }
+}
+
+#[test]
+fn every_enabled_channel_is_flagged_computed_by_default() {
+    let overlays = SnapshotOverlaysConfig::builtin();
+    let snapshot = capture_with((*overlays).clone());
+
+    let expected = OverlayChannelFlags::LOGISTICS
+        | OverlayChannelFlags::SENTIMENT
+        | OverlayChannelFlags::POPULATION_DENSITY
+        | OverlayChannelFlags::CORRUPTION
+        | OverlayChannelFlags::FOG
+        | OverlayChannelFlags::CULTURE
+        | OverlayChannelFlags::MILITARY
+        | OverlayChannelFlags::VISIBILITY
+        | source_flags(&snapshot);
+    assert_eq!(snapshot.header.computed_channels, expected);
+}
+
+#[test]
+fn channels_default_to_enabled() {
+    let overlays = SnapshotOverlaysConfig::builtin();
+    let snapshot = capture_with((*overlays).clone());
+
+    let raster = &snapshot.culture_raster;
+    assert!(raster.width > 0 && raster.height > 0);
+    assert_eq!(
+        raster.samples.len(),
+        (raster.width * raster.height) as usize
+    );
+}
@@ -0,0 +1,70 @@
+//! **A starting unit that cannot be placed is reported, not silently dropped.**
+//!
+//! `spawn_profile_population` relocates a unit off water to the nearest land tile, but a target
+//! off the map has no tile to search from, so the unit is skipped with no trace. `verify_starting_units`
+//! runs right after worldgen and compares the profile's specs against the `StartingUnit` bands that
+//! exist, publishing the result as `StartingUnitSpawnReport`.
+
+use bevy::math::UVec2;
+use core_sim::{build_headless_app, SimulationConfig, StartingUnitSpawnReport, StartingUnitSpec};
+
+/// Small reproducible map; **never 0** (`map_seed == 0` rolls a seed from entropy).
+const GRID: UVec2 = UVec2::new(40, 26);
+const MAP_SEED: u64 = 20_260_721;
+
+fn spec(kind: &str, count: u32, position: Option<[i32; 2]>) -> StartingUnitSpec {
+    StartingUnitSpec {
+        kind: kind.to_string(),
+        count,
+        position,
+        ..Default::default()
+    }
+}
+
+fn report_for(units: Vec<StartingUnitSpec>) -> StartingUnitSpawnReport {
+    let mut app = build_headless_app();
+    let mut config = app.world.resource::<SimulationConfig>().clone();
+    config.map_preset_id = "earthlike".to_string();
+    config.map_seed = MAP_SEED;
+    config.grid_size = GRID;
+    config.start_profile_overrides.starting_units = units;
+    app.world.insert_resource(config);
+    app.update();
+    app.world.resource::<StartingUnitSpawnReport>().clone()
+}
+
+#[test]
+fn infeasible_starting_unit_is_reported_as_a_discrepancy() {
+    let report = report_for(vec![
+        spec("band", 1, None),
+        // Far off the map: no tile to place on or to search for land from.
+        spec("scouts", 2, Some([5_000, 5_000])),
+    ]);
+
+    assert!(!report.is_complete());
+    assert!(!report.fell_back_to_default_clusters);
+    let discrepancies: Vec<_> = report.discrepancies().collect();
+    assert_eq!(
+        discrepancies.len(),
+        1,
+        "only the off-map kind came up short"
+    );
+    assert_eq!(discrepancies[0].kind, "scouts");
+    assert_eq!(discrepancies[0].requested, 2);
+    assert_eq!(discrepancies[0].spawned, 0);
+
+    let band = report
+        .outcomes
+        .iter()
+        .find(|outcome| outcome.kind == "band")
+        .expect("the feasible kind is reported too");
+    assert_eq!((band.requested, band.spawned), (1, 1));
+}
+
+#[test]
+fn a_profile_with_no_placeable_unit_reports_the_default_cluster_fallback() {
+    let report = report_for(vec![spec("scouts", 1, Some([-5_000, 0]))]);
+
+    assert!(report.fell_back_to_default_clusters);
+    assert_eq!(report.discrepancies().count(), 1);
+}
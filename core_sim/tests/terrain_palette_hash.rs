@@ -0,0 +1,72 @@
+//! Terrain legend fingerprint (`TerrainOverlayState::palette_hash`).
+//!
+//! `capture_snapshot` hashes the set of terrain types present so the client can skip rebuilding its
+//! terrain palette when nothing new appeared. Goes through the real capture path on a generated
+//! world: the hash holds across quiet turns and moves once a reclassification adds a new type.
+
+use std::collections::BTreeSet;
+
+use bevy::prelude::App;
+use core_sim::{
+    build_headless_app, recapture_snapshot_in_place, SimulationConfig, SnapshotHistory, Tile,
+};
+use sim_runtime::{TerrainOverlayState, TerrainType};
+
+fn terrain_overlay(app: &App) -> TerrainOverlayState {
+    app.world
+        .resource::<SnapshotHistory>()
+        .last_snapshot
+        .as_ref()
+        .map(|s| s.terrain.clone())
+        .expect("a snapshot was captured")
+}
+
+fn terrain_types(overlay: &TerrainOverlayState) -> BTreeSet<u16> {
+    overlay.samples.iter().map(|s| s.terrain as u16).collect()
+}
+
+#[test]
+fn palette_hash_is_stable_until_a_new_terrain_type_appears() {
+    let mut app = build_headless_app();
+    let mut config = app.world.resource::<SimulationConfig>().clone();
+    config.map_preset_id = "earthlike".to_string();
+    config.map_seed = 119304647;
+    app.world.insert_resource(config);
+    app.update();
+
+    let first = terrain_overlay(&app);
+    assert_ne!(first.palette_hash, 0, "capture computes the palette hash");
+
+    app.update();
+    let second = terrain_overlay(&app);
+    let present = terrain_types(&second);
+    assert_eq!(
+        present,
+        terrain_types(&first),
+        "a quiet turn introduces no terrain type"
+    );
+    assert_eq!(
+        second.palette_hash, first.palette_hash,
+        "an unchanged legend keeps its palette hash across turns"
+    );
+
+    let novel = TerrainType::VALUES
+        .iter()
+        .copied()
+        .find(|terrain| !present.contains(&(*terrain as u16)))
+        .expect("the generated world leaves at least one terrain type unused");
+
+    let mut tiles = app.world.query::<&mut Tile>();
+    let mut tile = tiles
+        .iter_mut(&mut app.world)
+        .next()
+        .expect("the world has tiles");
+    tile.terrain = novel;
+    recapture_snapshot_in_place(&mut app.world);
+
+    let reclassified = terrain_overlay(&app);
+    assert_ne!(
+        reclassified.palette_hash, second.palette_hash,
+        "introducing {novel:?} changes the palette hash"
+    );
+}
@@ -0,0 +1,71 @@
+//! Per-stage turn timing (`SimulationMetrics::turn_timings`).
+//!
+//! With `turn_stage_timing` on, every `TurnStage` reports a lap each turn. Laps are taken back to
+//! back, so they must cover the timed span exactly and fit inside the wall-clock time of the turn.
+
+use std::time::Instant;
+
+use core_sim::{build_headless_app, run_turn, SimulationConfig, SimulationMetrics, TurnStage};
+
+#[test]
+fn stage_laps_cover_the_whole_turn() {
+    let mut app = build_headless_app();
+    app.update();
+    assert!(
+        app.world
+            .resource::<SimulationMetrics>()
+            .turn_timings
+            .is_none(),
+        "timing is off by default"
+    );
+
+    app.world
+        .resource_mut::<SimulationConfig>()
+        .turn_stage_timing = true;
+    const TURNS: u64 = 3;
+    for _ in 0..TURNS {
+        let started = Instant::now();
+        run_turn(&mut app);
+        let wall_ms = started.elapsed().as_secs_f64() * 1000.0;
+
+        let metrics = app.world.resource::<SimulationMetrics>();
+        let timings = metrics.turn_timings.as_ref().expect("timed turn recorded");
+        let stages: Vec<TurnStage> = timings.stages.iter().map(|t| t.stage.clone()).collect();
+        assert_eq!(
+            stages,
+            TurnStage::ALL.to_vec(),
+            "one lap per stage, in order"
+        );
+        for timing in &timings.stages {
+            assert!(timing.last_ms >= 0.0, "{:?}", timing);
+            assert!(timing.avg_ms >= 0.0, "{:?}", timing);
+        }
+        let sum: f64 = timings.stages.iter().map(|t| t.last_ms).sum();
+        assert!(
+            (sum - timings.total_ms).abs() < 1e-6,
+            "stages sum to {sum} ms, turn took {} ms",
+            timings.total_ms
+        );
+        assert!(
+            timings.total_ms <= wall_ms,
+            "{} > {wall_ms}",
+            timings.total_ms
+        );
+    }
+
+    let metrics = app.world.resource::<SimulationMetrics>();
+    assert_eq!(metrics.turn_timings.as_ref().unwrap().samples, TURNS);
+    let text = metrics.to_prometheus();
+    assert!(text.contains("shadow_scale_turn_stage_ms{stage=\"great_discovery\"} "));
+    assert!(text.contains("# TYPE shadow_scale_turn_duration_ms gauge\n"));
+
+    app.world
+        .resource_mut::<SimulationConfig>()
+        .turn_stage_timing = false;
+    run_turn(&mut app);
+    assert!(app
+        .world
+        .resource::<SimulationMetrics>()
+        .turn_timings
+        .is_none());
+}
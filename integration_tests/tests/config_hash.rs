@@ -0,0 +1,58 @@
+mod common;
+
+use bevy::prelude::App;
+use core_sim::{
+    build_headless_app, run_turn, ConfigHash, SimulationConfig, SimulationConfigMetadata,
+    SnapshotHistory,
+};
+
+fn deterministic_app() -> App {
+    let mut app = build_headless_app();
+    if let Some(mut metadata) = app.world.get_resource_mut::<SimulationConfigMetadata>() {
+        metadata.set_seed_random(false);
+    }
+    app
+}
+
+fn captured_config_hash(app: &mut App) -> u64 {
+    run_turn(app);
+    app.world
+        .resource::<SnapshotHistory>()
+        .last_snapshot
+        .as_ref()
+        .expect("the world-build turn captures a snapshot")
+        .header
+        .config_hash
+}
+
+#[test]
+fn snapshot_config_hash_tracks_config_lineage() {
+    common::ensure_test_config();
+
+    let mut first = deterministic_app();
+    let built = first.world.resource::<ConfigHash>().0;
+    assert_eq!(
+        built,
+        first.world.resource::<SimulationConfig>().config_hash(),
+        "the hash is computed at app build"
+    );
+
+    let mut second = deterministic_app();
+    let mut retuned = deterministic_app();
+    {
+        let mut config = retuned.world.resource_mut::<SimulationConfig>();
+        config.map_seed ^= 0xC0FF_EE00;
+        config.population_cap += 1;
+    }
+
+    let first_hash = captured_config_hash(&mut first);
+    let second_hash = captured_config_hash(&mut second);
+    let retuned_hash = captured_config_hash(&mut retuned);
+
+    assert_ne!(first_hash, 0);
+    assert_eq!(first_hash, second_hash, "identical configs share a hash");
+    assert_ne!(
+        first_hash, retuned_hash,
+        "a config changed after build is re-fingerprinted before capture"
+    );
+}
@@ -90,6 +90,10 @@ fn deterministic_snapshots_match() {
     assert_eq!(snapshot_a.fog_raster, snapshot_b.fog_raster);
     assert_eq!(snapshot_a.culture_raster, snapshot_b.culture_raster);
     assert_eq!(snapshot_a.military_raster, snapshot_b.military_raster);
+    assert_eq!(
+        snapshot_a.population_density_raster,
+        snapshot_b.population_density_raster
+    );
     assert_eq!(snapshot_a.culture_layers, snapshot_b.culture_layers);
     assert_eq!(snapshot_a.culture_tensions, snapshot_b.culture_tensions);
     assert_eq!(snapshot_a.discovery_progress, snapshot_b.discovery_progress);
@@ -0,0 +1,44 @@
+mod common;
+
+use core_sim::{
+    build_headless_app, run_turn, SimulationConfig, SimulationConfigMetadata, SimulationMetrics,
+    SimulationTick, SnapshotHistory,
+};
+
+const CADENCE: u64 = 5;
+
+#[test]
+fn snapshot_cadence_captures_only_on_matching_ticks() {
+    common::ensure_test_config();
+    let mut app = build_headless_app();
+    if let Some(mut metadata) = app.world.get_resource_mut::<SimulationConfigMetadata>() {
+        metadata.set_seed_random(false);
+    }
+    {
+        let mut config = app.world.resource_mut::<SimulationConfig>();
+        config.map_seed = 0x5EED_F00D;
+        config.snapshot_every_n_ticks = CADENCE;
+    }
+
+    let mut captured_on = Vec::new();
+    for _ in 0..=10 {
+        let resolved = app.world.resource::<SimulationTick>().0;
+        let before = app.world.resource::<SnapshotHistory>().len();
+        run_turn(&mut app);
+        let history = app.world.resource::<SnapshotHistory>();
+        if history.len() > before {
+            captured_on.push(resolved);
+            assert_eq!(
+                history.latest_tick(),
+                Some(app.world.resource::<SimulationTick>().0),
+                "a captured turn's ring entry carries the post-turn tick"
+            );
+        }
+    }
+
+    assert_eq!(captured_on, vec![0, 5, 10]);
+    assert_eq!(app.world.resource::<SnapshotHistory>().len(), 3);
+    // The tick counter and metrics keep advancing every turn regardless of cadence.
+    assert_eq!(app.world.resource::<SimulationTick>().0, 11);
+    assert_eq!(app.world.resource::<SimulationMetrics>().turn, 11);
+}
@@ -0,0 +1,39 @@
+mod common;
+
+use core_sim::{
+    build_headless_app, run_turn, FactionId, PendingCrisisSpawns, SimulationConfigMetadata,
+    SimulationTick,
+};
+
+#[test]
+fn turn_report_reflects_crisis_spawns_and_warnings() {
+    common::ensure_test_config();
+    let mut app = build_headless_app();
+    if let Some(mut metadata) = app.world.get_resource_mut::<SimulationConfigMetadata>() {
+        metadata.set_seed_random(false);
+    }
+
+    let quiet = run_turn(&mut app);
+    assert_eq!(quiet.tick, 0);
+    assert_eq!(quiet.new_crises, 0);
+    assert!(quiet.warnings.is_empty(), "{:?}", quiet.warnings);
+
+    {
+        let mut spawns = app.world.resource_mut::<PendingCrisisSpawns>();
+        spawns.push(FactionId(0), "plague_bloom");
+        spawns.push(FactionId(1), "not_a_real_archetype");
+    }
+
+    let report = run_turn(&mut app);
+    assert_eq!(report.tick, 1, "report carries the resolved tick");
+    assert_eq!(app.world.resource::<SimulationTick>().0, 2);
+    assert_eq!(report.new_crises, 1);
+    assert_eq!(report.new_great_discoveries, 0);
+    assert!(!report.winner_declared());
+    assert_eq!(report.warnings.len(), 1);
+    assert!(report.warnings[0].contains("not_a_real_archetype"));
+
+    let next = run_turn(&mut app);
+    assert_eq!(next.new_crises, 0, "existing crises are not recounted");
+    assert!(next.warnings.is_empty(), "warnings reset every turn");
+}
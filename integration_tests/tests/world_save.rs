@@ -0,0 +1,132 @@
+mod common;
+
+use bevy::prelude::App;
+use core_sim::{
+    build_headless_app, load_world, recapture_snapshot_in_place, restore_world, run_turn,
+    save_world, ActiveCrisisLedger, CrisisMetricKind, CrisisTelemetry, FactionId,
+    PendingCrisisSpawns, SimulationConfig, SimulationConfigMetadata, SimulationTick,
+    SnapshotHistory, WorldSaveError,
+};
+use sim_runtime::{diff_snapshots, WorldSnapshot};
+
+/// Turns played before the save, so the influencer stream and espionage state have moved off
+/// their starting values.
+const TURNS_BEFORE_SAVE: usize = 4;
+
+fn seeded_app() -> App {
+    common::ensure_test_config();
+    let mut app = build_headless_app();
+    if let Some(mut metadata) = app.world.get_resource_mut::<SimulationConfigMetadata>() {
+        metadata.set_seed_random(false);
+    }
+    if let Some(mut config) = app.world.get_resource_mut::<SimulationConfig>() {
+        if config.map_seed == 0 {
+            config.map_seed = 0x5EED_F00D;
+        }
+    }
+    app
+}
+
+fn latest_snapshot(app: &mut App) -> WorldSnapshot {
+    recapture_snapshot_in_place(&mut app.world);
+    app.world
+        .resource::<SnapshotHistory>()
+        .last_snapshot
+        .as_ref()
+        .map(|snapshot| (**snapshot).clone())
+        .expect("recapture leaves a snapshot")
+}
+
+#[test]
+fn a_loaded_save_plays_the_next_turn_identically() {
+    let mut original = seeded_app();
+    for _ in 0..TURNS_BEFORE_SAVE {
+        original.update();
+    }
+
+    let bytes = save_world(&mut original);
+    let mut loaded = load_world(&bytes).expect("save loads");
+    assert_eq!(
+        loaded.world.resource::<SimulationTick>().0,
+        original.world.resource::<SimulationTick>().0
+    );
+
+    for turn in 1..=2 {
+        run_turn(&mut original);
+        run_turn(&mut loaded);
+        let (a, b) = (latest_snapshot(&mut original), latest_snapshot(&mut loaded));
+        assert_eq!(
+            a.header.hash,
+            b.header.hash,
+            "turn {turn} after load diverged: {:?}",
+            diff_snapshots(&a, &b)
+        );
+    }
+}
+
+#[test]
+fn active_crises_survive_a_save() {
+    let mut original = seeded_app();
+    original.update();
+    original
+        .world
+        .resource_mut::<PendingCrisisSpawns>()
+        .push(FactionId(0), "plague_bloom");
+    for _ in 0..TURNS_BEFORE_SAVE {
+        original.update();
+    }
+    assert!(!original.world.resource::<ActiveCrisisLedger>().is_empty());
+    let tick = original.world.resource::<SimulationTick>().0;
+    let gauges = original.world.resource::<CrisisTelemetry>().snapshot(tick);
+    assert!(
+        gauges
+            .gauge(CrisisMetricKind::R0)
+            .is_some_and(|gauge| !gauge.history.is_empty()),
+        "the crisis should have fed the gauges before the save"
+    );
+
+    let bytes = save_world(&mut original);
+    let mut loaded = load_world(&bytes).expect("save loads");
+    assert_eq!(
+        loaded.world.resource::<ActiveCrisisLedger>().entry_states(),
+        original
+            .world
+            .resource::<ActiveCrisisLedger>()
+            .entry_states()
+    );
+    // EMA, history and both hysteresis bands, not gauges re-seeded from the config.
+    assert_eq!(
+        loaded.world.resource::<CrisisTelemetry>().snapshot(tick),
+        gauges
+    );
+
+    for turn in 1..=2 {
+        run_turn(&mut original);
+        run_turn(&mut loaded);
+        let (a, b) = (latest_snapshot(&mut original), latest_snapshot(&mut loaded));
+        assert_eq!(
+            a.header.hash,
+            b.header.hash,
+            "turn {turn} after load diverged: {:?}",
+            diff_snapshots(&a, &b)
+        );
+    }
+}
+
+#[test]
+fn a_save_under_another_config_is_refused() {
+    let mut original = seeded_app();
+    original.update();
+    let bytes = save_world(&mut original);
+
+    let mut retuned = seeded_app();
+    retuned
+        .world
+        .resource_mut::<SimulationConfig>()
+        .population_cap += 1;
+    retuned.update();
+    assert!(matches!(
+        restore_world(&mut retuned.world, &bytes),
+        Err(WorldSaveError::ConfigMismatch { .. })
+    ));
+}
@@ -19,6 +19,12 @@ pub const COMMAND_VERBS: &[CommandVerbHelp] = &[
         summary: "Advance the simulation by one or more turns (default 1).",
         usage: "turn [steps]",
     },
+    CommandVerbHelp {
+        verb: "advance",
+        aliases: &["advance_turns"],
+        summary: "Resolve several turns in one request (capped by the server), stopping early on victory.",
+        usage: "advance <count>",
+    },
     CommandVerbHelp {
         verb: "map_size",
         aliases: &[],
@@ -43,6 +49,12 @@ pub const COMMAND_VERBS: &[CommandVerbHelp] = &[
         summary: "Submit orders for a faction (currently only 'ready').",
         usage: "order <faction_id> [ready]",
     },
+    CommandVerbHelp {
+        verb: "undo_order",
+        aliases: &["undo"],
+        summary: "Withdraw a faction's most recent order that has not resolved yet.",
+        usage: "undo_order <faction_id>",
+    },
     CommandVerbHelp {
         verb: "rollback",
         aliases: &[],
@@ -73,6 +85,12 @@ pub const COMMAND_VERBS: &[CommandVerbHelp] = &[
         summary: "Boost an influencer's specific support channel.",
         usage: "support_channel <id> <channel> [magnitude]",
     },
+    CommandVerbHelp {
+        verb: "influence",
+        aliases: &["influencer_action"],
+        summary: "Back or suppress an influencer through one channel; magnitude in hundredths (default 100, max 500).",
+        usage: "influence <support|suppress> <id> <channel> [magnitude]",
+    },
     CommandVerbHelp {
         verb: "spawn_influencer",
         aliases: &[],
@@ -91,6 +109,12 @@ pub const COMMAND_VERBS: &[CommandVerbHelp] = &[
         summary: "Adjust or set the counter-intel reserve for a faction.",
         usage: "counterintel_budget <faction_id> [reserve <value>|delta <value>|<value>]",
     },
+    CommandVerbHelp {
+        verb: "knowledge_posture",
+        aliases: &["posture"],
+        summary: "Set a knowledge-ledger entry's security posture (stricter postures cost counter-intel reserve each turn).",
+        usage: "knowledge_posture <faction_id> <discovery_id> <minimal|standard|hardened|black_vault>",
+    },
     CommandVerbHelp {
         verb: "queue_espionage_mission",
         aliases: &["queue_mission"],
@@ -121,6 +145,24 @@ pub const COMMAND_VERBS: &[CommandVerbHelp] = &[
         summary: "Spawn a crisis by archetype for the specified faction (default 0).",
         usage: "spawn_crisis <archetype_id> [faction_id]",
     },
+    CommandVerbHelp {
+        verb: "contain_crisis",
+        aliases: &["contain"],
+        summary: "Spend the faction's stockpile (default 0) on effort (1-10) to damp an active crisis' growth for a few turns.",
+        usage: "contain_crisis <crisis_id> <effort> [faction_id]",
+    },
+    CommandVerbHelp {
+        verb: "spawn_herd",
+        aliases: &[],
+        summary: "Debug: seat a herd of a known fauna species (key or display name) at a tile.",
+        usage: "spawn_herd <species> <x> <y> <biomass>",
+    },
+    CommandVerbHelp {
+        verb: "cull_herd",
+        aliases: &[],
+        summary: "Debug: remove a herd from the map by id.",
+        usage: "cull_herd <herd_id>",
+    },
     CommandVerbHelp {
         verb: "start_profile",
         aliases: &["scenario"],
@@ -235,11 +277,18 @@ pub const COMMAND_VERBS: &[CommandVerbHelp] = &[
         summary: "Write the current world map (terrain + seed) to a JSON file for inspection and tests.",
         usage: "export_map [path]",
     },
+    CommandVerbHelp {
+        verb: "inspect_region",
+        aliases: &["inspect"],
+        summary: "Log overlay means/extremes, population, dominant terrain and crises inside a rectangle.",
+        usage: "inspect_region <x0> <y0> <x1> <y1>",
+    },
 ];
 
 use crate::{
-    CancelScope, CommandPayload, CorruptionSubsystem, InfluenceScopeKind, OrdersDirective,
-    ReloadConfigKind, SecurityPolicyKind, SupportChannel,
+    CancelScope, CommandPayload, CorruptionSubsystem, InfluenceScopeKind, KnowledgeSecurityPosture,
+    OrdersDirective, ReloadConfigKind, SecurityPolicyKind, SupportAction, SupportChannel,
+    CONTAIN_CRISIS_EFFORT_MAX, INFLUENCER_ACTION_MAGNITUDE_MAX, INFLUENCER_ACTION_MAGNITUDE_SCALE,
 };
 
 #[derive(Debug, Error)]
@@ -269,6 +318,15 @@ pub enum CommandParseError {
     },
     #[error("invalid support channel '{0}'")]
     InvalidSupportChannel(String),
+    #[error("invalid support action '{0}' (expected support or suppress)")]
+    InvalidSupportAction(String),
+    #[error("{context} {value} out of range {min}..={max}")]
+    OutOfRange {
+        value: i64,
+        context: &'static str,
+        min: i64,
+        max: i64,
+    },
     #[error("invalid influence scope '{0}'")]
     InvalidScope(String),
     #[error("invalid corruption subsystem '{0}'")]
@@ -277,6 +335,8 @@ pub enum CommandParseError {
     InvalidDirective(String),
     #[error("invalid security policy '{0}'")]
     InvalidSecurityPolicy(String),
+    #[error("invalid knowledge posture '{0}'")]
+    InvalidKnowledgePosture(String),
     #[error("unexpected token '{0}'")]
     UnexpectedToken(String),
 }
@@ -299,6 +359,13 @@ pub fn parse_command_line(input: &str) -> Result<CommandPayload, CommandParseErr
             let steps = parse_u32(steps_str, "turn steps")?;
             Ok(CommandPayload::Turn { steps })
         }
+        "advance" | "advance_turns" => {
+            let count_str = parts
+                .next()
+                .ok_or(CommandParseError::MissingArgument("count"))?;
+            let count = parse_u32(count_str, "advance count")?;
+            Ok(CommandPayload::AdvanceTurns { count })
+        }
         "map_size" => {
             let width_str = parts
                 .next()
@@ -363,6 +430,14 @@ pub fn parse_command_line(input: &str) -> Result<CommandPayload, CommandParseErr
                 directive,
             })
         }
+        "undo_order" | "undo" => {
+            let faction_str = parts
+                .next()
+                .ok_or(CommandParseError::MissingArgument("faction"))?;
+            Ok(CommandPayload::UndoOrder {
+                faction_id: parse_u32(faction_str, "undo faction")?,
+            })
+        }
         "rollback" => {
             let tick_str = parts
                 .next()
@@ -416,6 +491,37 @@ pub fn parse_command_line(input: &str) -> Result<CommandPayload, CommandParseErr
                 magnitude,
             })
         }
+        "influence" | "influencer_action" => {
+            let action_str = parts
+                .next()
+                .ok_or(CommandParseError::MissingArgument("action"))?;
+            let id_str = parts
+                .next()
+                .ok_or(CommandParseError::MissingArgument("id"))?;
+            let channel_str = parts
+                .next()
+                .ok_or(CommandParseError::MissingArgument("channel"))?;
+            let default_magnitude = INFLUENCER_ACTION_MAGNITUDE_SCALE.to_string();
+            let magnitude_str = parts.next().unwrap_or(&default_magnitude);
+            let action = parse_support_action(action_str)?;
+            let id = parse_u32(id_str, "influence id")?;
+            let channel = parse_support_channel(channel_str)?;
+            let magnitude = parse_i64(magnitude_str, "influence magnitude")?;
+            if !(1..=INFLUENCER_ACTION_MAGNITUDE_MAX).contains(&magnitude) {
+                return Err(CommandParseError::OutOfRange {
+                    value: magnitude,
+                    context: "influence magnitude",
+                    min: 1,
+                    max: INFLUENCER_ACTION_MAGNITUDE_MAX,
+                });
+            }
+            Ok(CommandPayload::InfluencerAction {
+                id,
+                action,
+                channel,
+                magnitude,
+            })
+        }
         "counterintel_policy" => {
             let faction_str = parts
                 .next()
@@ -427,6 +533,22 @@ pub fn parse_command_line(input: &str) -> Result<CommandPayload, CommandParseErr
             let policy = parse_security_policy(policy_str)?;
             Ok(CommandPayload::UpdateCounterIntelPolicy { faction, policy })
         }
+        "knowledge_posture" | "posture" => {
+            let faction_str = parts
+                .next()
+                .ok_or(CommandParseError::MissingArgument("faction"))?;
+            let discovery_str = parts
+                .next()
+                .ok_or(CommandParseError::MissingArgument("discovery"))?;
+            let posture_str = parts
+                .next()
+                .ok_or(CommandParseError::MissingArgument("posture"))?;
+            Ok(CommandPayload::SetKnowledgePosture {
+                faction: parse_u32(faction_str, "knowledge posture faction")?,
+                discovery: parse_u32(discovery_str, "knowledge posture discovery")?,
+                posture: parse_knowledge_posture(posture_str)?,
+            })
+        }
         "counterintel_budget" => {
             let faction_str = parts
                 .next()
@@ -640,6 +762,57 @@ pub fn parse_command_line(input: &str) -> Result<CommandPayload, CommandParseErr
                 archetype_id: archetype_id.to_string(),
             })
         }
+        "contain_crisis" | "contain" => {
+            let id_str = parts
+                .next()
+                .ok_or(CommandParseError::MissingArgument("crisis_id"))?;
+            let effort_str = parts
+                .next()
+                .ok_or(CommandParseError::MissingArgument("effort"))?;
+            let faction_str = parts.next().unwrap_or("0");
+            let crisis_id = parse_u64(id_str, "crisis id")?;
+            let effort = parse_i64(effort_str, "containment effort")?;
+            let faction_id = parse_u32(faction_str, "crisis faction")?;
+            if !(1..=CONTAIN_CRISIS_EFFORT_MAX).contains(&effort) {
+                return Err(CommandParseError::OutOfRange {
+                    value: effort,
+                    context: "containment effort",
+                    min: 1,
+                    max: CONTAIN_CRISIS_EFFORT_MAX,
+                });
+            }
+            Ok(CommandPayload::ContainCrisis {
+                faction_id,
+                crisis_id,
+                effort,
+            })
+        }
+        "spawn_herd" => {
+            let species = parts
+                .next()
+                .ok_or(CommandParseError::MissingArgument("species"))?;
+            let x_str = parts
+                .next()
+                .ok_or(CommandParseError::MissingArgument("x"))?;
+            let y_str = parts
+                .next()
+                .ok_or(CommandParseError::MissingArgument("y"))?;
+            let biomass_str = parts
+                .next()
+                .ok_or(CommandParseError::MissingArgument("biomass"))?;
+            Ok(CommandPayload::SpawnHerd {
+                species: species.to_string(),
+                x: parse_u32(x_str, "herd x")?,
+                y: parse_u32(y_str, "herd y")?,
+                biomass: parse_f32(biomass_str, "herd biomass")?,
+            })
+        }
+        "cull_herd" => {
+            let id = parts
+                .next()
+                .ok_or(CommandParseError::MissingArgument("herd_id"))?;
+            Ok(CommandPayload::CullHerd { id: id.to_string() })
+        }
         "start_profile" | "scenario" => {
             let profile_id = parts
                 .next()
@@ -1093,6 +1266,20 @@ pub fn parse_command_line(input: &str) -> Result<CommandPayload, CommandParseErr
             };
             Ok(CommandPayload::ExportMap { path })
         }
+        "inspect" | "inspect_region" => {
+            let mut corner = |name: &'static str, context: &'static str| {
+                parts
+                    .next()
+                    .ok_or(CommandParseError::MissingArgument(name))
+                    .and_then(|value| parse_u32(value, context))
+            };
+            Ok(CommandPayload::InspectRegion {
+                x0: corner("x0", "inspect_region x0")?,
+                y0: corner("y0", "inspect_region y0")?,
+                x1: corner("x1", "inspect_region x1")?,
+                y1: corner("y1", "inspect_region y1")?,
+            })
+        }
         other => Err(CommandParseError::UnknownCommand(other.to_string())),
     }
 }
@@ -1180,6 +1367,14 @@ fn parse_support_channel(token: &str) -> Result<SupportChannel, CommandParseErro
     }
 }
 
+fn parse_support_action(token: &str) -> Result<SupportAction, CommandParseError> {
+    match token.to_ascii_lowercase().as_str() {
+        "support" | "back" => Ok(SupportAction::Support),
+        "suppress" | "oppose" => Ok(SupportAction::Suppress),
+        other => Err(CommandParseError::InvalidSupportAction(other.to_string())),
+    }
+}
+
 fn parse_security_policy(token: &str) -> Result<SecurityPolicyKind, CommandParseError> {
     match token.to_ascii_lowercase().as_str() {
         "lenient" | "light" | "open" => Ok(SecurityPolicyKind::Lenient),
@@ -1190,6 +1385,18 @@ fn parse_security_policy(token: &str) -> Result<SecurityPolicyKind, CommandParse
     }
 }
 
+fn parse_knowledge_posture(token: &str) -> Result<KnowledgeSecurityPosture, CommandParseError> {
+    match token.to_ascii_lowercase().as_str() {
+        "minimal" | "open" => Ok(KnowledgeSecurityPosture::Minimal),
+        "standard" | "normal" => Ok(KnowledgeSecurityPosture::Standard),
+        "hardened" | "secure" => Ok(KnowledgeSecurityPosture::Hardened),
+        "black_vault" | "blackvault" | "vault" => Ok(KnowledgeSecurityPosture::BlackVault),
+        other => Err(CommandParseError::InvalidKnowledgePosture(
+            other.to_string(),
+        )),
+    }
+}
+
 fn parse_corruption_subsystem(token: &str) -> Result<CorruptionSubsystem, CommandParseError> {
     match token {
         "logistics" | "log" | "supply" => Ok(CorruptionSubsystem::Logistics),
@@ -1323,6 +1530,162 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn parse_inspect_region_command() {
+        assert_eq!(
+            parse_command_line("inspect_region 2 3 10 12").unwrap(),
+            CommandPayload::InspectRegion {
+                x0: 2,
+                y0: 3,
+                x1: 10,
+                y1: 12,
+            }
+        );
+        // Inverted corners pass through untouched; the server orders and clamps them.
+        assert_eq!(
+            parse_command_line("inspect 10 12 2 3").unwrap(),
+            CommandPayload::InspectRegion {
+                x0: 10,
+                y0: 12,
+                x1: 2,
+                y1: 3,
+            }
+        );
+        assert!(matches!(
+            parse_command_line("inspect_region 1 2 3"),
+            Err(CommandParseError::MissingArgument("y1"))
+        ));
+        assert!(matches!(
+            parse_command_line("inspect_region 1 -2 3 4"),
+            Err(CommandParseError::InvalidInteger { .. })
+        ));
+    }
+
+    #[test]
+    fn parse_advance_turns_command() {
+        assert_eq!(
+            parse_command_line("advance 10").unwrap(),
+            CommandPayload::AdvanceTurns { count: 10 }
+        );
+        assert_eq!(
+            parse_command_line("ADVANCE_TURNS 3").unwrap(),
+            CommandPayload::AdvanceTurns { count: 3 }
+        );
+        assert!(matches!(
+            parse_command_line("advance"),
+            Err(CommandParseError::MissingArgument("count"))
+        ));
+        assert!(matches!(
+            parse_command_line("advance ten"),
+            Err(CommandParseError::InvalidInteger { .. })
+        ));
+    }
+
+    #[test]
+    fn parse_spawn_and_cull_herd_commands() {
+        assert_eq!(
+            parse_command_line("spawn_herd deer 12 7 450.5").unwrap(),
+            CommandPayload::SpawnHerd {
+                species: "deer".to_string(),
+                x: 12,
+                y: 7,
+                biomass: 450.5,
+            }
+        );
+        assert!(matches!(
+            parse_command_line("spawn_herd deer 12 7"),
+            Err(CommandParseError::MissingArgument("biomass"))
+        ));
+        assert!(matches!(
+            parse_command_line("spawn_herd deer twelve 7 100"),
+            Err(CommandParseError::InvalidInteger { .. })
+        ));
+        assert_eq!(
+            parse_command_line("cull_herd debug_deer_00").unwrap(),
+            CommandPayload::CullHerd {
+                id: "debug_deer_00".to_string(),
+            }
+        );
+        assert!(matches!(
+            parse_command_line("cull_herd"),
+            Err(CommandParseError::MissingArgument("herd_id"))
+        ));
+    }
+
+    #[test]
+    fn parse_influence_command() {
+        assert_eq!(
+            parse_command_line("influence support 3 peer 250").unwrap(),
+            CommandPayload::InfluencerAction {
+                id: 3,
+                action: SupportAction::Support,
+                channel: SupportChannel::Peer,
+                magnitude: 250,
+            }
+        );
+        assert_eq!(
+            parse_command_line("influencer_action suppress 7 civic").unwrap(),
+            CommandPayload::InfluencerAction {
+                id: 7,
+                action: SupportAction::Suppress,
+                channel: SupportChannel::Humanitarian,
+                magnitude: INFLUENCER_ACTION_MAGNITUDE_SCALE,
+            }
+        );
+        assert!(matches!(
+            parse_command_line("influence cheer 3 peer"),
+            Err(CommandParseError::InvalidSupportAction(_))
+        ));
+        assert!(matches!(
+            parse_command_line("influence support 3"),
+            Err(CommandParseError::MissingArgument("channel"))
+        ));
+        for magnitude in ["0", "-5", "501"] {
+            assert!(matches!(
+                parse_command_line(&format!("influence support 3 peer {magnitude}")),
+                Err(CommandParseError::OutOfRange { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn parse_contain_crisis_command() {
+        assert_eq!(
+            parse_command_line("contain_crisis 4 3").unwrap(),
+            CommandPayload::ContainCrisis {
+                faction_id: 0,
+                crisis_id: 4,
+                effort: 3,
+            }
+        );
+        assert_eq!(
+            parse_command_line("contain 12 10 2").unwrap(),
+            CommandPayload::ContainCrisis {
+                faction_id: 2,
+                crisis_id: 12,
+                effort: CONTAIN_CRISIS_EFFORT_MAX,
+            }
+        );
+        assert!(matches!(
+            parse_command_line("contain_crisis 4"),
+            Err(CommandParseError::MissingArgument("effort"))
+        ));
+        assert!(matches!(
+            parse_command_line("contain_crisis plague 2"),
+            Err(CommandParseError::InvalidInteger { .. })
+        ));
+        assert!(matches!(
+            parse_command_line("contain_crisis 4 3 north"),
+            Err(CommandParseError::InvalidInteger { .. })
+        ));
+        for effort in ["0", "-1", "11"] {
+            assert!(matches!(
+                parse_command_line(&format!("contain_crisis 4 {effort}")),
+                Err(CommandParseError::OutOfRange { .. })
+            ));
+        }
+    }
+
     /// `tame` **replaced** the `domesticate` early-claim — it is not an alias for it. The claim
     /// existed to skip the taming investment, which is the whole decision, so the verb is gone: a
     /// script still sending it must fail loudly rather than silently doing something adjacent.
@@ -1582,6 +1945,50 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parse_undo_order_command() {
+        assert_eq!(
+            parse_command_line("undo_order 3").unwrap(),
+            CommandPayload::UndoOrder { faction_id: 3 }
+        );
+        assert_eq!(
+            parse_command_line("undo 0").unwrap(),
+            CommandPayload::UndoOrder { faction_id: 0 }
+        );
+        assert!(matches!(
+            parse_command_line("undo_order"),
+            Err(CommandParseError::MissingArgument("faction"))
+        ));
+    }
+
+    #[test]
+    fn parse_knowledge_posture_command() {
+        assert_eq!(
+            parse_command_line("knowledge_posture 2 17 black_vault").unwrap(),
+            CommandPayload::SetKnowledgePosture {
+                faction: 2,
+                discovery: 17,
+                posture: KnowledgeSecurityPosture::BlackVault,
+            }
+        );
+        assert_eq!(
+            parse_command_line("posture 0 4 Hardened").unwrap(),
+            CommandPayload::SetKnowledgePosture {
+                faction: 0,
+                discovery: 4,
+                posture: KnowledgeSecurityPosture::Hardened,
+            }
+        );
+        assert!(matches!(
+            parse_command_line("knowledge_posture 2 17"),
+            Err(CommandParseError::MissingArgument("posture"))
+        ));
+        assert!(matches!(
+            parse_command_line("knowledge_posture 2 17 paranoid"),
+            Err(CommandParseError::InvalidKnowledgePosture(token)) if token == "paranoid"
+        ));
+    }
+
     #[test]
     fn parse_counterintel_budget_command() {
         let payload = parse_command_line("counterintel_budget 2 reserve 5.5").unwrap();
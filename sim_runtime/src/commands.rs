@@ -3,7 +3,7 @@ use std::convert::TryFrom;
 use prost::Message;
 use thiserror::Error;
 
-use crate::{CorruptionSubsystem, InfluenceScopeKind};
+use crate::{CorruptionSubsystem, InfluenceScopeKind, KnowledgeSecurityPosture};
 
 mod proto {
     include!(concat!(env!("OUT_DIR"), "/shadow_scale.commands.rs"));
@@ -25,6 +25,10 @@ pub enum CommandPayload {
     Turn {
         steps: u32,
     },
+    /// Resolve `count` turns back to back (capped server-side), stopping early on victory.
+    AdvanceTurns {
+        count: u32,
+    },
     ResetMap {
         width: u32,
         height: u32,
@@ -37,6 +41,10 @@ pub enum CommandPayload {
         faction_id: u32,
         directive: OrdersDirective,
     },
+    /// Withdraw the faction's most recently submitted order that has not resolved yet.
+    UndoOrder {
+        faction_id: u32,
+    },
     Rollback {
         tick: u64,
     },
@@ -57,6 +65,14 @@ pub enum CommandPayload {
         channel: SupportChannel,
         magnitude: f32,
     },
+    /// Back or suppress influencer `id` through `channel`. `magnitude` is in hundredths
+    /// ([`INFLUENCER_ACTION_MAGNITUDE_SCALE`]) and valid in `1..=`[`INFLUENCER_ACTION_MAGNITUDE_MAX`].
+    InfluencerAction {
+        id: u32,
+        action: SupportAction,
+        channel: SupportChannel,
+        magnitude: i64,
+    },
     SpawnInfluencer {
         scope: Option<InfluenceScopeKind>,
         generation: Option<u16>,
@@ -91,6 +107,13 @@ pub enum CommandPayload {
         reserve: Option<f32>,
         delta: Option<f32>,
     },
+    /// Change one knowledge-ledger entry's security posture; stricter postures cost counter-intel
+    /// reserve every turn they are held.
+    SetKnowledgePosture {
+        faction: u32,
+        discovery: u32,
+        posture: KnowledgeSecurityPosture,
+    },
     ReloadConfig {
         kind: ReloadConfigKind,
         path: Option<String>,
@@ -102,6 +125,24 @@ pub enum CommandPayload {
         faction_id: u32,
         archetype_id: String,
     },
+    /// Spend `effort` (1..=[`CONTAIN_CRISIS_EFFORT_MAX`]) on containing the active crisis
+    /// `crisis_id`, damping its growth for a few turns at a cost to `faction_id`'s stockpile.
+    ContainCrisis {
+        faction_id: u32,
+        crisis_id: u64,
+        effort: i64,
+    },
+    /// Debug: seat a herd of a known fauna species at a tile.
+    SpawnHerd {
+        species: String,
+        x: u32,
+        y: u32,
+        biomass: f32,
+    },
+    /// Debug: remove a herd from the registry.
+    CullHerd {
+        id: String,
+    },
     SetStartProfile {
         profile_id: String,
     },
@@ -216,6 +257,14 @@ pub enum CommandPayload {
     ExportMap {
         path: Option<String>,
     },
+    /// Debug: aggregate stats over the rectangle spanned by two corners (any order; clamped
+    /// server-side to the map).
+    InspectRegion {
+        x0: u32,
+        y0: u32,
+        x1: u32,
+        y1: u32,
+    },
     /// Boot-idle new game: generate a world on demand (the server boots with none). `seed == 0`
     /// randomizes the map seed, mirroring `ResetMap`; an unknown `profile_id` is rejected server-side.
     /// Proto field 43.
@@ -226,6 +275,12 @@ pub enum CommandPayload {
         seed: u64,
         profile_id: String,
     },
+    /// Connection handshake (see [`crate::handshake`]); consumed by the listener, never queued.
+    /// Proto field 51.
+    Handshake {
+        protocol_version: u32,
+        token: Option<String>,
+    },
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -301,6 +356,21 @@ pub enum SupportChannel {
     Humanitarian,
 }
 
+/// Whether an [`CommandPayload::InfluencerAction`] backs or works against the influencer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SupportAction {
+    Support,
+    Suppress,
+}
+
+/// `InfluencerAction` magnitudes are fixed-point hundredths: `100` is a magnitude of 1.0.
+pub const INFLUENCER_ACTION_MAGNITUDE_SCALE: i64 = 100;
+/// Largest accepted `InfluencerAction` magnitude (5.0).
+pub const INFLUENCER_ACTION_MAGNITUDE_MAX: i64 = 5 * INFLUENCER_ACTION_MAGNITUDE_SCALE;
+
+/// Largest accepted `ContainCrisis` effort.
+pub const CONTAIN_CRISIS_EFFORT_MAX: i64 = 10;
+
 /// Counter-intelligence security posture controls.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum SecurityPolicyKind {
@@ -310,6 +380,14 @@ pub enum SecurityPolicyKind {
     Crisis,
 }
 
+/// The server's acknowledgement of one command frame, echoing the request's `correlation_id`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommandResponse {
+    pub correlation_id: Option<u64>,
+    pub accepted: bool,
+    pub error: Option<String>,
+}
+
 /// Error returned when encoding a command envelope fails.
 #[derive(Debug, Error)]
 pub enum CommandEncodeError {
@@ -349,12 +427,25 @@ impl CommandEnvelope {
         Self::try_from_proto(proto)
     }
 
+    /// The `correlation_id` of a frame, read even when the payload itself fails to decode so a
+    /// rejection can still be routed back to its request.
+    pub fn peek_correlation_id(bytes: &[u8]) -> Option<u64> {
+        pb::CommandEnvelope::decode(bytes)
+            .ok()
+            .and_then(|proto| proto.correlation_id)
+    }
+
     /// Convert the high-level envelope into its protobuf representation.
     pub fn to_proto(&self) -> pb::CommandEnvelope {
         let command = Some(match &self.payload {
             CommandPayload::Turn { steps } => {
                 pb::command_envelope::Command::Turn(pb::TurnCommand { steps: *steps })
             }
+            CommandPayload::AdvanceTurns { count } => {
+                pb::command_envelope::Command::AdvanceTurns(pb::AdvanceTurnsCommand {
+                    count: *count,
+                })
+            }
             CommandPayload::ResetMap { width, height } => {
                 pb::command_envelope::Command::ResetMap(pb::ResetMapCommand {
                     width: *width,
@@ -374,6 +465,11 @@ impl CommandEnvelope {
                 faction_id: *faction_id,
                 directive: orders_directive_to_proto(*directive) as i32,
             }),
+            CommandPayload::UndoOrder { faction_id } => {
+                pb::command_envelope::Command::UndoOrder(pb::UndoOrderCommand {
+                    faction_id: *faction_id,
+                })
+            }
             CommandPayload::Rollback { tick } => {
                 pb::command_envelope::Command::Rollback(pb::RollbackCommand { tick: *tick })
             }
@@ -406,6 +502,17 @@ impl CommandEnvelope {
                     magnitude: *magnitude,
                 })
             }
+            CommandPayload::InfluencerAction {
+                id,
+                action,
+                channel,
+                magnitude,
+            } => pb::command_envelope::Command::InfluencerAction(pb::InfluencerActionCommand {
+                id: *id,
+                action: support_action_to_proto(*action) as i32,
+                channel: support_channel_to_proto(*channel) as i32,
+                magnitude: *magnitude,
+            }),
             CommandPayload::SpawnInfluencer { scope, generation } => {
                 pb::command_envelope::Command::SpawnInfluencer(pb::SpawnInfluencerCommand {
                     scope: scope.map(influence_scope_to_proto).map(|v| v as i32),
@@ -482,6 +589,17 @@ impl CommandEnvelope {
                     delta: *delta,
                 },
             ),
+            CommandPayload::SetKnowledgePosture {
+                faction,
+                discovery,
+                posture,
+            } => {
+                pb::command_envelope::Command::SetKnowledgePosture(pb::SetKnowledgePostureCommand {
+                    faction: *faction,
+                    discovery: *discovery,
+                    posture: knowledge_posture_to_proto(*posture) as i32,
+                })
+            }
             CommandPayload::ReloadConfig { kind, path } => {
                 pb::command_envelope::Command::ReloadConfig(pb::ReloadConfigCommand {
                     kind: reload_config_kind_to_proto(*kind) as i32,
@@ -500,6 +618,29 @@ impl CommandEnvelope {
                 faction: *faction_id,
                 archetype_id: archetype_id.clone(),
             }),
+            CommandPayload::ContainCrisis {
+                faction_id,
+                crisis_id,
+                effort,
+            } => pb::command_envelope::Command::ContainCrisis(pb::ContainCrisisCommand {
+                crisis_id: *crisis_id,
+                effort: *effort,
+                faction: *faction_id,
+            }),
+            CommandPayload::SpawnHerd {
+                species,
+                x,
+                y,
+                biomass,
+            } => pb::command_envelope::Command::SpawnHerd(pb::SpawnHerdCommand {
+                species: species.clone(),
+                x: *x,
+                y: *y,
+                biomass: *biomass,
+            }),
+            CommandPayload::CullHerd { id } => {
+                pb::command_envelope::Command::CullHerd(pb::CullHerdCommand { id: id.clone() })
+            }
             CommandPayload::SetStartProfile { profile_id } => {
                 pb::command_envelope::Command::SetStartProfile(pb::SetStartProfileCommand {
                     profile_id: profile_id.clone(),
@@ -700,6 +841,14 @@ impl CommandEnvelope {
                     path: path.clone(),
                 })
             }
+            CommandPayload::InspectRegion { x0, y0, x1, y1 } => {
+                pb::command_envelope::Command::InspectRegion(pb::InspectRegionCommand {
+                    x0: *x0,
+                    y0: *y0,
+                    x1: *x1,
+                    y1: *y1,
+                })
+            }
             CommandPayload::NewGame {
                 preset_id,
                 width,
@@ -713,6 +862,13 @@ impl CommandEnvelope {
                 seed: *seed,
                 profile_id: profile_id.clone(),
             }),
+            CommandPayload::Handshake {
+                protocol_version,
+                token,
+            } => pb::command_envelope::Command::Handshake(pb::HandshakeCommand {
+                protocol_version: *protocol_version,
+                token: token.clone(),
+            }),
         });
 
         pb::CommandEnvelope {
@@ -725,6 +881,9 @@ impl CommandEnvelope {
     pub fn try_from_proto(proto: pb::CommandEnvelope) -> Result<Self, CommandDecodeError> {
         let payload = match proto.command.ok_or(CommandDecodeError::MissingPayload)? {
             pb::command_envelope::Command::Turn(cmd) => CommandPayload::Turn { steps: cmd.steps },
+            pb::command_envelope::Command::AdvanceTurns(cmd) => {
+                CommandPayload::AdvanceTurns { count: cmd.count }
+            }
             pb::command_envelope::Command::ResetMap(cmd) => CommandPayload::ResetMap {
                 width: cmd.width,
                 height: cmd.height,
@@ -737,6 +896,9 @@ impl CommandEnvelope {
                 faction_id: cmd.faction_id,
                 directive: OrdersDirective::try_from(cmd.directive)?,
             },
+            pb::command_envelope::Command::UndoOrder(cmd) => CommandPayload::UndoOrder {
+                faction_id: cmd.faction_id,
+            },
             pb::command_envelope::Command::Rollback(cmd) => {
                 CommandPayload::Rollback { tick: cmd.tick }
             }
@@ -764,6 +926,14 @@ impl CommandEnvelope {
                     magnitude: cmd.magnitude,
                 }
             }
+            pb::command_envelope::Command::InfluencerAction(cmd) => {
+                CommandPayload::InfluencerAction {
+                    id: cmd.id,
+                    action: SupportAction::try_from(cmd.action)?,
+                    channel: SupportChannel::try_from(cmd.channel)?,
+                    magnitude: cmd.magnitude,
+                }
+            }
             pb::command_envelope::Command::SpawnInfluencer(cmd) => {
                 let scope = match cmd.scope {
                     Some(value) => Some(influence_scope_from_proto(value)?),
@@ -860,6 +1030,13 @@ impl CommandEnvelope {
                     delta: cmd.delta,
                 }
             }
+            pb::command_envelope::Command::SetKnowledgePosture(cmd) => {
+                CommandPayload::SetKnowledgePosture {
+                    faction: cmd.faction,
+                    discovery: cmd.discovery,
+                    posture: knowledge_posture_from_proto(cmd.posture)?,
+                }
+            }
             pb::command_envelope::Command::ReloadConfig(cmd) => {
                 let kind = reload_config_kind_from_proto(cmd.kind)?;
                 CommandPayload::ReloadConfig {
@@ -876,6 +1053,18 @@ impl CommandEnvelope {
                 faction_id: cmd.faction,
                 archetype_id: cmd.archetype_id,
             },
+            pb::command_envelope::Command::ContainCrisis(cmd) => CommandPayload::ContainCrisis {
+                faction_id: cmd.faction,
+                crisis_id: cmd.crisis_id,
+                effort: cmd.effort,
+            },
+            pb::command_envelope::Command::SpawnHerd(cmd) => CommandPayload::SpawnHerd {
+                species: cmd.species,
+                x: cmd.x,
+                y: cmd.y,
+                biomass: cmd.biomass,
+            },
+            pb::command_envelope::Command::CullHerd(cmd) => CommandPayload::CullHerd { id: cmd.id },
             pb::command_envelope::Command::SetStartProfile(cmd) => {
                 CommandPayload::SetStartProfile {
                     profile_id: cmd.profile_id,
@@ -1000,6 +1189,12 @@ impl CommandEnvelope {
             pb::command_envelope::Command::ExportMap(cmd) => {
                 CommandPayload::ExportMap { path: cmd.path }
             }
+            pb::command_envelope::Command::InspectRegion(cmd) => CommandPayload::InspectRegion {
+                x0: cmd.x0,
+                y0: cmd.y0,
+                x1: cmd.x1,
+                y1: cmd.y1,
+            },
             pb::command_envelope::Command::NewGame(cmd) => CommandPayload::NewGame {
                 preset_id: cmd.preset_id,
                 width: cmd.width,
@@ -1007,6 +1202,10 @@ impl CommandEnvelope {
                 seed: cmd.seed,
                 profile_id: cmd.profile_id,
             },
+            pb::command_envelope::Command::Handshake(cmd) => CommandPayload::Handshake {
+                protocol_version: cmd.protocol_version,
+                token: cmd.token,
+            },
         };
 
         Ok(CommandEnvelope {
@@ -1016,6 +1215,44 @@ impl CommandEnvelope {
     }
 }
 
+impl CommandResponse {
+    pub fn accepted(correlation_id: Option<u64>) -> Self {
+        Self {
+            correlation_id,
+            accepted: true,
+            error: None,
+        }
+    }
+
+    pub fn rejected(correlation_id: Option<u64>, error: impl Into<String>) -> Self {
+        Self {
+            correlation_id,
+            accepted: false,
+            error: Some(error.into()),
+        }
+    }
+
+    /// Encode the response into a protobuf binary frame.
+    pub fn encode_to_vec(&self) -> Vec<u8> {
+        pb::CommandResponse {
+            correlation_id: self.correlation_id,
+            accepted: self.accepted,
+            error: self.error.clone().unwrap_or_default(),
+        }
+        .encode_to_vec()
+    }
+
+    /// Decode a response from a protobuf binary frame.
+    pub fn decode(bytes: &[u8]) -> Result<Self, CommandDecodeError> {
+        let proto = pb::CommandResponse::decode(bytes)?;
+        Ok(Self {
+            correlation_id: proto.correlation_id,
+            accepted: proto.accepted,
+            error: (!proto.error.is_empty()).then_some(proto.error),
+        })
+    }
+}
+
 impl TryFrom<i32> for OrdersDirective {
     type Error = CommandDecodeError;
 
@@ -1030,6 +1267,21 @@ impl TryFrom<i32> for OrdersDirective {
     }
 }
 
+impl TryFrom<i32> for SupportAction {
+    type Error = CommandDecodeError;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        match pb::SupportAction::try_from(value) {
+            Ok(pb::SupportAction::Support) => Ok(SupportAction::Support),
+            Ok(pb::SupportAction::Suppress) => Ok(SupportAction::Suppress),
+            _ => Err(CommandDecodeError::InvalidEnum {
+                field: "SupportAction",
+                value,
+            }),
+        }
+    }
+}
+
 impl TryFrom<i32> for SupportChannel {
     type Error = CommandDecodeError;
 
@@ -1068,6 +1320,13 @@ fn support_channel_to_proto(value: SupportChannel) -> pb::SupportChannel {
     }
 }
 
+fn support_action_to_proto(value: SupportAction) -> pb::SupportAction {
+    match value {
+        SupportAction::Support => pb::SupportAction::Support,
+        SupportAction::Suppress => pb::SupportAction::Suppress,
+    }
+}
+
 fn security_policy_kind_to_proto(value: SecurityPolicyKind) -> pb::SecurityPolicyKind {
     match value {
         SecurityPolicyKind::Lenient => pb::SecurityPolicyKind::Lenient,
@@ -1077,6 +1336,15 @@ fn security_policy_kind_to_proto(value: SecurityPolicyKind) -> pb::SecurityPolic
     }
 }
 
+fn knowledge_posture_to_proto(value: KnowledgeSecurityPosture) -> pb::KnowledgePostureKind {
+    match value {
+        KnowledgeSecurityPosture::Minimal => pb::KnowledgePostureKind::Minimal,
+        KnowledgeSecurityPosture::Standard => pb::KnowledgePostureKind::Standard,
+        KnowledgeSecurityPosture::Hardened => pb::KnowledgePostureKind::Hardened,
+        KnowledgeSecurityPosture::BlackVault => pb::KnowledgePostureKind::BlackVault,
+    }
+}
+
 fn reload_config_kind_to_proto(kind: ReloadConfigKind) -> pb::ReloadConfigKind {
     match kind {
         ReloadConfigKind::Simulation => pb::ReloadConfigKind::Simulation,
@@ -1123,6 +1391,21 @@ fn security_policy_kind_from_proto(value: i32) -> Result<SecurityPolicyKind, Com
     }
 }
 
+fn knowledge_posture_from_proto(
+    value: i32,
+) -> Result<KnowledgeSecurityPosture, CommandDecodeError> {
+    match pb::KnowledgePostureKind::try_from(value) {
+        Ok(pb::KnowledgePostureKind::Minimal) => Ok(KnowledgeSecurityPosture::Minimal),
+        Ok(pb::KnowledgePostureKind::Standard) => Ok(KnowledgeSecurityPosture::Standard),
+        Ok(pb::KnowledgePostureKind::Hardened) => Ok(KnowledgeSecurityPosture::Hardened),
+        Ok(pb::KnowledgePostureKind::BlackVault) => Ok(KnowledgeSecurityPosture::BlackVault),
+        _ => Err(CommandDecodeError::InvalidEnum {
+            field: "KnowledgePostureKind",
+            value,
+        }),
+    }
+}
+
 fn corruption_subsystem_to_proto(value: CorruptionSubsystem) -> pb::CorruptionSubsystem {
     match value {
         CorruptionSubsystem::Logistics => pb::CorruptionSubsystem::Logistics,
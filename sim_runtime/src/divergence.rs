@@ -0,0 +1,133 @@
+//! Structural diff between two snapshots that should be identical, for chasing non-determinism.
+
+use serde_json::{Map, Value};
+
+use crate::{hash_snapshot, WorldSnapshot};
+
+/// Where two snapshots first differ.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DivergenceReport {
+    /// Tick of the left-hand snapshot.
+    pub tick: u64,
+    /// Top-level `WorldSnapshot` field, by its serde name (`populations`, `header`, ...).
+    pub section: String,
+    /// First differing entry inside the section: `id=N` / `entity=N` (or `[index]`) for lists, the
+    /// field name for structs. `None` when the section differs as a whole.
+    pub entity: Option<String>,
+}
+
+/// Header fields that differ between equal worlds (see `hash_snapshot`).
+const IGNORED_HEADER_FIELDS: [&str; 2] = ["hash", "sequence"];
+
+/// Compare two snapshots section by section and report the first difference, or `None` when they
+/// match. Body sections are checked in name order before the header, whose counts only echo them.
+pub fn diff_snapshots(a: &WorldSnapshot, b: &WorldSnapshot) -> Option<DivergenceReport> {
+    let report = |section: &str, entity: Option<String>| DivergenceReport {
+        tick: a.header.tick,
+        section: section.to_string(),
+        entity,
+    };
+
+    let (Ok(Value::Object(left)), Ok(Value::Object(right))) =
+        (serde_json::to_value(a), serde_json::to_value(b))
+    else {
+        // Not representable as JSON: all that can be said is whether they differ.
+        return (hash_snapshot(a) != hash_snapshot(b)).then(|| report("snapshot", None));
+    };
+
+    for (name, lhs) in left.iter().filter(|(name, _)| *name != "header") {
+        let rhs = right.get(name).unwrap_or(&Value::Null);
+        if lhs != rhs {
+            return Some(report(name, first_difference(lhs, rhs)));
+        }
+    }
+
+    let (lhs, rhs) = (header_fields(&left), header_fields(&right));
+    (lhs != rhs).then(|| report("header", first_difference(&lhs, &rhs)))
+}
+
+fn header_fields(snapshot: &Map<String, Value>) -> Value {
+    let mut header = snapshot.get("header").cloned().unwrap_or(Value::Null);
+    if let Value::Object(fields) = &mut header {
+        for field in IGNORED_HEADER_FIELDS {
+            fields.remove(field);
+        }
+    }
+    header
+}
+
+fn first_difference(lhs: &Value, rhs: &Value) -> Option<String> {
+    match (lhs, rhs) {
+        (Value::Array(lhs), Value::Array(rhs)) => {
+            let idx = (0..lhs.len().max(rhs.len())).find(|&idx| lhs.get(idx) != rhs.get(idx))?;
+            lhs.get(idx)
+                .or_else(|| rhs.get(idx))
+                .and_then(entity_label)
+                .or_else(|| Some(format!("[{idx}]")))
+        }
+        (Value::Object(lhs), Value::Object(rhs)) => lhs
+            .keys()
+            .chain(rhs.keys())
+            .find(|key| lhs.get(key.as_str()) != rhs.get(key.as_str()))
+            .cloned(),
+        _ => None,
+    }
+}
+
+fn entity_label(value: &Value) -> Option<String> {
+    let Value::Object(fields) = value else {
+        return None;
+    };
+    ["id", "entity"]
+        .into_iter()
+        .find_map(|key| fields.get(key).map(|id| format!("{key}={id}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::diff_snapshots;
+    use crate::{PopulationCohortState, WorldSnapshot, WorldSnapshotBuilder};
+
+    fn snapshot() -> WorldSnapshot {
+        WorldSnapshotBuilder::new()
+            .tick(7)
+            .with(|snapshot| {
+                snapshot.populations = [(11, 400), (12, 250)]
+                    .into_iter()
+                    .map(|(entity, size)| PopulationCohortState {
+                        entity,
+                        size,
+                        ..Default::default()
+                    })
+                    .collect();
+            })
+            .build()
+            .expect("fixture validates")
+            .finalize()
+    }
+
+    #[test]
+    fn identical_snapshots_do_not_diverge() {
+        let mut other = snapshot();
+        // Stream position and hash are not world state.
+        other.header.sequence = 99;
+        other.header.hash ^= 1;
+        assert_eq!(diff_snapshots(&snapshot(), &other), None);
+    }
+
+    #[test]
+    fn divergence_names_the_section_and_entity() {
+        let mut other = snapshot();
+        other.populations[1].size += 1;
+        let report = diff_snapshots(&snapshot(), &other).expect("snapshots differ");
+        assert_eq!(report.tick, 7);
+        assert_eq!(report.section, "populations");
+        assert_eq!(report.entity.as_deref(), Some("entity=12"));
+
+        let mut other = snapshot();
+        other.header.config_hash = 0xBEEF;
+        let report = diff_snapshots(&snapshot(), &other).expect("headers differ");
+        assert_eq!(report.section, "header");
+        assert_eq!(report.entity.as_deref(), Some("config_hash"));
+    }
+}
@@ -0,0 +1,226 @@
+//! Command-channel handshake.
+//!
+//! A client opens a command connection by sending [`CommandPayload::Handshake`] with the envelope
+//! protocol version it speaks (and the shared token, when the server is configured with one). The
+//! listener feeds every decoded payload through a per-connection [`CommandSession`], which consumes
+//! the handshake and gates everything else on it.
+//!
+//! While [`HandshakePolicy::required`] is off and no token is configured, commands are admitted
+//! without a handshake so clients that predate it keep working; a handshake that *is* sent is still
+//! verified. A configured token always requires the handshake, or it could simply be skipped.
+
+use thiserror::Error;
+
+use crate::CommandPayload;
+
+/// Version of the command envelope protocol. Bump it when a `command.proto` change would make an
+/// older peer misread frames (renumbered fields, changed semantics), not for appended commands.
+pub const COMMAND_PROTOCOL_VERSION: u32 = 1;
+
+/// What a command listener demands of new connections.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HandshakePolicy {
+    /// Refuse every command on a connection until it has completed a handshake.
+    pub required: bool,
+    /// Shared token a handshake must present; `None` accepts any (or no) token. Setting one
+    /// requires the handshake whatever `required` says.
+    pub token: Option<String>,
+}
+
+impl HandshakePolicy {
+    /// Whether a connection must complete a handshake before its commands are admitted.
+    pub fn requires_handshake(&self) -> bool {
+        self.required || self.token.is_some()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum HandshakeError {
+    #[error("protocol version mismatch: client speaks {client}, server speaks {server}")]
+    VersionMismatch { client: u32, server: u32 },
+    #[error("handshake token rejected")]
+    TokenRejected,
+    #[error("handshake required before commands (server protocol version {server})")]
+    Required { server: u32 },
+}
+
+/// Handshake state for one command connection.
+#[derive(Debug, Clone)]
+pub struct CommandSession {
+    policy: HandshakePolicy,
+    established: bool,
+}
+
+impl CommandSession {
+    pub fn new(policy: HandshakePolicy) -> Self {
+        Self {
+            policy,
+            established: false,
+        }
+    }
+
+    pub fn is_established(&self) -> bool {
+        self.established
+    }
+
+    /// Gate one decoded payload. A handshake is verified and consumed (`Ok(None)`); any other
+    /// command is passed back (`Ok(Some(..))`) once the session is established, or straight away
+    /// when the policy does not require a handshake ([`HandshakePolicy::requires_handshake`]). A
+    /// failed handshake leaves the session as it was.
+    pub fn admit(
+        &mut self,
+        payload: CommandPayload,
+    ) -> Result<Option<CommandPayload>, HandshakeError> {
+        match payload {
+            CommandPayload::Handshake {
+                protocol_version,
+                token,
+            } => {
+                self.verify(protocol_version, token.as_deref())?;
+                self.established = true;
+                Ok(None)
+            }
+            payload if self.established || !self.policy.requires_handshake() => Ok(Some(payload)),
+            _ => Err(HandshakeError::Required {
+                server: COMMAND_PROTOCOL_VERSION,
+            }),
+        }
+    }
+
+    fn verify(&self, protocol_version: u32, token: Option<&str>) -> Result<(), HandshakeError> {
+        if protocol_version != COMMAND_PROTOCOL_VERSION {
+            return Err(HandshakeError::VersionMismatch {
+                client: protocol_version,
+                server: COMMAND_PROTOCOL_VERSION,
+            });
+        }
+        match self.policy.token.as_deref() {
+            Some(expected) if !token.is_some_and(|token| tokens_match(token, expected)) => {
+                Err(HandshakeError::TokenRejected)
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Compare a presented token with the expected one in time that depends only on their lengths, so
+/// response timing does not reveal how long a matching prefix a guess has.
+fn tokens_match(presented: &str, expected: &str) -> bool {
+    let (presented, expected) = (presented.as_bytes(), expected.as_bytes());
+    let len = presented.len().max(expected.len());
+    let mut diff = presented.len() ^ expected.len();
+    for idx in 0..len {
+        let a = presented.get(idx).copied().unwrap_or(0);
+        let b = expected.get(idx).copied().unwrap_or(0);
+        diff |= usize::from(a ^ b);
+    }
+    diff == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn handshake(protocol_version: u32, token: Option<&str>) -> CommandPayload {
+        CommandPayload::Handshake {
+            protocol_version,
+            token: token.map(str::to_string),
+        }
+    }
+
+    fn turn() -> CommandPayload {
+        CommandPayload::Turn { steps: 1 }
+    }
+
+    fn required(token: Option<&str>) -> CommandSession {
+        CommandSession::new(HandshakePolicy {
+            required: true,
+            token: token.map(str::to_string),
+        })
+    }
+
+    #[test]
+    fn mismatched_version_is_rejected() {
+        let mut session = required(None);
+        let err = session
+            .admit(handshake(COMMAND_PROTOCOL_VERSION + 1, None))
+            .expect_err("version mismatch");
+        assert_eq!(
+            err,
+            HandshakeError::VersionMismatch {
+                client: COMMAND_PROTOCOL_VERSION + 1,
+                server: COMMAND_PROTOCOL_VERSION,
+            }
+        );
+        assert!(err.to_string().contains("client speaks 2, server speaks 1"));
+        assert!(!session.is_established());
+        assert!(session.admit(turn()).is_err());
+    }
+
+    #[test]
+    fn handshake_enables_command_flow() {
+        let mut session = required(Some("secret"));
+        assert_eq!(
+            session.admit(turn()),
+            Err(HandshakeError::Required {
+                server: COMMAND_PROTOCOL_VERSION
+            })
+        );
+        assert_eq!(
+            session.admit(handshake(COMMAND_PROTOCOL_VERSION, Some("wrong"))),
+            Err(HandshakeError::TokenRejected)
+        );
+        assert_eq!(
+            session.admit(handshake(COMMAND_PROTOCOL_VERSION, None)),
+            Err(HandshakeError::TokenRejected)
+        );
+
+        assert_eq!(
+            session.admit(handshake(COMMAND_PROTOCOL_VERSION, Some("secret"))),
+            Ok(None)
+        );
+        assert!(session.is_established());
+        assert_eq!(session.admit(turn()), Ok(Some(turn())));
+    }
+
+    #[test]
+    fn a_configured_token_cannot_be_skipped() {
+        let mut session = CommandSession::new(HandshakePolicy {
+            required: false,
+            token: Some("secret".to_string()),
+        });
+        assert_eq!(
+            session.admit(turn()),
+            Err(HandshakeError::Required {
+                server: COMMAND_PROTOCOL_VERSION
+            }),
+            "a token forces the handshake even when it is not required"
+        );
+        assert_eq!(
+            session.admit(handshake(COMMAND_PROTOCOL_VERSION, Some("secre"))),
+            Err(HandshakeError::TokenRejected)
+        );
+        assert_eq!(
+            session.admit(handshake(COMMAND_PROTOCOL_VERSION, Some("secrets"))),
+            Err(HandshakeError::TokenRejected)
+        );
+        assert_eq!(
+            session.admit(handshake(COMMAND_PROTOCOL_VERSION, Some("secret"))),
+            Ok(None)
+        );
+        assert_eq!(session.admit(turn()), Ok(Some(turn())));
+    }
+
+    #[test]
+    fn optional_policy_admits_legacy_clients() {
+        let mut session = CommandSession::new(HandshakePolicy::default());
+        assert_eq!(session.admit(turn()), Ok(Some(turn())));
+        assert!(!session.is_established());
+        // A handshake that is sent is still checked.
+        assert!(session.admit(handshake(0, None)).is_err());
+        assert_eq!(
+            session.admit(handshake(COMMAND_PROTOCOL_VERSION, Some("any"))),
+            Ok(None)
+        );
+    }
+}
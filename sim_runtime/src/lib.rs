@@ -11,18 +11,30 @@ pub use sim_schema::*;
 pub mod commands;
 pub use commands::{
     CancelScope, CommandDecodeError, CommandEncodeError, CommandEnvelope, CommandPayload,
-    OrdersDirective, ReloadConfigKind, SecurityPolicyKind, SupportChannel,
+    CommandResponse, OrdersDirective, ReloadConfigKind, SecurityPolicyKind, SupportAction,
+    SupportChannel, CONTAIN_CRISIS_EFFORT_MAX, INFLUENCER_ACTION_MAGNITUDE_MAX,
+    INFLUENCER_ACTION_MAGNITUDE_SCALE,
 };
 
+pub mod handshake;
+pub use handshake::{CommandSession, HandshakeError, HandshakePolicy, COMMAND_PROTOCOL_VERSION};
+
 pub mod command_text;
 pub use command_text::{parse_command_line, CommandParseError, CommandVerbHelp, COMMAND_VERBS};
 
 pub mod scripting;
 pub use scripting::{
-    capability_registry, manifest_schema, CapabilityRegistry, CapabilitySpec,
-    ManifestValidationError, ScriptManifest, ScriptManifestRef, SessionAccess, SimScriptState,
+    capability_registry, manifest_schema, topic_matches, CapabilityRegistry, CapabilitySpec,
+    ManifestValidationError, MissingPrerequisite, ScriptManifest, ScriptManifestRef, SessionAccess,
+    SimScriptState,
 };
 
+pub mod status;
+pub use status::snapshot_status_line;
+
+pub mod divergence;
+pub use divergence::{diff_snapshots, DivergenceReport};
+
 /// Fixed-point scaling constant shared with `core_sim::Scalar`.
 pub const FIXED_POINT_SCALE: i64 = 1_000_000;
 
@@ -371,72 +383,15 @@ pub mod knowledge {
             KnowledgeTelemetryFrame,
         };
         use crate::{
-            AxisBiasState, CorruptionLedger, CrisisOverlayState, CrisisTelemetryState,
-            FloatRasterState, GreatDiscoveryTelemetryState, KnowledgeLeakFlags,
-            KnowledgeLedgerEntryState, KnowledgeMetricsState, KnowledgeSecurityPosture,
-            KnowledgeTimelineEventKind, KnowledgeTimelineEventState, PowerTelemetryState,
-            ScalarRasterState, SentimentTelemetryState, SnapshotHeader, TerrainOverlayState,
-            VictorySnapshotState, WorldDelta, WorldSnapshot,
+            KnowledgeLeakFlags, KnowledgeLedgerEntryState, KnowledgeMetricsState,
+            KnowledgeSecurityPosture, KnowledgeTimelineEventKind, KnowledgeTimelineEventState,
+            WorldDelta, WorldSnapshot, WorldSnapshotBuilder,
         };
 
         fn empty_snapshot() -> WorldSnapshot {
-            WorldSnapshot {
-                header: SnapshotHeader::default(),
-                tiles: Vec::new(),
-                logistics: Vec::new(),
-                trade_links: Vec::new(),
-                populations: Vec::new(),
-                power: Vec::new(),
-                power_metrics: PowerTelemetryState::default(),
-                great_discovery_definitions: Vec::new(),
-                great_discoveries: Vec::new(),
-                great_discovery_progress: Vec::new(),
-                great_discovery_telemetry: GreatDiscoveryTelemetryState::default(),
-                knowledge_ledger: Vec::new(),
-                knowledge_timeline: Vec::new(),
-                knowledge_metrics: KnowledgeMetricsState::default(),
-                victory: VictorySnapshotState::default(),
-                crisis_telemetry: CrisisTelemetryState::default(),
-                crisis_overlay: CrisisOverlayState::default(),
-                capability_flags: 0,
-                campaign_profiles: Vec::new(),
-                command_events: Vec::new(),
-                pending_forks: Vec::new(),
-                stance_axes: Vec::new(),
-                voice_medium: Vec::new(),
-                herds: Vec::new(),
-                herd_registry: Vec::new(),
-                forage_registry: Vec::new(),
-                graze_registry: Vec::new(),
-                beat_ledger: Default::default(),
-                food_modules: Vec::new(),
-                faction_inventory: Vec::new(),
-                sedentarization: Vec::new(),
-                discovered_sites: Vec::new(),
-                demographics: Vec::new(),
-                forage_patches: Vec::new(),
-                intensification_knowledge: Vec::new(),
-                moisture_raster: FloatRasterState::default(),
-                terrain: TerrainOverlayState::default(),
-                logistics_raster: ScalarRasterState::default(),
-                sentiment_raster: ScalarRasterState::default(),
-                corruption_raster: ScalarRasterState::default(),
-                fog_raster: ScalarRasterState::default(),
-                culture_raster: ScalarRasterState::default(),
-                military_raster: ScalarRasterState::default(),
-                visibility_raster: ScalarRasterState::default(),
-                elevation_overlay: crate::ElevationOverlayState::default(),
-                climate_bands: crate::ClimateBandsState::default(),
-                start_marker: None,
-                axis_bias: AxisBiasState::default(),
-                sentiment: SentimentTelemetryState::default(),
-                generations: Vec::new(),
-                corruption: CorruptionLedger::default(),
-                influencers: Vec::new(),
-                culture_layers: Vec::new(),
-                culture_tensions: Vec::new(),
-                discovery_progress: Vec::new(),
-            }
+            WorldSnapshotBuilder::new()
+                .build()
+                .expect("empty snapshot validates")
         }
 
         #[test]
@@ -21,14 +21,18 @@ pub struct CapabilitySpec {
     pub session_access: SessionAccess,
     /// Whether the capability enables alerts.
     pub allows_alerts: bool,
+    /// Capabilities that must also be declared for this one to work. Resolved transitively by
+    /// manifest validation.
+    pub prerequisites: &'static [&'static str],
 }
 
 impl CapabilitySpec {
-    /// Returns true if this capability covers the provided telemetry topic.
+    /// Returns true if this capability covers the provided telemetry topic or subscription
+    /// pattern. A wildcard pattern is covered only when every topic it can match is covered.
     pub fn allows_subscription(&self, topic: &str) -> bool {
         self.subscriptions
             .iter()
-            .any(|allowed| topic_matches(topic, allowed))
+            .any(|allowed| pattern_covered_by(topic, allowed))
     }
 
     /// Returns true if this capability allows invoking the given host request.
@@ -115,25 +119,39 @@ impl ScriptManifest {
     }
 }
 
+/// A declared capability whose prerequisite (direct or transitive) is missing from the manifest.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MissingPrerequisite {
+    pub capability: String,
+    pub prerequisite: String,
+}
+
 #[derive(Debug, Clone)]
 pub struct ManifestValidationError {
     errors: Vec<String>,
+    missing_prerequisites: Vec<MissingPrerequisite>,
 }
 
 impl ManifestValidationError {
     pub fn new(errors: Vec<String>) -> Self {
-        Self { errors }
+        Self {
+            errors,
+            missing_prerequisites: Vec::new(),
+        }
     }
 
     pub fn single(message: impl Into<String>) -> Self {
-        Self {
-            errors: vec![message.into()],
-        }
+        Self::new(vec![message.into()])
     }
 
     pub fn errors(&self) -> &[String] {
         &self.errors
     }
+
+    /// Prerequisite gaps found during validation; each also appears in [`Self::errors`].
+    pub fn missing_prerequisites(&self) -> &[MissingPrerequisite] {
+        &self.missing_prerequisites
+    }
 }
 
 impl fmt::Display for ManifestValidationError {
@@ -147,7 +165,7 @@ impl std::error::Error for ManifestValidationError {}
 const CAPABILITY_SPECS: &[CapabilitySpec] = &[
     CapabilitySpec {
         id: "telemetry.subscribe",
-        description: "Grants access to telemetry topics exposed by the host (snapshots, deltas, overlays, discovery ledgers, log streams).",
+        description: "Grants access to telemetry topics exposed by the host (snapshots, deltas, overlays, discovery ledgers, log streams, crisis events).",
         host_requests: &["telemetry.subscribe", "telemetry.unsubscribe"],
         subscriptions: &[
             "world.snapshot",
@@ -155,9 +173,11 @@ const CAPABILITY_SPECS: &[CapabilitySpec] = &[
             "overlays.*",
             "ledger.discovery",
             "log.events",
+            "crisis.*",
         ],
         session_access: SessionAccess::None,
         allows_alerts: false,
+        prerequisites: &[],
     },
     CapabilitySpec {
         id: "ui.compose",
@@ -166,6 +186,8 @@ const CAPABILITY_SPECS: &[CapabilitySpec] = &[
         subscriptions: &[],
         session_access: SessionAccess::None,
         allows_alerts: false,
+        // Declarative widgets bind to telemetry topics; without them there is nothing to show.
+        prerequisites: &["telemetry.subscribe"],
     },
     CapabilitySpec {
         id: "commands.issue",
@@ -174,6 +196,8 @@ const CAPABILITY_SPECS: &[CapabilitySpec] = &[
         subscriptions: &["commands.issue.result"],
         session_access: SessionAccess::None,
         allows_alerts: false,
+        // Orders are only meaningful against world state the script can read.
+        prerequisites: &["telemetry.subscribe"],
     },
     CapabilitySpec {
         id: "storage.session",
@@ -182,6 +206,7 @@ const CAPABILITY_SPECS: &[CapabilitySpec] = &[
         subscriptions: &[],
         session_access: SessionAccess::ReadWrite,
         allows_alerts: false,
+        prerequisites: &[],
     },
     CapabilitySpec {
         id: "alerts.emit",
@@ -190,12 +215,18 @@ const CAPABILITY_SPECS: &[CapabilitySpec] = &[
         subscriptions: &["alerts.*"],
         session_access: SessionAccess::None,
         allows_alerts: true,
+        prerequisites: &[],
     },
 ];
 
-fn topic_matches(topic: &str, pattern: &str) -> bool {
-    if let Some(prefix) = pattern.strip_suffix('*') {
-        topic.starts_with(prefix)
+/// Returns true if `topic` matches the subscription `pattern`.
+///
+/// `*` is a glob wildcard matching any run of characters (dots included), so `crisis.*` matches
+/// both `crisis.spawned` and `crisis.spawn.manual`. A pattern ending in `.` is treated as a
+/// prefix, and anything else must match exactly.
+pub fn topic_matches(topic: &str, pattern: &str) -> bool {
+    if pattern.contains('*') {
+        glob_matches(topic.as_bytes(), pattern.as_bytes())
     } else if pattern.ends_with('.') {
         topic.starts_with(pattern)
     } else {
@@ -203,6 +234,43 @@ fn topic_matches(topic: &str, pattern: &str) -> bool {
     }
 }
 
+fn glob_matches(topic: &[u8], pattern: &[u8]) -> bool {
+    let (mut t, mut p) = (0usize, 0usize);
+    let mut backtrack: Option<(usize, usize)> = None;
+    while t < topic.len() {
+        if p < pattern.len() && pattern[p] == b'*' {
+            backtrack = Some((p, t));
+            p += 1;
+        } else if p < pattern.len() && pattern[p] == topic[t] {
+            p += 1;
+            t += 1;
+        } else if let Some((star, consumed)) = backtrack {
+            p = star + 1;
+            t = consumed + 1;
+            backtrack = Some((star, consumed + 1));
+        } else {
+            return false;
+        }
+    }
+    pattern[p..].iter().all(|byte| *byte == b'*')
+}
+
+/// Conservative coverage check: a wildcard subscription is covered by an allowed prefix pattern
+/// when its literal head (everything before the first `*`) already sits under that prefix.
+fn pattern_covered_by(pattern: &str, allowed: &str) -> bool {
+    let Some(star) = pattern.find('*') else {
+        return topic_matches(pattern, allowed);
+    };
+    let literal = &pattern[..star];
+    let allowed_prefix = allowed
+        .strip_suffix('*')
+        .or_else(|| allowed.ends_with('.').then_some(allowed));
+    match allowed_prefix {
+        Some(prefix) if !prefix.contains('*') => literal.starts_with(prefix),
+        _ => false,
+    }
+}
+
 /// Static registry containing all available capability specifications.
 pub struct CapabilityRegistry {
     specs: &'static [CapabilitySpec],
@@ -234,6 +302,7 @@ fn validate_manifest(
     registry: &CapabilityRegistry,
 ) -> Result<(), ManifestValidationError> {
     let mut errors = Vec::new();
+    let mut missing_prerequisites = Vec::new();
 
     if manifest.id.trim().is_empty() {
         errors.push("manifest id cannot be empty".to_string());
@@ -264,6 +333,21 @@ fn validate_manifest(
         }
     }
 
+    for spec in &resolved_specs {
+        for prerequisite in transitive_prerequisites(spec, registry) {
+            if !declared.contains(prerequisite) {
+                errors.push(format!(
+                    "capability '{}' requires undeclared capability '{prerequisite}'",
+                    spec.id
+                ));
+                missing_prerequisites.push(MissingPrerequisite {
+                    capability: spec.id.to_string(),
+                    prerequisite: prerequisite.to_string(),
+                });
+            }
+        }
+    }
+
     for topic in &manifest.subscriptions {
         let trimmed = topic.trim();
         if trimmed.is_empty() {
@@ -283,10 +367,33 @@ fn validate_manifest(
     if errors.is_empty() {
         Ok(())
     } else {
-        Err(ManifestValidationError::new(errors))
+        Err(ManifestValidationError {
+            errors,
+            missing_prerequisites,
+        })
     }
 }
 
+/// Every capability `spec` depends on, directly or through other prerequisites, in discovery
+/// order. Cycles and unknown ids are tolerated; each id is reported once.
+fn transitive_prerequisites(
+    spec: &CapabilitySpec,
+    registry: &CapabilityRegistry,
+) -> Vec<&'static str> {
+    let mut seen: Vec<&'static str> = Vec::new();
+    let mut pending: Vec<&'static str> = spec.prerequisites.to_vec();
+    while let Some(id) = pending.pop() {
+        if id == spec.id || seen.contains(&id) {
+            continue;
+        }
+        seen.push(id);
+        if let Some(next) = registry.get(id) {
+            pending.extend(next.prerequisites.iter().rev());
+        }
+    }
+    seen
+}
+
 pub fn manifest_schema() -> schemars::schema::RootSchema {
     schemars::schema_for!(ScriptManifest)
 }
@@ -316,11 +423,45 @@ mod tests {
             subscriptions: &["alerts.*"],
             session_access: SessionAccess::None,
             allows_alerts: true,
+            prerequisites: &[],
         };
         assert!(spec.allows_subscription("alerts.demo"));
         assert!(!spec.allows_subscription("alert"));
     }
 
+    #[test]
+    fn glob_subscriptions_match_only_their_namespace() {
+        assert!(topic_matches("crisis.spawned", "crisis.*"));
+        assert!(topic_matches("crisis.resolved", "crisis.*"));
+        assert!(topic_matches("crisis.spawn.manual", "crisis.*"));
+        assert!(topic_matches("overlays.crisis", "*.crisis"));
+        assert!(!topic_matches("world.snapshot", "crisis.*"));
+        assert!(!topic_matches("crisis", "crisis.*"));
+        assert!(!topic_matches("crisisx.spawned", "crisis.*"));
+    }
+
+    #[test]
+    fn manifest_validates_wildcard_subscriptions_against_capabilities() {
+        let covered = r#"{
+            "id": "demo",
+            "version": "0.1.0",
+            "entry": "./index.js",
+            "capabilities": ["telemetry.subscribe"],
+            "subscriptions": ["crisis.*", "overlays.crisis.*"]
+        }"#;
+        ScriptManifest::parse_str(covered).expect("wildcards under granted prefixes");
+
+        let too_broad = r#"{
+            "id": "demo",
+            "version": "0.1.0",
+            "entry": "./index.js",
+            "capabilities": ["telemetry.subscribe"],
+            "subscriptions": ["*"]
+        }"#;
+        let err = ScriptManifest::parse_str(too_broad).unwrap_err();
+        assert!(err.to_string().contains("subscription '*' not covered"));
+    }
+
     #[test]
     fn manifest_rejects_unknown_capability() {
         let json = r#"{
@@ -361,4 +502,93 @@ mod tests {
         let manifest = ScriptManifest::parse_str(json).expect("manifest should be valid");
         assert_eq!(manifest.capabilities.len(), 2);
     }
+
+    #[test]
+    fn manifest_reports_missing_prerequisite() {
+        let json = r#"{
+            "id": "demo",
+            "version": "0.1.0",
+            "entry": "./index.js",
+            "capabilities": ["commands.issue", "alerts.emit"],
+            "subscriptions": []
+        }"#;
+        let err = ScriptManifest::parse_str(json).expect_err("expected parse failure");
+        assert_eq!(
+            err.missing_prerequisites(),
+            &[MissingPrerequisite {
+                capability: "commands.issue".to_string(),
+                prerequisite: "telemetry.subscribe".to_string(),
+            }]
+        );
+        assert!(err.to_string().contains(
+            "capability 'commands.issue' requires undeclared capability 'telemetry.subscribe'"
+        ));
+    }
+
+    #[test]
+    fn prerequisites_resolve_transitively() {
+        const SPECS: &[CapabilitySpec] = &[
+            CapabilitySpec {
+                id: "a",
+                description: "",
+                host_requests: &[],
+                subscriptions: &[],
+                session_access: SessionAccess::None,
+                allows_alerts: false,
+                prerequisites: &["b"],
+            },
+            CapabilitySpec {
+                id: "b",
+                description: "",
+                host_requests: &[],
+                subscriptions: &[],
+                session_access: SessionAccess::None,
+                allows_alerts: false,
+                prerequisites: &["c", "a"],
+            },
+            CapabilitySpec {
+                id: "c",
+                description: "",
+                host_requests: &[],
+                subscriptions: &[],
+                session_access: SessionAccess::None,
+                allows_alerts: false,
+                prerequisites: &[],
+            },
+        ];
+        let registry = CapabilityRegistry { specs: SPECS };
+        let manifest = ScriptManifest {
+            id: "demo".to_string(),
+            version: "0.1.0".to_string(),
+            entry: "./index.js".to_string(),
+            capabilities: vec!["a".to_string(), "b".to_string()],
+            subscriptions: Vec::new(),
+            description: None,
+            author: None,
+            config: None,
+            manifest_path: None,
+        };
+        let err = validate_manifest(&manifest, &registry).expect_err("c is missing");
+        let missing: Vec<_> = err
+            .missing_prerequisites()
+            .iter()
+            .map(|gap| (gap.capability.as_str(), gap.prerequisite.as_str()))
+            .collect();
+        // `a` reaches `c` through `b`; the a <-> b cycle terminates.
+        assert_eq!(missing, vec![("a", "c"), ("b", "c")]);
+    }
+
+    #[test]
+    fn registry_prerequisites_name_known_capabilities() {
+        let registry = capability_registry();
+        for spec in registry.specs() {
+            for prerequisite in spec.prerequisites {
+                assert!(
+                    registry.get(prerequisite).is_some(),
+                    "{} lists unknown prerequisite {prerequisite}",
+                    spec.id
+                );
+            }
+        }
+    }
 }
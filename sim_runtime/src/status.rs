@@ -0,0 +1,104 @@
+//! One-line turn summaries for headless log output.
+
+use std::fmt::Write;
+
+use crate::WorldSnapshot;
+
+/// Summarize a snapshot as a single `key=value` status line, e.g.
+/// `tick=42 pops=1200 power=+3.2% crises=1(warn) leaks=0`.
+///
+/// The token order and spelling are stable so log scrapers can split on spaces and `=`. Every
+/// token is always present; an empty snapshot reports zeros and a `safe` crisis band.
+pub fn snapshot_status_line(snapshot: &WorldSnapshot) -> String {
+    let pops: u64 = snapshot
+        .populations
+        .iter()
+        .map(|cohort| u64::from(cohort.size))
+        .sum();
+
+    let margin = snapshot.power_metrics.surplus_margin;
+    let power_percent = if margin.is_finite() {
+        margin * 100.0
+    } else {
+        0.0
+    };
+
+    let crisis = &snapshot.crisis_telemetry;
+    let crises = crisis.warnings_active + crisis.criticals_active;
+    let band = if crisis.criticals_active > 0 {
+        "crit"
+    } else if crisis.warnings_active > 0 {
+        "warn"
+    } else {
+        "safe"
+    };
+
+    let metrics = &snapshot.knowledge_metrics;
+    let leaks = metrics.leak_warnings + metrics.leak_criticals;
+
+    let mut line = String::with_capacity(64);
+    // Writing into a `String` cannot fail.
+    let _ = write!(
+        line,
+        "tick={} pops={} power={:+.1}% crises={}({}) leaks={}",
+        snapshot.header.tick, pops, power_percent, crises, band, leaks
+    );
+    line
+}
+
+#[cfg(test)]
+mod tests {
+    use super::snapshot_status_line;
+    use crate::{
+        CrisisTelemetryState, KnowledgeMetricsState, PopulationCohortState, PowerTelemetryState,
+        WorldSnapshot, WorldSnapshotBuilder,
+    };
+
+    fn empty_snapshot() -> WorldSnapshot {
+        WorldSnapshotBuilder::new()
+            .build()
+            .expect("empty snapshot validates")
+    }
+
+    #[test]
+    fn status_line_reports_populated_snapshot() {
+        let mut snapshot = empty_snapshot();
+        snapshot.header.tick = 42;
+        snapshot.populations = [700, 500]
+            .into_iter()
+            .map(|size| PopulationCohortState {
+                size,
+                ..Default::default()
+            })
+            .collect();
+        snapshot.power_metrics = PowerTelemetryState {
+            surplus_margin: 0.032,
+            ..Default::default()
+        };
+        snapshot.crisis_telemetry = CrisisTelemetryState {
+            warnings_active: 1,
+            ..Default::default()
+        };
+        snapshot.knowledge_metrics = KnowledgeMetricsState {
+            leak_warnings: 2,
+            leak_criticals: 1,
+            ..Default::default()
+        };
+
+        let line = snapshot_status_line(&snapshot);
+        assert_eq!(line, "tick=42 pops=1200 power=+3.2% crises=1(warn) leaks=3");
+
+        snapshot.crisis_telemetry.criticals_active = 1;
+        snapshot.power_metrics.surplus_margin = -0.105;
+        let line = snapshot_status_line(&snapshot);
+        assert!(line.contains(" crises=2(crit) "), "{line}");
+        assert!(line.contains(" power=-10.5% "), "{line}");
+    }
+
+    #[test]
+    fn status_line_handles_empty_snapshot() {
+        let line = snapshot_status_line(&empty_snapshot());
+        assert_eq!(line, "tick=0 pops=0 power=+0.0% crises=0(safe) leaks=0");
+        assert_eq!(line.split(' ').count(), 5);
+    }
+}
@@ -1,9 +1,12 @@
 //! Campaign-section FlatBuffers serialization.
 
-use crate::codec::FbBuilder;
+use crate::codec::{decode_list, decode_string, FbBuilder};
 use crate::state::campaign::{
-    CampaignLabel, CampaignProfileState, CommandEventState, PendingForksState, StanceState,
-    VictorySnapshotState, VoiceLineState, VoiceMediumState,
+    CalendarState, CampaignInventoryEntryState, CampaignLabel, CampaignProfileState,
+    CampaignStartingUnitState, CommandEventState, EventSeverity, ForkChoiceState, GlossEntryState,
+    PendingForkState, PendingForksState, StanceAxisState, StanceState, VictoryFactionProgressState,
+    VictoryModeSnapshotState, VictoryResultState, VictorySnapshotState, VoiceLineState,
+    VoiceMediumState,
 };
 use crate::world::{WorldDelta, WorldSnapshot};
 use flatbuffers::{ForwardsUOffset, WIPOffset};
@@ -19,6 +22,7 @@ pub(crate) fn serialize_campaign_section<'a>(
     let pending_forks = create_pending_forks(builder, &snapshot.pending_forks);
     let stance_axes = create_stance_axes(builder, &snapshot.stance_axes);
     let voice_medium = create_voice_medium(builder, &snapshot.voice_medium);
+    let calendar = create_calendar(builder, &snapshot.calendar);
     fb::CampaignSection::create(
         builder,
         &fb::CampaignSectionArgs {
@@ -28,6 +32,7 @@ pub(crate) fn serialize_campaign_section<'a>(
             pendingForks: Some(pending_forks),
             stanceAxes: Some(stance_axes),
             voiceMedium: Some(voice_medium),
+            calendar: Some(calendar),
         },
     )
 }
@@ -53,6 +58,10 @@ pub(crate) fn serialize_campaign_section_delta<'a>(
         .voice_medium
         .as_ref()
         .map(|entries| create_voice_medium(builder, entries));
+    let calendar = delta
+        .calendar
+        .as_ref()
+        .map(|calendar| create_calendar(builder, calendar));
     fb::CampaignSection::create(
         builder,
         &fb::CampaignSectionArgs {
@@ -62,10 +71,144 @@ pub(crate) fn serialize_campaign_section_delta<'a>(
             pendingForks: pending_forks,
             stanceAxes: stance_axes,
             voiceMedium: voice_medium,
+            calendar,
         },
     )
 }
 
+pub(crate) fn decode_campaign_section(
+    section: fb::CampaignSection<'_>,
+    snapshot: &mut WorldSnapshot,
+) {
+    snapshot.campaign_profiles = decode_list(section.campaignProfiles(), decode_campaign_profile);
+    snapshot.command_events = decode_list(section.commandEvents(), |event| CommandEventState {
+        tick: event.tick(),
+        kind: decode_string(event.kind()),
+        faction: event.faction(),
+        label: decode_string(event.label()),
+        detail: event.detail().map(str::to_string),
+        severity: match event.severity() {
+            fb::EventSeverity::Notable => EventSeverity::Notable,
+            fb::EventSeverity::Alert => EventSeverity::Alert,
+            fb::EventSeverity::Major => EventSeverity::Major,
+            _ => EventSeverity::Routine,
+        },
+    });
+    if let Some(victory) = section.victory() {
+        snapshot.victory = decode_victory_state(victory);
+    }
+    snapshot.pending_forks = decode_list(section.pendingForks(), |state| PendingForksState {
+        faction: state.faction(),
+        forks: decode_list(state.forks(), decode_pending_fork),
+    });
+    snapshot.stance_axes = decode_list(section.stanceAxes(), |state| StanceState {
+        faction: state.faction(),
+        axes: decode_list(state.axes(), |axis| StanceAxisState {
+            axis: decode_string(axis.axis()),
+            value: axis.value(),
+        }),
+    });
+    snapshot.voice_medium = decode_list(section.voiceMedium(), |state| VoiceMediumState {
+        faction: state.faction(),
+        medium_id: decode_string(state.mediumId()),
+        medium_index: state.mediumIndex(),
+    });
+    if let Some(calendar) = section.calendar() {
+        snapshot.calendar = CalendarState {
+            year: calendar.year(),
+            season: calendar.season(),
+            day_in_year: calendar.dayInYear(),
+            ticks_per_year: calendar.ticksPerYear(),
+            phase: calendar.phase(),
+        };
+    }
+}
+
+pub(crate) fn decode_campaign_label(label: fb::CampaignLabel<'_>) -> CampaignLabel {
+    CampaignLabel {
+        profile_id: label.profileId().map(str::to_string),
+        title: label.title().map(str::to_string),
+        title_loc_key: label.titleLocKey().map(str::to_string),
+        subtitle: label.subtitle().map(str::to_string),
+        subtitle_loc_key: label.subtitleLocKey().map(str::to_string),
+    }
+}
+
+/// A zero `surveyRadius` is how the encoder ships an unset one, so it decodes back to `None`.
+fn decode_campaign_profile(profile: fb::CampaignProfile<'_>) -> CampaignProfileState {
+    CampaignProfileState {
+        id: profile.id().map(str::to_string),
+        title: profile.title().map(str::to_string),
+        title_loc_key: profile.titleLocKey().map(str::to_string),
+        subtitle: profile.subtitle().map(str::to_string),
+        subtitle_loc_key: profile.subtitleLocKey().map(str::to_string),
+        starting_units: decode_list(profile.startingUnits(), |unit| CampaignStartingUnitState {
+            kind: decode_string(unit.kind()),
+            count: unit.count(),
+            tags: decode_list(unit.tags(), str::to_string),
+        }),
+        inventory: decode_list(profile.inventory(), |entry| CampaignInventoryEntryState {
+            item: decode_string(entry.item()),
+            quantity: entry.quantity(),
+        }),
+        knowledge_tags: decode_list(profile.knowledgeTags(), str::to_string),
+        survey_radius: Some(profile.surveyRadius()).filter(|radius| *radius != 0),
+        fog_mode: profile.fogMode().map(str::to_string),
+        primary_food_module: profile.primaryFoodModule().map(str::to_string),
+        secondary_food_module: profile.secondaryFoodModule().map(str::to_string),
+    }
+}
+
+fn decode_pending_fork(fork: fb::PendingForkState<'_>) -> PendingForkState {
+    PendingForkState {
+        beat_id: decode_string(fork.beatId()),
+        wardrobe_id: decode_string(fork.wardrobeId()),
+        posted_tick: fork.postedTick(),
+        narration: decode_voice_lines(fork.narration()),
+        choices: decode_list(fork.choices(), |choice| ForkChoiceState {
+            choice_id: decode_string(choice.choiceId()),
+            label: decode_voice_lines(choice.label()),
+            is_defer: choice.isDefer(),
+        }),
+        gloss: decode_list(fork.gloss(), |entry| GlossEntryState {
+            signal: decode_string(entry.signal()),
+            value: entry.value(),
+        }),
+    }
+}
+
+fn decode_voice_lines<'a>(
+    lines: Option<flatbuffers::Vector<'a, ForwardsUOffset<fb::VoiceLine<'a>>>>,
+) -> Vec<VoiceLineState> {
+    decode_list(lines, |line| VoiceLineState {
+        register: decode_string(line.register()),
+        text: decode_string(line.text()),
+    })
+}
+
+pub(crate) fn decode_victory_state(state: fb::VictoryState<'_>) -> VictorySnapshotState {
+    VictorySnapshotState {
+        modes: decode_list(state.modes(), |mode| VictoryModeSnapshotState {
+            id: decode_string(mode.id()),
+            kind: decode_string(mode.kind()),
+            progress: mode.progress(),
+            threshold: mode.threshold(),
+            achieved: mode.achieved(),
+            per_faction_progress: decode_list(mode.perFactionProgress(), |slice| {
+                VictoryFactionProgressState {
+                    faction: slice.faction(),
+                    progress: slice.progress(),
+                }
+            }),
+        }),
+        winner: state.winner().map(|winner| VictoryResultState {
+            mode: decode_string(winner.mode()),
+            faction: winner.faction(),
+            tick: winner.tick(),
+        }),
+    }
+}
+
 pub(crate) fn create_campaign_label<'a>(
     builder: &mut FbBuilder<'a>,
     label: &CampaignLabel,
@@ -358,6 +501,22 @@ fn create_voice_medium<'a>(
     builder.create_vector(&entries)
 }
 
+fn create_calendar<'a>(
+    builder: &mut FbBuilder<'a>,
+    state: &CalendarState,
+) -> WIPOffset<fb::CalendarState<'a>> {
+    fb::CalendarState::create(
+        builder,
+        &fb::CalendarStateArgs {
+            year: state.year,
+            season: state.season,
+            dayInYear: state.day_in_year,
+            ticksPerYear: state.ticks_per_year,
+            phase: state.phase,
+        },
+    )
+}
+
 fn create_command_events<'a>(
     builder: &mut FbBuilder<'a>,
     events: &[CommandEventState],
@@ -378,6 +537,7 @@ fn create_command_events<'a>(
                 faction: event.faction,
                 label: Some(label),
                 detail,
+                severity: to_fb_event_severity(event.severity),
             },
         );
         entries.push(entry);
@@ -385,6 +545,15 @@ fn create_command_events<'a>(
     builder.create_vector(&entries)
 }
 
+fn to_fb_event_severity(severity: EventSeverity) -> fb::EventSeverity {
+    match severity {
+        EventSeverity::Routine => fb::EventSeverity::Routine,
+        EventSeverity::Notable => fb::EventSeverity::Notable,
+        EventSeverity::Alert => fb::EventSeverity::Alert,
+        EventSeverity::Major => fb::EventSeverity::Major,
+    }
+}
+
 pub(crate) fn create_victory_state<'a>(
     builder: &mut FbBuilder<'a>,
     state: &VictorySnapshotState,
@@ -393,6 +562,20 @@ pub(crate) fn create_victory_state<'a>(
     for mode in &state.modes {
         let id = builder.create_string(mode.id.as_str());
         let kind = builder.create_string(mode.kind.as_str());
+        let per_faction: Vec<_> = mode
+            .per_faction_progress
+            .iter()
+            .map(|slice| {
+                fb::VictoryFactionProgress::create(
+                    builder,
+                    &fb::VictoryFactionProgressArgs {
+                        faction: slice.faction,
+                        progress: slice.progress,
+                    },
+                )
+            })
+            .collect();
+        let per_faction_vec = builder.create_vector(&per_faction);
         let entry = fb::VictoryModeState::create(
             builder,
             &fb::VictoryModeStateArgs {
@@ -401,6 +584,7 @@ pub(crate) fn create_victory_state<'a>(
                 progress: mode.progress,
                 threshold: mode.threshold,
                 achieved: mode.achieved,
+                perFactionProgress: Some(per_faction_vec),
             },
         );
         mode_entries.push(entry);
@@ -1,11 +1,15 @@
 //! Culture-section FlatBuffers serialization.
 
-use crate::codec::{create_scalar_raster, FbBuilder};
+use crate::codec::{
+    create_scalar_raster, decode_list, decode_scalar_raster, decode_string, decode_vector,
+    FbBuilder,
+};
+use crate::raster::{CULTURE_RASTER_CHANNEL, SENTIMENT_RASTER_CHANNEL};
 use crate::state::culture::{
-    CultureLayerScope, CultureLayerState, CultureTensionKind, CultureTensionState,
+    AxisBiasState, CultureLayerScope, CultureLayerState, CultureTensionKind, CultureTensionState,
     CultureTraitAxis, CultureTraitEntry, InfluenceLifecycle, InfluenceScopeKind,
     InfluencerCultureResonanceEntry, InfluentialIndividualState, SentimentAxisTelemetry,
-    SentimentDriverCategory, SentimentTelemetryState,
+    SentimentDriverCategory, SentimentDriverState, SentimentTelemetryState,
 };
 use crate::world::{WorldDelta, WorldSnapshot};
 use flatbuffers::{ForwardsUOffset, WIPOffset};
@@ -17,7 +21,8 @@ pub(crate) fn serialize_culture_section<'a>(
 ) -> WIPOffset<fb::CultureSection<'a>> {
     let culture_layers = create_culture_layers(builder, &snapshot.culture_layers);
     let culture_tensions = create_culture_tensions(builder, &snapshot.culture_tensions);
-    let culture_raster = create_scalar_raster(builder, &snapshot.culture_raster);
+    let culture_raster =
+        create_scalar_raster(builder, CULTURE_RASTER_CHANNEL, &snapshot.culture_raster);
     let influencers = create_influencers(builder, &snapshot.influencers);
     let axis_bias = fb::AxisBiasState::create(
         builder,
@@ -29,7 +34,11 @@ pub(crate) fn serialize_culture_section<'a>(
         },
     );
     let sentiment = create_sentiment(builder, &snapshot.sentiment);
-    let sentiment_raster = create_scalar_raster(builder, &snapshot.sentiment_raster);
+    let sentiment_raster = create_scalar_raster(
+        builder,
+        SENTIMENT_RASTER_CHANNEL,
+        &snapshot.sentiment_raster,
+    );
     fb::CultureSection::create(
         builder,
         &fb::CultureSectionArgs {
@@ -56,7 +65,7 @@ pub(crate) fn serialize_culture_section_delta<'a>(
     let culture_raster = delta
         .culture_raster
         .as_ref()
-        .map(|raster| create_scalar_raster(builder, raster));
+        .map(|raster| create_scalar_raster(builder, CULTURE_RASTER_CHANNEL, raster));
     let influencers = create_influencers(builder, &delta.influencers);
     let removed_influencers = builder.create_vector(&delta.removed_influencers);
     let axis_bias = delta.axis_bias.as_ref().map(|axis| {
@@ -77,7 +86,7 @@ pub(crate) fn serialize_culture_section_delta<'a>(
     let sentiment_raster = delta
         .sentiment_raster
         .as_ref()
-        .map(|raster| create_scalar_raster(builder, raster));
+        .map(|raster| create_scalar_raster(builder, SENTIMENT_RASTER_CHANNEL, raster));
     fb::CultureSection::create(
         builder,
         &fb::CultureSectionArgs {
@@ -94,6 +103,156 @@ pub(crate) fn serialize_culture_section_delta<'a>(
     )
 }
 
+pub(crate) fn decode_culture_section(
+    section: fb::CultureSection<'_>,
+    snapshot: &mut WorldSnapshot,
+) {
+    snapshot.culture_layers = decode_list(section.cultureLayers(), |layer| CultureLayerState {
+        id: layer.id(),
+        owner: layer.owner(),
+        parent: layer.parent(),
+        scope: from_fb_culture_layer_scope(layer.scope()),
+        traits: decode_list(layer.traits(), |entry| CultureTraitEntry {
+            axis: from_fb_culture_trait_axis(entry.axis()),
+            baseline: entry.baseline(),
+            modifier: entry.modifier(),
+            value: entry.value(),
+        }),
+        divergence: layer.divergence(),
+        soft_threshold: layer.softThreshold(),
+        hard_threshold: layer.hardThreshold(),
+        ticks_above_soft: layer.ticksAboveSoft(),
+        ticks_above_hard: layer.ticksAboveHard(),
+        last_updated_tick: layer.lastUpdatedTick(),
+    });
+    snapshot.culture_tensions =
+        decode_list(section.cultureTensions(), |state| CultureTensionState {
+            layer_id: state.layerId(),
+            scope: from_fb_culture_layer_scope(state.scope()),
+            owner: state.owner(),
+            severity: state.severity(),
+            timer: state.timer(),
+            kind: match state.kind() {
+                fb::CultureTensionKind::AssimilationPush => CultureTensionKind::AssimilationPush,
+                fb::CultureTensionKind::SchismRisk => CultureTensionKind::SchismRisk,
+                _ => CultureTensionKind::DriftWarning,
+            },
+        });
+    if let Some(raster) = section.cultureRaster() {
+        snapshot.culture_raster = decode_scalar_raster(raster);
+    }
+    snapshot.influencers = decode_list(section.influencers(), decode_influencer);
+    if let Some(axis) = section.axisBias() {
+        snapshot.axis_bias = AxisBiasState {
+            knowledge: axis.knowledge(),
+            trust: axis.trust(),
+            equity: axis.equity(),
+            agency: axis.agency(),
+        };
+    }
+    if let Some(sentiment) = section.sentiment() {
+        snapshot.sentiment = SentimentTelemetryState {
+            knowledge: decode_sentiment_axis(sentiment.knowledge()),
+            trust: decode_sentiment_axis(sentiment.trust()),
+            equity: decode_sentiment_axis(sentiment.equity()),
+            agency: decode_sentiment_axis(sentiment.agency()),
+        };
+    }
+    if let Some(raster) = section.sentimentRaster() {
+        snapshot.sentiment_raster = decode_scalar_raster(raster);
+    }
+}
+
+fn decode_sentiment_axis(axis: Option<fb::SentimentAxisTelemetry<'_>>) -> SentimentAxisTelemetry {
+    let Some(axis) = axis else {
+        return SentimentAxisTelemetry::default();
+    };
+    SentimentAxisTelemetry {
+        policy: axis.policy(),
+        incidents: axis.incidents(),
+        influencers: axis.influencers(),
+        total: axis.total(),
+        drivers: decode_list(axis.drivers(), decode_sentiment_driver),
+        generations: axis.generations(),
+        generation_drivers: decode_list(axis.generationDrivers(), decode_sentiment_driver),
+    }
+}
+
+fn decode_sentiment_driver(driver: fb::SentimentDriverState<'_>) -> SentimentDriverState {
+    SentimentDriverState {
+        category: match driver.category() {
+            fb::SentimentDriverCategory::Incident => SentimentDriverCategory::Incident,
+            fb::SentimentDriverCategory::Influencer => SentimentDriverCategory::Influencer,
+            fb::SentimentDriverCategory::Generation => SentimentDriverCategory::Generation,
+            _ => SentimentDriverCategory::Policy,
+        },
+        label: decode_string(driver.label()),
+        value: driver.value(),
+        weight: driver.weight(),
+    }
+}
+
+fn decode_influencer(inf: fb::InfluentialIndividualState<'_>) -> InfluentialIndividualState {
+    InfluentialIndividualState {
+        id: inf.id(),
+        name: decode_string(inf.name()),
+        influence: inf.influence(),
+        growth_rate: inf.growthRate(),
+        baseline_growth: inf.baselineGrowth(),
+        notoriety: inf.notoriety(),
+        sentiment_knowledge: inf.sentimentKnowledge(),
+        sentiment_trust: inf.sentimentTrust(),
+        sentiment_equity: inf.sentimentEquity(),
+        sentiment_agency: inf.sentimentAgency(),
+        sentiment_weight_knowledge: inf.sentimentWeightKnowledge(),
+        sentiment_weight_trust: inf.sentimentWeightTrust(),
+        sentiment_weight_equity: inf.sentimentWeightEquity(),
+        sentiment_weight_agency: inf.sentimentWeightAgency(),
+        logistics_bonus: inf.logisticsBonus(),
+        morale_bonus: inf.moraleBonus(),
+        power_bonus: inf.powerBonus(),
+        logistics_weight: inf.logisticsWeight(),
+        morale_weight: inf.moraleWeight(),
+        power_weight: inf.powerWeight(),
+        support_charge: inf.supportCharge(),
+        suppress_pressure: inf.suppressPressure(),
+        domains: inf.domains(),
+        scope: match inf.scope() {
+            fb::InfluenceScopeKind::Regional => InfluenceScopeKind::Regional,
+            fb::InfluenceScopeKind::Global => InfluenceScopeKind::Global,
+            fb::InfluenceScopeKind::Generation => InfluenceScopeKind::Generation,
+            _ => InfluenceScopeKind::Local,
+        },
+        generation_scope: inf.generationScope(),
+        supported: inf.supported(),
+        suppressed: inf.suppressed(),
+        lifecycle: match inf.lifecycle() {
+            fb::InfluenceLifecycle::Active => InfluenceLifecycle::Active,
+            fb::InfluenceLifecycle::Dormant => InfluenceLifecycle::Dormant,
+            _ => InfluenceLifecycle::Potential,
+        },
+        coherence: inf.coherence(),
+        ticks_in_status: inf.ticksInStatus(),
+        ticks_below_dormancy: inf.ticksBelowDormancy(),
+        audience_generations: decode_vector(inf.audienceGenerations()),
+        support_popular: inf.supportPopular(),
+        support_peer: inf.supportPeer(),
+        support_institutional: inf.supportInstitutional(),
+        support_humanitarian: inf.supportHumanitarian(),
+        weight_popular: inf.weightPopular(),
+        weight_peer: inf.weightPeer(),
+        weight_institutional: inf.weightInstitutional(),
+        weight_humanitarian: inf.weightHumanitarian(),
+        culture_resonance: decode_list(inf.cultureResonance(), |entry| {
+            InfluencerCultureResonanceEntry {
+                axis: from_fb_culture_trait_axis(entry.axis()),
+                weight: entry.weight(),
+                output: entry.output(),
+            }
+        }),
+    }
+}
+
 fn create_sentiment<'a>(
     builder: &mut FbBuilder<'a>,
     sentiment: &SentimentTelemetryState,
@@ -117,8 +276,27 @@ fn create_sentiment_axis<'a>(
     builder: &mut FbBuilder<'a>,
     axis: &SentimentAxisTelemetry,
 ) -> WIPOffset<fb::SentimentAxisTelemetry<'a>> {
-    let drivers: Vec<_> = axis
-        .drivers
+    let drivers_vec = create_sentiment_drivers(builder, &axis.drivers);
+    let generation_drivers_vec = create_sentiment_drivers(builder, &axis.generation_drivers);
+    fb::SentimentAxisTelemetry::create(
+        builder,
+        &fb::SentimentAxisTelemetryArgs {
+            policy: axis.policy,
+            incidents: axis.incidents,
+            influencers: axis.influencers,
+            total: axis.total,
+            drivers: Some(drivers_vec),
+            generations: axis.generations,
+            generationDrivers: Some(generation_drivers_vec),
+        },
+    )
+}
+
+fn create_sentiment_drivers<'a>(
+    builder: &mut FbBuilder<'a>,
+    drivers: &[SentimentDriverState],
+) -> WIPOffset<flatbuffers::Vector<'a, ForwardsUOffset<fb::SentimentDriverState<'a>>>> {
+    let offsets: Vec<_> = drivers
         .iter()
         .map(|driver| {
             let label = builder.create_string(driver.label.as_str());
@@ -133,17 +311,7 @@ fn create_sentiment_axis<'a>(
             )
         })
         .collect();
-    let drivers_vec = builder.create_vector(&drivers);
-    fb::SentimentAxisTelemetry::create(
-        builder,
-        &fb::SentimentAxisTelemetryArgs {
-            policy: axis.policy,
-            incidents: axis.incidents,
-            influencers: axis.influencers,
-            total: axis.total,
-            drivers: Some(drivers_vec),
-        },
-    )
+    builder.create_vector(&offsets)
 }
 
 fn create_influencers<'a>(
@@ -200,6 +368,7 @@ fn create_influencers<'a>(
                     weightInstitutional: inf.weight_institutional,
                     weightHumanitarian: inf.weight_humanitarian,
                     cultureResonance: Some(resonance_vec),
+                    ticksBelowDormancy: inf.ticks_below_dormancy,
                 },
             )
         })
@@ -305,6 +474,7 @@ fn to_fb_driver_category(category: SentimentDriverCategory) -> fb::SentimentDriv
         SentimentDriverCategory::Policy => fb::SentimentDriverCategory::Policy,
         SentimentDriverCategory::Incident => fb::SentimentDriverCategory::Incident,
         SentimentDriverCategory::Influencer => fb::SentimentDriverCategory::Influencer,
+        SentimentDriverCategory::Generation => fb::SentimentDriverCategory::Generation,
     }
 }
 
@@ -333,6 +503,14 @@ fn to_fb_culture_layer_scope(scope: CultureLayerScope) -> fb::CultureLayerScope
     }
 }
 
+fn from_fb_culture_layer_scope(scope: fb::CultureLayerScope) -> CultureLayerScope {
+    match scope {
+        fb::CultureLayerScope::Regional => CultureLayerScope::Regional,
+        fb::CultureLayerScope::Local => CultureLayerScope::Local,
+        _ => CultureLayerScope::Global,
+    }
+}
+
 fn to_fb_culture_trait_axis(axis: CultureTraitAxis) -> fb::CultureTraitAxis {
     match axis {
         CultureTraitAxis::PassiveAggressive => fb::CultureTraitAxis::PassiveAggressive,
@@ -359,6 +537,32 @@ fn to_fb_culture_trait_axis(axis: CultureTraitAxis) -> fb::CultureTraitAxis {
     }
 }
 
+fn from_fb_culture_trait_axis(axis: fb::CultureTraitAxis) -> CultureTraitAxis {
+    match axis {
+        fb::CultureTraitAxis::OpenClosed => CultureTraitAxis::OpenClosed,
+        fb::CultureTraitAxis::CollectivistIndividualist => {
+            CultureTraitAxis::CollectivistIndividualist
+        }
+        fb::CultureTraitAxis::TraditionalistRevisionist => {
+            CultureTraitAxis::TraditionalistRevisionist
+        }
+        fb::CultureTraitAxis::HierarchicalEgalitarian => CultureTraitAxis::HierarchicalEgalitarian,
+        fb::CultureTraitAxis::SyncreticPurist => CultureTraitAxis::SyncreticPurist,
+        fb::CultureTraitAxis::AsceticIndulgent => CultureTraitAxis::AsceticIndulgent,
+        fb::CultureTraitAxis::PragmaticIdealistic => CultureTraitAxis::PragmaticIdealistic,
+        fb::CultureTraitAxis::RationalistMystical => CultureTraitAxis::RationalistMystical,
+        fb::CultureTraitAxis::ExpansionistInsular => CultureTraitAxis::ExpansionistInsular,
+        fb::CultureTraitAxis::AdaptiveStubborn => CultureTraitAxis::AdaptiveStubborn,
+        fb::CultureTraitAxis::HonorBoundOpportunistic => CultureTraitAxis::HonorBoundOpportunistic,
+        fb::CultureTraitAxis::MeritOrientedLineageOriented => {
+            CultureTraitAxis::MeritOrientedLineageOriented
+        }
+        fb::CultureTraitAxis::SecularDevout => CultureTraitAxis::SecularDevout,
+        fb::CultureTraitAxis::PluralisticMonocultural => CultureTraitAxis::PluralisticMonocultural,
+        _ => CultureTraitAxis::PassiveAggressive,
+    }
+}
+
 fn to_fb_culture_tension_kind(kind: CultureTensionKind) -> fb::CultureTensionKind {
     match kind {
         CultureTensionKind::DriftWarning => fb::CultureTensionKind::DriftWarning,
@@ -1,7 +1,14 @@
 //! Economy-section FlatBuffers serialization.
 
-use crate::codec::{create_known_fragments, create_scalar_raster, FbBuilder};
-use crate::state::economy::{FactionInventoryState, LogisticsLinkState, TradeLinkState};
+use crate::codec::{
+    create_known_fragments, create_scalar_raster, decode_known_fragments, decode_list,
+    decode_scalar_raster, decode_string, FbBuilder,
+};
+use crate::raster::LOGISTICS_RASTER_CHANNEL;
+use crate::state::economy::{
+    FactionInventoryEntryState, FactionInventoryState, LogisticsLinkState, TradeLinkKnowledge,
+    TradeLinkState,
+};
 use crate::world::{WorldDelta, WorldSnapshot};
 use flatbuffers::{ForwardsUOffset, WIPOffset};
 use shadow_scale_flatbuffers::generated::shadow_scale::sim as fb;
@@ -12,7 +19,11 @@ pub(crate) fn serialize_economy_section<'a>(
 ) -> WIPOffset<fb::EconomySection<'a>> {
     let logistics = create_logistics(builder, &snapshot.logistics);
     let trade_links = create_trade_links(builder, &snapshot.trade_links);
-    let logistics_raster = create_scalar_raster(builder, &snapshot.logistics_raster);
+    let logistics_raster = create_scalar_raster(
+        builder,
+        LOGISTICS_RASTER_CHANNEL,
+        &snapshot.logistics_raster,
+    );
     let faction_inventory = create_faction_inventory(builder, &snapshot.faction_inventory);
     fb::EconomySection::create(
         builder,
@@ -38,7 +49,7 @@ pub(crate) fn serialize_economy_section_delta<'a>(
     let logistics_raster = delta
         .logistics_raster
         .as_ref()
-        .map(|raster| create_scalar_raster(builder, raster));
+        .map(|raster| create_scalar_raster(builder, LOGISTICS_RASTER_CHANNEL, raster));
     let faction_inventory = delta
         .faction_inventory
         .as_ref()
@@ -56,6 +67,53 @@ pub(crate) fn serialize_economy_section_delta<'a>(
     )
 }
 
+pub(crate) fn decode_economy_section(
+    section: fb::EconomySection<'_>,
+    snapshot: &mut WorldSnapshot,
+) {
+    snapshot.logistics = decode_list(section.logistics(), |link| LogisticsLinkState {
+        entity: link.entity(),
+        from: link.from(),
+        to: link.to(),
+        capacity: link.capacity(),
+        flow: link.flow(),
+    });
+    snapshot.trade_links = decode_list(section.tradeLinks(), decode_trade_link);
+    if let Some(raster) = section.logisticsRaster() {
+        snapshot.logistics_raster = decode_scalar_raster(raster);
+    }
+    snapshot.faction_inventory =
+        decode_list(section.factionInventory(), |state| FactionInventoryState {
+            faction: state.faction(),
+            inventory: decode_list(state.inventory(), |entry| FactionInventoryEntryState {
+                item: decode_string(entry.item()),
+                quantity: entry.quantity(),
+            }),
+        });
+}
+
+fn decode_trade_link(link: fb::TradeLinkState<'_>) -> TradeLinkState {
+    TradeLinkState {
+        entity: link.entity(),
+        from_faction: link.fromFaction(),
+        to_faction: link.toFaction(),
+        throughput: link.throughput(),
+        tariff: link.tariff(),
+        knowledge: link
+            .knowledge()
+            .map(|knowledge| TradeLinkKnowledge {
+                openness: knowledge.openness(),
+                leak_timer: knowledge.leakTimer(),
+                last_discovery: knowledge.lastDiscovery(),
+                decay: knowledge.decay(),
+            })
+            .unwrap_or_default(),
+        from_tile: link.fromTile(),
+        to_tile: link.toTile(),
+        pending_fragments: decode_known_fragments(link.pendingFragments()),
+    }
+}
+
 fn create_faction_inventory<'a>(
     builder: &mut FbBuilder<'a>,
     factions: &[FactionInventoryState],
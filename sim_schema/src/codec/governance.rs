@@ -1,11 +1,15 @@
 //! Governance-section FlatBuffers serialization.
 
-use crate::codec::{create_scalar_raster, FbBuilder};
+use crate::codec::{
+    create_scalar_raster, decode_list, decode_scalar_raster, decode_string, decode_vector,
+    FbBuilder,
+};
+use crate::raster::{CORRUPTION_RASTER_CHANNEL, CRISIS_HEATMAP_CHANNEL};
 use crate::state::governance::{
-    CorruptionLedger, CorruptionSubsystem, CrisisGaugeState, CrisisMetricKind,
-    CrisisOverlayAnnotationState, CrisisOverlayState, CrisisSeverityBand, CrisisTelemetryState,
-    CrisisTrendSample, PowerIncidentSeverity, PowerIncidentState, PowerNodeState,
-    PowerTelemetryState,
+    CorruptionEntry, CorruptionLedger, CorruptionSubsystem, CrisisFollowOnState, CrisisGaugeState,
+    CrisisLedgerEntryState, CrisisMetricKind, CrisisOverlayAnnotationState, CrisisOverlayState,
+    CrisisSeverityBand, CrisisTelemetryState, CrisisTrendSample, DiplomacyLeverageState,
+    PowerIncidentSeverity, PowerIncidentState, PowerNodeKind, PowerNodeState, PowerTelemetryState,
 };
 use crate::world::{WorldDelta, WorldSnapshot};
 use flatbuffers::{ForwardsUOffset, WIPOffset};
@@ -18,9 +22,14 @@ pub(crate) fn serialize_governance_section<'a>(
     let power = create_power(builder, &snapshot.power);
     let power_metrics = create_power_metrics(builder, &snapshot.power_metrics);
     let corruption = create_corruption(builder, &snapshot.corruption);
-    let corruption_raster = create_scalar_raster(builder, &snapshot.corruption_raster);
+    let corruption_raster = create_scalar_raster(
+        builder,
+        CORRUPTION_RASTER_CHANNEL,
+        &snapshot.corruption_raster,
+    );
     let crisis_telemetry = create_crisis_telemetry(builder, &snapshot.crisis_telemetry);
     let crisis_overlay = create_crisis_overlay(builder, &snapshot.crisis_overlay);
+    let diplomacy_leverage = create_diplomacy_leverage(builder, &snapshot.diplomacy_leverage);
     fb::GovernanceSection::create(
         builder,
         &fb::GovernanceSectionArgs {
@@ -31,6 +40,7 @@ pub(crate) fn serialize_governance_section<'a>(
             crisisTelemetry: Some(crisis_telemetry),
             crisisOverlay: Some(crisis_overlay),
             removedPower: None,
+            diplomacyLeverage: Some(diplomacy_leverage),
         },
     )
 }
@@ -52,7 +62,7 @@ pub(crate) fn serialize_governance_section_delta<'a>(
     let corruption_raster = delta
         .corruption_raster
         .as_ref()
-        .map(|raster| create_scalar_raster(builder, raster));
+        .map(|raster| create_scalar_raster(builder, CORRUPTION_RASTER_CHANNEL, raster));
     let crisis_telemetry = delta
         .crisis_telemetry
         .as_ref()
@@ -61,6 +71,10 @@ pub(crate) fn serialize_governance_section_delta<'a>(
         .crisis_overlay
         .as_ref()
         .map(|overlay| create_crisis_overlay(builder, overlay));
+    let diplomacy_leverage = delta
+        .diplomacy_leverage
+        .as_ref()
+        .map(|pairs| create_diplomacy_leverage(builder, pairs));
     fb::GovernanceSection::create(
         builder,
         &fb::GovernanceSectionArgs {
@@ -71,10 +85,171 @@ pub(crate) fn serialize_governance_section_delta<'a>(
             crisisTelemetry: crisis_telemetry,
             crisisOverlay: crisis_overlay,
             removedPower: Some(removed_power),
+            diplomacyLeverage: diplomacy_leverage,
         },
     )
 }
 
+pub(crate) fn decode_governance_section(
+    section: fb::GovernanceSection<'_>,
+    snapshot: &mut WorldSnapshot,
+) {
+    snapshot.power = decode_list(section.power(), |node| PowerNodeState {
+        entity: node.entity(),
+        node_id: node.nodeId(),
+        generation: node.generation(),
+        demand: node.demand(),
+        efficiency: node.efficiency(),
+        storage_level: node.storageLevel(),
+        storage_capacity: node.storageCapacity(),
+        stability: node.stability(),
+        surplus: node.surplus(),
+        deficit: node.deficit(),
+        incident_count: node.incidentCount(),
+        kind: match node.kind() {
+            fb::PowerNodeKind::Generator => PowerNodeKind::Generator,
+            fb::PowerNodeKind::Storage => PowerNodeKind::Storage,
+            fb::PowerNodeKind::Consumer => PowerNodeKind::Consumer,
+            _ => PowerNodeKind::Hybrid,
+        },
+        priority: node.priority(),
+        shed: node.shed(),
+    });
+    if let Some(metrics) = section.powerMetrics() {
+        snapshot.power_metrics = PowerTelemetryState {
+            total_supply: metrics.totalSupply(),
+            total_demand: metrics.totalDemand(),
+            total_storage: metrics.totalStorage(),
+            total_capacity: metrics.totalCapacity(),
+            grid_stress_avg: metrics.gridStressAvg(),
+            surplus_margin: metrics.surplusMargin(),
+            instability_alerts: metrics.instabilityAlerts(),
+            incidents: decode_list(metrics.incidents(), |incident| PowerIncidentState {
+                node_id: incident.nodeId(),
+                severity: match incident.severity() {
+                    fb::PowerIncidentSeverity::Critical => PowerIncidentSeverity::Critical,
+                    _ => PowerIncidentSeverity::Warning,
+                },
+                deficit: incident.deficit(),
+            }),
+        };
+    }
+    if let Some(ledger) = section.corruption() {
+        snapshot.corruption = CorruptionLedger {
+            entries: decode_list(ledger.entries(), |entry| CorruptionEntry {
+                subsystem: from_fb_corruption_subsystem(entry.subsystem()),
+                intensity: entry.intensity(),
+                incident_id: entry.incidentId(),
+                exposure_timer: entry.exposureTimer(),
+                restitution_window: entry.restitutionWindow(),
+                last_update_tick: entry.lastUpdateTick(),
+            }),
+            reputation_modifier: ledger.reputationModifier(),
+            audit_capacity: ledger.auditCapacity(),
+        };
+    }
+    if let Some(raster) = section.corruptionRaster() {
+        snapshot.corruption_raster = decode_scalar_raster(raster);
+    }
+    if let Some(telemetry) = section.crisisTelemetry() {
+        snapshot.crisis_telemetry = CrisisTelemetryState {
+            gauges: decode_list(telemetry.gauges(), decode_crisis_gauge),
+            modifiers_active: telemetry.modifiersActive(),
+            foreshock_incidents: telemetry.foreshockIncidents(),
+            containment_incidents: telemetry.containmentIncidents(),
+            warnings_active: telemetry.warningsActive(),
+            criticals_active: telemetry.criticalsActive(),
+        };
+    }
+    if let Some(overlay) = section.crisisOverlay() {
+        snapshot.crisis_overlay = decode_crisis_overlay(overlay);
+    }
+    snapshot.diplomacy_leverage =
+        decode_list(section.diplomacyLeverage(), |pair| DiplomacyLeverageState {
+            faction_a: pair.factionA(),
+            faction_b: pair.factionB(),
+            leverage: pair.leverage(),
+        });
+}
+
+fn decode_crisis_gauge(gauge: fb::CrisisGaugeState<'_>) -> CrisisGaugeState {
+    CrisisGaugeState {
+        kind: match gauge.kind() {
+            fb::CrisisMetricKind::GridStressPct => CrisisMetricKind::GridStressPct,
+            fb::CrisisMetricKind::UnauthorizedQueuePct => CrisisMetricKind::UnauthorizedQueuePct,
+            fb::CrisisMetricKind::SwarmsActive => CrisisMetricKind::SwarmsActive,
+            fb::CrisisMetricKind::PhageDensity => CrisisMetricKind::PhageDensity,
+            _ => CrisisMetricKind::R0,
+        },
+        raw: gauge.raw(),
+        ema: gauge.ema(),
+        trend_5t: gauge.trend5t(),
+        warn_threshold: gauge.warnThreshold(),
+        critical_threshold: gauge.criticalThreshold(),
+        last_updated_tick: gauge.lastUpdatedTick(),
+        stale_ticks: gauge.staleTicks(),
+        band: from_fb_crisis_severity_band(gauge.band()),
+        history: decode_list(gauge.history(), |sample| CrisisTrendSample {
+            tick: sample.tick(),
+            value: sample.value(),
+        }),
+        raw_band: from_fb_crisis_severity_band(gauge.rawBand()),
+        ema_band: from_fb_crisis_severity_band(gauge.emaBand()),
+    }
+}
+
+fn decode_crisis_overlay(overlay: fb::CrisisOverlayState<'_>) -> CrisisOverlayState {
+    CrisisOverlayState {
+        heatmap: overlay
+            .heatmap()
+            .map(decode_scalar_raster)
+            .unwrap_or_default(),
+        annotations: decode_list(overlay.annotations(), |annotation| {
+            CrisisOverlayAnnotationState {
+                label: decode_string(annotation.label()),
+                severity: from_fb_crisis_severity_band(annotation.severity()),
+                path: decode_vector(annotation.path()),
+                crisis_id: annotation.crisisId(),
+            }
+        }),
+        crises: decode_list(overlay.crises(), |crisis| CrisisLedgerEntryState {
+            crisis_id: crisis.crisisId(),
+            archetype: decode_string(crisis.archetype()),
+            faction: crisis.faction(),
+            chain_depth: crisis.chainDepth(),
+            escalated: crisis.escalated(),
+            tiles: decode_vector(crisis.tiles()),
+        }),
+        pending_follow_ons: decode_list(overlay.pendingFollowOns(), |follow_on| {
+            CrisisFollowOnState {
+                faction: follow_on.faction(),
+                archetype: decode_string(follow_on.archetype()),
+                chain_depth: follow_on.chainDepth(),
+            }
+        }),
+    }
+}
+
+fn create_diplomacy_leverage<'a>(
+    builder: &mut FbBuilder<'a>,
+    pairs: &[DiplomacyLeverageState],
+) -> WIPOffset<flatbuffers::Vector<'a, ForwardsUOffset<fb::DiplomacyLeverageState<'a>>>> {
+    let offsets: Vec<_> = pairs
+        .iter()
+        .map(|pair| {
+            fb::DiplomacyLeverageState::create(
+                builder,
+                &fb::DiplomacyLeverageStateArgs {
+                    factionA: pair.faction_a,
+                    factionB: pair.faction_b,
+                    leverage: pair.leverage,
+                },
+            )
+        })
+        .collect();
+    builder.create_vector(&offsets)
+}
+
 fn create_power<'a>(
     builder: &mut FbBuilder<'a>,
     power_nodes: &[PowerNodeState],
@@ -96,6 +271,14 @@ fn create_power<'a>(
                     surplus: node.surplus,
                     deficit: node.deficit,
                     incidentCount: node.incident_count,
+                    kind: match node.kind {
+                        PowerNodeKind::Generator => fb::PowerNodeKind::Generator,
+                        PowerNodeKind::Storage => fb::PowerNodeKind::Storage,
+                        PowerNodeKind::Consumer => fb::PowerNodeKind::Consumer,
+                        PowerNodeKind::Hybrid => fb::PowerNodeKind::Hybrid,
+                    },
+                    priority: node.priority,
+                    shed: node.shed,
                 },
             )
         })
@@ -164,6 +347,14 @@ fn to_fb_crisis_severity_band(band: CrisisSeverityBand) -> fb::CrisisSeverityBan
     }
 }
 
+fn from_fb_crisis_severity_band(band: fb::CrisisSeverityBand) -> CrisisSeverityBand {
+    match band {
+        fb::CrisisSeverityBand::Warn => CrisisSeverityBand::Warn,
+        fb::CrisisSeverityBand::Critical => CrisisSeverityBand::Critical,
+        _ => CrisisSeverityBand::Safe,
+    }
+}
+
 fn create_crisis_trend_samples<'a>(
     builder: &mut FbBuilder<'a>,
     samples: &[CrisisTrendSample],
@@ -204,6 +395,8 @@ fn create_crisis_gauges<'a>(
                     staleTicks: gauge.stale_ticks,
                     band: to_fb_crisis_severity_band(gauge.band),
                     history: Some(history),
+                    rawBand: to_fb_crisis_severity_band(gauge.raw_band),
+                    emaBand: to_fb_crisis_severity_band(gauge.ema_band),
                 },
             )
         })
@@ -244,6 +437,53 @@ fn create_crisis_overlay_annotations<'a>(
                     label: Some(label),
                     severity: to_fb_crisis_severity_band(annotation.severity),
                     path: Some(path),
+                    crisisId: annotation.crisis_id,
+                },
+            )
+        })
+        .collect();
+    builder.create_vector(&offsets)
+}
+
+fn create_crisis_ledger_entries<'a>(
+    builder: &mut FbBuilder<'a>,
+    crises: &[CrisisLedgerEntryState],
+) -> WIPOffset<flatbuffers::Vector<'a, ForwardsUOffset<fb::CrisisLedgerEntryState<'a>>>> {
+    let offsets: Vec<_> = crises
+        .iter()
+        .map(|crisis| {
+            let archetype = builder.create_string(&crisis.archetype);
+            let tiles = builder.create_vector(&crisis.tiles);
+            fb::CrisisLedgerEntryState::create(
+                builder,
+                &fb::CrisisLedgerEntryStateArgs {
+                    crisisId: crisis.crisis_id,
+                    archetype: Some(archetype),
+                    faction: crisis.faction,
+                    chainDepth: crisis.chain_depth,
+                    escalated: crisis.escalated,
+                    tiles: Some(tiles),
+                },
+            )
+        })
+        .collect();
+    builder.create_vector(&offsets)
+}
+
+fn create_crisis_follow_ons<'a>(
+    builder: &mut FbBuilder<'a>,
+    follow_ons: &[CrisisFollowOnState],
+) -> WIPOffset<flatbuffers::Vector<'a, ForwardsUOffset<fb::CrisisFollowOnState<'a>>>> {
+    let offsets: Vec<_> = follow_ons
+        .iter()
+        .map(|follow_on| {
+            let archetype = builder.create_string(&follow_on.archetype);
+            fb::CrisisFollowOnState::create(
+                builder,
+                &fb::CrisisFollowOnStateArgs {
+                    faction: follow_on.faction,
+                    archetype: Some(archetype),
+                    chainDepth: follow_on.chain_depth,
                 },
             )
         })
@@ -255,13 +495,17 @@ fn create_crisis_overlay<'a>(
     builder: &mut FbBuilder<'a>,
     overlay: &CrisisOverlayState,
 ) -> WIPOffset<fb::CrisisOverlayState<'a>> {
-    let heatmap = create_scalar_raster(builder, &overlay.heatmap);
+    let heatmap = create_scalar_raster(builder, CRISIS_HEATMAP_CHANNEL, &overlay.heatmap);
     let annotations = create_crisis_overlay_annotations(builder, &overlay.annotations);
+    let crises = create_crisis_ledger_entries(builder, &overlay.crises);
+    let pending_follow_ons = create_crisis_follow_ons(builder, &overlay.pending_follow_ons);
     fb::CrisisOverlayState::create(
         builder,
         &fb::CrisisOverlayStateArgs {
             heatmap: Some(heatmap),
             annotations: Some(annotations),
+            crises: Some(crises),
+            pendingFollowOns: Some(pending_follow_ons),
         },
     )
 }
@@ -306,3 +550,12 @@ fn to_fb_corruption_subsystem(subsystem: CorruptionSubsystem) -> fb::CorruptionS
         CorruptionSubsystem::Governance => fb::CorruptionSubsystem::Governance,
     }
 }
+
+fn from_fb_corruption_subsystem(subsystem: fb::CorruptionSubsystem) -> CorruptionSubsystem {
+    match subsystem {
+        fb::CorruptionSubsystem::Trade => CorruptionSubsystem::Trade,
+        fb::CorruptionSubsystem::Military => CorruptionSubsystem::Military,
+        fb::CorruptionSubsystem::Governance => CorruptionSubsystem::Governance,
+        _ => CorruptionSubsystem::Logistics,
+    }
+}
@@ -1,13 +1,16 @@
 //! Knowledge-section FlatBuffers serialization.
 
-use crate::codec::FbBuilder;
+use crate::codec::{decode_list, decode_string, FbBuilder};
 use crate::state::knowledge::{
-    DiscoveredSitesState, DiscoveryProgressEntry, GreatDiscoveryDefinitionState,
-    GreatDiscoveryProgressState, GreatDiscoveryRequirementState, GreatDiscoveryState,
+    DiscoveredSiteState, DiscoveredSitesState, DiscoveryProgressEntry, EspionageAgentState,
+    GreatDiscoveryDefinitionState, GreatDiscoveryEffectAuditState,
+    GreatDiscoveryProgressDeltaState, GreatDiscoveryProgressState,
+    GreatDiscoveryRequirementEdgeState, GreatDiscoveryRequirementState, GreatDiscoveryState,
     GreatDiscoveryTelemetryState, KnowledgeCountermeasureKind, KnowledgeCountermeasureState,
-    KnowledgeField, KnowledgeInfiltrationState, KnowledgeLedgerEntryState, KnowledgeMetricsState,
-    KnowledgeModifierBreakdownState, KnowledgeModifierSource, KnowledgeSecurityPosture,
-    KnowledgeTimelineEventKind, KnowledgeTimelineEventState,
+    KnowledgeEarlyWarningEntryState, KnowledgeEarlyWarningState, KnowledgeField,
+    KnowledgeInfiltrationState, KnowledgeLeakFlags, KnowledgeLedgerEntryState,
+    KnowledgeMetricsState, KnowledgeModifierBreakdownState, KnowledgeModifierSource,
+    KnowledgeSecurityPosture, KnowledgeTimelineEventKind, KnowledgeTimelineEventState,
 };
 use crate::world::{WorldDelta, WorldSnapshot};
 use flatbuffers::{ForwardsUOffset, WIPOffset};
@@ -27,7 +30,9 @@ pub(crate) fn serialize_knowledge_section<'a>(
     let knowledge_ledger = create_knowledge_ledger(builder, &snapshot.knowledge_ledger);
     let knowledge_timeline = create_knowledge_timeline(builder, &snapshot.knowledge_timeline);
     let knowledge_metrics = create_knowledge_metrics(builder, &snapshot.knowledge_metrics);
+    let early_warnings = create_early_warnings(builder, &snapshot.knowledge_early_warnings);
     let discovered_sites = create_discovered_sites(builder, &snapshot.discovered_sites);
+    let espionage_agents = create_espionage_agents(builder, &snapshot.espionage_agents);
     let discovery_progress = create_discovery_progress(builder, &snapshot.discovery_progress);
     fb::KnowledgeSection::create(
         builder,
@@ -42,6 +47,8 @@ pub(crate) fn serialize_knowledge_section<'a>(
             discoveredSites: Some(discovered_sites),
             discoveryProgress: Some(discovery_progress),
             removedKnowledgeLedger: None,
+            knowledgeEarlyWarnings: Some(early_warnings),
+            espionageAgents: Some(espionage_agents),
         },
     )
 }
@@ -68,10 +75,18 @@ pub(crate) fn serialize_knowledge_section_delta<'a>(
         .knowledge_metrics
         .as_ref()
         .map(|metrics| create_knowledge_metrics(builder, metrics));
+    let early_warnings = delta
+        .knowledge_early_warnings
+        .as_ref()
+        .map(|warnings| create_early_warnings(builder, warnings));
     let discovered_sites = delta
         .discovered_sites
         .as_ref()
         .map(|entries| create_discovered_sites(builder, entries));
+    let espionage_agents = delta
+        .espionage_agents
+        .as_ref()
+        .map(|agents| create_espionage_agents(builder, agents));
     let discovery_progress = create_discovery_progress(builder, &delta.discovery_progress);
     fb::KnowledgeSection::create(
         builder,
@@ -86,10 +101,195 @@ pub(crate) fn serialize_knowledge_section_delta<'a>(
             discoveredSites: discovered_sites,
             discoveryProgress: Some(discovery_progress),
             removedKnowledgeLedger: Some(removed_knowledge_ledger),
+            knowledgeEarlyWarnings: early_warnings,
+            espionageAgents: espionage_agents,
         },
     )
 }
 
+pub(crate) fn decode_knowledge_section(
+    section: fb::KnowledgeSection<'_>,
+    snapshot: &mut WorldSnapshot,
+) {
+    snapshot.great_discovery_definitions = decode_list(
+        section.greatDiscoveryDefinitions(),
+        decode_great_discovery_definition,
+    );
+    snapshot.great_discoveries =
+        decode_list(section.greatDiscoveries(), |entry| GreatDiscoveryState {
+            id: entry.id(),
+            faction: entry.faction(),
+            field: from_fb_knowledge_field(entry.field()),
+            tick: entry.tick(),
+            publicly_deployed: entry.publiclyDeployed(),
+            effect_flags: entry.effectFlags(),
+        });
+    snapshot.great_discovery_progress = decode_list(section.greatDiscoveryProgress(), |entry| {
+        GreatDiscoveryProgressState {
+            faction: entry.faction(),
+            discovery: entry.discovery(),
+            progress: entry.progress(),
+            observation_deficit: entry.observationDeficit(),
+            eta_ticks: entry.etaTicks(),
+            covert: entry.covert(),
+            progress_delta: entry
+                .progressDelta()
+                .map(|delta| GreatDiscoveryProgressDeltaState {
+                    progress: delta.progress(),
+                    observation_deficit: delta.observationDeficit(),
+                }),
+        }
+    });
+    if let Some(telemetry) = section.greatDiscoveryTelemetry() {
+        snapshot.great_discovery_telemetry = GreatDiscoveryTelemetryState {
+            total_resolved: telemetry.totalResolved(),
+            pending_candidates: telemetry.pendingCandidates(),
+            active_constellations: telemetry.activeConstellations(),
+            effect_audit: decode_list(telemetry.effectAudit(), |entry| {
+                GreatDiscoveryEffectAuditState {
+                    tick: entry.tick(),
+                    discovery: entry.discovery(),
+                    faction: entry.faction(),
+                    effect: entry.effect(),
+                    target: decode_string(entry.target()),
+                    magnitude: entry.magnitude(),
+                    target_id: entry.targetId(),
+                }
+            }),
+        };
+    }
+    snapshot.knowledge_ledger = decode_list(section.knowledgeLedger(), decode_knowledge_ledger);
+    snapshot.knowledge_timeline = decode_list(section.knowledgeTimeline(), |event| {
+        KnowledgeTimelineEventState {
+            tick: event.tick(),
+            kind: from_fb_knowledge_timeline_kind(event.kind()),
+            source_faction: event.sourceFaction(),
+            delta_percent: event.deltaPercent(),
+            note_handle: event.noteHandle().map(str::to_string),
+        }
+    });
+    if let Some(metrics) = section.knowledgeMetrics() {
+        snapshot.knowledge_metrics = KnowledgeMetricsState {
+            leak_warnings: metrics.leakWarnings(),
+            leak_criticals: metrics.leakCriticals(),
+            countermeasures_active: metrics.countermeasuresActive(),
+            common_knowledge_total: metrics.commonKnowledgeTotal(),
+        };
+    }
+    snapshot.knowledge_early_warnings = decode_list(section.knowledgeEarlyWarnings(), |state| {
+        KnowledgeEarlyWarningState {
+            faction: state.faction(),
+            entries: decode_list(state.entries(), |entry| KnowledgeEarlyWarningEntryState {
+                discovery_id: entry.discoveryId(),
+                time_to_cascade: entry.timeToCascade(),
+                progress_percent: entry.progressPercent(),
+            }),
+        }
+    });
+    snapshot.discovered_sites =
+        decode_list(section.discoveredSites(), |state| DiscoveredSitesState {
+            faction: state.faction(),
+            sites: decode_list(state.sites(), |site| DiscoveredSiteState {
+                x: site.x(),
+                y: site.y(),
+                site_id: decode_string(site.site_id()),
+                category: decode_string(site.category()),
+                display_name: decode_string(site.display_name()),
+                glyph: decode_string(site.glyph()),
+            }),
+        });
+    snapshot.espionage_agents =
+        decode_list(section.espionageAgents(), |agent| EspionageAgentState {
+            faction: agent.faction(),
+            handle: agent.handle(),
+            template_id: decode_string(agent.templateId()),
+            name: decode_string(agent.name()),
+            experience: agent.experience(),
+        });
+    snapshot.discovery_progress = decode_list(section.discoveryProgress(), |entry| {
+        DiscoveryProgressEntry {
+            faction: entry.faction(),
+            discovery: entry.discovery(),
+            progress: entry.progress(),
+        }
+    });
+}
+
+/// `prerequisites` never ships, so it decodes empty.
+fn decode_great_discovery_definition(
+    entry: fb::GreatDiscoveryDefinition<'_>,
+) -> GreatDiscoveryDefinitionState {
+    GreatDiscoveryDefinitionState {
+        id: entry.id(),
+        name: decode_string(entry.name()),
+        field: from_fb_knowledge_field(entry.field()),
+        tier: entry.tier().map(str::to_string),
+        summary: entry.summary().map(str::to_string),
+        tags: decode_list(entry.tags(), str::to_string),
+        observation_threshold: entry.observationThreshold(),
+        cooldown_ticks: entry.cooldownTicks(),
+        freshness_window: entry.hasFreshnessWindow().then(|| entry.freshnessWindow()),
+        effect_flags: entry.effectFlags(),
+        covert_until_public: entry.covertUntilPublic(),
+        effects_summary: decode_list(entry.effectsSummary(), str::to_string),
+        observation_notes: entry.observationNotes().map(str::to_string),
+        leak_profile: entry.leakProfile().map(str::to_string),
+        requirements: decode_list(entry.requirements(), |requirement| {
+            GreatDiscoveryRequirementState {
+                discovery: requirement.discoveryId(),
+                weight: requirement.weight(),
+                minimum_progress: requirement.minimumProgress(),
+                name: requirement.name().map(str::to_string),
+                summary: requirement.summary().map(str::to_string),
+            }
+        }),
+        edges: decode_list(entry.edges(), |edge| GreatDiscoveryRequirementEdgeState {
+            from: edge.from(),
+            to: edge.to(),
+            weight: edge.weight(),
+        }),
+        ..Default::default()
+    }
+}
+
+fn decode_knowledge_ledger(entry: fb::KnowledgeLedgerState<'_>) -> KnowledgeLedgerEntryState {
+    KnowledgeLedgerEntryState {
+        discovery_id: entry.discoveryId(),
+        owner_faction: entry.ownerFaction(),
+        tier: entry.tier(),
+        progress_percent: entry.progressPercent(),
+        half_life_ticks: entry.halfLifeTicks(),
+        time_to_cascade: entry.timeToCascade(),
+        security_posture: from_fb_knowledge_security_posture(entry.securityPosture()),
+        countermeasures: decode_list(entry.countermeasures(), |countermeasure| {
+            KnowledgeCountermeasureState {
+                kind: from_fb_knowledge_countermeasure(countermeasure.kind()),
+                potency: countermeasure.potency(),
+                upkeep: countermeasure.upkeep(),
+                remaining_ticks: countermeasure.remainingTicks(),
+            }
+        }),
+        infiltrations: decode_list(entry.infiltrations(), |infiltration| {
+            KnowledgeInfiltrationState {
+                faction: infiltration.faction(),
+                blueprint_fidelity: infiltration.blueprintFidelity(),
+                suspicion: infiltration.suspicion(),
+                cells: infiltration.cells(),
+                last_activity_tick: infiltration.lastActivityTick(),
+            }
+        }),
+        modifiers: decode_list(entry.modifiers(), |modifier| {
+            KnowledgeModifierBreakdownState {
+                source: from_fb_knowledge_modifier_source(modifier.source()),
+                delta_half_life: modifier.deltaHalfLife(),
+                delta_progress: modifier.deltaProgress(),
+                note_handle: modifier.noteHandle().map(str::to_string),
+            }
+        }),
+        flags: KnowledgeLeakFlags::new(entry.flags()),
+    }
+}
+
 fn create_discovered_sites<'a>(
     builder: &mut FbBuilder<'a>,
     states: &[DiscoveredSitesState],
@@ -128,6 +328,28 @@ fn create_discovered_sites<'a>(
     builder.create_vector(&entries)
 }
 
+fn create_espionage_agents<'a>(
+    builder: &mut FbBuilder<'a>,
+    agents: &[EspionageAgentState],
+) -> WIPOffset<flatbuffers::Vector<'a, ForwardsUOffset<fb::EspionageAgentState<'a>>>> {
+    let mut offsets = Vec::with_capacity(agents.len());
+    for agent in agents {
+        let template_id = builder.create_string(agent.template_id.as_str());
+        let name = builder.create_string(agent.name.as_str());
+        offsets.push(fb::EspionageAgentState::create(
+            builder,
+            &fb::EspionageAgentStateArgs {
+                faction: agent.faction,
+                handle: agent.handle,
+                templateId: Some(template_id),
+                name: Some(name),
+                experience: agent.experience,
+            },
+        ));
+    }
+    builder.create_vector(&offsets)
+}
+
 fn create_discovery_progress<'a>(
     builder: &mut FbBuilder<'a>,
     entries: &[DiscoveryProgressEntry],
@@ -159,6 +381,17 @@ fn to_fb_knowledge_field(field: KnowledgeField) -> fb::KnowledgeField {
     }
 }
 
+fn from_fb_knowledge_field(field: fb::KnowledgeField) -> KnowledgeField {
+    match field {
+        fb::KnowledgeField::Chemistry => KnowledgeField::Chemistry,
+        fb::KnowledgeField::Biology => KnowledgeField::Biology,
+        fb::KnowledgeField::Data => KnowledgeField::Data,
+        fb::KnowledgeField::Communication => KnowledgeField::Communication,
+        fb::KnowledgeField::Exotic => KnowledgeField::Exotic,
+        _ => KnowledgeField::Physics,
+    }
+}
+
 fn create_great_discoveries<'a>(
     builder: &mut FbBuilder<'a>,
     entries: &[GreatDiscoveryState],
@@ -257,6 +490,21 @@ fn create_great_discovery_definitions<'a>(
                 .map(|value| builder.create_string(value.as_str()));
             let requirements =
                 create_great_discovery_definition_requirements(builder, &entry.requirements);
+            let edge_offsets: Vec<_> = entry
+                .edges
+                .iter()
+                .map(|edge| {
+                    fb::GreatDiscoveryRequirementEdge::create(
+                        builder,
+                        &fb::GreatDiscoveryRequirementEdgeArgs {
+                            from: edge.from,
+                            to: edge.to,
+                            weight: edge.weight,
+                        },
+                    )
+                })
+                .collect();
+            let edges = builder.create_vector(&edge_offsets);
 
             fb::GreatDiscoveryDefinition::create(
                 builder,
@@ -277,6 +525,7 @@ fn create_great_discovery_definitions<'a>(
                     observationNotes: observation_notes,
                     leakProfile: leak_profile,
                     requirements: Some(requirements),
+                    edges: Some(edges),
                 },
             )
         })
@@ -291,6 +540,15 @@ fn create_great_discovery_progress<'a>(
     let offsets: Vec<_> = entries
         .iter()
         .map(|entry| {
+            let progress_delta = entry.progress_delta.map(|delta| {
+                fb::GreatDiscoveryProgressDelta::create(
+                    builder,
+                    &fb::GreatDiscoveryProgressDeltaArgs {
+                        progress: delta.progress,
+                        observationDeficit: delta.observation_deficit,
+                    },
+                )
+            });
             fb::GreatDiscoveryProgressState::create(
                 builder,
                 &fb::GreatDiscoveryProgressStateArgs {
@@ -300,6 +558,7 @@ fn create_great_discovery_progress<'a>(
                     observationDeficit: entry.observation_deficit,
                     etaTicks: entry.eta_ticks,
                     covert: entry.covert,
+                    progressDelta: progress_delta,
                 },
             )
         })
@@ -311,12 +570,33 @@ fn create_great_discovery_telemetry<'a>(
     builder: &mut FbBuilder<'a>,
     telemetry: &GreatDiscoveryTelemetryState,
 ) -> WIPOffset<fb::GreatDiscoveryTelemetryState<'a>> {
+    let audit: Vec<_> = telemetry
+        .effect_audit
+        .iter()
+        .map(|entry| {
+            let target = builder.create_string(entry.target.as_str());
+            fb::GreatDiscoveryEffectAuditState::create(
+                builder,
+                &fb::GreatDiscoveryEffectAuditStateArgs {
+                    tick: entry.tick,
+                    discovery: entry.discovery,
+                    faction: entry.faction,
+                    effect: entry.effect,
+                    target: Some(target),
+                    magnitude: entry.magnitude,
+                    targetId: entry.target_id,
+                },
+            )
+        })
+        .collect();
+    let effect_audit = builder.create_vector(&audit);
     fb::GreatDiscoveryTelemetryState::create(
         builder,
         &fb::GreatDiscoveryTelemetryStateArgs {
             totalResolved: telemetry.total_resolved,
             pendingCandidates: telemetry.pending_candidates,
             activeConstellations: telemetry.active_constellations,
+            effectAudit: Some(effect_audit),
         },
     )
 }
@@ -461,6 +741,38 @@ fn create_knowledge_metrics<'a>(
     )
 }
 
+fn create_early_warnings<'a>(
+    builder: &mut FbBuilder<'a>,
+    states: &[KnowledgeEarlyWarningState],
+) -> WIPOffset<flatbuffers::Vector<'a, ForwardsUOffset<fb::KnowledgeEarlyWarningState<'a>>>> {
+    let mut offsets = Vec::with_capacity(states.len());
+    for state in states {
+        let entries: Vec<_> = state
+            .entries
+            .iter()
+            .map(|entry| {
+                fb::KnowledgeEarlyWarningEntry::create(
+                    builder,
+                    &fb::KnowledgeEarlyWarningEntryArgs {
+                        discoveryId: entry.discovery_id,
+                        timeToCascade: entry.time_to_cascade,
+                        progressPercent: entry.progress_percent,
+                    },
+                )
+            })
+            .collect();
+        let entries = builder.create_vector(&entries);
+        offsets.push(fb::KnowledgeEarlyWarningState::create(
+            builder,
+            &fb::KnowledgeEarlyWarningStateArgs {
+                faction: state.faction,
+                entries: Some(entries),
+            },
+        ));
+    }
+    builder.create_vector(&offsets)
+}
+
 fn to_fb_knowledge_security_posture(
     posture: KnowledgeSecurityPosture,
 ) -> fb::KnowledgeSecurityPosture {
@@ -472,6 +784,17 @@ fn to_fb_knowledge_security_posture(
     }
 }
 
+fn from_fb_knowledge_security_posture(
+    posture: fb::KnowledgeSecurityPosture,
+) -> KnowledgeSecurityPosture {
+    match posture {
+        fb::KnowledgeSecurityPosture::Standard => KnowledgeSecurityPosture::Standard,
+        fb::KnowledgeSecurityPosture::Hardened => KnowledgeSecurityPosture::Hardened,
+        fb::KnowledgeSecurityPosture::BlackVault => KnowledgeSecurityPosture::BlackVault,
+        _ => KnowledgeSecurityPosture::Minimal,
+    }
+}
+
 fn to_fb_knowledge_countermeasure(
     kind: KnowledgeCountermeasureKind,
 ) -> fb::KnowledgeCountermeasureKind {
@@ -491,6 +814,23 @@ fn to_fb_knowledge_countermeasure(
     }
 }
 
+fn from_fb_knowledge_countermeasure(
+    kind: fb::KnowledgeCountermeasureKind,
+) -> KnowledgeCountermeasureKind {
+    match kind {
+        fb::KnowledgeCountermeasureKind::CounterIntelSweep => {
+            KnowledgeCountermeasureKind::CounterIntelSweep
+        }
+        fb::KnowledgeCountermeasureKind::Misinformation => {
+            KnowledgeCountermeasureKind::Misinformation
+        }
+        fb::KnowledgeCountermeasureKind::KnowledgeDebtRelief => {
+            KnowledgeCountermeasureKind::KnowledgeDebtRelief
+        }
+        _ => KnowledgeCountermeasureKind::SecurityInvestment,
+    }
+}
+
 fn to_fb_knowledge_modifier_source(source: KnowledgeModifierSource) -> fb::KnowledgeModifierSource {
     match source {
         KnowledgeModifierSource::Visibility => fb::KnowledgeModifierSource::Visibility,
@@ -504,6 +844,21 @@ fn to_fb_knowledge_modifier_source(source: KnowledgeModifierSource) -> fb::Knowl
     }
 }
 
+fn from_fb_knowledge_modifier_source(
+    source: fb::KnowledgeModifierSource,
+) -> KnowledgeModifierSource {
+    match source {
+        fb::KnowledgeModifierSource::Security => KnowledgeModifierSource::Security,
+        fb::KnowledgeModifierSource::Spycraft => KnowledgeModifierSource::Spycraft,
+        fb::KnowledgeModifierSource::Culture => KnowledgeModifierSource::Culture,
+        fb::KnowledgeModifierSource::Exposure => KnowledgeModifierSource::Exposure,
+        fb::KnowledgeModifierSource::Debt => KnowledgeModifierSource::Debt,
+        fb::KnowledgeModifierSource::Treaty => KnowledgeModifierSource::Treaty,
+        fb::KnowledgeModifierSource::Event => KnowledgeModifierSource::Event,
+        _ => KnowledgeModifierSource::Visibility,
+    }
+}
+
 fn to_fb_knowledge_timeline_kind(
     kind: KnowledgeTimelineEventKind,
 ) -> fb::KnowledgeTimelineEventKind {
@@ -517,3 +872,17 @@ fn to_fb_knowledge_timeline_kind(
         KnowledgeTimelineEventKind::Digest => fb::KnowledgeTimelineEventKind::Digest,
     }
 }
+
+fn from_fb_knowledge_timeline_kind(
+    kind: fb::KnowledgeTimelineEventKind,
+) -> KnowledgeTimelineEventKind {
+    match kind {
+        fb::KnowledgeTimelineEventKind::SpyProbe => KnowledgeTimelineEventKind::SpyProbe,
+        fb::KnowledgeTimelineEventKind::CounterIntel => KnowledgeTimelineEventKind::CounterIntel,
+        fb::KnowledgeTimelineEventKind::Exposure => KnowledgeTimelineEventKind::Exposure,
+        fb::KnowledgeTimelineEventKind::Treaty => KnowledgeTimelineEventKind::Treaty,
+        fb::KnowledgeTimelineEventKind::Cascade => KnowledgeTimelineEventKind::Cascade,
+        fb::KnowledgeTimelineEventKind::Digest => KnowledgeTimelineEventKind::Digest,
+        _ => KnowledgeTimelineEventKind::LeakProgress,
+    }
+}
@@ -1,9 +1,11 @@
 //! Map-section FlatBuffers serialization.
 
-use crate::codec::{create_float_raster, FbBuilder};
+use crate::codec::{
+    create_float_raster, decode_float_raster, decode_list, decode_vector, FbBuilder,
+};
 use crate::state::map::{
-    ClimateBandsState, ElevationOverlayState, MountainKind, TerrainOverlayState, TerrainType,
-    TileState,
+    ClimateBandsState, ElevationOverlayState, MountainKind, TerrainOverlayState, TerrainSample,
+    TerrainTags, TerrainType, TileState,
 };
 use crate::world::{WorldDelta, WorldSnapshot};
 use flatbuffers::{ForwardsUOffset, WIPOffset};
@@ -66,6 +68,78 @@ pub(crate) fn serialize_map_section_delta<'a>(
     )
 }
 
+pub(crate) fn decode_map_section(section: fb::MapSection<'_>, snapshot: &mut WorldSnapshot) {
+    snapshot.tiles = decode_list(section.tiles(), decode_tile);
+    if let Some(overlay) = section.terrainOverlay() {
+        snapshot.terrain = decode_terrain_overlay(overlay);
+    }
+    if let Some(overlay) = section.elevationOverlay() {
+        snapshot.elevation_overlay = decode_elevation_overlay(overlay);
+    }
+    if let Some(raster) = section.moistureRaster() {
+        snapshot.moisture_raster = decode_float_raster(raster);
+    }
+    if let Some(bands) = section.climateBands() {
+        snapshot.climate_bands = ClimateBandsState {
+            polar_max_temp: bands.polarMaxTemp(),
+            boreal_max_temp: bands.borealMaxTemp(),
+            temperate_max_temp: bands.temperateMaxTemp(),
+        };
+    }
+}
+
+fn decode_tile(tile: fb::TileState<'_>) -> TileState {
+    TileState {
+        entity: tile.entity(),
+        x: tile.x(),
+        y: tile.y(),
+        element: tile.element(),
+        mass: tile.mass(),
+        temperature: tile.temperature(),
+        terrain: from_fb_terrain_type(tile.terrain()),
+        terrain_tags: TerrainTags::new(tile.terrainTags()),
+        culture_layer: tile.cultureLayer(),
+        mountain_kind: from_fb_mountain_kind(tile.mountainKind()),
+        mountain_relief: tile.mountainRelief(),
+        habitability: tile.habitability(),
+        river_edges: tile.riverEdges(),
+        river_inflow: tile.riverInflow(),
+        river_channel: tile.riverChannel(),
+        graze_biomass: tile.grazeBiomass(),
+        graze_capacity: tile.grazeCapacity(),
+        graze_ecology_phase: tile.grazeEcologyPhase(),
+        forage_capacity: tile.forageCapacity(),
+        underlying_terrain: from_fb_terrain_type(tile.underlyingTerrain()),
+        river_flow: tile.riverFlow(),
+        lake_depth: tile.lakeDepth(),
+    }
+}
+
+fn decode_terrain_overlay(overlay: fb::TerrainOverlay<'_>) -> TerrainOverlayState {
+    TerrainOverlayState {
+        width: overlay.width(),
+        height: overlay.height(),
+        samples: decode_list(overlay.samples(), |sample| TerrainSample {
+            terrain: from_fb_terrain_type(sample.terrain()),
+            tags: TerrainTags::new(sample.tags()),
+            mountain_kind: from_fb_mountain_kind(sample.mountainKind()),
+            relief_scale: sample.reliefScale(),
+        }),
+        palette_hash: overlay.paletteHash(),
+    }
+}
+
+fn decode_elevation_overlay(overlay: fb::ElevationOverlay<'_>) -> ElevationOverlayState {
+    ElevationOverlayState {
+        width: overlay.width(),
+        height: overlay.height(),
+        min_value: overlay.minValue(),
+        max_value: overlay.maxValue(),
+        samples: decode_vector(overlay.samples()),
+        sea_level: overlay.seaLevel(),
+    }
+}
+
 fn create_elevation_overlay<'a>(
     builder: &mut FbBuilder<'a>,
     overlay: &ElevationOverlayState,
@@ -128,6 +202,8 @@ fn create_tiles<'a>(
                     riverEdges: tile.river_edges,
                     riverInflow: tile.river_inflow,
                     riverChannel: tile.river_channel,
+                    riverFlow: tile.river_flow,
+                    lakeDepth: tile.lake_depth,
                 },
             )
         })
@@ -161,6 +237,7 @@ fn create_terrain_overlay<'a>(
             width: overlay.width,
             height: overlay.height,
             samples: Some(samples),
+            paletteHash: overlay.palette_hash,
         },
     )
 }
@@ -208,6 +285,60 @@ fn to_fb_terrain_type(terrain: TerrainType) -> fb::TerrainType {
     }
 }
 
+fn from_fb_terrain_type(terrain: fb::TerrainType) -> TerrainType {
+    match terrain {
+        fb::TerrainType::DeepOcean => TerrainType::DeepOcean,
+        fb::TerrainType::ContinentalShelf => TerrainType::ContinentalShelf,
+        fb::TerrainType::InlandSea => TerrainType::InlandSea,
+        fb::TerrainType::CoralShelf => TerrainType::CoralShelf,
+        fb::TerrainType::HydrothermalVentField => TerrainType::HydrothermalVentField,
+        fb::TerrainType::TidalFlat => TerrainType::TidalFlat,
+        fb::TerrainType::RiverDelta => TerrainType::RiverDelta,
+        fb::TerrainType::MangroveSwamp => TerrainType::MangroveSwamp,
+        fb::TerrainType::FreshwaterMarsh => TerrainType::FreshwaterMarsh,
+        fb::TerrainType::Floodplain => TerrainType::Floodplain,
+        fb::TerrainType::AlluvialPlain => TerrainType::AlluvialPlain,
+        fb::TerrainType::PrairieSteppe => TerrainType::PrairieSteppe,
+        fb::TerrainType::MixedWoodland => TerrainType::MixedWoodland,
+        fb::TerrainType::BorealTaiga => TerrainType::BorealTaiga,
+        fb::TerrainType::PeatHeath => TerrainType::PeatHeath,
+        fb::TerrainType::HotDesertErg => TerrainType::HotDesertErg,
+        fb::TerrainType::RockyReg => TerrainType::RockyReg,
+        fb::TerrainType::SemiAridScrub => TerrainType::SemiAridScrub,
+        fb::TerrainType::SaltFlat => TerrainType::SaltFlat,
+        fb::TerrainType::OasisBasin => TerrainType::OasisBasin,
+        fb::TerrainType::Tundra => TerrainType::Tundra,
+        fb::TerrainType::PeriglacialSteppe => TerrainType::PeriglacialSteppe,
+        fb::TerrainType::Glacier => TerrainType::Glacier,
+        fb::TerrainType::SeasonalSnowfield => TerrainType::SeasonalSnowfield,
+        fb::TerrainType::RollingHills => TerrainType::RollingHills,
+        fb::TerrainType::HighPlateau => TerrainType::HighPlateau,
+        fb::TerrainType::AlpineMountain => TerrainType::AlpineMountain,
+        fb::TerrainType::KarstHighland => TerrainType::KarstHighland,
+        fb::TerrainType::CanyonBadlands => TerrainType::CanyonBadlands,
+        fb::TerrainType::ActiveVolcanoSlope => TerrainType::ActiveVolcanoSlope,
+        fb::TerrainType::BasalticLavaField => TerrainType::BasalticLavaField,
+        fb::TerrainType::AshPlain => TerrainType::AshPlain,
+        fb::TerrainType::FumaroleBasin => TerrainType::FumaroleBasin,
+        fb::TerrainType::ImpactCraterField => TerrainType::ImpactCraterField,
+        fb::TerrainType::KarstCavernMouth => TerrainType::KarstCavernMouth,
+        fb::TerrainType::SinkholeField => TerrainType::SinkholeField,
+        fb::TerrainType::AquiferCeiling => TerrainType::AquiferCeiling,
+        fb::TerrainType::NavigableRiver => TerrainType::NavigableRiver,
+        _ => TerrainType::default(),
+    }
+}
+
+fn from_fb_mountain_kind(kind: fb::MountainKind) -> MountainKind {
+    match kind {
+        fb::MountainKind::Fold => MountainKind::Fold,
+        fb::MountainKind::Fault => MountainKind::Fault,
+        fb::MountainKind::Volcanic => MountainKind::Volcanic,
+        fb::MountainKind::Dome => MountainKind::Dome,
+        _ => MountainKind::None,
+    }
+}
+
 fn to_fb_mountain_kind(kind: MountainKind) -> fb::MountainKind {
     match kind {
         MountainKind::None => fb::MountainKind::None,
@@ -1,8 +1,9 @@
-//! FlatBuffers encoders for the world payloads.
+//! FlatBuffers encoders for the world payloads, plus the snapshot decoder.
 //!
 //! `build_snapshot_flatbuffer` / `build_delta_flatbuffer` assemble the envelope from the nine
 //! per-domain section serializers in the sibling modules; helpers shared by two or more sections
-//! live here.
+//! live here. [`decode_snapshot_flatbuffer`] is the inverse for a full snapshot frame, built from
+//! one `decode_*_section` per sibling module.
 
 // ---------------------------------------------------------------------------
 // Per-section FlatBuffers serializers (docs/plan_snapshot_and_systems_decomposition.md §1).
@@ -25,24 +26,41 @@ mod subsistence;
 mod vision;
 
 use crate::codec::campaign::{
-    create_campaign_label, create_victory_state, serialize_campaign_section,
-    serialize_campaign_section_delta,
+    create_campaign_label, create_victory_state, decode_campaign_label, decode_campaign_section,
+    serialize_campaign_section, serialize_campaign_section_delta,
+};
+use crate::codec::culture::{
+    decode_culture_section, serialize_culture_section, serialize_culture_section_delta,
+};
+use crate::codec::economy::{
+    decode_economy_section, serialize_economy_section, serialize_economy_section_delta,
+};
+use crate::codec::governance::{
+    decode_governance_section, serialize_governance_section, serialize_governance_section_delta,
+};
+use crate::codec::knowledge::{
+    decode_knowledge_section, serialize_knowledge_section, serialize_knowledge_section_delta,
+};
+use crate::codec::map::{decode_map_section, serialize_map_section, serialize_map_section_delta};
+use crate::codec::population::{
+    decode_population_section, serialize_population_section, serialize_population_section_delta,
 };
-use crate::codec::culture::{serialize_culture_section, serialize_culture_section_delta};
-use crate::codec::economy::{serialize_economy_section, serialize_economy_section_delta};
-use crate::codec::governance::{serialize_governance_section, serialize_governance_section_delta};
-use crate::codec::knowledge::{serialize_knowledge_section, serialize_knowledge_section_delta};
-use crate::codec::map::{serialize_map_section, serialize_map_section_delta};
-use crate::codec::population::{serialize_population_section, serialize_population_section_delta};
 use crate::codec::subsistence::{
-    serialize_subsistence_section, serialize_subsistence_section_delta,
+    decode_subsistence_section, serialize_subsistence_section, serialize_subsistence_section_delta,
+};
+use crate::codec::vision::{
+    decode_vision_section, serialize_vision_section, serialize_vision_section_delta,
 };
-use crate::codec::vision::{serialize_vision_section, serialize_vision_section_delta};
+use crate::raster::ScalarChannel;
 use crate::state::economy::KnownTechFragment;
-use crate::state::map::{FloatRasterState, ScalarRasterState};
-use crate::world::{WorldDelta, WorldSnapshot};
+use crate::state::map::{
+    FloatRasterState, OverlayChannelFlags, RasterPrecision, ScalarRasterState,
+    FLOAT_RASTER_FIXED_SCALE,
+};
+use crate::world::{SnapshotHeader, WorldDelta, WorldSnapshot};
 use flatbuffers::{DefaultAllocator, FlatBufferBuilder, ForwardsUOffset, WIPOffset};
 use shadow_scale_flatbuffers::generated::shadow_scale::sim as fb;
+use std::fmt;
 
 pub(crate) type FbBuilder<'a> = FlatBufferBuilder<'a, DefaultAllocator>;
 
@@ -60,6 +78,99 @@ pub fn encode_delta_flatbuffer(delta: &WorldDelta) -> Vec<u8> {
     builder.finished_data().to_vec()
 }
 
+/// Why [`decode_snapshot_flatbuffer`] refused a buffer.
+#[derive(Debug)]
+pub enum SnapshotFlatbufferError {
+    /// The bytes do not verify as a FlatBuffers envelope.
+    Invalid(flatbuffers::InvalidFlatbuffer),
+    /// The envelope verified but carries a delta, not a full snapshot.
+    NotASnapshot,
+}
+
+impl fmt::Display for SnapshotFlatbufferError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Invalid(err) => write!(f, "invalid snapshot flatbuffer: {err}"),
+            Self::NotASnapshot => f.write_str("flatbuffer envelope does not carry a snapshot"),
+        }
+    }
+}
+
+impl std::error::Error for SnapshotFlatbufferError {}
+
+/// Decodes a frame written by [`encode_snapshot_flatbuffer`] back to a [`WorldSnapshot`].
+///
+/// Lossy where the wire is: float rasters come back within [`RasterPrecision::max_error`], and the
+/// snapshot-only fields the wire never carries (`herd_registry`, `forage_registry`,
+/// `graze_registry`, `beat_ledger`, `latent_influencers`, `start_marker`) decode empty. Good for
+/// analysing an exported frame; not a substitute for the JSON snapshot when restoring a sim.
+pub fn decode_snapshot_flatbuffer(bytes: &[u8]) -> Result<WorldSnapshot, SnapshotFlatbufferError> {
+    let envelope = fb::root_as_envelope(bytes).map_err(SnapshotFlatbufferError::Invalid)?;
+    let table = envelope
+        .payload_as_snapshot()
+        .ok_or(SnapshotFlatbufferError::NotASnapshot)?;
+    let mut snapshot = WorldSnapshot {
+        capability_flags: table.capabilityFlags(),
+        ..Default::default()
+    };
+    if let Some(header) = table.header() {
+        snapshot.header = decode_snapshot_header(header);
+    }
+    if let Some(section) = table.map() {
+        decode_map_section(section, &mut snapshot);
+    }
+    if let Some(section) = table.economy() {
+        decode_economy_section(section, &mut snapshot);
+    }
+    if let Some(section) = table.population() {
+        decode_population_section(section, &mut snapshot);
+    }
+    if let Some(section) = table.subsistence() {
+        decode_subsistence_section(section, &mut snapshot);
+    }
+    if let Some(section) = table.knowledge() {
+        decode_knowledge_section(section, &mut snapshot);
+    }
+    if let Some(section) = table.governance() {
+        decode_governance_section(section, &mut snapshot);
+    }
+    if let Some(section) = table.culture() {
+        decode_culture_section(section, &mut snapshot);
+    }
+    if let Some(section) = table.vision() {
+        decode_vision_section(section, &mut snapshot);
+    }
+    if let Some(section) = table.campaign() {
+        decode_campaign_section(section, &mut snapshot);
+    }
+    Ok(snapshot)
+}
+
+/// The header's `victory` is the same table the campaign section points at, so it is decoded there.
+fn decode_snapshot_header(header: fb::SnapshotHeader<'_>) -> SnapshotHeader {
+    SnapshotHeader {
+        tick: header.tick(),
+        tile_count: header.tileCount(),
+        logistics_count: header.logisticsCount(),
+        trade_link_count: header.tradeLinkCount(),
+        population_count: header.populationCount(),
+        power_count: header.powerCount(),
+        influencer_count: header.influencerCount(),
+        hash: header.hash(),
+        campaign_label: header.campaignLabel().map(decode_campaign_label),
+        wrap_horizontal: header.wrapHorizontal(),
+        server_build: decode_string(header.serverBuild()),
+        world_epoch: header.worldEpoch(),
+        config_hash: header.configHash(),
+        sequence: header.sequence(),
+        influencers_omitted: header.influencersOmitted(),
+        delta_part: header.deltaPart(),
+        delta_part_count: header.deltaPartCount(),
+        computed_channels: OverlayChannelFlags(header.computedChannels()),
+        sequence_span: header.sequenceSpan(),
+    }
+}
+
 fn build_snapshot_flatbuffer<'a>(
     builder: &mut FbBuilder<'a>,
     snapshot: &WorldSnapshot,
@@ -88,6 +199,13 @@ fn build_snapshot_flatbuffer<'a>(
             wrapHorizontal: snapshot.header.wrap_horizontal,
             serverBuild: Some(server_build_fb),
             worldEpoch: snapshot.header.world_epoch,
+            configHash: snapshot.header.config_hash,
+            sequence: snapshot.header.sequence,
+            influencersOmitted: snapshot.header.influencers_omitted,
+            deltaPart: snapshot.header.delta_part,
+            deltaPartCount: snapshot.header.delta_part_count,
+            computedChannels: snapshot.header.computed_channels.bits(),
+            sequenceSpan: snapshot.header.sequence_span,
         },
     );
 
@@ -161,6 +279,13 @@ fn build_delta_flatbuffer<'a>(
             wrapHorizontal: delta.header.wrap_horizontal,
             serverBuild: server_build_fb,
             worldEpoch: delta.header.world_epoch,
+            configHash: delta.header.config_hash,
+            sequence: delta.header.sequence,
+            influencersOmitted: delta.header.influencers_omitted,
+            deltaPart: delta.header.delta_part,
+            deltaPartCount: delta.header.delta_part_count,
+            computedChannels: delta.header.computed_channels.bits(),
+            sequenceSpan: delta.header.sequence_span,
         },
     );
 
@@ -220,10 +345,56 @@ pub(crate) fn create_known_fragments<'a>(
     builder.create_vector(&offsets)
 }
 
+pub(crate) fn decode_known_fragments<'a>(
+    fragments: Option<flatbuffers::Vector<'a, ForwardsUOffset<fb::KnownTechFragment<'a>>>>,
+) -> Vec<KnownTechFragment> {
+    decode_list(fragments, |fragment| KnownTechFragment {
+        discovery_id: fragment.discoveryId(),
+        progress: fragment.progress(),
+        fidelity: fragment.fidelity(),
+    })
+}
+
+/// An absent string decodes empty, matching the encoders that skip empty strings.
+pub(crate) fn decode_string(value: Option<&str>) -> String {
+    value.unwrap_or_default().to_string()
+}
+
+/// An absent vector decodes empty, matching the encoders that skip empty vectors.
+pub(crate) fn decode_vector<'a, T: flatbuffers::Follow<'a> + 'a>(
+    values: Option<flatbuffers::Vector<'a, T>>,
+) -> Vec<T::Inner> {
+    values
+        .map(|values| values.iter().collect())
+        .unwrap_or_default()
+}
+
+pub(crate) fn decode_list<'a, T: flatbuffers::Follow<'a> + 'a, U>(
+    values: Option<flatbuffers::Vector<'a, T>>,
+    decode: impl FnMut(T::Inner) -> U,
+) -> Vec<U> {
+    values
+        .map(|values| values.iter().map(decode).collect())
+        .unwrap_or_default()
+}
+
+/// Encode one scalar raster. In debug builds a sample outside `channel`'s documented unit panics
+/// here, at the source, rather than surfacing as a wrong-scale overlay in the client.
 pub(crate) fn create_scalar_raster<'a>(
     builder: &mut FbBuilder<'a>,
+    channel: ScalarChannel,
     raster: &ScalarRasterState,
 ) -> WIPOffset<fb::ScalarRaster<'a>> {
+    if cfg!(debug_assertions) {
+        if let Some((idx, raw)) = channel.unit.first_out_of_range(&raster.samples) {
+            panic!(
+                "{} sample {raw} at index {idx} is outside its {:?} range {:?}",
+                channel.name,
+                channel.unit,
+                channel.unit.raw_range()
+            );
+        }
+    }
     let samples = builder.create_vector(&raster.samples);
     fb::ScalarRaster::create(
         builder,
@@ -235,17 +406,144 @@ pub(crate) fn create_scalar_raster<'a>(
     )
 }
 
+pub(crate) fn decode_scalar_raster(raster: fb::ScalarRaster<'_>) -> ScalarRasterState {
+    ScalarRasterState {
+        width: raster.width(),
+        height: raster.height(),
+        samples: decode_vector(raster.samples()),
+    }
+}
+
 pub(crate) fn create_float_raster<'a>(
     builder: &mut FbBuilder<'a>,
     raster: &FloatRasterState,
 ) -> WIPOffset<fb::FloatRaster<'a>> {
-    let samples = builder.create_vector(&raster.samples);
-    fb::FloatRaster::create(
-        builder,
-        &fb::FloatRasterArgs {
-            width: raster.width,
-            height: raster.height,
-            samples: Some(samples),
+    let mut args = fb::FloatRasterArgs {
+        width: raster.width,
+        height: raster.height,
+        precision: to_fb_raster_precision(raster.precision),
+        ..Default::default()
+    };
+    match raster.precision {
+        RasterPrecision::Float32 => {
+            args.samples = Some(builder.create_vector(&raster.samples));
+        }
+        RasterPrecision::Unorm8 => {
+            let (min, max) = float_raster_range(&raster.samples);
+            let packed: Vec<u8> = raster
+                .samples
+                .iter()
+                .map(|&value| quantize_unorm(value, min, max, u8::MAX as f32) as u8)
+                .collect();
+            args.minValue = min;
+            args.maxValue = max;
+            args.unorm8Samples = Some(builder.create_vector(&packed));
+        }
+        RasterPrecision::Unorm16 => {
+            let (min, max) = float_raster_range(&raster.samples);
+            let packed: Vec<u16> = raster
+                .samples
+                .iter()
+                .map(|&value| quantize_unorm(value, min, max, u16::MAX as f32) as u16)
+                .collect();
+            args.minValue = min;
+            args.maxValue = max;
+            args.unorm16Samples = Some(builder.create_vector(&packed));
+        }
+        RasterPrecision::Fixed64 => {
+            let packed: Vec<i64> = raster
+                .samples
+                .iter()
+                .map(|&value| (value as f64 * FLOAT_RASTER_FIXED_SCALE as f64).round() as i64)
+                .collect();
+            args.fixedSamples = Some(builder.create_vector(&packed));
+        }
+    }
+    fb::FloatRaster::create(builder, &args)
+}
+
+/// Decodes a [`fb::FloatRaster`] back to `f32` samples whatever its `precision`, within
+/// [`RasterPrecision::max_error`] of what the sim captured. Shared with the client decoder.
+pub fn decode_float_raster_samples(raster: fb::FloatRaster<'_>) -> Vec<f32> {
+    match raster.precision() {
+        fb::RasterPrecision::Unorm8 => {
+            let (min, span) = (raster.minValue(), raster.maxValue() - raster.minValue());
+            raster
+                .unorm8Samples()
+                .map(|packed| {
+                    packed
+                        .iter()
+                        .map(|q| min + span * (q as f32 / u8::MAX as f32))
+                        .collect()
+                })
+                .unwrap_or_default()
+        }
+        fb::RasterPrecision::Unorm16 => {
+            let (min, span) = (raster.minValue(), raster.maxValue() - raster.minValue());
+            raster
+                .unorm16Samples()
+                .map(|packed| {
+                    packed
+                        .iter()
+                        .map(|q| min + span * (q as f32 / u16::MAX as f32))
+                        .collect()
+                })
+                .unwrap_or_default()
+        }
+        fb::RasterPrecision::Fixed64 => raster
+            .fixedSamples()
+            .map(|packed| {
+                packed
+                    .iter()
+                    .map(|raw| (raw as f64 / FLOAT_RASTER_FIXED_SCALE as f64) as f32)
+                    .collect()
+            })
+            .unwrap_or_default(),
+        _ => raster
+            .samples()
+            .map(|samples| samples.iter().collect())
+            .unwrap_or_default(),
+    }
+}
+
+pub(crate) fn decode_float_raster(raster: fb::FloatRaster<'_>) -> FloatRasterState {
+    FloatRasterState {
+        width: raster.width(),
+        height: raster.height(),
+        precision: match raster.precision() {
+            fb::RasterPrecision::Unorm8 => RasterPrecision::Unorm8,
+            fb::RasterPrecision::Unorm16 => RasterPrecision::Unorm16,
+            fb::RasterPrecision::Fixed64 => RasterPrecision::Fixed64,
+            _ => RasterPrecision::Float32,
         },
-    )
+        samples: decode_float_raster_samples(raster),
+    }
+}
+
+fn to_fb_raster_precision(precision: RasterPrecision) -> fb::RasterPrecision {
+    match precision {
+        RasterPrecision::Float32 => fb::RasterPrecision::Float32,
+        RasterPrecision::Unorm8 => fb::RasterPrecision::Unorm8,
+        RasterPrecision::Unorm16 => fb::RasterPrecision::Unorm16,
+        RasterPrecision::Fixed64 => fb::RasterPrecision::Fixed64,
+    }
+}
+
+/// `[min, max]` over the finite samples; `(0, 0)` for an empty or all-non-finite raster.
+fn float_raster_range(samples: &[f32]) -> (f32, f32) {
+    let mut finite = samples.iter().copied().filter(|value| value.is_finite());
+    let Some(first) = finite.next() else {
+        return (0.0, 0.0);
+    };
+    finite.fold((first, first), |(min, max), value| {
+        (min.min(value), max.max(value))
+    })
+}
+
+/// Maps `value` onto `0..=levels` over `[min, max]`; a flat raster packs to all zeros.
+fn quantize_unorm(value: f32, min: f32, max: f32, levels: f32) -> f32 {
+    if max <= min || !value.is_finite() {
+        return 0.0;
+    }
+    (((value - min) / (max - min)).clamp(0.0, 1.0) * levels).round()
 }
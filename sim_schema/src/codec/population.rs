@@ -1,9 +1,14 @@
 //! Population-section FlatBuffers serialization.
 
-use crate::codec::{create_known_fragments, FbBuilder};
+use crate::codec::{
+    create_known_fragments, create_scalar_raster, decode_known_fragments, decode_list,
+    decode_scalar_raster, decode_string, decode_vector, FbBuilder,
+};
+use crate::raster::POPULATION_DENSITY_RASTER_CHANNEL;
 use crate::state::population::{
-    AccessibleStockpileEntryState, GenerationState, PopulationCohortState,
-    PopulationDemographicsState,
+    AccessibleStockpileEntryState, AccessibleStockpileState, CohortStoreState, GenerationState,
+    HarvestTaskState, LaborAssignmentState, MoraleSummaryState, PendingMigrationState,
+    PopulationCohortState, PopulationDemographicsState, ScoutTaskState, SettlementStageViewState,
 };
 use crate::world::{WorldDelta, WorldSnapshot};
 use flatbuffers::{ForwardsUOffset, WIPOffset};
@@ -15,7 +20,13 @@ pub(crate) fn serialize_population_section<'a>(
 ) -> WIPOffset<fb::PopulationSection<'a>> {
     let populations = create_populations(builder, &snapshot.populations);
     let demographics = create_demographics(builder, &snapshot.demographics);
+    let morale_summary = create_morale_summary(builder, &snapshot.morale_summary);
     let generations = create_generations(builder, &snapshot.generations);
+    let density_raster = create_scalar_raster(
+        builder,
+        POPULATION_DENSITY_RASTER_CHANNEL,
+        &snapshot.population_density_raster,
+    );
     fb::PopulationSection::create(
         builder,
         &fb::PopulationSectionArgs {
@@ -24,6 +35,8 @@ pub(crate) fn serialize_population_section<'a>(
             generations: Some(generations),
             removedPopulations: None,
             removedGenerations: None,
+            populationDensityRaster: Some(density_raster),
+            moraleSummary: Some(morale_summary),
         },
     )
 }
@@ -38,8 +51,16 @@ pub(crate) fn serialize_population_section_delta<'a>(
         .demographics
         .as_ref()
         .map(|entries| create_demographics(builder, entries));
+    let morale_summary = delta
+        .morale_summary
+        .as_ref()
+        .map(|entries| create_morale_summary(builder, entries));
     let generations = create_generations(builder, &delta.generations);
     let removed_generations = builder.create_vector(&delta.removed_generations);
+    let density_raster = delta
+        .population_density_raster
+        .as_ref()
+        .map(|raster| create_scalar_raster(builder, POPULATION_DENSITY_RASTER_CHANNEL, raster));
     fb::PopulationSection::create(
         builder,
         &fb::PopulationSectionArgs {
@@ -48,10 +69,188 @@ pub(crate) fn serialize_population_section_delta<'a>(
             generations: Some(generations),
             removedPopulations: Some(removed_populations),
             removedGenerations: Some(removed_generations),
+            populationDensityRaster: density_raster,
+            moraleSummary: morale_summary,
         },
     )
 }
 
+pub(crate) fn decode_population_section(
+    section: fb::PopulationSection<'_>,
+    snapshot: &mut WorldSnapshot,
+) {
+    snapshot.populations = decode_list(section.populations(), decode_cohort);
+    snapshot.demographics = decode_list(section.demographics(), |state| {
+        PopulationDemographicsState {
+            faction: state.faction(),
+            children: state.children(),
+            working: state.working(),
+            elders: state.elders(),
+        }
+    });
+    snapshot.morale_summary = decode_list(section.moraleSummary(), |state| MoraleSummaryState {
+        faction: state.faction(),
+        cohorts: state.cohorts(),
+        min: state.min(),
+        mean: state.mean(),
+        max: state.max(),
+        threshold: state.threshold(),
+        below_threshold: state.belowThreshold(),
+    });
+    snapshot.generations = decode_list(section.generations(), |generation| GenerationState {
+        id: generation.id(),
+        name: decode_string(generation.name()),
+        bias_knowledge: generation.biasKnowledge(),
+        bias_trust: generation.biasTrust(),
+        bias_equity: generation.biasEquity(),
+        bias_agency: generation.biasAgency(),
+    });
+    if let Some(raster) = section.populationDensityRaster() {
+        snapshot.population_density_raster = decode_scalar_raster(raster);
+    }
+}
+
+fn decode_cohort(cohort: fb::PopulationCohortState<'_>) -> PopulationCohortState {
+    PopulationCohortState {
+        entity: cohort.entity(),
+        home: cohort.home(),
+        current_x: cohort.currentX(),
+        current_y: cohort.currentY(),
+        is_traveling: cohort.isTraveling(),
+        size: cohort.size(),
+        morale: cohort.morale(),
+        generation: cohort.generation(),
+        faction: cohort.faction(),
+        knowledge_fragments: decode_known_fragments(cohort.knowledgeFragments()),
+        migration: cohort.migration().map(|pending| PendingMigrationState {
+            destination: pending.destination(),
+            eta: pending.eta(),
+            fragments: decode_known_fragments(pending.fragments()),
+        }),
+        harvest_task: cohort.harvestTask().map(|task| HarvestTaskState {
+            kind: decode_string(task.kind()),
+            module: decode_string(task.module()),
+            band_label: decode_string(task.bandLabel()),
+            target_tile: task.targetTile(),
+            target_x: task.targetX(),
+            target_y: task.targetY(),
+            travel_remaining: task.travelRemaining(),
+            travel_total: task.travelTotal(),
+            gather_remaining: task.gatherRemaining(),
+            gather_total: task.gatherTotal(),
+            provisions_reward: task.provisionsReward(),
+            trade_goods_reward: task.tradeGoodsReward(),
+            started_tick: task.startedTick(),
+        }),
+        scout_task: cohort.scoutTask().map(|task| ScoutTaskState {
+            band_label: decode_string(task.bandLabel()),
+            target_tile: task.targetTile(),
+            target_x: task.targetX(),
+            target_y: task.targetY(),
+            travel_remaining: task.travelRemaining(),
+            travel_total: task.travelTotal(),
+            reveal_radius: task.revealRadius(),
+            reveal_duration: task.revealDuration(),
+            morale_gain: task.moraleGain(),
+            started_tick: task.startedTick(),
+        }),
+        accessible_stockpile: cohort.accessibleStockpile().map(|stockpile| {
+            AccessibleStockpileState {
+                radius: stockpile.radius(),
+                entries: decode_list(stockpile.entries(), |entry| AccessibleStockpileEntryState {
+                    item: decode_string(entry.item()),
+                    quantity: entry.quantity(),
+                }),
+            }
+        }),
+        children: cohort.children(),
+        working: cohort.working(),
+        elders: cohort.elders(),
+        stores: decode_list(cohort.stores(), |entry| CohortStoreState {
+            item: decode_string(entry.item()),
+            quantity: entry.quantity(),
+        }),
+        age_turns: cohort.ageTurns(),
+        turns_of_food: cohort.turnsOfFood(),
+        activity: decode_string(cohort.activity()),
+        hunt_mode: decode_string(cohort.huntMode()),
+        labor_assignments: decode_list(cohort.laborAssignments(), decode_labor_assignment),
+        idle_workers: cohort.idleWorkers(),
+        working_age: cohort.workingAge(),
+        work_range: cohort.workRange(),
+        scout_reveal_radius: cohort.scoutRevealRadius(),
+        is_expedition: cohort.isExpedition(),
+        expedition_mission: decode_string(cohort.expeditionMission()),
+        expedition_phase: decode_string(cohort.expeditionPhase()),
+        home_band_entity: cohort.homeBandEntity(),
+        expedition_announced: cohort.expeditionAnnounced(),
+        pending_reveal_x: decode_vector(cohort.pendingRevealX()),
+        pending_reveal_y: decode_vector(cohort.pendingRevealY()),
+        max_expedition_party_size: cohort.maxExpeditionPartySize(),
+        expedition_carry_cap: cohort.expeditionCarryCap(),
+        expedition_target_herd: decode_string(cohort.expeditionTargetHerd()),
+        expedition_hunt_policy: decode_string(cohort.expeditionHuntPolicy()),
+        travel_target_x: cohort.travelTargetX(),
+        travel_target_y: cohort.travelTargetY(),
+        hunt_reach: cohort.huntReach(),
+        supply_network_id: cohort.supplyNetworkId(),
+        morale_delta: cohort.moraleDelta(),
+        morale_cause: cohort.moraleCause(),
+        output_multiplier: cohort.outputMultiplier(),
+        discontent_fraction: cohort.discontentFraction(),
+        last_emigrated: cohort.lastEmigrated(),
+        last_immigrated: cohort.lastImmigrated(),
+        grievance: cohort.grievance(),
+        morale_settling: cohort.moraleSettling(),
+        morale_terrain: cohort.moraleTerrain(),
+        morale_climate: cohort.moraleClimate(),
+        morale_unrest: cohort.moraleUnrest(),
+        settlement_stage: cohort
+            .settlementStage()
+            .map(|stage| SettlementStageViewState {
+                id: decode_string(stage.id()),
+                label: decode_string(stage.label()),
+                icon: decode_string(stage.icon()),
+            })
+            .unwrap_or_default(),
+        food_income: cohort.foodIncome(),
+        pen_feed_upkeep: cohort.penFeedUpkeep(),
+        food_consumption: cohort.foodConsumption(),
+        hunt_per_worker_provisions: cohort.huntPerWorkerProvisions(),
+        expedition_viability_warn_turns: cohort.expeditionViabilityWarnTurns(),
+        expedition_per_worker_carry: cohort.expeditionPerWorkerCarry(),
+        band_move_tiles_per_turn: cohort.bandMoveTilesPerTurn(),
+        expedition_eta_turns: cohort.expeditionEtaTurns(),
+        expedition_projected_delivery: cohort.expeditionProjectedDelivery(),
+        expedition_recurring: cohort.expeditionRecurring(),
+        fodder_store: cohort.fodderStore(),
+        expedition_reveal_radius: cohort.expeditionRevealRadius(),
+        expedition_reveal_footprint_x: decode_vector(cohort.expeditionRevealFootprintX()),
+        expedition_reveal_footprint_y: decode_vector(cohort.expeditionRevealFootprintY()),
+        morale_diet: cohort.moraleDiet(),
+    }
+}
+
+/// `species` is persisted intent that never ships, so it decodes empty.
+fn decode_labor_assignment(assignment: fb::LaborAssignment<'_>) -> LaborAssignmentState {
+    LaborAssignmentState {
+        kind: decode_string(assignment.kind()),
+        workers: assignment.workers(),
+        target_x: assignment.targetX(),
+        target_y: assignment.targetY(),
+        fauna_id: decode_string(assignment.faunaId()),
+        policy: decode_string(assignment.policy()),
+        actual_yield: assignment.actualYield(),
+        sustainable_yield: assignment.sustainableYield(),
+        workers_needed: assignment.workersNeeded(),
+        wasted_yield: assignment.wastedYield(),
+        overdraws: assignment.overdraws(),
+        realized_yield: assignment.realizedYield(),
+        arrival_schedule: decode_vector(assignment.arrivalSchedule()),
+        ..Default::default()
+    }
+}
+
 fn create_demographics<'a>(
     builder: &mut FbBuilder<'a>,
     states: &[PopulationDemographicsState],
@@ -72,6 +271,29 @@ fn create_demographics<'a>(
     builder.create_vector(&entries)
 }
 
+fn create_morale_summary<'a>(
+    builder: &mut FbBuilder<'a>,
+    states: &[MoraleSummaryState],
+) -> WIPOffset<flatbuffers::Vector<'a, ForwardsUOffset<fb::MoraleSummaryState<'a>>>> {
+    let mut entries = Vec::with_capacity(states.len());
+    for state in states {
+        let entry = fb::MoraleSummaryState::create(
+            builder,
+            &fb::MoraleSummaryStateArgs {
+                faction: state.faction,
+                cohorts: state.cohorts,
+                min: state.min,
+                mean: state.mean,
+                max: state.max,
+                threshold: state.threshold,
+                belowThreshold: state.below_threshold,
+            },
+        );
+        entries.push(entry);
+    }
+    builder.create_vector(&entries)
+}
+
 fn create_populations<'a>(
     builder: &mut FbBuilder<'a>,
     cohorts: &[PopulationCohortState],
@@ -256,6 +478,16 @@ fn create_populations<'a>(
             } else {
                 Some(builder.create_vector(&cohort.pending_reveal_y))
             };
+            let reveal_footprint_x = if cohort.expedition_reveal_footprint_x.is_empty() {
+                None
+            } else {
+                Some(builder.create_vector(&cohort.expedition_reveal_footprint_x))
+            };
+            let reveal_footprint_y = if cohort.expedition_reveal_footprint_y.is_empty() {
+                None
+            } else {
+                Some(builder.create_vector(&cohort.expedition_reveal_footprint_y))
+            };
             let accessible_stockpile_fb = cohort.accessible_stockpile.as_ref().map(|stockpile| {
                 let entries = if stockpile.entries.is_empty() {
                     None
@@ -345,6 +577,10 @@ fn create_populations<'a>(
                     // omits it from the generated Args — nothing to set.)
                     // The band's hay reserve (F3) — appended (append-only wire) after #165's trio.
                     fodderStore: cohort.fodder_store,
+                    expeditionRevealRadius: cohort.expedition_reveal_radius,
+                    expeditionRevealFootprintX: reveal_footprint_x,
+                    expeditionRevealFootprintY: reveal_footprint_y,
+                    moraleDiet: cohort.morale_diet,
                 },
             )
         })
@@ -1,9 +1,9 @@
 //! Subsistence-section FlatBuffers serialization.
 
-use crate::codec::FbBuilder;
+use crate::codec::{decode_list, decode_string, FbBuilder};
 use crate::state::subsistence::{
-    FloraShareInfo, FoodModuleState, ForagePatchState, HerdTelemetryState,
-    IntensificationKnowledgeState, SedentarizationState,
+    FloraShareInfo, FoodModuleState, ForagePatchState, HerdTelemetryState, HuntPolicyCeilingState,
+    HuntTripEstimateState, IntensificationKnowledgeState, SedentarizationState,
 };
 use crate::world::{WorldDelta, WorldSnapshot};
 use flatbuffers::{ForwardsUOffset, WIPOffset};
@@ -67,6 +67,135 @@ pub(crate) fn serialize_subsistence_section_delta<'a>(
     )
 }
 
+pub(crate) fn decode_subsistence_section(
+    section: fb::SubsistenceSection<'_>,
+    snapshot: &mut WorldSnapshot,
+) {
+    snapshot.herds = decode_list(section.herds(), decode_herd);
+    snapshot.forage_patches = decode_list(section.foragePatches(), decode_forage_patch);
+    snapshot.sedentarization =
+        decode_list(section.sedentarization(), |state| SedentarizationState {
+            faction: state.faction(),
+            score: state.score(),
+            stage: decode_string(state.stage()),
+        });
+    snapshot.intensification_knowledge = decode_list(section.intensificationKnowledge(), |state| {
+        IntensificationKnowledgeState {
+            faction: state.faction(),
+            cultivation: state.cultivation(),
+            herding: state.herding(),
+            seed_selection: state.seedSelection(),
+            penning: state.penning(),
+        }
+    });
+    snapshot.food_modules = decode_list(section.foodModules(), |module| FoodModuleState {
+        x: module.x(),
+        y: module.y(),
+        module: decode_string(module.module()),
+        seasonal_weight: module.seasonalWeight(),
+        kind: decode_string(module.kind()),
+    });
+}
+
+fn decode_herd(herd: fb::HerdTelemetryState<'_>) -> HerdTelemetryState {
+    HerdTelemetryState {
+        id: decode_string(herd.id()),
+        label: decode_string(herd.label()),
+        species: decode_string(herd.species()),
+        x: herd.x(),
+        y: herd.y(),
+        biomass: herd.biomass(),
+        route_length: herd.routeLength(),
+        next_x: herd.nextX(),
+        next_y: herd.nextY(),
+        size_class: decode_string(herd.sizeClass()),
+        huntable: herd.huntable(),
+        ecology_phase: decode_string(herd.ecologyPhase()),
+        domestication: herd.domestication(),
+        corralled: herd.corralled(),
+        corral_progress: herd.corralProgress(),
+        per_worker_yield: herd.perWorkerYield(),
+        corral_yield: herd.corralYield(),
+        hunt_policy_ceilings: decode_list(herd.huntPolicyCeilings(), |ceiling| {
+            HuntPolicyCeilingState {
+                policy: decode_string(ceiling.policy()),
+                provisions_per_turn: ceiling.provisionsPerTurn(),
+            }
+        }),
+        hunt_trip_estimates: decode_list(herd.huntTripEstimates(), |estimate| {
+            HuntTripEstimateState {
+                policy: decode_string(estimate.policy()),
+                party_workers: estimate.partyWorkers(),
+                turns_to_fill: estimate.turnsToFill(),
+                delivers_food: estimate.deliversFood(),
+                animals_taken: estimate.animalsTaken(),
+                delivered_food: estimate.deliveredFood(),
+                wasted_food: estimate.wastedFood(),
+            }
+        }),
+        pen_upkeep: herd.penUpkeep(),
+        pen_fed_fraction: herd.penFedFraction(),
+        carrying_capacity: herd.carryingCapacity(),
+        graze_range_radius: herd.grazeRangeRadius(),
+        pen_radius: herd.penRadius(),
+        pen_footprint_tiles: herd.penFootprintTiles(),
+        pen_pasture_fraction: herd.penPastureFraction(),
+        pen_extend_progress: herd.penExtendProgress(),
+        husbandry_ceiling: decode_string(herd.husbandryCeiling()),
+        body_mass: herd.bodyMass(),
+        food_per_animal: herd.foodPerAnimal(),
+        herders_needed: herd.herdersNeeded(),
+        herded_fraction: herd.herdedFraction(),
+        pastoral_yield: herd.pastoralYield(),
+        fodder_draw: herd.fodderDraw(),
+        pen_larder_bill: herd.penLarderBill(),
+        pen_hay_food: herd.penHayFood(),
+        attack: herd.attack(),
+        defense: herd.defense(),
+        ferocity: herd.ferocity(),
+        aggression: herd.aggression(),
+    }
+}
+
+fn decode_forage_patch(patch: fb::ForagePatchState<'_>) -> ForagePatchState {
+    ForagePatchState {
+        x: patch.x(),
+        y: patch.y(),
+        cultivation_progress: patch.cultivationProgress(),
+        is_cultivated: patch.isCultivated(),
+        owner: patch.hasOwner().then(|| patch.owner()),
+        biomass: patch.biomass(),
+        carrying_capacity: patch.carryingCapacity(),
+        ecology_phase: decode_string(patch.ecologyPhase()),
+        per_worker_yield: patch.perWorkerYield(),
+        ceiling_sustain: patch.ceilingSustain(),
+        ceiling_surplus: patch.ceilingSurplus(),
+        ceiling_market: patch.ceilingMarket(),
+        ceiling_eradicate: patch.ceilingEradicate(),
+        ceiling_cultivate: patch.ceilingCultivate(),
+        tended_yield: patch.tendedYield(),
+        field_progress: patch.fieldProgress(),
+        is_field: patch.isField(),
+        ceiling_sow: patch.ceilingSow(),
+        field_yield: patch.fieldYield(),
+        sow_site_refusal: decode_string(patch.sowSiteRefusal()),
+        composition: decode_list(patch.composition(), |share| FloraShareInfo {
+            species: decode_string(share.species()),
+            display_name: decode_string(share.displayName()),
+            share: share.share(),
+            can_cultivate: share.canCultivate(),
+            can_sow: share.canSow(),
+            cultivate_yield_ratio: share.cultivateYieldRatio(),
+            sow_yield_ratio: share.sowYieldRatio(),
+            cultivate_payoff: share.cultivatePayoff(),
+            sow_payoff: share.sowPayoff(),
+            sow_fodder_payoff: share.sowFodderPayoff(),
+        }),
+        committed_species: decode_string(patch.committedSpecies()),
+        committed_display_name: decode_string(patch.committedDisplayName()),
+    }
+}
+
 fn create_sedentarization<'a>(
     builder: &mut FbBuilder<'a>,
     states: &[SedentarizationState],
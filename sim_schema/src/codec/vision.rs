@@ -1,6 +1,7 @@
 //! Vision-section FlatBuffers serialization (the per-overlay rasters).
 
-use crate::codec::{create_scalar_raster, FbBuilder};
+use crate::codec::{create_scalar_raster, decode_scalar_raster, FbBuilder};
+use crate::raster::{FOG_RASTER_CHANNEL, MILITARY_RASTER_CHANNEL, VISIBILITY_RASTER_CHANNEL};
 use crate::world::{WorldDelta, WorldSnapshot};
 use flatbuffers::WIPOffset;
 use shadow_scale_flatbuffers::generated::shadow_scale::sim as fb;
@@ -9,9 +10,14 @@ pub(crate) fn serialize_vision_section<'a>(
     builder: &mut FbBuilder<'a>,
     snapshot: &WorldSnapshot,
 ) -> WIPOffset<fb::VisionSection<'a>> {
-    let fog_raster = create_scalar_raster(builder, &snapshot.fog_raster);
-    let visibility_raster = create_scalar_raster(builder, &snapshot.visibility_raster);
-    let military_raster = create_scalar_raster(builder, &snapshot.military_raster);
+    let fog_raster = create_scalar_raster(builder, FOG_RASTER_CHANNEL, &snapshot.fog_raster);
+    let visibility_raster = create_scalar_raster(
+        builder,
+        VISIBILITY_RASTER_CHANNEL,
+        &snapshot.visibility_raster,
+    );
+    let military_raster =
+        create_scalar_raster(builder, MILITARY_RASTER_CHANNEL, &snapshot.military_raster);
     fb::VisionSection::create(
         builder,
         &fb::VisionSectionArgs {
@@ -22,6 +28,18 @@ pub(crate) fn serialize_vision_section<'a>(
     )
 }
 
+pub(crate) fn decode_vision_section(section: fb::VisionSection<'_>, snapshot: &mut WorldSnapshot) {
+    if let Some(raster) = section.fogRaster() {
+        snapshot.fog_raster = decode_scalar_raster(raster);
+    }
+    if let Some(raster) = section.visibilityRaster() {
+        snapshot.visibility_raster = decode_scalar_raster(raster);
+    }
+    if let Some(raster) = section.militaryRaster() {
+        snapshot.military_raster = decode_scalar_raster(raster);
+    }
+}
+
 pub(crate) fn serialize_vision_section_delta<'a>(
     builder: &mut FbBuilder<'a>,
     delta: &WorldDelta,
@@ -29,15 +47,15 @@ pub(crate) fn serialize_vision_section_delta<'a>(
     let fog_raster = delta
         .fog_raster
         .as_ref()
-        .map(|raster| create_scalar_raster(builder, raster));
+        .map(|raster| create_scalar_raster(builder, FOG_RASTER_CHANNEL, raster));
     let visibility_raster = delta
         .visibility_raster
         .as_ref()
-        .map(|raster| create_scalar_raster(builder, raster));
+        .map(|raster| create_scalar_raster(builder, VISIBILITY_RASTER_CHANNEL, raster));
     let military_raster = delta
         .military_raster
         .as_ref()
-        .map(|raster| create_scalar_raster(builder, raster));
+        .map(|raster| create_scalar_raster(builder, MILITARY_RASTER_CHANNEL, raster));
     fb::VisionSection::create(
         builder,
         &fb::VisionSectionArgs {
@@ -0,0 +1,107 @@
+//! Human-readable JSON for fixed-point fields.
+//!
+//! Fixed-point values travel as raw `i64` at [`FIXED_POINT_SCALE`] (the sim's `Scalar::SCALE`), which
+//! reads as noise in a JSON dump (`750000` for `0.75`). Opt a field in with
+//! `#[serde(with = "crate::fixed_point")]`: human-readable formats (JSON) then carry the decimal
+//! value, while binary formats (bincode, and so `hash_snapshot`) keep the raw integer. The
+//! FlatBuffers codec never goes through serde and is unaffected.
+//!
+//! Decoding is exact: the decimal is scaled back and rounded to the nearest raw unit. An integer in
+//! JSON is taken as the **raw** value, so dumps written before a field opted in still load.
+
+use serde::de::{self, Visitor};
+use serde::{Deserializer, Serializer};
+
+use crate::state::map::FLOAT_RASTER_FIXED_SCALE;
+
+/// Raw units per 1.0.
+pub const FIXED_POINT_SCALE: i64 = FLOAT_RASTER_FIXED_SCALE;
+
+/// Raw fixed-point value as its decimal representation.
+pub fn to_decimal(raw: i64) -> f64 {
+    raw as f64 / FIXED_POINT_SCALE as f64
+}
+
+/// Decimal value back to the nearest raw fixed-point unit.
+pub fn from_decimal(value: f64) -> i64 {
+    (value * FIXED_POINT_SCALE as f64).round() as i64
+}
+
+pub fn serialize<S: Serializer>(raw: &i64, serializer: S) -> Result<S::Ok, S::Error> {
+    if serializer.is_human_readable() {
+        serializer.serialize_f64(to_decimal(*raw))
+    } else {
+        serializer.serialize_i64(*raw)
+    }
+}
+
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<i64, D::Error> {
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_any(FixedPointVisitor)
+    } else {
+        deserializer.deserialize_i64(FixedPointVisitor)
+    }
+}
+
+struct FixedPointVisitor;
+
+impl<'de> Visitor<'de> for FixedPointVisitor {
+    type Value = i64;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("a decimal fixed-point value or a raw integer")
+    }
+
+    fn visit_i64<E: de::Error>(self, raw: i64) -> Result<i64, E> {
+        Ok(raw)
+    }
+
+    fn visit_u64<E: de::Error>(self, raw: u64) -> Result<i64, E> {
+        i64::try_from(raw).map_err(|_| E::custom(format!("fixed-point raw {raw} overflows i64")))
+    }
+
+    fn visit_f64<E: de::Error>(self, value: f64) -> Result<i64, E> {
+        let raw = value * FIXED_POINT_SCALE as f64;
+        if !raw.is_finite() || raw.abs() >= i64::MAX as f64 {
+            return Err(E::custom(format!("fixed-point value {value} out of range")));
+        }
+        Ok(from_decimal(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Sample {
+        #[serde(with = "crate::fixed_point")]
+        value: i64,
+    }
+
+    #[test]
+    fn json_carries_the_decimal_and_round_trips_exactly() {
+        let sample = Sample { value: 750_000 };
+        let json = serde_json::to_string(&sample).unwrap();
+        assert_eq!(json, r#"{"value":0.75}"#);
+        assert_eq!(serde_json::from_str::<Sample>(&json).unwrap(), sample);
+
+        for raw in [0, 1, -1, 999_999, -2_500_001, 123_456_789_012] {
+            let json = serde_json::to_string(&Sample { value: raw }).unwrap();
+            let back: Sample = serde_json::from_str(&json).unwrap();
+            assert_eq!(back.value, raw, "{json}");
+        }
+    }
+
+    #[test]
+    fn integers_in_json_are_raw_and_bincode_stays_integral() {
+        let legacy: Sample = serde_json::from_str(r#"{"value":750000}"#).unwrap();
+        assert_eq!(legacy.value, 750_000);
+        assert!(serde_json::from_str::<Sample>(r#"{"value":1e300}"#).is_err());
+
+        let sample = Sample { value: 750_000 };
+        let bytes = bincode::serialize(&sample).unwrap();
+        assert_eq!(bytes, 750_000i64.to_le_bytes());
+        assert_eq!(bincode::deserialize::<Sample>(&bytes).unwrap(), sample);
+    }
+}
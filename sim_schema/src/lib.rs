@@ -6,17 +6,23 @@
 //!   three rasters are `ScalarRasterState` fields on [`WorldSnapshot`] itself).
 //! - [`world`] — the flat [`WorldSnapshot`] / [`WorldDelta`] payloads, their header, and the
 //!   bincode / JSON codecs plus the on-disk [`MapExport`].
-//! - [`codec`] — the FlatBuffers encoders, one module per section.
+//! - [`codec`] — the FlatBuffers encoders and the snapshot decoder, one module per section.
+//! - [`raster`] — raster post-processing (overlay normalization) shared by the sim and the client.
+//! - [`fixed_point`] — the `#[serde(with)]` helper that writes fixed-point fields as decimals in
+//!   JSON (not glob re-exported: name it by path).
 //!
 //! Every item is re-exported at the crate root, so consumers keep using `sim_schema::Foo`.
 //! When you add a snapshot field, append it to its section's `state` module **and** that
 //! section's `codec` module — see `sim_schema/README.md`.
 
 pub mod codec;
+pub mod fixed_point;
+pub mod raster;
 pub mod state;
 pub mod world;
 
 pub use codec::*;
+pub use raster::*;
 pub use state::*;
 pub use world::*;
 
@@ -93,4 +99,266 @@ mod tests {
         assert_eq!(herd.penUpkeep(), 0.0);
         assert_eq!(herd.penFedFraction(), 1.0);
     }
+
+    /// `computedChannels` carries the header's bitset verbatim, so a client can tell a disabled
+    /// (never computed) overlay from a computed all-zero one.
+    #[test]
+    fn computed_channels_survive_the_wire() {
+        let mut snapshot = WorldSnapshot::default();
+        snapshot.header.computed_channels = OverlayChannelFlags::LOGISTICS
+            | OverlayChannelFlags::ELEVATION
+            | OverlayChannelFlags::MOISTURE;
+
+        let bytes = encode_snapshot_flatbuffer(&snapshot);
+        let envelope = fb::root_as_envelope(&bytes).expect("snapshot decodes");
+        let header = envelope
+            .payload_as_snapshot()
+            .expect("snapshot payload")
+            .header()
+            .expect("header present");
+        assert_eq!(
+            header.computedChannels(),
+            snapshot.header.computed_channels.bits()
+        );
+        assert!(!OverlayChannelFlags(header.computedChannels()).contains(OverlayChannelFlags::FOG));
+    }
+
+    /// Command-feed severity survives both wires, and JSON written before the field existed
+    /// decodes as `Routine`.
+    #[test]
+    fn command_event_severity_round_trips() {
+        let severities = [
+            EventSeverity::Routine,
+            EventSeverity::Notable,
+            EventSeverity::Alert,
+            EventSeverity::Major,
+        ];
+        let snapshot = WorldSnapshot {
+            command_events: severities
+                .iter()
+                .map(|&severity| CommandEventState {
+                    tick: 7,
+                    kind: "campaign_victory".to_string(),
+                    label: severity.as_str().to_string(),
+                    severity,
+                    ..Default::default()
+                })
+                .collect(),
+            ..WorldSnapshot::default()
+        };
+
+        let bytes = encode_snapshot_flatbuffer(&snapshot);
+        let envelope = fb::root_as_envelope(&bytes).expect("snapshot decodes");
+        let events = envelope
+            .payload_as_snapshot()
+            .expect("snapshot payload")
+            .campaign()
+            .expect("campaign section present")
+            .commandEvents()
+            .expect("command events present");
+        let decoded: Vec<u8> = events.iter().map(|event| event.severity().0).collect();
+        assert_eq!(decoded, severities.map(|severity| severity as u8).to_vec());
+
+        let json = encode_snapshot_json(&snapshot).expect("snapshot encodes");
+        let restored = decode_snapshot_json(&json).expect("snapshot decodes");
+        assert_eq!(restored.command_events, snapshot.command_events);
+
+        let legacy: CommandEventState =
+            serde_json::from_str(r#"{"tick":3,"kind":"scout","faction":0,"label":"Scouted"}"#)
+                .expect("legacy entry decodes");
+        assert_eq!(legacy.severity, EventSeverity::Routine);
+    }
+
+    /// Opted-in fixed-point fields read as decimals in a JSON dump and restore the exact raw value;
+    /// the binary snapshot keeps them integral.
+    #[test]
+    fn fixed_point_fields_are_decimal_in_snapshot_json() {
+        let snapshot = WorldSnapshot {
+            morale_summary: vec![MoraleSummaryState {
+                faction: 0,
+                cohorts: 2,
+                min: 500_000,
+                mean: 750_000,
+                max: 1_000_000,
+                threshold: 400_001,
+                below_threshold: 0.0,
+            }],
+            ..WorldSnapshot::default()
+        };
+
+        let json = encode_snapshot_json(&snapshot).expect("snapshot encodes");
+        let value: serde_json::Value = serde_json::from_str(&json).expect("valid json");
+        let summary = &value["morale_summary"][0];
+        assert_eq!(summary["mean"], serde_json::json!(0.75));
+        assert_eq!(summary["threshold"], serde_json::json!(0.400001));
+
+        let restored = decode_snapshot_json(&json).expect("snapshot decodes");
+        assert_eq!(restored.morale_summary[0].mean, 750_000);
+        assert_eq!(restored.morale_summary, snapshot.morale_summary);
+
+        let bytes = encode_snapshot(&snapshot).expect("bincode encodes");
+        let restored: WorldSnapshot = bincode::deserialize(&bytes).expect("bincode decodes");
+        assert_eq!(restored.morale_summary, snapshot.morale_summary);
+    }
+
+    /// **Every moisture precision decodes within its quantization error.** The sim can ship the
+    /// moisture raster as raw `f32`, `u8`/`u16` normalized over its own range, or fixed-point; the
+    /// client decoder branches on the `precision` tag. Encode → decode each through the wire.
+    #[test]
+    fn moisture_raster_round_trips_within_each_precision_error() {
+        let samples: Vec<f32> = (0..64)
+            .map(|i| 0.05 + (i as f32 * 0.37).sin().abs() * 0.9)
+            .collect();
+        let (min, max) = samples
+            .iter()
+            .fold((f32::MAX, f32::MIN), |(lo, hi), &v| (lo.min(v), hi.max(v)));
+
+        for precision in [
+            RasterPrecision::Float32,
+            RasterPrecision::Unorm8,
+            RasterPrecision::Unorm16,
+            RasterPrecision::Fixed64,
+        ] {
+            let snapshot = WorldSnapshot {
+                moisture_raster: FloatRasterState {
+                    width: 8,
+                    height: 8,
+                    samples: samples.clone(),
+                    precision,
+                },
+                ..WorldSnapshot::default()
+            };
+
+            let bytes = encode_snapshot_flatbuffer(&snapshot);
+            let envelope = fb::root_as_envelope(&bytes).expect("snapshot decodes");
+            let raster = envelope
+                .payload_as_snapshot()
+                .expect("snapshot payload")
+                .map()
+                .expect("map section present")
+                .moistureRaster()
+                .expect("moisture raster present");
+            assert_eq!(raster.precision().0, precision as u8);
+
+            let decoded = decode_float_raster_samples(raster);
+            assert_eq!(
+                decoded.len(),
+                samples.len(),
+                "{precision:?} keeps every sample"
+            );
+            // A hair of f32 slack on top of the quantization step for the decode arithmetic.
+            let tolerance = precision.max_error(max - min) + 1e-6;
+            for (expected, actual) in samples.iter().zip(&decoded) {
+                assert!(
+                    (expected - actual).abs() <= tolerance,
+                    "{precision:?}: {actual} decoded for {expected} (tolerance {tolerance})"
+                );
+            }
+        }
+    }
+
+    /// **An out-of-range overlay sample is caught at encode, not on screen.** Fog is a `[0, 1]`
+    /// fraction; a negative sample means the producer used the wrong scale or sign, so debug
+    /// builds refuse to put it on the wire.
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "fog_raster sample -1 at index 2")]
+    fn a_negative_fog_sample_trips_the_encode_guard() {
+        let snapshot = WorldSnapshot {
+            fog_raster: ScalarRasterState {
+                width: 2,
+                height: 2,
+                samples: vec![0, FLOAT_RASTER_FIXED_SCALE, -1, 500_000],
+            },
+            ..WorldSnapshot::default()
+        };
+        encode_snapshot_flatbuffer(&snapshot);
+    }
+
+    /// A full frame decodes back to the snapshot it was encoded from, field for field, once the
+    /// snapshot-only fields the wire never carries are left out.
+    #[test]
+    fn a_snapshot_frame_decodes_back_to_its_snapshot() {
+        let mut snapshot = snapshot_with_herd(HerdTelemetryState {
+            id: "herd_pen".to_string(),
+            species: "Red Deer".to_string(),
+            corralled: true,
+            corral_yield: 3.5,
+            ..Default::default()
+        });
+        snapshot.header.tick = 12;
+        snapshot.header.config_hash = 0xfeed;
+        snapshot.header.computed_channels = OverlayChannelFlags::LOGISTICS;
+        snapshot.populations = vec![PopulationCohortState {
+            entity: 9,
+            size: 40,
+            activity: "forage".to_string(),
+            labor_assignments: vec![LaborAssignmentState {
+                kind: "hunt".to_string(),
+                workers: 3,
+                fauna_id: "herd_pen".to_string(),
+                arrival_schedule: vec![1.0, 0.5],
+                ..Default::default()
+            }],
+            migration: Some(PendingMigrationState {
+                destination: 2,
+                eta: 3,
+                fragments: vec![KnownTechFragment {
+                    discovery_id: 4,
+                    progress: 250_000,
+                    fidelity: 1_000_000,
+                }],
+            }),
+            ..Default::default()
+        }];
+        snapshot.command_events = vec![CommandEventState {
+            tick: 11,
+            kind: "scout".to_string(),
+            label: "Scouted".to_string(),
+            severity: EventSeverity::Alert,
+            ..Default::default()
+        }];
+        snapshot.moisture_raster = FloatRasterState {
+            width: 2,
+            height: 1,
+            samples: vec![0.25, 0.75],
+            ..Default::default()
+        };
+
+        let decoded = decode_snapshot_flatbuffer(&encode_snapshot_flatbuffer(&snapshot))
+            .expect("snapshot frame decodes");
+        assert_eq!(
+            encode_snapshot_json(&decoded).expect("decoded snapshot encodes"),
+            encode_snapshot_json(&snapshot).expect("snapshot encodes")
+        );
+    }
+
+    /// A delta frame is not a snapshot.
+    #[test]
+    fn a_delta_frame_does_not_decode_as_a_snapshot() {
+        let bytes = encode_delta_flatbuffer(&WorldDelta::default());
+        assert!(matches!(
+            decode_snapshot_flatbuffer(&bytes),
+            Err(SnapshotFlatbufferError::NotASnapshot)
+        ));
+    }
+
+    /// In-range samples at each unit's bounds, including a signed sentiment, encode cleanly.
+    #[test]
+    fn overlay_samples_at_their_unit_bounds_encode() {
+        let one = FLOAT_RASTER_FIXED_SCALE;
+        let raster = |samples: Vec<i64>| ScalarRasterState {
+            width: 2,
+            height: 1,
+            samples,
+        };
+        let snapshot = WorldSnapshot {
+            fog_raster: raster(vec![0, one]),
+            sentiment_raster: raster(vec![-one, one]),
+            logistics_raster: raster(vec![0, 40 * one]),
+            ..WorldSnapshot::default()
+        };
+        let bytes = encode_snapshot_flatbuffer(&snapshot);
+        assert!(fb::root_as_envelope(&bytes).is_ok());
+    }
 }
@@ -0,0 +1,210 @@
+//! Shared raster post-processing, so the sim and the client read overlay samples the same way.
+//!
+//! The client decodes every overlay to `f32` and min-max stretches it for display; that stretch
+//! lives here rather than in the client so any other consumer (tools, tests, the sim's own
+//! previews) lands on identical values. The fixed-point twin is `core_sim::bulk_normalize`.
+//!
+//! The per-channel [`ScalarUnit`] table also lives here: it is what the encoder checks a scalar
+//! raster against before it goes on the wire.
+
+use crate::state::map::FLOAT_RASTER_FIXED_SCALE;
+
+/// What a [`ScalarRasterState`](crate::ScalarRasterState) channel's raw `i64` samples mean. Every
+/// scalar raster is fixed-point at [`FLOAT_RASTER_FIXED_SCALE`] (the sim's `Scalar::SCALE`, 1e6);
+/// the unit says which raw values are legal for that channel.
+///
+/// Float channels are deliberately not covered: the moisture raster ships raw `f32` in `[0, 1]`
+/// and the elevation overlay its own unorm16 lattice, so neither is ever read through `Scalar`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScalarUnit {
+    /// A fraction in `[0, 1]`: raw `0..=1_000_000`.
+    UnitInterval,
+    /// A signed fraction in `[-1, 1]`: raw `-1_000_000..=1_000_000`.
+    SignedUnit,
+    /// An unbounded non-negative magnitude (a flow, a head-count, an intensity): raw `>= 0`.
+    NonNegative,
+}
+
+impl ScalarUnit {
+    /// Inclusive raw-sample bounds for the unit.
+    pub const fn raw_range(self) -> (i64, i64) {
+        match self {
+            Self::UnitInterval => (0, FLOAT_RASTER_FIXED_SCALE),
+            Self::SignedUnit => (-FLOAT_RASTER_FIXED_SCALE, FLOAT_RASTER_FIXED_SCALE),
+            Self::NonNegative => (0, i64::MAX),
+        }
+    }
+
+    /// Index and value of the first sample outside the unit's range, if any.
+    pub fn first_out_of_range(self, samples: &[i64]) -> Option<(usize, i64)> {
+        let (min, max) = self.raw_range();
+        samples
+            .iter()
+            .copied()
+            .enumerate()
+            .find(|&(_, raw)| raw < min || raw > max)
+    }
+}
+
+/// One scalar raster on the wire: its snapshot field name and the unit its samples are in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScalarChannel {
+    pub name: &'static str,
+    pub unit: ScalarUnit,
+}
+
+/// Summed absolute link flow per tile.
+pub const LOGISTICS_RASTER_CHANNEL: ScalarChannel = ScalarChannel {
+    name: "logistics_raster",
+    unit: ScalarUnit::NonNegative,
+};
+/// Head-count-weighted mean cohort morale. Signed, because a morale delta is.
+pub const SENTIMENT_RASTER_CHANNEL: ScalarChannel = ScalarChannel {
+    name: "sentiment_raster",
+    unit: ScalarUnit::SignedUnit,
+};
+/// Morale-deficit pressure plus the weighted logistics/trade/military/governance baseline.
+pub const CORRUPTION_RASTER_CHANNEL: ScalarChannel = ScalarChannel {
+    name: "corruption_raster",
+    unit: ScalarUnit::NonNegative,
+};
+/// `1 - discovery coverage`: `0` fully known, `1` unknown.
+pub const FOG_RASTER_CHANNEL: ScalarChannel = ScalarChannel {
+    name: "fog_raster",
+    unit: ScalarUnit::UnitInterval,
+};
+/// Divergence ratio of the strongest culture layer on the tile.
+pub const CULTURE_RASTER_CHANNEL: ScalarChannel = ScalarChannel {
+    name: "culture_raster",
+    unit: ScalarUnit::UnitInterval,
+};
+/// Presence plus support plus power margin, each clamped by its own config cap.
+pub const MILITARY_RASTER_CHANNEL: ScalarChannel = ScalarChannel {
+    name: "military_raster",
+    unit: ScalarUnit::NonNegative,
+};
+/// Unexplored `0`, discovered `0.5`, active `1`.
+pub const VISIBILITY_RASTER_CHANNEL: ScalarChannel = ScalarChannel {
+    name: "visibility_raster",
+    unit: ScalarUnit::UnitInterval,
+};
+/// Head-count homed on the tile, as a `Scalar`.
+pub const POPULATION_DENSITY_RASTER_CHANNEL: ScalarChannel = ScalarChannel {
+    name: "population_density_raster",
+    unit: ScalarUnit::NonNegative,
+};
+/// Active crisis intensity per tile.
+pub const CRISIS_HEATMAP_CHANNEL: ScalarChannel = ScalarChannel {
+    name: "crisis_heatmap",
+    unit: ScalarUnit::NonNegative,
+};
+
+/// Min-max stretches `values` onto `[0, 1]` in place, ignoring non-finite samples (which become
+/// `0`). A flat, empty, or all-non-finite raster becomes all zeros.
+///
+/// Two tight passes (range, then remap) with no early exits, so both auto-vectorize.
+pub fn normalize_overlay(values: &mut [f32]) {
+    if values.is_empty() {
+        return;
+    }
+    let (min, max) = values
+        .iter()
+        .fold((f32::INFINITY, f32::NEG_INFINITY), |(lo, hi), &v| {
+            if v.is_finite() {
+                (lo.min(v), hi.max(v))
+            } else {
+                (lo, hi)
+            }
+        });
+    if !min.is_finite() || !max.is_finite() || (max - min).abs() < f32::EPSILON {
+        values.fill(0.0);
+        return;
+    }
+    let range = max - min;
+    for v in values.iter_mut() {
+        *v = if v.is_finite() {
+            ((*v - min) / range).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scalar_units_bound_their_raw_samples() {
+        let one = FLOAT_RASTER_FIXED_SCALE;
+        assert_eq!(
+            ScalarUnit::UnitInterval.first_out_of_range(&[0, one / 2, one]),
+            None
+        );
+        assert_eq!(
+            ScalarUnit::UnitInterval.first_out_of_range(&[0, one + 1, -1]),
+            Some((1, one + 1))
+        );
+        assert_eq!(
+            ScalarUnit::SignedUnit.first_out_of_range(&[-one, one]),
+            None
+        );
+        assert_eq!(
+            ScalarUnit::SignedUnit.first_out_of_range(&[-one - 1]),
+            Some((0, -one - 1))
+        );
+        assert_eq!(
+            ScalarUnit::NonNegative.first_out_of_range(&[0, 900 * one]),
+            None
+        );
+        assert_eq!(
+            ScalarUnit::NonNegative.first_out_of_range(&[3, -2]),
+            Some((1, -2))
+        );
+    }
+
+    /// The element-wise reference the client used before the helper moved here.
+    fn naive_normalize(values: &mut [f32]) {
+        let finite = values.iter().copied().filter(|v| v.is_finite());
+        let min = finite.clone().fold(f32::INFINITY, f32::min);
+        let max = finite.fold(f32::NEG_INFINITY, f32::max);
+        if !min.is_finite() || !max.is_finite() || (max - min).abs() < f32::EPSILON {
+            values.fill(0.0);
+            return;
+        }
+        for v in values.iter_mut() {
+            *v = if v.is_finite() {
+                ((*v - min) / (max - min)).clamp(0.0, 1.0)
+            } else {
+                0.0
+            };
+        }
+    }
+
+    #[test]
+    fn normalize_overlay_matches_the_element_wise_reference() {
+        let mut values: Vec<f32> = (0..4_096)
+            .map(|i| ((i as f32) * 0.173).sin() * 40.0 - 3.0)
+            .collect();
+        values[17] = f32::NAN;
+        values[901] = f32::INFINITY;
+        let mut naive = values.clone();
+
+        normalize_overlay(&mut values);
+        naive_normalize(&mut naive);
+        assert_eq!(values, naive, "bit-identical to the element-wise stretch");
+        assert_eq!(values[17], 0.0);
+        assert_eq!(values[901], 0.0);
+    }
+
+    #[test]
+    fn normalize_overlay_zeroes_flat_rasters() {
+        let mut flat = vec![2.5f32; 8];
+        normalize_overlay(&mut flat);
+        assert!(flat.iter().all(|&v| v == 0.0));
+
+        let mut non_finite = vec![f32::NAN; 4];
+        normalize_overlay(&mut non_finite);
+        assert!(non_finite.iter().all(|&v| v == 0.0));
+    }
+}
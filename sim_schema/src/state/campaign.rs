@@ -66,8 +66,8 @@ pub struct BeatFiredState {
 pub struct BeatSignalValueState {
     #[serde(default)]
     pub signal: String,
-    /// Fixed-point raw (`Scalar::SCALE` = 1.0).
-    #[serde(default)]
+    /// Fixed-point raw (`Scalar::SCALE` = 1.0); a decimal in JSON ([`crate::fixed_point`]).
+    #[serde(default, with = "crate::fixed_point")]
     pub value: i64,
 }
 
@@ -301,6 +301,35 @@ pub struct BeatRearmState {
     pub tick: u64,
 }
 
+/// How prominently a client should surface a command-feed entry. Ordered, so a feed filter is
+/// `severity >= threshold`.
+#[derive(
+    Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash, Default,
+)]
+#[repr(u8)]
+pub enum EventSeverity {
+    /// Echo of an order or routine bookkeeping (scout, forage, expedition sent).
+    #[default]
+    Routine = 0,
+    /// Progress worth reading: milestones, discoveries, finished builds, story beats.
+    Notable = 1,
+    /// Wants the player's attention: casualties, losses, failed commands, pending decisions.
+    Alert = 2,
+    /// Campaign-defining (victory).
+    Major = 3,
+}
+
+impl EventSeverity {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            EventSeverity::Routine => "routine",
+            EventSeverity::Notable => "notable",
+            EventSeverity::Alert => "alert",
+            EventSeverity::Major => "major",
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default)]
 pub struct CommandEventState {
     pub tick: u64,
@@ -309,6 +338,8 @@ pub struct CommandEventState {
     pub label: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub detail: Option<String>,
+    #[serde(default)]
+    pub severity: EventSeverity,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default)]
@@ -326,6 +357,15 @@ pub struct VictoryModeSnapshotState {
     pub progress: f32,
     pub threshold: f32,
     pub achieved: bool,
+    /// Per-faction leaderboard, highest first; empty for global modes.
+    #[serde(default)]
+    pub per_faction_progress: Vec<VictoryFactionProgressState>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct VictoryFactionProgressState {
+    pub faction: u32,
+    pub progress: f32,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
@@ -342,3 +382,22 @@ pub struct VictorySnapshotState {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub winner: Option<VictoryResultState>,
 }
+
+/// Seasons in the in-world year; [`CalendarState::season`] counts `0..SEASONS_PER_YEAR` from the
+/// year's start.
+pub const SEASONS_PER_YEAR: u8 = 4;
+
+/// Where the sim sits in the in-world year, derived from the tick and the config's
+/// `ticks_per_year` — the same calendar the seasonal food weights and river flow run on. The
+/// calendar counts one day per tick, so `day_in_year` is the turn within the year. All zero when
+/// `ticks_per_year` is `0` (no calendar).
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Default)]
+pub struct CalendarState {
+    /// Whole years elapsed.
+    pub year: u64,
+    pub season: u8,
+    pub day_in_year: u32,
+    pub ticks_per_year: u32,
+    /// Fraction of the year elapsed, in `[0, 1)` (`core_sim::season_phase`).
+    pub phase: f32,
+}
@@ -11,6 +11,59 @@ pub enum CultureLayerScope {
     Local = 2,
 }
 
+/// Bit set on a packed culture `owner` that names a province. ECS entity bits never carry it (the
+/// top bit of an entity generation is reserved), so provinces and tiles cannot collide.
+pub const CULTURE_OWNER_PROVINCE_TAG: u64 = 1 << 63;
+
+/// Decoded form of the packed `owner` on `CultureLayerState` / `CultureTensionState`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CultureOwnerRef {
+    /// The single global layer; packed as `0`.
+    Global,
+    /// A regional layer, by province id; packed with `CULTURE_OWNER_PROVINCE_TAG`.
+    Province(u32),
+    /// A local layer, by the owning tile's entity bits; packed as-is.
+    Tile(u64),
+}
+
+impl CultureOwnerRef {
+    pub fn decode(owner: u64) -> Self {
+        if owner == 0 {
+            CultureOwnerRef::Global
+        } else if owner & CULTURE_OWNER_PROVINCE_TAG != 0 {
+            CultureOwnerRef::Province(owner as u32)
+        } else {
+            CultureOwnerRef::Tile(owner)
+        }
+    }
+
+    pub fn encode(self) -> u64 {
+        match self {
+            CultureOwnerRef::Global => 0,
+            CultureOwnerRef::Province(id) => CULTURE_OWNER_PROVINCE_TAG | u64::from(id),
+            CultureOwnerRef::Tile(bits) => bits,
+        }
+    }
+
+    /// Stable key for clients: `global`, `province` or `tile`.
+    pub fn kind_key(self) -> &'static str {
+        match self {
+            CultureOwnerRef::Global => "global",
+            CultureOwnerRef::Province(_) => "province",
+            CultureOwnerRef::Tile(_) => "tile",
+        }
+    }
+
+    /// The id within `kind_key`'s space: `0` for global, the province id, or the tile entity bits.
+    pub fn id(self) -> u64 {
+        match self {
+            CultureOwnerRef::Global => 0,
+            CultureOwnerRef::Province(id) => u64::from(id),
+            CultureOwnerRef::Tile(bits) => bits,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
 #[repr(u8)]
 pub enum CultureTraitAxis {
@@ -186,6 +239,10 @@ pub struct InfluentialIndividualState {
     pub lifecycle: InfluenceLifecycle,
     pub coherence: i64,
     pub ticks_in_status: u16,
+    /// Consecutive ticks an Active influencer has sat below the dormancy coherence threshold;
+    /// persisted so a rollback resumes the grace period where it was.
+    #[serde(default)]
+    pub ticks_below_dormancy: u16,
     pub audience_generations: Vec<u16>,
     pub support_popular: i64,
     pub support_peer: i64,
@@ -224,6 +281,8 @@ pub enum SentimentDriverCategory {
     Policy = 0,
     Incident = 1,
     Influencer = 2,
+    /// A generation's bias weighted by its population share; `weight` carries the share.
+    Generation = 3,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -251,7 +310,14 @@ pub struct SentimentAxisTelemetry {
     pub incidents: i64,
     pub influencers: i64,
     pub total: i64,
+    /// What `total` is made of: policy, incident and influencer drivers.
     pub drivers: Vec<SentimentDriverState>,
+    /// Population-weighted generation lean, reported beside the axis and not part of `total`.
+    #[serde(default)]
+    pub generations: i64,
+    /// `generations` broken down per generation, as `Generation` drivers.
+    #[serde(default)]
+    pub generation_drivers: Vec<SentimentDriverState>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
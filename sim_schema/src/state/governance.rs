@@ -51,6 +51,13 @@ pub struct CrisisGaugeState {
     pub stale_ticks: u64,
     pub band: CrisisSeverityBand,
     pub history: Vec<CrisisTrendSample>,
+    /// The raw value's band before the staleness override `band` applies. With `ema_band`, the
+    /// hysteresis state a rollback resumes the gauge from.
+    #[serde(default)]
+    pub raw_band: CrisisSeverityBand,
+    /// The band the EMA was last classified into (the one threshold events are raised on).
+    #[serde(default)]
+    pub ema_band: CrisisSeverityBand,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
@@ -68,12 +75,53 @@ pub struct CrisisOverlayAnnotationState {
     pub label: String,
     pub severity: CrisisSeverityBand,
     pub path: Vec<u32>,
+    /// Ledger id of the crisis the annotation belongs to (the `contain_crisis` target).
+    #[serde(default)]
+    pub crisis_id: u64,
+}
+
+/// One active crisis's identity and escalation progress.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
+pub struct CrisisLedgerEntryState {
+    pub crisis_id: u64,
+    pub archetype: String,
+    pub faction: u32,
+    pub chain_depth: u8,
+    /// Whether the crisis has already rolled its follow-ons.
+    pub escalated: bool,
+    /// Tiles the crisis covers (hotspot centres and contagion footprint), flattened `[x, y, ...]`
+    /// like an annotation `path`, ascending by `(x, y)`.
+    #[serde(default)]
+    pub tiles: Vec<u32>,
+}
+
+/// A follow-on crisis queued by an escalation, spawned on the next turn.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
+pub struct CrisisFollowOnState {
+    pub faction: u32,
+    pub archetype: String,
+    pub chain_depth: u8,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
 pub struct CrisisOverlayState {
     pub heatmap: ScalarRasterState,
     pub annotations: Vec<CrisisOverlayAnnotationState>,
+    /// Active crises in ledger order.
+    #[serde(default)]
+    pub crises: Vec<CrisisLedgerEntryState>,
+    #[serde(default)]
+    pub pending_follow_ons: Vec<CrisisFollowOnState>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, Default)]
+#[repr(u8)]
+pub enum PowerNodeKind {
+    Generator = 0,
+    Storage = 1,
+    Consumer = 2,
+    #[default]
+    Hybrid = 3,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -89,6 +137,14 @@ pub struct PowerNodeState {
     pub surplus: i64,
     pub deficit: i64,
     pub incident_count: u32,
+    #[serde(default)]
+    pub kind: PowerNodeKind,
+    /// Load-shedding priority; higher keeps power longer.
+    #[serde(default)]
+    pub priority: u8,
+    /// Load cut from this node in the last power phase (fixed-point raw).
+    #[serde(default)]
+    pub shed: i64,
 }
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
@@ -138,6 +194,16 @@ pub struct CorruptionEntry {
     pub last_update_tick: u64,
 }
 
+/// One cell of the pairwise diplomacy leverage matrix: the net leverage `faction_a` holds over
+/// `faction_b` (negative when `faction_b` has the upper hand). Only non-neutral pairs are sent,
+/// each once with `faction_a < faction_b`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub struct DiplomacyLeverageState {
+    pub faction_a: u32,
+    pub faction_b: u32,
+    pub leverage: i64,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
 pub struct CorruptionLedger {
     pub entries: Vec<CorruptionEntry>,
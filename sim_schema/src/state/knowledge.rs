@@ -1,4 +1,5 @@
-//! Knowledge-section state: the leak ledger, great discoveries, and discovered sites.
+//! Knowledge-section state: the leak ledger, great discoveries, discovered sites, and the
+//! espionage agent rosters.
 
 use serde::{Deserialize, Serialize};
 use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign};
@@ -28,6 +29,20 @@ pub struct DiscoveredSitesState {
     pub sites: Vec<DiscoveredSiteState>,
 }
 
+/// One espionage agent on a faction's roster. `experience` is what the agent has earned on fully
+/// successful missions; rollback restores it from here.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub struct EspionageAgentState {
+    pub faction: u32,
+    pub handle: u32,
+    #[serde(default)]
+    pub template_id: String,
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub experience: u32,
+}
+
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, Default)]
 #[repr(u8)]
 pub enum KnowledgeField {
@@ -212,6 +227,23 @@ impl KnowledgeLedgerEntryState {
     }
 }
 
+/// One of a faction's own secrets inside the early-warning horizon.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub struct KnowledgeEarlyWarningEntryState {
+    pub discovery_id: u32,
+    pub time_to_cascade: u16,
+    pub progress_percent: u16,
+}
+
+/// A faction's secrets whose `time_to_cascade` is within the ledger's early-warning horizon, most
+/// urgent first (fewest ticks, then highest progress). Factions with nothing at risk are omitted;
+/// secrets that already cascaded to common knowledge never appear.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub struct KnowledgeEarlyWarningState {
+    pub faction: u32,
+    pub entries: Vec<KnowledgeEarlyWarningEntryState>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default)]
 pub struct KnowledgeMetricsState {
     pub leak_warnings: u32,
@@ -247,6 +279,17 @@ pub struct GreatDiscoveryProgressState {
     pub observation_deficit: u32,
     pub eta_ticks: u32,
     pub covert: bool,
+    /// Change since the previous turn's export; `None` the first turn an entry is tracked.
+    #[serde(default)]
+    pub progress_delta: Option<GreatDiscoveryProgressDeltaState>,
+}
+
+/// Per-turn change of a [`GreatDiscoveryProgressState`]: `progress` in raw fixed-point units and
+/// `observation_deficit` signed (negative as observations come in).
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq, Eq)]
+pub struct GreatDiscoveryProgressDeltaState {
+    pub progress: i64,
+    pub observation_deficit: i32,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
@@ -254,6 +297,24 @@ pub struct GreatDiscoveryTelemetryState {
     pub total_resolved: u32,
     pub pending_candidates: u32,
     pub active_constellations: u32,
+    /// Most recent applied effects, oldest first.
+    #[serde(default)]
+    pub effect_audit: Vec<GreatDiscoveryEffectAuditState>,
+}
+
+/// One effect a resolved Great Discovery applied: `effect` is a single effect-flag bit,
+/// `target` names the ledger it changed, `target_id` the entry within it, and `magnitude` is the
+/// fixed-point size of the change.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
+pub struct GreatDiscoveryEffectAuditState {
+    pub tick: u64,
+    pub discovery: u16,
+    pub faction: u32,
+    pub effect: u32,
+    pub target: String,
+    pub magnitude: i64,
+    #[serde(default)]
+    pub target_id: u64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
@@ -265,6 +326,16 @@ pub struct GreatDiscoveryRequirementState {
     pub summary: Option<String>,
 }
 
+/// One edge of a constellation graph: requirement `from` is a prerequisite of requirement `to`
+/// (both are requirement `discovery` ids of the same definition). `weight` is `from`'s share of
+/// the constellation's total requirement weight, so clients can size edges by importance.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+pub struct GreatDiscoveryRequirementEdgeState {
+    pub from: u32,
+    pub to: u32,
+    pub weight: f32,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
 pub struct GreatDiscoveryDefinitionState {
     pub id: u16,
@@ -282,6 +353,12 @@ pub struct GreatDiscoveryDefinitionState {
     pub observation_notes: Option<String>,
     pub leak_profile: Option<String>,
     pub requirements: Vec<GreatDiscoveryRequirementState>,
+    #[serde(default)]
+    pub prerequisites: Vec<u16>,
+    /// Prerequisite edges between `requirements`, sorted by `(to, from)`. Requirements with no
+    /// outgoing edge feed the discovery directly.
+    #[serde(default)]
+    pub edges: Vec<GreatDiscoveryRequirementEdgeState>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
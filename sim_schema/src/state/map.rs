@@ -283,6 +283,57 @@ impl From<u16> for TerrainTags {
     }
 }
 
+/// Which overlay rasters the producing server actually computed for a snapshot (see
+/// `SnapshotHeader::computed_channels`). A channel whose bit is clear shipped the empty default
+/// raster because it was disabled or its source was absent — "never computed", as opposed to a
+/// computed raster that happens to be all zero.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default, Hash)]
+#[serde(transparent)]
+pub struct OverlayChannelFlags(pub u32);
+
+impl OverlayChannelFlags {
+    pub const fn empty() -> Self {
+        Self(0)
+    }
+
+    pub const fn bits(self) -> u32 {
+        self.0
+    }
+
+    pub const LOGISTICS: Self = Self(1 << 0);
+    pub const SENTIMENT: Self = Self(1 << 1);
+    pub const POPULATION_DENSITY: Self = Self(1 << 2);
+    pub const CORRUPTION: Self = Self(1 << 3);
+    pub const FOG: Self = Self(1 << 4);
+    pub const CULTURE: Self = Self(1 << 5);
+    pub const MILITARY: Self = Self(1 << 6);
+    pub const VISIBILITY: Self = Self(1 << 7);
+    pub const CRISIS: Self = Self(1 << 8);
+    pub const ELEVATION: Self = Self(1 << 9);
+    pub const MOISTURE: Self = Self(1 << 10);
+
+    pub fn contains(self, other: Self) -> bool {
+        (self.0 & other.0) == other.0
+    }
+
+    /// Sets `other`'s bits when `computed` holds.
+    pub fn set(&mut self, other: Self, computed: bool) {
+        if computed {
+            self.0 |= other.0;
+        } else {
+            self.0 &= !other.0;
+        }
+    }
+}
+
+impl BitOr for OverlayChannelFlags {
+    type Output = OverlayChannelFlags;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        OverlayChannelFlags(self.bits() | rhs.bits())
+    }
+}
+
 impl From<TerrainTags> for u16 {
     fn from(value: TerrainTags) -> Self {
         value.bits()
@@ -350,6 +401,11 @@ pub struct TerrainOverlayState {
     pub width: u32,
     pub height: u32,
     pub samples: Vec<TerrainSample>,
+    /// Fingerprint of the distinct terrain types present in `samples` (see snapshot.fbs
+    /// `paletteHash`). Unchanged while the legend is unchanged; a client may use it to skip
+    /// rebuilding its terrain palette until a reclassification introduces or retires a type.
+    #[serde(default)]
+    pub palette_hash: u64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
@@ -393,12 +449,47 @@ pub struct ScalarRasterState {
     pub samples: Vec<i64>,
 }
 
+/// Scale of [`RasterPrecision::Fixed64`] samples: the sim's fixed-point `Scalar::SCALE`.
+pub const FLOAT_RASTER_FIXED_SCALE: i64 = 1_000_000;
+
+/// How a [`FloatRasterState`] is packed onto the wire (see snapshot.fbs `RasterPrecision`). The
+/// state itself always carries full `f32` samples; only the FlatBuffers encoding changes.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, Default)]
+#[serde(rename_all = "snake_case")]
+#[repr(u8)]
+pub enum RasterPrecision {
+    /// Raw `f32` samples.
+    #[default]
+    Float32 = 0,
+    /// 8-bit unsigned normalized over the raster's own `[min, max]`.
+    Unorm8 = 1,
+    /// 16-bit unsigned normalized over the raster's own `[min, max]`, like the elevation overlay.
+    Unorm16 = 2,
+    /// Fixed-point `i64` at [`FLOAT_RASTER_FIXED_SCALE`]: bit-identical on every platform.
+    Fixed64 = 3,
+}
+
+impl RasterPrecision {
+    /// Worst-case absolute decode error for a raster whose samples span `range` (`max - min`).
+    pub fn max_error(self, range: f32) -> f32 {
+        match self {
+            RasterPrecision::Float32 => 0.0,
+            RasterPrecision::Unorm8 => range.max(0.0) / (2.0 * u8::MAX as f32),
+            RasterPrecision::Unorm16 => range.max(0.0) / (2.0 * u16::MAX as f32),
+            RasterPrecision::Fixed64 => 0.5 / FLOAT_RASTER_FIXED_SCALE as f32,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
 pub struct FloatRasterState {
     pub width: u32,
     pub height: u32,
     #[serde(default)]
     pub samples: Vec<f32>,
+    /// Wire encoding for `samples`; `Float32` (the default) ships them unquantized.
+    #[serde(default)]
+    pub precision: RasterPrecision,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -485,4 +576,14 @@ pub struct TileState {
     /// `Tile::resource_terrain()`.
     #[serde(default)]
     pub underlying_terrain: TerrainType,
+    /// This turn's **river flow** on the hex — the largest `RiverSegment::flow_volume` of any river
+    /// touching it, in precipitation-weighted drainage-area units. Swings with basin moisture and
+    /// season around the river's generated discharge; `0` on every hex with no river.
+    #[serde(default)]
+    pub river_flow: f32,
+    /// This turn's **lake depth** on the hex — water standing over it at its lake's current level,
+    /// in elevation units. Rises and falls with basin moisture and season, never above the lake's
+    /// spill point; `0` off-lake and on a lake hex that has dried out.
+    #[serde(default)]
+    pub lake_depth: f32,
 }
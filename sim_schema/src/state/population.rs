@@ -1,4 +1,4 @@
-//! Population-section state: cohorts, demographics, labor assignments, and tasks.
+//! Population-section state: cohorts, demographics, morale summaries, labor assignments, and tasks.
 
 use crate::state::economy::KnownTechFragment;
 use serde::{Deserialize, Serialize};
@@ -16,6 +16,29 @@ pub struct PopulationDemographicsState {
     pub elders: u32,
 }
 
+/// Per-faction spread of cohort morale (fixed-point raw, `Scalar::SCALE` = 1.0). `mean` and
+/// `below_threshold` are weighted by head-count, falling back to one vote per cohort when every
+/// cohort is empty. `threshold` is the migration morale threshold the share was measured against,
+/// so the client needs no copy of the wellbeing config. Factions with no cohorts are omitted. JSON
+/// carries the four morale values as decimals ([`crate::fixed_point`]).
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct MoraleSummaryState {
+    pub faction: u32,
+    #[serde(default)]
+    pub cohorts: u32,
+    #[serde(default, with = "crate::fixed_point")]
+    pub min: i64,
+    #[serde(default, with = "crate::fixed_point")]
+    pub mean: i64,
+    #[serde(default, with = "crate::fixed_point")]
+    pub max: i64,
+    #[serde(default, with = "crate::fixed_point")]
+    pub threshold: i64,
+    /// Share of the faction's head-count (0..=1) whose band morale sits below `threshold`.
+    #[serde(default)]
+    pub below_threshold: f32,
+}
+
 /// One commodity entry in a band's local goods store (fixed-point raw quantity).
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
 pub struct CohortStoreState {
@@ -371,6 +394,23 @@ pub struct PopulationCohortState {
     /// the Rust side.)
     #[serde(default)]
     pub fodder_store: f32,
+    /// Scout expedition only: the sight radius it observes with
+    /// (`expedition_config.observe_sight_range`). `0` for hunt parties and normal bands. Appended.
+    #[serde(default)]
+    pub expedition_reveal_radius: u32,
+    /// Scout expedition only: the projected reveal footprint (zipped `x`/`y`) — the tiles it will
+    /// observe on reaching its travel target (its current tile once arrived), LOS applied when
+    /// enabled. Exactly what `advance_expeditions` buffers into `pending_reveal` on that turn.
+    /// Derived at capture (not persisted). Appended.
+    #[serde(default)]
+    pub expedition_reveal_footprint_x: Vec<u32>,
+    #[serde(default)]
+    pub expedition_reveal_footprint_y: Vec<u32>,
+    /// Layer-1 `diet` morale contribution (culture × home-tile food module), fixed-point raw; part
+    /// of the itemized breakdown beside `morale_settling`..`morale_unrest`. Derived at capture.
+    /// Appended.
+    #[serde(default)]
+    pub morale_diet: i64,
 }
 
 /// Presentation view of a band's resolved settlement stage (mirror of the `SettlementStageView`
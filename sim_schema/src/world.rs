@@ -2,8 +2,8 @@
 //! bincode / JSON codecs plus the on-disk [`MapExport`].
 
 use crate::state::campaign::{
-    BeatLedgerState, CampaignLabel, CampaignProfileState, CommandEventState, PendingForksState,
-    StanceState, VictorySnapshotState, VoiceMediumState,
+    BeatLedgerState, CalendarState, CampaignLabel, CampaignProfileState, CommandEventState,
+    PendingForksState, StanceState, VictorySnapshotState, VoiceMediumState,
 };
 use crate::state::culture::{
     AxisBiasState, CultureLayerState, CultureTensionState, InfluentialIndividualState,
@@ -11,19 +11,21 @@ use crate::state::culture::{
 };
 use crate::state::economy::{FactionInventoryState, LogisticsLinkState, TradeLinkState};
 use crate::state::governance::{
-    CorruptionLedger, CrisisOverlayState, CrisisTelemetryState, PowerNodeState, PowerTelemetryState,
+    CorruptionLedger, CrisisOverlayState, CrisisTelemetryState, DiplomacyLeverageState,
+    PowerNodeState, PowerTelemetryState,
 };
 use crate::state::knowledge::{
-    DiscoveredSitesState, DiscoveryProgressEntry, GreatDiscoveryDefinitionState,
-    GreatDiscoveryProgressState, GreatDiscoveryState, GreatDiscoveryTelemetryState,
-    KnowledgeLedgerEntryState, KnowledgeMetricsState, KnowledgeTimelineEventState,
+    DiscoveredSitesState, DiscoveryProgressEntry, EspionageAgentState,
+    GreatDiscoveryDefinitionState, GreatDiscoveryProgressState, GreatDiscoveryState,
+    GreatDiscoveryTelemetryState, KnowledgeEarlyWarningState, KnowledgeLedgerEntryState,
+    KnowledgeMetricsState, KnowledgeTimelineEventState,
 };
 use crate::state::map::{
-    ClimateBandsState, ElevationOverlayState, FloatRasterState, ScalarRasterState,
-    StartMarkerState, TerrainOverlayState, TerrainSample, TileState,
+    ClimateBandsState, ElevationOverlayState, FloatRasterState, OverlayChannelFlags,
+    ScalarRasterState, StartMarkerState, TerrainOverlayState, TerrainSample, TileState,
 };
 use crate::state::population::{
-    GenerationState, PopulationCohortState, PopulationDemographicsState,
+    GenerationState, MoraleSummaryState, PopulationCohortState, PopulationDemographicsState,
 };
 use crate::state::subsistence::{
     FoodModuleState, ForagePatchState, ForageState, GrazeState, HerdState, HerdTelemetryState,
@@ -31,7 +33,9 @@ use crate::state::subsistence::{
 };
 use ahash::RandomState;
 use serde::{Deserialize, Serialize};
-use std::hash::{BuildHasher, Hasher};
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::{BuildHasher, Hash, Hasher};
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct SnapshotHeader {
@@ -55,6 +59,36 @@ pub struct SnapshotHeader {
     /// snapshot server replays to reconnecting subscribers. Set by core_sim.
     #[serde(default)]
     pub world_epoch: u32,
+    /// Fingerprint of the `SimulationConfig` the producing app ran with (see `snapshot.fbs`). Two
+    /// snapshots with different values came from different configs. Set by core_sim.
+    #[serde(default)]
+    pub config_hash: u64,
+    /// Position of this frame in the broadcast stream (see `snapshot.fbs`). Each delta advances it
+    /// by its `sequence_span` (one unless coalesced); a full snapshot carries the sequence of the
+    /// latest delta folded into it. Excluded from `hash`. Set by core_sim's `SnapshotHistory`.
+    #[serde(default)]
+    pub sequence: u64,
+    /// Influencers left off `influencers` because their effective influence fell below the
+    /// configured snapshot minimum (see `snapshot.fbs`). They are still simulated. Set by core_sim.
+    #[serde(default)]
+    pub influencers_omitted: u32,
+    /// Index of this frame within a delta split by [`WorldDelta::split`] (see `snapshot.fbs`). Every
+    /// part shares the header's tick and `sequence`; part `0` advances the stream.
+    #[serde(default)]
+    pub delta_part: u32,
+    /// How many parts the delta was split into; `0` (or `1`) for an unsplit frame.
+    #[serde(default)]
+    pub delta_part_count: u32,
+    /// Overlay rasters the server computed for this frame (see `snapshot.fbs`). An empty raster
+    /// whose bit is clear was never computed; one whose bit is set is a real (possibly all-zero)
+    /// result. Set by core_sim.
+    #[serde(default)]
+    pub computed_channels: OverlayChannelFlags,
+    /// How many stream positions this delta covers (see `snapshot.fbs`): a subscriber's backlog
+    /// coalesced into one frame keeps the last delta's `sequence` and spans every delta folded in,
+    /// so the stream stays gap-free at `previous + sequence_span`. `0` (or `1`) is a single delta.
+    #[serde(default)]
+    pub sequence_span: u32,
 }
 
 impl SnapshotHeader {
@@ -80,6 +114,13 @@ impl SnapshotHeader {
             wrap_horizontal: false,
             server_build: String::new(),
             world_epoch: 0,
+            config_hash: 0,
+            sequence: 0,
+            influencers_omitted: 0,
+            delta_part: 0,
+            delta_part_count: 0,
+            computed_channels: OverlayChannelFlags::empty(),
+            sequence_span: 0,
         }
     }
 
@@ -112,6 +153,9 @@ pub struct WorldSnapshot {
     pub knowledge_ledger: Vec<KnowledgeLedgerEntryState>,
     pub knowledge_timeline: Vec<KnowledgeTimelineEventState>,
     pub knowledge_metrics: KnowledgeMetricsState,
+    /// Per-faction secrets close to cascading, most urgent first.
+    #[serde(default)]
+    pub knowledge_early_warnings: Vec<KnowledgeEarlyWarningState>,
     pub crisis_telemetry: CrisisTelemetryState,
     pub crisis_overlay: CrisisOverlayState,
     pub victory: VictorySnapshotState,
@@ -130,6 +174,9 @@ pub struct WorldSnapshot {
     /// The Telling's narrator medium per faction (presentational — see `VoiceMediumState`).
     #[serde(default)]
     pub voice_medium: Vec<VoiceMediumState>,
+    /// The in-world calendar at `header.tick`, for the client's calendar UI.
+    #[serde(default)]
+    pub calendar: CalendarState,
     #[serde(default)]
     pub herds: Vec<HerdTelemetryState>,
     /// Authoritative herd sim state (`HerdRegistry`), round-tripped for rollback correctness —
@@ -161,8 +208,14 @@ pub struct WorldSnapshot {
     pub sedentarization: Vec<SedentarizationState>,
     #[serde(default)]
     pub discovered_sites: Vec<DiscoveredSitesState>,
+    /// Every faction's espionage agents, ordered by `(faction, handle)`.
+    #[serde(default)]
+    pub espionage_agents: Vec<EspionageAgentState>,
     #[serde(default)]
     pub demographics: Vec<PopulationDemographicsState>,
+    /// Per-faction morale spread (min/mean/max + share below the migration threshold).
+    #[serde(default)]
+    pub morale_summary: Vec<MoraleSummaryState>,
     /// Per-tile depletable-forage cultivation/ecology display state (Intensification Phase 1a).
     #[serde(default)]
     pub forage_patches: Vec<ForagePatchState>,
@@ -184,11 +237,22 @@ pub struct WorldSnapshot {
     pub military_raster: ScalarRasterState,
     #[serde(default)]
     pub visibility_raster: ScalarRasterState,
+    /// Head-count per tile, summed over the cohorts homed there.
+    #[serde(default)]
+    pub population_density_raster: ScalarRasterState,
     pub axis_bias: AxisBiasState,
     pub sentiment: SentimentTelemetryState,
     pub generations: Vec<GenerationState>,
     pub corruption: CorruptionLedger,
+    /// Pairwise diplomacy leverage (`DiplomacyLeverage`), for the diplomacy UI and rollback.
+    #[serde(default)]
+    pub diplomacy_leverage: Vec<DiplomacyLeverageState>,
     pub influencers: Vec<InfluentialIndividualState>,
+    /// Influencers below the snapshot minimum influence, omitted from `influencers` (counted in
+    /// `header.influencers_omitted`). Like `herd_registry` this is not on the FlatBuffers client
+    /// stream; rollback restore merges it back into the roster alongside `influencers`.
+    #[serde(default)]
+    pub latent_influencers: Vec<InfluentialIndividualState>,
     pub culture_layers: Vec<CultureLayerState>,
     pub culture_tensions: Vec<CultureTensionState>,
     pub discovery_progress: Vec<DiscoveryProgressEntry>,
@@ -215,12 +279,16 @@ pub struct WorldDelta {
     pub knowledge_ledger: Vec<KnowledgeLedgerEntryState>,
     pub removed_knowledge_ledger: Vec<u64>,
     pub knowledge_metrics: Option<KnowledgeMetricsState>,
+    pub knowledge_early_warnings: Option<Vec<KnowledgeEarlyWarningState>>,
     pub victory: Option<VictorySnapshotState>,
     pub capability_flags: Option<u32>,
     pub command_events: Option<Vec<CommandEventState>>,
     pub pending_forks: Option<Vec<PendingForksState>>,
     pub stance_axes: Option<Vec<StanceState>>,
     pub voice_medium: Option<Vec<VoiceMediumState>>,
+    /// The calendar whenever it moved; `None` means unchanged.
+    #[serde(default)]
+    pub calendar: Option<CalendarState>,
     pub knowledge_timeline: Vec<KnowledgeTimelineEventState>,
     pub crisis_telemetry: Option<CrisisTelemetryState>,
     pub crisis_overlay: Option<CrisisOverlayState>,
@@ -229,7 +297,11 @@ pub struct WorldDelta {
     pub faction_inventory: Option<Vec<FactionInventoryState>>,
     pub sedentarization: Option<Vec<SedentarizationState>>,
     pub discovered_sites: Option<Vec<DiscoveredSitesState>>,
+    /// The whole agent list when any agent changed; `None` means unchanged.
+    #[serde(default)]
+    pub espionage_agents: Option<Vec<EspionageAgentState>>,
     pub demographics: Option<Vec<PopulationDemographicsState>>,
+    pub morale_summary: Option<Vec<MoraleSummaryState>>,
     pub forage_patches: Option<Vec<ForagePatchState>>,
     pub intensification_knowledge: Option<Vec<IntensificationKnowledgeState>>,
     pub moisture_raster: Option<FloatRasterState>,
@@ -248,9 +320,14 @@ pub struct WorldDelta {
     pub culture_raster: Option<ScalarRasterState>,
     pub military_raster: Option<ScalarRasterState>,
     pub visibility_raster: Option<ScalarRasterState>,
+    #[serde(default)]
+    pub population_density_raster: Option<ScalarRasterState>,
     pub generations: Vec<GenerationState>,
     pub removed_generations: Vec<u16>,
     pub corruption: Option<CorruptionLedger>,
+    /// The whole leverage matrix when any pair moved; `None` means unchanged.
+    #[serde(default)]
+    pub diplomacy_leverage: Option<Vec<DiplomacyLeverageState>>,
     pub influencers: Vec<InfluentialIndividualState>,
     pub removed_influencers: Vec<u32>,
     pub terrain: Option<TerrainOverlayState>,
@@ -268,11 +345,739 @@ impl WorldSnapshot {
         self.header = header;
         self
     }
+
+    /// Structural consistency check: every header count matches its section's length, and every
+    /// raster/overlay carries exactly `width * height` samples (an empty `0 x 0` raster is the
+    /// "no data" placeholder and passes). Content is not inspected.
+    pub fn validate(&self) -> Result<(), SnapshotValidationError> {
+        let header = &self.header;
+        let counts = [
+            ("tiles", header.tile_count, self.tiles.len()),
+            ("logistics", header.logistics_count, self.logistics.len()),
+            (
+                "trade_links",
+                header.trade_link_count,
+                self.trade_links.len(),
+            ),
+            (
+                "populations",
+                header.population_count,
+                self.populations.len(),
+            ),
+            ("power", header.power_count, self.power.len()),
+            (
+                "influencers",
+                header.influencer_count,
+                self.influencers.len(),
+            ),
+        ];
+        for (section, header, actual) in counts {
+            if header as usize != actual {
+                return Err(SnapshotValidationError::HeaderCountMismatch {
+                    section,
+                    header,
+                    actual,
+                });
+            }
+        }
+
+        let rasters = [
+            (
+                "terrain",
+                self.terrain.width,
+                self.terrain.height,
+                self.terrain.samples.len(),
+            ),
+            (
+                "elevation_overlay",
+                self.elevation_overlay.width,
+                self.elevation_overlay.height,
+                self.elevation_overlay.samples.len(),
+            ),
+            (
+                "moisture_raster",
+                self.moisture_raster.width,
+                self.moisture_raster.height,
+                self.moisture_raster.samples.len(),
+            ),
+        ]
+        .into_iter()
+        .chain(
+            [
+                ("logistics_raster", &self.logistics_raster),
+                ("sentiment_raster", &self.sentiment_raster),
+                ("corruption_raster", &self.corruption_raster),
+                ("fog_raster", &self.fog_raster),
+                ("culture_raster", &self.culture_raster),
+                ("military_raster", &self.military_raster),
+                ("visibility_raster", &self.visibility_raster),
+                ("population_density_raster", &self.population_density_raster),
+            ]
+            .map(|(name, raster)| (name, raster.width, raster.height, raster.samples.len())),
+        );
+        for (raster, width, height, samples) in rasters {
+            if (width as usize) * (height as usize) != samples {
+                return Err(SnapshotValidationError::RasterSizeMismatch {
+                    raster,
+                    width,
+                    height,
+                    samples,
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+impl WorldDelta {
+    /// Folds a later delta into this one, so applying the result equals applying both in order.
+    ///
+    /// Keyed sections are last-write-wins per id and their removed ids union; an id the later
+    /// delta re-adds drops out of the removed list, and an id it removes drops out of the
+    /// updates. Optional sections keep the latest `Some`, the replace-whole lists
+    /// (`knowledge_timeline`, `culture_tensions`) keep the latest non-empty list, and the header
+    /// is the later one.
+    pub fn merge(&mut self, later: WorldDelta) {
+        let WorldDelta {
+            header,
+            tiles,
+            removed_tiles,
+            logistics,
+            removed_logistics,
+            trade_links,
+            removed_trade_links,
+            populations,
+            removed_populations,
+            power,
+            removed_power,
+            power_metrics,
+            great_discovery_definitions,
+            great_discoveries,
+            great_discovery_progress,
+            great_discovery_telemetry,
+            knowledge_ledger,
+            removed_knowledge_ledger,
+            knowledge_metrics,
+            knowledge_early_warnings,
+            victory,
+            capability_flags,
+            command_events,
+            pending_forks,
+            stance_axes,
+            voice_medium,
+            calendar,
+            knowledge_timeline,
+            crisis_telemetry,
+            crisis_overlay,
+            herds,
+            food_modules,
+            faction_inventory,
+            sedentarization,
+            discovered_sites,
+            espionage_agents,
+            demographics,
+            morale_summary,
+            forage_patches,
+            intensification_knowledge,
+            moisture_raster,
+            elevation_overlay,
+            climate_bands,
+            start_marker,
+            axis_bias,
+            sentiment,
+            logistics_raster,
+            sentiment_raster,
+            corruption_raster,
+            fog_raster,
+            culture_raster,
+            military_raster,
+            visibility_raster,
+            population_density_raster,
+            generations,
+            removed_generations,
+            corruption,
+            diplomacy_leverage,
+            influencers,
+            removed_influencers,
+            terrain,
+            culture_layers,
+            removed_culture_layers,
+            culture_tensions,
+            discovery_progress,
+        } = later;
+
+        self.header = header;
+
+        merge_keyed(
+            (&mut self.tiles, &mut self.removed_tiles),
+            (tiles, removed_tiles),
+            |state| state.entity,
+        );
+        merge_keyed(
+            (&mut self.logistics, &mut self.removed_logistics),
+            (logistics, removed_logistics),
+            |state| state.entity,
+        );
+        merge_keyed(
+            (&mut self.trade_links, &mut self.removed_trade_links),
+            (trade_links, removed_trade_links),
+            |state| state.entity,
+        );
+        merge_keyed(
+            (&mut self.populations, &mut self.removed_populations),
+            (populations, removed_populations),
+            |state| state.entity,
+        );
+        merge_keyed(
+            (&mut self.power, &mut self.removed_power),
+            (power, removed_power),
+            |state| state.entity,
+        );
+        // Same packing as `sim_runtime::encode_knowledge_ledger_key`, which the removed ids use.
+        merge_keyed(
+            (
+                &mut self.knowledge_ledger,
+                &mut self.removed_knowledge_ledger,
+            ),
+            (knowledge_ledger, removed_knowledge_ledger),
+            |entry| ((entry.discovery_id as u64) << 32) | entry.owner_faction as u64,
+        );
+        merge_keyed(
+            (&mut self.generations, &mut self.removed_generations),
+            (generations, removed_generations),
+            |state| state.id,
+        );
+        merge_keyed(
+            (&mut self.influencers, &mut self.removed_influencers),
+            (influencers, removed_influencers),
+            |state| state.id,
+        );
+        merge_keyed(
+            (&mut self.culture_layers, &mut self.removed_culture_layers),
+            (culture_layers, removed_culture_layers),
+            |state| state.id,
+        );
+
+        upsert_keyed(&mut self.great_discoveries, great_discoveries, |state| {
+            (state.faction, state.id)
+        });
+        upsert_keyed(
+            &mut self.great_discovery_progress,
+            great_discovery_progress,
+            |state| (state.faction, state.discovery),
+        );
+        upsert_keyed(&mut self.discovery_progress, discovery_progress, |entry| {
+            (entry.faction, entry.discovery)
+        });
+
+        replace_if_sent(&mut self.knowledge_timeline, knowledge_timeline);
+        replace_if_sent(&mut self.culture_tensions, culture_tensions);
+
+        keep_latest(&mut self.power_metrics, power_metrics);
+        keep_latest(
+            &mut self.great_discovery_definitions,
+            great_discovery_definitions,
+        );
+        keep_latest(
+            &mut self.great_discovery_telemetry,
+            great_discovery_telemetry,
+        );
+        keep_latest(&mut self.knowledge_metrics, knowledge_metrics);
+        keep_latest(&mut self.knowledge_early_warnings, knowledge_early_warnings);
+        keep_latest(&mut self.victory, victory);
+        keep_latest(&mut self.capability_flags, capability_flags);
+        keep_latest(&mut self.command_events, command_events);
+        keep_latest(&mut self.pending_forks, pending_forks);
+        keep_latest(&mut self.stance_axes, stance_axes);
+        keep_latest(&mut self.voice_medium, voice_medium);
+        keep_latest(&mut self.calendar, calendar);
+        keep_latest(&mut self.crisis_telemetry, crisis_telemetry);
+        keep_latest(&mut self.crisis_overlay, crisis_overlay);
+        keep_latest(&mut self.herds, herds);
+        keep_latest(&mut self.food_modules, food_modules);
+        keep_latest(&mut self.faction_inventory, faction_inventory);
+        keep_latest(&mut self.sedentarization, sedentarization);
+        keep_latest(&mut self.discovered_sites, discovered_sites);
+        keep_latest(&mut self.espionage_agents, espionage_agents);
+        keep_latest(&mut self.demographics, demographics);
+        keep_latest(&mut self.morale_summary, morale_summary);
+        keep_latest(&mut self.forage_patches, forage_patches);
+        keep_latest(
+            &mut self.intensification_knowledge,
+            intensification_knowledge,
+        );
+        keep_latest(&mut self.moisture_raster, moisture_raster);
+        keep_latest(&mut self.elevation_overlay, elevation_overlay);
+        keep_latest(&mut self.climate_bands, climate_bands);
+        keep_latest(&mut self.start_marker, start_marker);
+        keep_latest(&mut self.axis_bias, axis_bias);
+        keep_latest(&mut self.sentiment, sentiment);
+        keep_latest(&mut self.logistics_raster, logistics_raster);
+        keep_latest(&mut self.sentiment_raster, sentiment_raster);
+        keep_latest(&mut self.corruption_raster, corruption_raster);
+        keep_latest(&mut self.fog_raster, fog_raster);
+        keep_latest(&mut self.culture_raster, culture_raster);
+        keep_latest(&mut self.military_raster, military_raster);
+        keep_latest(&mut self.visibility_raster, visibility_raster);
+        keep_latest(
+            &mut self.population_density_raster,
+            population_density_raster,
+        );
+        keep_latest(&mut self.corruption, corruption);
+        keep_latest(&mut self.diplomacy_leverage, diplomacy_leverage);
+        keep_latest(&mut self.terrain, terrain);
+    }
+}
+
+impl WorldDelta {
+    /// Encoded size in bytes: the exact bincode length, which tracks the FlatBuffers frame closely
+    /// enough to budget against (leave headroom for its vtables when capping a FlatBuffers frame).
+    pub fn estimate_size(&self) -> usize {
+        encoded_size(self)
+    }
+
+    /// Splits the delta into parts whose [`Self::estimate_size`] stays within `max_bytes`, for
+    /// transports with a frame cap. Keyed and removed-id lists are partitioned element by element
+    /// in order; every other section (the optional sections and the replace-whole
+    /// `knowledge_timeline` / `culture_tensions`) travels whole in one part. Each part carries
+    /// the original header with `delta_part` / `delta_part_count` set, and applying the parts in
+    /// order (or [`Self::merge`]-ing them) equals applying the original.
+    ///
+    /// A delta that already fits comes back as a single, unmarked part. A single element or
+    /// whole section larger than the cap cannot be divided and gets an oversized part of its own.
+    pub fn split(self, max_bytes: usize) -> Vec<WorldDelta> {
+        if self.estimate_size() <= max_bytes {
+            return vec![self];
+        }
+        let WorldDelta {
+            header,
+            tiles,
+            removed_tiles,
+            logistics,
+            removed_logistics,
+            trade_links,
+            removed_trade_links,
+            populations,
+            removed_populations,
+            power,
+            removed_power,
+            power_metrics,
+            great_discovery_definitions,
+            great_discoveries,
+            great_discovery_progress,
+            great_discovery_telemetry,
+            knowledge_ledger,
+            removed_knowledge_ledger,
+            knowledge_metrics,
+            knowledge_early_warnings,
+            victory,
+            capability_flags,
+            command_events,
+            pending_forks,
+            stance_axes,
+            voice_medium,
+            calendar,
+            knowledge_timeline,
+            crisis_telemetry,
+            crisis_overlay,
+            herds,
+            food_modules,
+            faction_inventory,
+            sedentarization,
+            discovered_sites,
+            espionage_agents,
+            demographics,
+            morale_summary,
+            forage_patches,
+            intensification_knowledge,
+            moisture_raster,
+            elevation_overlay,
+            climate_bands,
+            start_marker,
+            axis_bias,
+            sentiment,
+            logistics_raster,
+            sentiment_raster,
+            corruption_raster,
+            fog_raster,
+            culture_raster,
+            military_raster,
+            visibility_raster,
+            population_density_raster,
+            generations,
+            removed_generations,
+            corruption,
+            diplomacy_leverage,
+            influencers,
+            removed_influencers,
+            terrain,
+            culture_layers,
+            removed_culture_layers,
+            culture_tensions,
+            discovery_progress,
+        } = self;
+
+        let mut parts = DeltaParts::new(header, max_bytes);
+        // Bincode is additive: a part's size is its empty shell plus each element it carries (a
+        // `Some` adds its payload to the shell's `None` tag byte).
+        macro_rules! spread {
+            ($($field:ident),* $(,)?) => {$(
+                for item in $field {
+                    parts.fit(encoded_size(&item)).$field.push(item);
+                }
+            )*};
+        }
+        macro_rules! whole {
+            ($($field:ident),* $(,)?) => {$(
+                if let Some(value) = $field {
+                    parts.fit(encoded_size(&value)).$field = Some(value);
+                }
+            )*};
+        }
+        macro_rules! whole_list {
+            ($($field:ident),* $(,)?) => {$(
+                if !$field.is_empty() {
+                    let size: usize = $field.iter().map(encoded_size).sum();
+                    parts.fit(size).$field = $field;
+                }
+            )*};
+        }
+
+        whole!(
+            power_metrics,
+            great_discovery_definitions,
+            great_discovery_telemetry,
+            knowledge_metrics,
+            knowledge_early_warnings,
+            victory,
+            capability_flags,
+            command_events,
+            pending_forks,
+            stance_axes,
+            voice_medium,
+            calendar,
+            crisis_telemetry,
+            crisis_overlay,
+            herds,
+            food_modules,
+            faction_inventory,
+            sedentarization,
+            discovered_sites,
+            espionage_agents,
+            demographics,
+            morale_summary,
+            forage_patches,
+            intensification_knowledge,
+            moisture_raster,
+            elevation_overlay,
+            climate_bands,
+            start_marker,
+            axis_bias,
+            sentiment,
+            logistics_raster,
+            sentiment_raster,
+            corruption_raster,
+            fog_raster,
+            culture_raster,
+            military_raster,
+            visibility_raster,
+            population_density_raster,
+            corruption,
+            diplomacy_leverage,
+            terrain,
+        );
+        whole_list!(knowledge_timeline, culture_tensions);
+        spread!(
+            tiles,
+            removed_tiles,
+            logistics,
+            removed_logistics,
+            trade_links,
+            removed_trade_links,
+            populations,
+            removed_populations,
+            power,
+            removed_power,
+            great_discoveries,
+            great_discovery_progress,
+            knowledge_ledger,
+            removed_knowledge_ledger,
+            generations,
+            removed_generations,
+            influencers,
+            removed_influencers,
+            culture_layers,
+            removed_culture_layers,
+            discovery_progress,
+        );
+        parts.finish()
+    }
+}
+
+/// Accumulates the parts of [`WorldDelta::split`], opening a new one whenever the next element
+/// would push the current part over the cap.
+struct DeltaParts {
+    shell: WorldDelta,
+    shell_size: usize,
+    max_bytes: usize,
+    current_size: usize,
+    parts: Vec<WorldDelta>,
+}
+
+impl DeltaParts {
+    fn new(header: SnapshotHeader, max_bytes: usize) -> Self {
+        let shell = WorldDelta {
+            header,
+            ..WorldDelta::default()
+        };
+        let shell_size = shell.estimate_size();
+        Self {
+            parts: vec![shell.clone()],
+            shell,
+            shell_size,
+            max_bytes,
+            current_size: shell_size,
+        }
+    }
+
+    /// The part an element of `size` bytes goes into.
+    fn fit(&mut self, size: usize) -> &mut WorldDelta {
+        let has_content = self.current_size > self.shell_size;
+        if has_content && self.current_size.saturating_add(size) > self.max_bytes {
+            self.parts.push(self.shell.clone());
+            self.current_size = self.shell_size;
+        }
+        self.current_size = self.current_size.saturating_add(size);
+        self.parts.last_mut().expect("parts starts non-empty")
+    }
+
+    fn finish(self) -> Vec<WorldDelta> {
+        let mut parts = self.parts;
+        let count = parts.len() as u32;
+        for (index, part) in parts.iter_mut().enumerate() {
+            part.header.delta_part = index as u32;
+            part.header.delta_part_count = count;
+        }
+        parts
+    }
+}
+
+fn encoded_size<T: Serialize + ?Sized>(value: &T) -> usize {
+    bincode::serialized_size(value).map_or(usize::MAX, |size| size as usize)
+}
+
+/// Last-write-wins merge of one keyed section and its removed-id list (see [`WorldDelta::merge`]).
+fn merge_keyed<T, K>(
+    (updates, removed): (&mut Vec<T>, &mut Vec<K>),
+    (later_updates, later_removed): (Vec<T>, Vec<K>),
+    key: impl Fn(&T) -> K,
+) where
+    K: Copy + Eq + Hash,
+{
+    if !later_removed.is_empty() {
+        updates.retain(|state| !later_removed.contains(&key(state)));
+    }
+    if !later_updates.is_empty() {
+        removed.retain(|id| !later_updates.iter().any(|state| key(state) == *id));
+    }
+    for id in later_removed {
+        if !removed.contains(&id) {
+            removed.push(id);
+        }
+    }
+    upsert_keyed(updates, later_updates, key);
+}
+
+/// Replaces entries whose key is already present, in place, and appends the rest in order.
+fn upsert_keyed<T, K>(updates: &mut Vec<T>, later_updates: Vec<T>, key: impl Fn(&T) -> K)
+where
+    K: Eq + Hash,
+{
+    if later_updates.is_empty() {
+        return;
+    }
+    let mut index: HashMap<K, usize> = updates
+        .iter()
+        .enumerate()
+        .map(|(position, state)| (key(state), position))
+        .collect();
+    for state in later_updates {
+        match index.get(&key(&state)) {
+            Some(&position) => updates[position] = state,
+            None => {
+                index.insert(key(&state), updates.len());
+                updates.push(state);
+            }
+        }
+    }
+}
+
+fn replace_if_sent<T>(current: &mut Vec<T>, later: Vec<T>) {
+    if !later.is_empty() {
+        *current = later;
+    }
+}
+
+fn keep_latest<T>(current: &mut Option<T>, later: Option<T>) {
+    if later.is_some() {
+        *current = later;
+    }
+}
+
+/// Why [`WorldSnapshot::validate`] rejected a snapshot.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SnapshotValidationError {
+    /// A `SnapshotHeader` count disagrees with the length of the section it describes.
+    HeaderCountMismatch {
+        section: &'static str,
+        header: u32,
+        actual: usize,
+    },
+    /// A raster's sample buffer does not cover its `width * height` grid.
+    RasterSizeMismatch {
+        raster: &'static str,
+        width: u32,
+        height: u32,
+        samples: usize,
+    },
+}
+
+impl fmt::Display for SnapshotValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::HeaderCountMismatch {
+                section,
+                header,
+                actual,
+            } => write!(
+                f,
+                "header reports {header} {section} but the snapshot carries {actual}"
+            ),
+            Self::RasterSizeMismatch {
+                raster,
+                width,
+                height,
+                samples,
+            } => write!(
+                f,
+                "{raster} is {width}x{height} but carries {samples} samples"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for SnapshotValidationError {}
+
+/// Fluent constructor for [`WorldSnapshot`] test fixtures and tools. Every section starts empty
+/// (the same values as `WorldSnapshot::default()`), so a fixture names only what it cares about
+/// and keeps compiling as fields are added. [`Self::build`] derives the header counts from the
+/// sections and runs [`WorldSnapshot::validate`].
+#[derive(Debug, Clone, Default)]
+pub struct WorldSnapshotBuilder {
+    snapshot: WorldSnapshot,
+}
+
+impl WorldSnapshotBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn tick(mut self, tick: u64) -> Self {
+        self.snapshot.header.tick = tick;
+        self
+    }
+
+    pub fn wrap_horizontal(mut self, wrap: bool) -> Self {
+        self.snapshot.header.wrap_horizontal = wrap;
+        self
+    }
+
+    pub fn tiles(mut self, tiles: Vec<TileState>) -> Self {
+        self.snapshot.tiles = tiles;
+        self
+    }
+
+    pub fn logistics(mut self, logistics: Vec<LogisticsLinkState>) -> Self {
+        self.snapshot.logistics = logistics;
+        self
+    }
+
+    pub fn trade_links(mut self, trade_links: Vec<TradeLinkState>) -> Self {
+        self.snapshot.trade_links = trade_links;
+        self
+    }
+
+    pub fn populations(mut self, populations: Vec<PopulationCohortState>) -> Self {
+        self.snapshot.populations = populations;
+        self
+    }
+
+    pub fn power(mut self, power: Vec<PowerNodeState>) -> Self {
+        self.snapshot.power = power;
+        self
+    }
+
+    pub fn influencers(mut self, influencers: Vec<InfluentialIndividualState>) -> Self {
+        self.snapshot.influencers = influencers;
+        self
+    }
+
+    pub fn knowledge_ledger(mut self, entries: Vec<KnowledgeLedgerEntryState>) -> Self {
+        self.snapshot.knowledge_ledger = entries;
+        self
+    }
+
+    pub fn knowledge_metrics(mut self, metrics: KnowledgeMetricsState) -> Self {
+        self.snapshot.knowledge_metrics = metrics;
+        self
+    }
+
+    pub fn terrain(mut self, terrain: TerrainOverlayState) -> Self {
+        self.snapshot.terrain = terrain;
+        self
+    }
+
+    pub fn elevation_overlay(mut self, overlay: ElevationOverlayState) -> Self {
+        self.snapshot.elevation_overlay = overlay;
+        self
+    }
+
+    pub fn moisture_raster(mut self, raster: FloatRasterState) -> Self {
+        self.snapshot.moisture_raster = raster;
+        self
+    }
+
+    pub fn start_marker(mut self, marker: StartMarkerState) -> Self {
+        self.snapshot.start_marker = Some(marker);
+        self
+    }
+
+    /// Escape hatch for sections without a dedicated setter.
+    pub fn with(mut self, edit: impl FnOnce(&mut WorldSnapshot)) -> Self {
+        edit(&mut self.snapshot);
+        self
+    }
+
+    /// Syncs the header counts to the sections, then validates. The hash is left unset; call
+    /// [`WorldSnapshot::finalize`] when a fixture needs it.
+    pub fn build(self) -> Result<WorldSnapshot, SnapshotValidationError> {
+        let mut snapshot = self.snapshot;
+        let header = &mut snapshot.header;
+        header.tile_count = snapshot.tiles.len() as u32;
+        header.logistics_count = snapshot.logistics.len() as u32;
+        header.trade_link_count = snapshot.trade_links.len() as u32;
+        header.population_count = snapshot.populations.len() as u32;
+        header.power_count = snapshot.power.len() as u32;
+        header.influencer_count = snapshot.influencers.len() as u32;
+        snapshot.validate()?;
+        Ok(snapshot)
+    }
 }
 
 pub fn hash_snapshot(snapshot: &WorldSnapshot) -> u64 {
     let mut clone = snapshot.clone();
     clone.header.hash = 0;
+    clone.header.sequence = 0;
     let encoded = bincode::serialize(&clone).expect("snapshot serialization for hashing");
     let mut hasher = RandomState::with_seeds(0, 0, 0, 0).build_hasher();
     hasher.write(&encoded);
@@ -368,3 +1173,148 @@ pub fn encode_map_export_json(export: &MapExport) -> serde_json::Result<String>
 pub fn decode_map_export_json(data: &str) -> serde_json::Result<MapExport> {
     serde_json::from_str(data)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builder_defaults_produce_a_valid_empty_snapshot() {
+        let snapshot = WorldSnapshotBuilder::new()
+            .build()
+            .expect("empty snapshot validates");
+        assert!(snapshot.tiles.is_empty());
+        assert_eq!(snapshot.header.tile_count, 0);
+        assert!(snapshot.validate().is_ok());
+    }
+
+    #[test]
+    fn builder_populated_snapshot_syncs_header_and_validates() {
+        let snapshot = WorldSnapshotBuilder::new()
+            .tick(42)
+            .wrap_horizontal(true)
+            .logistics(vec![LogisticsLinkState {
+                entity: 7,
+                from: 1,
+                to: 2,
+                capacity: 10,
+                flow: 4,
+            }])
+            .terrain(TerrainOverlayState {
+                width: 2,
+                height: 2,
+                samples: vec![TerrainSample::default(); 4],
+                palette_hash: 0,
+            })
+            .moisture_raster(FloatRasterState {
+                width: 2,
+                height: 2,
+                samples: vec![0.5; 4],
+                ..Default::default()
+            })
+            .start_marker(StartMarkerState { x: 1, y: 0 })
+            .with(|snapshot| {
+                snapshot.fog_raster = ScalarRasterState {
+                    width: 2,
+                    height: 2,
+                    samples: vec![0; 4],
+                };
+            })
+            .build()
+            .expect("populated snapshot validates");
+
+        assert_eq!(snapshot.header.tick, 42);
+        assert!(snapshot.header.wrap_horizontal);
+        assert_eq!(snapshot.header.logistics_count, 1);
+        assert_eq!(snapshot.fog_raster.samples.len(), 4);
+        assert!(snapshot.validate().is_ok());
+    }
+
+    fn large_delta() -> WorldDelta {
+        let mut delta = WorldDelta {
+            header: SnapshotHeader::new(42, 600, 400, 0, 0, 0, 0),
+            ..WorldDelta::default()
+        };
+        delta.header.sequence = 7;
+        delta.logistics = (0..400)
+            .map(|entity| LogisticsLinkState {
+                entity,
+                from: entity,
+                to: entity + 1,
+                capacity: 10,
+                flow: entity as i64,
+            })
+            .collect();
+        delta.removed_tiles = (1_000..1_300).collect();
+        delta.knowledge_timeline = vec![KnowledgeTimelineEventState::default(); 20];
+        delta.fog_raster = Some(ScalarRasterState {
+            width: 10,
+            height: 10,
+            samples: vec![5; 100],
+        });
+        delta.capability_flags = Some(3);
+        delta
+    }
+
+    #[test]
+    fn split_delta_parts_stay_under_the_cap_and_merge_back_to_the_original() {
+        let original = large_delta();
+        let cap = 2_048;
+        assert!(original.estimate_size() > 4 * cap);
+        let expected = encode_delta(&original).unwrap();
+
+        let parts = original.split(cap);
+        assert!(parts.len() > 4, "got {} parts", parts.len());
+        for (index, part) in parts.iter().enumerate() {
+            assert!(
+                part.estimate_size() <= cap,
+                "part {index} is {} bytes",
+                part.estimate_size()
+            );
+            assert_eq!(part.header.tick, 42);
+            assert_eq!(part.header.sequence, 7);
+            assert_eq!(part.header.delta_part, index as u32);
+            assert_eq!(part.header.delta_part_count, parts.len() as u32);
+        }
+
+        let mut parts = parts.into_iter();
+        let mut applied = parts.next().unwrap();
+        for part in parts {
+            applied.merge(part);
+        }
+        applied.header.delta_part = 0;
+        applied.header.delta_part_count = 0;
+        assert_eq!(encode_delta(&applied).unwrap(), expected);
+    }
+
+    #[test]
+    fn a_delta_under_the_cap_is_not_split() {
+        let delta = large_delta();
+        let size = delta.estimate_size();
+        let parts = delta.split(size);
+        assert_eq!(parts.len(), 1);
+        assert_eq!(parts[0].header.delta_part_count, 0);
+    }
+
+    #[test]
+    fn build_rejects_a_raster_that_does_not_cover_its_grid() {
+        let err = WorldSnapshotBuilder::new()
+            .moisture_raster(FloatRasterState {
+                width: 3,
+                height: 2,
+                samples: vec![0.0; 4],
+                ..Default::default()
+            })
+            .build()
+            .expect_err("short raster is rejected");
+        assert_eq!(
+            err,
+            SnapshotValidationError::RasterSizeMismatch {
+                raster: "moisture_raster",
+                width: 3,
+                height: 2,
+                samples: 4,
+            }
+        );
+    }
+}
@@ -2,7 +2,9 @@ use glob::glob;
 use jsonschema::JSONSchema;
 use serde_json::Value;
 use sim_runtime::scripting::{manifest_schema, ScriptManifest};
-use sim_runtime::{parse_command_line, CommandEnvelope, COMMAND_VERBS};
+use sim_runtime::{
+    parse_command_line, CommandEnvelope, CommandPayload, COMMAND_PROTOCOL_VERSION, COMMAND_VERBS,
+};
 use std::env;
 use std::error::Error;
 use std::fmt;
@@ -507,25 +509,38 @@ fn build_command_pieces(
     Ok(parts)
 }
 
+/// Sends a handshake frame (protocol version plus the optional `SIM_COMMAND_TOKEN`) followed by
+/// `envelope` on one connection, so servers with `command_handshake_required` admit the command.
 fn send_proto_command(
     host: &str,
     port: u16,
     envelope: &CommandEnvelope,
 ) -> Result<(), CommandCliError> {
-    let bytes = envelope
-        .encode_to_vec()
-        .map_err(|err| CommandCliError::new(format!("failed to encode command: {err}")))?;
+    let handshake = CommandEnvelope {
+        payload: CommandPayload::Handshake {
+            protocol_version: COMMAND_PROTOCOL_VERSION,
+            token: env::var("SIM_COMMAND_TOKEN")
+                .ok()
+                .filter(|token| !token.is_empty()),
+        },
+        correlation_id: None,
+    };
     let addr = format!("{}:{}", host, port);
     let mut stream = TcpStream::connect(&addr)
         .map_err(|err| CommandCliError::new(format!("failed to connect to {addr}: {err}")))?;
-    stream
-        .write_all(&(bytes.len() as u32).to_le_bytes())
-        .map_err(|err| {
-            CommandCliError::new(format!("failed to write frame length to {addr}: {err}"))
+    for frame in [&handshake, envelope] {
+        let bytes = frame
+            .encode_to_vec()
+            .map_err(|err| CommandCliError::new(format!("failed to encode command: {err}")))?;
+        stream
+            .write_all(&(bytes.len() as u32).to_le_bytes())
+            .map_err(|err| {
+                CommandCliError::new(format!("failed to write frame length to {addr}: {err}"))
+            })?;
+        stream.write_all(&bytes).map_err(|err| {
+            CommandCliError::new(format!("failed to write command payload to {addr}: {err}"))
         })?;
-    stream.write_all(&bytes).map_err(|err| {
-        CommandCliError::new(format!("failed to write command payload to {addr}: {err}"))
-    })?;
+    }
     stream
         .flush()
         .map_err(|err| CommandCliError::new(format!("failed to flush command to {addr}: {err}")))?;